@@ -1,5 +1,8 @@
 use clap::{Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::io::OutputEncoding;
 
 /// Fast YAML processor with validation and linting
 #[derive(Parser, Debug)]
@@ -38,6 +41,12 @@ pub struct Cli {
     /// Verbose output
     #[arg(short, long, global = true)]
     pub verbose: bool,
+
+    /// Read default settings for all commands from FILE instead of the
+    /// nearest `.fy.yaml` or `.fy.yml` (found by walking up from the current
+    /// directory); command-line flags take precedence
+    #[arg(long, global = true, value_name = "FILE")]
+    pub config_file: Option<PathBuf>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -47,9 +56,30 @@ pub enum Command {
         /// Input file (default: stdin)
         file: Option<PathBuf>,
 
+        /// Read from stdin even if a file is given
+        #[arg(long)]
+        stdin: bool,
+
         /// Show parse statistics
         #[arg(long)]
         stats: bool,
+
+        /// Print the parser event stream as an indented tree (for debugging)
+        #[arg(long, conflicts_with = "stats")]
+        ast: bool,
+
+        /// Maximum nesting depth of collections
+        #[arg(long, default_value_t = fast_yaml_core::Parser::DEFAULT_MAX_DEPTH, value_name = "N")]
+        max_depth: usize,
+
+        /// Maximum number of nodes, counting each alias as a copy of its anchor
+        #[arg(long, default_value_t = fast_yaml_core::Parser::DEFAULT_MAX_NODES, value_name = "N")]
+        max_nodes: usize,
+
+        /// Validate the document against a JSON Schema file.
+        /// Exits with code 2 if the document does not match.
+        #[arg(long, value_name = "FILE", conflicts_with = "ast")]
+        schema: Option<PathBuf>,
     },
 
     /// Format YAML with consistent style
@@ -59,22 +89,95 @@ pub enum Command {
         #[arg(value_name = "PATHS")]
         paths: Vec<PathBuf>,
 
-        /// Indentation width (2-8 spaces)
-        #[arg(long, default_value = "2", value_parser = clap::value_parser!(u8).range(2..=8))]
-        indent: u8,
+        /// Read the document from stdin even if paths are given
+        #[arg(long, conflicts_with_all = ["stdin_files", "stdin_files_nul", "include", "exclude", "jobs"])]
+        stdin: bool,
 
-        /// Maximum line width
-        #[arg(long, default_value = "80")]
-        width: usize,
+        /// Indentation width (2-8 spaces) [default: 2]
+        #[arg(long, value_parser = clap::value_parser!(u8).range(2..=8))]
+        indent: Option<u8>,
 
-        /// Number of parallel jobs (0 = auto-detect)
-        #[arg(short = 'j', long, default_value = "0")]
-        jobs: usize,
+        /// Maximum line width [default: 80]
+        #[arg(long)]
+        width: Option<usize>,
+
+        /// How to read files that are not valid UTF-8 (latin1: decode as ISO-8859-1,
+        /// replace: substitute U+FFFD); decoded files are written back as UTF-8
+        #[arg(long, value_enum, default_value = "strict", value_name = "MODE")]
+        encoding_fallback: EncodingFallback,
+
+        /// Encoding of the formatted output (UTF-16 variants include a BOM).
+        /// Only supported for a single file or stdin, not for batch runs
+        #[arg(
+            long,
+            value_enum,
+            default_value = "utf8",
+            value_name = "ENC",
+            conflicts_with = "diff"
+        )]
+        output_encoding: OutputEncoding,
+
+        /// Rewrite null values as `~`, `null`, or an empty value
+        /// (default: keep the original representation)
+        #[arg(long, value_enum, value_name = "STYLE")]
+        null_style: Option<NullStyle>,
+
+        /// Double-quote plain strings that YAML 1.1 reads as booleans (yaml11)
+        /// or integers (integers), or all plain strings (all)
+        #[arg(long, value_enum, value_name = "MODE")]
+        normalize_strings: Option<StringNormalization>,
+
+        /// Keep every scalar in its original style (quoted, plain, block) and
+        /// flow collections (`[a, b]`, `{k: v}`) in flow style; overrides
+        /// --normalize-strings
+        #[arg(long)]
+        keep_styles: bool,
+
+        /// Write `---` before the first document too, not only between documents
+        #[arg(long, overrides_with = "no_explicit_start")]
+        explicit_start: bool,
+
+        /// Don't add `---` before the first document, overriding
+        /// `explicit_start: true` in the config file
+        #[arg(long, overrides_with = "explicit_start")]
+        no_explicit_start: bool,
+
+        /// End the output with a newline (default)
+        #[arg(
+            long,
+            alias = "ensure-trailing-newline",
+            overrides_with = "no_trailing_newline"
+        )]
+        trailing_newline: bool,
+
+        /// Omit the final newline, e.g. when embedding YAML in another format
+        #[arg(long, overrides_with = "trailing_newline")]
+        no_trailing_newline: bool,
+
+        /// Skip files larger than SIZE bytes; accepts K, M and G suffixes
+        /// (powers of 1024), e.g. 512K or 1MB
+        #[arg(long, value_name = "SIZE", value_parser = parse_size)]
+        max_file_size: Option<u64>,
+
+        /// Number of worker threads for batch formatting, 1-256
+        /// (default: auto-detect)
+        #[arg(
+            short = 'j',
+            long,
+            alias = "workers",
+            value_name = "N",
+            value_parser = clap::value_parser!(u16).range(1..=256)
+        )]
+        jobs: Option<u16>,
 
         /// Read file paths from stdin (one per line)
         #[arg(long, conflicts_with = "paths")]
         stdin_files: bool,
 
+        /// Read NUL-delimited file paths from stdin (`find -print0`, `git ls-files -z`)
+        #[arg(long, conflicts_with_all = ["paths", "stdin_files"])]
+        stdin_files_nul: bool,
+
         /// Include files matching glob pattern (can be repeated)
         #[arg(long)]
         include: Vec<String>,
@@ -91,14 +194,109 @@ pub enum Command {
         #[arg(short = 'n', long)]
         dry_run: bool,
 
+        /// Print a unified diff of the changes instead of the formatted content.
+        /// Exits with code 1 if any file would change.
+        #[arg(long, conflicts_with = "dry_run")]
+        diff: bool,
+
+        /// When printing several formatted files to stdout, write SEP between
+        /// them, e.g. `---` (`\n` and `\t` are expanded)
+        #[arg(
+            long,
+            value_name = "SEP",
+            allow_hyphen_values = true,
+            conflicts_with_all = ["dry_run", "diff"]
+        )]
+        output_separator: Option<String>,
+
+        /// When printing several formatted files to stdout, write
+        /// `# === <file> ===` before each one
+        #[arg(long, conflicts_with_all = ["dry_run", "diff"])]
+        output_header: bool,
+
+        /// Batch result report format (junit: XML test report on stdout)
+        #[arg(long, value_enum, default_value = "text", conflicts_with = "diff")]
+        reporter: ReportFormat,
+
+        /// After formatting in place, print the slowest files with their read,
+        /// parse, format and write times
+        #[arg(long)]
+        timing: bool,
+
+        /// Number of files listed by --timing
+        #[arg(long, value_name = "N", default_value_t = 10, requires = "timing")]
+        timing_top: usize,
+
+        /// Show a progress bar on stderr while formatting several files
+        #[arg(short = 'P', long)]
+        progress: bool,
+
+        /// Stop formatting in place after N files have failed; files not
+        /// started yet are reported as skipped
+        #[arg(
+            long,
+            value_name = "N",
+            value_parser = clap::value_parser!(u64).range(1..)
+        )]
+        max_errors: Option<u64>,
+
+        /// Only format files modified at or after this RFC 3339 timestamp,
+        /// e.g. `2024-05-01T12:00:00Z` or `2024-05-01T14:00:00+02:00`
+        #[arg(
+            long,
+            value_name = "RFC3339",
+            value_parser = parse_timestamp,
+            conflicts_with_all = ["stdin", "stdin_files", "stdin_files_nul"]
+        )]
+        since_timestamp: Option<SystemTime>,
+
         /// Suppress the error when YAML comments are detected.
         /// Comments are not preserved by the formatter and will be stripped.
         /// Without this flag, formatting a file that contains comments exits with an error.
         #[arg(long)]
         strip_comments: bool,
+
+        /// Preserve the leading comment block (shebang, copyright notice) verbatim.
+        /// Only applies when formatting a single file or stdin.
+        #[arg(long)]
+        respect_file_header: bool,
     },
 
-    /// Convert between YAML and JSON
+    /// Check that YAML files are already formatted, without writing them.
+    /// Exits with code 2 if any file would change; files with comments always
+    /// would, since the formatter strips them.
+    Check {
+        /// Input paths (files, directories, or glob patterns).
+        /// If empty, reads from stdin
+        #[arg(value_name = "PATHS")]
+        paths: Vec<PathBuf>,
+
+        /// Indentation width (2-8 spaces) [default: 2]
+        #[arg(long, value_parser = clap::value_parser!(u8).range(2..=8))]
+        indent: Option<u8>,
+
+        /// Maximum line width [default: 80]
+        #[arg(long)]
+        width: Option<usize>,
+
+        /// Include files matching glob pattern (can be repeated)
+        #[arg(long)]
+        include: Vec<String>,
+
+        /// Exclude files matching glob pattern (can be repeated)
+        #[arg(long)]
+        exclude: Vec<String>,
+
+        /// Don't recurse into subdirectories
+        #[arg(long)]
+        no_recursive: bool,
+
+        /// Number of parallel jobs (0 = auto-detect)
+        #[arg(short = 'j', long, default_value = "0")]
+        jobs: usize,
+    },
+
+    /// Convert between YAML, JSON, XML, TOML and `MessagePack`
     Convert {
         /// Target format
         #[arg(value_enum)]
@@ -107,9 +305,54 @@ pub enum Command {
         /// Input file (default: stdin)
         file: Option<PathBuf>,
 
+        /// Input format when converting to YAML (default: TOML for `.toml` files,
+        /// otherwise detect JSON or XML)
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        from: Option<ConvertSource>,
+
         /// Pretty-print JSON output
         #[arg(long, default_value_t = true, num_args = 0..=1, default_missing_value = "true", action = clap::ArgAction::Set)]
         pretty: bool,
+
+        /// Root element name for XML output
+        #[arg(long, value_name = "TAG", default_value = "root")]
+        xml_root: String,
+
+        /// Write single-key mappings with a scalar value as XML attributes
+        #[arg(long)]
+        xml_attributes: bool,
+
+        /// Convert YAML to JSON without building a document tree, reading the input
+        /// as it goes. Keys keep their input order, so this requires
+        /// --preserve-order; use --pretty=false for one JSON value per line
+        #[arg(long)]
+        streaming: bool,
+
+        /// Convert every document of a multi-document YAML stream and wrap them
+        /// in a JSON array (without it, multi-document input is an error)
+        #[arg(short = 'm', long, conflicts_with = "streaming")]
+        multi_document: bool,
+
+        /// Keep mapping keys in input order instead of sorting them alphabetically.
+        /// JSON object key order carries no meaning per the JSON spec, so this is
+        /// advisory for consumers that display or diff the output
+        #[arg(long)]
+        preserve_order: bool,
+    },
+
+    /// Compare two YAML files by value and list the keys that were added,
+    /// removed or changed. Exits with code 1 if the files differ and 2 if
+    /// either file cannot be read or parsed.
+    Diff {
+        /// Old file
+        old: PathBuf,
+
+        /// New file
+        new: PathBuf,
+
+        /// Diff output format
+        #[arg(long, value_enum, default_value = "text")]
+        format: DiffFormat,
     },
 
     #[cfg(feature = "linter")]
@@ -120,6 +363,10 @@ pub enum Command {
         #[arg(value_name = "PATHS")]
         paths: Vec<PathBuf>,
 
+        /// Read the document from stdin even if paths are given
+        #[arg(long, conflicts_with_all = ["include", "exclude", "jobs", "baseline"])]
+        stdin: bool,
+
         /// Path to config file (default: auto-discover .fast-yaml.yaml)
         #[arg(long, value_name = "FILE", conflicts_with = "no_config")]
         config: Option<PathBuf>,
@@ -148,8 +395,14 @@ pub enum Command {
         #[arg(long)]
         include: Vec<String>,
 
-        /// Exclude files matching glob pattern (can be repeated)
-        #[arg(long)]
+        /// Skip files matching glob pattern; a bare name such as `vendor` skips
+        /// that file or directory at any depth (can be repeated)
+        #[arg(
+            long,
+            visible_alias = "ignore-path",
+            value_name = "PATTERN",
+            action = clap::ArgAction::Append
+        )]
         exclude: Vec<String>,
 
         /// Don't recurse into subdirectories
@@ -159,6 +412,35 @@ pub enum Command {
         /// Number of parallel jobs (0 = auto-detect)
         #[arg(short = 'j', long, default_value = "0")]
         jobs: usize,
+
+        /// Batch result report format (junit: XML test report on stdout)
+        #[arg(long, value_enum, default_value = "text")]
+        reporter: ReportFormat,
+
+        /// Don't report violations recorded in this baseline file
+        #[arg(long, value_name = "FILE")]
+        baseline: Option<PathBuf>,
+
+        /// Rewrite the baseline file: drop fixed violations, add new ones
+        #[arg(long, requires = "baseline")]
+        baseline_update: bool,
+
+        /// Also warn about anchors that are defined but never aliased
+        #[arg(long)]
+        report_unused_anchors: bool,
+
+        /// Exit with code 2 on warnings as well as errors
+        /// (by default only errors fail the run)
+        #[arg(long)]
+        fail_on_warning: bool,
+
+        /// Print detailed documentation for a rule and exit
+        #[arg(long, value_name = "CODE")]
+        rule_help: Option<String>,
+
+        /// List available rules and exit (with --verbose, include descriptions)
+        #[arg(long, conflicts_with = "rule_help")]
+        list_rules: bool,
     },
 }
 
@@ -171,15 +453,175 @@ pub enum OutputFormat {
 
 #[derive(ValueEnum, Clone, Debug)]
 pub enum ConvertFormat {
+    /// YAML (from JSON, XML or `MessagePack` input)
     Yaml,
+    /// JSON (from YAML input)
+    Json,
+    /// XML (from YAML input)
+    Xml,
+    /// `MessagePack` (from YAML input; base64 when written to stdout)
+    Msgpack,
+    /// TOML (from YAML input whose root is a mapping)
+    Toml,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiffFormat {
+    /// `- path: old` and `+ path: new` lines
+    Text,
+    /// JSON array of `{path, change, old, new}` objects
     Json,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvertSource {
+    /// JSON
+    Json,
+    /// XML
+    Xml,
+    /// `MessagePack` (a binary file, or base64 on stdin)
+    Msgpack,
+    /// TOML
+    Toml,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NullStyle {
+    /// `key: ~`
+    Tilde,
+    /// `key: null`
+    Null,
+    /// `key:`
+    Empty,
+}
+
+impl From<NullStyle> for fast_yaml_core::NullStyle {
+    fn from(style: NullStyle) -> Self {
+        match style {
+            NullStyle::Tilde => Self::Tilde,
+            NullStyle::Null => Self::Null,
+            NullStyle::Empty => Self::Empty,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StringNormalization {
+    /// `yes`, `no`, `on`, `off`, `y`, `n`
+    Yaml11,
+    /// `1_000`, `0b101`, `1:30`
+    Integers,
+    /// Every plain string
+    All,
+}
+
+impl From<StringNormalization> for fast_yaml_core::StringNormalization {
+    fn from(mode: StringNormalization) -> Self {
+        match mode {
+            StringNormalization::Yaml11 => Self::QuoteYaml11Scalars,
+            StringNormalization::Integers => Self::QuoteAmbiguousIntegers,
+            StringNormalization::All => Self::QuoteAll,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodingFallback {
+    /// Fail on files that are not valid UTF-8
+    Strict,
+    /// Decode as ISO-8859-1
+    Latin1,
+    /// Replace invalid bytes with U+FFFD
+    Replace,
+}
+
+impl From<EncodingFallback> for fast_yaml_parallel::EncodingFallback {
+    fn from(fallback: EncodingFallback) -> Self {
+        match fallback {
+            EncodingFallback::Strict => Self::Strict,
+            EncodingFallback::Latin1 => Self::Latin1Fallback,
+            EncodingFallback::Replace => Self::ReplaceInvalid,
+        }
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Text,
+    Junit,
+}
+
 #[cfg(feature = "linter")]
 #[derive(ValueEnum, Clone, Debug)]
 pub enum LintFormat {
     Text,
     Json,
+    /// GitHub Actions workflow commands (`::error file=…,line=…::message`)
+    Github,
+}
+
+/// Parses a byte size such as `1024`, `512K`, `1MB` or `2g`.
+///
+/// Suffixes are case-insensitive, may end in `B`, and use powers of 1024.
+fn parse_size(input: &str) -> Result<u64, String> {
+    let trimmed = input.trim();
+    let upper = trimmed.to_ascii_uppercase();
+    let without_b = upper.strip_suffix('B').unwrap_or(&upper);
+    let (digits, multiplier) = match without_b.chars().last() {
+        Some('K') => (&without_b[..without_b.len() - 1], 1u64 << 10),
+        Some('M') => (&without_b[..without_b.len() - 1], 1 << 20),
+        Some('G') => (&without_b[..without_b.len() - 1], 1 << 30),
+        _ => (without_b, 1),
+    };
+    digits
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .ok_or_else(|| format!("invalid size '{input}' (expected e.g. 1048576, 512K, 1MB, 2G)"))
+}
+
+/// Parses an RFC 3339 timestamp such as `2024-05-01T12:00:00Z`,
+/// `2024-05-01T14:00:00+02:00` or `2024-05-01` (midnight UTC).
+fn parse_timestamp(input: &str) -> Result<SystemTime, String> {
+    let invalid =
+        || format!("invalid timestamp '{input}' (expected RFC 3339, e.g. 2024-05-01T12:00:00Z)");
+    let trimmed = input.trim();
+    if !trimmed.is_ascii() {
+        return Err(invalid());
+    }
+
+    // humantime only accepts UTC, so a numeric offset is applied here
+    let bytes = trimmed.as_bytes();
+    let len = bytes.len();
+    let (datetime, offset_secs) =
+        if len > 16 && matches!(bytes[len - 6], b'+' | b'-') && bytes[len - 3] == b':' {
+            let hours: u64 = trimmed[len - 5..len - 3].parse().map_err(|_| invalid())?;
+            let minutes: u64 = trimmed[len - 2..].parse().map_err(|_| invalid())?;
+            let secs = (hours * 60 + minutes) * 60;
+            let signed = if bytes[len - 6] == b'-' {
+                -i64::try_from(secs).map_err(|_| invalid())?
+            } else {
+                i64::try_from(secs).map_err(|_| invalid())?
+            };
+            (&trimmed[..len - 6], signed)
+        } else {
+            (trimmed, 0)
+        };
+
+    let datetime = if datetime.len() == 10 {
+        format!("{datetime}T00:00:00Z")
+    } else {
+        datetime.to_string()
+    };
+    let local = humantime::parse_rfc3339_weak(&datetime).map_err(|_| invalid())?;
+    let offset = Duration::from_secs(offset_secs.unsigned_abs());
+    if offset_secs >= 0 {
+        local.checked_sub(offset)
+    } else {
+        local.checked_add(offset)
+    }
+    .ok_or_else(invalid)
 }
 
 #[cfg(test)]
@@ -191,4 +633,57 @@ mod tests {
         use clap::CommandFactory;
         Cli::command().debug_assert();
     }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("1024"), Ok(1024));
+        assert_eq!(parse_size("512K"), Ok(512 * 1024));
+        assert_eq!(parse_size("512kb"), Ok(512 * 1024));
+        assert_eq!(parse_size("1MB"), Ok(1024 * 1024));
+        assert_eq!(parse_size("1m"), Ok(1024 * 1024));
+        assert_eq!(parse_size("2G"), Ok(2 * 1024 * 1024 * 1024));
+        assert_eq!(parse_size("100B"), Ok(100));
+    }
+
+    #[test]
+    fn test_parse_size_invalid() {
+        assert!(parse_size("").is_err());
+        assert!(parse_size("MB").is_err());
+        assert!(parse_size("1.5M").is_err());
+        assert!(parse_size("-1").is_err());
+        assert!(parse_size("1T").is_err());
+        assert!(parse_size("99999999999999999999G").is_err());
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        let at = |secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs);
+
+        assert_eq!(
+            parse_timestamp("2024-05-01T12:00:00Z"),
+            Ok(at(1_714_564_800))
+        );
+        assert_eq!(
+            parse_timestamp("2024-05-01 12:00:00"),
+            Ok(at(1_714_564_800))
+        );
+        assert_eq!(
+            parse_timestamp("2024-05-01T14:00:00+02:00"),
+            Ok(at(1_714_564_800))
+        );
+        assert_eq!(
+            parse_timestamp("2024-05-01T09:30:00-02:30"),
+            Ok(at(1_714_564_800))
+        );
+        assert_eq!(parse_timestamp("2024-05-01"), Ok(at(1_714_521_600)));
+    }
+
+    #[test]
+    fn test_parse_timestamp_invalid() {
+        assert!(parse_timestamp("").is_err());
+        assert!(parse_timestamp("yesterday").is_err());
+        assert!(parse_timestamp("2024-13-01T00:00:00Z").is_err());
+        assert!(parse_timestamp("2024-05-01T12:00:00+xx:00").is_err());
+        assert!(parse_timestamp("2024-05-01T12:00:00Zé").is_err());
+    }
 }
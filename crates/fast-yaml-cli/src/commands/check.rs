@@ -0,0 +1,103 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use fast_yaml_core::Emitter;
+use fast_yaml_parallel::FileProcessor;
+
+use crate::commands::format::yaml_has_comments;
+use crate::config::CommonConfig;
+use crate::discovery::{DiscoveryConfig, FileDiscovery};
+use crate::error::ExitCode;
+use crate::io::InputSource;
+
+/// Check command implementation
+///
+/// Formats each file in memory and reports the ones whose content would
+/// change, without writing anything.
+pub struct CheckCommand {
+    config: CommonConfig,
+    discovery: DiscoveryConfig,
+}
+
+impl CheckCommand {
+    pub fn new(config: CommonConfig) -> Self {
+        Self {
+            config,
+            discovery: DiscoveryConfig::new(),
+        }
+    }
+
+    /// Set the file discovery configuration.
+    #[must_use]
+    pub fn with_discovery(mut self, discovery: DiscoveryConfig) -> Self {
+        self.discovery = discovery;
+        self
+    }
+
+    /// Execute check command
+    ///
+    /// Reads stdin when `paths` is empty. Prints the path of every file that
+    /// would be reformatted to stderr and returns `ExitCode::Unformatted`
+    /// (exit code 2) if there is any; files that fail to parse are reported
+    /// as errors and yield `ExitCode::ParseError`. The formatter drops
+    /// comments, so files with comments always count as unformatted and are
+    /// listed with that reason.
+    pub fn execute(&self, paths: &[PathBuf]) -> Result<ExitCode> {
+        let emitter_config = self.config.formatter.to_emitter_config();
+
+        if paths.is_empty() {
+            let input = InputSource::from_stdin()?;
+            let formatted = Emitter::format_with_config(input.as_str(), &emitter_config)
+                .context("Failed to format YAML")?;
+            if formatted == input.as_str() {
+                return Ok(ExitCode::Success);
+            }
+            report_unformatted("<stdin>", input.as_str());
+            return Ok(ExitCode::Unformatted);
+        }
+
+        let files = FileDiscovery::new(self.discovery.clone())
+            .context("Failed to initialize file discovery")?
+            .discover(paths)
+            .context("Failed to discover files")?;
+        let file_paths: Vec<PathBuf> = files.into_iter().map(|f| f.path).collect();
+
+        let processor = FileProcessor::with_config(self.config.parallel.clone());
+        let mut changed = 0;
+        let mut failed = 0;
+
+        for (path, result) in processor.format_files_with_original(&file_paths, &emitter_config) {
+            match result {
+                Ok((original, formatted)) if original != formatted => {
+                    report_unformatted(&path.display().to_string(), &original);
+                    changed += 1;
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    eprintln!("error: {error}");
+                    failed += 1;
+                }
+            }
+        }
+
+        if failed > 0 {
+            Ok(ExitCode::ParseError)
+        } else if changed > 0 {
+            Ok(ExitCode::Unformatted)
+        } else {
+            Ok(ExitCode::Success)
+        }
+    }
+}
+
+/// Print `label` to stderr, saying why when the changes come from comments.
+fn report_unformatted(label: &str, original: &str) {
+    if yaml_has_comments(original) {
+        eprintln!(
+            "{label}: YAML comments will be stripped by the formatter \
+             (`fy format --strip-comments` formats it anyway)"
+        );
+    } else {
+        eprintln!("{label}");
+    }
+}
@@ -1,52 +1,253 @@
 use anyhow::{Context, Result};
+use fast_yaml_core::streaming::{
+    convert_yaml_to_json_streaming, convert_yaml_to_json_streaming_pretty,
+};
 use fast_yaml_core::{Emitter, Parser, Value};
 use serde_json;
+use std::io::BufRead;
 
-use crate::cli::ConvertFormat;
+mod msgpack;
+mod toml;
+mod xml;
+
+pub use xml::XmlOptions;
+
+use crate::cli::{ConvertFormat, ConvertSource};
 use crate::config::CommonConfig;
 use crate::io::{InputSource, OutputWriter};
 
 /// Convert command implementation
+#[allow(clippy::struct_excessive_bools)]
 pub struct ConvertCommand {
     config: CommonConfig,
     target_format: ConvertFormat,
+    source: Option<ConvertSource>,
     pretty: bool,
+    streaming: bool,
+    multi_document: bool,
+    preserve_order: bool,
+    xml: XmlOptions,
 }
 
 impl ConvertCommand {
-    pub const fn new(config: CommonConfig, target_format: ConvertFormat, pretty: bool) -> Self {
+    pub fn new(config: CommonConfig, target_format: ConvertFormat, pretty: bool) -> Self {
         Self {
             config,
             target_format,
+            source: None,
             pretty,
+            streaming: false,
+            multi_document: false,
+            preserve_order: false,
+            xml: XmlOptions::default(),
         }
     }
 
+    /// Set the input format for conversion to YAML (`None` detects JSON or XML)
+    #[must_use]
+    pub const fn with_source(mut self, source: Option<ConvertSource>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Use the streaming YAML-to-JSON converter instead of building a DOM
+    #[must_use]
+    pub const fn with_streaming(mut self, streaming: bool) -> Self {
+        self.streaming = streaming;
+        self
+    }
+
+    /// Convert every document of a YAML stream into one JSON array
+    #[must_use]
+    pub const fn with_multi_document(mut self, multi_document: bool) -> Self {
+        self.multi_document = multi_document;
+        self
+    }
+
+    /// Keep mapping keys in input order instead of sorting them
+    #[must_use]
+    pub const fn with_preserve_order(mut self, preserve_order: bool) -> Self {
+        self.preserve_order = preserve_order;
+        self
+    }
+
+    /// Set root element name and attribute handling for XML output
+    #[must_use]
+    pub fn with_xml_options(mut self, xml: XmlOptions) -> Self {
+        self.xml = xml;
+        self
+    }
+
     /// Execute convert command
     pub fn execute(&self, input: &InputSource, output: &OutputWriter) -> Result<()> {
+        self.check_source()?;
         match self.target_format {
+            ConvertFormat::Json if self.streaming => {
+                self.execute_streaming(input.as_str().as_bytes(), output)
+            }
             ConvertFormat::Json => self.yaml_to_json(input, output),
-            ConvertFormat::Yaml => self.json_to_yaml(input, output),
+            _ if self.streaming => {
+                anyhow::bail!("--streaming is only supported when converting to JSON")
+            }
+            _ if self.multi_document => {
+                anyhow::bail!("--multi-document is only supported when converting to JSON")
+            }
+            ConvertFormat::Yaml => match self.source {
+                Some(ConvertSource::Msgpack) => {
+                    anyhow::bail!("MessagePack input is binary; use execute_msgpack")
+                }
+                Some(ConvertSource::Toml) => self.toml_to_yaml(input, output),
+                None if is_toml_input(input) => self.toml_to_yaml(input, output),
+                Some(ConvertSource::Xml) => Self::xml_to_yaml(input, output),
+                None if is_xml_input(input.as_str()) => Self::xml_to_yaml(input, output),
+                Some(ConvertSource::Json) | None => self.json_to_yaml(input, output),
+            },
+            ConvertFormat::Xml => self.yaml_to_xml(input, output),
+            ConvertFormat::Msgpack => Self::yaml_to_msgpack(input, output),
+            ConvertFormat::Toml => self.yaml_to_toml(input, output),
         }
     }
 
-    /// Convert YAML to JSON
-    fn yaml_to_json(&self, input: &InputSource, output: &OutputWriter) -> Result<()> {
-        // Parse all YAML documents to support multi-document streams
+    /// Execute streaming YAML-to-JSON conversion, reading `input` as it goes
+    ///
+    /// Keys are written in input order, so `preserve_order` must be set; the
+    /// output is pretty-printed unless `pretty` is off, which gives one
+    /// compact JSON value per line.
+    pub fn execute_streaming(&self, input: impl BufRead, output: &OutputWriter) -> Result<()> {
+        self.check_source()?;
+        if !matches!(self.target_format, ConvertFormat::Json) {
+            anyhow::bail!("--streaming is only supported when converting to JSON");
+        }
+        if !self.preserve_order {
+            anyhow::bail!(
+                "--streaming writes keys in input order and cannot sort them; \
+                 add --preserve-order"
+            );
+        }
+
+        output.write_stream(|out| {
+            let documents = if self.pretty {
+                convert_yaml_to_json_streaming_pretty(input, out)
+            } else {
+                convert_yaml_to_json_streaming(input, out)
+            }
+            .context("Failed to convert YAML to JSON")?;
+
+            if documents == 0 {
+                anyhow::bail!("Empty YAML document");
+            }
+            Ok(())
+        })
+    }
+
+    /// Execute convert command on `MessagePack` input, writing YAML
+    pub fn execute_msgpack(&self, input: &[u8], output: &OutputWriter) -> Result<()> {
+        self.check_source()?;
+        if self.streaming {
+            anyhow::bail!("--streaming is only supported when converting to JSON");
+        }
+        if self.multi_document {
+            anyhow::bail!("--multi-document is only supported when converting to JSON");
+        }
+        let yaml_value = msgpack::msgpack_to_value(input)?;
+        let yaml_string = Emitter::emit_str(&yaml_value).context("Failed to emit YAML")?;
+        output.write(&yaml_string)?;
+
+        Ok(())
+    }
+
+    /// Rejects `--from` for targets other than YAML
+    fn check_source(&self) -> Result<()> {
+        if self.source.is_some() && !matches!(self.target_format, ConvertFormat::Yaml) {
+            anyhow::bail!("--from is only supported when converting to YAML");
+        }
+        Ok(())
+    }
+
+    /// Convert YAML to `MessagePack`
+    fn yaml_to_msgpack(input: &InputSource, output: &OutputWriter) -> Result<()> {
         let docs = Parser::parse_all(input.as_str()).context("Failed to parse YAML")?;
 
         if docs.is_empty() {
             return Err(anyhow::anyhow!("Empty YAML document"));
         }
 
-        let json_value = if docs.len() == 1 {
-            // Single document: preserve existing behaviour (plain object/value)
-            value_to_json(&docs[0])?
-        } else {
-            // Multi-document stream: output a JSON array
+        let bytes = msgpack::yaml_to_msgpack(&docs)?;
+        output.write_bytes(&bytes)?;
+
+        Ok(())
+    }
+
+    /// Convert YAML to TOML
+    fn yaml_to_toml(&self, input: &InputSource, output: &OutputWriter) -> Result<()> {
+        let docs = Parser::parse_all(input.as_str()).context("Failed to parse YAML")?;
+
+        if docs.is_empty() {
+            return Err(anyhow::anyhow!("Empty YAML document"));
+        }
+
+        let toml_string = toml::yaml_to_toml(&docs, self.preserve_order)?;
+        output.write(&toml_string)?;
+
+        Ok(())
+    }
+
+    /// Convert TOML to YAML
+    fn toml_to_yaml(&self, input: &InputSource, output: &OutputWriter) -> Result<()> {
+        let yaml_value = toml::toml_to_value(input.as_str(), self.preserve_order)?;
+        let yaml_string = Emitter::emit_str(&yaml_value).context("Failed to emit YAML")?;
+        output.write(&yaml_string)?;
+
+        Ok(())
+    }
+
+    /// Convert YAML to XML
+    fn yaml_to_xml(&self, input: &InputSource, output: &OutputWriter) -> Result<()> {
+        let docs = Parser::parse_all(input.as_str()).context("Failed to parse YAML")?;
+
+        if docs.is_empty() {
+            return Err(anyhow::anyhow!("Empty YAML document"));
+        }
+
+        let xml_string = xml::yaml_to_xml(&docs, &self.xml)?;
+        output.write(&xml_string)?;
+
+        Ok(())
+    }
+
+    /// Convert XML to YAML
+    fn xml_to_yaml(input: &InputSource, output: &OutputWriter) -> Result<()> {
+        let yaml_value = xml::xml_to_value(input.as_str())?;
+        let yaml_string = Emitter::emit_str(&yaml_value).context("Failed to emit YAML")?;
+        output.write(&yaml_string)?;
+
+        Ok(())
+    }
+
+    /// Convert YAML to JSON
+    ///
+    /// A stream of several documents is rejected unless `multi_document` is
+    /// set, which always wraps the documents in a JSON array, even a single one.
+    fn yaml_to_json(&self, input: &InputSource, output: &OutputWriter) -> Result<()> {
+        let docs = Parser::parse_all(input.as_str()).context("Failed to parse YAML")?;
+
+        let mut json_value = if self.multi_document {
             let arr: Result<Vec<_>> = docs.iter().map(value_to_json).collect();
             serde_json::Value::Array(arr?)
+        } else {
+            match docs.as_slice() {
+                [] => anyhow::bail!("Empty YAML document"),
+                [doc] => value_to_json(doc)?,
+                _ => anyhow::bail!(
+                    "input contains {} YAML documents; use --multi-document (-m) to convert \
+                     them to a JSON array, or --streaming --preserve-order to convert them one by one",
+                    docs.len()
+                ),
+            }
         };
+        if !self.preserve_order {
+            json_value.sort_all_objects();
+        }
 
         // Serialize to JSON
         let mut json_string = if self.pretty {
@@ -68,8 +269,11 @@ impl ConvertCommand {
     #[allow(clippy::unused_self)]
     fn json_to_yaml(&self, input: &InputSource, output: &OutputWriter) -> Result<()> {
         // Parse JSON
-        let json_value: serde_json::Value =
+        let mut json_value: serde_json::Value =
             serde_json::from_str(input.as_str()).context("Failed to parse JSON")?;
+        if !self.preserve_order {
+            json_value.sort_all_objects();
+        }
 
         // Convert to YAML Value
         let yaml_value = json_to_value(&json_value)?;
@@ -84,6 +288,22 @@ impl ConvertCommand {
     }
 }
 
+/// Returns true if the input was read from a `.toml` file.
+fn is_toml_input(input: &InputSource) -> bool {
+    input
+        .file_path()
+        .and_then(|path| path.extension())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+}
+
+/// Returns true if the input looks like an XML document rather than JSON.
+fn is_xml_input(input: &str) -> bool {
+    input
+        .trim_start_matches('\u{feff}')
+        .trim_start()
+        .starts_with('<')
+}
+
 /// Coerce a YAML scalar key to its string representation for JSON output.
 ///
 /// JSON only supports string keys. Scalar YAML keys are converted to their
@@ -114,7 +334,7 @@ fn yaml_key_to_string(key: &Value) -> Result<String> {
 }
 
 /// Convert `fast_yaml_core::Value` to `serde_json::Value`
-fn value_to_json(value: &Value) -> Result<serde_json::Value> {
+pub fn value_to_json(value: &Value) -> Result<serde_json::Value> {
     use Value as YValue;
     use fast_yaml_core::value::ScalarOwned;
     use serde_json::Value as JValue;
@@ -250,6 +470,90 @@ mod tests {
         assert_eq!(json["value"], 123);
     }
 
+    fn yaml_to_json_string(yaml: &str, preserve_order: bool) -> String {
+        let input = InputSource {
+            content: yaml.to_string(),
+            origin: InputOrigin::Stdin,
+        };
+        let temp_dir = tempfile::tempdir().unwrap();
+        let temp_path = temp_dir.path().join("output.json");
+        let output = OutputWriter::from_args(Some(temp_path.clone()), false, None).unwrap();
+
+        ConvertCommand::new(CommonConfig::new(), ConvertFormat::Json, false)
+            .with_preserve_order(preserve_order)
+            .execute(&input, &output)
+            .unwrap();
+        std::fs::read_to_string(&temp_path).unwrap()
+    }
+
+    #[test]
+    fn test_yaml_to_json_preserve_order() {
+        let yaml = "z: 1\na: 2\nm: {y: 3, b: 4}\n";
+        assert_eq!(
+            yaml_to_json_string(yaml, true),
+            "{\"z\":1,\"a\":2,\"m\":{\"y\":3,\"b\":4}}\n"
+        );
+        assert_eq!(
+            yaml_to_json_string(yaml, false),
+            "{\"a\":2,\"m\":{\"b\":4,\"y\":3},\"z\":1}\n"
+        );
+    }
+
+    #[test]
+    fn test_yaml_to_json_streaming_matches_dom() {
+        let input = InputSource {
+            content: "name: test\nitems: [1, 2.5, ~]\nnested: {b: true, a: []}\n".to_string(),
+            origin: InputOrigin::Stdin,
+        };
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dom_path = temp_dir.path().join("dom.json");
+        let stream_path = temp_dir.path().join("stream.json");
+
+        ConvertCommand::new(CommonConfig::new(), ConvertFormat::Json, true)
+            .with_preserve_order(true)
+            .execute(
+                &input,
+                &OutputWriter::from_args(Some(dom_path.clone()), false, None).unwrap(),
+            )
+            .unwrap();
+        ConvertCommand::new(CommonConfig::new(), ConvertFormat::Json, true)
+            .with_preserve_order(true)
+            .with_streaming(true)
+            .execute(
+                &input,
+                &OutputWriter::from_args(Some(stream_path.clone()), false, None).unwrap(),
+            )
+            .unwrap();
+
+        // Same text, not just the same value: indentation and key order match
+        assert_eq!(
+            std::fs::read_to_string(&dom_path).unwrap(),
+            std::fs::read_to_string(&stream_path).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_streaming_requires_preserve_order() {
+        let cmd = ConvertCommand::new(CommonConfig::new(), ConvertFormat::Json, true)
+            .with_streaming(true);
+        let err = cmd
+            .execute_streaming(&b"b: 1\na: 2\n"[..], &OutputWriter::stdout())
+            .unwrap_err();
+        assert!(err.to_string().contains("--preserve-order"), "{err}");
+    }
+
+    #[test]
+    fn test_streaming_rejects_yaml_target() {
+        let input = InputSource {
+            content: "{}".to_string(),
+            origin: InputOrigin::Stdin,
+        };
+        let cmd = ConvertCommand::new(CommonConfig::new(), ConvertFormat::Yaml, false)
+            .with_streaming(true);
+        assert!(cmd.execute(&input, &OutputWriter::stdout()).is_err());
+    }
+
     #[test]
     fn test_json_to_yaml() {
         let input = InputSource {
@@ -334,6 +638,11 @@ mod tests {
 
         let config = CommonConfig::new();
         let cmd = ConvertCommand::new(config, ConvertFormat::Json, false);
+        let err = cmd.execute(&input, &output).unwrap_err().to_string();
+        assert!(err.contains("3 YAML documents"), "{err}");
+        assert!(err.contains("--multi-document"), "{err}");
+
+        let cmd = cmd.with_multi_document(true);
         assert!(cmd.execute(&input, &output).is_ok());
 
         let json_str = std::fs::read_to_string(&temp_path).unwrap();
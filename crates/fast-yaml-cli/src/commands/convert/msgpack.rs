@@ -0,0 +1,274 @@
+//! YAML/`MessagePack` conversion for `fy convert msgpack` and `fy convert yaml --from msgpack`.
+//!
+//! Values go through `serde`: YAML scalars, sequences and mappings map onto
+//! their `MessagePack` counterparts. Mapping keys must be non-null scalars, and
+//! `MessagePack` binary and extension values have no YAML equivalent, so both
+//! are rejected with an error rather than converted lossily.
+
+use std::fmt;
+use std::io::Cursor;
+
+use anyhow::{Context, Result, bail};
+use fast_yaml_core::value::ScalarOwned;
+use fast_yaml_core::{Map, Value};
+use saphyr_parser::ScalarStyle;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Serialize, SerializeMap, SerializeSeq, Serializer};
+
+/// Convert parsed YAML documents to `MessagePack`.
+///
+/// A single document becomes one `MessagePack` value; multiple documents are
+/// written as an array, as for JSON output.
+///
+/// # Errors
+///
+/// Returns an error if a mapping key is null or a collection, or the
+/// document contains a value that cannot be serialized.
+pub fn yaml_to_msgpack(docs: &[Value]) -> Result<Vec<u8>> {
+    let bytes = if let [doc] = docs {
+        rmp_serde::to_vec(&Msgpack(doc))
+    } else {
+        rmp_serde::to_vec(&docs.iter().map(Msgpack).collect::<Vec<_>>())
+    };
+    bytes.context("Failed to convert YAML to MessagePack")
+}
+
+/// Convert one `MessagePack` value to a YAML value.
+///
+/// # Errors
+///
+/// Returns an error if the input is not valid `MessagePack`, contains binary
+/// or extension values, or has bytes left after the first value.
+pub fn msgpack_to_value(bytes: &[u8]) -> Result<Value> {
+    let mut deserializer = rmp_serde::Deserializer::new(Cursor::new(bytes));
+    let YamlValue(value) =
+        YamlValue::deserialize(&mut deserializer).context("Failed to parse MessagePack")?;
+    let read = deserializer.position();
+    if read != bytes.len() as u64 {
+        bail!(
+            "Failed to parse MessagePack: {} unexpected byte(s) after the value",
+            bytes.len() as u64 - read
+        );
+    }
+    Ok(value)
+}
+
+/// Serializes a borrowed YAML value.
+struct Msgpack<'a>(&'a Value);
+
+impl Serialize for Msgpack<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Value(scalar) => match scalar {
+                ScalarOwned::Null => serializer.serialize_unit(),
+                ScalarOwned::Boolean(b) => serializer.serialize_bool(*b),
+                ScalarOwned::Integer(i) => serializer.serialize_i64(*i),
+                ScalarOwned::FloatingPoint(f) => serializer.serialize_f64(f.0),
+                ScalarOwned::String(s) => serializer.serialize_str(s),
+            },
+            Value::Representation(s, _, _) => serializer.serialize_str(s),
+            Value::Sequence(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(&Msgpack(item))?;
+                }
+                seq.end()
+            }
+            Value::Mapping(map) => {
+                let mut out = serializer.serialize_map(Some(map.len()))?;
+                for (key, value) in map {
+                    check_key(key).map_err(ser::Error::custom)?;
+                    out.serialize_entry(&Msgpack(key), &Msgpack(value))?;
+                }
+                out.end()
+            }
+            // Tags have no MessagePack equivalent; keep the tagged value
+            Value::Tagged(_, inner) => Msgpack(inner).serialize(serializer),
+            Value::Alias(_) => Err(ser::Error::custom(
+                "YAML aliases are not supported in MessagePack conversion",
+            )),
+            Value::BadValue => Err(ser::Error::custom("Invalid YAML value encountered")),
+        }
+    }
+}
+
+/// Rejects mapping keys that `MessagePack` consumers cannot use.
+fn check_key(key: &Value) -> Result<(), &'static str> {
+    match key {
+        Value::Value(ScalarOwned::Null) => {
+            Err("Unsupported YAML map key: null keys cannot be converted to MessagePack")
+        }
+        Value::Value(_) | Value::Representation(..) => Ok(()),
+        Value::Tagged(_, inner) => check_key(inner),
+        _ => Err(
+            "Unsupported YAML map key type: only scalar keys (string, number, boolean) \
+             can be converted to MessagePack",
+        ),
+    }
+}
+
+/// Deserializes a `MessagePack` value into a YAML value.
+struct YamlValue(Value);
+
+impl<'de> Deserialize<'de> for YamlValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(YamlVisitor).map(YamlValue)
+    }
+}
+
+struct YamlVisitor;
+
+impl<'de> Visitor<'de> for YamlVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a MessagePack value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::Null))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        self.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_bool<E: de::Error>(self, b: bool) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::Boolean(b)))
+    }
+
+    fn visit_i64<E: de::Error>(self, i: i64) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::Integer(i)))
+    }
+
+    fn visit_u64<E: de::Error>(self, u: u64) -> Result<Value, E> {
+        // YAML integers are i64; keep larger values exact as plain scalars
+        Ok(i64::try_from(u).map_or_else(
+            |_| Value::Representation(u.to_string(), ScalarStyle::Plain, None),
+            |i| Value::Value(ScalarOwned::Integer(i)),
+        ))
+    }
+
+    fn visit_f64<E: de::Error>(self, f: f64) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::FloatingPoint(f.into())))
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::String(s.to_string())))
+    }
+
+    fn visit_string<E: de::Error>(self, s: String) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::String(s)))
+    }
+
+    fn visit_bytes<E: de::Error>(self, _: &[u8]) -> Result<Value, E> {
+        Err(E::custom(
+            "MessagePack binary values cannot be converted to YAML",
+        ))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(self, _: D) -> Result<Value, D::Error> {
+        // rmp-serde reports extension types as newtype structs
+        Err(de::Error::custom(
+            "MessagePack extension types cannot be converted to YAML",
+        ))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(YamlValue(item)) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Value::Sequence(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Value, A::Error> {
+        let mut map = Map::new();
+        while let Some((YamlValue(key), YamlValue(value))) = access.next_entry()? {
+            map.insert(key, value);
+        }
+        Ok(Value::Mapping(map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_yaml_core::Parser;
+
+    fn roundtrip(yaml: &str) -> Value {
+        let docs = Parser::parse_all(yaml).unwrap();
+        msgpack_to_value(&yaml_to_msgpack(&docs).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip_scalars_and_collections() {
+        let yaml =
+            "name: app\nport: 8080\nratio: 0.5\nenabled: true\nnothing: null\ntags: [a, b]\n";
+        assert_eq!(roundtrip(yaml), Parser::parse_str(yaml).unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_non_string_keys_roundtrip() {
+        let yaml = "1: one\ntrue: yes\n";
+        assert_eq!(roundtrip(yaml), Parser::parse_str(yaml).unwrap().unwrap());
+    }
+
+    #[test]
+    fn test_multiple_documents_become_array() {
+        let value = roundtrip("a: 1\n---\nb: 2\n");
+        assert!(matches!(value, Value::Sequence(ref docs) if docs.len() == 2));
+    }
+
+    #[test]
+    fn test_compact_encoding() {
+        let docs = Parser::parse_all("a: 1\n").unwrap();
+        // fixmap(1), fixstr "a", positive fixint 1
+        assert_eq!(yaml_to_msgpack(&docs).unwrap(), [0x81, 0xa1, b'a', 0x01]);
+    }
+
+    #[test]
+    fn test_null_key_rejected() {
+        let docs = Parser::parse_all("~: value\n").unwrap();
+        let err = yaml_to_msgpack(&docs).unwrap_err();
+        assert!(format!("{err:#}").contains("null keys cannot be converted"));
+    }
+
+    #[test]
+    fn test_collection_key_rejected() {
+        let docs = Parser::parse_all("? [a, b]\n: value\n").unwrap();
+        let err = yaml_to_msgpack(&docs).unwrap_err();
+        assert!(format!("{err:#}").contains("only scalar keys"));
+    }
+
+    #[test]
+    fn test_large_unsigned_integer_kept_exact() {
+        // uint64 18446744073709551615
+        let value =
+            msgpack_to_value(&[0xcf, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]).unwrap();
+        assert!(matches!(value, Value::Representation(ref s, _, _) if s == "18446744073709551615"));
+    }
+
+    #[test]
+    fn test_binary_rejected() {
+        // bin8 of length 2
+        let err = msgpack_to_value(&[0xc4, 0x02, 0x00, 0x01]).unwrap_err();
+        assert!(format!("{err:#}").contains("binary values cannot be converted"));
+    }
+
+    #[test]
+    fn test_trailing_bytes_rejected() {
+        let err = msgpack_to_value(&[0x01, 0x02]).unwrap_err();
+        assert!(err.to_string().contains("1 unexpected byte(s)"));
+    }
+
+    #[test]
+    fn test_invalid_msgpack() {
+        // array16 header without its elements
+        assert!(msgpack_to_value(&[0xdc, 0x00, 0x05]).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+//! YAML/TOML conversion for `fy convert toml` and `fy convert yaml --from toml`.
+//!
+//! A TOML document is a table, so only YAML documents whose root is a mapping
+//! can be converted. TOML has no null and only string keys: YAML nulls and
+//! non-string mapping keys are rejected with an error naming the offending
+//! path rather than dropped or coerced. TOML datetimes become YAML strings.
+
+use anyhow::{Context, Result, bail};
+use fast_yaml_core::value::ScalarOwned;
+use fast_yaml_core::{Map, Value};
+use ordered_float::OrderedFloat;
+
+/// Convert a parsed YAML document to a TOML string.
+///
+/// Mapping keys are sorted unless `preserve_order` is set.
+///
+/// # Errors
+///
+/// Returns an error if there is more than one document, the root is not a
+/// mapping, or the document contains a null, a non-string key or an alias.
+pub fn yaml_to_toml(docs: &[Value], preserve_order: bool) -> Result<String> {
+    let [doc] = docs else {
+        bail!(
+            "TOML has no multi-document streams; the YAML input contains {} documents",
+            docs.len()
+        );
+    };
+
+    let table = match untag(doc) {
+        Value::Mapping(map) => mapping_to_table(map, "", preserve_order)?,
+        other => bail!(
+            "A TOML document must be a table; the YAML document root is {}",
+            kind(other)
+        ),
+    };
+
+    ::toml::to_string(&table).context("Failed to serialize TOML")
+}
+
+/// Convert a TOML document to a YAML value.
+///
+/// Table keys are sorted unless `preserve_order` is set.
+///
+/// # Errors
+///
+/// Returns an error if the input is not valid TOML.
+pub fn toml_to_value(input: &str, preserve_order: bool) -> Result<Value> {
+    let table: ::toml::Table = input.parse().context("Failed to parse TOML")?;
+    Ok(table_to_mapping(table, preserve_order))
+}
+
+fn mapping_to_table(map: &Map, path: &str, preserve_order: bool) -> Result<::toml::Table> {
+    let mut entries = Vec::with_capacity(map.len());
+    for (key, value) in map {
+        let key = match untag(key) {
+            Value::Value(ScalarOwned::String(s)) => s.clone(),
+            other => bail!(
+                "Unsupported YAML map key in '{}': TOML keys must be strings, found {}",
+                display_path(path),
+                kind(other)
+            ),
+        };
+        let child = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        entries.push((key, value_to_toml(value, &child, preserve_order)?));
+    }
+    if !preserve_order {
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+    Ok(entries.into_iter().collect())
+}
+
+fn value_to_toml(value: &Value, path: &str, preserve_order: bool) -> Result<::toml::Value> {
+    use ::toml::Value as TValue;
+
+    Ok(match value {
+        Value::Value(scalar) => match scalar {
+            ScalarOwned::Null => bail!(
+                "YAML null at '{}' cannot be represented in TOML (TOML has no null value)",
+                display_path(path)
+            ),
+            ScalarOwned::Boolean(b) => TValue::Boolean(*b),
+            ScalarOwned::Integer(i) => TValue::Integer(*i),
+            ScalarOwned::FloatingPoint(f) => TValue::Float(f.0),
+            ScalarOwned::String(s) => TValue::String(s.clone()),
+        },
+        Value::Representation(s, _, _) => TValue::String(s.clone()),
+        Value::Sequence(items) => TValue::Array(
+            items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| value_to_toml(item, &format!("{path}[{i}]"), preserve_order))
+                .collect::<Result<_>>()?,
+        ),
+        Value::Mapping(map) => TValue::Table(mapping_to_table(map, path, preserve_order)?),
+        // Tags have no TOML equivalent; keep the tagged value
+        Value::Tagged(_, inner) => value_to_toml(inner, path, preserve_order)?,
+        Value::Alias(_) => bail!("YAML aliases are not supported in TOML conversion"),
+        Value::BadValue => bail!("Invalid YAML value encountered"),
+    })
+}
+
+fn table_to_mapping(table: ::toml::Table, preserve_order: bool) -> Value {
+    let mut entries: Vec<_> = table.into_iter().collect();
+    if !preserve_order {
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+    let mut map = Map::new();
+    for (key, value) in entries {
+        map.insert(
+            Value::Value(ScalarOwned::String(key)),
+            toml_to_yaml(value, preserve_order),
+        );
+    }
+    Value::Mapping(map)
+}
+
+fn toml_to_yaml(value: ::toml::Value, preserve_order: bool) -> Value {
+    use ::toml::Value as TValue;
+
+    match value {
+        TValue::String(s) => Value::Value(ScalarOwned::String(s)),
+        TValue::Integer(i) => Value::Value(ScalarOwned::Integer(i)),
+        TValue::Float(f) => Value::Value(ScalarOwned::FloatingPoint(OrderedFloat(f))),
+        TValue::Boolean(b) => Value::Value(ScalarOwned::Boolean(b)),
+        TValue::Datetime(dt) => Value::Value(ScalarOwned::String(dt.to_string())),
+        TValue::Array(items) => Value::Sequence(
+            items
+                .into_iter()
+                .map(|item| toml_to_yaml(item, preserve_order))
+                .collect(),
+        ),
+        TValue::Table(table) => table_to_mapping(table, preserve_order),
+    }
+}
+
+fn untag(value: &Value) -> &Value {
+    match value {
+        Value::Tagged(_, inner) => untag(inner),
+        other => other,
+    }
+}
+
+/// Describes a YAML value for error messages.
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Value(ScalarOwned::Null) => "null",
+        Value::Value(ScalarOwned::Boolean(_)) => "a boolean",
+        Value::Value(ScalarOwned::Integer(_)) => "an integer",
+        Value::Value(ScalarOwned::FloatingPoint(_)) => "a float",
+        Value::Value(ScalarOwned::String(_)) | Value::Representation(..) => "a string",
+        Value::Sequence(_) => "a sequence",
+        Value::Mapping(_) => "a mapping",
+        Value::Tagged(_, inner) => kind(inner),
+        Value::Alias(_) => "an alias",
+        Value::BadValue => "an invalid value",
+    }
+}
+
+const fn display_path(path: &str) -> &str {
+    if path.is_empty() { "<root>" } else { path }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fast_yaml_core::Parser;
+
+    fn convert(yaml: &str) -> Result<String> {
+        let docs = Parser::parse_all(yaml).unwrap();
+        yaml_to_toml(&docs, false)
+    }
+
+    #[test]
+    fn test_yaml_to_toml_tables_and_arrays() {
+        let toml =
+            convert("title: demo\nserver:\n  port: 8080\n  ratio: 0.5\ntags: [a, b]\n").unwrap();
+        assert_eq!(
+            toml,
+            "tags = [\"a\", \"b\"]\ntitle = \"demo\"\n\n[server]\nport = 8080\nratio = 0.5\n"
+        );
+    }
+
+    #[test]
+    fn test_yaml_to_toml_preserve_order() {
+        let docs = Parser::parse_all("b: 1\na: 2\n").unwrap();
+        assert_eq!(yaml_to_toml(&docs, true).unwrap(), "b = 1\na = 2\n");
+        assert_eq!(yaml_to_toml(&docs, false).unwrap(), "a = 2\nb = 1\n");
+    }
+
+    #[test]
+    fn test_yaml_to_toml_null_names_path() {
+        let err = convert("server:\n  hosts: [a, ~]\n").unwrap_err();
+        assert!(err.to_string().contains("'server.hosts[1]'"), "{err}");
+        assert!(err.to_string().contains("no null"), "{err}");
+    }
+
+    #[test]
+    fn test_yaml_to_toml_non_string_key() {
+        let err = convert("ports:\n  80: http\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unsupported YAML map key in 'ports': TOML keys must be strings, found an integer"
+        );
+    }
+
+    #[test]
+    fn test_yaml_to_toml_rejects_non_mapping_root() {
+        let err = convert("- a\n- b\n").unwrap_err();
+        assert!(err.to_string().contains("root is a sequence"), "{err}");
+    }
+
+    #[test]
+    fn test_yaml_to_toml_rejects_multiple_documents() {
+        let err = convert("a: 1\n---\nb: 2\n").unwrap_err();
+        assert!(err.to_string().contains("2 documents"), "{err}");
+    }
+
+    #[test]
+    fn test_toml_to_value() {
+        let value = toml_to_value(
+            "name = \"x\"\nwhen = 1979-05-27\n[db]\nport = 5432\n",
+            false,
+        )
+        .unwrap();
+        let Value::Mapping(map) = value else {
+            panic!("expected a mapping");
+        };
+        let keys: Vec<_> = map.keys().map(|k| k.as_str().unwrap()).collect();
+        assert_eq!(keys, ["db", "name", "when"]);
+        assert_eq!(
+            map[&Value::Value(ScalarOwned::String("when".into()))],
+            Value::Value(ScalarOwned::String("1979-05-27".into()))
+        );
+    }
+
+    #[test]
+    fn test_invalid_toml() {
+        let err = toml_to_value("a = \n", false).unwrap_err();
+        assert!(err.to_string().contains("Failed to parse TOML"));
+    }
+}
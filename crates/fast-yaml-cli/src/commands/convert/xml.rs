@@ -0,0 +1,489 @@
+//! YAML/XML conversion for `fy convert xml` and XML input to `fy convert yaml`.
+//!
+//! Mapping keys become element names, sequences under a key become repeated
+//! elements with that name, and scalars become text content. Keys that are not
+//! valid XML names are written as `<item key="...">`. Top-level and nested
+//! sequences without a key use `<item>` elements.
+
+use anyhow::{Context, Result, bail};
+use fast_yaml_core::value::ScalarOwned;
+use fast_yaml_core::{Map, Parser, Value};
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer, XmlVersion};
+
+use super::yaml_key_to_string;
+
+/// Element name for sequence items and for keys that are not valid XML names.
+const ITEM_TAG: &str = "item";
+
+/// Element name wrapping each document of a multi-document stream.
+const DOCUMENT_TAG: &str = "document";
+
+/// Key under which text mixed with child elements is stored when reading XML.
+const TEXT_KEY: &str = "#text";
+
+/// Options for YAML-to-XML conversion.
+#[derive(Debug, Clone)]
+pub struct XmlOptions {
+    /// Name of the root element
+    pub root: String,
+    /// Serialize single-key mappings with a scalar value as attributes
+    pub attributes: bool,
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        Self {
+            root: "root".to_string(),
+            attributes: false,
+        }
+    }
+}
+
+/// Returns true if `name` can be used as an XML element name.
+///
+/// Namespace prefixes (`:`) are not accepted, so such keys fall back to `<item key="...">`.
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Convert parsed YAML documents to an XML document.
+///
+/// A single document becomes the content of the root element; multiple
+/// documents are wrapped in `<document>` elements under the root.
+///
+/// # Errors
+///
+/// Returns an error if the root name is not a valid XML name, or the YAML
+/// contains aliases, non-scalar keys, or invalid values.
+pub fn yaml_to_xml(docs: &[Value], options: &XmlOptions) -> Result<String> {
+    if !is_xml_name(&options.root) {
+        bail!("invalid XML root element name '{}'", options.root);
+    }
+
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    if let [doc] = docs {
+        write_element(&mut writer, &options.root, None, doc, options)?;
+    } else {
+        writer.write_event(Event::Start(BytesStart::new(options.root.as_str())))?;
+        for doc in docs {
+            write_element(&mut writer, DOCUMENT_TAG, None, doc, options)?;
+        }
+        writer.write_event(Event::End(BytesEnd::new(options.root.as_str())))?;
+    }
+
+    let mut xml = String::from_utf8(writer.into_inner()).context("XML output is not UTF-8")?;
+    xml.push('\n');
+    Ok(xml)
+}
+
+/// Text content for a scalar value.
+fn scalar_text(scalar: &ScalarOwned) -> Option<String> {
+    match scalar {
+        ScalarOwned::Null => None,
+        ScalarOwned::Boolean(b) => Some(b.to_string()),
+        ScalarOwned::Integer(i) => Some(i.to_string()),
+        ScalarOwned::FloatingPoint(f) => Some(f.to_string()),
+        ScalarOwned::String(s) => Some(s.clone()),
+    }
+}
+
+/// Returns the attribute for a single-key mapping whose value is a scalar.
+fn single_attribute(map: &Map) -> Option<(String, String)> {
+    let mut entries = map.iter();
+    let (key, value) = entries.next()?;
+    if entries.next().is_some() {
+        return None;
+    }
+    let Value::Value(scalar) = value else {
+        return None;
+    };
+    let key = yaml_key_to_string(key).ok()?;
+    (is_xml_name(&key) && key != "key").then(|| (key, scalar_text(scalar).unwrap_or_default()))
+}
+
+fn write_text_element(
+    writer: &mut Writer<Vec<u8>>,
+    start: BytesStart<'_>,
+    name: &str,
+    text: Option<&str>,
+) -> Result<()> {
+    match text {
+        None => writer.write_event(Event::Empty(start))?,
+        Some(text) => {
+            writer.write_event(Event::Start(start))?;
+            writer.write_event(Event::Text(BytesText::new(text)))?;
+            writer.write_event(Event::End(BytesEnd::new(name)))?;
+        }
+    }
+    Ok(())
+}
+
+fn write_element(
+    writer: &mut Writer<Vec<u8>>,
+    name: &str,
+    key_attr: Option<&str>,
+    value: &Value,
+    options: &XmlOptions,
+) -> Result<()> {
+    let mut start = BytesStart::new(name);
+    if let Some(key) = key_attr {
+        start.push_attribute(("key", key));
+    }
+
+    match value {
+        Value::Value(scalar) => {
+            write_text_element(writer, start, name, scalar_text(scalar).as_deref())?;
+        }
+        Value::Representation(s, _, _) => write_text_element(writer, start, name, Some(s))?,
+        Value::Sequence(items) => {
+            writer.write_event(Event::Start(start))?;
+            for item in items {
+                write_element(writer, ITEM_TAG, None, item, options)?;
+            }
+            writer.write_event(Event::End(BytesEnd::new(name)))?;
+        }
+        Value::Mapping(map) => {
+            if options.attributes
+                && let Some((attr, attr_value)) = single_attribute(map)
+            {
+                start.push_attribute((attr.as_str(), attr_value.as_str()));
+                writer.write_event(Event::Empty(start))?;
+                return Ok(());
+            }
+            writer.write_event(Event::Start(start))?;
+            for (key, child) in map {
+                write_entry(writer, key, child, options)?;
+            }
+            writer.write_event(Event::End(BytesEnd::new(name)))?;
+        }
+        Value::Tagged(_, inner) => write_element(writer, name, key_attr, inner, options)?,
+        Value::Alias(_) => bail!("YAML aliases are not supported in XML conversion"),
+        Value::BadValue => bail!("Invalid YAML value encountered"),
+    }
+    Ok(())
+}
+
+/// Write a mapping entry; sequence values become repeated elements named after the key.
+fn write_entry(
+    writer: &mut Writer<Vec<u8>>,
+    key: &Value,
+    value: &Value,
+    options: &XmlOptions,
+) -> Result<()> {
+    let key = yaml_key_to_string(key)?;
+    let (name, key_attr) = if is_xml_name(&key) {
+        (key.as_str(), None)
+    } else {
+        (ITEM_TAG, Some(key.as_str()))
+    };
+
+    match value {
+        Value::Sequence(items) if !items.is_empty() => {
+            for item in items {
+                write_element(writer, name, key_attr, item, options)?;
+            }
+            Ok(())
+        }
+        _ => write_element(writer, name, key_attr, value, options),
+    }
+}
+
+/// Parsed XML element.
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Self>,
+    text: String,
+}
+
+impl Element {
+    fn from_start(start: &BytesStart<'_>) -> Result<Self> {
+        let mut attributes = Vec::new();
+        for attr in start.attributes() {
+            let attr = attr.context("Invalid XML attribute")?;
+            let value = attr
+                .normalized_value(XmlVersion::Implicit1_0)
+                .context("Invalid XML attribute value")?;
+            attributes.push((attr.key.as_ref().to_string(), value.into_owned()));
+        }
+        Ok(Self {
+            name: start.name().as_ref().to_string(),
+            attributes,
+            ..Self::default()
+        })
+    }
+
+    /// Mapping key for this element: the `key` attribute of `<item>`, otherwise the name.
+    fn take_key(&mut self) -> String {
+        if self.name == ITEM_TAG
+            && let Some(pos) = self.attributes.iter().position(|(k, _)| k == "key")
+        {
+            return self.attributes.remove(pos).1;
+        }
+        self.name.clone()
+    }
+}
+
+/// Parse an XML document into a YAML value.
+///
+/// The root element itself is dropped and its content becomes the value.
+/// Repeated child elements become sequences, attributes become mapping
+/// entries, and text is resolved with YAML scalar rules (`42` is an integer).
+///
+/// # Errors
+///
+/// Returns an error if the XML is malformed, uses unknown entities, or has
+/// no root element.
+pub fn xml_to_value(input: &str) -> Result<Value> {
+    let mut reader = Reader::from_str(input);
+
+    let mut stack: Vec<Element> = Vec::new();
+    let mut root = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .with_context(|| format!("Failed to parse XML at byte {}", reader.error_position()))?;
+        match event {
+            Event::Start(start) => stack.push(Element::from_start(&start)?),
+            Event::Empty(start) => {
+                let element = Element::from_start(&start)?;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => root = Some(element),
+                }
+            }
+            Event::End(_) => {
+                let mut element = stack.pop().context("Unexpected closing XML tag")?;
+                // Text arrives in chunks split around entity references, so trim it as a whole
+                element.text = element.text.trim().to_string();
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => root = Some(element),
+                }
+            }
+            Event::Text(text) => {
+                if let Some(current) = stack.last_mut() {
+                    current.text.push_str(&text.xml10_content());
+                }
+            }
+            Event::CData(data) => {
+                if let Some(current) = stack.last_mut() {
+                    current.text.push_str(&data.xml10_content());
+                }
+            }
+            Event::GeneralRef(reference) => {
+                let Some(current) = stack.last_mut() else {
+                    continue;
+                };
+                if let Some(ch) = reference
+                    .resolve_char_ref()
+                    .context("Invalid XML character reference")?
+                {
+                    current.text.push(ch);
+                } else {
+                    let name = reference.xml10_content();
+                    let resolved = resolve_predefined_entity(&name)
+                        .with_context(|| format!("Unknown XML entity '&{name};'"))?;
+                    current.text.push_str(resolved);
+                }
+            }
+            Event::Eof => break,
+            Event::Comment(_) | Event::Decl(_) | Event::PI(_) | Event::DocType(_) => {}
+        }
+    }
+
+    let root = root.context("XML input has no root element")?;
+    Ok(element_to_value(root))
+}
+
+/// Resolve element text as a YAML plain scalar, falling back to a string.
+fn text_to_value(text: &str) -> Value {
+    match Parser::parse_str(text) {
+        Ok(Some(value @ Value::Value(_))) => value,
+        _ => Value::Value(ScalarOwned::String(text.to_string())),
+    }
+}
+
+const fn string_key(key: String) -> Value {
+    Value::Value(ScalarOwned::String(key))
+}
+
+fn element_to_value(element: Element) -> Value {
+    let Element {
+        attributes,
+        children,
+        text,
+        ..
+    } = element;
+
+    if attributes.is_empty() && children.is_empty() {
+        return if text.is_empty() {
+            Value::Value(ScalarOwned::Null)
+        } else {
+            text_to_value(&text)
+        };
+    }
+
+    // Only unkeyed <item> children: a sequence
+    let is_sequence = attributes.is_empty()
+        && text.is_empty()
+        && children
+            .iter()
+            .all(|c| c.name == ITEM_TAG && c.attributes.iter().all(|(k, _)| k != "key"));
+    if is_sequence {
+        return Value::Sequence(children.into_iter().map(element_to_value).collect());
+    }
+
+    let mut map = Map::new();
+    for (key, value) in attributes {
+        map.insert(string_key(key), text_to_value(&value));
+    }
+
+    let mut repeated: Vec<String> = Vec::new();
+    for mut child in children {
+        let key = child.take_key();
+        let value = element_to_value(child);
+        let map_key = string_key(key.clone());
+        match map.get_mut(&map_key) {
+            Some(Value::Sequence(items)) if repeated.contains(&key) => items.push(value),
+            Some(existing) => {
+                let first = std::mem::replace(existing, Value::Value(ScalarOwned::Null));
+                *existing = Value::Sequence(vec![first, value]);
+                repeated.push(key);
+            }
+            None => {
+                map.insert(map_key, value);
+            }
+        }
+    }
+
+    if !text.is_empty() {
+        map.insert(string_key(TEXT_KEY.to_string()), text_to_value(&text));
+    }
+
+    Value::Mapping(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_xml(yaml: &str, options: &XmlOptions) -> String {
+        let docs = Parser::parse_all(yaml).unwrap();
+        yaml_to_xml(&docs, options).unwrap()
+    }
+
+    #[test]
+    fn test_mapping_to_elements() {
+        let xml = to_xml("name: app\nport: 8080\n", &XmlOptions::default());
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains("<root>\n  <name>app</name>\n  <port>8080</port>\n</root>"));
+    }
+
+    #[test]
+    fn test_sequence_under_key_repeats_element() {
+        let xml = to_xml("tags: [a, b]\n", &XmlOptions::default());
+        assert!(xml.contains("<tags>a</tags>\n  <tags>b</tags>"));
+    }
+
+    #[test]
+    fn test_top_level_sequence_uses_item() {
+        let xml = to_xml("- 1\n- 2\n", &XmlOptions::default());
+        assert!(xml.contains("<root>\n  <item>1</item>\n  <item>2</item>\n</root>"));
+    }
+
+    #[test]
+    fn test_invalid_key_uses_item_with_key_attribute() {
+        let xml = to_xml("\"my key\": 1\n2fa: true\n", &XmlOptions::default());
+        assert!(xml.contains("<item key=\"my key\">1</item>"));
+        assert!(xml.contains("<item key=\"2fa\">true</item>"));
+    }
+
+    #[test]
+    fn test_text_is_escaped() {
+        let xml = to_xml("expr: a < b & c\n", &XmlOptions::default());
+        assert!(xml.contains("<expr>a &lt; b &amp; c</expr>"));
+    }
+
+    #[test]
+    fn test_null_is_empty_element() {
+        let xml = to_xml("value: ~\n", &XmlOptions::default());
+        assert!(xml.contains("<value/>"));
+    }
+
+    #[test]
+    fn test_custom_root_and_invalid_root() {
+        let options = XmlOptions {
+            root: "config".to_string(),
+            attributes: false,
+        };
+        assert!(to_xml("a: 1\n", &options).contains("<config>"));
+
+        let options = XmlOptions {
+            root: "not valid".to_string(),
+            attributes: false,
+        };
+        let docs = Parser::parse_all("a: 1\n").unwrap();
+        assert!(yaml_to_xml(&docs, &options).is_err());
+    }
+
+    #[test]
+    fn test_single_key_mapping_as_attribute() {
+        let options = XmlOptions {
+            attributes: true,
+            ..XmlOptions::default()
+        };
+        let xml = to_xml(
+            "server:\n  port: 80\nlimits:\n  cpu: 1\n  mem: 2\n",
+            &options,
+        );
+        assert!(xml.contains("<server port=\"80\"/>"));
+        assert!(xml.contains("<limits>\n    <cpu>1</cpu>"));
+    }
+
+    #[test]
+    fn test_multi_document_wraps_documents() {
+        let xml = to_xml("a: 1\n---\nb: 2\n", &XmlOptions::default());
+        assert!(xml.contains("<document>\n    <a>1</a>\n  </document>"));
+    }
+
+    #[test]
+    fn test_xml_to_value_round_trip() {
+        let yaml = "name: app\nport: 8080\ntags:\n- a\n- b\nnested:\n  enabled: true\n";
+        let docs = Parser::parse_all(yaml).unwrap();
+        let xml = yaml_to_xml(&docs, &XmlOptions::default()).unwrap();
+        assert_eq!(xml_to_value(&xml).unwrap(), docs[0]);
+    }
+
+    #[test]
+    fn test_xml_to_value_attributes_and_entities() {
+        let value =
+            xml_to_value("<config version=\"2\"><title>a &amp; b &#x41;</title><empty/></config>")
+                .unwrap();
+        let expected = Parser::parse_str("version: 2\ntitle: a & b A\nempty: ~\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn test_xml_to_value_item_key_attribute() {
+        let value = xml_to_value("<root><item key=\"my key\">1</item></root>").unwrap();
+        let expected = Parser::parse_str("my key: 1\n").unwrap().unwrap();
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn test_xml_to_value_malformed() {
+        assert!(xml_to_value("<root><a></root>").is_err());
+        assert!(xml_to_value("").is_err());
+    }
+}
@@ -0,0 +1,370 @@
+use std::fmt::Write as _;
+
+use anyhow::{Context, Result};
+use fast_yaml_core::{Parser, ScalarOwned, Value};
+
+use crate::cli::DiffFormat;
+use crate::commands::convert::value_to_json;
+use crate::error::ExitCode;
+use crate::io::{InputSource, OutputWriter};
+
+/// Diff command implementation
+///
+/// Compares two YAML files by their parsed values rather than their text, so
+/// formatting, comments and mapping key order do not count as changes.
+pub struct DiffCommand {
+    format: DiffFormat,
+}
+
+/// How the value at a path changed between the old and the new document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    /// Present only in the new document
+    Added,
+    /// Present only in the old document
+    Removed,
+    /// Present in both, with different values
+    Changed,
+}
+
+impl DiffKind {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::Changed => "changed",
+        }
+    }
+}
+
+/// A single difference found by [`diff_values`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffEntry {
+    /// Location of the value: mapping keys joined with `.`, sequence
+    /// indices as `[N]`; empty for the document root
+    pub path: String,
+    /// Kind of change
+    pub kind: DiffKind,
+    /// Value in the old document (`None` when added)
+    pub old: Option<Value>,
+    /// Value in the new document (`None` when removed)
+    pub new: Option<Value>,
+}
+
+impl DiffCommand {
+    pub const fn new(format: DiffFormat) -> Self {
+        Self { format }
+    }
+
+    /// Execute diff command
+    ///
+    /// Returns `ExitCode::ChangesFound` (exit code 1) when the documents differ,
+    /// like `fy format --diff`.
+    pub fn execute(
+        &self,
+        old: &InputSource,
+        new: &InputSource,
+        output: &OutputWriter,
+    ) -> Result<ExitCode> {
+        let old_value = parse_documents(old)?;
+        let new_value = parse_documents(new)?;
+        let entries = diff_values(&old_value, &new_value);
+
+        let report = match self.format {
+            DiffFormat::Text => format_text(&entries),
+            DiffFormat::Json => format_json(&entries)?,
+        };
+        output.write(&report)?;
+
+        if entries.is_empty() {
+            Ok(ExitCode::Success)
+        } else {
+            Ok(ExitCode::ChangesFound)
+        }
+    }
+}
+
+/// Parses `input` into the value to compare.
+///
+/// A single document is compared as is; a multi-document stream is compared
+/// as a sequence of documents, so its paths start with the document index.
+fn parse_documents(input: &InputSource) -> Result<Value> {
+    let label = input
+        .file_path()
+        .map_or_else(|| "<stdin>".to_string(), |p| p.display().to_string());
+    let mut docs =
+        Parser::parse_all(input.as_str()).with_context(|| format!("Failed to parse {label}"))?;
+    Ok(match docs.len() {
+        0 => Value::Value(ScalarOwned::Null),
+        1 => docs.remove(0),
+        _ => Value::Sequence(docs),
+    })
+}
+
+/// Recursively compares two values and returns every difference, in document
+/// order of `a` followed by the keys only found in `b`.
+///
+/// Mappings are compared key by key and sequences index by index; any other
+/// pair of values that is not equal is reported as one `Changed` entry.
+pub fn diff_values(a: &Value, b: &Value) -> Vec<DiffEntry> {
+    let mut entries = Vec::new();
+    diff_into(a, b, &mut String::new(), &mut entries);
+    entries
+}
+
+fn diff_into(a: &Value, b: &Value, path: &mut String, entries: &mut Vec<DiffEntry>) {
+    match (a, b) {
+        (Value::Mapping(old), Value::Mapping(new)) => {
+            for (key, old_value) in old {
+                let len = path.len();
+                push_key(path, key);
+                match new.get(key) {
+                    Some(new_value) => diff_into(old_value, new_value, path, entries),
+                    None => entries.push(entry(path, DiffKind::Removed, Some(old_value), None)),
+                }
+                path.truncate(len);
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    let len = path.len();
+                    push_key(path, key);
+                    entries.push(entry(path, DiffKind::Added, None, Some(new_value)));
+                    path.truncate(len);
+                }
+            }
+        }
+        (Value::Sequence(old), Value::Sequence(new)) => {
+            for index in 0..old.len().max(new.len()) {
+                let len = path.len();
+                let _ = write!(path, "[{index}]");
+                match (old.get(index), new.get(index)) {
+                    (Some(old_value), Some(new_value)) => {
+                        diff_into(old_value, new_value, path, entries);
+                    }
+                    (Some(old_value), None) => {
+                        entries.push(entry(path, DiffKind::Removed, Some(old_value), None));
+                    }
+                    (None, Some(new_value)) => {
+                        entries.push(entry(path, DiffKind::Added, None, Some(new_value)));
+                    }
+                    (None, None) => {}
+                }
+                path.truncate(len);
+            }
+        }
+        _ if a == b => {}
+        _ => entries.push(entry(path, DiffKind::Changed, Some(a), Some(b))),
+    }
+}
+
+fn entry(path: &str, kind: DiffKind, old: Option<&Value>, new: Option<&Value>) -> DiffEntry {
+    DiffEntry {
+        path: path.to_string(),
+        kind,
+        old: old.cloned(),
+        new: new.cloned(),
+    }
+}
+
+/// Appends a mapping key to `path`.
+///
+/// Keys that contain path punctuation or whitespace are written as
+/// `["key"]` so the path stays unambiguous.
+fn push_key(path: &mut String, key: &Value) {
+    let text = render(key);
+    if text.is_empty()
+        || text.starts_with('"')
+        || text.contains(['.', '[', ']'])
+        || text.contains(char::is_whitespace)
+    {
+        let quoted = match key {
+            Value::Value(ScalarOwned::String(s)) => quote(s),
+            _ => quote(&text),
+        };
+        let _ = write!(path, "[{quoted}]");
+        return;
+    }
+    if !path.is_empty() {
+        path.push('.');
+    }
+    path.push_str(&text);
+}
+
+/// Renders a value on one line in YAML flow style.
+///
+/// Strings are double-quoted when the plain form would read back as
+/// something else (a number, a boolean, `null`, a collection) so that
+/// `"1"` and `1` are told apart.
+fn render(value: &Value) -> String {
+    match value {
+        Value::Value(scalar) => match scalar {
+            ScalarOwned::Null => "null".to_string(),
+            ScalarOwned::Boolean(b) => b.to_string(),
+            ScalarOwned::Integer(i) => i.to_string(),
+            ScalarOwned::FloatingPoint(f) => render_float(f.0),
+            ScalarOwned::String(s) if reads_back_as_string(s) => s.clone(),
+            ScalarOwned::String(s) => quote(s),
+        },
+        Value::Sequence(items) => {
+            let items: Vec<String> = items.iter().map(render).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Mapping(map) => {
+            let pairs: Vec<String> = map
+                .iter()
+                .map(|(k, v)| format!("{}: {}", render(k), render(v)))
+                .collect();
+            format!("{{{}}}", pairs.join(", "))
+        }
+        Value::Tagged(tag, inner) => format!("{tag} {}", render(inner)),
+        Value::Representation(s, _, _) => s.clone(),
+        Value::Alias(_) => "*alias".to_string(),
+        Value::BadValue => "<invalid>".to_string(),
+    }
+}
+
+fn render_float(f: f64) -> String {
+    if f.is_nan() {
+        ".nan".to_string()
+    } else if f.is_infinite() {
+        if f > 0.0 { ".inf" } else { "-.inf" }.to_string()
+    } else if f.fract() == 0.0 && f.abs() < 1e16 {
+        format!("{f:.1}")
+    } else {
+        f.to_string()
+    }
+}
+
+/// Returns true if `s` written as a plain scalar parses back to the same string.
+fn reads_back_as_string(s: &str) -> bool {
+    if s.is_empty() || s.trim() != s || s.contains(['\n', '\r', '\t', '#', ',', '[', ']', '{', '}'])
+    {
+        return false;
+    }
+    matches!(
+        Parser::parse_str(s),
+        Ok(Some(Value::Value(ScalarOwned::String(ref parsed)))) if parsed == s
+    )
+}
+
+/// Double-quotes `s`; JSON string escapes are valid in YAML double-quoted scalars.
+fn quote(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| format!("\"{s}\""))
+}
+
+const fn display_path(path: &str) -> &str {
+    if path.is_empty() { "." } else { path }
+}
+
+/// Formats entries as `- path: old` / `+ path: new` lines.
+fn format_text(entries: &[DiffEntry]) -> String {
+    let mut out = String::new();
+    for entry in entries {
+        let path = display_path(&entry.path);
+        if let Some(old) = &entry.old {
+            let _ = writeln!(out, "- {path}: {}", render(old));
+        }
+        if let Some(new) = &entry.new {
+            let _ = writeln!(out, "+ {path}: {}", render(new));
+        }
+    }
+    out
+}
+
+/// Formats entries as a JSON array of `{path, change, old?, new?}` objects.
+///
+/// Values JSON cannot hold (`.inf`, `.nan`) are given in their YAML form as strings.
+fn format_json(entries: &[DiffEntry]) -> Result<String> {
+    let to_json = |value: &Value| value_to_json(value).unwrap_or_else(|_| render(value).into());
+    let items: Vec<serde_json::Value> = entries
+        .iter()
+        .map(|entry| {
+            let mut object = serde_json::Map::new();
+            object.insert("path".to_string(), display_path(&entry.path).into());
+            object.insert("change".to_string(), entry.kind.as_str().into());
+            if let Some(old) = &entry.old {
+                object.insert("old".to_string(), to_json(old));
+            }
+            if let Some(new) = &entry.new {
+                object.insert("new".to_string(), to_json(new));
+            }
+            serde_json::Value::Object(object)
+        })
+        .collect();
+
+    let mut json =
+        serde_json::to_string_pretty(&items).context("Failed to serialize diff as JSON")?;
+    json.push('\n');
+    Ok(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> Value {
+        Parser::parse_str(yaml).unwrap().unwrap()
+    }
+
+    fn paths(entries: &[DiffEntry]) -> Vec<(&str, DiffKind)> {
+        entries.iter().map(|e| (e.path.as_str(), e.kind)).collect()
+    }
+
+    #[test]
+    fn test_diff_values_identical() {
+        let a = parse("a: 1\nb: [x, y]\n");
+        let b = parse("b: [x, y]\na: 1\n");
+        assert!(diff_values(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn test_diff_values_nested_paths() {
+        let a = parse("db:\n  host: a\n  port: 1\nitems: [1, 2, 3]\n");
+        let b = parse("db:\n  host: b\n  user: u\nitems: [1, 5]\n");
+        assert_eq!(
+            paths(&diff_values(&a, &b)),
+            vec![
+                ("db.host", DiffKind::Changed),
+                ("db.port", DiffKind::Removed),
+                ("db.user", DiffKind::Added),
+                ("items[1]", DiffKind::Changed),
+                ("items[2]", DiffKind::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_values_type_change_is_one_entry() {
+        let a = parse("a: {x: 1}\n");
+        let b = parse("a: [1]\n");
+        let entries = diff_values(&a, &b);
+        assert_eq!(paths(&entries), vec![("a", DiffKind::Changed)]);
+    }
+
+    #[test]
+    fn test_push_key_quotes_punctuation() {
+        let a = parse("\"a.b\": 1\n\"\": 2\n");
+        let b = parse("\"a.b\": 3\n\"\": 4\n");
+        assert_eq!(
+            paths(&diff_values(&a, &b)),
+            vec![
+                ("[\"a.b\"]", DiffKind::Changed),
+                ("[\"\"]", DiffKind::Changed)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_distinguishes_strings() {
+        assert_eq!(render(&parse("'1'")), "\"1\"");
+        assert_eq!(render(&parse("1")), "1");
+        assert_eq!(render(&parse("'true'")), "\"true\"");
+        assert_eq!(render(&parse("hello world")), "hello world");
+        assert_eq!(render(&parse("2.0")), "2.0");
+        assert_eq!(render(&parse(".inf")), ".inf");
+        assert_eq!(
+            render(&parse("{a: [1, x], b: null}")),
+            "{a: [1, x], b: null}"
+        );
+    }
+}
@@ -1,13 +1,17 @@
 use anyhow::{Context, Result};
-use fast_yaml_core::{Emitter, EmitterConfig};
+use fast_yaml_core::Emitter;
+use similar::TextDiff;
 
 use crate::config::CommonConfig;
+use crate::error::ExitCode;
 use crate::io::{InputSource, OutputWriter};
 
 /// Format command implementation
 pub struct FormatCommand {
     config: CommonConfig,
     strip_comments: bool,
+    diff: bool,
+    respect_file_header: bool,
 }
 
 impl FormatCommand {
@@ -15,36 +19,133 @@ impl FormatCommand {
         Self {
             config,
             strip_comments,
+            diff: false,
+            respect_file_header: false,
         }
     }
 
+    /// Print a unified diff instead of the formatted content.
+    #[must_use]
+    pub const fn with_diff(mut self, diff: bool) -> Self {
+        self.diff = diff;
+        self
+    }
+
+    /// Keep the leading comment block (shebang, copyright notice) verbatim.
+    #[must_use]
+    pub const fn with_respect_file_header(mut self, respect_file_header: bool) -> Self {
+        self.respect_file_header = respect_file_header;
+        self
+    }
+
     /// Execute format command
-    pub fn execute(&self, input: &InputSource, output: &OutputWriter) -> Result<()> {
-        if !self.strip_comments && yaml_has_comments(input.as_str()) {
+    ///
+    /// In diff mode, returns `ExitCode::ChangesFound` (exit code 1) when the
+    /// input would be changed by formatting.
+    pub fn execute(&self, input: &InputSource, output: &OutputWriter) -> Result<ExitCode> {
+        let (header, body) = if self.respect_file_header {
+            split_file_header(input.as_str())
+        } else {
+            ("", input.as_str())
+        };
+
+        if !self.strip_comments && yaml_has_comments(body) {
             anyhow::bail!(
                 "warning: YAML comments will be stripped by the formatter. \
                  Use --strip-comments to suppress this error."
             );
         }
 
-        let emitter_config = EmitterConfig::new()
-            .with_indent(self.config.formatter.indent() as usize)
-            .with_width(self.config.formatter.width());
+        let emitter_config = self.config.formatter.to_emitter_config();
 
-        let formatted = Emitter::format_with_config(input.as_str(), &emitter_config)
-            .context("Failed to format YAML")?;
+        let mut formatted =
+            Emitter::format_with_config(body, &emitter_config).context("Failed to format YAML")?;
+        if !header.is_empty() {
+            formatted.insert_str(0, header);
+        }
+
+        if self.diff {
+            let label = input
+                .file_path()
+                .map_or_else(|| "<stdin>".to_string(), |p| p.display().to_string());
+            return match unified_diff(input.as_str(), &formatted, &label) {
+                Some(diff) => {
+                    output.write(&diff)?;
+                    Ok(ExitCode::ChangesFound)
+                }
+                None => Ok(ExitCode::Success),
+            };
+        }
 
         output.write(&formatted)?;
 
-        Ok(())
+        Ok(ExitCode::Success)
+    }
+}
+
+/// Builds a unified diff between `original` and `formatted`.
+///
+/// The `---` and `+++` headers read `a/<label>` and `b/<label>` (the label is
+/// usually the file name), as in `git diff`. Returns `None` if the contents are
+/// identical.
+pub fn unified_diff(original: &str, formatted: &str, label: &str) -> Option<String> {
+    if original == formatted {
+        return None;
+    }
+
+    let diff = TextDiff::from_lines(original, formatted);
+    Some(
+        diff.unified_diff()
+            .context_radius(3)
+            .header(&format!("a/{label}"), &format!("b/{label}"))
+            .to_string(),
+    )
+}
+
+/// Splits `input` into its file header and the remaining YAML.
+///
+/// The header is the run of comment lines (a shebang is a comment too) at the
+/// very start of the file; blank lines between them belong to the header. A
+/// blank line separating the header from the content is kept as well. Returns
+/// an empty header if the file does not start with a comment.
+fn split_file_header(input: &str) -> (&str, &str) {
+    let mut header_end = 0;
+    let mut offset = 0;
+    let mut blank_after_header = false;
+
+    for line in input.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            offset += line.len();
+            header_end = offset;
+            blank_after_header = false;
+        } else if trimmed.is_empty() && header_end > 0 {
+            offset += line.len();
+            blank_after_header = true;
+        } else {
+            break;
+        }
+    }
+
+    if header_end == 0 {
+        return ("", input);
+    }
+
+    // Keep a single blank line between the header and the content.
+    let mut split = header_end;
+    if blank_after_header {
+        split += input[header_end..]
+            .find('\n')
+            .map_or(0, |newline| newline + 1);
     }
+    (&input[..split], &input[offset..])
 }
 
 /// Returns true if the YAML input contains at least one comment.
 ///
 /// Scans line by line and tracks single-quoted and double-quoted string regions
 /// to avoid false positives from `#` inside string literals.
-fn yaml_has_comments(input: &str) -> bool {
+pub fn yaml_has_comments(input: &str) -> bool {
     for line in input.lines() {
         let mut in_single = false;
         let mut in_double = false;
@@ -148,6 +249,58 @@ mod tests {
         assert!(make_cmd(true).execute(&input, &output).is_ok());
     }
 
+    #[test]
+    fn test_format_diff_mode_writes_unified_diff() {
+        let input = InputSource {
+            content: "name:    test\nvalue:   123\n".to_string(),
+            origin: InputOrigin::Stdin,
+        };
+        let temp_file = NamedTempFile::new().unwrap();
+        let output =
+            OutputWriter::from_args(Some(temp_file.path().to_path_buf()), false, None).unwrap();
+
+        let code = make_cmd(false)
+            .with_diff(true)
+            .execute(&input, &output)
+            .unwrap();
+        assert_eq!(code, ExitCode::ChangesFound);
+
+        let diff = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(diff.starts_with("--- a/<stdin>\n+++ b/<stdin>\n"));
+        assert!(diff.contains("-name:    test\n"));
+        assert!(diff.contains("+name: test\n"));
+        assert!(diff.contains("-value:   123\n"));
+        assert!(diff.contains("+value: 123\n"));
+    }
+
+    #[test]
+    fn test_format_diff_mode_no_changes() {
+        let input = InputSource {
+            content: "name: test\n".to_string(),
+            origin: InputOrigin::Stdin,
+        };
+        let output = OutputWriter::stdout();
+        let code = make_cmd(false)
+            .with_diff(true)
+            .execute(&input, &output)
+            .unwrap();
+        assert_eq!(code, ExitCode::Success);
+    }
+
+    #[test]
+    fn test_unified_diff_identical() {
+        assert!(unified_diff("a: 1\n", "a: 1\n", "x.yaml").is_none());
+    }
+
+    #[test]
+    fn test_unified_diff_format() {
+        let diff = unified_diff("a:  1\nb: 2\n", "a: 1\nb: 2\n", "x.yaml").unwrap();
+        assert_eq!(
+            diff,
+            "--- a/x.yaml\n+++ b/x.yaml\n@@ -1,2 +1,2 @@\n-a:  1\n+a: 1\n b: 2\n"
+        );
+    }
+
     #[test]
     fn test_yaml_has_comments_detects_inline() {
         assert!(yaml_has_comments("key: value # inline"));
@@ -163,4 +316,60 @@ mod tests {
     fn test_yaml_has_comments_no_comment() {
         assert!(!yaml_has_comments("key: value\nother: 123"));
     }
+
+    #[test]
+    fn test_split_file_header() {
+        let (header, body) = split_file_header("#!/usr/bin/env app\n# Copyright\n\nkey: value\n");
+        assert_eq!(header, "#!/usr/bin/env app\n# Copyright\n\n");
+        assert_eq!(body, "key: value\n");
+
+        let (header, body) = split_file_header("# a\n\n# b\nkey: value\n");
+        assert_eq!(header, "# a\n\n# b\n");
+        assert_eq!(body, "key: value\n");
+
+        let (header, body) = split_file_header("# a\n\n\n\nkey: value\n");
+        assert_eq!(header, "# a\n\n");
+        assert_eq!(body, "key: value\n");
+
+        let (header, body) = split_file_header("key: value # trailing\n");
+        assert_eq!(header, "");
+        assert_eq!(body, "key: value # trailing\n");
+    }
+
+    #[test]
+    fn test_format_respect_file_header() {
+        let input = InputSource {
+            content: "# Copyright 2024 Example\n# SPDX-License-Identifier: MIT\n\nname:    test\n"
+                .to_string(),
+            origin: InputOrigin::Stdin,
+        };
+        let temp_file = NamedTempFile::new().unwrap();
+        let output =
+            OutputWriter::from_args(Some(temp_file.path().to_path_buf()), false, None).unwrap();
+
+        make_cmd(false)
+            .with_respect_file_header(true)
+            .execute(&input, &output)
+            .unwrap();
+
+        let formatted = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert_eq!(
+            formatted,
+            "# Copyright 2024 Example\n# SPDX-License-Identifier: MIT\n\nname: test\n"
+        );
+    }
+
+    #[test]
+    fn test_format_respect_file_header_body_comments_still_error() {
+        let input = InputSource {
+            content: "# header\nname: test # inline\n".to_string(),
+            origin: InputOrigin::Stdin,
+        };
+        let output = OutputWriter::stdout();
+        let err = make_cmd(false)
+            .with_respect_file_header(true)
+            .execute(&input, &output)
+            .unwrap_err();
+        assert!(err.to_string().contains("--strip-comments"));
+    }
 }
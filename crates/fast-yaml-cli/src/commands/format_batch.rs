@@ -1,18 +1,31 @@
 //! Batch format command execution.
 
+#[cfg(feature = "progress")]
+use std::fmt;
+use std::io::Write;
 use std::path::PathBuf;
+#[cfg(feature = "progress")]
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::SystemTime;
 
 use anyhow::{Context, Result, bail};
-use fast_yaml_core::emitter::EmitterConfig;
-use fast_yaml_parallel::{BatchResult as ParallelBatchResult, FileProcessor};
+#[cfg(feature = "progress")]
+use fast_yaml_parallel::ProgressCallback;
+use fast_yaml_parallel::{BatchResult as ParallelBatchResult, FileProcessor, FileResult};
+#[cfg(feature = "progress")]
+use indicatif::{ProgressBar, ProgressState, ProgressStyle};
 
+use crate::cli::ReportFormat;
+use crate::commands::format::unified_diff;
 use crate::config::CommonConfig;
 use crate::discovery::{DiscoveryConfig, FileDiscovery};
 use crate::error::ExitCode;
-use crate::reporter::{ReportEvent, Reporter};
+use crate::reporter::{FileOutcome, ReportEvent, Reporter};
 
 /// Configuration for batch format execution using composed configs.
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct BatchConfig {
     /// Common configuration (formatter, output, parallel settings)
     pub common: CommonConfig,
@@ -20,7 +33,24 @@ pub struct BatchConfig {
     pub discovery: DiscoveryConfig,
     /// Batch-specific settings
     pub dry_run: bool,
+    pub diff: bool,
     pub in_place: bool,
+    /// Printed between files when writing formatted output to stdout
+    pub output_separator: String,
+    /// Print `# === <file> ===` before each file written to stdout
+    pub output_header: bool,
+    /// Report format for the batch summary
+    pub reporter: ReportFormat,
+    /// Files larger than this many bytes are skipped
+    pub max_file_size: Option<u64>,
+    /// Number of slowest files to list in a timing table
+    pub timing: Option<usize>,
+    /// Draw a progress bar on stderr while files are processed
+    pub progress: bool,
+    /// File paths on stdin are NUL-delimited rather than one per line
+    pub stdin_files_nul: bool,
+    /// Only process files modified at or after this time
+    pub since: Option<SystemTime>,
 }
 
 impl BatchConfig {
@@ -29,7 +59,16 @@ impl BatchConfig {
             common,
             discovery: DiscoveryConfig::new(),
             dry_run: false,
+            diff: false,
             in_place: false,
+            output_separator: String::new(),
+            output_header: false,
+            reporter: ReportFormat::Text,
+            max_file_size: None,
+            timing: None,
+            progress: false,
+            stdin_files_nul: false,
+            since: None,
         }
     }
 
@@ -45,11 +84,67 @@ impl BatchConfig {
         self
     }
 
+    #[must_use]
+    pub const fn with_diff(mut self, diff: bool) -> Self {
+        self.diff = diff;
+        self
+    }
+
     #[must_use]
     pub const fn with_in_place(mut self, in_place: bool) -> Self {
         self.in_place = in_place;
         self
     }
+
+    /// Sets the separator printed between files on stdout, expanding the
+    /// escapes `\n`, `\t` and `\\`.
+    #[must_use]
+    pub fn with_output_separator(mut self, separator: &str) -> Self {
+        self.output_separator = expand_escapes(separator);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_output_header(mut self, output_header: bool) -> Self {
+        self.output_header = output_header;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_reporter(mut self, reporter: ReportFormat) -> Self {
+        self.reporter = reporter;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_max_file_size(mut self, max_file_size: Option<u64>) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_timing(mut self, top: Option<usize>) -> Self {
+        self.timing = top;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_progress(mut self, progress: bool) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_stdin_files_nul(mut self, stdin_files_nul: bool) -> Self {
+        self.stdin_files_nul = stdin_files_nul;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_since(mut self, since: Option<SystemTime>) -> Self {
+        self.since = since;
+        self
+    }
 }
 
 /// Execute batch formatting on multiple files.
@@ -63,10 +158,18 @@ pub fn execute_batch(
         .context("Failed to initialize file discovery")?;
 
     // Discover files
-    let files = if stdin_files {
+    let files = if stdin_files && config.stdin_files_nul {
+        discovery
+            .discover_from_stdin_nul()
+            .context("Failed to read file list from stdin")?
+    } else if stdin_files {
         discovery
             .discover_from_stdin()
             .context("Failed to read file list from stdin")?
+    } else if let Some(since) = config.since {
+        discovery
+            .discover_changed(paths, since)
+            .context("Failed to discover files")?
     } else {
         discovery
             .discover(paths)
@@ -76,7 +179,11 @@ pub fn execute_batch(
     // Handle empty result
     if files.is_empty() {
         if !config.common.output.is_quiet() {
-            eprintln!("No YAML files found");
+            if config.since.is_some() {
+                eprintln!("No YAML files modified since --since-timestamp");
+            } else {
+                eprintln!("No YAML files found");
+            }
         }
         return Ok(ExitCode::Success);
     }
@@ -84,33 +191,97 @@ pub fn execute_batch(
     // Create reporter
     let reporter = Reporter::new(config.common.output.clone());
 
-    // Extract paths from discovered files
-    let file_paths: Vec<PathBuf> = files.iter().map(|f| f.path.clone()).collect();
+    // Extract paths from discovered files, setting aside files over the size limit
+    let (file_paths, oversized) = split_oversized(
+        files.into_iter().map(|f| f.path).collect(),
+        config.max_file_size,
+    );
 
     // Create emitter config
-    let emitter_config = EmitterConfig::new()
-        .with_indent(config.common.formatter.indent() as usize)
-        .with_width(config.common.formatter.width());
+    let emitter_config = config.common.formatter.to_emitter_config();
 
-    // Create processor with config from CLI settings
-    let processor = FileProcessor::with_config(config.common.parallel.clone());
+    // Create processor with config from CLI settings, updating the progress bar if requested
+    #[cfg(feature = "progress")]
+    let progress = config.progress.then(|| progress_bar(file_paths.len()));
+    #[cfg(feature = "progress")]
+    let parallel_config = config
+        .common
+        .parallel
+        .clone()
+        .with_progress_callback(progress.clone().map(progress_callback));
+    #[cfg(not(feature = "progress"))]
+    let parallel_config = config.common.parallel.clone();
+    let processor = FileProcessor::with_config(parallel_config);
 
     // Process files based on mode
-    let result = if config.dry_run {
+    let mut result = if config.diff {
+        // Diff: format but don't write, print one unified diff per changed file
+        let formatted = processor.format_files_with_original(&file_paths, &emitter_config);
+        let (result, diffs) = diff_format_results(formatted);
+        if !diffs.is_empty() {
+            let mut stdout = std::io::stdout().lock();
+            stdout
+                .write_all(diffs.join("\n").as_bytes())
+                .context("Failed to write diff to stdout")?;
+        }
+        result
+    } else if config.dry_run {
         // Dry run: format but don't write, report what would change
         let formatted = processor.format_files(&file_paths, &emitter_config);
         convert_format_results_to_batch_result(formatted)
     } else if config.in_place {
-        // In-place: format and write
-        processor.format_in_place(&file_paths, &emitter_config)
+        // In-place: format and write, reporting each file as it finishes in verbose mode
+        if config.common.output.is_verbose() {
+            let done = AtomicUsize::new(0);
+            processor.format_in_place_with_progress(&file_paths, &emitter_config, |file| {
+                let current = done.fetch_add(1, Ordering::Relaxed) + 1;
+                let _ = report_file_result(&reporter, current, file_paths.len(), file);
+            })
+        } else {
+            processor.format_in_place(&file_paths, &emitter_config)
+        }
     } else {
-        bail!("use -i to format files in-place or --dry-run to preview changes");
+        // Stdout: print the formatted files in order
+        if config.reporter == ReportFormat::Junit {
+            bail!(
+                "--reporter junit requires -i, --dry-run or --diff when formatting several files"
+            );
+        }
+        let formatted = processor.format_files(&file_paths, &emitter_config);
+        let (result, output) =
+            join_format_results(formatted, &config.output_separator, config.output_header);
+        let mut stdout = std::io::stdout().lock();
+        stdout
+            .write_all(output.as_bytes())
+            .context("Failed to write formatted output to stdout")?;
+        result
     };
+    // Files skipped after reaching --max-errors come after the oversized ones
+    let stopped_early = result.skipped.len();
+    result.skipped.splice(0..0, oversized.iter().cloned());
+
+    #[cfg(feature = "progress")]
+    if let Some(bar) = progress {
+        bar.finish_and_clear();
+    }
+
+    if let Some(top) = config.timing {
+        eprint!("{}", timing_table(&result, top));
+    }
+
+    if config.reporter == ReportFormat::Junit {
+        let mut stdout = std::io::stdout().lock();
+        stdout
+            .write_all(result.to_junit_xml("fy format").as_bytes())
+            .context("Failed to write JUnit report to stdout")?;
+        return Ok(exit_code(config, &result));
+    }
 
     // Report results using BatchSummary event
-    // In dry-run mode, 'changed' means "would change"; in in-place mode it means "formatted".
-    let would_change = if config.dry_run { result.changed } else { 0 };
-    let formatted = if config.dry_run { 0 } else { result.changed };
+    // In dry-run and diff modes, 'changed' means "would change"; in in-place mode it means "formatted".
+    let preview = config.dry_run || config.diff;
+    let would_change = if preview { result.changed } else { 0 };
+    let formatted = if preview { 0 } else { result.changed };
 
     reporter.report(ReportEvent::BatchSummary {
         total: result.total,
@@ -118,9 +289,29 @@ pub fn execute_batch(
         unchanged: result.success - result.changed,
         would_change,
         failed: result.failed,
+        skipped: result.skipped.len(),
         duration: result.duration,
     })?;
 
+    if config.common.output.is_verbose()
+        && let Some(max) = config.max_file_size
+    {
+        for path in &oversized {
+            reporter.report(ReportEvent::Warning {
+                message: &format!("skipped {}: larger than {max} bytes", path.display()),
+            })?;
+        }
+    }
+
+    // Encoding fallbacks are informational; only shown in verbose mode
+    if config.common.output.is_verbose() {
+        for (_, warning) in &result.warnings {
+            reporter.report(ReportEvent::Warning {
+                message: &warning.to_string(),
+            })?;
+        }
+    }
+
     // Report errors
     for (path, error) in &result.errors {
         reporter.report(ReportEvent::Error {
@@ -129,11 +320,161 @@ pub fn execute_batch(
         })?;
     }
 
-    // Return appropriate exit code
+    if stopped_early > 0 {
+        reporter.report(ReportEvent::Warning {
+            message: &format!(
+                "stopped after {} failed files (--max-errors); {stopped_early} files not processed",
+                result.failed
+            ),
+        })?;
+    }
+
+    Ok(exit_code(config, &result))
+}
+
+/// Reports one file of an in-place run as `[current/total] path … outcome`.
+fn report_file_result(
+    reporter: &Reporter,
+    current: usize,
+    total: usize,
+    file: &FileResult,
+) -> std::io::Result<()> {
+    use fast_yaml_parallel::FileOutcome as Outcome;
+
+    let message;
+    let outcome = match &file.outcome {
+        Outcome::Changed { .. } => FileOutcome::Formatted,
+        Outcome::Success { .. } | Outcome::Unchanged { .. } => FileOutcome::Unchanged,
+        Outcome::Error { error, .. } => {
+            message = error.to_string();
+            FileOutcome::Failed { message: &message }
+        }
+        Outcome::Skipped => FileOutcome::Skipped,
+    };
+    reporter.report(ReportEvent::FileResult {
+        current,
+        total,
+        path: &file.path,
+        outcome,
+        duration: file.outcome.duration(),
+        read_strategy: file.read_strategy,
+    })
+}
+
+/// Creates a progress bar for `total` files, drawn on stderr when it is a terminal:
+/// `[##########] 142/500 files (28.4%) – 1,234 files/sec`
+#[cfg(feature = "progress")]
+fn progress_bar(total: usize) -> ProgressBar {
+    let style = ProgressStyle::with_template("[{bar:10}] {pos}/{len} files ({percent1}) – {rate}")
+        .unwrap_or_else(|_| ProgressStyle::default_bar())
+        .progress_chars("# ")
+        .with_key(
+            "percent1",
+            |state: &ProgressState, w: &mut dyn fmt::Write| {
+                let _ = write!(w, "{:.1}%", state.fraction() * 100.0);
+            },
+        )
+        .with_key("rate", |state: &ProgressState, w: &mut dyn fmt::Write| {
+            let _ = write!(w, "{} files/sec", group_thousands(state.per_sec()));
+        });
+    ProgressBar::new(total as u64).with_style(style)
+}
+
+/// Returns a progress callback that moves `bar` to the number of completed files.
+#[cfg(feature = "progress")]
+fn progress_callback(bar: ProgressBar) -> ProgressCallback {
+    Arc::new(move |done, _total| bar.set_position(done as u64))
+}
+
+/// Formats a non-negative rate rounded to a whole number with `,` thousands separators.
+#[cfg(feature = "progress")]
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn group_thousands(value: f64) -> String {
+    let digits = (value.max(0.0).round() as u64).to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Renders the `--timing` table of the `top` slowest files, in milliseconds.
+fn timing_table(result: &ParallelBatchResult, top: usize) -> String {
+    use std::fmt::Write as _;
+
+    const HEADERS: [&str; 6] = [
+        "File",
+        "Read Time",
+        "Parse Time",
+        "Format Time",
+        "Write Time",
+        "Total",
+    ];
+
+    let ms = |duration: std::time::Duration| format!("{:.2}ms", duration.as_secs_f64() * 1000.0);
+    let rows: Vec<[String; 6]> = result
+        .slowest(top)
+        .into_iter()
+        .map(|(path, times)| {
+            [
+                path.display().to_string(),
+                ms(times.read),
+                ms(times.parse),
+                ms(times.format),
+                ms(times.write),
+                ms(times.total()),
+            ]
+        })
+        .collect();
+
+    let mut widths = HEADERS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    let _ = writeln!(
+        table,
+        "Slowest {} of {} files:",
+        rows.len(),
+        result.timings.len()
+    );
+    let header = HEADERS.map(str::to_string);
+    for row in std::iter::once(&header).chain(&rows) {
+        let mut line = format!("{:<w$}", row[0], w = widths[0]);
+        for (cell, width) in row.iter().zip(widths).skip(1) {
+            let _ = write!(line, "  {cell:>width$}");
+        }
+        let _ = writeln!(table, "{line}");
+    }
+    table
+}
+
+/// Splits `paths` into files to process and files larger than `max_bytes`.
+///
+/// Files whose size cannot be read are kept so that processing reports the error.
+fn split_oversized(paths: Vec<PathBuf>, max_bytes: Option<u64>) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    let Some(max_bytes) = max_bytes else {
+        return (paths, Vec::new());
+    };
+    paths.into_iter().partition(|path| {
+        std::fs::metadata(path).map_or(true, |metadata| metadata.len() <= max_bytes)
+    })
+}
+
+/// Returns the appropriate exit code for a batch result
+const fn exit_code(config: &BatchConfig, result: &ParallelBatchResult) -> ExitCode {
     if result.failed > 0 {
-        Ok(ExitCode::ParseError)
+        ExitCode::ParseError
+    } else if config.diff && result.changed > 0 {
+        ExitCode::ChangesFound
     } else {
-        Ok(ExitCode::Success)
+        ExitCode::Success
     }
 }
 
@@ -168,3 +509,135 @@ fn convert_format_results_to_batch_result(
     batch.duration = start.elapsed();
     batch
 }
+
+/// Convert `format_files` results to `BatchResult` and the text printed to stdout.
+///
+/// Outputs are joined in input order, with `separator` between them and an
+/// optional `# === <file> ===` header before each. Failed files are left out
+/// and reported as errors.
+fn join_format_results(
+    results: Vec<(PathBuf, Result<String, fast_yaml_parallel::Error>)>,
+    separator: &str,
+    header: bool,
+) -> (ParallelBatchResult, String) {
+    use fast_yaml_parallel::{FileOutcome, FileResult};
+    use std::fmt::Write as _;
+    use std::time::{Duration, Instant};
+
+    let start = Instant::now();
+    let mut file_results = Vec::with_capacity(results.len());
+    let mut output = String::new();
+    // Discovered paths are absolute; label files relative to the working directory
+    let root = std::env::current_dir()
+        .and_then(|dir| dir.canonicalize())
+        .ok();
+
+    for (path, result) in results {
+        let outcome = match result {
+            Ok(formatted) => {
+                if !output.is_empty() {
+                    ensure_newline(&mut output);
+                    if !separator.is_empty() {
+                        output.push_str(separator);
+                        ensure_newline(&mut output);
+                    }
+                }
+                if header {
+                    let label = root
+                        .as_ref()
+                        .and_then(|root| path.strip_prefix(root).ok())
+                        .unwrap_or(&path);
+                    let _ = writeln!(output, "# === {} ===", label.display());
+                }
+                output.push_str(&formatted);
+                FileOutcome::Changed {
+                    duration: Duration::ZERO,
+                }
+            }
+            Err(error) => FileOutcome::Error {
+                error,
+                duration: Duration::ZERO,
+            },
+        };
+        file_results.push(FileResult::new(path, outcome));
+    }
+
+    let mut batch = ParallelBatchResult::from_results(file_results);
+    batch.duration = start.elapsed();
+    (batch, output)
+}
+
+/// Appends a newline unless `output` is empty or already ends with one.
+fn ensure_newline(output: &mut String) {
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+}
+
+/// Expands the `\n`, `\t` and `\\` escapes in a command-line separator.
+///
+/// Other backslashes are kept as written.
+fn expand_escapes(input: &str) -> String {
+    let mut expanded = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            expanded.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => expanded.push('\n'),
+            Some('t') => expanded.push('\t'),
+            Some('\\') | None => expanded.push('\\'),
+            Some(other) => {
+                expanded.push('\\');
+                expanded.push(other);
+            }
+        }
+    }
+    expanded
+}
+
+/// Convert `format_files_with_original` results to `BatchResult` and unified
+/// diffs for `--diff` mode.
+///
+/// Files whose formatted content matches the original are reported as unchanged
+/// and produce no diff.
+fn diff_format_results(
+    results: Vec<(PathBuf, fast_yaml_parallel::Result<(String, String)>)>,
+) -> (ParallelBatchResult, Vec<String>) {
+    use fast_yaml_parallel::{FileOutcome, FileResult};
+    use std::time::{Duration, Instant};
+
+    let start = Instant::now();
+    let mut file_results = Vec::with_capacity(results.len());
+    let mut diffs = Vec::new();
+
+    for (path, result) in results {
+        let outcome = match result {
+            Ok((original, formatted)) => {
+                let diff = unified_diff(&original, &formatted, &path.display().to_string());
+                let changed = diff.is_some();
+                diffs.extend(diff);
+                if changed {
+                    FileOutcome::Changed {
+                        duration: Duration::ZERO,
+                    }
+                } else {
+                    FileOutcome::Unchanged {
+                        duration: Duration::ZERO,
+                    }
+                }
+            }
+            Err(error) => FileOutcome::Error {
+                error,
+                duration: Duration::ZERO,
+            },
+        };
+        file_results.push(FileResult::new(path, outcome));
+    }
+
+    let mut batch = ParallelBatchResult::from_results(file_results);
+    batch.duration = start.elapsed();
+    (batch, diffs)
+}
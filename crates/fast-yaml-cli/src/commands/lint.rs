@@ -1,7 +1,11 @@
 use anyhow::{Context, Result};
+use fast_yaml_linter::rules::default_rules;
 use fast_yaml_linter::{
-    ConfigFile, Formatter, JsonFormatter, LintConfig, Linter, Severity, TextFormatter,
+    ConfigFile, Diagnostic, DiagnosticBuilder, Formatter, GithubFormatter, JsonFormatter,
+    LintConfig, Linter, Location, Severity, Span, TextFormatter,
 };
+use saphyr_parser::{Event, Parser as EventParser};
+use std::collections::{BTreeMap, HashSet};
 use std::path::PathBuf;
 
 use crate::cli::LintFormat;
@@ -31,6 +35,20 @@ pub struct LintCommand {
     /// Resolved lint configuration (exposed for batch reuse).
     pub lint_config: LintConfig,
     format: LintFormat,
+    report_unused_anchors: bool,
+    fail_on_warning: bool,
+}
+
+/// Returns the lowest severity that makes `fy lint` exit with code 2.
+///
+/// Only errors fail a run unless `--fail-on-warning` is set, in which case
+/// warnings do too. Info and hint diagnostics never fail a run.
+pub const fn failing_severity(fail_on_warning: bool) -> Severity {
+    if fail_on_warning {
+        Severity::Warning
+    } else {
+        Severity::Error
+    }
 }
 
 impl LintCommand {
@@ -51,9 +69,25 @@ impl LintCommand {
             config,
             lint_config,
             format: args.format,
+            report_unused_anchors: false,
+            fail_on_warning: false,
         })
     }
 
+    /// Also report anchors that are never aliased (`--report-unused-anchors`).
+    #[must_use]
+    pub const fn with_report_unused_anchors(mut self, enabled: bool) -> Self {
+        self.report_unused_anchors = enabled;
+        self
+    }
+
+    /// Exit with code 2 on warnings as well as errors (`--fail-on-warning`).
+    #[must_use]
+    pub const fn with_fail_on_warning(mut self, enabled: bool) -> Self {
+        self.fail_on_warning = enabled;
+        self
+    }
+
     /// Load `LintConfig` from config file (explicit path, auto-discovered, or default).
     fn load_lint_config(
         config_path: Option<PathBuf>,
@@ -109,7 +143,14 @@ impl LintCommand {
         };
 
         let linter = Linter::with_config(lint_config);
-        let diagnostics = linter.lint(input.as_str()).context("Failed to lint YAML")?;
+        let mut diagnostics = linter.lint(input.as_str()).context("Failed to lint YAML")?;
+        if self.report_unused_anchors {
+            UnusedAnchorChecker::extend(&mut diagnostics, input.as_str());
+        }
+
+        // Decided before quiet mode drops warnings from the output
+        let threshold = failing_severity(self.fail_on_warning);
+        let has_violations = diagnostics.iter().any(|d| d.severity >= threshold);
 
         let filtered_diagnostics: Vec<_> = if self.config.output.is_quiet() {
             diagnostics
@@ -127,7 +168,17 @@ impl LintCommand {
                 formatter.format(&filtered_diagnostics, input.as_str())
             }
             LintFormat::Json => {
-                let formatter = JsonFormatter::new(true);
+                let file = input
+                    .file_path()
+                    .map_or_else(|| "<stdin>".to_string(), |p| p.display().to_string());
+                let formatter = JsonFormatter::new(true).with_file(file);
+                formatter.format(&filtered_diagnostics, input.as_str())
+            }
+            LintFormat::Github => {
+                let mut formatter = GithubFormatter::new();
+                if let Some(path) = input.file_path() {
+                    formatter = formatter.with_file(path.display().to_string());
+                }
                 formatter.format(&filtered_diagnostics, input.as_str())
             }
         };
@@ -142,11 +193,7 @@ impl LintCommand {
             eprintln!("Lint time: {:.2}ms", elapsed.as_secs_f64() * 1000.0);
         }
 
-        let has_errors = filtered_diagnostics
-            .iter()
-            .any(|d| d.severity == Severity::Error);
-
-        if has_errors {
+        if has_violations {
             Ok(ExitCode::LintErrors)
         } else {
             Ok(ExitCode::Success)
@@ -154,6 +201,183 @@ impl LintCommand {
     }
 }
 
+/// Reports anchors that are defined but never aliased (`--report-unused-anchors`).
+///
+/// This runs outside the lint rule registry: rules see the parsed document, in
+/// which aliases are already resolved, whereas this check needs the event
+/// stream to match each anchored node to the `Alias` events that refer to it.
+/// Anchor ids are unique per definition, so a redefined anchor name is tracked
+/// separately for each definition.
+pub struct UnusedAnchorChecker;
+
+impl UnusedAnchorChecker {
+    /// Diagnostic code for unused anchors.
+    pub const CODE: &'static str = "unused-anchor";
+
+    /// Returns a warning for each anchor in `source` that no alias refers to.
+    ///
+    /// Returns no diagnostics if `source` is not valid YAML, since aliases after
+    /// the syntax error cannot be seen; the linter reports the error itself.
+    pub fn check(source: &str) -> Vec<Diagnostic> {
+        // anchor id -> byte offset of the anchored node
+        let mut defined = BTreeMap::new();
+        let mut used = HashSet::new();
+
+        for result in EventParser::new_from_str(source) {
+            let Ok((event, span)) = result else {
+                return Vec::new();
+            };
+            match event {
+                Event::MappingStart(id, _)
+                | Event::SequenceStart(id, _)
+                | Event::Scalar(_, _, id, _)
+                    if id > 0 =>
+                {
+                    defined.insert(id, byte_offset(source, span.start.line(), span.start.col()));
+                }
+                Event::Alias(id) => {
+                    used.insert(id);
+                }
+                _ => {}
+            }
+        }
+
+        defined
+            .into_iter()
+            .filter(|(id, _)| !used.contains(id))
+            .filter_map(|(_, node)| anchor_before(source, node))
+            .map(|(offset, name)| {
+                let (line, column) = line_and_column(source, offset);
+                let len = name.len() + 1;
+                DiagnosticBuilder::new(
+                    Self::CODE,
+                    Severity::Warning,
+                    format!("anchor '{name}' defined at line {line} is never used"),
+                    Span::new(
+                        Location::new(line, column, offset),
+                        Location::new(line, column + len, offset + len),
+                    ),
+                )
+                .build(source)
+            })
+            .collect()
+    }
+
+    /// Adds unused anchor warnings to `diagnostics`, keeping them in source order.
+    pub fn extend(diagnostics: &mut Vec<Diagnostic>, source: &str) {
+        diagnostics.extend(Self::check(source));
+        diagnostics.sort_by_key(|d| d.span.start);
+    }
+}
+
+/// Converts a 1-based line and 0-based character column to a byte offset.
+fn byte_offset(source: &str, line: usize, col: usize) -> usize {
+    let line_start = if line <= 1 {
+        0
+    } else {
+        source
+            .match_indices('\n')
+            .nth(line - 2)
+            .map_or(source.len(), |(i, _)| i + 1)
+    };
+    source[line_start..]
+        .char_indices()
+        .nth(col)
+        .map_or(source.len(), |(i, _)| line_start + i)
+}
+
+/// Returns the 1-based line and byte column of `offset`.
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (before.matches('\n').count() + 1, offset - line_start + 1)
+}
+
+/// Finds the `&name` that precedes an anchored node starting at byte `node`.
+///
+/// Only whitespace, a tag and comments can separate an anchor from its node,
+/// so the nearest `&` outside a comment is the anchor. The node's own line
+/// cannot hold a comment before the node and is searched as-is.
+fn anchor_before(source: &str, node: usize) -> Option<(usize, &str)> {
+    let mut end = node;
+    let mut first = true;
+    loop {
+        let start = source[..end].rfind('\n').map_or(0, |i| i + 1);
+        let line = &source[start..end];
+        let code = if first { line } else { strip_comment(line) };
+        if let Some(amp) = code.rfind('&') {
+            let name = &code[amp + 1..];
+            let len = name
+                .find(|c: char| c.is_whitespace() || ",[]{}".contains(c))
+                .unwrap_or(name.len());
+            return Some((start + amp, &name[..len]));
+        }
+        if start == 0 {
+            return None;
+        }
+        end = start - 1;
+        first = false;
+    }
+}
+
+/// Removes a trailing `# comment` from `line`, ignoring `#` inside quotes.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut prev = ' ';
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if c == q => quote = None,
+            (None, '#') if prev.is_whitespace() => return &line[..i],
+            _ => {}
+        }
+        prev = c;
+    }
+    line
+}
+
+/// Render the documentation for the rule with the given code (`--rule-help`).
+///
+/// # Errors
+///
+/// Returns an error if no rule has the given code.
+pub fn rule_help(code: &str) -> Result<String> {
+    let rule = default_rules()
+        .iter()
+        .find(|rule| rule.code() == code)
+        .with_context(|| {
+            format!("unknown rule '{code}' (use `fy lint --list-rules` to see available rules)")
+        })?;
+    Ok(format!("{}\n", rule.help()))
+}
+
+/// Render the list of available rules (`--list-rules`).
+///
+/// Verbose output adds the default severity and a one-line description.
+pub fn list_rules(verbose: bool) -> String {
+    let width = default_rules()
+        .iter()
+        .map(|rule| rule.code().len())
+        .max()
+        .unwrap_or(0);
+
+    default_rules()
+        .iter()
+        .map(|rule| {
+            if verbose {
+                format!(
+                    "{:<width$}  {:<7}  {}\n",
+                    rule.code(),
+                    rule.default_severity().as_str(),
+                    rule.description()
+                )
+            } else {
+                format!("{}\n", rule.code())
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,6 +446,28 @@ mod tests {
         assert_eq!(result.unwrap(), ExitCode::Success);
     }
 
+    #[test]
+    fn test_lint_with_warnings_fail_on_warning() {
+        // colons: too many spaces after colon (warning)
+        let input = stdin_input("name:   test\n");
+        for quiet in [false, true] {
+            let config = create_test_config(quiet, false, false, 2);
+            let cmd = build_no_config(config.clone(), Some(120), LintFormat::Text, None, &input);
+            assert_eq!(cmd.execute(&input).unwrap(), ExitCode::Success);
+
+            let cmd = build_no_config(config, Some(120), LintFormat::Text, None, &input)
+                .with_fail_on_warning(true);
+            assert_eq!(cmd.execute(&input).unwrap(), ExitCode::LintErrors);
+        }
+    }
+
+    #[test]
+    fn test_failing_severity() {
+        assert_eq!(failing_severity(false), Severity::Error);
+        assert_eq!(failing_severity(true), Severity::Warning);
+        assert!(Severity::Info < failing_severity(true));
+    }
+
     #[test]
     fn test_lint_invalid_yaml() {
         let input = stdin_input("invalid: [unclosed");
@@ -407,4 +653,97 @@ mod tests {
         .unwrap();
         assert!(!cmd.lint_config.allow_duplicate_keys);
     }
+
+    fn unused_anchors(source: &str) -> Vec<(String, usize, usize)> {
+        UnusedAnchorChecker::check(source)
+            .into_iter()
+            .map(|d| (d.message, d.span.start.line, d.span.start.column))
+            .collect()
+    }
+
+    #[test]
+    fn test_unused_anchor_reported_at_definition() {
+        let found = unused_anchors("base: &base\n  a: 1\nlist: &lst [1, 2]\nuse: *base\n");
+        assert_eq!(
+            found,
+            [(
+                "anchor 'lst' defined at line 3 is never used".to_string(),
+                3,
+                7
+            )]
+        );
+    }
+
+    #[test]
+    fn test_unused_anchor_all_used() {
+        assert!(unused_anchors("a: &x 1\nb: *x\nc: &y [1]\nd: *y\n").is_empty());
+        assert!(unused_anchors("plain: value\n").is_empty());
+    }
+
+    #[test]
+    fn test_unused_anchor_redefinition_tracked_per_definition() {
+        // The alias refers to the second `&v`, so only the first is unused
+        let found = unused_anchors("a: &v 1\nb: &v 2\nc: *v\n");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, 1);
+    }
+
+    #[test]
+    fn test_unused_anchor_with_tag_and_comment() {
+        let found = unused_anchors("a: !!str &t x\n\"k #1\": &q # see & notes\n  b: 2\nc: &e\n");
+        let names: Vec<_> = found.iter().map(|(m, _, _)| m.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "anchor 't' defined at line 1 is never used",
+                "anchor 'q' defined at line 2 is never used",
+                "anchor 'e' defined at line 4 is never used",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unused_anchor_per_document() {
+        let found = unused_anchors("a: &x 1\nb: *x\n---\nc: &x 2\n");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, 4);
+    }
+
+    #[test]
+    fn test_unused_anchor_invalid_yaml_reports_nothing() {
+        assert!(unused_anchors("a: &x 1\nb: [unclosed\n").is_empty());
+    }
+
+    #[test]
+    fn test_unused_anchor_non_ascii_column() {
+        let found = unused_anchors("ключ: &k значение\n");
+        assert_eq!(found[0].2, "ключ: ".len() + 1);
+    }
+
+    #[test]
+    fn test_rule_help_line_length_includes_max_option() {
+        let help = rule_help("line-length").unwrap();
+        assert!(help.starts_with("line-length (Line Length)"));
+        assert!(help.contains("max (integer, default: 80)"));
+    }
+
+    #[test]
+    fn test_rule_help_unknown_rule() {
+        let err = rule_help("no-such-rule").unwrap_err();
+        assert!(err.to_string().contains("unknown rule 'no-such-rule'"));
+    }
+
+    #[test]
+    fn test_list_rules() {
+        let brief = list_rules(false);
+        assert!(brief.lines().any(|line| line == "duplicate-key"));
+
+        let verbose = list_rules(true);
+        let line = verbose
+            .lines()
+            .find(|line| line.starts_with("line-length "))
+            .unwrap();
+        assert!(line.contains("info"));
+        assert!(line.contains("maximum length"));
+    }
 }
@@ -1,12 +1,19 @@
 //! Batch lint command execution.
 
-use std::path::PathBuf;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use anyhow::{Context, Result};
-use fast_yaml_linter::{Diagnostic, Formatter, LintConfig, Linter, Severity, TextFormatter};
+use anyhow::{Context, Result, bail};
+use fast_yaml_linter::{
+    Baseline, Diagnostic, Formatter, GithubFormatter, JsonFormatter, LintConfig, Linter, Severity,
+    TextFormatter,
+};
+use fast_yaml_parallel::escape_xml;
 use rayon::prelude::*;
 
-use crate::cli::LintFormat;
+use crate::cli::{LintFormat, ReportFormat};
+use crate::commands::lint::{UnusedAnchorChecker, failing_severity};
 use crate::config::CommonConfig;
 use crate::discovery::{DiscoveryConfig, FileDiscovery};
 use crate::error::ExitCode;
@@ -22,6 +29,16 @@ pub struct LintBatchConfig {
     pub lint_config: LintConfig,
     /// Lint output format
     pub format: LintFormat,
+    /// Report format for batch results
+    pub reporter: ReportFormat,
+    /// Baseline file of known violations to suppress
+    pub baseline: Option<PathBuf>,
+    /// Rewrite the baseline file from this run's diagnostics
+    pub baseline_update: bool,
+    /// Also report anchors that are never aliased
+    pub report_unused_anchors: bool,
+    /// Exit with code 2 on warnings as well as errors
+    pub fail_on_warning: bool,
 }
 
 impl LintBatchConfig {
@@ -31,6 +48,11 @@ impl LintBatchConfig {
             discovery: DiscoveryConfig::new(),
             lint_config,
             format,
+            reporter: ReportFormat::Text,
+            baseline: None,
+            baseline_update: false,
+            report_unused_anchors: false,
+            fail_on_warning: false,
         }
     }
 
@@ -39,13 +61,44 @@ impl LintBatchConfig {
         self.discovery = discovery;
         self
     }
+
+    #[must_use]
+    pub const fn with_reporter(mut self, reporter: ReportFormat) -> Self {
+        self.reporter = reporter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Option<PathBuf>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_baseline_update(mut self, update: bool) -> Self {
+        self.baseline_update = update;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_report_unused_anchors(mut self, enabled: bool) -> Self {
+        self.report_unused_anchors = enabled;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_fail_on_warning(mut self, enabled: bool) -> Self {
+        self.fail_on_warning = enabled;
+        self
+    }
 }
 
 /// Execute batch linting on multiple files.
 ///
 /// # Errors
 ///
-/// Returns error if file discovery fails.
+/// Returns error if file discovery fails, or if the baseline file cannot be
+/// read or written.
 pub fn execute_lint_batch(config: &LintBatchConfig, paths: &[PathBuf]) -> Result<ExitCode> {
     let discovery = FileDiscovery::new(config.discovery.clone())
         .context("Failed to initialize file discovery")?;
@@ -76,12 +129,14 @@ pub fn execute_lint_batch(config: &LintBatchConfig, paths: &[PathBuf]) -> Result
     let format = config.format.clone();
     let use_color = config.common.output.use_color();
     let is_quiet = config.common.output.is_quiet();
+    let report_unused_anchors = config.report_unused_anchors;
 
     let file_paths: Vec<PathBuf> = files.iter().map(|f| f.path.clone()).collect();
+    let start = Instant::now();
 
-    // Process files in parallel, collecting (path, content, diagnostics, has_errors) tuples.
-    // Read/lint errors are printed to stderr directly; has_errors=true is set in that case.
-    let results: Vec<(PathBuf, String, Vec<Diagnostic>, bool)> = pool.install(|| {
+    // Process files in parallel, collecting (path, content, diagnostics, failed) tuples.
+    // Read/lint errors are printed to stderr directly; failed=true is set in that case.
+    let mut results: Vec<(PathBuf, String, Vec<Diagnostic>, bool)> = pool.install(|| {
         file_paths
             .par_iter()
             .map(|path| {
@@ -94,30 +149,59 @@ pub fn execute_lint_batch(config: &LintBatchConfig, paths: &[PathBuf]) -> Result
                 };
 
                 let linter = Linter::with_config(lint_config.clone());
-                let diagnostics = match linter.lint(&content) {
-                    Ok(d) => d,
+                match linter.lint(&content) {
+                    Ok(mut diagnostics) => {
+                        if report_unused_anchors {
+                            UnusedAnchorChecker::extend(&mut diagnostics, &content);
+                        }
+                        (path.clone(), content, diagnostics, false)
+                    }
                     Err(e) => {
                         eprintln!("error: '{}': {e}", path.display());
-                        return (path.clone(), content, vec![], true);
+                        (path.clone(), content, vec![], true)
                     }
-                };
-
-                let filtered: Vec<_> = if is_quiet {
-                    diagnostics
-                        .into_iter()
-                        .filter(|d| d.severity == Severity::Error)
-                        .collect()
-                } else {
-                    diagnostics
-                };
-
-                let has_errors = filtered.iter().any(|d| d.severity == Severity::Error);
-                (path.clone(), content, filtered, has_errors)
+                }
             })
             .collect()
     });
 
-    let any_errors = results.iter().any(|(_, _, _, has_errors)| *has_errors);
+    if let Some(baseline_path) = &config.baseline {
+        apply_baseline(
+            baseline_path,
+            config.baseline_update,
+            is_quiet,
+            &mut results,
+        )?;
+    }
+
+    let any_failed = results.iter().any(|(_, _, _, failed)| *failed);
+
+    // From here on the flag means "fails the run": lint failures or diagnostics
+    // at or above the failing severity. It is decided before quiet mode drops
+    // warnings from the output.
+    let threshold = failing_severity(config.fail_on_warning);
+    for (_, _, diagnostics, fails) in &mut results {
+        *fails |= diagnostics.iter().any(|d| d.severity >= threshold);
+        if is_quiet {
+            diagnostics.retain(|d| d.severity == Severity::Error);
+        }
+    }
+
+    let exit_code = if any_failed {
+        ExitCode::ParseError
+    } else if results.iter().any(|(_, _, _, fails)| *fails) {
+        ExitCode::LintErrors
+    } else {
+        ExitCode::Success
+    };
+
+    if config.reporter == ReportFormat::Junit {
+        print!(
+            "{}",
+            lint_results_to_junit_xml(&results, threshold, start.elapsed())
+        );
+        return Ok(exit_code);
+    }
 
     match format {
         LintFormat::Text => {
@@ -139,24 +223,153 @@ pub fn execute_lint_batch(config: &LintBatchConfig, paths: &[PathBuf]) -> Result
             let all: Vec<serde_json::Value> = results
                 .iter()
                 .flat_map(|(path, _, diagnostics, _)| {
-                    let file = path.display().to_string();
-                    diagnostics.iter().map(move |d| {
-                        let mut v = serde_json::to_value(d).unwrap_or(serde_json::Value::Null);
-                        if let serde_json::Value::Object(ref mut map) = v {
-                            map.insert("file".to_string(), serde_json::Value::String(file.clone()));
-                        }
-                        v
-                    })
+                    let formatter = JsonFormatter::new(true).with_file(path.display().to_string());
+                    diagnostics.iter().map(move |d| formatter.to_value(d))
                 })
                 .collect();
             let json = serde_json::to_string_pretty(&all).unwrap_or_else(|_| "[]".to_string());
             println!("{json}");
         }
+        LintFormat::Github => {
+            for (path, content, diagnostics, _) in &results {
+                let formatter = GithubFormatter::new().with_file(path.display().to_string());
+                print!("{}", formatter.format(diagnostics, content));
+            }
+        }
     }
 
-    if any_errors {
-        Ok(ExitCode::LintErrors)
+    Ok(exit_code)
+}
+
+/// Suppresses the diagnostics recorded in the baseline file.
+///
+/// With `update`, the baseline is first rewritten from this run's diagnostics
+/// (creating the file if needed). Files that failed to lint keep their entries.
+/// File paths are recorded relative to the working directory so that the
+/// baseline can be committed and shared.
+fn apply_baseline(
+    path: &Path,
+    update: bool,
+    is_quiet: bool,
+    results: &mut [(PathBuf, String, Vec<Diagnostic>, bool)],
+) -> Result<()> {
+    let mut baseline = if path.exists() {
+        Baseline::load(path)?
+    } else if update {
+        Baseline::new()
     } else {
-        Ok(ExitCode::Success)
+        bail!(
+            "baseline file '{}' not found; create it with --baseline-update",
+            path.display()
+        );
+    };
+
+    let root = std::env::current_dir()
+        .and_then(|dir| dir.canonicalize())
+        .ok();
+    let relative = |file: &'_ Path| -> PathBuf {
+        root.as_ref()
+            .and_then(|root| file.strip_prefix(root).ok())
+            .unwrap_or(file)
+            .to_path_buf()
+    };
+
+    if update {
+        let (mut removed, mut added) = (0, 0);
+        for (file, _, diagnostics, failed) in results.iter() {
+            if *failed {
+                continue;
+            }
+            let (updated, r, a) = baseline.update(&relative(file), diagnostics);
+            baseline = updated;
+            removed += r;
+            added += a;
+        }
+        baseline.save(path)?;
+        if !is_quiet {
+            eprintln!(
+                "Baseline updated: {removed} removed, {added} added ({} total) in {}",
+                baseline.len(),
+                path.display()
+            );
+        }
+    }
+
+    for (file, _, diagnostics, _) in results.iter_mut() {
+        *diagnostics = baseline.filter(&relative(file), std::mem::take(diagnostics));
     }
+    Ok(())
+}
+
+/// Renders batch lint results as a `JUnit` XML report.
+///
+/// Each linted file becomes a `<testcase>`; files with diagnostics at or above
+/// `threshold` (or that could not be read) carry a `<failure>` listing those
+/// diagnostics.
+fn lint_results_to_junit_xml(
+    results: &[(PathBuf, String, Vec<Diagnostic>, bool)],
+    threshold: Severity,
+    duration: Duration,
+) -> String {
+    let failed = results.iter().filter(|(_, _, _, fails)| *fails).count();
+    let time = duration.as_secs_f64();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        xml,
+        r#"<testsuites name="fy lint" tests="{}" failures="{failed}" errors="0" time="{time:.3}">"#,
+        results.len()
+    );
+    let _ = writeln!(
+        xml,
+        r#"  <testsuite name="fy lint" tests="{}" failures="{failed}" errors="0" skipped="0" time="{time:.3}">"#,
+        results.len()
+    );
+
+    for (path, _, diagnostics, fails) in results {
+        let name = escape_xml(&path.display().to_string());
+        if !fails {
+            let _ = writeln!(xml, r#"    <testcase name="{name}" classname="fy lint"/>"#);
+            continue;
+        }
+
+        let errors: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.severity >= threshold)
+            .collect();
+        let kind = if threshold == Severity::Error {
+            "error(s)"
+        } else {
+            "error(s) or warning(s)"
+        };
+        let message = errors.first().map_or_else(
+            || "failed to lint file".to_string(),
+            |d| format!("{} {kind}, first: {}", errors.len(), d.message),
+        );
+        let details = errors
+            .iter()
+            .map(|d| {
+                format!(
+                    "{}:{} [{}] {}",
+                    d.span.start.line,
+                    d.span.start.column,
+                    d.code.as_str(),
+                    d.message
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let _ = writeln!(xml, r#"    <testcase name="{name}" classname="fy lint">"#);
+        let _ = writeln!(
+            xml,
+            r#"      <failure message="{}">{}</failure>"#,
+            escape_xml(&message),
+            escape_xml(&details)
+        );
+        xml.push_str("    </testcase>\n");
+    }
+
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
 }
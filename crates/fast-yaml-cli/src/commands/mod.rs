@@ -1,4 +1,6 @@
+pub mod check;
 pub mod convert;
+pub mod diff;
 pub mod format;
 pub mod format_batch;
 pub mod parse;
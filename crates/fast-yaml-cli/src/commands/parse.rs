@@ -1,29 +1,92 @@
 #![allow(clippy::needless_pass_by_ref_mut)]
 
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
 use anyhow::{Context, Result};
 use fast_yaml_core::Parser;
+use saphyr_parser::{Event, Parser as EventParser, ScalarStyle, Tag};
 
+use crate::commands::convert::value_to_json;
 use crate::config::CommonConfig;
+use crate::error::ExitCode;
 use crate::io::InputSource;
 use crate::reporter::{ReportEvent, Reporter};
 
 /// Parse command implementation
 pub struct ParseCommand {
     show_stats: bool,
+    show_ast: bool,
+    max_depth: usize,
+    max_nodes: usize,
+    schema: Option<PathBuf>,
     config: CommonConfig,
 }
 
 impl ParseCommand {
     pub const fn new(config: CommonConfig, show_stats: bool) -> Self {
-        Self { show_stats, config }
+        Self {
+            show_stats,
+            show_ast: false,
+            max_depth: Parser::DEFAULT_MAX_DEPTH,
+            max_nodes: Parser::DEFAULT_MAX_NODES,
+            schema: None,
+            config,
+        }
+    }
+
+    /// Print the parser event stream instead of validating
+    #[must_use]
+    pub const fn with_ast(mut self, show_ast: bool) -> Self {
+        self.show_ast = show_ast;
+        self
+    }
+
+    /// Reject input that nests deeper than `max_depth` or expands to more than `max_nodes` nodes
+    #[must_use]
+    pub const fn with_limits(mut self, max_depth: usize, max_nodes: usize) -> Self {
+        self.max_depth = max_depth;
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Validate the document against the JSON Schema in this file
+    #[must_use]
+    pub fn with_schema(mut self, schema: Option<PathBuf>) -> Self {
+        self.schema = schema;
+        self
     }
 
     /// Execute parse command
-    pub fn execute(&self, input: &InputSource) -> Result<()> {
+    ///
+    /// Returns `ExitCode::SchemaViolations` (exit code 2) if the document does not
+    /// match the `--schema` JSON Schema.
+    pub fn execute(&self, input: &InputSource) -> Result<ExitCode> {
+        if self.show_ast {
+            print!("{}", event_tree(input.as_str())?);
+            return Ok(ExitCode::Success);
+        }
+
         let mut reporter = Reporter::new(self.config.output.clone());
         reporter.start_timing();
 
-        let maybe_value = Parser::parse_str(input.as_str()).context("Failed to parse YAML")?;
+        let maybe_value = Parser::parse_with_limit(input.as_str(), self.max_depth, self.max_nodes)
+            .context("Failed to parse YAML")?;
+
+        if let Some(schema_path) = &self.schema {
+            let violations = validate_schema(maybe_value.as_ref(), schema_path)?;
+            if !violations.is_empty() {
+                for message in &violations {
+                    reporter
+                        .report(ReportEvent::Error {
+                            path: input.file_path(),
+                            message,
+                        })
+                        .ok();
+                }
+                return Ok(ExitCode::SchemaViolations);
+            }
+        }
 
         reporter
             .report(ReportEvent::Success {
@@ -46,7 +109,7 @@ impl ParseCommand {
                 .ok();
         }
 
-        Ok(())
+        Ok(ExitCode::Success)
     }
 
     /// Print parsing statistics
@@ -73,6 +136,132 @@ impl ParseCommand {
     }
 }
 
+/// Validate a parsed document against the JSON Schema in `schema_path`.
+///
+/// The document is converted to JSON first (an empty document is `null`).
+/// Returns one message per violation, formatted as
+/// `<JSON pointer>: <message> (<keyword>)`; empty if the document is valid.
+fn validate_schema(
+    value: Option<&fast_yaml_core::Value>,
+    schema_path: &Path,
+) -> Result<Vec<String>> {
+    let schema_text = std::fs::read_to_string(schema_path)
+        .with_context(|| format!("Failed to read schema file '{}'", schema_path.display()))?;
+    let schema: serde_json::Value = serde_json::from_str(&schema_text).with_context(|| {
+        format!(
+            "Failed to parse schema file '{}' as JSON",
+            schema_path.display()
+        )
+    })?;
+    let validator = jsonschema::validator_for(&schema)
+        .map_err(|err| anyhow::anyhow!("Invalid JSON Schema '{}': {err}", schema_path.display()))?;
+
+    let instance = value
+        .map(value_to_json)
+        .transpose()
+        .context("Failed to convert YAML to JSON for schema validation")?
+        .unwrap_or(serde_json::Value::Null);
+
+    Ok(validator
+        .iter_errors(&instance)
+        .map(|error| {
+            let pointer = error.instance_path().to_string();
+            let pointer = if pointer.is_empty() {
+                "<root>"
+            } else {
+                &pointer
+            };
+            format!("{pointer}: {error} ({})", error.kind().keyword())
+        })
+        .collect())
+}
+
+/// Render the parser event stream as an indented tree, one event per line.
+///
+/// Start events (stream, document, sequence, mapping) increase the indentation
+/// of the events that follow until the matching end event. Each line ends with
+/// the 1-based `line:column` where the event starts.
+pub fn event_tree(input: &str) -> Result<String> {
+    let mut tree = String::new();
+    let mut depth = 0usize;
+
+    for result in EventParser::new_from_str(input) {
+        let (event, span) = result.context("Failed to parse YAML")?;
+
+        let (label, nesting) = match event {
+            Event::StreamStart => ("StreamStart".to_string(), 1),
+            Event::StreamEnd => ("StreamEnd".to_string(), -1),
+            Event::DocumentStart(explicit) => (
+                format!("DocumentStart{}", if explicit { " (explicit)" } else { "" }),
+                1,
+            ),
+            Event::DocumentEnd => ("DocumentEnd".to_string(), -1),
+            Event::SequenceStart(anchor_id, tag) => (
+                format!(
+                    "SequenceStart{}",
+                    node_properties(anchor_id, tag.as_deref())
+                ),
+                1,
+            ),
+            Event::SequenceEnd => ("SequenceEnd".to_string(), -1),
+            Event::MappingStart(anchor_id, tag) => (
+                format!("MappingStart{}", node_properties(anchor_id, tag.as_deref())),
+                1,
+            ),
+            Event::MappingEnd => ("MappingEnd".to_string(), -1),
+            Event::Scalar(value, style, anchor_id, tag) => (
+                format!(
+                    "Scalar {value:?} ({}){}",
+                    scalar_style_name(style),
+                    node_properties(anchor_id, tag.as_deref())
+                ),
+                0,
+            ),
+            Event::Alias(anchor_id) => (format!("Alias *{anchor_id}"), 0),
+            Event::Nothing => continue,
+        };
+
+        if nesting < 0 {
+            depth = depth.saturating_sub(1);
+        }
+        let _ = writeln!(
+            tree,
+            "{:indent$}{label} @{}:{}",
+            "",
+            span.start.line(),
+            span.start.col() + 1,
+            indent = depth * 2
+        );
+        if nesting > 0 {
+            depth += 1;
+        }
+    }
+
+    Ok(tree)
+}
+
+/// Format anchor and tag properties of a node (e.g. ` &1 !<tag:yaml.org,2002:str>`).
+fn node_properties(anchor_id: usize, tag: Option<&Tag>) -> String {
+    let mut props = String::new();
+    if anchor_id > 0 {
+        let _ = write!(props, " &{anchor_id}");
+    }
+    if let Some(tag) = tag {
+        let _ = write!(props, " !<{}{}>", tag.handle, tag.suffix);
+    }
+    props
+}
+
+const fn scalar_style_name(style: ScalarStyle) -> &'static str {
+    match style {
+        ScalarStyle::Plain => "plain",
+        ScalarStyle::SingleQuoted => "single-quoted",
+        ScalarStyle::DoubleQuoted => "double-quoted",
+        ScalarStyle::Literal => "literal",
+        ScalarStyle::Folded => "folded",
+    }
+}
+
 /// Recursively count keys and max depth
 fn count_keys_and_depth(value: &fast_yaml_core::Value, current_depth: usize) -> (usize, usize) {
     use fast_yaml_core::Value;
@@ -195,4 +384,37 @@ mod tests {
         assert_eq!(keys, 3); // parent, child1, child2
         assert!(depth >= 2);
     }
+
+    #[test]
+    fn test_event_tree_nesting() {
+        let tree = event_tree("key: [a, 'b']\n").unwrap();
+        let expected = "\
+StreamStart @1:1
+  DocumentStart @1:1
+    MappingStart @1:1
+      Scalar \"key\" (plain) @1:1
+      SequenceStart @1:6
+        Scalar \"a\" (plain) @1:7
+        Scalar \"b\" (single-quoted) @1:10
+      SequenceEnd @1:13
+    MappingEnd @2:1
+  DocumentEnd @2:1
+StreamEnd @2:1
+";
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn test_event_tree_anchor_tag_alias() {
+        let tree = event_tree("--- !!map\na: &x !!str 1\nb: *x\n").unwrap();
+        assert!(tree.contains("DocumentStart (explicit)"));
+        assert!(tree.contains("MappingStart !<tag:yaml.org,2002:map>"));
+        assert!(tree.contains("Scalar \"1\" (plain) &1 !<tag:yaml.org,2002:str>"));
+        assert!(tree.contains("Alias *1"));
+    }
+
+    #[test]
+    fn test_event_tree_invalid_yaml() {
+        assert!(event_tree("key: [unclosed\n").is_err());
+    }
 }
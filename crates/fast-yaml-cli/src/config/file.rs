@@ -0,0 +1,305 @@
+//! Settings loaded from a `.fy.yaml` project config file or from `--config-file`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+
+use super::{FormatterConfig, GlobalConfig};
+
+/// File names looked up in each directory, in order.
+const CONFIG_FILE_NAMES: [&str; 2] = [".fy.yaml", ".fy.yml"];
+
+/// Depth limit for config file discovery walk-up.
+const MAX_DISCOVERY_DEPTH: usize = 20;
+
+/// Settings for all commands; `no_color`, `quiet`, `verbose` and `workers`
+/// form the [`GlobalConfig`], and the formatter settings are used by
+/// `fy format` and `fy check`.
+///
+/// Every setting is optional; command-line flags take precedence over values
+/// from the file.
+///
+/// ```yaml
+/// no_color: true
+/// verbose: true
+/// workers: 4
+/// indent: 4
+/// width: 100
+/// sort_keys: true
+/// explicit_start: true
+/// explicit_end: true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Disable colored output
+    pub no_color: bool,
+    /// Suppress non-error output
+    pub quiet: bool,
+    /// Enable verbose output
+    pub verbose: bool,
+    /// Worker threads for batch commands (0 = auto-detect)
+    pub workers: Option<usize>,
+    /// Indentation width (2-8 spaces)
+    pub indent: Option<u8>,
+    /// Maximum line width
+    pub width: Option<usize>,
+    /// Sort mapping keys
+    pub sort_keys: Option<bool>,
+    /// Begin every document with `---`
+    pub explicit_start: Option<bool>,
+    /// End every document with `...`
+    pub explicit_end: Option<bool>,
+}
+
+impl Config {
+    /// Loads a config file from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid config.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file '{}'", path.display()))?;
+        Self::from_yaml(&content)
+            .with_context(|| format!("invalid config file '{}'", path.display()))
+    }
+
+    /// Parses a config from YAML source.
+    ///
+    /// An empty document yields the defaults. Unknown keys are rejected so
+    /// that typos do not go unnoticed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on invalid YAML, unknown keys, values of the wrong
+    /// type, an indent outside 2-8, or both `quiet` and `verbose` enabled.
+    pub fn from_yaml(source: &str) -> Result<Self> {
+        let config: Option<Self> = serde_norway::from_str(source)?;
+        let config = config.unwrap_or_default();
+        if let Some(indent) = config.indent
+            && !(FormatterConfig::MIN_INDENT..=FormatterConfig::MAX_INDENT).contains(&indent)
+        {
+            bail!("'indent' must be between 2 and 8, got {indent}");
+        }
+        if config.quiet && config.verbose {
+            bail!("'quiet' and 'verbose' cannot both be enabled");
+        }
+        Ok(config)
+    }
+
+    /// Returns the settings that apply to every command.
+    pub const fn global(&self) -> GlobalConfig {
+        GlobalConfig {
+            no_color: self.no_color,
+            quiet: self.quiet,
+            verbose: self.verbose,
+            workers: self.workers,
+        }
+    }
+
+    /// Applies the file's formatter settings on top of `formatter`.
+    #[must_use]
+    pub fn apply(&self, mut formatter: FormatterConfig) -> FormatterConfig {
+        if let Some(indent) = self.indent {
+            formatter = formatter.with_indent(indent);
+        }
+        if let Some(width) = self.width {
+            formatter = formatter.with_width(width);
+        }
+        if let Some(sort_keys) = self.sort_keys {
+            formatter = formatter.with_sort_keys(sort_keys);
+        }
+        if let Some(explicit_start) = self.explicit_start {
+            formatter = formatter.with_explicit_start(explicit_start);
+        }
+        if let Some(explicit_end) = self.explicit_end {
+            formatter = formatter.with_explicit_end(explicit_end);
+        }
+        formatter
+    }
+}
+
+/// Loads the config file given with `--config-file`, or else the nearest
+/// `.fy.yaml` or `.fy.yml` above the current directory.
+///
+/// Returns the path of the file together with its settings, or `None` if no
+/// path was given and no config file was found.
+///
+/// # Errors
+///
+/// Returns an error if the config file cannot be loaded.
+pub fn resolve_config(explicit: Option<&Path>) -> Result<Option<(PathBuf, Config)>> {
+    match explicit {
+        Some(path) => Ok(Some((path.to_owned(), Config::load(path)?))),
+        None => load_config(&std::env::current_dir()?),
+    }
+}
+
+/// Finds and loads the nearest `.fy.yaml` or `.fy.yml`, walking up from
+/// `start_dir`.
+///
+/// Returns the path of the file together with its settings, or `None` if no
+/// config file was found. The walk uses `Path::pop` rather than
+/// `canonicalize()` and is capped at `MAX_DISCOVERY_DEPTH` directories.
+///
+/// # Errors
+///
+/// Returns an error if a config file is found but cannot be loaded.
+pub fn load_config(start_dir: &Path) -> Result<Option<(PathBuf, Config)>> {
+    let mut dir = start_dir.to_owned();
+    for _ in 0..MAX_DISCOVERY_DEPTH {
+        for name in CONFIG_FILE_NAMES {
+            let candidate = dir.join(name);
+            if candidate.is_file() {
+                let config = Config::load(&candidate)?;
+                return Ok(Some((candidate, config)));
+            }
+        }
+        if !dir.pop() {
+            break;
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_from_yaml_all_settings() {
+        let config = Config::from_yaml(
+            "no_color: true\nverbose: true\nworkers: 4\n\
+             indent: 4\nwidth: 100\nsort_keys: true\nexplicit_start: false\nexplicit_end: true\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                no_color: true,
+                quiet: false,
+                verbose: true,
+                workers: Some(4),
+                indent: Some(4),
+                width: Some(100),
+                sort_keys: Some(true),
+                explicit_start: Some(false),
+                explicit_end: Some(true),
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_yaml_empty_is_default() {
+        assert_eq!(Config::from_yaml("").unwrap(), Config::default());
+        assert_eq!(Config::from_yaml("# comment\n").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_from_yaml_rejects_unknown_key() {
+        let err = Config::from_yaml("indnet: 4\n").unwrap_err();
+        assert!(err.to_string().contains("indnet"), "{err}");
+    }
+
+    #[test]
+    fn test_from_yaml_rejects_invalid_indent() {
+        let err = Config::from_yaml("indent: 1\n").unwrap_err();
+        assert!(err.to_string().contains("between 2 and 8"), "{err}");
+        assert!(Config::from_yaml("indent: four\n").is_err());
+    }
+
+    #[test]
+    fn test_from_yaml_rejects_wrong_types() {
+        assert!(Config::from_yaml("quiet: yes please\n").is_err());
+        assert!(Config::from_yaml("workers: -1\n").is_err());
+        assert!(Config::from_yaml("workers: many\n").is_err());
+        assert!(Config::from_yaml("- quiet\n").is_err());
+    }
+
+    #[test]
+    fn test_from_yaml_rejects_quiet_and_verbose() {
+        assert!(Config::from_yaml("quiet: true\nverbose: true\n").is_err());
+    }
+
+    #[test]
+    fn test_global_settings() {
+        let config =
+            Config::from_yaml("no_color: true\nquiet: true\nworkers: 3\nindent: 4\n").unwrap();
+        assert_eq!(
+            config.global(),
+            GlobalConfig {
+                no_color: true,
+                quiet: true,
+                verbose: false,
+                workers: Some(3),
+            }
+        );
+    }
+
+    #[test]
+    fn test_resolve_config_explicit_path() {
+        let root = TempDir::new().unwrap();
+        let path = root.path().join("style.yaml");
+        std::fs::write(&path, "sort_keys: true\n").unwrap();
+
+        let (found, config) = resolve_config(Some(&path)).unwrap().unwrap();
+        assert_eq!(found, path);
+        assert_eq!(config.sort_keys, Some(true));
+
+        let err = resolve_config(Some(&root.path().join("missing.yaml"))).unwrap_err();
+        assert!(
+            err.to_string().contains("failed to read config file"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_apply_overrides_only_set_values() {
+        let config = Config {
+            indent: Some(4),
+            sort_keys: Some(true),
+            ..Config::default()
+        };
+        let formatter = config.apply(FormatterConfig::new().with_width(120));
+        assert_eq!(formatter.indent(), 4);
+        assert_eq!(formatter.width(), 120);
+        assert!(formatter.sort_keys());
+        assert!(!formatter.explicit_start());
+        assert!(!formatter.explicit_end());
+    }
+
+    #[test]
+    fn test_load_config_walks_up() {
+        let root = TempDir::new().unwrap();
+        let nested = root.path().join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(root.path().join(".fy.yml"), "width: 60\n").unwrap();
+
+        let (path, config) = load_config(&nested).unwrap().unwrap();
+        assert_eq!(path, root.path().join(".fy.yml"));
+        assert_eq!(config.width, Some(60));
+    }
+
+    #[test]
+    fn test_load_config_prefers_yaml_extension() {
+        let root = TempDir::new().unwrap();
+        std::fs::write(root.path().join(".fy.yaml"), "indent: 4\n").unwrap();
+        std::fs::write(root.path().join(".fy.yml"), "indent: 8\n").unwrap();
+
+        let (path, config) = load_config(root.path()).unwrap().unwrap();
+        assert_eq!(path, root.path().join(".fy.yaml"));
+        assert_eq!(config.indent, Some(4));
+    }
+
+    #[test]
+    fn test_load_config_reports_invalid_file() {
+        let root = TempDir::new().unwrap();
+        std::fs::write(root.path().join(".fy.yaml"), "indent: [\n").unwrap();
+
+        let err = load_config(root.path()).unwrap_err();
+        assert!(err.to_string().contains(".fy.yaml"), "{err}");
+    }
+}
@@ -1,19 +1,34 @@
 //! Formatter configuration for YAML formatting.
 
-use fast_yaml_core::EmitterConfig;
+use fast_yaml_core::{EmitterConfig, NullStyle, StringNormalization};
 
 #[cfg(feature = "linter")]
 use fast_yaml_linter::LintConfig;
 
 /// Configuration for YAML formatting.
 ///
-/// Controls indentation and line width for formatting operations.
+/// Controls indentation, line width and null representation for formatting operations.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub struct FormatterConfig {
     /// Indentation width (2-8 spaces)
     indent: u8,
     /// Maximum line width
     width: usize,
+    /// Representation of null values
+    null_style: NullStyle,
+    /// Plain strings to double-quote
+    string_normalization: Option<StringNormalization>,
+    /// Keep the original scalar and flow collection styles
+    keep_original_styles: bool,
+    /// End output with a newline
+    trailing_newline: bool,
+    /// Sort mapping keys
+    sort_keys: bool,
+    /// Begin every document with `---`
+    explicit_start: bool,
+    /// End every document with `...`
+    explicit_end: bool,
 }
 
 impl FormatterConfig {
@@ -46,6 +61,58 @@ impl FormatterConfig {
         self
     }
 
+    /// Sets the representation of null values.
+    #[must_use]
+    pub const fn with_null_style(mut self, null_style: NullStyle) -> Self {
+        self.null_style = null_style;
+        self
+    }
+
+    /// Sets which plain strings are double-quoted.
+    #[must_use]
+    pub const fn with_string_normalization(
+        mut self,
+        string_normalization: Option<StringNormalization>,
+    ) -> Self {
+        self.string_normalization = string_normalization;
+        self
+    }
+
+    /// Sets whether scalars and flow collections keep their original style.
+    #[must_use]
+    pub const fn with_keep_original_styles(mut self, keep_original_styles: bool) -> Self {
+        self.keep_original_styles = keep_original_styles;
+        self
+    }
+
+    /// Sets whether output ends with a newline.
+    #[must_use]
+    pub const fn with_trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Sets whether mapping keys are sorted.
+    #[must_use]
+    pub const fn with_sort_keys(mut self, sort_keys: bool) -> Self {
+        self.sort_keys = sort_keys;
+        self
+    }
+
+    /// Sets whether every document begins with `---`.
+    #[must_use]
+    pub const fn with_explicit_start(mut self, explicit_start: bool) -> Self {
+        self.explicit_start = explicit_start;
+        self
+    }
+
+    /// Sets whether every document ends with `...`.
+    #[must_use]
+    pub const fn with_explicit_end(mut self, explicit_end: bool) -> Self {
+        self.explicit_end = explicit_end;
+        self
+    }
+
     /// Returns the indentation width.
     #[must_use]
     pub const fn indent(&self) -> u8 {
@@ -58,12 +125,61 @@ impl FormatterConfig {
         self.width
     }
 
+    /// Returns the representation of null values.
+    #[must_use]
+    pub const fn null_style(&self) -> NullStyle {
+        self.null_style
+    }
+
+    /// Returns which plain strings are double-quoted.
+    #[must_use]
+    pub const fn string_normalization(&self) -> Option<StringNormalization> {
+        self.string_normalization
+    }
+
+    /// Returns whether scalars and flow collections keep their original style.
+    #[must_use]
+    pub const fn keep_original_styles(&self) -> bool {
+        self.keep_original_styles
+    }
+
+    /// Returns whether output ends with a newline.
+    #[must_use]
+    pub const fn trailing_newline(&self) -> bool {
+        self.trailing_newline
+    }
+
+    /// Returns whether mapping keys are sorted.
+    #[must_use]
+    pub const fn sort_keys(&self) -> bool {
+        self.sort_keys
+    }
+
+    /// Returns whether every document begins with `---`.
+    #[must_use]
+    pub const fn explicit_start(&self) -> bool {
+        self.explicit_start
+    }
+
+    /// Returns whether every document ends with `...`.
+    #[must_use]
+    pub const fn explicit_end(&self) -> bool {
+        self.explicit_end
+    }
+
     /// Converts to `EmitterConfig` for fast-yaml-core.
     #[must_use]
     pub fn to_emitter_config(&self) -> EmitterConfig {
         EmitterConfig::new()
             .with_indent(self.indent as usize)
             .with_width(self.width)
+            .with_null_style(self.null_style)
+            .with_string_normalization(self.string_normalization)
+            .with_keep_original_styles(self.keep_original_styles)
+            .with_trailing_newline(self.trailing_newline)
+            .with_sort_keys(self.sort_keys)
+            .with_explicit_start(self.explicit_start)
+            .with_explicit_end(self.explicit_end)
     }
 
     /// Converts to `LintConfig` for fast-yaml-linter.
@@ -81,6 +197,13 @@ impl Default for FormatterConfig {
         Self {
             indent: 2,
             width: Self::DEFAULT_WIDTH,
+            null_style: NullStyle::Preserve,
+            string_normalization: None,
+            keep_original_styles: false,
+            trailing_newline: true,
+            sort_keys: false,
+            explicit_start: false,
+            explicit_end: false,
         }
     }
 }
@@ -94,6 +217,7 @@ mod tests {
         let config = FormatterConfig::default();
         assert_eq!(config.indent(), 2);
         assert_eq!(config.width(), FormatterConfig::DEFAULT_WIDTH);
+        assert!(config.trailing_newline());
     }
 
     #[test]
@@ -137,9 +261,28 @@ mod tests {
 
     #[test]
     fn test_to_emitter_config() {
-        let config = FormatterConfig::new().with_indent(4).with_width(120);
+        let config = FormatterConfig::new()
+            .with_indent(4)
+            .with_width(120)
+            .with_null_style(NullStyle::Tilde)
+            .with_string_normalization(Some(StringNormalization::QuoteAll))
+            .with_keep_original_styles(true)
+            .with_trailing_newline(false)
+            .with_sort_keys(true)
+            .with_explicit_start(true)
+            .with_explicit_end(true);
 
-        let _emitter_config = config.to_emitter_config();
+        let emitter_config = config.to_emitter_config();
+        assert_eq!(emitter_config.null_style, NullStyle::Tilde);
+        assert_eq!(
+            emitter_config.string_normalization,
+            Some(StringNormalization::QuoteAll)
+        );
+        assert!(emitter_config.keep_original_styles);
+        assert!(!emitter_config.trailing_newline);
+        assert!(emitter_config.sort_keys);
+        assert!(emitter_config.explicit_start);
+        assert!(emitter_config.explicit_end);
     }
 
     #[cfg(feature = "linter")]
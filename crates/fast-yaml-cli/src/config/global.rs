@@ -0,0 +1,96 @@
+//! Settings from the config file that apply to every command.
+
+use crate::cli::Cli;
+
+/// Defaults shared by all subcommands, read from the `global` settings of a
+/// [`Config`](super::Config) file.
+///
+/// Command-line flags take precedence over values from the file.
+///
+/// ```yaml
+/// # fast-yaml.yaml
+/// no_color: true
+/// verbose: true
+/// workers: 4
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GlobalConfig {
+    /// Disable colored output
+    pub no_color: bool,
+    /// Suppress non-error output
+    pub quiet: bool,
+    /// Enable verbose output
+    pub verbose: bool,
+    /// Worker threads for batch commands (0 = auto-detect)
+    pub workers: Option<usize>,
+}
+
+impl GlobalConfig {
+    /// Applies the file's output settings to `cli` where no flag was given.
+    ///
+    /// `--quiet` and `--verbose` on the command line each replace the other
+    /// setting from the file.
+    pub const fn apply(&self, cli: &mut Cli) {
+        cli.no_color |= self.no_color;
+        if !cli.quiet && !cli.verbose {
+            cli.quiet = self.quiet;
+            cli.verbose = self.verbose;
+        }
+    }
+
+    /// Returns the worker count for a command's `--jobs` value.
+    ///
+    /// An explicit `--jobs` wins; otherwise the file's `workers` is used.
+    /// `None` means auto-detect.
+    pub const fn resolve_workers(&self, jobs: usize) -> Option<usize> {
+        match (jobs, self.workers) {
+            (0, Some(0) | None) => None,
+            (0, Some(workers)) => Some(workers),
+            (jobs, _) => Some(jobs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser as _;
+
+    #[test]
+    fn test_apply_fills_unset_flags() {
+        let mut cli = Cli::parse_from(["fy", "parse"]);
+        GlobalConfig {
+            no_color: true,
+            quiet: true,
+            ..GlobalConfig::default()
+        }
+        .apply(&mut cli);
+        assert!(cli.no_color);
+        assert!(cli.quiet);
+    }
+
+    #[test]
+    fn test_apply_cli_verbose_overrides_quiet() {
+        let mut cli = Cli::parse_from(["fy", "--verbose", "parse"]);
+        GlobalConfig {
+            quiet: true,
+            ..GlobalConfig::default()
+        }
+        .apply(&mut cli);
+        assert!(cli.verbose);
+        assert!(!cli.quiet);
+    }
+
+    #[test]
+    fn test_resolve_workers() {
+        let config = GlobalConfig {
+            workers: Some(3),
+            ..GlobalConfig::default()
+        };
+        assert_eq!(config.resolve_workers(0), Some(3));
+        assert_eq!(config.resolve_workers(8), Some(8));
+        assert_eq!(GlobalConfig::default().resolve_workers(0), None);
+        assert_eq!(GlobalConfig::default().resolve_workers(2), Some(2));
+    }
+}
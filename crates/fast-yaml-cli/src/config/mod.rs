@@ -4,12 +4,16 @@
 //! duplication across commands while maintaining backward compatibility.
 
 mod common;
+mod file;
 mod formatter;
+mod global;
 mod io;
 mod output;
 
 pub use common::CommonConfig;
+pub use file::{Config, resolve_config};
 pub use formatter::FormatterConfig;
+pub use global::GlobalConfig;
 pub use io::IoConfig;
 pub use output::OutputConfig;
 
@@ -3,6 +3,7 @@
 use std::collections::HashSet;
 use std::io::BufRead;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use globset::{Glob, GlobSet, GlobSetBuilder};
 
@@ -22,7 +23,9 @@ const MAX_GLOB_MATCHES: usize = 100_000;
 pub struct DiscoveryConfig {
     /// Glob patterns for files to include (e.g., "*.yaml", "*.yml")
     pub include_patterns: Vec<String>,
-    /// Glob patterns for files/directories to exclude (e.g., "**/vendor/**")
+    /// Glob patterns for files/directories to exclude (e.g., "**/vendor/**").
+    /// A bare name without `/` or glob characters (e.g., "vendor") matches that
+    /// file or directory at any depth.
     pub exclude_patterns: Vec<String>,
     /// Maximum recursion depth (None = unlimited)
     pub max_depth: Option<usize>,
@@ -61,6 +64,24 @@ impl DiscoveryConfig {
         self
     }
 
+    /// Set include patterns from file extensions, e.g. `["yaml", "yaml.tpl"]`
+    /// becomes `["*.yaml", "*.yaml.tpl"]`. A leading `.` is ignored.
+    #[must_use]
+    pub fn with_extensions(mut self, exts: Vec<String>) -> Self {
+        self.include_patterns = exts
+            .into_iter()
+            .map(|ext| format!("*.{}", ext.trim_start_matches('.')))
+            .collect();
+        self
+    }
+
+    /// Append include patterns, keeping the existing ones.
+    #[must_use]
+    pub fn with_additional_patterns(mut self, patterns: Vec<String>) -> Self {
+        self.include_patterns.extend(patterns);
+        self
+    }
+
     /// Set exclude patterns (builder pattern).
     #[must_use]
     pub fn with_exclude_patterns(mut self, patterns: Vec<String>) -> Self {
@@ -138,7 +159,7 @@ impl FileDiscovery {
     /// Create a new file discovery instance.
     pub fn new(config: DiscoveryConfig) -> Result<Self, DiscoveryError> {
         let include_matcher = build_globset(&config.include_patterns)?;
-        let exclude_matcher = build_globset(&config.exclude_patterns)?;
+        let exclude_matcher = build_globset(&expand_exclude_patterns(&config.exclude_patterns))?;
 
         Ok(Self {
             config,
@@ -160,26 +181,43 @@ impl FileDiscovery {
         let mut seen = HashSet::new();
 
         for path in paths {
-            if path.exists() {
-                if path.is_file() {
-                    self.discover_file(
-                        path,
-                        DiscoveryOrigin::DirectPath,
-                        &mut discovered,
-                        &mut seen,
-                    )?;
-                } else if path.is_dir() {
-                    self.discover_directory(path, &mut discovered, &mut seen);
-                }
-            } else {
-                // Treat as glob pattern
-                self.discover_glob(&path.to_string_lossy(), &mut discovered, &mut seen);
+            let pattern = path.to_string_lossy();
+            // An existing file wins over a pattern, so names like `a[1].yaml` still work
+            if path.is_file() {
+                self.discover_file(
+                    path,
+                    DiscoveryOrigin::DirectPath,
+                    &mut discovered,
+                    &mut seen,
+                )?;
+            } else if is_glob_pattern(&pattern) || !path.exists() {
+                self.discover_glob(&pattern, &mut discovered, &mut seen);
+            } else if path.is_dir() {
+                self.discover_directory(path, &mut discovered, &mut seen);
             }
         }
 
         Ok(discovered)
     }
 
+    /// Discover files like [`discover`](Self::discover), keeping only those
+    /// modified at or after `since`.
+    ///
+    /// Files whose modification time cannot be read are kept.
+    pub fn discover_changed(
+        &self,
+        paths: &[PathBuf],
+        since: SystemTime,
+    ) -> Result<Vec<DiscoveredFile>, DiscoveryError> {
+        let mut files = self.discover(paths)?;
+        files.retain(|file| {
+            std::fs::metadata(&file.path)
+                .and_then(|metadata| metadata.modified())
+                .map_or(true, |modified| modified >= since)
+        });
+        Ok(files)
+    }
+
     /// Discover files from stdin (one path per line).
     pub fn discover_from_stdin(&self) -> Result<Vec<DiscoveredFile>, DiscoveryError> {
         self.discover_from_reader(std::io::stdin().lock())
@@ -230,6 +268,58 @@ impl FileDiscovery {
         Ok(discovered)
     }
 
+    /// Discover files from NUL-delimited paths on stdin.
+    pub fn discover_from_stdin_nul(&self) -> Result<Vec<DiscoveredFile>, DiscoveryError> {
+        self.discover_from_reader_nul(std::io::stdin().lock())
+    }
+
+    /// Discover files from NUL-delimited paths, as written by `find -print0`
+    /// or `git ls-files -z`.
+    ///
+    /// Unlike [`discover_from_reader`](Self::discover_from_reader), paths are
+    /// taken verbatim: they are not trimmed and `#` does not start a comment.
+    pub fn discover_from_reader_nul<R: BufRead>(
+        &self,
+        reader: R,
+    ) -> Result<Vec<DiscoveredFile>, DiscoveryError> {
+        let mut discovered = Vec::new();
+        let mut seen = HashSet::new();
+        let mut count = 0;
+
+        for entry in reader.split(b'\0') {
+            let entry = entry.map_err(|e| DiscoveryError::StdinError { source: e })?;
+
+            count += 1;
+            if count > MAX_STDIN_PATHS {
+                return Err(DiscoveryError::TooManyPaths {
+                    max: MAX_STDIN_PATHS,
+                });
+            }
+
+            if entry.len() > MAX_LINE_LENGTH {
+                eprintln!("Warning: skipping path {count} (exceeds {MAX_LINE_LENGTH} bytes)");
+                continue;
+            }
+
+            // The list usually ends with a NUL, leaving an empty last entry
+            if entry.is_empty() {
+                continue;
+            }
+
+            let path = path_from_bytes(entry);
+            if path.is_file() {
+                self.discover_file(
+                    &path,
+                    DiscoveryOrigin::StdinList,
+                    &mut discovered,
+                    &mut seen,
+                )?;
+            }
+        }
+
+        Ok(discovered)
+    }
+
     /// Check if a single path should be included.
     #[must_use]
     pub fn should_include(&self, path: &Path) -> bool {
@@ -302,7 +392,9 @@ impl FileDiscovery {
             .git_ignore(self.config.respect_gitignore)
             .git_global(self.config.respect_gitignore)
             .git_exclude(self.config.respect_gitignore)
-            .follow_links(self.config.follow_symlinks);
+            .follow_links(self.config.follow_symlinks)
+            // Stable order, e.g. for formatted output printed to stdout
+            .sort_by_file_name(std::ffi::OsStr::cmp);
 
         if let Some(depth) = self.config.max_depth {
             builder.max_depth(Some(depth));
@@ -367,6 +459,44 @@ impl FileDiscovery {
     }
 }
 
+/// Converts raw path bytes read from stdin to a path.
+#[cfg(unix)]
+fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    use std::os::unix::ffi::OsStringExt;
+
+    PathBuf::from(std::ffi::OsString::from_vec(bytes))
+}
+
+/// Converts raw path bytes read from stdin to a path.
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: Vec<u8>) -> PathBuf {
+    PathBuf::from(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Returns true if `s` contains glob metacharacters (`*`, `?` or `[`).
+#[must_use]
+pub fn is_glob_pattern(s: &str) -> bool {
+    s.contains(['*', '?', '['])
+}
+
+/// Expands bare names in exclude patterns to match at any depth.
+///
+/// `vendor` becomes `vendor`, `**/vendor` and `**/vendor/**`, so it excludes a
+/// file or directory with that name anywhere, like a `.gitignore` entry.
+fn expand_exclude_patterns(patterns: &[String]) -> Vec<String> {
+    let mut expanded = Vec::with_capacity(patterns.len());
+    for pattern in patterns {
+        let is_bare_name =
+            !pattern.is_empty() && !pattern.contains(['/', '*', '?', '[', ']', '{', '}', '\\']);
+        if is_bare_name {
+            expanded.push(format!("**/{pattern}"));
+            expanded.push(format!("**/{pattern}/**"));
+        }
+        expanded.push(pattern.clone());
+    }
+    expanded
+}
+
 fn build_globset(patterns: &[String]) -> Result<GlobSet, DiscoveryError> {
     let mut builder = GlobSetBuilder::new();
 
@@ -423,6 +553,52 @@ mod tests {
         assert!(config.follow_symlinks);
     }
 
+    #[test]
+    fn test_with_extensions() {
+        let config = default_config().with_extensions(vec![
+            "yaml".to_string(),
+            ".yml".to_string(),
+            "yaml.tpl".to_string(),
+        ]);
+        assert_eq!(
+            config.include_patterns,
+            vec!["*.yaml", "*.yml", "*.yaml.tpl"]
+        );
+
+        let discovery = FileDiscovery::new(config).unwrap();
+        assert!(discovery.should_include(Path::new("chart/templates/deploy.yaml.tpl")));
+        assert!(!discovery.should_include(Path::new("chart/values.gotmpl")));
+    }
+
+    #[test]
+    fn test_with_additional_patterns_discovers_templates() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("values.yaml"), "a: 1").unwrap();
+        fs::write(temp.path().join("deploy.yaml.tpl"), "b: 2").unwrap();
+        fs::write(temp.path().join("notes.txt"), "c: 3").unwrap();
+
+        let default_files = FileDiscovery::new(default_config())
+            .unwrap()
+            .discover(&[temp.path().to_path_buf()])
+            .unwrap();
+        assert_eq!(default_files.len(), 1);
+
+        let config = default_config().with_additional_patterns(vec!["*.yaml.tpl".to_string()]);
+        assert_eq!(
+            config.include_patterns,
+            vec!["*.yaml", "*.yml", "*.yaml.tpl"]
+        );
+        let mut names: Vec<_> = FileDiscovery::new(config)
+            .unwrap()
+            .discover(&[temp.path().to_path_buf()])
+            .unwrap()
+            .into_iter()
+            .map(|f| f.path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["deploy.yaml.tpl", "values.yaml"]);
+    }
+
     #[test]
     fn test_include_pattern_yaml() {
         let config = default_config();
@@ -460,6 +636,31 @@ mod tests {
         assert!(discovery.should_include(Path::new("src/config.yaml")));
     }
 
+    #[test]
+    fn test_exclude_bare_name_matches_any_depth() {
+        let config = default_config().with_exclude_patterns(vec![
+            "vendor".to_string(),
+            "generated".to_string(),
+            "**/*.min.yaml".to_string(),
+        ]);
+        let discovery = FileDiscovery::new(config).unwrap();
+
+        assert!(!discovery.should_include(Path::new("vendor/lib.yaml")));
+        assert!(!discovery.should_include(Path::new("/repo/a/vendor/b/lib.yaml")));
+        assert!(!discovery.should_include(Path::new("/repo/generated/api.yaml")));
+        assert!(!discovery.should_include(Path::new("/repo/app.min.yaml")));
+        assert!(discovery.should_include(Path::new("/repo/vendored/lib.yaml")));
+        assert!(discovery.should_include(Path::new("/repo/app.yaml")));
+    }
+
+    #[test]
+    fn test_expand_exclude_patterns() {
+        assert_eq!(
+            expand_exclude_patterns(&["vendor".to_string(), "**/*.min.yaml".to_string()]),
+            vec!["**/vendor", "**/vendor/**", "vendor", "**/*.min.yaml"]
+        );
+    }
+
     #[test]
     fn test_discover_single_file() {
         let temp = TempDir::new().unwrap();
@@ -510,6 +711,71 @@ mod tests {
         assert_eq!(files[0].origin, DiscoveryOrigin::GlobExpansion);
     }
 
+    #[test]
+    fn test_is_glob_pattern() {
+        assert!(is_glob_pattern("**/*.yaml"));
+        assert!(is_glob_pattern("config?.yml"));
+        assert!(is_glob_pattern("env/[ab].yaml"));
+        assert!(!is_glob_pattern("config/app.yaml"));
+    }
+
+    #[test]
+    fn test_discover_recursive_glob() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("a/b")).unwrap();
+        fs::write(temp.path().join("root.yaml"), "a: 1").unwrap();
+        fs::write(temp.path().join("a/one.yaml"), "b: 2").unwrap();
+        fs::write(temp.path().join("a/b/two.yaml"), "c: 3").unwrap();
+        fs::write(temp.path().join("a/b/skip.yml"), "d: 4").unwrap();
+
+        let pattern = format!("{}/**/*.yaml", temp.path().display());
+        let discovery = FileDiscovery::new(default_config()).unwrap();
+        let files = discovery.discover(&[PathBuf::from(pattern)]).unwrap();
+
+        assert_eq!(files.len(), 3);
+        assert!(
+            files
+                .iter()
+                .all(|f| f.origin == DiscoveryOrigin::GlobExpansion)
+        );
+    }
+
+    #[test]
+    fn test_discover_changed() {
+        use std::time::Duration;
+
+        let temp = TempDir::new().unwrap();
+        fs::create_dir(temp.path().join("nested")).unwrap();
+        let old = temp.path().join("old.yaml");
+        let new = temp.path().join("nested/new.yaml");
+        fs::write(&old, "a: 1").unwrap();
+        fs::write(&new, "b: 2").unwrap();
+
+        let since = SystemTime::now() - Duration::from_secs(3600);
+        let set_modified = |path: &Path, time: SystemTime| {
+            fs::File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(time)
+                .unwrap();
+        };
+        set_modified(&old, since - Duration::from_secs(60));
+        set_modified(&new, since + Duration::from_secs(60));
+
+        let discovery = FileDiscovery::new(default_config()).unwrap();
+        let files = discovery
+            .discover_changed(&[temp.path().to_path_buf()], since)
+            .unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path.file_name().unwrap(), "new.yaml");
+
+        let all = discovery
+            .discover_changed(&[temp.path().to_path_buf()], SystemTime::UNIX_EPOCH)
+            .unwrap();
+        assert_eq!(all.len(), 2);
+    }
+
     #[test]
     fn test_discover_mixed_paths() {
         let temp = TempDir::new().unwrap();
@@ -726,6 +992,53 @@ mod tests {
         assert_eq!(files.len(), 1);
     }
 
+    #[test]
+    fn test_discover_from_reader_nul() {
+        let temp = TempDir::new().unwrap();
+        let spaced = temp.path().join("my config.yaml");
+        let hashed = temp.path().join("#notes.yaml");
+        let plain = temp.path().join("plain.yml");
+        fs::write(&spaced, "a: 1").unwrap();
+        fs::write(&hashed, "b: 2").unwrap();
+        fs::write(&plain, "c: 3").unwrap();
+
+        let input = format!(
+            "{}\0{}\0{}\0{}\0",
+            spaced.display(),
+            hashed.display(),
+            temp.path().join("missing.yaml").display(),
+            plain.display()
+        );
+        let discovery = FileDiscovery::new(default_config()).unwrap();
+        let files = discovery
+            .discover_from_reader_nul(input.as_bytes())
+            .unwrap();
+
+        assert_eq!(files.len(), 3);
+        assert!(files.iter().all(|f| f.origin == DiscoveryOrigin::StdinList));
+        assert!(
+            files
+                .iter()
+                .any(|f| f.path.file_name().unwrap() == "my config.yaml")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_discover_from_reader_nul_newline_in_name() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("line\nbreak.yaml");
+        fs::write(&file, "a: 1").unwrap();
+
+        let input = format!("{}\0", file.display());
+        let discovery = FileDiscovery::new(default_config()).unwrap();
+        let files = discovery
+            .discover_from_reader_nul(input.as_bytes())
+            .unwrap();
+
+        assert_eq!(files.len(), 1);
+    }
+
     #[test]
     fn test_permission_denied_continues() {
         // Testing permission errors requires platform-specific setup
@@ -7,15 +7,24 @@ use thiserror::Error;
 #[allow(dead_code)]
 pub enum ExitCode {
     /// Operation completed successfully
-    Success = 0,
+    Success,
     /// YAML parsing failed
-    ParseError = 1,
-    /// Linter found errors
-    LintErrors = 2,
+    ParseError,
+    /// `fy format --diff` found files that formatting would change, or
+    /// `fy diff` found differences
+    ChangesFound,
+    /// Linter found errors, or warnings with `fy lint --fail-on-warning`
+    LintErrors,
+    /// `fy check` found files that are not formatted
+    Unformatted,
+    /// `fy parse --schema` found schema violations
+    SchemaViolations,
+    /// `fy diff` could not read or parse one of its files
+    DiffFailed,
     /// I/O operation failed
-    IoError = 3,
+    IoError,
     /// Invalid command-line arguments
-    InvalidArgs = 4,
+    InvalidArgs,
 }
 
 /// Errors that can occur during file discovery.
@@ -81,7 +90,13 @@ pub enum DiscoveryError {
 impl ExitCode {
     /// Converts exit code to i32 for use with `std::process::exit`
     pub const fn as_i32(self) -> i32 {
-        self as i32
+        match self {
+            Self::Success => 0,
+            Self::ParseError | Self::ChangesFound => 1,
+            Self::LintErrors | Self::Unformatted | Self::SchemaViolations | Self::DiffFailed => 2,
+            Self::IoError => 3,
+            Self::InvalidArgs => 4,
+        }
     }
 }
 
@@ -126,7 +141,11 @@ mod tests {
     fn test_exit_code_values() {
         assert_eq!(ExitCode::Success.as_i32(), 0);
         assert_eq!(ExitCode::ParseError.as_i32(), 1);
+        assert_eq!(ExitCode::ChangesFound.as_i32(), 1);
         assert_eq!(ExitCode::LintErrors.as_i32(), 2);
+        assert_eq!(ExitCode::Unformatted.as_i32(), 2);
+        assert_eq!(ExitCode::DiffFailed.as_i32(), 2);
+        assert_eq!(ExitCode::SchemaViolations.as_i32(), 2);
         assert_eq!(ExitCode::IoError.as_i32(), 3);
         assert_eq!(ExitCode::InvalidArgs.as_i32(), 4);
     }
@@ -1,6 +1,8 @@
 use anyhow::{Context, Result};
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use std::fs;
-use std::io::{self, Read};
+use std::io::{self, BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
 
 /// Source of input data
@@ -19,11 +21,13 @@ pub enum InputOrigin {
 
 impl InputSource {
     /// Read input from file or stdin based on arguments
+    ///
+    /// With `stdin` set, stdin is read even if a file is given.
     #[allow(clippy::option_if_let_else)]
-    pub fn from_args(file: Option<PathBuf>) -> Result<Self> {
+    pub fn from_args(file: Option<PathBuf>, stdin: bool) -> Result<Self> {
         match file {
-            Some(path) => Self::from_file(&path),
-            None => Self::from_stdin(),
+            Some(path) if !stdin => Self::from_file(&path),
+            _ => Self::from_stdin(),
         }
     }
 
@@ -65,6 +69,36 @@ impl InputSource {
     }
 }
 
+/// Open input for reading without loading it into memory: a buffered file, or
+/// locked stdin when no file is given.
+pub fn open_reader(file: Option<&Path>) -> Result<Box<dyn BufRead>> {
+    match file {
+        Some(path) => {
+            let file = fs::File::open(path)
+                .with_context(|| format!("Failed to open file: {}", path.display()))?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+        None => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+/// Read binary input: raw bytes from a file, or base64 text from stdin.
+///
+/// Stdin is decoded from base64 (whitespace and line breaks are ignored), the
+/// same form `OutputWriter::write_bytes` prints to stdout.
+pub fn read_binary(file: Option<&Path>) -> Result<Vec<u8>> {
+    if let Some(path) = file {
+        return fs::read(path).with_context(|| format!("Failed to read file: {}", path.display()));
+    }
+    let input = InputSource::from_stdin()?;
+    decode_base64(input.as_str()).context("Failed to decode base64 from stdin")
+}
+
+fn decode_base64(text: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    let compact: String = text.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    BASE64.decode(compact)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,6 +116,34 @@ mod tests {
         assert_eq!(input.file_path(), Some(temp_file.path()));
     }
 
+    #[test]
+    fn test_from_args_file() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "test: value").unwrap();
+
+        let input = InputSource::from_args(Some(temp_file.path().to_path_buf()), false).unwrap();
+        assert_eq!(input.as_str(), "test: value");
+        assert_eq!(input.file_path(), Some(temp_file.path()));
+    }
+
+    #[test]
+    fn test_read_binary_file() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(&[0x81, 0xff, 0x00]).unwrap();
+
+        let bytes = read_binary(Some(temp_file.path())).unwrap();
+        assert_eq!(bytes, [0x81, 0xff, 0x00]);
+    }
+
+    #[test]
+    fn test_decode_base64_ignores_whitespace() {
+        assert_eq!(
+            decode_base64("gaF h\nAQ==\n").unwrap(),
+            [0x81, 0xa1, b'a', 0x01]
+        );
+        assert!(decode_base64("not base64!").is_err());
+    }
+
     #[test]
     fn test_from_file_not_found() {
         let result = InputSource::from_file(Path::new("/nonexistent/file.yaml"));
@@ -2,4 +2,4 @@ pub mod input;
 pub mod output;
 
 pub use input::InputSource;
-pub use output::OutputWriter;
+pub use output::{OutputEncoding, OutputWriter};
@@ -1,8 +1,42 @@
 use anyhow::{Context, Result};
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use clap::ValueEnum;
+use std::borrow::Cow;
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
+/// Character encoding of written output
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputEncoding {
+    /// UTF-8 without BOM
+    #[default]
+    Utf8,
+    /// UTF-16 little-endian with BOM
+    #[value(name = "utf16-le")]
+    Utf16Le,
+    /// UTF-16 big-endian with BOM
+    #[value(name = "utf16-be")]
+    Utf16Be,
+}
+
+impl OutputEncoding {
+    /// Encode `content`, prepending a byte order mark for UTF-16.
+    pub fn encode(self, content: &str) -> Cow<'_, [u8]> {
+        let to_bytes: fn(u16) -> [u8; 2] = match self {
+            Self::Utf8 => return Cow::Borrowed(content.as_bytes()),
+            Self::Utf16Le => u16::to_le_bytes,
+            Self::Utf16Be => u16::to_be_bytes,
+        };
+        let mut bytes = Vec::with_capacity(2 + content.len() * 2);
+        for unit in std::iter::once(0xFEFF).chain(content.encode_utf16()) {
+            bytes.extend_from_slice(&to_bytes(unit));
+        }
+        Cow::Owned(bytes)
+    }
+}
+
 /// Destination for output data
 #[derive(Debug)]
 pub enum OutputDestination {
@@ -15,6 +49,7 @@ pub enum OutputDestination {
 #[derive(Debug)]
 pub struct OutputWriter {
     destination: OutputDestination,
+    encoding: OutputEncoding,
 }
 
 /// Returns the `OutputDestination` for paths that should bypass the temp-file strategy.
@@ -59,7 +94,17 @@ impl OutputWriter {
             OutputDestination::Stdout
         };
 
-        Ok(Self { destination })
+        Ok(Self {
+            destination,
+            encoding: OutputEncoding::Utf8,
+        })
+    }
+
+    /// Set the encoding used when writing output.
+    #[must_use]
+    pub const fn with_encoding(mut self, encoding: OutputEncoding) -> Self {
+        self.encoding = encoding;
+        self
     }
 
     /// Create stdout writer for tests
@@ -67,6 +112,7 @@ impl OutputWriter {
     pub const fn stdout() -> Self {
         Self {
             destination: OutputDestination::Stdout,
+            encoding: OutputEncoding::Utf8,
         }
     }
 
@@ -76,38 +122,120 @@ impl OutputWriter {
     ///
     /// Returns an error on I/O failure for any destination variant (file write, stdout, or stderr).
     pub fn write(&self, content: &str) -> Result<()> {
+        let bytes = self.encoding.encode(content);
         match &self.destination {
             OutputDestination::File(path) => {
-                Self::write_file(path, content)?;
+                Self::write_file(path, &bytes)?;
             }
             OutputDestination::Stdout => {
                 io::stdout()
-                    .write_all(content.as_bytes())
+                    .write_all(&bytes)
                     .context("Failed to write to stdout")?;
             }
             OutputDestination::Stderr => {
                 io::stderr()
-                    .write_all(content.as_bytes())
+                    .write_all(&bytes)
                     .context("Failed to write to stderr")?;
             }
         }
         Ok(())
     }
 
+    /// Write binary output.
+    ///
+    /// Files receive the raw bytes. Stdout and stderr receive base64 text with
+    /// a trailing newline, so binary data is never written to a terminal.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on I/O failure.
+    pub fn write_bytes(&self, bytes: &[u8]) -> Result<()> {
+        match &self.destination {
+            OutputDestination::File(path) => Self::write_file(path, bytes),
+            OutputDestination::Stdout | OutputDestination::Stderr => {
+                let mut text = BASE64.encode(bytes);
+                text.push('\n');
+                self.write(&text)
+            }
+        }
+    }
+
+    /// Stream output through `f` instead of building it in memory first.
+    ///
+    /// Files are still replaced atomically, and only if `f` succeeds. Output in
+    /// a UTF-16 encoding is collected and re-encoded before it is written.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `f`, or an error on I/O failure.
+    pub fn write_stream(&self, f: impl FnOnce(&mut dyn Write) -> Result<()>) -> Result<()> {
+        if self.encoding != OutputEncoding::Utf8 {
+            let mut buffer = Vec::new();
+            f(&mut buffer)?;
+            let content = String::from_utf8(buffer).context("Output is not valid UTF-8")?;
+            return self.write(&content);
+        }
+        match &self.destination {
+            OutputDestination::File(path) => {
+                let temp = Self::temp_file_for(path)?;
+                let mut writer = io::BufWriter::new(temp);
+                f(&mut writer)?;
+                let temp = writer
+                    .into_inner()
+                    .map_err(io::IntoInnerError::into_error)
+                    .context("Failed to write temp file")?;
+                temp.persist(path)
+                    .with_context(|| format!("Failed to replace file: {}", path.display()))?;
+            }
+            OutputDestination::Stdout => f(&mut io::stdout().lock())?,
+            OutputDestination::Stderr => f(&mut io::stderr().lock())?,
+        }
+        Ok(())
+    }
+
     /// Write to file with atomic operation
-    fn write_file(path: &Path, content: &str) -> Result<()> {
-        // Write to temporary file first
-        let temp_path = path.with_extension("tmp");
+    fn write_file(path: &Path, content: &[u8]) -> Result<()> {
+        let mut temp = Self::temp_file_for(path)?;
 
-        fs::write(&temp_path, content)
-            .with_context(|| format!("Failed to write temp file: {}", temp_path.display()))?;
+        temp.write_all(content)
+            .with_context(|| format!("Failed to write temp file: {}", temp.path().display()))?;
 
         // Atomic rename
-        fs::rename(&temp_path, path)
+        temp.persist(path)
             .with_context(|| format!("Failed to replace file: {}", path.display()))?;
 
         Ok(())
     }
+
+    /// Create the temp file that is renamed over `path` once it is written
+    ///
+    /// The temp file gets a unique name in the target directory, so writes to
+    /// `config.yaml` and `config.tmp` running at the same time cannot collide.
+    fn temp_file_for(path: &Path) -> Result<tempfile::NamedTempFile> {
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+
+        let mut builder = tempfile::Builder::new();
+        // Temp files are private by default; give new files the mode `fs::write` would
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            builder.permissions(fs::Permissions::from_mode(0o666));
+        }
+        let temp = builder
+            .tempfile_in(dir)
+            .with_context(|| format!("Failed to create temp file in: {}", dir.display()))?;
+
+        if let Ok(metadata) = fs::metadata(path) {
+            temp.as_file()
+                .set_permissions(metadata.permissions())
+                .with_context(|| format!("Failed to copy permissions of: {}", path.display()))?;
+        }
+
+        Ok(temp)
+    }
 }
 
 #[cfg(test)]
@@ -199,9 +327,108 @@ mod tests {
         write!(temp_file, "original content").unwrap();
         let path = temp_file.path();
 
-        OutputWriter::write_file(path, "new content").unwrap();
+        OutputWriter::write_file(path, b"new content").unwrap();
 
         let content = fs::read_to_string(path).unwrap();
         assert_eq!(content, "new content");
     }
+
+    #[test]
+    fn test_write_stream_to_file() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        write!(temp_file, "original content").unwrap();
+        let writer =
+            OutputWriter::from_args(Some(temp_file.path().to_path_buf()), false, None).unwrap();
+
+        writer
+            .write_stream(|out| Ok(out.write_all(b"streamed")?))
+            .unwrap();
+        assert_eq!(fs::read_to_string(temp_file.path()).unwrap(), "streamed");
+
+        // A failing stream leaves the file untouched
+        let result = writer.write_stream(|out| {
+            out.write_all(b"partial")?;
+            anyhow::bail!("conversion failed")
+        });
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(temp_file.path()).unwrap(), "streamed");
+    }
+
+    #[test]
+    fn test_write_file_concurrent_same_stem() {
+        let dir = tempfile::tempdir().unwrap();
+        let yaml = dir.path().join("config.yaml");
+        let tmp = dir.path().join("config.tmp");
+
+        std::thread::scope(|scope| {
+            for (path, content) in [(&yaml, "yaml"), (&tmp, "tmp")] {
+                scope.spawn(move || {
+                    for _ in 0..200 {
+                        OutputWriter::write_file(path, content.as_bytes()).unwrap();
+                    }
+                });
+            }
+        });
+
+        assert_eq!(fs::read_to_string(&yaml).unwrap(), "yaml");
+        assert_eq!(fs::read_to_string(&tmp).unwrap(), "tmp");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 2);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_write_file_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("script.yaml");
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+        OutputWriter::write_file(&path, b"new").unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o640);
+    }
+
+    #[test]
+    fn test_encode_utf8_is_unchanged() {
+        let bytes = OutputEncoding::Utf8.encode("a: é\n");
+        assert!(matches!(bytes, Cow::Borrowed(_)));
+        assert_eq!(&*bytes, "a: é\n".as_bytes());
+    }
+
+    #[test]
+    fn test_encode_utf16_le_with_bom() {
+        let bytes = OutputEncoding::Utf16Le.encode("a: é");
+        assert_eq!(
+            &*bytes,
+            &[0xFF, 0xFE, b'a', 0, b':', 0, b' ', 0, 0xE9, 0][..]
+        );
+    }
+
+    #[test]
+    fn test_encode_utf16_be_with_bom() {
+        let bytes = OutputEncoding::Utf16Be.encode("a: 😀");
+        assert_eq!(
+            &*bytes,
+            &[
+                0xFE, 0xFF, 0, b'a', 0, b':', 0, b' ', 0xD8, 0x3D, 0xDE, 0x00
+            ][..]
+        );
+    }
+
+    #[test]
+    fn test_write_file_with_encoding() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let writer = OutputWriter::from_args(Some(temp_file.path().to_path_buf()), false, None)
+            .unwrap()
+            .with_encoding(OutputEncoding::Utf16Le);
+
+        writer.write("k: v\n").unwrap();
+
+        let bytes = fs::read(temp_file.path()).unwrap();
+        assert_eq!(&bytes[..2], &[0xFF, 0xFE]);
+        assert_eq!(bytes.len(), 2 + 5 * 2);
+    }
 }
@@ -9,6 +9,9 @@
 //! # Format YAML with consistent style
 //! fy format --indent 4 messy.yaml
 //!
+//! # Check that files are already formatted (exit code 2 if not)
+//! fy check config/
+//!
 //! # Convert YAML to JSON
 //! fy convert json config.yaml
 //!
@@ -44,7 +47,7 @@ mod error;
 mod io;
 mod reporter;
 
-use cli::{Cli, Command};
+use cli::{Cli, Command, ConvertFormat, ConvertSource, EncodingFallback, ReportFormat};
 use error::{ExitCode, format_error};
 use io::input::InputOrigin;
 use io::{InputSource, OutputWriter};
@@ -54,7 +57,10 @@ fn main() {
         Ok(code) => code,
         Err(err) => {
             // Use OutputConfig to determine color usage
-            let cli = Cli::parse();
+            let mut cli = Cli::parse();
+            if let Ok(Some((_, settings))) = config::resolve_config(cli.config_file.as_deref()) {
+                settings.global().apply(&mut cli);
+            }
             let output_config =
                 config::OutputConfig::from_cli(cli.quiet, cli.verbose, cli.no_color);
             eprintln!("{}", format_error(&err, output_config.use_color()));
@@ -66,35 +72,167 @@ fn main() {
 }
 
 fn run() -> Result<ExitCode> {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
+
+    // Settings from the config file fill in flags that were not given
+    let settings = match config::resolve_config(cli.config_file.as_deref())? {
+        Some((path, settings)) => {
+            settings.global().apply(&mut cli);
+            if cli.verbose {
+                eprintln!("using config file: {}", path.display());
+            }
+            settings
+        }
+        None => config::Config::default(),
+    };
 
     // Create common config early to avoid borrow issues
     let common_config = config::CommonConfig::from_cli(&cli);
 
     // Execute command
     let exit_code = match cli.command {
-        Some(Command::Parse { file, stats }) => {
-            let input = InputSource::from_args(file)?;
-            let cmd = commands::parse::ParseCommand::new(common_config, stats);
-            cmd.execute(&input)?;
-            ExitCode::Success
+        Some(Command::Parse {
+            file,
+            stdin,
+            stats,
+            ast,
+            max_depth,
+            max_nodes,
+            schema,
+        }) => {
+            let input = InputSource::from_args(file, stdin)?;
+            let cmd = commands::parse::ParseCommand::new(common_config, stats)
+                .with_ast(ast)
+                .with_limits(max_depth, max_nodes)
+                .with_schema(schema);
+            cmd.execute(&input)?
         }
         Some(Command::Format {
             paths,
+            stdin,
             indent,
             width,
+            encoding_fallback,
+            output_encoding,
+            null_style,
+            normalize_strings,
+            keep_styles,
+            explicit_start,
+            no_explicit_start,
+            trailing_newline: _,
+            no_trailing_newline,
+            max_file_size,
             jobs,
             stdin_files,
+            stdin_files_nul,
             include,
             exclude,
             no_recursive,
             dry_run,
+            diff,
+            output_separator,
+            output_header,
+            reporter,
+            timing,
+            timing_top,
+            progress,
+            max_errors,
+            since_timestamp,
             strip_comments,
+            respect_file_header,
         }) => {
-            // Determine if this is batch mode
-            let is_batch = is_batch_mode(&paths, stdin_files, &include, &exclude, jobs);
+            let null_style = null_style.map_or(fast_yaml_core::NullStyle::Preserve, Into::into);
+            let normalize_strings = normalize_strings.map(Into::into);
+            let mut formatter = resolve_formatter(&settings, indent, width);
+            if explicit_start || no_explicit_start {
+                formatter = formatter.with_explicit_start(explicit_start);
+            }
+            let formatter = formatter
+                .with_null_style(null_style)
+                .with_string_normalization(normalize_strings)
+                .with_keep_original_styles(keep_styles)
+                .with_trailing_newline(!no_trailing_newline);
+            // --stdin takes precedence over paths, so treat them as absent
+            let paths = if stdin { Vec::new() } else { paths };
+            let stdin_files = stdin_files || stdin_files_nul;
+            let single_file = paths.len() == 1 && !is_batch_path(&paths[0]) && !stdin_files;
+            if jobs.is_some() && single_file {
+                reporter::Reporter::new(common_config.output.clone()).report(
+                    reporter::ReportEvent::Warning {
+                        message: "--jobs has no effect when formatting a single file",
+                    },
+                )?;
+            }
+            let jobs = jobs.map_or(0, usize::from);
+            if diff && cli.in_place {
+                anyhow::bail!("--diff cannot be combined with --in-place (-i)");
+            }
+
+            let labelled_output = output_separator.is_some() || output_header;
+            if labelled_output && cli.in_place {
+                anyhow::bail!(
+                    "--output-separator and --output-header cannot be combined with --in-place (-i)"
+                );
+            }
+            if labelled_output && paths.is_empty() && !stdin_files {
+                anyhow::bail!("--output-separator and --output-header require file paths");
+            }
+
+            // JUnit reports, encoding fallback, size limits, timings, progress,
+            // change filtering and labelled output are produced by batch processing
+            let is_batch = ((reporter == ReportFormat::Junit
+                || encoding_fallback != EncodingFallback::Strict
+                || max_file_size.is_some()
+                || timing
+                || progress
+                || max_errors.is_some()
+                || since_timestamp.is_some()
+                || labelled_output)
+                && !paths.is_empty())
+                // --jobs alone does not switch a single file to batch mode
+                || is_batch_mode(
+                    &paths,
+                    stdin_files,
+                    &include,
+                    &exclude,
+                    if single_file { 0 } else { jobs },
+                );
+
+            if encoding_fallback != EncodingFallback::Strict && paths.is_empty() && !stdin_files {
+                anyhow::bail!("--encoding-fallback requires file paths (stdin must be UTF-8)");
+            }
+            if max_file_size.is_some() && paths.is_empty() && !stdin_files {
+                anyhow::bail!("--max-file-size requires file paths");
+            }
+            if timing && (!cli.in_place || (paths.is_empty() && !stdin_files)) {
+                anyhow::bail!("--timing requires file paths and --in-place (-i)");
+            }
+            if max_errors.is_some() && (!cli.in_place || (paths.is_empty() && !stdin_files)) {
+                anyhow::bail!("--max-errors requires file paths and --in-place (-i)");
+            }
+            if progress && paths.is_empty() && !stdin_files {
+                anyhow::bail!("--progress requires file paths");
+            }
+            if since_timestamp.is_some() && paths.is_empty() {
+                anyhow::bail!("--since-timestamp requires file paths");
+            }
+            if progress && !cfg!(feature = "progress") {
+                anyhow::bail!("--progress requires fy to be built with the `progress` feature");
+            }
 
             if is_batch {
+                // Batch runs rewrite files through fast-yaml-parallel, which always writes
+                // UTF-8; converting a whole tree to UTF-16 in place is not supported.
+                if output_encoding != io::OutputEncoding::Utf8 {
+                    anyhow::bail!(
+                        "--output-encoding is only supported when formatting a single file or stdin"
+                    );
+                }
+                if respect_file_header {
+                    anyhow::bail!(
+                        "--respect-file-header is only supported when formatting a single file or stdin"
+                    );
+                }
                 // BATCH MODE - using composed BatchConfig
                 let mut discovery_config = discovery::DiscoveryConfig::new();
 
@@ -117,19 +255,28 @@ fn run() -> Result<ExitCode> {
                 let batch_config = commands::format_batch::BatchConfig::new(
                     common_config
                         .clone()
-                        .with_formatter(
-                            config::FormatterConfig::new()
-                                .with_indent(indent)
-                                .with_width(width),
-                        )
-                        .with_parallel(config::ParallelConfig::new().with_workers(if jobs == 0 {
-                            None
-                        } else {
-                            Some(jobs)
-                        })),
+                        .with_formatter(formatter)
+                        .with_parallel(
+                            config::ParallelConfig::new()
+                                .with_workers(settings.global().resolve_workers(jobs))
+                                .with_encoding_fallback(encoding_fallback.into())
+                                .with_detailed_timing(timing)
+                                .with_max_errors(
+                                    max_errors.map(|n| usize::try_from(n).unwrap_or(usize::MAX)),
+                                ),
+                        ),
                 )
                 .with_discovery(discovery_config)
                 .with_dry_run(dry_run)
+                .with_diff(diff)
+                .with_output_separator(output_separator.as_deref().unwrap_or_default())
+                .with_output_header(output_header)
+                .with_reporter(reporter)
+                .with_max_file_size(max_file_size)
+                .with_timing(timing.then_some(timing_top))
+                .with_progress(progress)
+                .with_stdin_files_nul(stdin_files_nul)
+                .with_since(since_timestamp)
                 .with_in_place(cli.in_place);
 
                 commands::format_batch::execute_batch(&batch_config, &paths, stdin_files)?
@@ -139,42 +286,126 @@ fn run() -> Result<ExitCode> {
                     anyhow::bail!("--in-place (-i) requires a file argument");
                 }
                 let input = InputSource::from_stdin()?;
-                let output = OutputWriter::from_args(cli.output.clone(), false, None)?;
-                let format_config = common_config.clone().with_formatter(
-                    config::FormatterConfig::new()
-                        .with_indent(indent)
-                        .with_width(width),
-                );
-                let cmd = commands::format::FormatCommand::new(format_config, strip_comments);
-                cmd.execute(&input, &output)?;
-                ExitCode::Success
+                let output = OutputWriter::from_args(cli.output.clone(), false, None)?
+                    .with_encoding(output_encoding);
+                let format_config = common_config.clone().with_formatter(formatter);
+                let cmd = commands::format::FormatCommand::new(format_config, strip_comments)
+                    .with_diff(diff)
+                    .with_respect_file_header(respect_file_header);
+                cmd.execute(&input, &output)?
             } else {
                 // SINGLE FILE MODE - backward compatible
                 let file_path = &paths[0];
                 let input = InputSource::from_file(file_path)?;
                 let output =
-                    OutputWriter::from_args(cli.output.clone(), cli.in_place, Some(file_path))?;
-                let format_config = common_config.clone().with_formatter(
-                    config::FormatterConfig::new()
-                        .with_indent(indent)
-                        .with_width(width),
+                    OutputWriter::from_args(cli.output.clone(), cli.in_place, Some(file_path))?
+                        .with_encoding(output_encoding);
+                let format_config = common_config.clone().with_formatter(formatter);
+                let cmd = commands::format::FormatCommand::new(format_config, strip_comments)
+                    .with_diff(diff)
+                    .with_respect_file_header(respect_file_header);
+                cmd.execute(&input, &output)?
+            }
+        }
+        Some(Command::Check {
+            paths,
+            indent,
+            width,
+            include,
+            exclude,
+            no_recursive,
+            jobs,
+        }) => {
+            if cli.in_place {
+                anyhow::bail!("--in-place is not supported by `fy check`");
+            }
+            let mut discovery_config = discovery::DiscoveryConfig::new();
+            if !include.is_empty() {
+                discovery_config = discovery_config.with_include_patterns(include);
+            }
+            if !exclude.is_empty() {
+                discovery_config = discovery_config.with_exclude_patterns(exclude);
+            }
+            if no_recursive {
+                discovery_config = discovery_config.with_max_depth(Some(1));
+            }
+            let check_config = common_config
+                .clone()
+                .with_formatter(resolve_formatter(&settings, indent, width))
+                .with_parallel(
+                    config::ParallelConfig::new()
+                        .with_workers(settings.global().resolve_workers(jobs)),
                 );
-                let cmd = commands::format::FormatCommand::new(format_config, strip_comments);
+            commands::check::CheckCommand::new(check_config)
+                .with_discovery(discovery_config)
+                .execute(&paths)?
+        }
+        Some(Command::Convert {
+            to,
+            file,
+            from,
+            pretty,
+            xml_root,
+            xml_attributes,
+            streaming,
+            multi_document,
+            preserve_order,
+        }) => {
+            let is_msgpack =
+                matches!(to, ConvertFormat::Msgpack) || from == Some(ConvertSource::Msgpack);
+            let is_streaming_json = streaming && matches!(to, ConvertFormat::Json);
+            if is_msgpack && cli.in_place {
+                anyhow::bail!("--in-place is not supported for MessagePack conversion");
+            }
+            let cmd = commands::convert::ConvertCommand::new(common_config, to, pretty)
+                .with_source(from)
+                .with_streaming(streaming)
+                .with_multi_document(multi_document)
+                .with_preserve_order(preserve_order)
+                .with_xml_options(commands::convert::XmlOptions {
+                    root: xml_root,
+                    attributes: xml_attributes,
+                });
+            if is_streaming_json {
+                // Streamed straight from the file or stdin, never held in memory
+                let reader = io::input::open_reader(file.as_deref())?;
+                let output =
+                    OutputWriter::from_args(cli.output.clone(), cli.in_place, file.as_deref())?;
+                cmd.execute_streaming(reader, &output)?;
+            } else if from == Some(ConvertSource::Msgpack) {
+                // Binary input is not UTF-8, so it bypasses InputSource
+                let bytes = io::input::read_binary(file.as_deref())?;
+                let output = OutputWriter::from_args(cli.output.clone(), false, None)?;
+                cmd.execute_msgpack(&bytes, &output)?;
+            } else {
+                let input = InputSource::from_args(file, false)?;
+                let output =
+                    OutputWriter::from_args(cli.output.clone(), cli.in_place, input.file_path())?;
                 cmd.execute(&input, &output)?;
-                ExitCode::Success
             }
-        }
-        Some(Command::Convert { to, file, pretty }) => {
-            let input = InputSource::from_args(file)?;
-            let output =
-                OutputWriter::from_args(cli.output.clone(), cli.in_place, input.file_path())?;
-            let cmd = commands::convert::ConvertCommand::new(common_config, to, pretty);
-            cmd.execute(&input, &output)?;
             ExitCode::Success
         }
+        Some(Command::Diff { old, new, format }) => {
+            if cli.in_place {
+                anyhow::bail!("--in-place is not supported by `fy diff`");
+            }
+            // Like diff(1), trouble reading or parsing a file exits with 2
+            // so that it cannot be mistaken for "the files differ"
+            let diff = || {
+                let old = InputSource::from_file(&old)?;
+                let new = InputSource::from_file(&new)?;
+                let output = OutputWriter::from_args(cli.output.clone(), false, None)?;
+                commands::diff::DiffCommand::new(format).execute(&old, &new, &output)
+            };
+            diff().unwrap_or_else(|err| {
+                eprintln!("{}", format_error(&err, common_config.output.use_color()));
+                ExitCode::DiffFailed
+            })
+        }
         #[cfg(feature = "linter")]
         Some(Command::Lint {
             paths,
+            stdin,
             config: config_path,
             no_config,
             max_line_length,
@@ -185,13 +416,34 @@ fn run() -> Result<ExitCode> {
             exclude,
             no_recursive,
             jobs,
+            reporter,
+            baseline,
+            baseline_update,
+            report_unused_anchors,
+            fail_on_warning,
+            rule_help,
+            list_rules,
         }) => {
+            if let Some(code) = rule_help {
+                print!("{}", commands::lint::rule_help(&code)?);
+                return Ok(ExitCode::Success);
+            }
+            if list_rules {
+                print!("{}", commands::lint::list_rules(cli.verbose));
+                return Ok(ExitCode::Success);
+            }
             if cli.in_place {
                 anyhow::bail!(
                     "--in-place is not supported by `fy lint` (auto-fix is not implemented)"
                 );
             }
-            let is_batch = is_batch_mode(&paths, false, &include, &exclude, jobs);
+            let paths = if stdin { Vec::new() } else { paths };
+            if baseline.is_some() && paths.is_empty() {
+                anyhow::bail!("--baseline requires file paths");
+            }
+            let is_batch = ((reporter == ReportFormat::Junit || baseline.is_some())
+                && !paths.is_empty())
+                || is_batch_mode(&paths, false, &include, &exclude, jobs);
 
             if is_batch {
                 // BATCH MODE — multiple files, directories, or glob patterns
@@ -228,16 +480,18 @@ fn run() -> Result<ExitCode> {
 
                 let batch_config = commands::lint_batch::LintBatchConfig::new(
                     common_config.clone().with_parallel(
-                        config::ParallelConfig::new().with_workers(if jobs == 0 {
-                            None
-                        } else {
-                            Some(jobs)
-                        }),
+                        config::ParallelConfig::new()
+                            .with_workers(settings.global().resolve_workers(jobs)),
                     ),
                     cmd.lint_config,
                     format,
                 )
-                .with_discovery(discovery_config);
+                .with_discovery(discovery_config)
+                .with_reporter(reporter)
+                .with_baseline(baseline)
+                .with_baseline_update(baseline_update)
+                .with_report_unused_anchors(report_unused_anchors)
+                .with_fail_on_warning(fail_on_warning);
 
                 commands::lint_batch::execute_lint_batch(&batch_config, &paths)?
             } else if paths.is_empty() {
@@ -251,7 +505,9 @@ fn run() -> Result<ExitCode> {
                     format,
                     allow_duplicate_keys,
                 };
-                let cmd = commands::lint::LintCommand::build(common_config.clone(), args, &input)?;
+                let cmd = commands::lint::LintCommand::build(common_config.clone(), args, &input)?
+                    .with_report_unused_anchors(report_unused_anchors)
+                    .with_fail_on_warning(fail_on_warning);
                 cmd.execute(&input)?
             } else {
                 // SINGLE FILE MODE
@@ -264,7 +520,9 @@ fn run() -> Result<ExitCode> {
                     format,
                     allow_duplicate_keys,
                 };
-                let cmd = commands::lint::LintCommand::build(common_config.clone(), args, &input)?;
+                let cmd = commands::lint::LintCommand::build(common_config.clone(), args, &input)?
+                    .with_report_unused_anchors(report_unused_anchors)
+                    .with_fail_on_warning(fail_on_warning);
                 cmd.execute(&input)?
             }
         }
@@ -276,14 +534,33 @@ fn run() -> Result<ExitCode> {
                 .clone()
                 .with_formatter(config::FormatterConfig::new().with_indent(2).with_width(80));
             let cmd = commands::format::FormatCommand::new(format_config, false);
-            cmd.execute(&input, &output)?;
-            ExitCode::Success
+            cmd.execute(&input, &output)?
         }
     };
 
     Ok(exit_code)
 }
 
+/// Builds the formatter settings for `fy format` and `fy check`.
+///
+/// Settings come from `--indent`/`--width`, then from the config file (the
+/// one given with `--config`, or the nearest `.fy.yaml` above the current
+/// directory), then from the defaults.
+fn resolve_formatter(
+    settings: &config::Config,
+    indent: Option<u8>,
+    width: Option<usize>,
+) -> config::FormatterConfig {
+    let mut formatter = settings.apply(config::FormatterConfig::new());
+    if let Some(indent) = indent {
+        formatter = formatter.with_indent(indent);
+    }
+    if let Some(width) = width {
+        formatter = formatter.with_width(width);
+    }
+    formatter
+}
+
 /// Determines if format command should use batch mode.
 fn is_batch_mode(
     paths: &[std::path::PathBuf],
@@ -322,10 +599,5 @@ fn is_batch_mode(
 
 /// Determines if a path should trigger batch mode.
 fn is_batch_path(path: &std::path::Path) -> bool {
-    path.is_dir() || contains_glob_chars(&path.to_string_lossy())
-}
-
-/// Checks if path contains glob special characters.
-fn contains_glob_chars(s: &str) -> bool {
-    s.contains('*') || s.contains('?') || s.contains('[')
+    path.is_dir() || discovery::is_glob_pattern(&path.to_string_lossy())
 }
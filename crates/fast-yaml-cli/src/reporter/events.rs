@@ -3,6 +3,8 @@
 use std::path::Path;
 use std::time::Duration;
 
+use fast_yaml_parallel::ReadStrategy;
+
 /// Events that can be reported during command execution.
 #[derive(Debug, Clone)]
 pub enum ReportEvent<'a> {
@@ -15,14 +17,20 @@ pub enum ReportEvent<'a> {
         /// Path being processed
         path: &'a Path,
     },
-    /// File processing result
+    /// File processing result, shown as `[current/total] path … outcome`
     FileResult {
+        /// Number of files finished so far, including this one
+        current: usize,
+        /// Total number of files
+        total: usize,
         /// Path that was processed
         path: &'a Path,
         /// Processing outcome
-        outcome: FileOutcome,
+        outcome: FileOutcome<'a>,
         /// Time taken to process
         duration: Duration,
+        /// How the file was read, if it was read
+        read_strategy: Option<ReadStrategy>,
     },
     /// Error occurred
     Error {
@@ -65,6 +73,8 @@ pub enum ReportEvent<'a> {
         would_change: usize,
         /// Files that failed
         failed: usize,
+        /// Files skipped without processing
+        skipped: usize,
         /// Total duration
         duration: Duration,
     },
@@ -72,7 +82,7 @@ pub enum ReportEvent<'a> {
 
 /// File processing outcome (compatible with existing batch result).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum FileOutcome {
+pub enum FileOutcome<'a> {
     /// File was successfully formatted
     Formatted,
     /// File was already correctly formatted
@@ -80,7 +90,10 @@ pub enum FileOutcome {
     /// File was skipped (e.g., not a YAML file)
     Skipped,
     /// Processing failed
-    Failed,
+    Failed {
+        /// Error message
+        message: &'a str,
+    },
 }
 
 #[cfg(test)]
@@ -110,9 +123,12 @@ mod tests {
 
         assert!(matches!(
             ReportEvent::FileResult {
+                current: 1,
+                total: 10,
                 path: &path,
                 outcome: FileOutcome::Formatted,
                 duration: Duration::from_millis(100),
+                read_strategy: None,
             },
             ReportEvent::FileResult { .. }
         ));
@@ -161,6 +177,7 @@ mod tests {
                 unchanged: 3,
                 would_change: 1,
                 failed: 1,
+                skipped: 0,
                 duration: Duration::from_secs(5),
             },
             ReportEvent::BatchSummary { .. }
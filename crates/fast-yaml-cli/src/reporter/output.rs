@@ -2,6 +2,7 @@
 
 use super::events::{FileOutcome, ReportEvent};
 use crate::config::OutputConfig;
+use fast_yaml_parallel::ReadStrategy;
 use std::io::{self, Write};
 use std::path::Path;
 use std::time::{Duration, Instant};
@@ -62,12 +63,15 @@ impl Reporter {
                 }
             }
             ReportEvent::FileResult {
+                current,
+                total,
                 path,
                 outcome,
                 duration,
+                read_strategy,
             } => {
                 if self.config.is_verbose() && !self.config.is_quiet() {
-                    self.write_file_result(path, outcome, duration)?;
+                    self.write_file_result(current, total, path, outcome, duration, read_strategy)?;
                 }
             }
             ReportEvent::Error { path, message } => {
@@ -102,6 +106,7 @@ impl Reporter {
                 unchanged,
                 would_change,
                 failed,
+                skipped,
                 duration,
             } => {
                 if !self.config.is_quiet() || failed > 0 {
@@ -111,6 +116,7 @@ impl Reporter {
                         unchanged,
                         would_change,
                         failed,
+                        skipped,
                         duration,
                     )?;
                 }
@@ -126,23 +132,18 @@ impl Reporter {
 
     fn write_file_result(
         &self,
+        current: usize,
+        total: usize,
         path: &Path,
-        outcome: FileOutcome,
+        outcome: FileOutcome<'_>,
         duration: Duration,
+        read_strategy: Option<ReadStrategy>,
     ) -> io::Result<()> {
         let mut lock = self.stderr.lock();
-        let outcome_str = match outcome {
-            FileOutcome::Formatted => "formatted",
-            FileOutcome::Unchanged => "unchanged",
-            FileOutcome::Skipped => "skipped",
-            FileOutcome::Failed => "failed",
-        };
         writeln!(
             lock,
-            "{} ({} in {:.2}ms)",
-            path.display(),
-            outcome_str,
-            duration.as_secs_f64() * 1000.0
+            "{}",
+            file_result_line(current, total, path, outcome, duration, read_strategy)
         )
     }
 
@@ -253,6 +254,7 @@ impl Reporter {
         unchanged: usize,
         would_change: usize,
         failed: usize,
+        skipped: usize,
         duration: Duration,
     ) -> io::Result<()> {
         let mut lock = self.stderr.lock();
@@ -280,6 +282,9 @@ impl Reporter {
             if failed > 0 {
                 writeln!(lock, "  {} failed", failed.to_string().red())?;
             }
+            if skipped > 0 {
+                writeln!(lock, "  {} skipped", skipped.to_string().dimmed())?;
+            }
             return Ok(());
         }
         #[cfg(not(feature = "colors"))]
@@ -306,10 +311,34 @@ impl Reporter {
         if failed > 0 {
             writeln!(lock, "  {} failed", failed)?;
         }
+        if skipped > 0 {
+            writeln!(lock, "  {} skipped", skipped)?;
+        }
         Ok(())
     }
 }
 
+/// Renders a file result as `[current/total] path … outcome`, followed by
+/// the read strategy in parentheses when it is known.
+fn file_result_line(
+    current: usize,
+    total: usize,
+    path: &Path,
+    outcome: FileOutcome<'_>,
+    duration: Duration,
+    read_strategy: Option<ReadStrategy>,
+) -> String {
+    let ms = duration.as_secs_f64() * 1000.0;
+    let read = read_strategy.map(|s| format!(" ({s})")).unwrap_or_default();
+    let outcome = match outcome {
+        FileOutcome::Formatted => format!("formatted in {ms:.2}ms{read}"),
+        FileOutcome::Unchanged => format!("unchanged in {ms:.2}ms{read}"),
+        FileOutcome::Skipped => "skipped".to_string(),
+        FileOutcome::Failed { message } => format!("failed: {message}"),
+    };
+    format!("[{current}/{total}] {} … {outcome}", path.display())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -374,6 +403,60 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_file_result_line() {
+        let path = PathBuf::from("config.yaml");
+        let duration = Duration::from_micros(1234);
+
+        assert_eq!(
+            file_result_line(2, 5, &path, FileOutcome::Formatted, duration, None),
+            "[2/5] config.yaml … formatted in 1.23ms"
+        );
+        assert_eq!(
+            file_result_line(3, 5, &path, FileOutcome::Unchanged, duration, None),
+            "[3/5] config.yaml … unchanged in 1.23ms"
+        );
+        assert_eq!(
+            file_result_line(
+                2,
+                5,
+                &path,
+                FileOutcome::Formatted,
+                duration,
+                Some(ReadStrategy::MemoryMapped)
+            ),
+            "[2/5] config.yaml … formatted in 1.23ms (mmap)"
+        );
+        assert_eq!(
+            file_result_line(
+                3,
+                5,
+                &path,
+                FileOutcome::Unchanged,
+                duration,
+                Some(ReadStrategy::InMemory)
+            ),
+            "[3/5] config.yaml … unchanged in 1.23ms (in-memory)"
+        );
+        assert_eq!(
+            file_result_line(4, 5, &path, FileOutcome::Skipped, duration, None),
+            "[4/5] config.yaml … skipped"
+        );
+        assert_eq!(
+            file_result_line(
+                5,
+                5,
+                &path,
+                FileOutcome::Failed {
+                    message: "invalid YAML"
+                },
+                duration,
+                None
+            ),
+            "[5/5] config.yaml … failed: invalid YAML"
+        );
+    }
+
     #[test]
     fn test_report_timing_verbose_mode() {
         let config = OutputConfig::new().with_verbose(true);
@@ -9,6 +9,7 @@
 
 use assert_cmd::Command;
 use predicates::prelude::*;
+use std::fmt::Write as _;
 use std::fs;
 use tempfile::TempDir;
 
@@ -144,6 +145,75 @@ fn test_batch_include_pattern() {
     assert_eq!(fs::read_to_string(&txt_file).unwrap(), "key3:  value3\n");
 }
 
+#[test]
+fn test_batch_recursive_glob_argument() {
+    let temp = TempDir::new().unwrap();
+    let nested = temp.path().join("a/b");
+    fs::create_dir_all(&nested).unwrap();
+
+    let root_file = temp.path().join("root.yaml");
+    let nested_file = nested.join("nested.yaml");
+    let yml_file = nested.join("other.yml");
+    fs::write(&root_file, "key1:  value1\n").unwrap();
+    fs::write(&nested_file, "key2:  value2\n").unwrap();
+    fs::write(&yml_file, "key3:  value3\n").unwrap();
+
+    let pattern = format!("{}/**/*.yaml", temp.path().display());
+    fy().args(["format", "-i", &pattern]).assert().success();
+
+    assert_eq!(fs::read_to_string(&root_file).unwrap(), "key1: value1\n");
+    assert_eq!(fs::read_to_string(&nested_file).unwrap(), "key2: value2\n");
+    // Not matched by the pattern
+    assert_eq!(fs::read_to_string(&yml_file).unwrap(), "key3:  value3\n");
+}
+
+#[test]
+fn test_batch_since_timestamp() {
+    let temp = TempDir::new().unwrap();
+    let old_file = temp.path().join("old.yaml");
+    let new_file = temp.path().join("new.yaml");
+
+    fs::write(&old_file, "key1:  value1\n").unwrap();
+    fs::write(&new_file, "key2:  value2\n").unwrap();
+    // 2020-01-01T00:00:00Z
+    let old_time = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_577_836_800);
+    fs::File::options()
+        .write(true)
+        .open(&old_file)
+        .unwrap()
+        .set_modified(old_time)
+        .unwrap();
+
+    fy().args([
+        "format",
+        "-i",
+        "--since-timestamp",
+        "2024-01-01T00:00:00Z",
+        temp.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success();
+
+    assert_eq!(fs::read_to_string(&old_file).unwrap(), "key1:  value1\n");
+    assert_eq!(fs::read_to_string(&new_file).unwrap(), "key2: value2\n");
+}
+
+#[test]
+fn test_batch_since_timestamp_invalid() {
+    let temp = TempDir::new().unwrap();
+
+    fy().args([
+        "format",
+        "-i",
+        "--since-timestamp",
+        "last tuesday",
+        temp.path().to_str().unwrap(),
+    ])
+    .assert()
+    .code(2)
+    .stderr(predicate::str::contains("invalid timestamp 'last tuesday'"));
+}
+
 #[test]
 fn test_batch_dry_run() {
     let temp = TempDir::new().unwrap();
@@ -180,6 +250,26 @@ fn test_batch_stdin_files() {
     assert_eq!(fs::read_to_string(&file2).unwrap(), "key2: value2\n");
 }
 
+#[test]
+fn test_batch_stdin_files_nul() {
+    let temp = TempDir::new().unwrap();
+    let spaced = temp.path().join("my file.yaml");
+    let plain = temp.path().join("plain.yaml");
+
+    fs::write(&spaced, "key1:  value1\n").unwrap();
+    fs::write(&plain, "key2:  value2\n").unwrap();
+
+    let stdin_input = format!("{}\0{}\0", spaced.display(), plain.display());
+
+    fy().args(["format", "-i", "--stdin-files-nul"])
+        .write_stdin(stdin_input)
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_to_string(&spaced).unwrap(), "key1: value1\n");
+    assert_eq!(fs::read_to_string(&plain).unwrap(), "key2: value2\n");
+}
+
 #[test]
 fn test_batch_empty_directory() {
     let temp = TempDir::new().unwrap();
@@ -251,6 +341,73 @@ fn test_batch_quiet_mode() {
         .stderr("");
 }
 
+#[test]
+fn test_batch_jobs_formats_all_files() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path().join("yaml");
+    fs::create_dir(&dir).unwrap();
+
+    for i in 0..20 {
+        fs::write(
+            dir.join(format!("file{i}.yaml")),
+            format!("key{i}:   [a,  b]\n"),
+        )
+        .unwrap();
+    }
+
+    fy().args(["format", "-i", "-j", "2", dir.to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("20 formatted"));
+
+    for i in 0..20 {
+        assert_eq!(
+            fs::read_to_string(dir.join(format!("file{i}.yaml"))).unwrap(),
+            format!("key{i}:\n  - a\n  - b\n")
+        );
+    }
+}
+
+#[test]
+fn test_batch_jobs_out_of_range() {
+    let temp = TempDir::new().unwrap();
+
+    for jobs in ["0", "257"] {
+        fy().args(["format", "-i", "-j", jobs, temp.path().to_str().unwrap()])
+            .assert()
+            .code(2)
+            .stderr(predicate::str::contains("1..=256"));
+    }
+}
+
+#[test]
+fn test_jobs_single_file_warns() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("test.yaml");
+    fs::write(&file, "key:  value\n").unwrap();
+
+    fy().args(["format", "-j", "4", file.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout("key: value\n")
+        .stderr(predicate::str::contains(
+            "warning: --jobs has no effect when formatting a single file",
+        ));
+}
+
+#[test]
+fn test_jobs_single_file_keeps_single_file_checks() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("test.yaml");
+    fs::write(&file, "key:  value # comment\n").unwrap();
+
+    fy().args(["format", "-j", "4", file.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains("YAML comments will be stripped"));
+}
+
 #[test]
 fn test_batch_verbose_mode() {
     let temp = TempDir::new().unwrap();
@@ -262,6 +419,46 @@ fn test_batch_verbose_mode() {
         .success();
 }
 
+#[test]
+fn test_batch_verbose_reports_each_file() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path().join("yaml");
+    fs::create_dir(&dir).unwrap();
+    fs::write(dir.join("changed.yaml"), "key:  value\n").unwrap();
+    fs::write(dir.join("same.yaml"), "key: value\n").unwrap();
+    fs::write(dir.join("broken.yaml"), "key: [\n").unwrap();
+
+    fy().args(["format", "-i", "-v", dir.to_str().unwrap()])
+        .assert()
+        .code(1)
+        .stderr(
+            predicate::str::is_match(
+                r"\[[1-3]/3\] \S*changed\.yaml … formatted in \d+\.\d{2}ms \(in-memory\)",
+            )
+            .unwrap(),
+        )
+        .stderr(
+            predicate::str::is_match(
+                r"\[[1-3]/3\] \S*same\.yaml … unchanged in \d+\.\d{2}ms \(in-memory\)",
+            )
+            .unwrap(),
+        )
+        .stderr(predicate::str::is_match(r"\[[1-3]/3\] \S*broken\.yaml … failed: ").unwrap());
+}
+
+#[test]
+fn test_batch_file_results_hidden_without_verbose() {
+    let temp = TempDir::new().unwrap();
+    let dir = temp.path().join("yaml");
+    fs::create_dir(&dir).unwrap();
+    fs::write(dir.join("test.yaml"), "key:  value\n").unwrap();
+
+    fy().args(["format", "-i", dir.to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("[1/1]").not());
+}
+
 #[test]
 fn test_batch_custom_indent() {
     let temp = TempDir::new().unwrap();
@@ -311,3 +508,483 @@ fn test_batch_respects_gitignore() {
     // Ignored file should NOT be formatted (respects .gitignore)
     assert_eq!(fs::read_to_string(&ignored).unwrap(), "key2:  value2\n");
 }
+
+#[test]
+fn test_batch_diff_mode() {
+    let temp = TempDir::new().unwrap();
+    let changed = temp.path().join("changed.yaml");
+    let clean = temp.path().join("clean.yaml");
+
+    fs::write(&changed, "key:  value\n").unwrap();
+    fs::write(&clean, "key: value\n").unwrap();
+
+    let changed_name = changed.to_str().unwrap();
+    fy().args(["format", "--diff", changed_name, clean.to_str().unwrap()])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(format!(
+            "--- a/{changed_name}\n+++ b/{changed_name}\n"
+        )))
+        .stdout(predicate::str::contains("-key:  value\n+key: value\n"))
+        .stdout(predicate::str::contains("clean.yaml").not());
+
+    // Files should NOT be modified
+    assert_eq!(fs::read_to_string(&changed).unwrap(), "key:  value\n");
+}
+
+#[test]
+fn test_batch_diff_mode_no_changes() {
+    let temp = TempDir::new().unwrap();
+    let file1 = temp.path().join("file1.yaml");
+    let file2 = temp.path().join("file2.yaml");
+
+    fs::write(&file1, "key1: value1\n").unwrap();
+    fs::write(&file2, "key2: value2\n").unwrap();
+
+    fy().args([
+        "format",
+        "--diff",
+        file1.to_str().unwrap(),
+        file2.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_batch_junit_reporter() {
+    let temp = TempDir::new().unwrap();
+    let good = temp.path().join("good.yaml");
+    let bad = temp.path().join("bad.yaml");
+
+    fs::write(&good, "key:  value\n").unwrap();
+    fs::write(&bad, "invalid: [\n").unwrap();
+
+    fy().args([
+        "format",
+        "-n",
+        "--reporter",
+        "junit",
+        good.to_str().unwrap(),
+        bad.to_str().unwrap(),
+    ])
+    .assert()
+    .code(1)
+    .stdout(predicate::str::starts_with(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+    ))
+    .stdout(predicate::str::contains(
+        r#"<testsuite name="fy format" tests="2" failures="1" errors="0""#,
+    ))
+    .stdout(predicate::str::contains(format!(
+        r#"<testcase name="{}" classname="fy format"/>"#,
+        good.display()
+    )))
+    .stdout(predicate::str::contains("<failure message="));
+}
+
+#[test]
+fn test_batch_latin1_strict_fails() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("latin1.yaml");
+    fs::write(&file, b"city:  K\xf6ln\n").unwrap();
+
+    fy().args(["format", "-i", temp.path().to_str().unwrap()])
+        .assert()
+        .failure();
+
+    assert_eq!(fs::read(&file).unwrap(), b"city:  K\xf6ln\n");
+}
+
+#[test]
+fn test_batch_latin1_fallback_transcodes() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("latin1.yaml");
+    fs::write(&file, b"city:  K\xf6ln\n").unwrap();
+
+    fy().args([
+        "--verbose",
+        "format",
+        "-i",
+        "--encoding-fallback",
+        "latin1",
+        file.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stderr(predicate::str::contains("decoded as ISO-8859-1"));
+
+    assert_eq!(fs::read_to_string(&file).unwrap(), "city: K\u{f6}ln\n");
+}
+
+#[test]
+fn test_batch_encoding_fallback_warning_hidden_without_verbose() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("latin1.yaml");
+    fs::write(&file, b"city: K\xf6ln\n").unwrap();
+
+    fy().args([
+        "format",
+        "-i",
+        "--encoding-fallback",
+        "replace",
+        temp.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stderr(predicate::str::contains("not valid UTF-8").not());
+
+    assert_eq!(fs::read_to_string(&file).unwrap(), "city: K\u{fffd}ln\n");
+}
+
+#[test]
+fn test_encoding_fallback_rejects_stdin() {
+    fy().args(["format", "--encoding-fallback", "latin1"])
+        .write_stdin("key: value\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("requires file paths"));
+}
+
+/// Writes an unformatted YAML file of roughly `len` bytes.
+fn write_padded_yaml(path: &std::path::Path, len: usize) {
+    let mut content = String::from("key:  value\n");
+    while content.len() < len {
+        content.push_str("# padding padding padding padding padding padding\n");
+    }
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+fn test_batch_max_file_size_skips_large_files() {
+    let temp = TempDir::new().unwrap();
+    let small = temp.path().join("small.yaml");
+    let large = temp.path().join("large.yaml");
+    fs::write(&small, "key:  value\n").unwrap();
+    write_padded_yaml(&large, 4096);
+    let original_large = fs::read_to_string(&large).unwrap();
+
+    fy().args([
+        "format",
+        "-i",
+        "--max-file-size",
+        "1K",
+        temp.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stderr(predicate::str::contains("1 skipped"));
+
+    assert_eq!(fs::read_to_string(&small).unwrap(), "key: value\n");
+    assert_eq!(fs::read_to_string(&large).unwrap(), original_large);
+}
+
+#[test]
+fn test_batch_max_file_size_verbose_lists_skipped() {
+    let temp = TempDir::new().unwrap();
+    let large = temp.path().join("large.yaml");
+    write_padded_yaml(&large, 2048);
+
+    fy().args([
+        "--verbose",
+        "format",
+        "--dry-run",
+        "--max-file-size",
+        "1KB",
+        large.to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stderr(predicate::str::contains(
+        "large.yaml: larger than 1024 bytes",
+    ));
+}
+
+#[test]
+fn test_batch_max_file_size_junit() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("small.yaml"), "key: value\n").unwrap();
+    write_padded_yaml(&temp.path().join("large.yaml"), 2048);
+
+    fy().args([
+        "format",
+        "--dry-run",
+        "--reporter",
+        "junit",
+        "--max-file-size",
+        "1024",
+        temp.path().to_str().unwrap(),
+    ])
+    .assert()
+    .success()
+    .stdout(predicate::str::contains(
+        r#"tests="2" failures="0" errors="0" skipped="1""#,
+    ))
+    .stdout(predicate::str::contains("<skipped/>"));
+}
+
+#[test]
+fn test_batch_max_file_size_invalid() {
+    fy().args(["format", "--max-file-size", "lots", "."])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid size 'lots'"));
+}
+
+#[test]
+fn test_batch_timing_lists_slowest_files() {
+    let temp = TempDir::new().unwrap();
+    fs::write(temp.path().join("small.yaml"), "key:  value\n").unwrap();
+    let mut large = String::new();
+    for i in 0..20_000 {
+        writeln!(large, "key{i}:   value{i}").unwrap();
+    }
+    fs::write(temp.path().join("large.yaml"), large).unwrap();
+
+    let output = fy()
+        .args([
+            "format",
+            "-i",
+            "--timing",
+            "--timing-top",
+            "1",
+            temp.path().to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Slowest 1 of 2 files:"));
+    let header = stderr
+        .lines()
+        .find(|line| line.starts_with("File"))
+        .unwrap();
+    for column in [
+        "Read Time",
+        "Parse Time",
+        "Format Time",
+        "Write Time",
+        "Total",
+    ] {
+        assert!(header.contains(column), "missing column {column}");
+    }
+    assert!(stderr.contains("large.yaml"));
+    assert!(!stderr.contains("small.yaml"));
+}
+
+#[test]
+fn test_batch_timing_default_top_lists_all_files() {
+    let temp = TempDir::new().unwrap();
+    for name in ["a.yaml", "b.yaml", "c.yaml"] {
+        fs::write(temp.path().join(name), "key:  value\n").unwrap();
+    }
+
+    fy().args(["format", "-i", "--timing", temp.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Slowest 3 of 3 files:"))
+        .stderr(predicate::str::contains("a.yaml"))
+        .stderr(predicate::str::contains("c.yaml"));
+}
+
+#[test]
+fn test_batch_timing_requires_in_place() {
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join("test.yaml");
+    fs::write(&file, "key:  value\n").unwrap();
+
+    fy().args(["format", "--dry-run", "--timing", file.to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--timing requires"));
+}
+
+#[test]
+fn test_batch_timing_top_requires_timing() {
+    fy().args(["format", "-i", "--timing-top", "3", "."])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--timing"));
+}
+
+#[test]
+fn test_batch_progress_formats_files() {
+    let temp = TempDir::new().unwrap();
+    for name in ["a.yaml", "b.yaml", "c.yaml"] {
+        fs::write(temp.path().join(name), "key:  value\n").unwrap();
+    }
+
+    // The bar is only drawn when stderr is a terminal
+    fy().args(["format", "-i", "-P", temp.path().to_str().unwrap()])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("files/sec").not());
+
+    for name in ["a.yaml", "b.yaml", "c.yaml"] {
+        assert_eq!(
+            fs::read_to_string(temp.path().join(name)).unwrap(),
+            "key: value\n"
+        );
+    }
+}
+
+#[test]
+fn test_batch_progress_requires_paths() {
+    fy().args(["format", "--progress"])
+        .write_stdin("key: value\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--progress requires file paths"));
+}
+
+#[test]
+fn test_batch_max_errors_skips_remaining_files() {
+    let temp = TempDir::new().unwrap();
+    for i in 0..40 {
+        fs::write(temp.path().join(format!("f{i:02}.yaml")), "key: [\n").unwrap();
+    }
+
+    let output = fy()
+        .args([
+            "format",
+            "-i",
+            "--max-errors",
+            "2",
+            temp.path().to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("stopped after"), "{stderr}");
+    let failed = stderr.lines().filter(|l| l.starts_with("error:")).count();
+    assert!((2..40).contains(&failed), "{stderr}");
+}
+
+#[test]
+fn test_batch_max_errors_requires_in_place() {
+    fy().args(["format", "--dry-run", "--max-errors", "1", "."])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--max-errors requires"));
+}
+
+/// Creates `a.yaml` and `b.yaml` with unformatted content in `dir`.
+fn write_two_files(dir: &std::path::Path) {
+    fs::write(dir.join("a.yaml"), "a:   1\n").unwrap();
+    fs::write(dir.join("b.yaml"), "b: [1, 2]\n").unwrap();
+}
+
+#[test]
+fn test_batch_stdout_concatenates_without_separator() {
+    let temp = TempDir::new().unwrap();
+    write_two_files(temp.path());
+
+    fy().current_dir(temp.path())
+        .args(["format", "a.yaml", "b.yaml"])
+        .assert()
+        .success()
+        .stdout("a: 1\nb:\n  - 1\n  - 2\n");
+
+    // Files are not modified
+    assert_eq!(
+        fs::read_to_string(temp.path().join("a.yaml")).unwrap(),
+        "a:   1\n"
+    );
+}
+
+#[test]
+fn test_batch_output_separator() {
+    let temp = TempDir::new().unwrap();
+    write_two_files(temp.path());
+
+    fy().current_dir(temp.path())
+        .args(["format", "--output-separator", "---", "a.yaml", "b.yaml"])
+        .assert()
+        .success()
+        .stdout("a: 1\n---\nb:\n  - 1\n  - 2\n");
+}
+
+#[test]
+fn test_batch_output_separator_escapes() {
+    let temp = TempDir::new().unwrap();
+    write_two_files(temp.path());
+
+    fy().current_dir(temp.path())
+        .args(["format", "--output-separator", r"\n", "a.yaml", "b.yaml"])
+        .assert()
+        .success()
+        .stdout("a: 1\n\nb:\n  - 1\n  - 2\n");
+}
+
+#[test]
+fn test_batch_output_header() {
+    let temp = TempDir::new().unwrap();
+    write_two_files(temp.path());
+
+    // Directory contents are printed in file name order
+    fy().current_dir(temp.path())
+        .args(["format", "--output-header", "."])
+        .assert()
+        .success()
+        .stdout("# === a.yaml ===\na: 1\n# === b.yaml ===\nb:\n  - 1\n  - 2\n");
+}
+
+#[test]
+fn test_batch_output_header_single_file() {
+    let temp = TempDir::new().unwrap();
+    write_two_files(temp.path());
+
+    fy().current_dir(temp.path())
+        .args(["format", "--output-header", "a.yaml"])
+        .assert()
+        .success()
+        .stdout("# === a.yaml ===\na: 1\n");
+}
+
+#[test]
+fn test_batch_output_skips_failed_files() {
+    let temp = TempDir::new().unwrap();
+    write_two_files(temp.path());
+    fs::write(temp.path().join("bad.yaml"), "invalid: [\n").unwrap();
+
+    fy().current_dir(temp.path())
+        .args([
+            "format",
+            "--output-separator",
+            "---",
+            "a.yaml",
+            "bad.yaml",
+            "b.yaml",
+        ])
+        .assert()
+        .failure()
+        .code(1)
+        .stdout("a: 1\n---\nb:\n  - 1\n  - 2\n");
+}
+
+#[test]
+fn test_batch_output_header_rejects_in_place() {
+    let temp = TempDir::new().unwrap();
+    write_two_files(temp.path());
+
+    fy().current_dir(temp.path())
+        .args(["format", "-i", "--output-header", "a.yaml", "b.yaml"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "cannot be combined with --in-place",
+        ));
+}
+
+#[test]
+fn test_batch_output_separator_requires_paths() {
+    fy().args(["format", "--output-separator", "---"])
+        .write_stdin("a: 1\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("require file paths"));
+}
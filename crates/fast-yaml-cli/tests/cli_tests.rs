@@ -59,6 +59,51 @@ fn test_format_stdin() {
         .stdout(predicate::str::contains("name: test"));
 }
 
+#[test]
+fn test_format_stdin_multi_document() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("format")
+        .write_stdin("a:   1\n---\nb:   2\n---\n- x\n")
+        .assert()
+        .success()
+        .stdout("a: 1\n---\nb: 2\n---\n- x\n");
+}
+
+#[test]
+fn test_format_stdin_explicit_start() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--explicit-start"])
+        .write_stdin("a:   1\n---\nb:   2\n")
+        .assert()
+        .success()
+        .stdout("---\na: 1\n---\nb: 2\n");
+}
+
+#[test]
+fn test_format_no_explicit_start_overrides_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = dir.path().join("fy.yaml");
+    std::fs::write(&config, "explicit_start: true\n").unwrap();
+    let config = config.to_str().unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--config-file", config])
+        .write_stdin("a: 1\n---\nb: 2\n")
+        .assert()
+        .success()
+        .stdout("---\na: 1\n---\nb: 2\n");
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--config-file", config, "--no-explicit-start"])
+        .write_stdin("a: 1\n---\nb: 2\n")
+        .assert()
+        .success()
+        .stdout("a: 1\n---\nb: 2\n");
+}
+
 #[test]
 fn test_convert_yaml_to_json() {
     Command::cargo_bin("fy")
@@ -71,6 +116,88 @@ fn test_convert_yaml_to_json() {
         .stdout(predicate::str::contains("\"name\": \"test\""));
 }
 
+#[test]
+fn test_convert_yaml_to_json_streaming() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("json")
+        .args(["--streaming", "--preserve-order", "--pretty=false"])
+        .write_stdin("name: test\nvalue: 123\n---\n- a\n")
+        .assert()
+        .success()
+        .stdout("{\"name\":\"test\",\"value\":123}\n[\"a\"]\n");
+}
+
+#[test]
+fn test_convert_yaml_to_json_streaming_matches_default_output() {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    std::io::Write::write_all(&mut file, b"z: [1, {b: x}]\na: {}\n").unwrap();
+
+    let dom = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json", "--preserve-order"])
+        .arg(file.path())
+        .output()
+        .unwrap();
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json", "--streaming", "--preserve-order"])
+        .arg(file.path())
+        .assert()
+        .success()
+        .stdout(String::from_utf8(dom.stdout).unwrap());
+}
+
+#[test]
+fn test_convert_yaml_to_json_streaming_requires_preserve_order() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json", "--streaming"])
+        .write_stdin("b: 1\na: 2\n")
+        .assert()
+        .failure()
+        .stdout("")
+        .stderr(predicate::str::contains("--preserve-order"));
+}
+
+#[test]
+fn test_convert_yaml_to_xml() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "xml", "--xml-root", "config"])
+        .write_stdin("name: test\ntags: [a, b]\n\"my key\": 1\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("<config>"))
+        .stdout(predicate::str::contains("<name>test</name>"))
+        .stdout(predicate::str::contains("<tags>a</tags>\n  <tags>b</tags>"))
+        .stdout(predicate::str::contains("<item key=\"my key\">1</item>"));
+}
+
+#[test]
+fn test_convert_yaml_to_xml_attributes() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "xml", "--xml-attributes"])
+        .write_stdin("server:\n  port: 80\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("<server port=\"80\"/>"));
+}
+
+#[test]
+fn test_convert_xml_to_yaml() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "yaml"])
+        .write_stdin("<?xml version=\"1.0\"?>\n<root><name>test</name><port>8080</port></root>\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("name: test"))
+        .stdout(predicate::str::contains("port: 8080"));
+}
+
 #[test]
 fn test_convert_json_to_yaml() {
     Command::cargo_bin("fy")
@@ -249,3 +376,189 @@ fn test_lint_verbose_mode() {
         .success()
         .stderr(predicate::str::contains("Lint time:"));
 }
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_rule_help() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--rule-help", "line-length"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("max (integer, default: 80)"))
+        .stdout(predicate::str::contains("Compliant:"));
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_rule_help_unknown_rule() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--rule-help", "no-such-rule"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown rule 'no-such-rule'"));
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_list_rules_verbose() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["--verbose", "lint", "--list-rules"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("duplicate-key"))
+        .stdout(predicate::str::contains("Detects duplicate keys"));
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_junit_reporter() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let clean = temp.path().join("clean.yaml");
+    let dup = temp.path().join("dup.yaml");
+    std::fs::write(&clean, "key: value\n").unwrap();
+    std::fs::write(&dup, "key: value1\nkey: value2\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .arg("--no-config")
+        .arg("--reporter")
+        .arg("junit")
+        .arg(&clean)
+        .arg(&dup)
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains(
+            r#"<testsuite name="fy lint" tests="2" failures="1" errors="0""#,
+        ))
+        .stdout(predicate::str::contains(format!(
+            r#"<testcase name="{}" classname="fy lint"/>"#,
+            clean.display()
+        )))
+        .stdout(predicate::str::contains(
+            "[duplicate-key] duplicate key &apos;key&apos;",
+        ));
+}
+
+/// A `<testcase>` read back from a `JUnit` report: its name, and the
+/// `message` attribute and text of its `<failure>`, if any.
+type JunitCase = (String, Option<(String, String)>);
+
+/// Parses a `JUnit` report with quick-xml, panicking on malformed XML.
+fn parse_junit(xml: &str) -> Vec<JunitCase> {
+    use quick_xml::escape::resolve_predefined_entity;
+    use quick_xml::events::{BytesStart, Event};
+    use quick_xml::{Reader, XmlVersion};
+
+    fn attribute(start: &BytesStart<'_>, key: &str) -> String {
+        let attr = start
+            .try_get_attribute(key)
+            .unwrap()
+            .unwrap_or_else(|| panic!("missing {key} attribute"));
+        attr.normalized_value(XmlVersion::Implicit1_0)
+            .unwrap()
+            .into_owned()
+    }
+
+    let mut reader = Reader::from_str(xml);
+    let mut cases: Vec<JunitCase> = Vec::new();
+    // Failure text arrives in chunks split around entity references
+    let mut failure: Option<(String, String)> = None;
+    loop {
+        match reader.read_event().unwrap() {
+            Event::Start(e) | Event::Empty(e) if e.name().as_ref() == "testcase" => {
+                cases.push((attribute(&e, "name"), None));
+            }
+            Event::Start(e) if e.name().as_ref() == "failure" => {
+                failure = Some((attribute(&e, "message"), String::new()));
+            }
+            Event::End(e) if e.name().as_ref() == "failure" => {
+                cases.last_mut().unwrap().1 = failure.take();
+            }
+            Event::Text(text) => {
+                if let Some((_, content)) = &mut failure {
+                    content.push_str(&text.xml10_content());
+                }
+            }
+            Event::GeneralRef(reference) => {
+                if let Some((_, content)) = &mut failure {
+                    match reference.resolve_char_ref().unwrap() {
+                        Some(ch) => content.push(ch),
+                        None => content.push_str(
+                            resolve_predefined_entity(&reference.xml10_content()).unwrap(),
+                        ),
+                    }
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    cases
+}
+
+#[test]
+#[cfg(all(unix, feature = "linter"))]
+fn test_lint_junit_reporter_round_trips_special_characters() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let dir = temp.path().join(r#"a&b <"c'd">"#);
+    std::fs::create_dir(&dir).unwrap();
+    let dup = dir.join("dup.yaml");
+    std::fs::write(&dup, "\"<&>\": 1\n\"<&>\": 2\n").unwrap();
+
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--no-config", "--reporter", "junit"])
+        .arg(&dup)
+        .assert()
+        .code(2)
+        .get_output()
+        .stdout
+        .clone();
+
+    let cases = parse_junit(&String::from_utf8(output).unwrap());
+    assert_eq!(cases.len(), 1);
+    let (name, failure) = &cases[0];
+    assert_eq!(name, &dup.display().to_string());
+    let (message, text) = failure.as_ref().unwrap();
+    assert!(message.contains("duplicate key '<&>'"), "{message}");
+    assert!(
+        text.contains("[duplicate-key] duplicate key '<&>'"),
+        "{text}"
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn test_format_junit_reporter_round_trips_special_characters() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let dir = temp.path().join(r#"a&b <"c'd">"#);
+    std::fs::create_dir(&dir).unwrap();
+    let good = dir.join("good.yaml");
+    let bad = dir.join("bad.yaml");
+    std::fs::write(&good, "key:  value\n").unwrap();
+    std::fs::write(&bad, "invalid: [\n").unwrap();
+
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "-n", "--reporter", "junit"])
+        .arg(&good)
+        .arg(&bad)
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+
+    let mut cases = parse_junit(&String::from_utf8(output).unwrap());
+    cases.sort();
+    assert_eq!(cases.len(), 2);
+    assert_eq!(cases[0].0, bad.display().to_string());
+    let (message, text) = cases[0].1.as_ref().unwrap();
+    assert!(!message.is_empty());
+    assert_eq!(message, &text.replace('\n', " "));
+    assert_eq!(cases[1], (good.display().to_string(), None));
+}
@@ -71,6 +71,119 @@ fn test_parse_with_stats_flag() {
         .stdout(predicate::str::contains("Max depth:"));
 }
 
+#[test]
+fn test_parse_ast_flag() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("parse")
+        .arg("--ast")
+        .write_stdin("base: &b {x: 1}\nref: *b\nlist:\n  - 'a'\n")
+        .assert()
+        .success()
+        .code(0)
+        .stdout(predicate::str::starts_with("StreamStart"))
+        .stdout(predicate::str::contains("\n    MappingStart @1:1\n"))
+        .stdout(predicate::str::contains("MappingStart &1 @1:10"))
+        .stdout(predicate::str::contains("Alias *1 @2:6"))
+        .stdout(predicate::str::contains(
+            "        Scalar \"a\" (single-quoted) @4:5",
+        ))
+        .stdout(predicate::str::contains("YAML is valid").not());
+}
+
+#[test]
+fn test_parse_ast_invalid_yaml() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("parse")
+        .arg("--ast")
+        .write_stdin("invalid: [unclosed")
+        .assert()
+        .failure()
+        .code(1)
+        .stderr(predicate::str::contains("Failed to parse YAML"));
+}
+
+#[test]
+fn test_parse_ast_conflicts_with_stats() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--ast", "--stats"])
+        .write_stdin("a: 1")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_parse_rejects_alias_bomb() {
+    use std::fmt::Write as _;
+
+    let mut yaml = String::from("l0: &l0 [lol, lol, lol, lol, lol, lol, lol, lol, lol, lol]\n");
+    for level in 1..=9 {
+        let items = vec![format!("*l{}", level - 1); 10].join(", ");
+        writeln!(yaml, "l{level}: &l{level} [{items}]").unwrap();
+    }
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("parse")
+        .write_stdin(yaml)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("more than 1000000 nodes"));
+}
+
+#[test]
+fn test_parse_max_nodes() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--max-nodes", "10"])
+        .write_stdin("a: &a [1, 2, 3]\nb: *a\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("more than 10 nodes"));
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--max-nodes", "11"])
+        .write_stdin("a: &a [1, 2, 3]\nb: *a\n")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_parse_max_depth() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--max-depth", "2"])
+        .write_stdin("a:\n  b:\n    c: 1\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("nesting depth exceeds limit of 2"));
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--max-depth", "3"])
+        .write_stdin("a:\n  b:\n    c: 1\n")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_parse_stdin_flag_overrides_file() {
+    let file = create_temp_yaml("valid: yaml\n");
+
+    // The file is valid, so failure shows stdin was parsed instead
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("parse")
+        .arg(file.path())
+        .arg("--stdin")
+        .write_stdin("invalid: [unclosed\n")
+        .assert()
+        .failure();
+}
+
 #[test]
 fn test_parse_quiet_mode() {
     Command::cargo_bin("fy")
@@ -160,6 +273,140 @@ fn test_parse_complex_nested_yaml() {
         .stdout(predicate::str::contains("YAML is valid"));
 }
 
+// Helper function to write a JSON Schema file into a temporary directory
+fn create_schema(dir: &TempDir, schema: &str) -> std::path::PathBuf {
+    let path = dir.path().join("schema.json");
+    fs::write(&path, schema).unwrap();
+    path
+}
+
+const SERVICE_SCHEMA: &str = r#"{
+    "type": "object",
+    "required": ["name", "port"],
+    "properties": {
+        "name": { "type": "string" },
+        "port": { "type": "integer", "minimum": 1 },
+        "tags": { "type": "array", "items": { "type": "string" } }
+    },
+    "additionalProperties": false
+}"#;
+
+#[test]
+fn test_parse_schema_valid() {
+    let dir = TempDir::new().unwrap();
+    let schema = create_schema(&dir, SERVICE_SCHEMA);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--schema"])
+        .arg(&schema)
+        .write_stdin("name: api\nport: 8080\ntags: [web]\n")
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn test_parse_schema_required_field() {
+    let dir = TempDir::new().unwrap();
+    let schema = create_schema(&dir, SERVICE_SCHEMA);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--schema"])
+        .arg(&schema)
+        .write_stdin("name: api\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "<root>: \"port\" is a required property (required)",
+        ));
+}
+
+#[test]
+fn test_parse_schema_type_mismatch() {
+    let dir = TempDir::new().unwrap();
+    let schema = create_schema(&dir, SERVICE_SCHEMA);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--schema"])
+        .arg(&schema)
+        .write_stdin("name: api\nport: \"8080\"\ntags: [web, 1]\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "/port: \"8080\" is not of type \"integer\" (type)",
+        ))
+        .stderr(predicate::str::contains(
+            "/tags/1: 1 is not of type \"string\" (type)",
+        ));
+}
+
+#[test]
+fn test_parse_schema_additional_properties() {
+    let dir = TempDir::new().unwrap();
+    let schema = create_schema(&dir, SERVICE_SCHEMA);
+    let input = dir.path().join("service.yaml");
+    fs::write(&input, "name: api\nport: 8080\ndebug: true\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--schema"])
+        .arg(&schema)
+        .arg(&input)
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(input.display().to_string()))
+        .stderr(predicate::str::contains("'debug' was unexpected"))
+        .stderr(predicate::str::contains("(additionalProperties)"));
+}
+
+#[test]
+fn test_parse_schema_constraint() {
+    let dir = TempDir::new().unwrap();
+    let schema = create_schema(&dir, SERVICE_SCHEMA);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--schema"])
+        .arg(&schema)
+        .write_stdin("name: api\nport: 0\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("/port:"))
+        .stderr(predicate::str::contains("(minimum)"));
+}
+
+#[test]
+fn test_parse_schema_parse_error_exit_code() {
+    let dir = TempDir::new().unwrap();
+    let schema = create_schema(&dir, SERVICE_SCHEMA);
+
+    // Invalid YAML is a parse error (exit 1), not a schema violation
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--schema"])
+        .arg(&schema)
+        .write_stdin("name: [api\n")
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn test_parse_schema_invalid_schema_file() {
+    let dir = TempDir::new().unwrap();
+    let schema = create_schema(&dir, "{ not json");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["parse", "--schema"])
+        .arg(&schema)
+        .write_stdin("name: api\n")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("Failed to parse schema file"));
+}
+
 // =============================================================================
 // FORMAT COMMAND TESTS
 // =============================================================================
@@ -206,6 +453,33 @@ fn test_format_with_custom_indent() {
         .code(0);
 }
 
+#[test]
+fn test_format_indent_keeps_mappings_in_sequences_valid() {
+    // Large enough to go through the streaming formatter
+    let item = |i, indent: &str| {
+        format!("{indent}- name: item{i}\n{indent}  tags:\n{indent}  {indent}- t\n")
+    };
+    let yaml: String = std::iter::once("list:\n".to_string())
+        .chain((0..100).map(|i| item(i, "  ")))
+        .collect();
+    let expected: String = std::iter::once("list:\n".to_string())
+        .chain((0..100).map(|i| item(i, "    ")))
+        .collect();
+
+    for args in [
+        &["format", "--indent", "4"][..],
+        &["format", "--indent", "4", "--keep-styles"],
+    ] {
+        Command::cargo_bin("fy")
+            .unwrap()
+            .args(args)
+            .write_stdin(yaml.clone())
+            .assert()
+            .success()
+            .stdout(expected.clone());
+    }
+}
+
 #[test]
 fn test_format_with_custom_width() {
     Command::cargo_bin("fy")
@@ -220,246 +494,323 @@ fn test_format_with_custom_width() {
 }
 
 #[test]
-fn test_format_in_place_flag() {
-    let file = create_temp_yaml("name:   test\nvalue:    123");
-    let path = file.path().to_path_buf();
+fn test_format_null_style() {
+    let input = "a: ~\nb: null\nc:\nl:\n  - NULL\n";
+    for (style, expected) in [
+        ("tilde", "a: ~\nb: ~\nc: ~\nl:\n  - ~\n"),
+        ("null", "a: null\nb: null\nc: null\nl:\n  - null\n"),
+        ("empty", "a:\nb:\nc:\nl:\n  -\n"),
+    ] {
+        Command::cargo_bin("fy")
+            .unwrap()
+            .args(["format", "--null-style", style])
+            .write_stdin(input)
+            .assert()
+            .success()
+            .stdout(expected);
+    }
+}
 
+#[test]
+fn test_format_null_style_default_preserves() {
     Command::cargo_bin("fy")
         .unwrap()
         .arg("format")
-        .arg("-i")
-        .arg(&path)
+        .write_stdin("a: ~\nb: null\nc: NULL\n")
         .assert()
         .success()
-        .code(0)
-        .stdout(predicate::str::is_empty());
-
-    // Verify file was modified
-    let content = fs::read_to_string(&path).unwrap();
-    assert!(content.contains("name:"));
+        .stdout("a: ~\nb: null\nc: NULL\n");
 }
 
 #[test]
-fn test_format_to_output_file() {
-    let temp_dir = TempDir::new().unwrap();
-    let output_path = temp_dir.path().join("output.yaml");
+fn test_format_null_style_batch() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("nulls.yaml");
+    fs::write(&path, "a: ~\nb:\n").unwrap();
 
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("format")
-        .arg("--output")
-        .arg(&output_path)
-        .write_stdin("name: test")
+        .args(["format", "-i", "--null-style", "null"])
+        .arg(dir.path())
         .assert()
-        .success()
-        .code(0);
+        .success();
 
-    // Verify output file exists and contains formatted YAML
-    assert!(output_path.exists());
-    let content = fs::read_to_string(&output_path).unwrap();
-    assert!(content.contains("name:"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "a: null\nb: null\n");
 }
 
 #[test]
-fn test_format_invalid_yaml() {
-    Command::cargo_bin("fy")
-        .unwrap()
-        .arg("format")
-        .write_stdin("invalid: [")
-        .assert()
-        .failure()
-        .code(1);
+fn test_format_normalize_strings() {
+    let input = "on: push\nanswer: y\nsize: 1_000\nport: 8080\n";
+    for (mode, expected) in [
+        (
+            "yaml11",
+            "\"on\": push\nanswer: \"y\"\nsize: 1_000\nport: 8080\n",
+        ),
+        (
+            "integers",
+            "on: push\nanswer: y\nsize: \"1_000\"\nport: 8080\n",
+        ),
+        (
+            "all",
+            "\"on\": \"push\"\n\"answer\": \"y\"\n\"size\": \"1_000\"\n\"port\": 8080\n",
+        ),
+    ] {
+        Command::cargo_bin("fy")
+            .unwrap()
+            .args(["format", "--normalize-strings", mode])
+            .write_stdin(input)
+            .assert()
+            .success()
+            .stdout(expected);
+    }
 }
 
 #[test]
-fn test_format_indent_range_validation() {
-    // Test minimum value (2)
+fn test_format_normalize_strings_batch() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("workflow.yaml");
+    fs::write(&path, "on:\n  push:\n    branches: [main]\ndebug: off\n").unwrap();
+
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("format")
-        .arg("--indent")
-        .arg("2")
-        .write_stdin("name: test")
+        .args(["format", "-i", "--normalize-strings", "yaml11"])
+        .arg(dir.path())
         .assert()
         .success();
 
-    // Test maximum value (8)
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "\"on\":\n  push:\n    branches:\n      - main\ndebug: \"off\"\n"
+    );
+}
+
+#[test]
+fn test_format_keep_styles() {
+    let input = "on:   \"push\"\nbranches: [main, 'dev']\nenv: {CI: \"true\"}\nrun: |\n  make\n";
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("format")
-        .arg("--indent")
-        .arg("8")
-        .write_stdin("name: test")
+        .args(["format", "--keep-styles", "--normalize-strings", "all"])
+        .write_stdin(input)
         .assert()
-        .success();
+        .success()
+        .stdout("on: \"push\"\nbranches: [main, 'dev']\nenv: {CI: \"true\"}\nrun: |\n  make\n");
+
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("workflow.yaml");
+    fs::write(&path, "on:\n  push:\n    branches: [ main ]\n").unwrap();
 
-    // Test below minimum (should fail)
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("format")
-        .arg("--indent")
-        .arg("1")
-        .write_stdin("name: test")
+        .args(["format", "-i", "--keep-styles"])
+        .arg(dir.path())
         .assert()
-        .failure();
+        .success();
 
-    // Test above maximum (should fail)
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "on:\n  push:\n    branches: [main]\n"
+    );
+}
+
+#[test]
+fn test_format_no_trailing_newline() {
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("format")
-        .arg("--indent")
-        .arg("9")
-        .write_stdin("name: test")
+        .args(["format", "--no-trailing-newline"])
+        .write_stdin("a:   1\nb: 2\n")
         .assert()
-        .failure();
+        .success()
+        .stdout("a: 1\nb: 2");
 }
 
-// =============================================================================
-// CONVERT COMMAND TESTS
-// =============================================================================
-
 #[test]
-fn test_convert_yaml_to_json() {
+fn test_format_trailing_newline_flags() {
+    // Added by default, and explicitly with --trailing-newline
+    for args in [&["format"][..], &["format", "--trailing-newline"]] {
+        Command::cargo_bin("fy")
+            .unwrap()
+            .args(args)
+            .write_stdin("a:   1")
+            .assert()
+            .success()
+            .stdout("a: 1\n");
+    }
+
+    // The last flag wins
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("json")
-        .write_stdin("name: test\nvalue: 123")
+        .args(["format", "--no-trailing-newline", "--trailing-newline"])
+        .write_stdin("a: 1\n")
         .assert()
         .success()
-        .code(0)
-        .stdout(predicate::str::contains("\"name\""))
-        .stdout(predicate::str::contains("\"test\""));
+        .stdout("a: 1\n");
 }
 
 #[test]
-fn test_convert_json_to_yaml() {
+fn test_format_no_trailing_newline_batch() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("config.yaml");
+    fs::write(&path, "a:   1\n").unwrap();
+
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("yaml")
-        .write_stdin(r#"{"name": "test", "value": 123}"#)
+        .args(["format", "-i", "--no-trailing-newline"])
+        .arg(dir.path())
         .assert()
-        .success()
-        .code(0)
-        .stdout(predicate::str::contains("name:"))
-        .stdout(predicate::str::contains("test"));
+        .success();
+
+    assert_eq!(fs::read_to_string(&path).unwrap(), "a: 1");
 }
 
 #[test]
-fn test_convert_yaml_to_json_file_input() {
-    let file = create_temp_yaml("name: test\nvalue: 123");
+fn test_format_respect_file_header_copyright() {
+    let file = create_temp_yaml(indoc! {"
+        # Copyright (c) 2024 Example Corp.
+        # Licensed under the MIT License.
+
+        name:   test
+        items: [a,   b]
+    "});
 
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("json")
+        .args(["format", "--respect-file-header"])
         .arg(file.path())
         .assert()
         .success()
-        .code(0)
-        .stdout(predicate::str::contains("\"name\""));
+        .stdout(indoc! {"
+            # Copyright (c) 2024 Example Corp.
+            # Licensed under the MIT License.
+
+            name: test
+            items:
+              - a
+              - b
+        "});
 }
 
 #[test]
-fn test_convert_json_to_yaml_file_input() {
-    let file = create_temp_json(r#"{"name": "test", "value": 123}"#);
+fn test_format_respect_file_header_shebang_in_place() {
+    let file = create_temp_yaml("#!/usr/bin/env yaml-runner\nstep:    build\n");
 
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("yaml")
+        .args(["format", "-i", "--respect-file-header"])
         .arg(file.path())
         .assert()
-        .success()
-        .code(0)
-        .stdout(predicate::str::contains("name:"));
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(file.path()).unwrap(),
+        "#!/usr/bin/env yaml-runner\nstep: build\n"
+    );
 }
 
 #[test]
-fn test_convert_with_pretty_flag_true() {
+fn test_format_header_without_flag_still_errors() {
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("json")
-        .arg("--pretty")
-        .write_stdin("name: test\nvalue: 123")
+        .arg("format")
+        .write_stdin("#!/usr/bin/env yaml-runner\nstep: build\n")
         .assert()
-        .success()
-        .code(0)
-        .stdout(predicate::str::contains("{\n"));
+        .failure()
+        .stderr(predicate::str::contains("--strip-comments"));
 }
 
 #[test]
-fn test_convert_with_pretty_flag_false() {
-    Command::cargo_bin("fy")
+fn test_format_output_encoding_utf16_le() {
+    let output = Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("json")
-        .arg("--pretty=false")
-        .write_stdin("name: test\nvalue: 123")
-        .assert()
-        .success()
-        .code(0);
+        .args(["format", "--output-encoding", "utf16-le"])
+        .write_stdin("key:   värde\n")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(&output.stdout[..2], &[0xFF, 0xFE]);
+    let units: Vec<u16> = output.stdout[2..]
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    assert_eq!(String::from_utf16(&units).unwrap(), "key: värde\n");
 }
 
 #[test]
-fn test_convert_complex_yaml_to_json() {
-    let yaml = indoc! {"
-        server:
-          host: localhost
-          port: 8080
-        features:
-          - authentication
-          - logging
-          - monitoring
-    "};
+fn test_format_output_encoding_utf16_be_in_place() {
+    let file = create_temp_yaml("key:   value\n");
 
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("json")
-        .write_stdin(yaml)
+        .args(["format", "-i", "--output-encoding", "utf16-be"])
+        .arg(file.path())
+        .assert()
+        .success();
+
+    let bytes = fs::read(file.path()).unwrap();
+    assert_eq!(&bytes[..4], &[0xFE, 0xFF, 0x00, b'k']);
+    assert_eq!(bytes.len(), 2 + "key: value\n".len() * 2);
+}
+
+#[test]
+fn test_format_output_encoding_utf8_default() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--output-encoding", "utf8"])
+        .write_stdin("key:   value\n")
         .assert()
         .success()
-        .code(0)
-        .stdout(predicate::str::contains("\"server\""))
-        .stdout(predicate::str::contains("\"features\""));
+        .stdout("key: value\n");
 }
 
 #[test]
-fn test_convert_invalid_yaml_to_json() {
+fn test_format_output_encoding_rejected_in_batch_mode() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.yaml"), "key: value\n").unwrap();
+
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("json")
-        .write_stdin("invalid: [")
+        .args(["format", "-i", "--output-encoding", "utf16-le"])
+        .arg(dir.path())
         .assert()
         .failure()
-        .code(1);
+        .stderr(predicate::str::contains(
+            "--output-encoding is only supported when formatting a single file or stdin",
+        ));
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("a.yaml")).unwrap(),
+        "key: value\n"
+    );
 }
 
 #[test]
-fn test_convert_invalid_json_to_yaml() {
+fn test_format_in_place_flag() {
+    let file = create_temp_yaml("name:   test\nvalue:    123");
+    let path = file.path().to_path_buf();
+
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("yaml")
-        .write_stdin("{invalid json}")
+        .arg("format")
+        .arg("-i")
+        .arg(&path)
         .assert()
-        .failure()
-        .code(1);
+        .success()
+        .code(0)
+        .stdout(predicate::str::is_empty());
+
+    // Verify file was modified
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("name:"));
 }
 
 #[test]
-fn test_convert_with_output_file() {
+fn test_format_to_output_file() {
     let temp_dir = TempDir::new().unwrap();
-    let output_path = temp_dir.path().join("output.json");
+    let output_path = temp_dir.path().join("output.yaml");
 
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("convert")
-        .arg("json")
+        .arg("format")
         .arg("--output")
         .arg(&output_path)
         .write_stdin("name: test")
@@ -467,43 +818,1336 @@ fn test_convert_with_output_file() {
         .success()
         .code(0);
 
+    // Verify output file exists and contains formatted YAML
     assert!(output_path.exists());
     let content = fs::read_to_string(&output_path).unwrap();
-    assert!(content.contains("\"name\""));
+    assert!(content.contains("name:"));
+}
+
+#[test]
+fn test_format_stdin_flag_overrides_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("config.yaml");
+    let output_path = temp_dir.path().join("result.yaml");
+    fs::write(&input_path, "from: file\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("format")
+        .arg(&input_path)
+        .arg("--stdin")
+        .arg("--output")
+        .arg(&output_path)
+        .write_stdin("from:   stdin\n")
+        .assert()
+        .success();
+
+    assert_eq!(fs::read_to_string(&output_path).unwrap(), "from: stdin\n");
+    // The file argument is left alone
+    assert_eq!(fs::read_to_string(&input_path).unwrap(), "from: file\n");
+}
+
+#[test]
+fn test_format_stdin_flag_rejects_in_place() {
+    let file = create_temp_yaml("key: value\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--stdin", "-i"])
+        .arg(file.path())
+        .write_stdin("key: value\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--in-place (-i) requires a file argument",
+        ));
+}
+
+#[test]
+fn test_format_stdin_flag_conflicts_with_stdin_files() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--stdin", "--stdin-files"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_format_invalid_yaml() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("format")
+        .write_stdin("invalid: [")
+        .assert()
+        .failure()
+        .code(1);
+}
+
+#[test]
+fn test_format_indent_range_validation() {
+    // Test minimum value (2)
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("format")
+        .arg("--indent")
+        .arg("2")
+        .write_stdin("name: test")
+        .assert()
+        .success();
+
+    // Test maximum value (8)
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("format")
+        .arg("--indent")
+        .arg("8")
+        .write_stdin("name: test")
+        .assert()
+        .success();
+
+    // Test below minimum (should fail)
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("format")
+        .arg("--indent")
+        .arg("1")
+        .write_stdin("name: test")
+        .assert()
+        .failure();
+
+    // Test above maximum (should fail)
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("format")
+        .arg("--indent")
+        .arg("9")
+        .write_stdin("name: test")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_format_diff_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("config.yaml");
+    fs::write(&path, "name:   test\nvalue: 1\n").unwrap();
+    let label = path.display().to_string();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--diff"])
+        .arg(&path)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::starts_with(format!(
+            "--- a/{label}\n+++ b/{label}\n@@ -1,2 +1,2 @@\n"
+        )))
+        .stdout(predicate::str::contains(
+            "-name:   test\n+name: test\n value: 1\n",
+        ));
+
+    // The file is left untouched
+    assert_eq!(
+        fs::read_to_string(&path).unwrap(),
+        "name:   test\nvalue: 1\n"
+    );
+}
+
+#[test]
+fn test_format_diff_already_formatted() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("config.yaml");
+    fs::write(&path, "name: test\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--diff"])
+        .arg(&path)
+        .assert()
+        .code(0)
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_format_diff_stdin() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["format", "--diff"])
+        .write_stdin("{a: 1}\n")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::starts_with(
+            "--- a/<stdin>\n+++ b/<stdin>\n",
+        ))
+        .stdout(predicate::str::contains("-{a: 1}\n+a: 1\n"));
+}
+
+#[test]
+fn test_format_keeps_anchors_aliases_and_document_markers() {
+    for input in [
+        "a: &x 1\nb: *x\n",
+        "---\nbase: &b\n  x: 1.0\nd: *b\n",
+        "---\na: 1\n---\nb: 2\n",
+    ] {
+        Command::cargo_bin("fy")
+            .unwrap()
+            .arg("format")
+            .write_stdin(input)
+            .assert()
+            .success()
+            .stdout(input);
+    }
 }
 
 // =============================================================================
-// LINT COMMAND TESTS (requires 'linter' feature)
+// CHECK COMMAND TESTS
+// =============================================================================
+
+// Helper function to create a `.yaml` file in a temporary directory
+fn create_temp_yaml_file(dir: &TempDir, content: &str) -> std::path::PathBuf {
+    let path = dir.path().join("config.yaml");
+    fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn test_check_formatted_file() {
+    let dir = TempDir::new().unwrap();
+    let file = create_temp_yaml_file(&dir, "name: test\nitems:\n  - a\n  - b\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .code(0)
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_check_unformatted_file() {
+    let dir = TempDir::new().unwrap();
+    let file = create_temp_yaml_file(&dir, "name:   test\nitems: [a, b]\n");
+    let original = fs::read_to_string(&file).unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(file.display().to_string()));
+
+    // The file is left untouched
+    assert_eq!(fs::read_to_string(&file).unwrap(), original);
+}
+
+#[test]
+fn test_check_reports_comments() {
+    let dir = TempDir::new().unwrap();
+    let file = create_temp_yaml_file(&dir, "# settings\nname: test\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(file.display().to_string()))
+        .stderr(predicate::str::contains("comments will be stripped"))
+        .stderr(predicate::str::contains("--strip-comments"));
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .write_stdin("key: value # inline\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(
+            "<stdin>: YAML comments will be stripped",
+        ));
+}
+
+#[test]
+fn test_check_respects_indent() {
+    let dir = TempDir::new().unwrap();
+    let file = create_temp_yaml_file(&dir, "outer:\n    inner: 1\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .code(2);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["check", "--indent", "4"])
+        .arg(&file)
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn test_check_directory_lists_changed_files() {
+    let dir = TempDir::new().unwrap();
+    let formatted = dir.path().join("formatted.yaml");
+    let messy = dir.path().join("messy.yaml");
+    fs::write(&formatted, "key: value\n").unwrap();
+    fs::write(&messy, "key:    value\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .arg(dir.path())
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("messy.yaml"))
+        .stderr(predicate::str::contains("formatted.yaml").not());
+}
+
+#[test]
+fn test_check_stdin() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .write_stdin("key: value\n")
+        .assert()
+        .code(0);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .write_stdin("{key: value}\n")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("<stdin>"));
+}
+
+#[test]
+fn test_check_invalid_yaml() {
+    let dir = TempDir::new().unwrap();
+    let file = create_temp_yaml_file(&dir, "key: [unclosed\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .code(1);
+}
+
 // =============================================================================
+// FORMATTER CONFIG FILE TESTS (.fy.yaml)
+// =============================================================================
+
+#[test]
+fn test_format_discovers_config_in_project_root() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join(".fy.yaml"),
+        "indent: 4\nsort_keys: true\nexplicit_start: true\n",
+    )
+    .unwrap();
+    let nested = dir.path().join("deploy");
+    fs::create_dir(&nested).unwrap();
+    fs::write(nested.join("app.yaml"), "b:\n  y: 1\n  x: 2\na: 3\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(&nested)
+        .arg("format")
+        .arg("app.yaml")
+        .assert()
+        .success()
+        .stdout("---\na: 3\nb:\n    x: 2\n    y: 1\n");
+}
+
+#[test]
+fn test_format_config_file_explicit_end() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(".fy.yaml"), "explicit_end: true\n").unwrap();
+    let file = create_temp_yaml_file(&dir, "a: 1\n---\nb: 2\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("format")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout("a: 1\n...\n---\nb: 2\n...\n");
+}
+
+#[test]
+fn test_format_cli_flags_override_config_file() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(".fy.yaml"), "indent: 4\nsort_keys: true\n").unwrap();
+    let file = create_temp_yaml_file(&dir, "b:\n  y: 1\na: 2\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("format")
+        .arg("--indent")
+        .arg("2")
+        .arg(&file)
+        .assert()
+        .success()
+        .stdout("a: 2\nb:\n  y: 1\n");
+}
+
+#[test]
+fn test_format_explicit_config_file() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join("style.yaml");
+    fs::write(&config, "sort_keys: true\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("format")
+        .arg("--config-file")
+        .arg(&config)
+        .write_stdin("b: 1\na: 2\n")
+        .assert()
+        .success()
+        .stdout("a: 2\nb: 1\n");
+}
+
+#[test]
+fn test_format_invalid_config_file() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(".fy.yaml"), "indnet: 4\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("format")
+        .write_stdin("a: 1\n")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(".fy.yaml"))
+        .stderr(predicate::str::contains("indnet"));
+}
+
+#[test]
+fn test_format_verbose_reports_config_file() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(".fy.yml"), "width: 100\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("-v")
+        .arg("format")
+        .write_stdin("a: 1\n")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("using config file:"))
+        .stderr(predicate::str::contains(".fy.yml"));
+}
+
+#[test]
+fn test_check_uses_config_file() {
+    let dir = TempDir::new().unwrap();
+    let file = create_temp_yaml_file(&dir, "a: 1\nb:\n    c: 2\n");
+
+    // Indented with 4 spaces: unformatted under the default settings
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .code(2);
+
+    fs::write(dir.path().join(".fy.yaml"), "indent: 4\n").unwrap();
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("check")
+        .arg(&file)
+        .assert()
+        .code(0);
+}
+
+// =============================================================================
+// CONVERT COMMAND TESTS
+// =============================================================================
+
+#[test]
+fn test_convert_yaml_to_json() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("json")
+        .write_stdin("name: test\nvalue: 123")
+        .assert()
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("\"name\""))
+        .stdout(predicate::str::contains("\"test\""));
+}
+
+#[test]
+fn test_convert_json_to_yaml() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("yaml")
+        .write_stdin(r#"{"name": "test", "value": 123}"#)
+        .assert()
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("name:"))
+        .stdout(predicate::str::contains("test"));
+}
+
+#[test]
+fn test_convert_yaml_to_json_file_input() {
+    let file = create_temp_yaml("name: test\nvalue: 123");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("json")
+        .arg(file.path())
+        .assert()
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("\"name\""));
+}
+
+#[test]
+fn test_convert_json_to_yaml_file_input() {
+    let file = create_temp_json(r#"{"name": "test", "value": 123}"#);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("yaml")
+        .arg(file.path())
+        .assert()
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("name:"));
+}
+
+#[test]
+fn test_convert_with_pretty_flag_true() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("json")
+        .arg("--pretty")
+        .write_stdin("name: test\nvalue: 123")
+        .assert()
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("{\n"));
+}
+
+#[test]
+fn test_convert_with_pretty_flag_false() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("json")
+        .arg("--pretty=false")
+        .write_stdin("name: test\nvalue: 123")
+        .assert()
+        .success()
+        .code(0);
+}
+
+#[test]
+fn test_convert_preserve_order() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json", "--pretty=false", "--preserve-order"])
+        .write_stdin("z: 1\na: 2\nm: 3\n")
+        .assert()
+        .success()
+        .stdout("{\"z\":1,\"a\":2,\"m\":3}\n");
+}
+
+#[test]
+fn test_convert_sorts_keys_by_default() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json", "--pretty=false"])
+        .write_stdin("z: 1\na: 2\nm: 3\n")
+        .assert()
+        .success()
+        .stdout("{\"a\":2,\"m\":3,\"z\":1}\n");
+}
+
+#[test]
+fn test_convert_multi_document_requires_flag() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json"])
+        .write_stdin("a: 1\n---\nb: 2\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("2 YAML documents"))
+        .stderr(predicate::str::contains("--multi-document"));
+}
+
+#[test]
+fn test_convert_multi_document_to_json_array() {
+    for flag in ["--multi-document", "-m"] {
+        Command::cargo_bin("fy")
+            .unwrap()
+            .args(["convert", "json", "--pretty=false", flag])
+            .write_stdin("a: 1\n---\nb: 2\n")
+            .assert()
+            .success()
+            .stdout("[{\"a\":1},{\"b\":2}]\n");
+    }
+}
+
+#[test]
+fn test_convert_multi_document_wraps_single_document() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json", "--pretty=false", "-m"])
+        .write_stdin("- x\n- y\n")
+        .assert()
+        .success()
+        .stdout("[[\"x\",\"y\"]]\n");
+}
+
+#[test]
+fn test_convert_multi_document_only_for_json() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "xml", "-m"])
+        .write_stdin("a: 1\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--multi-document is only supported when converting to JSON",
+        ));
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json", "-m", "--streaming"])
+        .write_stdin("a: 1\n")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_convert_json_to_yaml_preserve_order() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "yaml", "--preserve-order"])
+        .write_stdin(r#"{"z": 1, "a": 2, "m": 3}"#)
+        .assert()
+        .success()
+        .stdout("z: 1\na: 2\nm: 3\n");
+}
+
+#[test]
+fn test_convert_complex_yaml_to_json() {
+    let yaml = indoc! {"
+        server:
+          host: localhost
+          port: 8080
+        features:
+          - authentication
+          - logging
+          - monitoring
+    "};
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("json")
+        .write_stdin(yaml)
+        .assert()
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("\"server\""))
+        .stdout(predicate::str::contains("\"features\""));
+}
+
+#[test]
+fn test_convert_invalid_yaml_to_json() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("json")
+        .write_stdin("invalid: [")
+        .assert()
+        .failure()
+        .code(1);
+}
+
+#[test]
+fn test_convert_invalid_json_to_yaml() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("yaml")
+        .write_stdin("{invalid json}")
+        .assert()
+        .failure()
+        .code(1);
+}
+
+#[test]
+fn test_convert_with_output_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("output.json");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("convert")
+        .arg("json")
+        .arg("--output")
+        .arg(&output_path)
+        .write_stdin("name: test")
+        .assert()
+        .success()
+        .code(0);
+
+    assert!(output_path.exists());
+    let content = fs::read_to_string(&output_path).unwrap();
+    assert!(content.contains("\"name\""));
+}
+
+#[test]
+fn test_convert_yaml_to_msgpack_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("out.msgpack");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "msgpack", "--output"])
+        .arg(&output_path)
+        .write_stdin("name: app\nport: 8080\ntags: [a, b]\n")
+        .assert()
+        .success();
+
+    let bytes = fs::read(&output_path).unwrap();
+    let mut rd = bytes.as_slice();
+    let mut buf = [0u8; 16];
+    assert_eq!(rmp::decode::read_map_len(&mut rd).unwrap(), 3);
+    assert_eq!(rmp::decode::read_str(&mut rd, &mut buf).unwrap(), "name");
+    assert_eq!(rmp::decode::read_str(&mut rd, &mut buf).unwrap(), "app");
+    assert_eq!(rmp::decode::read_str(&mut rd, &mut buf).unwrap(), "port");
+    assert_eq!(rmp::decode::read_int::<i64, _>(&mut rd).unwrap(), 8080);
+    assert_eq!(rmp::decode::read_str(&mut rd, &mut buf).unwrap(), "tags");
+    assert_eq!(rmp::decode::read_array_len(&mut rd).unwrap(), 2);
+    assert_eq!(rmp::decode::read_str(&mut rd, &mut buf).unwrap(), "a");
+    assert_eq!(rmp::decode::read_str(&mut rd, &mut buf).unwrap(), "b");
+    assert!(rd.is_empty());
+}
+
+#[test]
+fn test_convert_msgpack_file_to_yaml() {
+    let mut bytes = Vec::new();
+    rmp::encode::write_map_len(&mut bytes, 2).unwrap();
+    rmp::encode::write_str(&mut bytes, "name").unwrap();
+    rmp::encode::write_str(&mut bytes, "app").unwrap();
+    rmp::encode::write_str(&mut bytes, "ratio").unwrap();
+    rmp::encode::write_f64(&mut bytes, 0.5).unwrap();
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("in.msgpack");
+    fs::write(&input_path, &bytes).unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "yaml", "--from", "msgpack"])
+        .arg(&input_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("name: app"))
+        .stdout(predicate::str::contains("ratio: 0.5"));
+}
+
+#[test]
+fn test_convert_msgpack_base64_stdout_roundtrip() {
+    let yaml = "name: app\nport: 8080\nenabled: true\n";
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "msgpack"])
+        .write_stdin(yaml)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let base64 = String::from_utf8(output.stdout).unwrap();
+    assert!(base64.is_ascii() && base64.ends_with('\n'));
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "yaml", "--from", "msgpack"])
+        .write_stdin(base64)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("name: app"))
+        .stdout(predicate::str::contains("port: 8080"))
+        .stdout(predicate::str::contains("enabled: true"));
+}
+
+#[test]
+fn test_convert_msgpack_rejects_null_key() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "msgpack"])
+        .write_stdin("~: value\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "null keys cannot be converted to MessagePack",
+        ));
+}
+
+#[test]
+fn test_convert_msgpack_rejects_binary_input() {
+    let mut bytes = Vec::new();
+    rmp::encode::write_bin(&mut bytes, &[0xde, 0xad]).unwrap();
+    let file = NamedTempFile::new().unwrap();
+    fs::write(file.path(), &bytes).unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "yaml", "--from", "msgpack"])
+        .arg(file.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "binary values cannot be converted to YAML",
+        ));
+}
+
+#[test]
+fn test_convert_yaml_to_toml() {
+    let yaml = indoc! {"
+        name: app
+        ratio: 0.5
+        servers:
+          - host: a.example
+            port: 80
+    "};
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "toml"])
+        .write_stdin(yaml)
+        .assert()
+        .success()
+        .stdout(indoc! {r#"
+            name = "app"
+            ratio = 0.5
+
+            [[servers]]
+            host = "a.example"
+            port = 80
+        "#});
+}
+
+#[test]
+fn test_convert_toml_roundtrip() {
+    let yaml = "db:\n  port: 5432\n  tags:\n    - a\n    - b\nenabled: true\nname: app\n";
+    let toml = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "toml"])
+        .write_stdin(yaml)
+        .output()
+        .unwrap();
+    assert!(toml.status.success());
+
+    // `.toml` files are read as TOML without --from
+    let temp_dir = TempDir::new().unwrap();
+    let toml_path = temp_dir.path().join("config.toml");
+    fs::write(&toml_path, &toml.stdout).unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "yaml"])
+        .arg(&toml_path)
+        .assert()
+        .success()
+        .stdout(yaml);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "yaml", "--from", "toml"])
+        .write_stdin(toml.stdout)
+        .assert()
+        .success()
+        .stdout(yaml);
+}
+
+#[test]
+fn test_convert_toml_rejects_null() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "toml"])
+        .write_stdin("server:\n  host: ~\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "YAML null at 'server.host' cannot be represented in TOML",
+        ));
+}
+
+#[test]
+fn test_convert_toml_rejects_non_string_key() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "toml"])
+        .write_stdin("ports:\n  80: http\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("TOML keys must be strings"));
+}
+
+#[test]
+fn test_convert_toml_rejects_non_mapping_root() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "toml"])
+        .write_stdin("- a\n- b\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("A TOML document must be a table"));
+}
+
+#[test]
+fn test_convert_invalid_toml_to_yaml() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "yaml", "--from", "toml"])
+        .write_stdin("name = \n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to parse TOML"));
+}
+
+#[test]
+fn test_convert_from_requires_yaml_target() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["convert", "json", "--from", "msgpack"])
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--from is only supported when converting to YAML",
+        ));
+}
+
+// =============================================================================
+// DIFF COMMAND TESTS
+// =============================================================================
+
+#[test]
+fn test_diff_identical_files() {
+    let old = create_temp_yaml("name: app\nport: 80\n");
+    let new = create_temp_yaml("name: app\nport: 80\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .success()
+        .code(0)
+        .stdout("");
+}
+
+#[test]
+fn test_diff_ignores_formatting_comments_and_key_order() {
+    let old = create_temp_yaml("# config\nname:   app\nitems: [a, b]\n");
+    let new = create_temp_yaml("items:\n  - a\n  - b\nname: 'app'\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .success()
+        .stdout("");
+}
+
+#[test]
+fn test_diff_changed_value() {
+    let old = create_temp_yaml("name: app\nport: 80\n");
+    let new = create_temp_yaml("name: app\nport: 8080\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .failure()
+        .code(1)
+        .stdout("- port: 80\n+ port: 8080\n");
+}
+
+#[test]
+fn test_diff_added_and_removed_keys() {
+    let old = create_temp_yaml("a: 1\nb: 2\n");
+    let new = create_temp_yaml("a: 1\nc: 3\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(1)
+        .stdout("- b: 2\n+ c: 3\n");
+}
+
+#[test]
+fn test_diff_nested_paths() {
+    let old = create_temp_yaml(indoc! {"
+        database:
+          primary:
+            host: db1
+            port: 5432
+    "});
+    let new = create_temp_yaml(indoc! {"
+        database:
+          primary:
+            host: db2
+            port: 5432
+    "});
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(1)
+        .stdout("- database.primary.host: db1\n+ database.primary.host: db2\n");
+}
+
+#[test]
+fn test_diff_sequence_indices() {
+    let old = create_temp_yaml("servers:\n  - name: a\n  - name: b\n");
+    let new = create_temp_yaml("servers:\n  - name: a\n  - name: c\n  - name: d\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(1)
+        .stdout("- servers[1].name: b\n+ servers[1].name: c\n+ servers[2]: {name: d}\n");
+}
+
+#[test]
+fn test_diff_type_change_quotes_strings() {
+    let old = create_temp_yaml("version: \"1\"\nenabled: \"true\"\n");
+    let new = create_temp_yaml("version: 1\nenabled: true\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(1)
+        .stdout("- version: \"1\"\n+ version: 1\n- enabled: \"true\"\n+ enabled: true\n");
+}
+
+#[test]
+fn test_diff_collection_replaced_by_scalar() {
+    let old = create_temp_yaml("tags: [a, b]\n");
+    let new = create_temp_yaml("tags: none\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(1)
+        .stdout("- tags: [a, b]\n+ tags: none\n");
+}
+
+#[test]
+fn test_diff_json_format() {
+    let old = create_temp_yaml("a: 1\nb: x\n");
+    let new = create_temp_yaml("a: 2\nc: [1]\n");
+
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["diff", "--format", "json"])
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([
+            {"path": "a", "change": "changed", "old": 1, "new": 2},
+            {"path": "b", "change": "removed", "old": "x"},
+            {"path": "c", "change": "added", "new": [1]},
+        ])
+    );
+}
+
+#[test]
+fn test_diff_parse_error_exits_2() {
+    let old = create_temp_yaml("a: 1\n");
+    let new = create_temp_yaml("a: [1\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains("Failed to parse"));
+}
+
+#[test]
+fn test_diff_missing_file_exits_2() {
+    let old = create_temp_yaml("a: 1\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg("does-not-exist.yaml")
+        .assert()
+        .code(2)
+        .stdout("");
+}
+
+#[test]
+fn test_diff_json_format_identical() {
+    let old = create_temp_yaml("a: 1\n");
+    let new = create_temp_yaml("a: 1\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["diff", "--format", "json"])
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .success()
+        .stdout("[]\n");
+}
+
+#[test]
+fn test_diff_root_scalar() {
+    let old = create_temp_yaml("hello\n");
+    let new = create_temp_yaml("world\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(1)
+        .stdout("- .: hello\n+ .: world\n");
+}
+
+#[test]
+fn test_diff_multi_document_paths() {
+    let old = create_temp_yaml("a: 1\n---\nb: 2\n");
+    let new = create_temp_yaml("a: 1\n---\nb: 3\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .code(1)
+        .stdout("- [1].b: 2\n+ [1].b: 3\n");
+}
+
+#[test]
+fn test_diff_output_file() {
+    let old = create_temp_yaml("a: 1\n");
+    let new = create_temp_yaml("a: 2\n");
+    let dir = TempDir::new().unwrap();
+    let out = dir.path().join("diff.txt");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .arg("-o")
+        .arg(&out)
+        .assert()
+        .code(1);
+
+    assert_eq!(fs::read_to_string(&out).unwrap(), "- a: 1\n+ a: 2\n");
+}
+
+#[test]
+fn test_diff_invalid_yaml() {
+    let old = create_temp_yaml("a: 1\n");
+    let new = create_temp_yaml("a: [unclosed\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to parse"));
+}
+
+#[test]
+fn test_diff_missing_file() {
+    let old = create_temp_yaml("a: 1\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("diff")
+        .arg(old.path())
+        .arg("/nonexistent/file.yaml")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to read file"));
+}
+
+#[test]
+fn test_diff_rejects_in_place() {
+    let old = create_temp_yaml("a: 1\n");
+    let new = create_temp_yaml("a: 2\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["diff", "-i"])
+        .arg(old.path())
+        .arg(new.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--in-place is not supported"));
+}
+
+// =============================================================================
+// LINT COMMAND TESTS (requires 'linter' feature)
+// =============================================================================
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_valid_yaml() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .write_stdin("name: test\nvalue: 123\n")
+        .assert()
+        .success()
+        .code(0);
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_with_warnings() {
+    let long_line = "name: this is a very very very very very very very very very very very very very very very very very very very very long line";
+
+    // line-length rule emits info-severity diagnostics.
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .arg("--max-line-length")
+        .arg("80")
+        .write_stdin(long_line)
+        .assert()
+        .success()
+        .code(0)
+        .stdout(predicate::str::contains("line-length"));
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_fail_on_warning() {
+    // colons: too many spaces after colon (warning)
+    let input = "name:   test\n";
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .write_stdin(input)
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("warning[colons]"));
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .arg("--fail-on-warning")
+        .write_stdin(input)
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("warning[colons]"));
+}
 
 #[test]
 #[cfg(feature = "linter")]
-fn test_lint_valid_yaml() {
+fn test_lint_fail_on_warning_ignores_info() {
+    let long_line = "name: this is a very very very very very very very very very very very very very very very very very very very very long line\n";
+
+    // line-length diagnostics are info-level and never fail the run
     Command::cargo_bin("fy")
         .unwrap()
-        .arg("lint")
-        .write_stdin("name: test\nvalue: 123\n")
+        .args(["lint", "--fail-on-warning", "--max-line-length", "80"])
+        .write_stdin(long_line)
         .assert()
-        .success()
-        .code(0);
+        .code(0)
+        .stdout(predicate::str::contains("line-length"));
 }
 
 #[test]
 #[cfg(feature = "linter")]
-fn test_lint_with_warnings() {
-    let long_line = "name: this is a very very very very very very very very very very very very very very very very very very very very long line";
+fn test_lint_fail_on_warning_batch() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.yaml"), "name:   test\n").unwrap();
+    fs::write(dir.path().join("b.yaml"), "name: test\n").unwrap();
 
-    // line-length rule emits info-severity diagnostics.
     Command::cargo_bin("fy")
         .unwrap()
         .arg("lint")
-        .arg("--max-line-length")
-        .arg("80")
-        .write_stdin(long_line)
+        .arg(dir.path())
         .assert()
-        .success()
-        .code(0)
-        .stdout(predicate::str::contains("line-length"));
+        .code(0);
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .arg("--fail-on-warning")
+        .arg(dir.path())
+        .assert()
+        .code(2);
+
+    // Parse errors take precedence over lint violations
+    fs::write(dir.path().join("c.yaml"), "invalid: [\n").unwrap();
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .arg("--fail-on-warning")
+        .arg(dir.path())
+        .assert()
+        .code(1);
 }
 
 #[test]
@@ -532,6 +2176,29 @@ fn test_lint_with_max_line_length_option() {
         .code(0);
 }
 
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_config_loads_rule_file() {
+    let rules = create_config_file("rules:\n  line-length:\n    max: 20\n");
+    let input = "name: a-line-longer-than-twenty-characters\n";
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--config"])
+        .arg(rules.path())
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("maximum length of 20"));
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .write_stdin(input)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("line-length").not());
+}
+
 #[test]
 #[cfg(feature = "linter")]
 fn test_lint_with_indent_size_option() {
@@ -577,6 +2244,101 @@ fn test_lint_json_format() {
         .stdout(predicate::str::starts_with("["));
 }
 
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_json_format_schema() {
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--no-config", "--format", "json"])
+        .write_stdin("a: 1   \nb: 2\n")
+        .output()
+        .unwrap();
+
+    let diagnostics: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let diagnostic = diagnostics
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|d| d["code"] == "trailing-whitespace")
+        .expect("trailing-whitespace diagnostic");
+
+    assert_eq!(diagnostic["file"], "<stdin>");
+    assert_eq!(diagnostic["line"], 1);
+    assert_eq!(diagnostic["column"], 5);
+    let level = match diagnostic["severity"].as_str().unwrap() {
+        "hint" => 0,
+        "info" => 1,
+        "warning" => 2,
+        "error" => 3,
+        other => panic!("unexpected severity {other}"),
+    };
+    assert_eq!(diagnostic["severity_level"], level);
+
+    let suggestion = &diagnostic["suggestion"];
+    assert!(suggestion["description"].is_string());
+    assert!(suggestion.get("replacement").is_some());
+    assert_eq!(suggestion["span"]["start"]["line"], 1);
+    assert_eq!(suggestion["span"]["start"]["column"], 5);
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_json_format_file_path() {
+    let file = create_temp_yaml("a: 1\na: 2\n");
+
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--no-config", "--format", "json"])
+        .arg(file.path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+
+    let diagnostics: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic["file"], file.path().display().to_string());
+    assert_eq!(diagnostic["code"], "duplicate-key");
+    assert_eq!(diagnostic["line"], 2);
+    assert_eq!(diagnostic["severity_level"], 3);
+    assert!(diagnostic.get("suggestion").is_some());
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_github_format() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--no-config", "--format", "github"])
+        .write_stdin("a: 1\na: 2\n")
+        .assert()
+        .code(2)
+        .stdout(predicate::str::starts_with(
+            "::error line=2,col=1,endLine=2,endColumn=2,title=duplicate-key::duplicate key 'a'",
+        ));
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_github_format_batch() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("clean.yaml"), "x: 1\n").unwrap();
+    fs::write(dir.path().join("dup.yaml"), "x: 1\nx: 2\n").unwrap();
+
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--no-config", "--format", "github"])
+        .arg(dir.path())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(lines.len(), 1, "{stdout}");
+    assert!(lines[0].starts_with("::error file="), "{stdout}");
+    assert!(lines[0].contains("dup.yaml,line=2,col=1,"), "{stdout}");
+}
+
 #[test]
 #[cfg(feature = "linter")]
 fn test_lint_quiet_mode() {
@@ -618,6 +2380,142 @@ fn test_lint_file_input() {
         .code(0);
 }
 
+/// Creates a tree with one valid file and three invalid files that
+/// `--ignore-path vendor --ignore-path generated --ignore-path "**/*.min.yaml"` skips.
+#[cfg(feature = "linter")]
+fn create_lint_tree_with_ignored_paths() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("vendor/lib")).unwrap();
+    fs::create_dir_all(root.join("src/generated")).unwrap();
+    fs::write(root.join("config.yaml"), "name: test\n").unwrap();
+    fs::write(root.join("vendor/lib/broken.yaml"), "key: [\n").unwrap();
+    fs::write(root.join("src/generated/broken.yaml"), "key: [\n").unwrap();
+    fs::write(root.join("src/app.min.yaml"), "key: [\n").unwrap();
+    dir
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_ignore_path_multiple() {
+    let dir = create_lint_tree_with_ignored_paths();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args([
+            "lint",
+            "--ignore-path",
+            "vendor",
+            "--ignore-path",
+            "generated",
+            "--ignore-path",
+            "**/*.min.yaml",
+        ])
+        .arg(dir.path())
+        .assert()
+        .success();
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_ignore_path_partial() {
+    let dir = create_lint_tree_with_ignored_paths();
+
+    // The minified file is still linted and fails
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--ignore-path", "vendor", "--exclude", "generated"])
+        .arg(dir.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("app.min.yaml"));
+
+    // Without patterns every broken file is reported
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .arg(dir.path())
+        .assert()
+        .failure();
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_stdin_flag_overrides_file() {
+    let file = create_temp_yaml("key: value\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .arg(file.path())
+        .arg("--stdin")
+        .write_stdin("key: 1\nkey: 2\n")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("duplicate-key"));
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_report_unused_anchors() {
+    let yaml = indoc! {"
+        defaults: &defaults
+          adapter: postgres
+        legacy: &legacy
+          adapter: mysql
+        production: *defaults
+    "};
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--report-unused-anchors"])
+        .write_stdin(yaml)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("unused-anchor"))
+        .stdout(predicate::str::contains(
+            "anchor 'legacy' defined at line 3 is never used",
+        ))
+        .stdout(predicate::str::contains("anchor 'defaults'").not());
+
+    // Off by default
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("lint")
+        .write_stdin(yaml)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("unused-anchor").not());
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_report_unused_anchors_batch_json() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.yaml"), "x: &x 1\ny: *x\n").unwrap();
+    fs::write(dir.path().join("b.yaml"), "x: &unused 1\n").unwrap();
+
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--report-unused-anchors", "--format", "json"])
+        .arg(dir.path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let diagnostics: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let unused: Vec<_> = diagnostics
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["code"] == "unused-anchor")
+        .collect();
+    assert_eq!(unused.len(), 1);
+    assert!(unused[0]["file"].as_str().unwrap().ends_with("b.yaml"));
+    assert_eq!(unused[0]["severity"], "warning");
+    assert_eq!(unused[0]["span"]["start"]["line"], 1);
+}
+
 #[test]
 #[cfg(feature = "linter")]
 fn test_lint_trailing_whitespace() {
@@ -632,6 +2530,87 @@ fn test_lint_trailing_whitespace() {
         .stdout(predicate::str::contains("trailing-whitespace"));
 }
 
+/// Lints `config.yaml` in `dir` against `baseline.yaml`.
+#[cfg(feature = "linter")]
+fn lint_with_baseline(dir: &TempDir, extra: &[&str]) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .args(["lint", "config.yaml", "--baseline", "baseline.yaml"])
+        .args(extra)
+        .assert()
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_baseline_suppresses_known_violations() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("config.yaml"), "a: 1\na: 2\nb: 1\nb: 2\n").unwrap();
+
+    lint_with_baseline(&dir, &["--baseline-update"])
+        .success()
+        .stderr(predicate::str::contains("0 removed, 2 added"));
+    lint_with_baseline(&dir, &[]).success();
+
+    // A new violation is still reported
+    fs::write(
+        dir.path().join("config.yaml"),
+        "a: 1\na: 2\nb: 1\nb: 2\nc: 1\nc: 2\n",
+    )
+    .unwrap();
+    lint_with_baseline(&dir, &[])
+        .failure()
+        .stdout(predicate::str::contains("duplicate key 'c'"))
+        .stdout(predicate::str::contains("duplicate key 'a'").not());
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_baseline_update_removes_fixed_violation() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("config.yaml"), "a: 1\na: 2\nb: 1\nb: 2\n").unwrap();
+    lint_with_baseline(&dir, &["--baseline-update"]).success();
+
+    // Fix the duplicate 'b' key
+    fs::write(dir.path().join("config.yaml"), "a: 1\na: 2\nb: 1\n").unwrap();
+    lint_with_baseline(&dir, &["--baseline-update"])
+        .success()
+        .stderr(predicate::str::contains("1 removed, 0 added (1 total)"));
+
+    let baseline = fs::read_to_string(dir.path().join("baseline.yaml")).unwrap();
+    assert!(baseline.contains("duplicate key 'a'"));
+    assert!(!baseline.contains("duplicate key 'b'"));
+
+    // Reintroducing the fixed violation is reported again
+    fs::write(dir.path().join("config.yaml"), "a: 1\na: 2\nb: 1\nb: 2\n").unwrap();
+    lint_with_baseline(&dir, &[])
+        .failure()
+        .stdout(predicate::str::contains("duplicate key 'b'"));
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_baseline_missing_file() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("config.yaml"), "a: 1\n").unwrap();
+
+    lint_with_baseline(&dir, &[])
+        .failure()
+        .stderr(predicate::str::contains("create it with --baseline-update"));
+}
+
+#[test]
+#[cfg(feature = "linter")]
+fn test_lint_baseline_requires_paths() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .args(["lint", "--baseline", "baseline.yaml"])
+        .write_stdin("a: 1\n")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--baseline requires file paths"));
+}
+
 // =============================================================================
 // DEFAULT COMMAND (no subcommand - should format)
 // =============================================================================
@@ -699,6 +2678,139 @@ fn test_verbose_flag() {
         .success();
 }
 
+fn create_config_file(content: &str) -> NamedTempFile {
+    let mut file = tempfile::Builder::new().suffix(".yaml").tempfile().unwrap();
+    write!(file, "{content}").unwrap();
+    file
+}
+
+#[test]
+fn test_config_file_quiet() {
+    let config = create_config_file("quiet: true\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("--config-file")
+        .arg(config.path())
+        .arg("parse")
+        .write_stdin("name: test")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+#[test]
+fn test_config_file_verbose() {
+    let config = create_config_file("verbose: true\nno_color: true\n");
+
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .arg("parse")
+        .arg("--config-file")
+        .arg(config.path())
+        .write_stdin("name: test")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let all = [output.stdout, output.stderr].concat();
+    assert!(String::from_utf8(all).unwrap().contains("parse in"));
+}
+
+#[test]
+fn test_config_file_overridden_by_flag() {
+    let config = create_config_file("verbose: true\n");
+
+    let output = Command::cargo_bin("fy")
+        .unwrap()
+        .arg("--config-file")
+        .arg(config.path())
+        .arg("--quiet")
+        .arg("parse")
+        .write_stdin("name: test")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(
+        !String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("parse in")
+    );
+}
+
+#[test]
+fn test_config_file_workers_applies_to_batch() {
+    let config = create_config_file("workers: 2\n");
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.yaml"), "key:  value\n").unwrap();
+    fs::write(dir.path().join("b.yaml"), "key: value\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("--config-file")
+        .arg(config.path())
+        .arg("format")
+        .arg("-i")
+        .arg(dir.path())
+        .assert()
+        .success();
+    assert_eq!(
+        fs::read_to_string(dir.path().join("a.yaml")).unwrap(),
+        "key: value\n"
+    );
+}
+
+#[test]
+fn test_config_file_unknown_setting() {
+    let config = create_config_file("colour: false\n");
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("--config-file")
+        .arg(config.path())
+        .arg("parse")
+        .write_stdin("name: test")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown field `colour`"));
+}
+
+#[test]
+fn test_config_file_missing() {
+    Command::cargo_bin("fy")
+        .unwrap()
+        .arg("--config-file")
+        .arg("/nonexistent/fast-yaml.yaml")
+        .arg("parse")
+        .write_stdin("name: test")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("failed to read config file"));
+}
+
+#[test]
+fn test_discovered_config_file_applies_to_all_commands() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(".fy.yaml"), "quiet: true\nindent: 4\n").unwrap();
+
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("parse")
+        .write_stdin("name: test")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+    Command::cargo_bin("fy")
+        .unwrap()
+        .current_dir(dir.path())
+        .arg("format")
+        .write_stdin("a:\n  b: 1\n")
+        .assert()
+        .success()
+        .stdout("a:\n    b: 1\n");
+}
+
 #[test]
 fn test_version_flag() {
     Command::cargo_bin("fy")
@@ -750,7 +2862,9 @@ fn test_convert_help() {
         .arg("--help")
         .assert()
         .success()
-        .stdout(predicate::str::contains("Convert between YAML and JSON"));
+        .stdout(predicate::str::contains(
+            "Convert between YAML, JSON, XML, TOML and `MessagePack`",
+        ));
 }
 
 #[test]
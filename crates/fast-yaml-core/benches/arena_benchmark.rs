@@ -24,7 +24,7 @@ fn benchmark_streaming(c: &mut Criterion) {
         let config = EmitterConfig::default();
 
         // Only benchmark if streaming is suitable
-        if !is_streaming_suitable(&yaml) {
+        if !is_streaming_suitable(&yaml, config.streaming_threshold) {
             continue;
         }
 
@@ -0,0 +1,160 @@
+//! Emitter benchmarks with throughput and heap allocation profiling.
+//!
+//! Wall time and throughput (bytes of emitted YAML per second):
+//!
+//! ```bash
+//! cargo bench -p fast-yaml-core --bench emitter_bench
+//! ```
+//!
+//! Heap allocations per emitted document, counted with dhat instead of timing:
+//!
+//! ```bash
+//! cargo bench -p fast-yaml-core --bench emitter_bench --features dhat-heap
+//! ```
+
+use criterion::{Criterion, Throughput, criterion_group};
+use fast_yaml_core::{Emitter, EmitterConfig, Map, Parser, ScalarOwned, Value};
+use std::fmt::Write;
+use std::hint::black_box;
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+fn string(s: impl Into<String>) -> Value {
+    Value::Value(ScalarOwned::String(s.into()))
+}
+
+/// 1K scalars of mixed types in a flat sequence.
+fn scalars_1k() -> Value {
+    Value::Sequence(
+        (0..1_000_i64)
+            .map(|i| match i % 4 {
+                0 => Value::Value(ScalarOwned::Integer(i)),
+                1 => string(format!("value {i}")),
+                2 => Value::Value(ScalarOwned::Boolean(i % 3 == 0)),
+                _ => Value::Value(ScalarOwned::Null),
+            })
+            .collect(),
+    )
+}
+
+/// Mappings nested 50 levels deep, with a sibling scalar at each level.
+fn nested_depth_50() -> Value {
+    (0..50).fold(string("leaf"), |inner, level| {
+        let mut map = Map::new();
+        map.insert(
+            string(format!("name{level}")),
+            string(format!("level {level}")),
+        );
+        map.insert(string(format!("child{level}")), inner);
+        Value::Mapping(map)
+    })
+}
+
+/// A flat sequence of 10K strings.
+fn sequence_10k() -> Value {
+    Value::Sequence((0..10_000).map(|i| string(format!("item-{i}"))).collect())
+}
+
+/// A single mapping with 1K keys.
+fn mapping_1k_keys() -> Value {
+    let mut map = Map::new();
+    for i in 0..1_000 {
+        map.insert(string(format!("key_{i:04}")), string(format!("value {i}")));
+    }
+    Value::Mapping(map)
+}
+
+/// A document built from anchors and aliases (aliases are expanded when parsed).
+fn anchors_and_aliases() -> Value {
+    let mut yaml = String::from(
+        "defaults: &defaults\n  adapter: postgres\n  host: localhost\n  pool: 5\nservices:\n",
+    );
+    for i in 0..500 {
+        writeln!(yaml, "  - name: service-{i}\n    db: *defaults").unwrap();
+    }
+    Parser::parse_str(&yaml).unwrap().unwrap()
+}
+
+fn cases() -> [(&'static str, Value); 5] {
+    [
+        ("scalars_1k", scalars_1k()),
+        ("nested_depth_50", nested_depth_50()),
+        ("sequence_10k", sequence_10k()),
+        ("mapping_1k_keys", mapping_1k_keys()),
+        ("anchors_and_aliases", anchors_and_aliases()),
+    ]
+}
+
+fn benchmark_emit(c: &mut Criterion) {
+    let mut group = c.benchmark_group("emit");
+
+    for (name, value) in cases() {
+        let output_len = Emitter::emit_str(&value).unwrap().len();
+        group.throughput(Throughput::Bytes(output_len as u64));
+        group.bench_function(name, |b| {
+            b.iter(|| Emitter::emit_str(black_box(&value)));
+        });
+    }
+
+    group.finish();
+}
+
+fn benchmark_emit_reindent(c: &mut Criterion) {
+    // Indentation other than 2 adds a re-indentation pass over the output
+    let config = EmitterConfig::new().with_indent(4);
+    let value = mapping_1k_keys();
+    let output_len = Emitter::emit_str_with_config(&value, &config)
+        .unwrap()
+        .len();
+
+    let mut group = c.benchmark_group("emit_indent_4");
+    group.throughput(Throughput::Bytes(output_len as u64));
+    group.bench_function("mapping_1k_keys", |b| {
+        b.iter(|| Emitter::emit_str_with_config(black_box(&value), &config));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_emit, benchmark_emit_reindent);
+
+/// Prints heap allocations made while emitting each benchmark document.
+#[cfg(feature = "dhat-heap")]
+fn report_allocations() {
+    let _profiler = dhat::Profiler::builder().testing().build();
+    let config = EmitterConfig::new().with_indent(4);
+
+    println!(
+        "{:<28} {:>12} {:>14} {:>14}",
+        "case", "allocations", "bytes", "output bytes"
+    );
+    for (name, value) in cases() {
+        for (label, config) in [
+            ("", EmitterConfig::default()),
+            (" (indent 4)", config.clone()),
+        ] {
+            let before = dhat::HeapStats::get();
+            let output = Emitter::emit_str_with_config(&value, &config).unwrap();
+            let after = dhat::HeapStats::get();
+            println!(
+                "{:<28} {:>12} {:>14} {:>14}",
+                format!("{name}{label}"),
+                after.total_blocks - before.total_blocks,
+                after.total_bytes - before.total_bytes,
+                output.len()
+            );
+        }
+    }
+}
+
+fn main() {
+    #[cfg(feature = "dhat-heap")]
+    report_allocations();
+
+    #[cfg(not(feature = "dhat-heap"))]
+    {
+        benches();
+        Criterion::default().configure_from_args().final_summary();
+    }
+}
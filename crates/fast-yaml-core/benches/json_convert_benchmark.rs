@@ -0,0 +1,61 @@
+//! Benchmarks comparing streaming YAML-to-JSON conversion against the DOM path
+//! (`Parser::parse_all` followed by `serde_json` serialization).
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use fast_yaml_core::Parser;
+use fast_yaml_core::serde_value::serialize;
+use fast_yaml_core::streaming::convert_yaml_to_json_streaming;
+use std::fmt::Write;
+use std::hint::black_box;
+
+/// Target input size for the large-file benchmark (50 MB).
+const LARGE_INPUT_BYTES: usize = 50 * 1024 * 1024;
+
+fn generate_large_yaml(target_bytes: usize) -> String {
+    let mut yaml = String::with_capacity(target_bytes + 256);
+    yaml.push_str("records:\n");
+    let mut i = 0usize;
+    while yaml.len() < target_bytes {
+        writeln!(
+            yaml,
+            "  - id: {i}\n    name: \"record {i}\"\n    score: {}.5\n    active: {}\n    tags: [alpha, beta, gamma]",
+            i % 100,
+            i.is_multiple_of(2)
+        )
+        .unwrap();
+        i += 1;
+    }
+    yaml
+}
+
+fn benchmark_large_file(c: &mut Criterion) {
+    let yaml = generate_large_yaml(LARGE_INPUT_BYTES);
+    let mut group = c.benchmark_group("yaml_to_json_50mb");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(yaml.len() as u64));
+
+    // Building the document tree alone, before any JSON is written
+    group.bench_function("dom_parse", |b| {
+        b.iter(|| Parser::parse_all(black_box(&yaml)));
+    });
+
+    // DOM baseline: build the tree, then serialize every document with serde_json
+    group.bench_function("dom_serde_json", |b| {
+        b.iter(|| {
+            let docs = Parser::parse_all(black_box(&yaml)).unwrap();
+            let mut serializer = serde_json::Serializer::new(std::io::sink());
+            for doc in &docs {
+                serialize(doc, &mut serializer).unwrap();
+            }
+        });
+    });
+
+    group.bench_function("streaming", |b| {
+        b.iter(|| convert_yaml_to_json_streaming(black_box(yaml.as_bytes()), std::io::sink()));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, benchmark_large_file);
+criterion_main!(benches);
@@ -1,15 +1,46 @@
 use std::fmt::Write as _;
 
 use crate::error::{EmitError, EmitResult};
-use crate::value::Value;
+use crate::value::{StringNormalization, Value};
 use memchr::memmem;
 use saphyr::{ScalarOwned, YamlEmitter};
 use saphyr_parser::ScalarStyle;
 
+/// Representation of plain null scalars in formatted output.
+///
+/// YAML accepts `~`, `null` (also `Null`/`NULL`) and an empty value for null.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NullStyle {
+    /// Always emit `~`.
+    Tilde,
+    /// Always emit `null`.
+    Null,
+    /// Emit nothing after the key or sequence dash (`key:`, `-`).
+    ///
+    /// Mapping keys, document roots and anchored nulls keep their original
+    /// representation, since they cannot be written as an empty value.
+    Empty,
+    /// Keep the representation reported by the parser (default).
+    ///
+    /// The parser reports empty values as `~`, so those are written as `~`.
+    #[default]
+    Preserve,
+}
+
+impl NullStyle {
+    /// Returns true if `value` is a plain scalar that resolves to null
+    /// under the YAML 1.2 Core Schema.
+    #[must_use]
+    pub fn is_null(value: &str) -> bool {
+        matches!(value, "" | "~" | "null" | "Null" | "NULL")
+    }
+}
+
 /// Configuration for YAML emission.
 ///
 /// Controls formatting, style, and output options when serializing YAML.
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct EmitterConfig {
     /// Indentation width in spaces (default: 2).
     ///
@@ -23,11 +54,12 @@ pub struct EmitterConfig {
 
     /// Maximum line width for wrapping (default: 80).
     ///
-    /// When lines exceed this width, the emitter will attempt to wrap them.
+    /// String values that would overflow this width are emitted as folded
+    /// block scalars (`>-`) wrapped at spaces. Strings without a single space
+    /// to break at, or with line breaks, control characters, or leading or
+    /// trailing spaces, are not wrapped. Mapping keys and flow style output
+    /// are never wrapped.
     /// Valid range: 20-1000 (values outside this range will be clamped).
-    ///
-    /// Note: saphyr has limited control over line wrapping.
-    /// This parameter is accepted for `PyYAML` API compatibility.
     pub width: usize,
 
     /// Default flow style for collections (default: None).
@@ -42,6 +74,11 @@ pub struct EmitterConfig {
     /// When true, prepends `---\n` to the output.
     pub explicit_start: bool,
 
+    /// Add explicit document end marker `...` (default: false).
+    ///
+    /// When true, every document is followed by a `...` line.
+    pub explicit_end: bool,
+
     /// Enable compact inline notation (default: true).
     ///
     /// Controls whether saphyr uses compact notation for
@@ -53,6 +90,62 @@ pub struct EmitterConfig {
     /// When true, strings containing newlines will be rendered
     /// using literal block scalar notation (`|`).
     pub multiline_strings: bool,
+
+    /// Representation of plain null scalars (default: `NullStyle::Preserve`).
+    ///
+    /// Applied by [`Emitter::format_with_config`] when the `streaming`
+    /// feature is enabled.
+    pub null_style: NullStyle,
+
+    /// Plain string scalars to double-quote (default: None).
+    ///
+    /// Applied by [`Emitter::format_with_config`], e.g. to quote YAML 1.1
+    /// booleans such as `yes` when migrating to YAML 1.2.
+    pub string_normalization: Option<StringNormalization>,
+
+    /// Scalar style for every string value (default: None).
+    ///
+    /// `PyYAML`'s `default_style`, applied by the `emit_*` methods. Strings a
+    /// style cannot hold fall back to double quotes: line breaks and control
+    /// characters for `SingleQuoted`, and empty strings or lines starting with
+    /// a space for `Literal` and `Folded`. The block styles also fall back to
+    /// double quotes for mapping keys, document-level scalars and flow style
+    /// output. Numbers, booleans and nulls stay plain so they keep their type,
+    /// and `Plain` leaves strings unchanged.
+    pub default_style: Option<ScalarStyle>,
+
+    /// End non-empty output with a newline (default: true).
+    ///
+    /// When false, the final line break is removed, e.g. for embedding
+    /// YAML in another format.
+    pub trailing_newline: bool,
+
+    /// Sort mapping keys (default: false).
+    ///
+    /// Applied by the `emit_*` methods to the value being emitted (see
+    /// [`ValueExt::sort_keys`](crate::ValueExt::sort_keys) for the ordering)
+    /// and by the formatters, where the streaming formatter buffers each
+    /// mapping's events to reorder them.
+    pub sort_keys: bool,
+
+    /// Keep the original scalar and flow collection styles (default: false).
+    ///
+    /// When true, [`Emitter::format_with_config`] writes every scalar in the
+    /// style reported by the parser, ignoring `string_normalization`, so
+    /// quoting that carries meaning (e.g. `"true"` as a string) is never
+    /// changed. Flow collections (`[a, b]`, `{k: v}`) stay on one line in
+    /// flow style instead of being expanded to block style.
+    pub keep_original_styles: bool,
+
+    /// Minimum input size in bytes for the streaming formatter (default: 1024).
+    ///
+    /// [`Emitter::format_with_config`] formats smaller inputs through the DOM
+    /// when the `streaming` feature is enabled, unless `null_style` or
+    /// `keep_original_styles` need the streaming formatter or the input uses
+    /// anchors, aliases or document markers, which the DOM does not keep; see
+    /// [`is_streaming_suitable`](crate::streaming::is_streaming_suitable).
+    /// `0` streams every input.
+    pub streaming_threshold: usize,
 }
 
 impl Default for EmitterConfig {
@@ -62,8 +155,16 @@ impl Default for EmitterConfig {
             width: 80,
             default_flow_style: None,
             explicit_start: false,
+            explicit_end: false,
             compact: true,
             multiline_strings: false,
+            null_style: NullStyle::Preserve,
+            string_normalization: None,
+            default_style: None,
+            trailing_newline: true,
+            sort_keys: false,
+            keep_original_styles: false,
+            streaming_threshold: 1024,
         }
     }
 }
@@ -102,6 +203,13 @@ impl EmitterConfig {
         self
     }
 
+    /// Set explicit document end marker.
+    #[must_use]
+    pub const fn with_explicit_end(mut self, explicit_end: bool) -> Self {
+        self.explicit_end = explicit_end;
+        self
+    }
+
     /// Set compact inline notation.
     #[must_use]
     pub const fn with_compact(mut self, compact: bool) -> Self {
@@ -115,6 +223,58 @@ impl EmitterConfig {
         self.multiline_strings = multiline_strings;
         self
     }
+
+    /// Set the representation of plain null scalars.
+    #[must_use]
+    pub const fn with_null_style(mut self, null_style: NullStyle) -> Self {
+        self.null_style = null_style;
+        self
+    }
+
+    /// Set which plain string scalars are double-quoted.
+    #[must_use]
+    pub const fn with_string_normalization(
+        mut self,
+        string_normalization: Option<StringNormalization>,
+    ) -> Self {
+        self.string_normalization = string_normalization;
+        self
+    }
+
+    /// Set the scalar style for every string value.
+    #[must_use]
+    pub const fn with_default_style(mut self, default_style: Option<ScalarStyle>) -> Self {
+        self.default_style = default_style;
+        self
+    }
+
+    /// Set whether non-empty output ends with a newline.
+    #[must_use]
+    pub const fn with_trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    /// Set whether mapping keys are sorted.
+    #[must_use]
+    pub const fn with_sort_keys(mut self, sort_keys: bool) -> Self {
+        self.sort_keys = sort_keys;
+        self
+    }
+
+    /// Set whether scalars and flow collections keep their original style.
+    #[must_use]
+    pub const fn with_keep_original_styles(mut self, keep_original_styles: bool) -> Self {
+        self.keep_original_styles = keep_original_styles;
+        self
+    }
+
+    /// Set the minimum input size in bytes for the streaming formatter.
+    #[must_use]
+    pub const fn with_streaming_threshold(mut self, streaming_threshold: usize) -> Self {
+        self.streaming_threshold = streaming_threshold;
+        self
+    }
 }
 
 /// Emitter for YAML documents.
@@ -141,14 +301,39 @@ impl Emitter {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn emit_str_with_config(value: &Value, config: &EmitterConfig) -> EmitResult<String> {
+        let flow = config.default_flow_style == Some(true);
+        let mut owned = if config.sort_keys {
+            let mut copy = value.clone();
+            crate::ValueExt::sort_keys(&mut copy);
+            Some(copy)
+        } else {
+            None
+        };
+        if let Some(style) = config.default_style
+            && style != ScalarStyle::Plain
+        {
+            let mut copy = owned.take().unwrap_or_else(|| value.clone());
+            Self::apply_default_style(&mut copy, style, false, flow);
+            owned = Some(copy);
+        }
+        let value = owned.as_ref().unwrap_or(value);
+
         // When flow style is requested, use the custom path that renders {k: v} / [a, b].
-        if config.default_flow_style == Some(true) {
+        if flow {
             let raw = Self::emit_flow(value)?;
-            let mut output = Self::apply_formatting(raw, config);
-            if !output.is_empty() && !output.ends_with('\n') {
-                output.push('\n');
-            }
-            return Ok(output);
+            let output = Self::apply_formatting(raw, config);
+            return Ok(Self::finish_output(output, config));
+        }
+
+        // saphyr cannot write literal or folded representations
+        if Self::has_block_scalar(value) {
+            let canonical = EmitterConfig {
+                indent: 2,
+                ..*config
+            };
+            let raw = Self::emit_value(value, &canonical, 0)?;
+            let output = Self::apply_formatting(raw, config);
+            return Ok(Self::finish_output(output, config));
         }
 
         let estimated_size = Self::estimate_output_size(value);
@@ -161,7 +346,8 @@ impl Emitter {
             emitter.multiline_strings(config.multiline_strings);
 
             // Convert YamlOwned to Yaml for emission
-            let yaml_borrowed: saphyr::Yaml = value.into();
+            let mut yaml_borrowed: saphyr::Yaml = value.into();
+            Self::fold_long_strings(&mut yaml_borrowed, config, 0);
             emitter
                 .dump(&yaml_borrowed)
                 .map_err(|e| EmitError::Emit(e.to_string()))?;
@@ -170,12 +356,222 @@ impl Emitter {
         // Apply post-processing for configuration options
         output = Self::apply_formatting(output, config);
 
-        // Ensure output always ends with a newline
-        if !output.is_empty() && !output.ends_with('\n') {
-            output.push('\n');
+        Ok(Self::finish_output(output, config))
+    }
+
+    /// Applies `config.trailing_newline` to the end of emitted output.
+    fn finish_output(mut output: String, config: &EmitterConfig) -> String {
+        if config.trailing_newline {
+            if !output.is_empty() && !output.ends_with('\n') {
+                output.push('\n');
+            }
+        } else if output.ends_with('\n') {
+            output.pop();
+        }
+        output
+    }
+
+    /// Rewrite string scalars in `style`, for [`EmitterConfig::default_style`].
+    ///
+    /// `block` is false where a literal or folded scalar cannot be written
+    /// (mapping keys, the document root, tagged values and flow collections).
+    fn apply_default_style(value: &mut Value, style: ScalarStyle, block: bool, flow: bool) {
+        match value {
+            Value::Value(ScalarOwned::String(s)) => {
+                *value = Self::styled_string(s, style, block);
+            }
+            Value::Sequence(seq) => {
+                for item in seq {
+                    Self::apply_default_style(item, style, !flow, flow);
+                }
+            }
+            Value::Mapping(map) => {
+                // Keys are immutable in place, so rebuild the mapping in order
+                *map = std::mem::take(map)
+                    .into_iter()
+                    .map(|(mut key, mut value)| {
+                        Self::apply_default_style(&mut key, style, false, flow);
+                        Self::apply_default_style(&mut value, style, !flow, flow);
+                        (key, value)
+                    })
+                    .collect();
+            }
+            Value::Tagged(_, inner) => Self::apply_default_style(inner, style, false, flow),
+            Value::Value(_) | Value::Representation(..) | Value::Alias(_) | Value::BadValue => {}
+        }
+    }
+
+    /// Representation of `s` in `style`, or double-quoted if `style` cannot
+    /// hold it.
+    fn styled_string(s: &str, style: ScalarStyle, block: bool) -> Value {
+        match style {
+            ScalarStyle::SingleQuoted if !s.contains(char::is_control) => {
+                Value::Representation(s.replace('\'', "''"), ScalarStyle::SingleQuoted, None)
+            }
+            ScalarStyle::Literal | ScalarStyle::Folded
+                if block
+                    && !s.trim().is_empty()
+                    && !s.contains(|c: char| c.is_control() && c != '\n')
+                    && !s.split('\n').any(|line| line.starts_with(' ')) =>
+            {
+                let content = if style == ScalarStyle::Folded {
+                    Self::unfold_line_breaks(s)
+                } else {
+                    s.to_string()
+                };
+                Value::Representation(content, style, None)
+            }
+            _ => Value::Representation(
+                escape_double_quoted(s, false),
+                ScalarStyle::DoubleQuoted,
+                None,
+            ),
+        }
+    }
+
+    /// Re-escape quoted scalars kept by `Parser::parse_all_preserving_styles`.
+    ///
+    /// The parser stores their unescaped value, while the saphyr emitter writes
+    /// `Representation` content verbatim between the quotes.
+    fn escape_quoted_scalars(value: &mut Value) {
+        match value {
+            Value::Representation(
+                s,
+                style @ (ScalarStyle::SingleQuoted | ScalarStyle::DoubleQuoted),
+                _,
+            ) => {
+                *value = Self::styled_string(s, *style, false);
+            }
+            Value::Sequence(seq) => seq.iter_mut().for_each(Self::escape_quoted_scalars),
+            Value::Mapping(map) => {
+                // Keys are immutable in place, so rebuild the mapping in order
+                *map = std::mem::take(map)
+                    .into_iter()
+                    .map(|(mut key, mut value)| {
+                        Self::escape_quoted_scalars(&mut key);
+                        Self::escape_quoted_scalars(&mut value);
+                        (key, value)
+                    })
+                    .collect();
+            }
+            Value::Tagged(_, inner) => Self::escape_quoted_scalars(inner),
+            Value::Value(_) | Value::Representation(..) | Value::Alias(_) | Value::BadValue => {}
+        }
+    }
+
+    /// Double each line break between two lines of text, so a folded scalar
+    /// reads it back as a line break rather than a space.
+    fn unfold_line_breaks(s: &str) -> String {
+        let mut out = String::with_capacity(s.len() + 8);
+        let mut seen_text = false;
+        for (i, line) in s.split('\n').enumerate() {
+            if i > 0 {
+                out.push('\n');
+                if seen_text && !line.is_empty() {
+                    out.push('\n');
+                }
+            }
+            seen_text |= !line.is_empty();
+            out.push_str(line);
+        }
+        out
+    }
+
+    /// Rewrite string values that would overflow `config.width` as folded
+    /// block scalars (`>-`), wrapped at spaces.
+    ///
+    /// `depth` is the number of collections enclosing `node`. saphyr writes
+    /// plain representations verbatim and indents block scalar bodies by 2
+    /// spaces per enclosing collection, so the folded body is built with that
+    /// indentation and `reindent` rescales it like any other block scalar.
+    /// Mapping keys and document-level scalars are never folded.
+    fn fold_long_strings(node: &mut saphyr::Yaml<'_>, config: &EmitterConfig, depth: usize) {
+        let column = depth * config.indent;
+        match node {
+            saphyr::Yaml::Sequence(items) => {
+                for item in items {
+                    Self::fold_value(item, config, depth + 1, column + 2);
+                }
+            }
+            saphyr::Yaml::Mapping(map) => {
+                for (key, value) in map.iter_mut() {
+                    let key_len = key.as_str().map_or(0, |k| k.chars().count());
+                    Self::fold_value(value, config, depth + 1, column + key_len + 2);
+                }
+            }
+            saphyr::Yaml::Tagged(_, inner) => Self::fold_long_strings(inner, config, depth),
+            _ => {}
         }
+    }
 
-        Ok(output)
+    /// Fold a collection entry whose scalar would start at column `start`.
+    fn fold_value(
+        value: &mut saphyr::Yaml<'_>,
+        config: &EmitterConfig,
+        depth: usize,
+        start: usize,
+    ) {
+        let saphyr::Yaml::Value(saphyr::Scalar::String(s)) = value else {
+            Self::fold_long_strings(value, config, depth);
+            return;
+        };
+        if start + s.chars().count() <= config.width {
+            return;
+        }
+        let budget = config.width.saturating_sub(depth * config.indent);
+        if let Some(folded) = Self::fold_string(s, 2 * depth, budget) {
+            *value = saphyr::Yaml::Representation(folded.into(), ScalarStyle::Plain, None);
+        }
+    }
+
+    /// Wrap `s` at single spaces into a `>-` header and folded body lines
+    /// indented by `indent` spaces, aiming for lines of at most `budget` chars.
+    ///
+    /// Returns `None` if a folded scalar cannot hold `s` as-is (line breaks,
+    /// control characters, leading or trailing whitespace) or if there is no
+    /// space to break at.
+    fn fold_string(s: &str, indent: usize, budget: usize) -> Option<String> {
+        if s.starts_with(' ')
+            || s.ends_with(' ')
+            || s.contains(|c: char| {
+                c.is_control() || matches!(c, '\u{feff}' | '\u{2028}' | '\u{2029}')
+            })
+        {
+            return None;
+        }
+
+        // A single space between two non-spaces folds back from a line break;
+        // runs of spaces must stay within a line.
+        let mut segments: Vec<&str> = Vec::new();
+        let mut start = 0;
+        let bytes = s.as_bytes();
+        for (i, &b) in bytes.iter().enumerate() {
+            if b == b' ' && i > start && bytes[i - 1] != b' ' && bytes.get(i + 1) != Some(&b' ') {
+                segments.push(&s[start..i]);
+                start = i + 1;
+            }
+        }
+        if segments.is_empty() {
+            return None;
+        }
+        segments.push(&s[start..]);
+
+        let mut out = String::with_capacity(s.len() + segments.len() * (indent + 1) + 2);
+        out.push_str(">-");
+        let mut line_len = 0;
+        for segment in segments {
+            let len = segment.chars().count();
+            if line_len > 0 && line_len + 1 + len <= budget {
+                out.push(' ');
+                line_len += 1 + len;
+            } else {
+                out.push('\n');
+                out.extend(std::iter::repeat_n(' ', indent));
+                line_len = len;
+            }
+            out.push_str(segment);
+        }
+        Some(out)
     }
 
     /// Estimate output size based on input value structure.
@@ -273,6 +669,7 @@ impl Emitter {
         // Create single config variant for non-first documents (avoids cloning per document)
         let inner_config = EmitterConfig {
             explicit_start: false,
+            trailing_newline: true,
             ..*config
         };
 
@@ -293,7 +690,7 @@ impl Emitter {
             }
         }
 
-        Ok(output)
+        Ok(Self::finish_output(output, config))
     }
 
     /// Emit multiple YAML documents to a string with document separators.
@@ -319,9 +716,73 @@ impl Emitter {
         Self::emit_all_with_config(values, &EmitterConfig::default())
     }
 
+    /// Render a single scalar as YAML text, without document markers or a
+    /// trailing newline.
+    ///
+    /// Unlike [`Emitter::emit_str`] this cannot fail. Strings are quoted when
+    /// they would otherwise read as another type or need escaping, floats
+    /// always read back as floats, and special floats use the YAML 1.2
+    /// spelling (`.inf`, `-.inf`, `.nan`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{Emitter, ScalarOwned};
+    ///
+    /// assert_eq!(Emitter::scalar_to_string(&ScalarOwned::Integer(42)), "42");
+    /// assert_eq!(
+    ///     Emitter::scalar_to_string(&ScalarOwned::String("true".to_string())),
+    ///     "\"true\""
+    /// );
+    /// ```
+    #[must_use]
+    pub fn scalar_to_string(value: &ScalarOwned) -> String {
+        match value {
+            ScalarOwned::Null => "~".to_string(),
+            ScalarOwned::Boolean(b) => b.to_string(),
+            ScalarOwned::Integer(i) => i.to_string(),
+            ScalarOwned::FloatingPoint(f) if f.is_nan() => ".nan".to_string(),
+            ScalarOwned::FloatingPoint(f) if f.is_infinite() => if f.is_sign_negative() {
+                "-.inf"
+            } else {
+                ".inf"
+            }
+            .to_string(),
+            ScalarOwned::FloatingPoint(f) => Self::float_repr(f.into_inner()),
+            ScalarOwned::String(s) => {
+                let yaml = saphyr::Yaml::Value(saphyr::Scalar::String(s.as_str().into()));
+                let mut out = String::new();
+                // saphyr only fails when the writer does, and writing to a
+                // `String` cannot fail
+                let _ = YamlEmitter::new(&mut out).dump(&yaml);
+                out.strip_prefix("---\n").unwrap_or(&out).to_string()
+            }
+        }
+    }
+
+    /// Format a float so that it reads back as a float (YAML Core Schema).
+    ///
+    /// Rust formats e.g. `1.0` as `"1"` and `1.23e10` as `"12300000000"`, so
+    /// `.0` is appended when the string has no decimal point or exponent and
+    /// is not a special value (inf / NaN are handled by `fix_special_floats`).
+    fn float_repr(f: f64) -> String {
+        let s = f.to_string();
+        if s.contains('.')
+            || s.contains('e')
+            || s.contains('E')
+            || s.eq_ignore_ascii_case("inf")
+            || s.eq_ignore_ascii_case("-inf")
+            || s.eq_ignore_ascii_case("nan")
+        {
+            s
+        } else {
+            format!("{s}.0")
+        }
+    }
+
     /// Apply formatting configuration to YAML output.
     ///
-    /// Handles `explicit_start` and potentially other post-processing.
+    /// Handles `explicit_start`, `explicit_end` and potentially other post-processing.
     fn apply_formatting(mut output: String, config: &EmitterConfig) -> String {
         // Handle explicit_start
         if config.explicit_start {
@@ -338,13 +799,20 @@ impl Emitter {
 
         // Fix special float values for YAML 1.2 Core Schema compliance
         // saphyr outputs "inf"/"-inf"/"NaN", but YAML 1.2 requires ".inf"/"-.inf"/".nan"
-        output = Self::fix_special_floats(&output);
+        output = Self::fix_special_floats(output);
 
         // Re-indent when caller requests a width other than saphyr's fixed 2 spaces.
         if config.indent != 2 {
             output = Self::reindent(&output, config.indent);
         }
 
+        if config.explicit_end {
+            if !output.ends_with('\n') {
+                output.push('\n');
+            }
+            output.push_str("...\n");
+        }
+
         output
     }
 
@@ -354,13 +822,16 @@ impl Emitter {
     /// - `inf` → `.inf`
     /// - `-inf` → `-.inf`
     /// - `NaN` → `.nan`
-    fn fix_special_floats(output: &str) -> String {
-        if !Self::might_contain_special_floats(output) {
-            return output.to_string();
+    ///
+    /// Takes the output by value so that the common case, with no special
+    /// floats, returns it without copying.
+    fn fix_special_floats(output: String) -> String {
+        if !Self::might_contain_special_floats(&output) {
+            return output;
         }
 
         // Slow path: line-by-line transformation
-        Self::fix_special_floats_slow(output)
+        Self::fix_special_floats_slow(&output)
     }
 
     /// Quick check if output might contain special float patterns.
@@ -467,53 +938,77 @@ impl Emitter {
         // formatter (and DOM fallback) silently drops them.
         let directives = Self::extract_directives(input);
 
-        // Always prefer the streaming formatter when available.
+        // Prefer the streaming formatter when available and the input is large
+        // enough for it to pay off.
         //
         // The DOM-based path (saphyr's YamlEmitter) quotes YAML 1.1 boolean-like
         // keys (`on`, `off`, `yes`, `no`) even though they are plain strings in
         // YAML 1.2.2 Core Schema. The streaming formatter preserves the original
         // ScalarStyle from the parser, so it never introduces spurious quoting.
-        #[cfg(all(feature = "streaming", feature = "arena"))]
+        // `null_style` and `keep_original_styles` are only implemented by the
+        // streaming formatter, so they stream inputs of any size.
+        #[cfg(feature = "streaming")]
+        if config.null_style != NullStyle::Preserve
+            || config.keep_original_styles
+            || crate::streaming::is_streaming_suitable(input, config.streaming_threshold)
         {
+            #[cfg(feature = "arena")]
             let formatted = crate::streaming::format_streaming_arena(input, config)?;
-            Ok(Self::prepend_directives(&directives, formatted))
-        }
-
-        #[cfg(all(feature = "streaming", not(feature = "arena")))]
-        {
+            #[cfg(not(feature = "arena"))]
             let formatted = crate::streaming::format_streaming(input, config)?;
-            Ok(Self::prepend_directives(&directives, formatted))
+            return Ok(Self::prepend_directives(&directives, formatted));
         }
 
-        // Non-streaming fallback: parse with early_parse=false to preserve block scalar styles
-        // (literal | and folded >) instead of converting them to double-quoted strings.
-        #[cfg(not(feature = "streaming"))]
+        Ok(Self::prepend_directives(
+            &directives,
+            Self::format_dom(input, config)?,
+        ))
+    }
+
+    /// Format every document in `input` through the DOM.
+    ///
+    /// Parses with `early_parse=false` to preserve block scalar styles
+    /// (literal `|` and folded `>`) instead of converting them to
+    /// double-quoted strings.
+    fn format_dom(input: &str, config: &EmitterConfig) -> EmitResult<String> {
+        let mut docs = crate::Parser::parse_all_preserving_styles(input)
+            .map_err(|e| EmitError::Emit(e.to_string()))?;
+        for doc in &mut docs {
+            Self::escape_quoted_scalars(doc);
+        }
+        if let Some(mode) = config.string_normalization
+            && !config.keep_original_styles
         {
-            let docs = crate::Parser::parse_all_preserving_styles(input)
-                .map_err(|e| EmitError::Emit(e.to_string()))?;
-            if docs.is_empty() {
-                return Ok(String::new());
+            for doc in &mut docs {
+                crate::ValueExt::normalize_strings(doc, mode);
             }
-            let inner_config = EmitterConfig {
-                explicit_start: false,
-                ..*config
-            };
-            let mut output = String::new();
-            for (i, doc) in docs.iter().enumerate() {
-                if i > 0 || config.explicit_start {
-                    if !output.is_empty() && !output.ends_with('\n') {
-                        output.push('\n');
-                    }
-                    output.push_str("---\n");
-                }
-                let emitted = Self::emit_str_preserving_styles(doc, &inner_config, 0)?;
-                output.push_str(&emitted);
+        }
+        if config.sort_keys {
+            for doc in &mut docs {
+                crate::ValueExt::sort_keys(doc);
             }
-            if !output.is_empty() && !output.ends_with('\n') {
-                output.push('\n');
+        }
+        if docs.is_empty() {
+            return Ok(String::new());
+        }
+        let inner_config = EmitterConfig {
+            explicit_start: false,
+            trailing_newline: true,
+            sort_keys: false,
+            ..*config
+        };
+        let mut output = String::new();
+        for (i, doc) in docs.iter().enumerate() {
+            if i > 0 || config.explicit_start {
+                if !output.is_empty() && !output.ends_with('\n') {
+                    output.push('\n');
+                }
+                output.push_str("---\n");
             }
-            Ok(Self::prepend_directives(&directives, output))
+            let emitted = Self::emit_str_preserving_styles(doc, &inner_config, 0)?;
+            output.push_str(&emitted);
         }
+        Ok(Self::finish_output(output, config))
     }
 
     /// Prepend directive lines to formatted output.
@@ -569,6 +1064,16 @@ impl Emitter {
         }
     }
 
+    /// Check whether `value` is an empty mapping or sequence, which must be
+    /// written inline (`{}`, `[]`) rather than as a nested block.
+    fn is_empty_collection(value: &Value) -> bool {
+        match value {
+            Value::Mapping(map) => map.is_empty(),
+            Value::Sequence(seq) => seq.is_empty(),
+            _ => false,
+        }
+    }
+
     /// Recursively emit a YAML value, handling block scalars manually.
     ///
     /// `indent_level` is the current nesting depth (in units of `config.indent` spaces).
@@ -597,7 +1102,7 @@ impl Emitter {
                             write!(out, "{indent}{key_str}: {val_str}")
                                 .map_err(|e| EmitError::Emit(e.to_string()))?;
                         }
-                        Value::Mapping(_) | Value::Sequence(_) => {
+                        Value::Mapping(_) | Value::Sequence(_) if !Self::is_empty_collection(v) => {
                             writeln!(out, "{indent}{key_str}:")
                                 .map_err(|e| EmitError::Emit(e.to_string()))?;
                             let val_str = Self::emit_value(v, config, indent_level + 1)?;
@@ -622,7 +1127,9 @@ impl Emitter {
                             write!(out, "{indent}- {item_str}")
                                 .map_err(|e| EmitError::Emit(e.to_string()))?;
                         }
-                        Value::Mapping(_) | Value::Sequence(_) => {
+                        Value::Mapping(_) | Value::Sequence(_)
+                            if !Self::is_empty_collection(item) =>
+                        {
                             writeln!(out, "{indent}-")
                                 .map_err(|e| EmitError::Emit(e.to_string()))?;
                             let item_str = Self::emit_value(item, config, indent_level + 1)?;
@@ -652,24 +1159,7 @@ impl Emitter {
                 ScalarOwned::Null => Ok("null".to_string()),
                 ScalarOwned::Boolean(b) => Ok(if *b { "true" } else { "false" }.to_string()),
                 ScalarOwned::Integer(i) => Ok(i.to_string()),
-                ScalarOwned::FloatingPoint(f) => {
-                    let s = f.to_string();
-                    // Ensure the output is recognisable as a float (YAML Core Schema).
-                    // Rust formats e.g. `1.0` as `"1"` and `1.23e10` as `"12300000000"`.
-                    // Append `.0` when the string contains no decimal point or exponent and
-                    // is not a special value (inf / NaN handled by fix_special_floats).
-                    if s.contains('.')
-                        || s.contains('e')
-                        || s.contains('E')
-                        || s.eq_ignore_ascii_case("inf")
-                        || s.eq_ignore_ascii_case("-inf")
-                        || s.eq_ignore_ascii_case("nan")
-                    {
-                        Ok(s)
-                    } else {
-                        Ok(format!("{s}.0"))
-                    }
-                }
+                ScalarOwned::FloatingPoint(f) => Ok(Self::float_repr(f.into_inner())),
                 ScalarOwned::String(s) => {
                     if s.contains(':') || s.contains('#') || s.is_empty() {
                         Ok(format!("\"{s}\""))
@@ -777,14 +1267,20 @@ impl Emitter {
 
     /// Re-indent saphyr output from 2-space indentation to `target` spaces per level.
     ///
+    /// Each line is indented relative to the nearest enclosing line. Content after
+    /// a `- ` indicator keeps its 2-space offset from the dash, so keys of a mapping
+    /// inside a sequence stay aligned with the first key on the `- ` line.
+    ///
     /// Lines that form block scalar bodies (content under `|` / `>`) retain their
     /// relative spacing; only the base indent level is rescaled.
     ///
     /// `---` / `...` markers and directive lines (`%YAML`, `%TAG`) are left unchanged.
     fn reindent(output: &str, target: usize) -> String {
         let mut result = String::with_capacity(output.len());
-        let mut in_block_scalar = false;
-        let mut block_scalar_base_indent: usize = 0;
+        // Columns of enclosing nodes: (column in saphyr output, rescaled column)
+        let mut anchors: Vec<(usize, usize)> = Vec::new();
+        // Block scalar header line: (column in saphyr output, rescaled column)
+        let mut block_scalar: Option<(usize, usize)> = None;
 
         for (i, line) in output.lines().enumerate() {
             if i > 0 {
@@ -793,48 +1289,65 @@ impl Emitter {
 
             // Directives and document markers: never re-indent.
             let trimmed = line.trim_start();
-            if trimmed.starts_with("---")
-                || trimmed.starts_with("...")
-                || trimmed.starts_with("%YAML")
-                || trimmed.starts_with("%TAG")
+            if line.starts_with("---")
+                || line.starts_with("...")
+                || line.starts_with("%YAML")
+                || line.starts_with("%TAG")
             {
-                in_block_scalar = false;
+                anchors.clear();
+                block_scalar = None;
                 result.push_str(line);
                 continue;
             }
 
             let leading = line.len() - trimmed.len();
-            let level = leading / 2; // saphyr always uses 2-space indent
 
-            if in_block_scalar {
+            if let Some((base, new_base)) = block_scalar {
                 // Inside a block scalar body: keep lines that are deeper than the
                 // mapping/sequence key that introduced the scalar.
-                if leading > block_scalar_base_indent {
-                    // Rescale: base_level * target + (extra spaces beyond base)
-                    let base_level = block_scalar_base_indent / 2;
-                    let extra = leading - block_scalar_base_indent;
-                    let new_leading = base_level * target + extra;
-                    let spaces = " ".repeat(new_leading);
-                    result.push_str(&spaces);
+                if leading > base {
+                    result.extend(std::iter::repeat_n(' ', new_base + leading - base));
                     result.push_str(trimmed);
                     continue;
                 }
+                // Blank lines do not end a block scalar
+                if trimmed.is_empty() {
+                    continue;
+                }
                 // Dedented back out of the block scalar
-                in_block_scalar = false;
+                block_scalar = None;
+            }
+            if trimmed.is_empty() {
+                continue;
             }
 
-            // Detect start of block scalar: line ends with `|` or `>` (with optional
-            // chomping indicator and trailing whitespace).
-            let value_part = trimmed.trim_end_matches(|c: char| c.is_whitespace());
-            let last_nonws = value_part.trim_start_matches(|c: char| c != '|' && c != '>');
-            if last_nonws.starts_with('|') || last_nonws.starts_with('>') {
-                in_block_scalar = true;
-                block_scalar_base_indent = leading;
+            while anchors.last().is_some_and(|&(col, _)| col > leading) {
+                anchors.pop();
+            }
+            let new_leading = match anchors.last() {
+                Some(&(col, new_col)) if col == leading => new_col,
+                // saphyr always uses 2-space indent
+                Some(&(col, new_col)) => new_col + (leading - col) / 2 * target,
+                None => leading / 2 * target,
+            };
+            if anchors.last().is_none_or(|&(col, _)| col != leading) {
+                anchors.push((leading, new_leading));
+            }
+
+            // Nodes that start after `- ` indicators (possibly nested, as in `- - a`)
+            let (mut col, mut new_col, mut rest) = (leading, new_leading, trimmed);
+            while let Some(after) = rest.strip_prefix("- ") {
+                col += 2;
+                new_col += 2;
+                anchors.push((col, new_col));
+                rest = after;
             }
 
-            let new_leading = level * target;
-            let spaces = " ".repeat(new_leading);
-            result.push_str(&spaces);
+            if Self::is_block_scalar_header(trimmed) {
+                block_scalar = Some((leading, new_leading));
+            }
+
+            result.extend(std::iter::repeat_n(' ', new_leading));
             result.push_str(trimmed);
         }
 
@@ -846,6 +1359,16 @@ impl Emitter {
         result
     }
 
+    /// Returns true if `line` ends with a block scalar header (`|`, `>-`, `|2+`, ...).
+    fn is_block_scalar_header(line: &str) -> bool {
+        let header = line
+            .trim_end()
+            .trim_end_matches(|c: char| c == '+' || c == '-' || c.is_ascii_digit());
+        header
+            .strip_suffix(['|', '>'])
+            .is_some_and(|before| before.is_empty() || before.ends_with(' '))
+    }
+
     /// Format a YAML string with default configuration.
     ///
     /// Uses streaming formatter for large files when the `streaming` feature is enabled.
@@ -868,6 +1391,33 @@ impl Emitter {
     }
 }
 
+/// Escape `s` for the body of a double-quoted scalar.
+///
+/// Control characters are always escaped; with `ascii_only`, so is every
+/// other non-ASCII character.
+pub(crate) fn escape_double_quoted(s: &str, ascii_only: bool) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            // String::write never fails
+            c if c.is_control() || (ascii_only && !c.is_ascii()) => {
+                if c <= '\u{ffff}' {
+                    let _ = write!(out, "\\u{:04X}", u32::from(c));
+                } else {
+                    let _ = write!(out, "\\U{:08X}", u32::from(c));
+                }
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -900,6 +1450,43 @@ mod tests {
         assert!(result.contains("---"));
     }
 
+    #[test]
+    fn test_scalar_to_string() {
+        let cases = [
+            (ScalarOwned::Null, "~"),
+            (ScalarOwned::Boolean(false), "false"),
+            (ScalarOwned::Integer(-7), "-7"),
+            (ScalarOwned::FloatingPoint(1.0.into()), "1.0"),
+            (ScalarOwned::FloatingPoint(2.5.into()), "2.5"),
+            (ScalarOwned::FloatingPoint(f64::INFINITY.into()), ".inf"),
+            (
+                ScalarOwned::FloatingPoint(f64::NEG_INFINITY.into()),
+                "-.inf",
+            ),
+            (ScalarOwned::FloatingPoint(f64::NAN.into()), ".nan"),
+            (ScalarOwned::String("plain".to_string()), "plain"),
+            (ScalarOwned::String("true".to_string()), "\"true\""),
+            (ScalarOwned::String("12".to_string()), "\"12\""),
+            (ScalarOwned::String(String::new()), "\"\""),
+            (ScalarOwned::String("a: b\nc".to_string()), "\"a: b\\nc\""),
+        ];
+        for (scalar, expected) in cases {
+            let text = Emitter::scalar_to_string(&scalar);
+            assert_eq!(text, expected, "{scalar:?}");
+
+            // The text reads back as the same scalar
+            let parsed = crate::Parser::parse_str(&text).unwrap().unwrap();
+            match (&parsed, &scalar) {
+                (Value::Value(ScalarOwned::FloatingPoint(a)), ScalarOwned::FloatingPoint(b))
+                    if b.is_nan() =>
+                {
+                    assert!(a.is_nan());
+                }
+                _ => assert_eq!(parsed, Value::Value(scalar)),
+            }
+        }
+    }
+
     #[test]
     fn test_emit_all_single() {
         let values = vec![Value::Value(ScalarOwned::String("only".to_string()))];
@@ -917,6 +1504,10 @@ mod tests {
         assert!(!config.explicit_start);
         assert!(config.compact);
         assert!(!config.multiline_strings);
+        assert_eq!(config.null_style, NullStyle::Preserve);
+        assert!(config.trailing_newline);
+        assert!(!config.keep_original_styles);
+        assert_eq!(config.streaming_threshold, 1024);
     }
 
     #[test]
@@ -925,12 +1516,26 @@ mod tests {
             .with_indent(4)
             .with_width(120)
             .with_explicit_start(true)
-            .with_compact(false);
+            .with_compact(false)
+            .with_null_style(NullStyle::Tilde)
+            .with_trailing_newline(false);
 
         assert_eq!(config.indent, 4);
         assert_eq!(config.width, 120);
         assert!(config.explicit_start);
         assert!(!config.compact);
+        assert_eq!(config.null_style, NullStyle::Tilde);
+        assert!(!config.trailing_newline);
+    }
+
+    #[test]
+    fn test_null_style_is_null() {
+        for value in ["", "~", "null", "Null", "NULL"] {
+            assert!(NullStyle::is_null(value), "{value:?}");
+        }
+        for value in ["nil", "none", "nULL", "'null'"] {
+            assert!(!NullStyle::is_null(value), "{value:?}");
+        }
     }
 
     #[test]
@@ -967,6 +1572,83 @@ mod tests {
         assert!(!result.starts_with("---"));
     }
 
+    #[test]
+    fn test_emit_with_explicit_end() {
+        let mut map = crate::Map::new();
+        map.insert(
+            Value::Value(ScalarOwned::String("key".to_string())),
+            Value::Value(ScalarOwned::String("value".to_string())),
+        );
+        let value = Value::Mapping(map);
+        let config = EmitterConfig::new()
+            .with_explicit_start(true)
+            .with_explicit_end(true);
+        let result = Emitter::emit_str_with_config(&value, &config).unwrap();
+        assert_eq!(result, "---\nkey: value\n...\n");
+
+        let docs = crate::Parser::parse_all(&result).unwrap();
+        assert_eq!(docs, vec![value]);
+    }
+
+    #[test]
+    fn test_emit_with_explicit_end_no_trailing_newline() {
+        let value = Value::Value(ScalarOwned::String("test".to_string()));
+        let config = EmitterConfig::new()
+            .with_explicit_end(true)
+            .with_trailing_newline(false);
+        let result = Emitter::emit_str_with_config(&value, &config).unwrap();
+        assert_eq!(result, "test\n...");
+    }
+
+    #[test]
+    fn test_emit_all_with_explicit_end() {
+        let values = vec![
+            Value::Value(ScalarOwned::String("first".to_string())),
+            Value::Value(ScalarOwned::String("second".to_string())),
+        ];
+        let config = EmitterConfig::new().with_explicit_end(true);
+        let result = Emitter::emit_all_with_config(&values, &config).unwrap();
+        assert_eq!(result, "first\n...\n---\nsecond\n...\n");
+        assert_eq!(crate::Parser::parse_all(&result).unwrap(), values);
+    }
+
+    #[test]
+    fn test_emit_all_with_indent() {
+        let values = vec![
+            crate::Parser::parse_str("parent:\n  child: value\n")
+                .unwrap()
+                .unwrap(),
+            crate::Parser::parse_str("list:\n  - a\n").unwrap().unwrap(),
+        ];
+        let config = EmitterConfig::new().with_indent(4);
+        let result = Emitter::emit_all_with_config(&values, &config).unwrap();
+        assert_eq!(result, "parent:\n    child: value\n---\nlist:\n    - a\n");
+        assert_eq!(crate::Parser::parse_all(&result).unwrap(), values);
+    }
+
+    #[test]
+    fn test_format_with_explicit_end() {
+        let config = EmitterConfig::new().with_explicit_end(true);
+        let result = Emitter::format_with_config("a: 1\n---\nb:\n  - x\n", &config).unwrap();
+        assert_eq!(result, "a: 1\n...\n---\nb:\n  - x\n...\n");
+        assert_eq!(crate::Parser::parse_all(&result).unwrap().len(), 2);
+
+        // Formatting is idempotent: the markers are not duplicated
+        assert_eq!(
+            Emitter::format_with_config(&result, &config).unwrap(),
+            result
+        );
+    }
+
+    #[test]
+    fn test_format_sort_keys_explicit_end() {
+        let config = EmitterConfig::new()
+            .with_sort_keys(true)
+            .with_explicit_end(true);
+        let result = Emitter::format_with_config("b: 1\na: 2\n", &config).unwrap();
+        assert_eq!(result, "a: 2\nb: 1\n...\n");
+    }
+
     #[test]
     fn test_emit_all_with_explicit_start() {
         let values = vec![
@@ -1132,32 +1814,32 @@ mod tests {
     #[test]
     fn test_fix_special_floats_inf() {
         // Test standalone inf conversion
-        let result = Emitter::fix_special_floats("inf");
+        let result = Emitter::fix_special_floats("inf".into());
         assert_eq!(result, ".inf");
 
         // Test inf in a mapping value position
-        let result = Emitter::fix_special_floats("key: inf");
+        let result = Emitter::fix_special_floats("key: inf".into());
         assert_eq!(result, "key: .inf");
 
         // Test -inf conversion
-        let result = Emitter::fix_special_floats("-inf");
+        let result = Emitter::fix_special_floats("-inf".into());
         assert_eq!(result, "-.inf");
 
         // Test -inf in a mapping value position
-        let result = Emitter::fix_special_floats("key: -inf");
+        let result = Emitter::fix_special_floats("key: -inf".into());
         assert_eq!(result, "key: -.inf");
 
         // Test inf in a sequence
-        let result = Emitter::fix_special_floats("- inf");
+        let result = Emitter::fix_special_floats("- inf".into());
         assert_eq!(result, "- .inf");
 
         // Test -inf in a sequence
-        let result = Emitter::fix_special_floats("- -inf");
+        let result = Emitter::fix_special_floats("- -inf".into());
         assert_eq!(result, "- -.inf");
 
         // Test mixed document with multiple inf values
         let input = "positive: inf\nnegative: -inf\nlist:\n  - inf\n  - -inf";
-        let result = Emitter::fix_special_floats(input);
+        let result = Emitter::fix_special_floats(input.into());
         assert!(result.contains("positive: .inf"));
         assert!(result.contains("negative: -.inf"));
         assert!(result.contains("- .inf"));
@@ -1167,31 +1849,31 @@ mod tests {
     #[test]
     fn test_fix_special_floats_nan() {
         // Test standalone NaN conversion
-        let result = Emitter::fix_special_floats("NaN");
+        let result = Emitter::fix_special_floats("NaN".into());
         assert_eq!(result, ".nan");
 
         // Test NaN in a mapping value position
-        let result = Emitter::fix_special_floats("value: NaN");
+        let result = Emitter::fix_special_floats("value: NaN".into());
         assert_eq!(result, "value: .nan");
 
         // Test NaN in a sequence
-        let result = Emitter::fix_special_floats("- NaN");
+        let result = Emitter::fix_special_floats("- NaN".into());
         assert_eq!(result, "- .nan");
 
         // Test document with multiple NaN values
         let input = "nan_value: NaN\nlist:\n  - NaN";
-        let result = Emitter::fix_special_floats(input);
+        let result = Emitter::fix_special_floats(input.into());
         assert!(result.contains("nan_value: .nan"));
         assert!(result.contains("- .nan"));
 
         // Test that strings containing "NaN" as part of word are not converted
         // (this relies on is_value_position check)
-        let result = Emitter::fix_special_floats("name: BaNaNa");
+        let result = Emitter::fix_special_floats("name: BaNaNa".into());
         assert_eq!(result, "name: BaNaNa", "BaNaNa should not be modified");
 
         // Test mixed special floats
         let input = "inf_val: inf\nnan_val: NaN\nneg_inf: -inf";
-        let result = Emitter::fix_special_floats(input);
+        let result = Emitter::fix_special_floats(input.into());
         assert!(result.contains("inf_val: .inf"));
         assert!(result.contains("nan_val: .nan"));
         assert!(result.contains("neg_inf: -.inf"));
@@ -1311,7 +1993,7 @@ mod tests {
     fn test_fix_special_floats_no_changes() {
         // Test output that doesn't contain special floats (fast path)
         let input = "key: value\nlist:\n  - item1\n  - item2\nnumber: 42\n";
-        let result = Emitter::fix_special_floats(input);
+        let result = Emitter::fix_special_floats(input.into());
         assert_eq!(result, input, "No changes should be made for normal YAML");
     }
 
@@ -1693,6 +2375,184 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_emit_str_without_trailing_newline() {
+        let mut map = saphyr::MappingOwned::new();
+        map.insert(
+            Value::Value(ScalarOwned::String("a".to_string())),
+            Value::Value(ScalarOwned::Integer(1)),
+        );
+        let value = Value::Mapping(map);
+        let config = EmitterConfig::new().with_trailing_newline(false);
+        assert_eq!(
+            Emitter::emit_str_with_config(&value, &config).unwrap(),
+            "a: 1"
+        );
+
+        let flow = config.with_default_flow_style(Some(true));
+        assert_eq!(
+            Emitter::emit_str_with_config(&value, &flow).unwrap(),
+            "{a: 1}"
+        );
+    }
+
+    #[test]
+    fn test_emit_all_without_trailing_newline() {
+        let docs = vec![
+            Value::Value(ScalarOwned::String("first".to_string())),
+            Value::Value(ScalarOwned::String("second".to_string())),
+        ];
+        let config = EmitterConfig::new().with_trailing_newline(false);
+        assert_eq!(
+            Emitter::emit_all_with_config(&docs, &config).unwrap(),
+            "first\n---\nsecond"
+        );
+    }
+
+    #[test]
+    fn test_format_trailing_newline() {
+        let with = Emitter::format_with_config("a:   1", &EmitterConfig::new()).unwrap();
+        assert_eq!(with, "a: 1\n");
+
+        let config = EmitterConfig::new().with_trailing_newline(false);
+        let without = Emitter::format_with_config("a:   1\nb: [x]\n", &config).unwrap();
+        assert_eq!(without, "a: 1\nb:\n  - x");
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_format_streaming_threshold() {
+        // The DOM path writes an empty flow mapping as `{}` and the streaming
+        // formatter as an empty value, which tells the two paths apart.
+        let input = "a: {}\n";
+        let dom = "a: {}\n";
+        let streamed = "a:\n";
+
+        let config = EmitterConfig::new();
+        assert_eq!(Emitter::format_with_config(input, &config).unwrap(), dom);
+
+        let config = config.with_streaming_threshold(0);
+        assert_eq!(
+            Emitter::format_with_config(input, &config).unwrap(),
+            streamed
+        );
+
+        let large: String = std::iter::once(input.to_string())
+            .chain((0..100).map(|i| format!("key{i}: value\n")))
+            .collect();
+        let config = config.with_streaming_threshold(1024);
+        assert!(
+            Emitter::format_with_config(&large, &config)
+                .unwrap()
+                .starts_with(streamed)
+        );
+
+        let config = config.with_streaming_threshold(large.len() + 1);
+        assert!(
+            Emitter::format_with_config(&large, &config)
+                .unwrap()
+                .starts_with(dom)
+        );
+
+        // Null styles are only implemented by the streaming formatter
+        let config = config.with_null_style(NullStyle::Null);
+        assert_eq!(
+            Emitter::format_with_config(input, &config).unwrap(),
+            streamed
+        );
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_format_small_input_keeps_anchors_and_markers() {
+        // Small inputs format through the DOM by default, except these, which
+        // only the streaming formatter reproduces
+        let config = EmitterConfig::new();
+        for input in [
+            "a: &x 1\nb: *x\n",
+            "---\na: 1\n",
+            "---\nbase: &b\n  x: 1.0\nd: *b\n",
+        ] {
+            assert_eq!(Emitter::format_with_config(input, &config).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_format_dom_escapes_quoted_scalars() {
+        let input = "a: 'it''s'\nb: \"x\\ty \\\"q\\\"\"\n";
+        let config = EmitterConfig::new().with_streaming_threshold(usize::MAX);
+        let formatted = Emitter::format_with_config(input, &config).unwrap();
+        assert_eq!(formatted, input);
+        assert_eq!(
+            crate::Parser::parse_all(&formatted).unwrap(),
+            crate::Parser::parse_all(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_format_sort_keys() {
+        let config = EmitterConfig::new().with_sort_keys(true);
+        let input = "b: 1\na:\n  z: 'text'\n  y: [2, 1]\n---\nd: 1\nc: 2\n";
+        assert_eq!(
+            Emitter::format_with_config(input, &config).unwrap(),
+            "a:\n  y:\n    - 2\n    - 1\n  z: 'text'\nb: 1\n---\nc: 2\nd: 1\n"
+        );
+
+        let unsorted = Emitter::format_with_config("b: 1\na: 2\n", &EmitterConfig::new()).unwrap();
+        assert_eq!(unsorted, "b: 1\na: 2\n");
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_format_sort_keys_with_indent() {
+        let config = EmitterConfig::new().with_sort_keys(true).with_indent(4);
+        assert_eq!(
+            Emitter::format_with_config("b:\n  y: 1\n  x: 2\na: 3\n", &config).unwrap(),
+            "a: 3\nb:\n    x: 2\n    y: 1\n"
+        );
+    }
+
+    #[test]
+    fn test_format_sort_keys_explicit_start() {
+        let config = EmitterConfig::new()
+            .with_sort_keys(true)
+            .with_explicit_start(true);
+        assert_eq!(
+            Emitter::format_with_config("b: 1\na: 2\n", &config).unwrap(),
+            "---\na: 2\nb: 1\n"
+        );
+    }
+
+    #[test]
+    fn test_emit_sort_keys() {
+        let value = crate::Parser::parse_str("b: 1\na:\n  z: [{y: 1, x: 2}]\n  c: 3\n")
+            .unwrap()
+            .unwrap();
+        let config = EmitterConfig::new().with_sort_keys(true);
+        assert_eq!(
+            Emitter::emit_str_with_config(&value, &config).unwrap(),
+            "a:\n  c: 3\n  z:\n    - x: 2\n      y: 1\nb: 1\n"
+        );
+        assert_eq!(
+            Emitter::emit_str_with_config(&value, &config.with_default_flow_style(Some(true)))
+                .unwrap(),
+            "{a: {c: 3, z: [{x: 2, y: 1}]}, b: 1}\n"
+        );
+
+        // The input value is left untouched
+        assert!(Emitter::emit_str(&value).unwrap().starts_with("b: 1\n"));
+    }
+
+    #[test]
+    fn test_emit_all_sort_keys() {
+        let docs = crate::Parser::parse_all("b: 1\na: 2\n---\nd: 3\nc: 4\n").unwrap();
+        let config = EmitterConfig::new().with_sort_keys(true);
+        assert_eq!(
+            Emitter::emit_all_with_config(&docs, &config).unwrap(),
+            "a: 2\nb: 1\n---\nc: 4\nd: 3\n"
+        );
+    }
+
     // Regression tests for issue #95: format must preserve %YAML and %TAG directives
     #[test]
     fn test_format_preserves_yaml_directive() {
@@ -1902,4 +2762,199 @@ mod tests {
         assert!(result.contains("---"), "--- marker must be preserved");
         assert!(result.contains("key: value"));
     }
+
+    #[test]
+    fn test_reindent_sequence_of_mappings() {
+        // Keys after the first stay aligned with the key on the `- ` line
+        let input = "items:\n  - name: a\n    value: 1\n    nested:\n      deep: true\n";
+        assert_eq!(
+            Emitter::reindent(input, 4),
+            "items:\n    - name: a\n      value: 1\n      nested:\n          deep: true\n"
+        );
+    }
+
+    #[test]
+    fn test_reindent_1_keeps_nesting_under_sequence() {
+        let input = "list:\n  - x:\n      y: 2\n  - - a\n    - b\n";
+        assert_eq!(
+            Emitter::reindent(input, 1),
+            "list:\n - x:\n    y: 2\n - - a\n   - b\n"
+        );
+    }
+
+    #[test]
+    fn test_reindent_block_scalar_with_blank_line() {
+        let input = "outer:\n  text: |\n    a\n\n      b\n  next: 1\n";
+        assert_eq!(
+            Emitter::reindent(input, 4),
+            "outer:\n    text: |\n      a\n\n        b\n    next: 1\n"
+        );
+    }
+
+    const LONG: &str = "The quick brown fox jumps over the lazy dog and keeps running \
+                        through the forest until night falls on the valley.";
+
+    fn long_string_doc() -> Value {
+        let long = Value::Value(ScalarOwned::String(LONG.to_string()));
+        let mut inner = saphyr::MappingOwned::new();
+        inner.insert(
+            Value::Value(ScalarOwned::String("note".into())),
+            long.clone(),
+        );
+        let mut map = saphyr::MappingOwned::new();
+        map.insert(
+            Value::Value(ScalarOwned::String("description".into())),
+            long.clone(),
+        );
+        map.insert(
+            Value::Value(ScalarOwned::String("items".into())),
+            Value::Sequence(vec![long, Value::Mapping(inner)]),
+        );
+        Value::Mapping(map)
+    }
+
+    #[test]
+    fn test_emit_folds_long_strings_at_width() {
+        let value = long_string_doc();
+        let output = Emitter::emit_str(&value).unwrap();
+        assert!(
+            output.starts_with("description: >-\n  The quick"),
+            "{output}"
+        );
+        assert!(output.contains("  - >-\n"), "{output}");
+        assert!(output.contains("  - note: >-\n"), "{output}");
+        assert!(output.lines().all(|line| line.len() <= 80), "{output}");
+        assert_eq!(crate::Parser::parse_str(&output).unwrap().unwrap(), value);
+    }
+
+    #[test]
+    fn test_emit_wide_width_keeps_strings_on_one_line() {
+        let config = EmitterConfig::new().with_width(200);
+        let output = Emitter::emit_str_with_config(&long_string_doc(), &config).unwrap();
+        assert!(!output.contains(">-"), "{output}");
+        assert!(output.contains(&format!("description: {LONG}\n")));
+    }
+
+    #[test]
+    fn test_emit_folded_strings_roundtrip() {
+        let value = long_string_doc();
+        for (width, indent) in [(20, 1), (40, 2), (60, 4), (80, 9)] {
+            let config = EmitterConfig::new().with_width(width).with_indent(indent);
+            let output = Emitter::emit_str_with_config(&value, &config).unwrap();
+            let reparsed = crate::Parser::parse_str(&output).unwrap().unwrap();
+            assert_eq!(reparsed, value, "width={width} indent={indent}:\n{output}");
+        }
+    }
+
+    #[test]
+    fn test_fold_string_breaks_only_at_single_spaces() {
+        let folded = Emitter::fold_string("aaa bbb  ccc ddd", 2, 8).unwrap();
+        assert_eq!(folded, ">-\n  aaa\n  bbb  ccc\n  ddd");
+    }
+
+    #[test]
+    fn test_fold_string_rejects_unfoldable() {
+        for s in [
+            "no-spaces-at-all",
+            " leading",
+            "trailing ",
+            "tab\there x",
+            "two  spaces",
+        ] {
+            assert_eq!(Emitter::fold_string(s, 2, 4), None, "{s:?}");
+        }
+    }
+
+    #[test]
+    fn test_emit_indent_roundtrip() {
+        let yaml = "a:\n  b:\n    c: 1\nlist:\n  - x: 1\n    z:\n      q:\n        - k: v\n          j: w\n  - - 1\n    - 2\n";
+        let value = crate::Parser::parse_str(yaml).unwrap().unwrap();
+        for indent in 1..=9 {
+            let config = EmitterConfig::new().with_indent(indent);
+            let output = Emitter::emit_str_with_config(&value, &config).unwrap();
+            let reparsed = crate::Parser::parse_str(&output).unwrap().unwrap();
+            assert_eq!(
+                reparsed, value,
+                "indent={indent} changed the value:\n{output}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_emit_default_style() {
+        let value = crate::Parser::parse_str("name: it's\nport: 8080\nitems: [a, b]\n")
+            .unwrap()
+            .unwrap();
+        let emit = |style| {
+            let config = EmitterConfig::new().with_default_style(Some(style));
+            Emitter::emit_str_with_config(&value, &config).unwrap()
+        };
+        assert_eq!(
+            emit(ScalarStyle::DoubleQuoted),
+            "\"name\": \"it's\"\n\"port\": 8080\n\"items\":\n  - \"a\"\n  - \"b\"\n"
+        );
+        assert_eq!(
+            emit(ScalarStyle::SingleQuoted),
+            "'name': 'it''s'\n'port': 8080\n'items':\n  - 'a'\n  - 'b'\n"
+        );
+        assert_eq!(
+            emit(ScalarStyle::Literal),
+            "\"name\": |-\n  it's\n\"port\": 8080\n\"items\":\n  - |-\n    a\n  - |-\n    b\n"
+        );
+        assert_eq!(
+            emit(ScalarStyle::Plain),
+            Emitter::emit_str_with_config(&value, &EmitterConfig::new()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_emit_default_style_roundtrip() {
+        let yaml = "text: \"a\\nb\\n\\nc\\n\"\nquote: 'it''s \"x\"'\nlead: \" x\"\ntab: \"a\\tb\"\nempty: ''\nn: 1\nnull: ~\nlist: [one, \"two\\nlines\", {}, []]\nnested: {k: v, e: {}}\n";
+        let value = crate::Parser::parse_str(yaml).unwrap().unwrap();
+        for style in [
+            ScalarStyle::DoubleQuoted,
+            ScalarStyle::SingleQuoted,
+            ScalarStyle::Literal,
+            ScalarStyle::Folded,
+        ] {
+            for flow in [None, Some(true)] {
+                let config = EmitterConfig::new()
+                    .with_default_style(Some(style))
+                    .with_default_flow_style(flow)
+                    .with_indent(4);
+                let output = Emitter::emit_str_with_config(&value, &config).unwrap();
+                let reparsed = crate::Parser::parse_str(&output).unwrap().unwrap();
+                assert_eq!(
+                    reparsed, value,
+                    "{style:?} flow={flow:?} changed the value:\n{output}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_emit_default_style_block_fallbacks() {
+        let config = EmitterConfig::new().with_default_style(Some(ScalarStyle::Folded));
+        let root = Value::Value(ScalarOwned::String("root".to_string()));
+        assert_eq!(
+            Emitter::emit_str_with_config(&root, &config).unwrap(),
+            "\"root\"\n"
+        );
+
+        let value = crate::Parser::parse_str("a: \"x\\ny\"\nb: \" lead\"\n")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            Emitter::emit_str_with_config(&value, &config).unwrap(),
+            "\"a\": >-\n  x\n\n  y\n\"b\": \" lead\"\n"
+        );
+    }
+
+    #[test]
+    fn test_unfold_line_breaks() {
+        assert_eq!(Emitter::unfold_line_breaks("a"), "a");
+        assert_eq!(Emitter::unfold_line_breaks("a\nb"), "a\n\nb");
+        assert_eq!(Emitter::unfold_line_breaks("a\n\nb\n"), "a\n\n\nb\n");
+        assert_eq!(Emitter::unfold_line_breaks("\na"), "\na");
+    }
 }
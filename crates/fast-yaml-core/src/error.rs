@@ -27,6 +27,85 @@ pub enum ParseError {
     /// YAML scanner error from saphyr.
     #[error("YAML scanner error: {0}")]
     Scanner(#[from] saphyr::ScanError),
+
+    /// Collection nesting exceeded the depth limit.
+    #[error("nesting depth exceeds limit of {max_depth} at line {line}, column {column}")]
+    DepthLimitExceeded {
+        /// Maximum allowed nesting depth.
+        max_depth: usize,
+        /// Line where the limit was exceeded (1-indexed).
+        line: usize,
+        /// Column where the limit was exceeded (1-indexed).
+        column: usize,
+    },
+
+    /// Node count, with aliases expanded, exceeded the node limit.
+    #[error(
+        "document has more than {max_nodes} nodes (aliases expanded) at line {line}, column {column}"
+    )]
+    NodeLimitExceeded {
+        /// Maximum allowed number of nodes.
+        max_nodes: usize,
+        /// Line where the limit was exceeded (1-indexed).
+        line: usize,
+        /// Column where the limit was exceeded (1-indexed).
+        column: usize,
+    },
+}
+
+impl ParseError {
+    /// Returns the 1-indexed `(line, column)` where the error occurred, or
+    /// `None` if the error has no location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::Parser;
+    ///
+    /// let err = Parser::parse_str("a: 1\nb: [2\n").unwrap_err();
+    /// let (line, column) = err.location().unwrap();
+    /// assert_eq!(line, 3);
+    /// assert_eq!(column, 1);
+    /// ```
+    #[must_use]
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Syntax { line, column, .. }
+            | Self::DepthLimitExceeded { line, column, .. }
+            | Self::NodeLimitExceeded { line, column, .. } => Some((*line, *column)),
+            Self::Scanner(err) => Some((err.marker().line(), err.marker().col() + 1)),
+            Self::InvalidFloat { .. } => None,
+        }
+    }
+
+    /// Returns the 1-indexed line where the error occurred, if known.
+    #[must_use]
+    pub fn line(&self) -> Option<usize> {
+        self.location().map(|(line, _)| line)
+    }
+
+    /// Returns the 1-indexed column where the error occurred, if known.
+    #[must_use]
+    pub fn column(&self) -> Option<usize> {
+        self.location().map(|(_, column)| column)
+    }
+
+    /// Returns the description of the error without its location, e.g. for
+    /// structured output that reports the location separately.
+    #[must_use]
+    pub fn message(&self) -> String {
+        match self {
+            Self::Syntax { message, .. } => message.clone(),
+            Self::Scanner(err) => err.info().to_string(),
+            Self::DepthLimitExceeded { max_depth, .. } => {
+                format!("nesting depth exceeds limit of {max_depth}")
+            }
+            Self::NodeLimitExceeded { max_nodes, .. } => {
+                format!("document has more than {max_nodes} nodes (aliases expanded)")
+            }
+            Self::InvalidFloat { .. } => self.to_string(),
+        }
+    }
 }
 
 /// Errors that can occur during YAML emission.
@@ -41,12 +120,47 @@ pub enum EmitError {
     UnsupportedType(String),
 }
 
+/// Errors that can occur during streaming YAML-to-JSON conversion.
+#[derive(Error, Debug)]
+pub enum ConvertError {
+    /// YAML scanner error from saphyr.
+    #[error("YAML scanner error: {0}")]
+    Scanner(#[from] saphyr::ScanError),
+
+    /// Failed to read input or write output.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Mapping key that cannot be represented as a JSON object key.
+    #[error("unsupported mapping key: {0} (JSON object keys must be scalars)")]
+    UnsupportedKey(&'static str),
+
+    /// Float value (infinity or NaN) that has no JSON representation.
+    #[error("float value '{0}' cannot be represented in JSON")]
+    NonFiniteFloat(String),
+
+    /// Alias referring to an anchor that was not defined before it.
+    #[error("alias refers to undefined anchor (id {0})")]
+    UndefinedAlias(usize),
+
+    /// Nesting depth exceeded the supported limit.
+    #[error("maximum nesting depth of {0} exceeded")]
+    TooDeep(usize),
+
+    /// Aliases in one document expanded to more JSON than the supported limit.
+    #[error("aliases expand to more than {0} bytes of JSON")]
+    AliasExpansionTooLarge(usize),
+}
+
 /// Result type for parsing operations.
 pub type ParseResult<T> = std::result::Result<T, ParseError>;
 
 /// Result type for emission operations.
 pub type EmitResult<T> = std::result::Result<T, EmitError>;
 
+/// Result type for streaming conversion operations.
+pub type ConvertResult<T> = std::result::Result<T, ConvertError>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -62,9 +176,38 @@ mod tests {
         assert!(err.to_string().contains("column 5"));
     }
 
+    #[test]
+    fn test_parse_error_location() {
+        let err = ParseError::Syntax {
+            line: 10,
+            column: 5,
+            message: "unexpected token".to_string(),
+        };
+        assert_eq!(err.location(), Some((10, 5)));
+        assert_eq!(err.message(), "unexpected token");
+
+        let err = crate::Parser::parse_str("key: \"unterminated").unwrap_err();
+        assert!(matches!(err, ParseError::Scanner(_)));
+        assert_eq!(err.line(), Some(1));
+        assert_eq!(err.column(), Some(6));
+        assert!(!err.message().contains("line"), "{}", err.message());
+
+        let err = ParseError::InvalidFloat {
+            value: "x".to_string(),
+            source: "x".parse::<f64>().unwrap_err(),
+        };
+        assert_eq!(err.location(), None);
+    }
+
     #[test]
     fn test_emit_error_display() {
         let err = EmitError::UnsupportedType("CustomType".to_string());
         assert!(err.to_string().contains("CustomType"));
     }
+
+    #[test]
+    fn test_convert_error_display() {
+        let err = ConvertError::NonFiniteFloat("inf".to_string());
+        assert!(err.to_string().contains("cannot be represented in JSON"));
+    }
 }
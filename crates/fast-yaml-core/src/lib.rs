@@ -44,6 +44,10 @@ pub mod parser;
 /// Value types representing YAML data structures.
 pub mod value;
 
+/// `serde` support for [`Value`].
+#[cfg(feature = "serde")]
+pub mod serde_value;
+
 /// Streaming YAML formatter module.
 ///
 /// Provides high-performance formatting by processing parser events directly
@@ -51,7 +55,7 @@ pub mod value;
 #[cfg(feature = "streaming")]
 pub mod streaming;
 
-pub use emitter::{Emitter, EmitterConfig};
-pub use error::{EmitError, EmitResult, ParseError, ParseResult};
-pub use parser::{Parser, canonicalize};
-pub use value::{Array, Map, OrderedFloat, ScalarOwned, Value};
+pub use emitter::{Emitter, EmitterConfig, NullStyle};
+pub use error::{ConvertError, ConvertResult, EmitError, EmitResult, ParseError, ParseResult};
+pub use parser::{ParseConfig, Parser, canonicalize};
+pub use value::{Array, Map, OrderedFloat, ScalarOwned, StringNormalization, Value, ValueExt};
@@ -1,7 +1,44 @@
-use crate::error::ParseResult;
-use crate::value::Value;
+use std::collections::HashMap;
+
+use crate::error::{ParseError, ParseResult};
+use crate::value::{Value, resolve_merge_keys};
 use saphyr::{ScalarOwned, YamlLoader};
-use saphyr_parser::{BufferedInput, Parser as SaphyrParser, ScalarStyle, Tag};
+use saphyr_parser::{
+    BufferedInput, Event, Parser as SaphyrParser, ScalarStyle, Span, SpannedEventReceiver, Tag,
+};
+
+/// Configuration for [`Parser::parse_str_with_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseConfig {
+    /// Resolve YAML merge keys (`<<: *anchor`) into their parent mappings
+    /// (default: true).
+    ///
+    /// When false, `<<` entries are kept as ordinary mapping keys. See
+    /// [`ValueExt::apply_merge_keys`](crate::ValueExt::apply_merge_keys).
+    pub resolve_merge_keys: bool,
+}
+
+impl Default for ParseConfig {
+    fn default() -> Self {
+        Self {
+            resolve_merge_keys: true,
+        }
+    }
+}
+
+impl ParseConfig {
+    /// Create a new parse configuration with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether merge keys are resolved.
+    #[must_use]
+    pub const fn with_resolve_merge_keys(mut self, resolve_merge_keys: bool) -> Self {
+        self.resolve_merge_keys = resolve_merge_keys;
+        self
+    }
+}
 
 /// Parser for YAML documents.
 ///
@@ -10,6 +47,12 @@ use saphyr_parser::{BufferedInput, Parser as SaphyrParser, ScalarStyle, Tag};
 pub struct Parser;
 
 impl Parser {
+    /// Default maximum collection nesting depth for [`parse_with_limit`](Self::parse_with_limit).
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+    /// Default maximum node count for [`parse_with_limit`](Self::parse_with_limit).
+    pub const DEFAULT_MAX_NODES: usize = 1_000_000;
+
     /// Parse a single YAML document from a string.
     ///
     /// Returns the first document if multiple are present, or None if the input is empty.
@@ -27,11 +70,79 @@ impl Parser {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn parse_str(input: &str) -> ParseResult<Option<Value>> {
+        Self::parse_str_with_config(input, &ParseConfig::default())
+    }
+
+    /// Parse a single YAML document from a string with custom configuration.
+    ///
+    /// Returns the first document if multiple are present, or None if the input is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Scanner` if the YAML syntax is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{ParseConfig, Parser};
+    ///
+    /// let yaml = "base: &base {a: 1}\nchild:\n  <<: *base\n";
+    /// let config = ParseConfig::new().with_resolve_merge_keys(false);
+    /// let value = Parser::parse_str_with_config(yaml, &config)?.unwrap();
+    /// assert!(value["child"]["<<"].is_mapping());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_str_with_config(input: &str, config: &ParseConfig) -> ParseResult<Option<Value>> {
         let mut saphyr_parser = SaphyrParser::new(BufferedInput::new(input.chars()));
         let mut loader = YamlLoader::<Value>::default();
         loader.early_parse(false);
         saphyr_parser.load(&mut loader, true)?;
         let docs = inject_implicit_null_if_empty(loader.into_documents(), input);
+        Ok(docs
+            .into_iter()
+            .next()
+            .map(|doc| canonicalize_with(doc, *config)))
+    }
+
+    /// Parse a single YAML document, rejecting input that exceeds resource limits.
+    ///
+    /// Use this for untrusted input. `max_depth` bounds collection nesting and
+    /// `max_nodes` bounds the number of nodes across all documents, counting
+    /// every alias as a copy of the node it refers to. Limits are checked while
+    /// parsing, so an alias bomb ("billion laughs") is rejected before its
+    /// expansion is built in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::DepthLimitExceeded` or `ParseError::NodeLimitExceeded`
+    /// when a limit is hit, or `ParseError::Scanner` if the YAML syntax is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{ParseError, Parser};
+    ///
+    /// let value = Parser::parse_with_limit("a: [1, 2]", 8, 100)?;
+    /// assert!(value.is_some());
+    ///
+    /// let bomb = "a: &a [x, x, x]\nb: &b [*a, *a, *a]\nc: [*b, *b, *b]\n";
+    /// let err = Parser::parse_with_limit(bomb, 8, 20).unwrap_err();
+    /// assert!(matches!(err, ParseError::NodeLimitExceeded { max_nodes: 20, .. }));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_with_limit(
+        input: &str,
+        max_depth: usize,
+        max_nodes: usize,
+    ) -> ParseResult<Option<Value>> {
+        let mut loader = YamlLoader::<Value>::default();
+        loader.early_parse(false);
+        let mut limited = LimitedParser::new(&mut loader, max_depth, max_nodes);
+        for event in SaphyrParser::new(BufferedInput::new(input.chars())) {
+            let (event, span) = event?;
+            limited.on_event(event, span)?;
+        }
+        let docs = inject_implicit_null_if_empty(loader.into_documents(), input);
         Ok(docs.into_iter().next().map(canonicalize))
     }
 
@@ -86,6 +197,90 @@ impl Parser {
     }
 }
 
+/// Event receiver wrapper that enforces depth and node limits before
+/// forwarding events to the wrapped loader.
+///
+/// Aliases are counted with the size of the node they refer to, so the node
+/// count reflects the fully expanded document.
+struct LimitedParser<'a, R> {
+    inner: &'a mut R,
+    max_depth: usize,
+    max_nodes: usize,
+    nodes: usize,
+    /// Open collections: anchor id and node count before the collection started.
+    open: Vec<(usize, usize)>,
+    /// Expanded node count of each anchored node, by anchor id.
+    anchor_sizes: HashMap<usize, usize>,
+}
+
+impl<'a, R> LimitedParser<'a, R> {
+    fn new(inner: &'a mut R, max_depth: usize, max_nodes: usize) -> Self {
+        Self {
+            inner,
+            max_depth,
+            max_nodes,
+            nodes: 0,
+            open: Vec::new(),
+            anchor_sizes: HashMap::new(),
+        }
+    }
+
+    fn add_nodes(&mut self, count: usize, span: Span) -> ParseResult<()> {
+        self.nodes = self.nodes.saturating_add(count);
+        if self.nodes > self.max_nodes {
+            return Err(ParseError::NodeLimitExceeded {
+                max_nodes: self.max_nodes,
+                line: span.start.line(),
+                column: span.start.col() + 1,
+            });
+        }
+        Ok(())
+    }
+
+    fn on_event<'input>(&mut self, event: Event<'input>, span: Span) -> ParseResult<()>
+    where
+        R: SpannedEventReceiver<'input>,
+    {
+        match event {
+            Event::Scalar(_, _, anchor_id, _) => {
+                self.add_nodes(1, span)?;
+                if anchor_id > 0 {
+                    self.anchor_sizes.insert(anchor_id, 1);
+                }
+            }
+            Event::SequenceStart(anchor_id, _) | Event::MappingStart(anchor_id, _) => {
+                if self.open.len() >= self.max_depth {
+                    return Err(ParseError::DepthLimitExceeded {
+                        max_depth: self.max_depth,
+                        line: span.start.line(),
+                        column: span.start.col() + 1,
+                    });
+                }
+                self.open.push((anchor_id, self.nodes));
+                self.add_nodes(1, span)?;
+            }
+            Event::SequenceEnd | Event::MappingEnd => {
+                if let Some((anchor_id, start)) = self.open.pop()
+                    && anchor_id > 0
+                {
+                    self.anchor_sizes.insert(anchor_id, self.nodes - start);
+                }
+            }
+            Event::Alias(anchor_id) => {
+                let size = self.anchor_sizes.get(&anchor_id).copied().unwrap_or(1);
+                self.add_nodes(size, span)?;
+            }
+            Event::StreamStart
+            | Event::StreamEnd
+            | Event::DocumentStart(_)
+            | Event::DocumentEnd
+            | Event::Nothing => {}
+        }
+        self.inner.on_event(event, span);
+        Ok(())
+    }
+}
+
 /// Returns `true` when `tag` is the YAML non-specific tag `!`.
 ///
 /// The non-specific tag forces the failsafe schema: scalars resolve to plain strings
@@ -117,6 +312,11 @@ fn inject_implicit_null_if_empty(docs: Vec<Value>, input: &str) -> Vec<Value> {
 /// - Handle `True`, `TRUE`, `False`, `FALSE`, `Null` mixed-case variants.
 /// - Resolve YAML 1.1 merge keys (`<<: *anchor`) into parent mappings (#204).
 pub fn canonicalize(value: Value) -> Value {
+    canonicalize_with(value, ParseConfig::default())
+}
+
+/// [`canonicalize`], resolving merge keys only if `config` enables it.
+fn canonicalize_with(value: Value, config: ParseConfig) -> Value {
     match value {
         Value::Representation(ref s, style, ref tag) => {
             coerce_representation(s, style, tag.as_ref())
@@ -127,14 +327,21 @@ pub fn canonicalize(value: Value) -> Value {
             "Null" | "NULL" => Value::Value(ScalarOwned::Null),
             _ => value,
         },
-        Value::Tagged(ref tag, ref inner) => coerce_tagged(tag, inner),
-        Value::Sequence(seq) => Value::Sequence(seq.into_iter().map(canonicalize).collect()),
+        Value::Tagged(ref tag, ref inner) => coerce_tagged(tag, inner, config),
+        Value::Sequence(seq) => Value::Sequence(
+            seq.into_iter()
+                .map(|item| canonicalize_with(item, config))
+                .collect(),
+        ),
         Value::Mapping(map) => {
-            let canonicalized: crate::value::Map = map
+            let mut canonicalized: crate::value::Map = map
                 .into_iter()
-                .map(|(k, v)| (canonicalize(k), canonicalize(v)))
+                .map(|(k, v)| (canonicalize_with(k, config), canonicalize_with(v, config)))
                 .collect();
-            resolve_merge_keys(canonicalized)
+            if config.resolve_merge_keys {
+                resolve_merge_keys(&mut canonicalized);
+            }
+            Value::Mapping(canonicalized)
         }
         other => other,
     }
@@ -217,7 +424,7 @@ fn parse_core_schema_float(s: &str) -> Option<f64> {
 ///
 /// When `early_parse = false`, saphyr preserves the raw string, style, and tag in a
 /// `Representation` node. This function resolves that node to a typed `Value::Value`.
-fn coerce_representation(s: &str, style: ScalarStyle, tag: Option<&Tag>) -> Value {
+pub(crate) fn coerce_representation(s: &str, style: ScalarStyle, tag: Option<&Tag>) -> Value {
     // 1. Core-schema explicit tag (!!str, !!int, !!float, !!bool, !!null).
     if let Some(tag) = tag.filter(|t| t.is_yaml_core_schema()) {
         let coerced: Option<ScalarOwned> = match tag.suffix.as_str() {
@@ -269,7 +476,7 @@ fn coerce_representation(s: &str, style: ScalarStyle, tag: Option<&Tag>) -> Valu
 }
 
 /// Coerce a tagged value to the appropriate scalar type based on the YAML core schema tag suffix.
-fn coerce_tagged(tag: &Tag, inner: &Value) -> Value {
+fn coerce_tagged(tag: &Tag, inner: &Value, config: ParseConfig) -> Value {
     if tag.is_yaml_core_schema()
         && let Value::Value(ScalarOwned::String(ref s)) = *inner
     {
@@ -287,50 +494,7 @@ fn coerce_tagged(tag: &Tag, inner: &Value) -> Value {
             return Value::Value(scalar);
         }
     }
-    canonicalize(inner.clone())
-}
-
-/// Resolve YAML 1.1 merge keys (`<<`) in a canonicalized mapping.
-///
-/// Explicit keys always win over merged keys.
-fn resolve_merge_keys(map: crate::value::Map) -> Value {
-    let merge_key = Value::Value(ScalarOwned::String("<<".into()));
-    if !map.contains_key(&merge_key) {
-        return Value::Mapping(map);
-    }
-
-    let mut result: crate::value::Map = crate::value::Map::new();
-    let mut merges: Vec<Value> = Vec::new();
-
-    for (k, v) in map {
-        if k == merge_key {
-            merges.push(v);
-        } else {
-            result.insert(k, v);
-        }
-    }
-
-    for merge_val in merges {
-        match merge_val {
-            Value::Mapping(merge_map) => {
-                for (mk, mv) in merge_map {
-                    result.entry(mk).or_insert(mv);
-                }
-            }
-            Value::Sequence(seq) => {
-                for item in seq {
-                    if let Value::Mapping(merge_map) = item {
-                        for (mk, mv) in merge_map {
-                            result.entry(mk).or_insert(mv);
-                        }
-                    }
-                }
-            }
-            _ => {}
-        }
-    }
-
-    Value::Mapping(result)
+    canonicalize_with(inner.clone(), config)
 }
 
 #[cfg(test)]
@@ -355,6 +519,21 @@ mod tests {
         assert_eq!(docs.len(), 2);
     }
 
+    #[test]
+    fn test_parse_all_document_counts() {
+        for count in [0, 1, 2, 5] {
+            let input = (0..count)
+                .map(|i| format!("---\nid: {i}\n"))
+                .collect::<Vec<_>>()
+                .concat();
+            let docs = Parser::parse_all(&input).unwrap();
+            assert_eq!(docs.len(), count, "{input:?}");
+            for (i, doc) in docs.iter().enumerate() {
+                assert_eq!(doc["id"].as_integer(), i64::try_from(i).ok());
+            }
+        }
+    }
+
     #[test]
     fn test_yaml12_bool_true_variants() {
         for variant in &["True", "TRUE"] {
@@ -642,6 +821,53 @@ merged:
         assert!(m.contains_key(&z), "z should be present");
     }
 
+    #[test]
+    fn test_merge_key_inheritance_chain_round_trip() {
+        let yaml = r"
+base: &base
+  adapter: postgres
+  pool: 5
+staging: &staging
+  <<: *base
+  host: staging.local
+  pool: 10
+production:
+  <<: *staging
+  host: prod.local
+";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+        let expected = Parser::parse_str("{host: prod.local, adapter: postgres, pool: 10}")
+            .unwrap()
+            .unwrap();
+        assert_eq!(value["production"], expected);
+
+        let emitted = crate::Emitter::emit_str(&value).unwrap();
+        assert!(!emitted.contains("<<"), "{emitted}");
+        assert_eq!(Parser::parse_str(&emitted).unwrap().unwrap(), value);
+    }
+
+    #[test]
+    fn test_merge_key_resolution_disabled() {
+        let yaml = "base: &base {a: 1}\nchild:\n  <<: *base\n  b: 2\n";
+        let config = ParseConfig::new().with_resolve_merge_keys(false);
+        let value = Parser::parse_str_with_config(yaml, &config)
+            .unwrap()
+            .unwrap();
+        let Value::Mapping(child) = &value["child"] else {
+            panic!("expected mapping")
+        };
+        let keys: Vec<_> = child.keys().filter_map(Value::as_str).collect();
+        assert_eq!(keys, ["<<", "b"]);
+        assert_eq!(
+            child[&Value::Value(ScalarOwned::String("<<".into()))],
+            value["base"]
+        );
+        assert_eq!(
+            Parser::parse_str_with_config(yaml, &ParseConfig::default()).unwrap(),
+            Parser::parse_str(yaml).unwrap()
+        );
+    }
+
     #[test]
     fn test_i64_max_boundary() {
         let v = get_mapping_val("x: 9223372036854775807", "x");
@@ -956,4 +1182,91 @@ merged:
         // Null should not format as empty string.
         assert_ne!(formatted.trim(), "", "null doc must not format to empty");
     }
+
+    // --- parse_with_limit ---
+
+    #[test]
+    fn test_parse_with_limit_matches_parse_str() {
+        let yaml = "base: &base {a: 1, b: [x, y]}\nother:\n  <<: *base\n  c: 3\n";
+        let limited = Parser::parse_with_limit(yaml, 8, 100).unwrap();
+        assert_eq!(limited, Parser::parse_str(yaml).unwrap());
+    }
+
+    #[test]
+    fn test_parse_with_limit_empty_input() {
+        assert_eq!(Parser::parse_with_limit("", 8, 100).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_with_limit_alias_bomb() {
+        use std::fmt::Write as _;
+
+        // Nine levels of ten aliases each expand to 10^9 scalars.
+        let mut yaml = String::from("l0: &l0 [lol, lol, lol, lol, lol, lol, lol, lol, lol, lol]\n");
+        for level in 1..=9 {
+            let prev = level - 1;
+            let items = vec![format!("*l{prev}"); 10].join(", ");
+            writeln!(yaml, "l{level}: &l{level} [{items}]").unwrap();
+        }
+
+        let err =
+            Parser::parse_with_limit(&yaml, Parser::DEFAULT_MAX_DEPTH, Parser::DEFAULT_MAX_NODES)
+                .unwrap_err();
+        assert!(matches!(
+            err,
+            ParseError::NodeLimitExceeded {
+                max_nodes: Parser::DEFAULT_MAX_NODES,
+                line: 6,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_with_limit_node_count_includes_aliases() {
+        // 1 mapping + 2 keys + 1 sequence + 3 items + 3 copies via the alias
+        let yaml = "a: &a [1, 2, 3]\nb: *a\n";
+        assert!(Parser::parse_with_limit(yaml, 8, 11).is_ok());
+        assert!(matches!(
+            Parser::parse_with_limit(yaml, 8, 10),
+            Err(ParseError::NodeLimitExceeded { max_nodes: 10, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_with_limit_depth() {
+        let yaml = "a: {b: {c: [1]}}";
+        assert!(Parser::parse_with_limit(yaml, 4, 100).is_ok());
+        let err = Parser::parse_with_limit(yaml, 3, 100).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseError::DepthLimitExceeded {
+                max_depth: 3,
+                line: 1,
+                column: 12,
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "nesting depth exceeds limit of 3 at line 1, column 12"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_limit_deep_flow_nesting() {
+        let yaml = format!("{}{}", "[".repeat(200), "]".repeat(200));
+        assert!(Parser::parse_with_limit(&yaml, 200, 1_000).is_ok());
+        assert!(matches!(
+            Parser::parse_with_limit(&yaml, 64, 1_000),
+            Err(ParseError::DepthLimitExceeded { max_depth: 64, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_with_limit_invalid_yaml() {
+        assert!(matches!(
+            Parser::parse_with_limit("key: [", 8, 100),
+            Err(ParseError::Scanner(_))
+        ));
+    }
 }
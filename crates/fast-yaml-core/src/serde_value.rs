@@ -0,0 +1,326 @@
+//! `serde` support for [`Value`].
+//!
+//! `Value` is a re-export of saphyr's `YamlOwned`, so it cannot implement
+//! `Serialize` and `Deserialize` itself. [`SerdeValue`] wraps it instead, and
+//! the [`serialize`] and [`deserialize`] functions allow a `Value` field to
+//! be used with `#[serde(with = "fast_yaml_core::serde_value")]`.
+//!
+//! Values map to the serde data model as follows:
+//!
+//! - null → unit
+//! - boolean → `bool`
+//! - integer → `i64`
+//! - float → `f64`
+//! - string → `str`
+//! - sequence → seq
+//! - mapping → map
+//!
+//! Tags are dropped and plain scalar representations are resolved with the
+//! YAML 1.2 Core Schema. Aliases cannot be serialized. When deserializing,
+//! unsigned integers above `i64::MAX` become floats.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use ordered_float::OrderedFloat;
+use saphyr_parser::ScalarStyle;
+use serde::de::{self, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, SerializeMap, SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::value::{Map, ScalarOwned, Value};
+
+/// A [`Value`] that implements `Serialize` and `Deserialize`.
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_core::Parser;
+/// use fast_yaml_core::serde_value::SerdeValue;
+///
+/// let value = Parser::parse_str("name: app\nports: [80, 443]")?.unwrap();
+/// let json = serde_json::to_string(&SerdeValue(value.clone()))?;
+/// assert_eq!(json, r#"{"name":"app","ports":[80,443]}"#);
+///
+/// let back: SerdeValue = serde_json::from_str(&json)?;
+/// assert_eq!(back.0, value);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerdeValue(pub Value);
+
+impl SerdeValue {
+    /// Returns the wrapped value.
+    #[must_use]
+    pub fn into_inner(self) -> Value {
+        self.0
+    }
+}
+
+impl From<Value> for SerdeValue {
+    fn from(value: Value) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SerdeValue> for Value {
+    fn from(value: SerdeValue) -> Self {
+        value.0
+    }
+}
+
+impl Serialize for SerdeValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Self)
+    }
+}
+
+/// Serializes `value` into the serde data model.
+pub fn serialize<S: Serializer>(value: &Value, serializer: S) -> Result<S::Ok, S::Error> {
+    ValueRef(value).serialize(serializer)
+}
+
+/// Deserializes a [`Value`] from the serde data model.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Value, D::Error> {
+    deserializer.deserialize_any(ValueVisitor)
+}
+
+/// Borrowed value serialized by [`serialize`].
+struct ValueRef<'a>(&'a Value);
+
+impl Serialize for ValueRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Value(scalar) => serialize_scalar(scalar, serializer),
+            Value::Representation(text, ScalarStyle::Plain, _) => serialize_scalar(
+                &ScalarOwned::parse_from_cow(Cow::Borrowed(text)),
+                serializer,
+            ),
+            Value::Representation(text, _, _) => serializer.serialize_str(text),
+            Value::Sequence(items) => {
+                let mut seq = serializer.serialize_seq(Some(items.len()))?;
+                for item in items {
+                    seq.serialize_element(&ValueRef(item))?;
+                }
+                seq.end()
+            }
+            Value::Mapping(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(&ValueRef(key), &ValueRef(value))?;
+                }
+                map.end()
+            }
+            Value::Tagged(_, inner) => ValueRef(inner).serialize(serializer),
+            Value::Alias(_) => Err(ser::Error::custom("YAML aliases cannot be serialized")),
+            Value::BadValue => Err(ser::Error::custom("invalid YAML value")),
+        }
+    }
+}
+
+fn serialize_scalar<S: Serializer>(scalar: &ScalarOwned, serializer: S) -> Result<S::Ok, S::Error> {
+    match scalar {
+        ScalarOwned::Null => serializer.serialize_unit(),
+        ScalarOwned::Boolean(b) => serializer.serialize_bool(*b),
+        ScalarOwned::Integer(i) => serializer.serialize_i64(*i),
+        ScalarOwned::FloatingPoint(f) => serializer.serialize_f64(f.into_inner()),
+        ScalarOwned::String(s) => serializer.serialize_str(s),
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("any YAML value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::Null))
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        self.visit_unit()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        deserialize(deserializer)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::Boolean(v)))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::Integer(v)))
+    }
+
+    #[allow(clippy::cast_precision_loss)] // Out-of-range integers become floats
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::Value(i64::try_from(v).map_or_else(
+            |_| ScalarOwned::FloatingPoint(OrderedFloat(v as f64)),
+            ScalarOwned::Integer,
+        )))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::FloatingPoint(OrderedFloat(v))))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::String(v.to_string())))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Value, E> {
+        Ok(Value::Value(ScalarOwned::String(v)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut items = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(SerdeValue(item)) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Value::Sequence(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut entries = Map::new();
+        let Some(SerdeValue(first_key)) = map.next_key()? else {
+            return Ok(Value::Mapping(entries));
+        };
+        if matches!(&first_key, Value::Value(ScalarOwned::String(k)) if k == NUMBER_TOKEN) {
+            let text: String = map.next_value()?;
+            return parse_number(&text);
+        }
+        let SerdeValue(first_value) = map.next_value()?;
+        entries.insert(first_key, first_value);
+        while let Some((SerdeValue(key), SerdeValue(value))) = map.next_entry()? {
+            entries.insert(key, value);
+        }
+        Ok(Value::Mapping(entries))
+    }
+}
+
+/// Key under which `serde_json` passes numbers as strings when its
+/// `arbitrary_precision` feature is enabled anywhere in the build.
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+/// Converts the text of an `arbitrary_precision` number into a scalar.
+fn parse_number<E: de::Error>(text: &str) -> Result<Value, E> {
+    if let Ok(i) = text.parse::<i64>() {
+        return ValueVisitor.visit_i64(i);
+    }
+    if let Ok(u) = text.parse::<u64>() {
+        return ValueVisitor.visit_u64(u);
+    }
+    text.parse::<f64>()
+        .map_err(|_| E::invalid_value(de::Unexpected::Str(text), &"a number"))
+        .and_then(|f| ValueVisitor.visit_f64(f))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parser;
+
+    fn parse(yaml: &str) -> Value {
+        Parser::parse_str(yaml).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_serialize_to_json() {
+        let value = parse("n: ~\nb: true\ni: -3\nf: 2.5\ns: text\nl: [1, x]\nm: {k: v}\n");
+        let json = serde_json::to_value(SerdeValue(value)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "n": null, "b": true, "i": -3, "f": 2.5, "s": "text",
+                "l": [1, "x"], "m": {"k": "v"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_serialize_tagged_and_representation() {
+        let value = Value::Sequence(vec![
+            Value::Tagged(
+                saphyr_parser::Tag {
+                    handle: "!".to_string(),
+                    suffix: "custom".to_string(),
+                },
+                Box::new(Value::Value(ScalarOwned::Integer(1))),
+            ),
+            Value::Representation("2.0".to_string(), ScalarStyle::Plain, None),
+            Value::Representation("true".to_string(), ScalarStyle::DoubleQuoted, None),
+        ]);
+        let json = serde_json::to_value(SerdeValue(value)).unwrap();
+        assert_eq!(json, serde_json::json!([1, 2.0, "true"]));
+    }
+
+    #[test]
+    fn test_serialize_alias_fails() {
+        let err = serde_json::to_string(&SerdeValue(Value::Alias(1))).unwrap_err();
+        assert!(err.to_string().contains("aliases"), "{err}");
+    }
+
+    #[test]
+    fn test_deserialize_from_json() {
+        let value: SerdeValue =
+            serde_json::from_str(r#"{"a": [1, 2.5, null], "b": {"c": "d"}, "e": false}"#).unwrap();
+        assert_eq!(value.0, parse("a: [1, 2.5, ~]\nb: {c: d}\ne: false\n"));
+    }
+
+    #[test]
+    fn test_deserialize_arbitrary_precision_numbers() {
+        use serde::de::value::{Error, MapDeserializer};
+
+        let number = |text: &'static str| {
+            let map = MapDeserializer::<_, Error>::new(std::iter::once((NUMBER_TOKEN, text)));
+            deserialize(map).unwrap()
+        };
+        assert_eq!(number("-3"), Value::Value(ScalarOwned::Integer(-3)));
+        assert_eq!(
+            number("2.5"),
+            Value::Value(ScalarOwned::FloatingPoint(OrderedFloat(2.5)))
+        );
+        assert_eq!(
+            number("1e3"),
+            Value::Value(ScalarOwned::FloatingPoint(OrderedFloat(1000.0)))
+        );
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn test_deserialize_large_unsigned_as_float() {
+        let value: SerdeValue = serde_json::from_str("18446744073709551615").unwrap();
+        assert_eq!(
+            value.0,
+            Value::Value(ScalarOwned::FloatingPoint(OrderedFloat(u64::MAX as f64)))
+        );
+    }
+
+    #[test]
+    fn test_serde_with_field() {
+        #[derive(Serialize, Deserialize)]
+        struct Doc {
+            #[serde(with = "crate::serde_value")]
+            body: Value,
+        }
+
+        let doc = Doc {
+            body: parse("k: [v]"),
+        };
+        let json = serde_json::to_string(&doc).unwrap();
+        assert_eq!(json, r#"{"body":{"k":["v"]}}"#);
+        let back: Doc = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.body, doc.body);
+    }
+}
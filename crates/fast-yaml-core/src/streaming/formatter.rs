@@ -10,7 +10,7 @@ use saphyr_parser::{Event, ScalarStyle, Span, Tag};
 
 use super::traits::{AnchorStoreOps, ContextStackOps, FormatterBackend};
 use super::{Context, INDENT_SPACES, MAX_ANCHOR_ID, MAX_DEPTH};
-use crate::emitter::EmitterConfig;
+use crate::emitter::{EmitterConfig, NullStyle};
 
 /// Return the YAML chomp indicator suffix for a block scalar value.
 ///
@@ -27,6 +27,13 @@ fn chomp_indicator(value: &str) -> &'static str {
     }
 }
 
+/// A flow collection being written inline while `keep_original_styles` is set.
+struct FlowFrame {
+    mapping: bool,
+    /// Number of nodes written so far; in a mapping, even nodes are keys.
+    nodes: usize,
+}
+
 /// Generic streaming formatter with pluggable backend.
 ///
 /// This struct contains ALL formatting logic and is parameterized over
@@ -36,7 +43,9 @@ fn chomp_indicator(value: &str) -> &'static str {
 pub struct StreamingFormatter<'a, B: FormatterBackend> {
     config: &'a EmitterConfig,
     output: String,
-    indent_level: usize,
+    /// Column of the nodes of each open block collection, outermost first:
+    /// the dash of a sequence's items or the start of a mapping's keys.
+    columns: Vec<usize>,
     /// Tracks whether we need to emit a newline before the next value
     pending_newline: bool,
     /// Tracks whether the last character written was a newline.
@@ -53,6 +62,14 @@ pub struct StreamingFormatter<'a, B: FormatterBackend> {
     first_item_after_dash: bool,
     /// Backend providing context stack and anchor storage
     backend: B,
+    /// Input being formatted; read to tell flow collections from block ones.
+    source: &'a str,
+    /// Whether `source` is ASCII, so parser char indices are byte offsets.
+    source_is_ascii: bool,
+    /// Last char index and its byte offset resolved in a non-ASCII `source`.
+    source_cursor: (usize, usize),
+    /// Open flow collections, outermost first, kept in flow style.
+    flow: Vec<FlowFrame>,
 }
 
 impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
@@ -61,19 +78,29 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
     /// # Arguments
     ///
     /// * `config` - Emitter configuration (indent, `explicit_start`, etc.)
+    /// * `source` - Input the events are parsed from
     /// * `output_capacity` - Initial capacity for output buffer
     /// * `backend` - Backend providing context stack and anchor storage
-    pub fn new(config: &'a EmitterConfig, output_capacity: usize, backend: B) -> Self {
+    pub fn new(
+        config: &'a EmitterConfig,
+        source: &'a str,
+        output_capacity: usize,
+        backend: B,
+    ) -> Self {
         Self {
             config,
             output: String::with_capacity(output_capacity),
-            indent_level: 0,
+            columns: Vec::new(),
             pending_newline: false,
             last_char_newline: true, // Empty buffer conceptually "ends with" newline
             pending_space: false,
             first_key_after_dash: false,
             first_item_after_dash: false,
             backend,
+            source,
+            source_is_ascii: config.keep_original_styles && source.is_ascii(),
+            source_cursor: (0, 0),
+            flow: Vec::new(),
         }
     }
 
@@ -120,7 +147,11 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
     }
 
     /// Processes a parser event and updates formatter state.
-    pub fn format_event(&mut self, event: Event<'_>, _span: Span) {
+    pub fn format_event(&mut self, event: Event<'_>, span: Span) {
+        if !self.flow.is_empty() {
+            self.format_flow_event(event);
+            return;
+        }
         match event {
             Event::DocumentStart(explicit) => {
                 if explicit || self.config.explicit_start {
@@ -135,12 +166,20 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                     self.output.push('\n');
                     self.last_char_newline = true;
                 }
+                if self.config.explicit_end {
+                    self.output.push_str("...\n");
+                    self.last_char_newline = true;
+                }
             }
 
             Event::Scalar(value, style, anchor_id, tag) => {
                 self.emit_scalar(&value, style, anchor_id, tag.as_ref());
             }
 
+            Event::SequenceStart(anchor_id, _) if self.is_flow_collection(span) => {
+                self.start_flow(anchor_id, false);
+            }
+
             Event::SequenceStart(anchor_id, tag) => {
                 self.start_sequence(anchor_id, tag.as_ref());
             }
@@ -149,6 +188,10 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                 self.end_sequence();
             }
 
+            Event::MappingStart(anchor_id, _) if self.is_flow_collection(span) => {
+                self.start_flow(anchor_id, true);
+            }
+
             Event::MappingStart(anchor_id, tag) => {
                 self.start_mapping(anchor_id, tag.as_ref());
             }
@@ -171,10 +214,30 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
         value: &str,
         style: ScalarStyle,
         anchor_id: usize,
-        _tag: Option<&Cow<'_, Tag>>,
+        tag: Option<&Cow<'_, Tag>>,
     ) {
         let ctx = self.current_context();
+        let null_replacement = self.null_replacement(value, style, ctx, anchor_id);
+        let bare = null_replacement == Some("");
+        let style = self.normalized_style(value, style, tag);
+
+        self.write_inline_prefix(ctx, bare);
+
+        // Handle anchor if present (with bounds check for security)
+        self.emit_anchor_if_present(anchor_id, false);
+
+        // Emit value with appropriate style
+        self.emit_value_with_style(null_replacement.unwrap_or(value), style);
+
+        self.finish_inline_node(ctx);
+    }
 
+    /// Writes what goes before a node that is written on one line (a scalar
+    /// or a flow collection): the dash of a sequence item, the indentation of
+    /// a mapping key, or the space after a mapping key's colon.
+    ///
+    /// `bare` omits the space after a dash or colon for an empty value.
+    fn write_inline_prefix(&mut self, ctx: Context, bare: bool) {
         // Handle pending newline from document start or collection start
         if self.pending_newline {
             self.output.push('\n');
@@ -190,7 +253,7 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                 } else {
                     self.write_indent();
                 }
-                self.output.push_str("- ");
+                self.output.push_str(if bare { "-" } else { "- " });
                 self.last_char_newline = false;
             }
             Context::MappingKey => {
@@ -201,22 +264,23 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                 }
             }
             // Root level scalar needs no prefix; mapping value emits pending space
-            Context::Root => {}
+            Context::Root | Context::ComplexKey => {}
             Context::MappingValue => {
                 if self.pending_space {
-                    self.output.push(' ');
+                    if !bare {
+                        self.output.push(' ');
+                    }
                     self.pending_space = false;
                     self.last_char_newline = false;
                 }
             }
         }
+    }
 
-        // Handle anchor if present (with bounds check for security)
-        self.emit_anchor_if_present(anchor_id, false);
-
-        // Emit value with appropriate style
-        self.emit_value_with_style(value, style);
-
+    /// Ends a node written on one line: a mapping key is followed by its
+    /// colon, anything else by a newline unless it was a block scalar, which
+    /// already ends with one.
+    fn finish_inline_node(&mut self, ctx: Context) {
         // Handle context transitions
         match ctx {
             Context::MappingKey => {
@@ -232,17 +296,69 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                 self.last_char_newline = false;
             }
             Context::MappingValue => {
-                self.output.push('\n');
-                self.last_char_newline = true;
+                if !self.last_char_newline {
+                    self.output.push('\n');
+                    self.last_char_newline = true;
+                }
                 // Transition back to expecting key
                 if let Some(last) = self.backend.context_stack_mut().last_mut() {
                     *last = Context::MappingKey;
                 }
             }
-            Context::Sequence | Context::Root => {
-                self.output.push('\n');
-                self.last_char_newline = true;
+            Context::Sequence | Context::Root | Context::ComplexKey => {
+                if !self.last_char_newline {
+                    self.output.push('\n');
+                    self.last_char_newline = true;
+                }
+            }
+        }
+    }
+
+    /// Returns the text to write in place of a plain null scalar, according to
+    /// the configured `NullStyle`, or `None` to write the scalar unchanged.
+    ///
+    /// `NullStyle::Empty` only applies to mapping values and sequence items
+    /// without an anchor; elsewhere an empty value would change the document.
+    fn null_replacement(
+        &self,
+        value: &str,
+        style: ScalarStyle,
+        ctx: Context,
+        anchor_id: usize,
+    ) -> Option<&'static str> {
+        if style != ScalarStyle::Plain || !NullStyle::is_null(value) {
+            return None;
+        }
+        match self.config.null_style {
+            NullStyle::Tilde => Some("~"),
+            NullStyle::Null => Some("null"),
+            NullStyle::Empty
+                if anchor_id == 0 && matches!(ctx, Context::MappingValue | Context::Sequence) =>
+            {
+                Some("")
+            }
+            NullStyle::Empty | NullStyle::Preserve => None,
+        }
+    }
+
+    /// Returns the style to write a scalar in: double-quoted for untagged
+    /// plain strings selected by the configured `StringNormalization`,
+    /// otherwise `style` unchanged. `keep_original_styles` always keeps
+    /// `style`.
+    fn normalized_style(
+        &self,
+        value: &str,
+        style: ScalarStyle,
+        tag: Option<&Cow<'_, Tag>>,
+    ) -> ScalarStyle {
+        if self.config.keep_original_styles {
+            return style;
+        }
+        match self.config.string_normalization {
+            Some(mode) if style == ScalarStyle::Plain && tag.is_none() && mode.quotes(value) => {
+                ScalarStyle::DoubleQuoted
             }
+            _ => style,
         }
     }
 
@@ -338,9 +454,12 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                 }
             }
             Context::MappingKey => {
-                // Sequence as mapping key - unusual but valid
-                self.write_indent();
-                self.emit_anchor_if_present(anchor_id, false);
+                // Sequence as mapping key: "? " introduces the key and, like
+                // "- ", holds its first item unless an anchor takes the line.
+                self.write_complex_key_indicator();
+                if !self.emit_anchor_if_present(anchor_id, true) {
+                    self.first_item_after_dash = true;
+                }
             }
             Context::MappingValue => {
                 // Value position - emit anchor inline if present, then newline.
@@ -357,7 +476,7 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                 self.output.push('\n');
                 self.last_char_newline = true;
             }
-            Context::Root => {
+            Context::Root | Context::ComplexKey => {
                 if anchor_id > 0 && anchor_id <= MAX_ANCHOR_ID {
                     self.backend.anchor_store_mut().ensure_capacity(anchor_id);
                     let name = self.backend.anchor_store_mut().set_if_empty(anchor_id);
@@ -369,23 +488,26 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
             }
         }
 
-        // Update context for mapping value -> key transition
-        if ctx == Context::MappingValue
-            && let Some(last) = self.backend.context_stack_mut().last_mut()
-        {
-            *last = Context::MappingKey;
+        // Update context for mapping value -> key and key -> complex key transitions
+        if let Some(last) = self.backend.context_stack_mut().last_mut() {
+            match ctx {
+                Context::MappingValue => *last = Context::MappingKey,
+                Context::MappingKey => *last = Context::ComplexKey,
+                _ => {}
+            }
         }
 
         // Push sequence context and increase indent (with depth limit)
         if self.backend.context_stack().len() < MAX_DEPTH {
             self.backend.context_stack_mut().push(Context::Sequence);
-            self.indent_level += 1;
+            self.push_column(self.first_item_after_dash);
         }
     }
 
     fn end_sequence(&mut self) {
         self.backend.context_stack_mut().pop();
-        self.indent_level = self.indent_level.saturating_sub(1);
+        self.columns.pop();
+        self.finish_complex_key();
     }
 
     fn start_mapping(&mut self, anchor_id: usize, _tag: Option<&Cow<'_, Tag>>) {
@@ -425,9 +547,12 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                 }
             }
             Context::MappingKey => {
-                // Mapping as mapping key - unusual but valid (complex key)
-                self.write_indent();
-                self.emit_anchor_if_present(anchor_id, false);
+                // Mapping as mapping key: "? " introduces the key and, like
+                // "- ", holds its first key unless an anchor takes the line.
+                self.write_complex_key_indicator();
+                if !self.emit_anchor_if_present(anchor_id, true) {
+                    self.first_key_after_dash = true;
+                }
             }
             Context::MappingValue => {
                 // Value position - emit anchor inline if present, then newline.
@@ -444,7 +569,7 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                 self.output.push('\n');
                 self.last_char_newline = true;
             }
-            Context::Root => {
+            Context::Root | Context::ComplexKey => {
                 if anchor_id > 0 && anchor_id <= MAX_ANCHOR_ID {
                     self.backend.anchor_store_mut().ensure_capacity(anchor_id);
                     let name = self.backend.anchor_store_mut().set_if_empty(anchor_id);
@@ -456,23 +581,69 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
             }
         }
 
-        // Update context for mapping value -> key transition
-        if ctx == Context::MappingValue
-            && let Some(last) = self.backend.context_stack_mut().last_mut()
-        {
-            *last = Context::MappingKey;
+        // Update context for mapping value -> key and key -> complex key transitions
+        if let Some(last) = self.backend.context_stack_mut().last_mut() {
+            match ctx {
+                Context::MappingValue => *last = Context::MappingKey,
+                Context::MappingKey => *last = Context::ComplexKey,
+                _ => {}
+            }
         }
 
         // Push mapping context and increase indent (with depth limit)
         if self.backend.context_stack().len() < MAX_DEPTH {
             self.backend.context_stack_mut().push(Context::MappingKey);
-            self.indent_level += 1;
+            self.push_column(self.first_key_after_dash);
         }
     }
 
     fn end_mapping(&mut self) {
         self.backend.context_stack_mut().pop();
-        self.indent_level = self.indent_level.saturating_sub(1);
+        self.columns.pop();
+        self.finish_complex_key();
+    }
+
+    /// Records the column of a block collection being opened.
+    ///
+    /// A collection whose first node shares the line of a `- ` or `? `
+    /// indicator is aligned with that node, two columns in, whatever the
+    /// configured indent; otherwise it is indented one level from its parent.
+    fn push_column(&mut self, after_indicator: bool) {
+        let column = match self.columns.last() {
+            None => 0,
+            Some(&parent) if after_indicator => parent + 2,
+            Some(&parent) => parent + self.config.indent,
+        };
+        self.columns.push(column);
+    }
+
+    /// Writes the `? ` indicator that introduces a collection mapping key.
+    fn write_complex_key_indicator(&mut self) {
+        if self.first_key_after_dash {
+            self.first_key_after_dash = false;
+        } else {
+            self.write_indent();
+        }
+        self.output.push_str("? ");
+        self.last_char_newline = false;
+    }
+
+    /// Writes the `:` that follows a collection mapping key once it has been
+    /// closed, and moves the enclosing mapping on to expecting its value.
+    fn finish_complex_key(&mut self) {
+        if self.current_context() != Context::ComplexKey {
+            return;
+        }
+        if !self.last_char_newline {
+            self.output.push('\n');
+        }
+        self.write_indent();
+        self.output.push(':');
+        self.last_char_newline = false;
+        if let Some(last) = self.backend.context_stack_mut().last_mut() {
+            *last = Context::MappingValue;
+        }
+        self.pending_space = true;
     }
 
     fn emit_alias(&mut self, anchor_id: usize) {
@@ -499,7 +670,7 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
             Context::MappingKey => {
                 self.write_indent();
             }
-            Context::Root => {}
+            Context::Root | Context::ComplexKey => {}
             Context::MappingValue => {
                 if self.pending_space {
                     self.output.push(' ');
@@ -509,15 +680,7 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
             }
         }
 
-        // Emit the alias reference
-        self.output.push('*');
-        if let Some(name) = self.backend.anchor_store().get(anchor_id) {
-            self.output.push_str(name);
-        } else {
-            // Fallback: generate name directly into output
-            let _ = write!(self.output, "anchor{anchor_id}");
-        }
-        self.last_char_newline = false;
+        self.write_alias(anchor_id);
 
         // Handle context transitions
         match ctx {
@@ -536,13 +699,124 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
                     *last = Context::MappingKey;
                 }
             }
-            Context::Sequence | Context::Root => {
+            Context::Sequence | Context::Root | Context::ComplexKey => {
                 self.output.push('\n');
                 self.last_char_newline = true;
             }
         }
     }
 
+    /// Writes the `*name` reference to an anchor.
+    fn write_alias(&mut self, anchor_id: usize) {
+        self.output.push('*');
+        if let Some(name) = self.backend.anchor_store().get(anchor_id) {
+            self.output.push_str(name);
+        } else {
+            // Fallback: generate name directly into output
+            let _ = write!(self.output, "anchor{anchor_id}");
+        }
+        self.last_char_newline = false;
+    }
+
+    /// Returns true if `keep_original_styles` is set and the collection
+    /// starting at `span` is written in flow style (`[...]` or `{...}`).
+    ///
+    /// Block collection starts have an empty span or point at a `-`; flow
+    /// ones point at their opening bracket.
+    fn is_flow_collection(&mut self, span: Span) -> bool {
+        if !self.config.keep_original_styles || span.is_empty() {
+            return false;
+        }
+        let offset = if self.source_is_ascii {
+            Some(span.start.index())
+        } else {
+            self.byte_offset(span.start.index())
+        };
+        matches!(
+            offset.and_then(|offset| self.source.as_bytes().get(offset)),
+            Some(b'[' | b'{')
+        )
+    }
+
+    /// Converts a parser char index into a byte offset in a non-ASCII source.
+    ///
+    /// Events mostly arrive in source order, so the scan resumes from the
+    /// last offset found and only restarts when `sort_keys` moved back.
+    fn byte_offset(&mut self, char_index: usize) -> Option<usize> {
+        let (mut chars, mut bytes) = if char_index >= self.source_cursor.0 {
+            self.source_cursor
+        } else {
+            (0, 0)
+        };
+        let mut rest = self.source.get(bytes..)?.chars();
+        while chars < char_index {
+            bytes += rest.next()?.len_utf8();
+            chars += 1;
+        }
+        self.source_cursor = (chars, bytes);
+        Some(bytes)
+    }
+
+    /// Opens a flow collection in block context; its nodes are written
+    /// inline by `format_flow_event` until it closes.
+    fn start_flow(&mut self, anchor_id: usize, mapping: bool) {
+        let ctx = self.current_context();
+        self.write_inline_prefix(ctx, false);
+        self.emit_anchor_if_present(anchor_id, false);
+        self.open_flow(mapping);
+    }
+
+    fn open_flow(&mut self, mapping: bool) {
+        self.output.push(if mapping { '{' } else { '[' });
+        self.last_char_newline = false;
+        self.flow.push(FlowFrame { mapping, nodes: 0 });
+    }
+
+    /// Writes an event inside an open flow collection, keeping the original
+    /// scalar styles and writing nested collections in flow style too.
+    fn format_flow_event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Scalar(value, style, anchor_id, _) => {
+                self.write_flow_separator();
+                self.emit_anchor_if_present(anchor_id, false);
+                let replacement = self.null_replacement(&value, style, Context::Root, anchor_id);
+                self.emit_value_with_style(replacement.unwrap_or(&value), style);
+            }
+            Event::Alias(anchor_id) => {
+                self.write_flow_separator();
+                self.write_alias(anchor_id);
+            }
+            Event::SequenceStart(anchor_id, _) | Event::MappingStart(anchor_id, _) => {
+                let mapping = matches!(event, Event::MappingStart(..));
+                self.write_flow_separator();
+                self.emit_anchor_if_present(anchor_id, false);
+                self.open_flow(mapping);
+            }
+            Event::SequenceEnd | Event::MappingEnd => {
+                let mapping = self.flow.pop().is_some_and(|frame| frame.mapping);
+                self.output.push(if mapping { '}' } else { ']' });
+                if self.flow.is_empty() {
+                    let ctx = self.current_context();
+                    self.finish_inline_node(ctx);
+                }
+            }
+            // Document and stream events never occur inside a collection
+            _ => {}
+        }
+    }
+
+    /// Writes the `, ` or `: ` that goes before the next node of the
+    /// innermost flow collection.
+    fn write_flow_separator(&mut self) {
+        if let Some(frame) = self.flow.last_mut() {
+            if frame.nodes > 0 {
+                let value = frame.mapping && frame.nodes % 2 == 1;
+                self.output.push_str(if value { ": " } else { ", " });
+            }
+            frame.nodes += 1;
+        }
+    }
+
     /// Derive YAML block scalar chomp indicator from content.
     ///
     /// - `"+"` (keep): value ends with two or more newlines
@@ -563,13 +837,16 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
     /// Empty lines are emitted as bare `\n` (no trailing spaces) to match
     /// the non-streaming path in `emitter.rs`.
     fn write_block_scalar_lines(&mut self, value: &str) {
-        let indent_chars = self.indent_level.saturating_mul(self.config.indent);
+        let indent_chars = self
+            .columns
+            .last()
+            .map_or(0, |&column| column + self.config.indent);
 
-        // Track the last non-empty line position to handle keep (+) chomp.
-        // value.lines() drops trailing empty entries produced by trailing newlines.
-        let last_non_empty = value.trim_end_matches('\n');
+        // Trailing newlines are written after the content lines so that keep
+        // (+) chomp reproduces each of them exactly once.
+        let content = value.trim_end_matches('\n');
 
-        for line in value.lines() {
+        for line in content.lines() {
             // Blank lines inside block scalars must not receive indentation — that
             // would create trailing whitespace, which is a lint violation.
             if !line.is_empty() {
@@ -583,23 +860,17 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
             self.output.push('\n');
         }
 
-        // For keep (+) chomp: value.lines() drops trailing empty entries.
-        // After the last non-empty line, emit the extra blank lines that lines() skipped.
-        // Example: "text\n\n" → lines() yields ["text"], but we need "text\n\n".
-        // The loop above already emitted one \n after the last content line,
-        // so we emit (trailing_count - 1) additional newlines.
-        if value.ends_with("\n\n") {
-            let trailing_count = value.len() - last_non_empty.len();
-            for _ in 1..trailing_count {
-                self.output.push('\n');
-            }
+        // The loop already ended the last content line; emit the remaining
+        // trailing newlines. Example: "text\n\n" → "  text\n" + "\n".
+        let trailing_count = value.len() - content.len();
+        for _ in usize::from(!content.is_empty())..trailing_count {
+            self.output.push('\n');
         }
     }
 
     fn write_indent(&mut self) {
-        if self.indent_level > 1 {
-            let indent_chars = (self.indent_level - 1).saturating_mul(self.config.indent);
-
+        let indent_chars = self.columns.last().copied().unwrap_or(0);
+        if indent_chars > 0 {
             if indent_chars <= INDENT_SPACES.len() {
                 self.output.push_str(&INDENT_SPACES[..indent_chars]);
             } else {
@@ -610,9 +881,14 @@ impl<'a, B: FormatterBackend> StreamingFormatter<'a, B> {
     }
 
     /// Completes formatting and returns the output string.
+    ///
+    /// Non-empty output ends with a newline unless `trailing_newline` is disabled.
     pub fn finish(mut self) -> String {
-        // Ensure output ends with newline
-        if !self.output.is_empty() && !self.last_char_newline {
+        if !self.config.trailing_newline {
+            if self.output.ends_with('\n') {
+                self.output.pop();
+            }
+        } else if !self.output.is_empty() && !self.last_char_newline {
             self.output.push('\n');
         }
         self.output
@@ -0,0 +1,636 @@
+//! Streaming YAML-to-JSON conversion.
+//!
+//! Converts parser events directly into JSON text without building a DOM,
+//! so memory usage is bounded by nesting depth and anchored content rather
+//! than by document size.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Write as FmtWrite;
+use std::io::{self, BufRead, BufWriter, Write};
+use std::rc::Rc;
+
+use saphyr_parser::{BufferedInput, Event, Parser};
+
+use super::{MAX_ANCHOR_ID, MAX_DEPTH};
+use crate::error::{ConvertError, ConvertResult};
+use crate::parser::coerce_representation;
+use crate::value::{ScalarOwned, Value};
+
+/// Maximum number of bytes that alias replay may add to one document's JSON.
+/// Bounds output and anchor memory for "billion laughs" inputs.
+const MAX_ALIAS_EXPANSION: usize = 64 * 1024 * 1024;
+
+/// Position within the JSON output structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JsonContext {
+    /// Inside a JSON array
+    Array { first: bool },
+    /// Inside a JSON object, either expecting a key or the value for the last key
+    Object { first: bool, expect_key: bool },
+}
+
+/// Character iterator over a `BufRead`, reading one line at a time.
+///
+/// I/O errors end the iteration and are stored in `error` so the converter
+/// can report them instead of a misleading scanner error.
+struct ReaderChars<R> {
+    reader: R,
+    line: String,
+    pos: usize,
+    error: Rc<RefCell<Option<io::Error>>>,
+}
+
+impl<R: BufRead> Iterator for ReaderChars<R> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(ch) = self.line[self.pos..].chars().next() {
+                self.pos += ch.len_utf8();
+                return Some(ch);
+            }
+            self.line.clear();
+            self.pos = 0;
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(err) => {
+                    *self.error.borrow_mut() = Some(err);
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// Re-indents the compact JSON written by `JsonStreamWriter` the way
+/// `serde_json::to_string_pretty` lays it out: two spaces per level, `": "`
+/// after keys, and `[]`/`{}` for empty collections.
+///
+/// Working on the output bytes keeps alias replay simple: anchored nodes are
+/// recorded compact and indented for wherever they are replayed.
+struct PrettyJson<W: Write> {
+    out: W,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// An opening bracket was written; its line break waits until the next
+    /// byte shows whether the collection is empty.
+    pending_open: bool,
+}
+
+impl<W: Write> PrettyJson<W> {
+    const fn new(out: W) -> Self {
+        Self {
+            out,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            pending_open: false,
+        }
+    }
+
+    fn newline(&mut self) -> io::Result<()> {
+        self.out.write_all(b"\n")?;
+        for _ in 0..self.depth {
+            self.out.write_all(b"  ")?;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for PrettyJson<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for &byte in buf {
+            if self.pending_open {
+                self.pending_open = false;
+                if matches!(byte, b']' | b'}') {
+                    self.depth -= 1;
+                    self.out.write_all(&[byte])?;
+                    continue;
+                }
+                self.newline()?;
+            }
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                }
+                self.out.write_all(&[byte])?;
+                continue;
+            }
+            match byte {
+                b'"' => {
+                    self.in_string = true;
+                    self.out.write_all(b"\"")?;
+                }
+                b'[' | b'{' => {
+                    self.out.write_all(&[byte])?;
+                    self.depth += 1;
+                    self.pending_open = true;
+                }
+                b']' | b'}' => {
+                    self.depth = self.depth.saturating_sub(1);
+                    self.newline()?;
+                    self.out.write_all(&[byte])?;
+                }
+                b',' => {
+                    self.out.write_all(b",")?;
+                    self.newline()?;
+                }
+                b':' => self.out.write_all(b": ")?,
+                _ => self.out.write_all(&[byte])?,
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// Event-driven JSON writer.
+struct JsonStreamWriter<W: Write> {
+    out: W,
+    stack: Vec<JsonContext>,
+    /// Anchored collections being recorded: (anchor id, stack depth, JSON text)
+    captures: Vec<(usize, usize, String)>,
+    /// JSON text of completed anchored nodes, replayed on alias
+    anchors: HashMap<usize, String>,
+    /// Bytes replayed by aliases in the current document
+    expanded: usize,
+    /// Documents written so far
+    documents: usize,
+}
+
+impl<W: Write> JsonStreamWriter<W> {
+    fn new(out: W) -> Self {
+        Self {
+            out,
+            stack: Vec::with_capacity(32),
+            captures: Vec::new(),
+            anchors: HashMap::new(),
+            expanded: 0,
+            documents: 0,
+        }
+    }
+
+    fn write(&mut self, s: &str) -> ConvertResult<()> {
+        self.out.write_all(s.as_bytes())?;
+        for (_, _, buf) in &mut self.captures {
+            buf.push_str(s);
+        }
+        Ok(())
+    }
+
+    /// Writes the separator preceding a node and reports whether the node is a mapping key.
+    fn begin_node(&mut self) -> ConvertResult<bool> {
+        let (separator, is_key) = match self.stack.last_mut() {
+            None => (false, false),
+            Some(JsonContext::Array { first }) => {
+                let separator = !*first;
+                *first = false;
+                (separator, false)
+            }
+            Some(JsonContext::Object { first, expect_key }) => {
+                if *expect_key {
+                    let separator = !*first;
+                    *first = false;
+                    *expect_key = false;
+                    (separator, true)
+                } else {
+                    *expect_key = true;
+                    (false, false)
+                }
+            }
+        };
+        if separator {
+            self.write(",")?;
+        }
+        Ok(is_key)
+    }
+
+    /// Records the JSON text of an anchored node; anchors above `MAX_ANCHOR_ID` are not kept.
+    fn store_anchor(&mut self, anchor_id: usize, json: String) {
+        if anchor_id <= MAX_ANCHOR_ID {
+            self.anchors.insert(anchor_id, json);
+        }
+    }
+
+    fn start_collection(
+        &mut self,
+        anchor_id: usize,
+        context: JsonContext,
+        open: &str,
+        kind: &'static str,
+    ) -> ConvertResult<()> {
+        if self.begin_node()? {
+            return Err(ConvertError::UnsupportedKey(kind));
+        }
+        if self.stack.len() >= MAX_DEPTH {
+            return Err(ConvertError::TooDeep(MAX_DEPTH));
+        }
+        self.stack.push(context);
+        if anchor_id > 0 {
+            self.captures
+                .push((anchor_id, self.stack.len(), String::new()));
+        }
+        self.write(open)
+    }
+
+    fn end_collection(&mut self, close: &str) -> ConvertResult<()> {
+        self.write(close)?;
+        let depth = self.stack.len();
+        self.stack.pop();
+        while self.captures.last().is_some_and(|(_, d, _)| *d == depth) {
+            if let Some((anchor_id, _, json)) = self.captures.pop() {
+                self.store_anchor(anchor_id, json);
+            }
+        }
+        Ok(())
+    }
+
+    fn scalar(&mut self, scalar: &ScalarOwned, anchor_id: usize) -> ConvertResult<()> {
+        if self.begin_node()? {
+            let mut key = String::new();
+            write_json_string(&mut key, &key_string(scalar));
+            key.push(':');
+            self.write(&key)?;
+            if anchor_id > 0 {
+                self.store_anchor(anchor_id, scalar_to_json(scalar)?);
+            }
+        } else {
+            let json = scalar_to_json(scalar)?;
+            self.write(&json)?;
+            if anchor_id > 0 {
+                self.store_anchor(anchor_id, json);
+            }
+        }
+        Ok(())
+    }
+
+    fn alias(&mut self, anchor_id: usize) -> ConvertResult<()> {
+        if self.begin_node()? {
+            return Err(ConvertError::UnsupportedKey("alias"));
+        }
+        let json = self
+            .anchors
+            .get(&anchor_id)
+            .cloned()
+            .ok_or(ConvertError::UndefinedAlias(anchor_id))?;
+        self.expanded += json.len();
+        if self.expanded > MAX_ALIAS_EXPANSION {
+            return Err(ConvertError::AliasExpansionTooLarge(MAX_ALIAS_EXPANSION));
+        }
+        self.write(&json)
+    }
+
+    fn handle_event(&mut self, event: Event<'_>) -> ConvertResult<()> {
+        match event {
+            Event::Scalar(value, style, anchor_id, tag) => {
+                let scalar = match coerce_representation(&value, style, tag.as_deref()) {
+                    Value::Value(scalar) => scalar,
+                    _ => ScalarOwned::String(value.into_owned()),
+                };
+                self.scalar(&scalar, anchor_id)
+            }
+            Event::SequenceStart(anchor_id, _) => self.start_collection(
+                anchor_id,
+                JsonContext::Array { first: true },
+                "[",
+                "sequence",
+            ),
+            Event::SequenceEnd => self.end_collection("]"),
+            Event::MappingStart(anchor_id, _) => self.start_collection(
+                anchor_id,
+                JsonContext::Object {
+                    first: true,
+                    expect_key: true,
+                },
+                "{",
+                "mapping",
+            ),
+            Event::MappingEnd => self.end_collection("}"),
+            Event::Alias(anchor_id) => self.alias(anchor_id),
+            Event::DocumentEnd => {
+                // Anchors are scoped to a single document
+                self.anchors.clear();
+                self.expanded = 0;
+                self.documents += 1;
+                self.write("\n")
+            }
+            Event::StreamStart | Event::StreamEnd | Event::DocumentStart(_) | Event::Nothing => {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Convert YAML to JSON by streaming parser events, without building a DOM.
+///
+/// Each YAML document is written as one compact JSON value followed by a
+/// newline, so multi-document streams produce JSON Lines output. Mapping
+/// keys are written in input order. Returns the number of documents written.
+///
+/// Scalars are resolved with the same YAML 1.2 Core Schema rules as
+/// [`Parser`](crate::Parser). Aliases are expanded by replaying the JSON text of
+/// the anchored node, up to 64 MiB of replayed JSON per document. Merge keys
+/// (`<<`) are emitted as ordinary keys.
+///
+/// # Errors
+///
+/// Returns `ConvertError::Scanner` if the YAML syntax is invalid,
+/// `ConvertError::Io` on read or write failure, `ConvertError::UnsupportedKey`
+/// for sequence, mapping, or alias keys, `ConvertError::NonFiniteFloat`
+/// for `.inf`/`.nan` values, which have no JSON representation, and
+/// `ConvertError::AliasExpansionTooLarge` when aliases exceed the expansion limit.
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_core::streaming::convert_yaml_to_json_streaming;
+///
+/// let yaml = "name: test\nitems:\n  - 1\n  - true\n";
+/// let mut json = Vec::new();
+/// convert_yaml_to_json_streaming(yaml.as_bytes(), &mut json).unwrap();
+/// assert_eq!(
+///     String::from_utf8(json).unwrap(),
+///     "{\"name\":\"test\",\"items\":[1,true]}\n"
+/// );
+/// ```
+pub fn convert_yaml_to_json_streaming(
+    input: impl BufRead,
+    output: impl Write,
+) -> ConvertResult<usize> {
+    convert_streaming(input, BufWriter::new(output))
+}
+
+/// Convert YAML to pretty-printed JSON by streaming parser events.
+///
+/// Same as [`convert_yaml_to_json_streaming`], but each document is laid out
+/// like `serde_json::to_string_pretty` output, with two-space indentation.
+///
+/// # Errors
+///
+/// Same as [`convert_yaml_to_json_streaming`].
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_core::streaming::convert_yaml_to_json_streaming_pretty;
+///
+/// let mut json = Vec::new();
+/// convert_yaml_to_json_streaming_pretty("a: [1]\nb: {}\n".as_bytes(), &mut json).unwrap();
+/// assert_eq!(
+///     String::from_utf8(json).unwrap(),
+///     "{\n  \"a\": [\n    1\n  ],\n  \"b\": {}\n}\n"
+/// );
+/// ```
+pub fn convert_yaml_to_json_streaming_pretty(
+    input: impl BufRead,
+    output: impl Write,
+) -> ConvertResult<usize> {
+    convert_streaming(input, PrettyJson::new(BufWriter::new(output)))
+}
+
+fn convert_streaming(input: impl BufRead, output: impl Write) -> ConvertResult<usize> {
+    let read_error = Rc::new(RefCell::new(None));
+    let chars = ReaderChars {
+        reader: input,
+        line: String::new(),
+        pos: 0,
+        error: Rc::clone(&read_error),
+    };
+    let mut writer = JsonStreamWriter::new(output);
+
+    for result in Parser::new(BufferedInput::new(chars)) {
+        if let Some(err) = read_error.borrow_mut().take() {
+            return Err(err.into());
+        }
+        let (event, _span) = result?;
+        writer.handle_event(event)?;
+    }
+    if let Some(err) = read_error.borrow_mut().take() {
+        return Err(err.into());
+    }
+
+    writer.out.flush()?;
+    Ok(writer.documents)
+}
+
+/// Render a resolved scalar as a JSON value.
+fn scalar_to_json(scalar: &ScalarOwned) -> ConvertResult<String> {
+    Ok(match scalar {
+        ScalarOwned::Null => "null".to_string(),
+        ScalarOwned::Boolean(b) => b.to_string(),
+        ScalarOwned::Integer(i) => i.to_string(),
+        ScalarOwned::FloatingPoint(f) => {
+            if !f.is_finite() {
+                return Err(ConvertError::NonFiniteFloat(f.to_string()));
+            }
+            format!("{:?}", f.into_inner())
+        }
+        ScalarOwned::String(s) => {
+            let mut json = String::with_capacity(s.len() + 2);
+            write_json_string(&mut json, s);
+            json
+        }
+    })
+}
+
+/// Stringify a scalar mapping key the same way DOM-based conversion does.
+fn key_string(scalar: &ScalarOwned) -> String {
+    match scalar {
+        ScalarOwned::Null => "null".to_string(),
+        ScalarOwned::Boolean(b) => b.to_string(),
+        ScalarOwned::Integer(i) => i.to_string(),
+        ScalarOwned::FloatingPoint(f) => f.to_string(),
+        ScalarOwned::String(s) => s.clone(),
+    }
+}
+
+/// Append `s` to `buf` as a quoted, escaped JSON string.
+fn write_json_string(buf: &mut String, s: &str) {
+    buf.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\u{8}' => buf.push_str("\\b"),
+            '\u{c}' => buf.push_str("\\f"),
+            c if c < '\u{20}' => {
+                let _ = write!(buf, "\\u{:04x}", c as u32);
+            }
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(yaml: &str) -> ConvertResult<String> {
+        let mut out = Vec::new();
+        convert_yaml_to_json_streaming(yaml.as_bytes(), &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(convert("42").unwrap(), "42\n");
+        assert_eq!(convert("~").unwrap(), "null\n");
+        assert_eq!(convert("true").unwrap(), "true\n");
+        assert_eq!(convert("1.5").unwrap(), "1.5\n");
+        assert_eq!(convert("'42'").unwrap(), "\"42\"\n");
+    }
+
+    #[test]
+    fn test_nested_structures() {
+        let yaml = "a:\n  b: [1, 2]\n  c: {d: x}\nlist:\n  - {}\n  - []\n";
+        assert_eq!(
+            convert(yaml).unwrap(),
+            "{\"a\":{\"b\":[1,2],\"c\":{\"d\":\"x\"}},\"list\":[{},[]]}\n"
+        );
+    }
+
+    #[test]
+    fn test_scalar_keys_are_stringified() {
+        assert_eq!(
+            convert("1: a\ntrue: b\n~: c\n").unwrap(),
+            "{\"1\":\"a\",\"true\":\"b\",\"null\":\"c\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_complex_key_is_rejected() {
+        let err = convert("? [a, b]\n: value\n").unwrap_err();
+        assert!(matches!(err, ConvertError::UnsupportedKey("sequence")));
+    }
+
+    #[test]
+    fn test_string_escaping() {
+        assert_eq!(
+            convert("\"a\\\"b\\\\c\\nd\\u0001\"").unwrap(),
+            "\"a\\\"b\\\\c\\nd\\u0001\"\n"
+        );
+    }
+
+    #[test]
+    fn test_aliases_are_expanded() {
+        let yaml = "base: &b {x: 1, y: [2]}\nname: &n foo\ncopy: *b\nalso: *n\n";
+        assert_eq!(
+            convert(yaml).unwrap(),
+            "{\"base\":{\"x\":1,\"y\":[2]},\"name\":\"foo\",\"copy\":{\"x\":1,\"y\":[2]},\"also\":\"foo\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_alias_bomb_is_rejected() {
+        // Nine levels of ten aliases each expand to 10^9 scalars.
+        let mut yaml = String::from("l0: &l0 [lol, lol, lol, lol, lol, lol, lol, lol, lol, lol]\n");
+        for level in 1..=9 {
+            let prev = level - 1;
+            let items = vec![format!("*l{prev}"); 10].join(", ");
+            writeln!(yaml, "l{level}: &l{level} [{items}]").unwrap();
+        }
+
+        let err = convert(&yaml).unwrap_err();
+        assert!(matches!(
+            err,
+            ConvertError::AliasExpansionTooLarge(MAX_ALIAS_EXPANSION)
+        ));
+    }
+
+    #[test]
+    fn test_non_finite_float_is_rejected() {
+        let err = convert("value: .inf\n").unwrap_err();
+        assert!(matches!(err, ConvertError::NonFiniteFloat(_)));
+    }
+
+    #[test]
+    fn test_multi_document_writes_json_lines() {
+        assert_eq!(
+            convert("---\na: 1\n---\nb: 2\n").unwrap(),
+            "{\"a\":1}\n{\"b\":2}\n"
+        );
+    }
+
+    #[test]
+    fn test_returns_document_count() {
+        let mut out = Vec::new();
+        assert_eq!(
+            convert_yaml_to_json_streaming(&b"---\na: 1\n---\nb\n"[..], &mut out).unwrap(),
+            2
+        );
+        assert_eq!(
+            convert_yaml_to_json_streaming(&b"# only a comment\n"[..], &mut out).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_pretty_matches_serde_json_layout() {
+        let yaml = "base: &b {x: \"a:b, {c}\", y: [\"\\\"[\"]}\nlist:\n  - {}\n  - []\n  - nested: *b\n---\n42\n";
+        let mut out = Vec::new();
+        convert_yaml_to_json_streaming_pretty(yaml.as_bytes(), &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            indoc::indoc! {r#"
+                {
+                  "base": {
+                    "x": "a:b, {c}",
+                    "y": [
+                      "\"["
+                    ]
+                  },
+                  "list": [
+                    {},
+                    [],
+                    {
+                      "nested": {
+                        "x": "a:b, {c}",
+                        "y": [
+                          "\"["
+                        ]
+                      }
+                    }
+                  ]
+                }
+                42
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_matches_dom_parser_for_scalars() {
+        let yaml = "int: 0x1F\nfloat: 1e3\nneg: -0.5\nstr: !!str 12\n";
+        assert_eq!(
+            convert(yaml).unwrap(),
+            "{\"int\":31,\"float\":1000.0,\"neg\":-0.5,\"str\":\"12\"}\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_yaml_is_scanner_error() {
+        let err = convert("key: [unclosed\n").unwrap_err();
+        assert!(matches!(err, ConvertError::Scanner(_)));
+    }
+
+    #[test]
+    fn test_too_deep() {
+        let mut writer = JsonStreamWriter::new(Vec::new());
+        let result =
+            (0..=MAX_DEPTH).try_for_each(|_| writer.handle_event(Event::SequenceStart(0, None)));
+        assert!(matches!(result, Err(ConvertError::TooDeep(MAX_DEPTH))));
+    }
+}
@@ -22,7 +22,7 @@
 //! let yaml = "key: value\nlist:\n  - item1\n  - item2\n";
 //! let config = EmitterConfig::default();
 //!
-//! if is_streaming_suitable(yaml) {
+//! if is_streaming_suitable(yaml, config.streaming_threshold) {
 //!     let formatted = format_streaming(yaml, &config).unwrap();
 //!     println!("{formatted}");
 //! }
@@ -30,6 +30,7 @@
 //! ```
 
 mod formatter;
+mod json;
 mod std_backend;
 mod traits;
 
@@ -37,11 +38,18 @@ mod traits;
 mod arena_backend;
 
 // Re-export public API
+pub use json::{convert_yaml_to_json_streaming, convert_yaml_to_json_streaming_pretty};
 pub use std_backend::format_streaming;
 
 #[cfg(feature = "arena")]
 pub use arena_backend::format_streaming_arena;
 
+use std::collections::{HashMap, HashSet};
+
+use saphyr_parser::{Event, Parser, Span};
+
+use crate::error::{EmitError, EmitResult};
+
 /// Maximum allowed anchor ID to prevent memory exhaustion attacks.
 /// 4096 anchors is more than sufficient for any legitimate YAML file.
 const MAX_ANCHOR_ID: usize = 4096;
@@ -65,6 +73,8 @@ pub(crate) enum Context {
     MappingKey,
     /// Inside a mapping, expecting a value
     MappingValue,
+    /// Inside a mapping, writing a collection key introduced by `? `
+    ComplexKey,
 }
 
 /// Fix special float value for YAML 1.2 compliance.
@@ -172,15 +182,229 @@ pub(super) fn extract_anchor_names(input: &str) -> Vec<String> {
     names
 }
 
+/// Parse `input` into events with the entries of every mapping in key order.
+///
+/// Used when `sort_keys` is set: a mapping has to be read in full before its
+/// first entry can be written, so all events are buffered. Scalar keys sort
+/// by their text, and collection keys keep their relative order after them.
+/// When sorting moves an alias ahead of its anchor, the anchored node is
+/// written at the alias and the alias at the node's place; see
+/// [`anchors_first`].
+pub(super) fn sorted_events(input: &str) -> EmitResult<Vec<(Event<'_>, Span)>> {
+    let mut events = Vec::new();
+    for result in Parser::new_from_str(input) {
+        events.push(result.map_err(|e| EmitError::Emit(e.to_string()))?);
+    }
+
+    let mut order = Vec::with_capacity(events.len());
+    let mut i = 0;
+    while i < events.len() {
+        if !matches!(events[i].0, Event::DocumentStart(_)) {
+            order.push(i);
+            i += 1;
+            continue;
+        }
+        let end = events[i..]
+            .iter()
+            .position(|(event, _)| matches!(event, Event::DocumentEnd))
+            .map_or(events.len(), |offset| i + offset);
+        order.push(i);
+        i += 1;
+        while i < end {
+            i = push_sorted_node(&events, i, 0, &mut order);
+        }
+    }
+    if events
+        .iter()
+        .any(|(event, _)| matches!(event, Event::Alias(_)))
+    {
+        order = anchors_first(&events, &order);
+    }
+
+    let mut slots: Vec<_> = events.into_iter().map(Some).collect();
+    Ok(order
+        .into_iter()
+        .filter_map(|index| slots[index].take())
+        .collect())
+}
+
+/// Append the indices of the node starting at `start` to `order`, with
+/// mapping entries sorted by key, and return the index just past the node.
+///
+/// Nodes nested deeper than `MAX_DEPTH` keep their input order.
+fn push_sorted_node(
+    events: &[(Event<'_>, Span)],
+    start: usize,
+    depth: usize,
+    order: &mut Vec<usize>,
+) -> usize {
+    if depth >= MAX_DEPTH {
+        let end = node_end(events, start);
+        order.extend(start..end);
+        return end;
+    }
+
+    match events[start].0 {
+        Event::SequenceStart(..) => {
+            order.push(start);
+            let mut i = start + 1;
+            while i < events.len() && !matches!(events[i].0, Event::SequenceEnd) {
+                i = push_sorted_node(events, i, depth + 1, order);
+            }
+            order.push(i);
+            i + 1
+        }
+        Event::MappingStart(..) => {
+            let mut entries = Vec::new();
+            let mut i = start + 1;
+            while i < events.len() && !matches!(events[i].0, Event::MappingEnd) {
+                let value = node_end(events, i);
+                entries.push((i, value));
+                i = node_end(events, value);
+            }
+            entries.sort_by(|&(a, _), &(b, _)| {
+                let (a, b) = (scalar_text(&events[a].0), scalar_text(&events[b].0));
+                a.is_none().cmp(&b.is_none()).then_with(|| a.cmp(&b))
+            });
+
+            order.push(start);
+            for (key, value) in entries {
+                push_sorted_node(events, key, depth + 1, order);
+                push_sorted_node(events, value, depth + 1, order);
+            }
+            order.push(i);
+            i + 1
+        }
+        _ => {
+            order.push(start);
+            start + 1
+        }
+    }
+}
+
+/// Reorder `order` so that every anchored node is written before its aliases.
+///
+/// An alias met before its anchor takes the anchored node's events, and the
+/// node's own position gets the alias event instead. Both stand for the same
+/// value, so the document is unchanged apart from where the anchor sits.
+fn anchors_first(events: &[(Event<'_>, Span)], order: &[usize]) -> Vec<usize> {
+    let mut reorder = AnchorReorder {
+        events,
+        order,
+        anchors: HashMap::new(),
+        defined: HashSet::new(),
+        replaced: HashMap::new(),
+        out: Vec::with_capacity(order.len()),
+    };
+    for (pos, &index) in order.iter().enumerate() {
+        if let Some(anchor_id) = anchor_id(&events[index].0) {
+            reorder.anchors.insert(anchor_id, pos);
+        }
+    }
+    reorder.emit(0, order.len());
+    reorder.out
+}
+
+/// State of [`anchors_first`].
+struct AnchorReorder<'a, 'e> {
+    events: &'a [(Event<'e>, Span)],
+    order: &'a [usize],
+    /// Position in `order` of each anchored node
+    anchors: HashMap<usize, usize>,
+    /// Anchors already written to `out`
+    defined: HashSet<usize>,
+    /// Positions of moved nodes, with the alias event written in their place
+    replaced: HashMap<usize, usize>,
+    out: Vec<usize>,
+}
+
+impl AnchorReorder<'_, '_> {
+    /// Number of events in the node whose first event is at `pos`.
+    fn node_len(&self, pos: usize) -> usize {
+        let index = self.order[pos];
+        node_end(self.events, index) - index
+    }
+
+    /// Write the events at positions `from..to` of `order` to `out`.
+    fn emit(&mut self, from: usize, to: usize) {
+        let mut pos = from;
+        while pos < to {
+            if let Some(alias) = self.replaced.remove(&pos) {
+                self.out.push(alias);
+                pos += self.node_len(pos);
+                continue;
+            }
+            let index = self.order[pos];
+            if let Event::Alias(anchor_id) = self.events[index].0
+                && !self.defined.contains(&anchor_id)
+                && let Some(&anchor) = self.anchors.get(&anchor_id)
+                && anchor > pos
+            {
+                let len = self.node_len(anchor);
+                self.emit(anchor, anchor + len);
+                self.replaced.insert(anchor, index);
+            } else {
+                if let Some(anchor_id) = anchor_id(&self.events[index].0) {
+                    self.defined.insert(anchor_id);
+                }
+                self.out.push(index);
+            }
+            pos += 1;
+        }
+    }
+}
+
+/// Return the anchor ID of a node's first event, if it has one.
+const fn anchor_id(event: &Event<'_>) -> Option<usize> {
+    match *event {
+        Event::Scalar(_, _, anchor_id, _)
+        | Event::SequenceStart(anchor_id, _)
+        | Event::MappingStart(anchor_id, _)
+            if anchor_id > 0 =>
+        {
+            Some(anchor_id)
+        }
+        _ => None,
+    }
+}
+
+/// Return the index just past the node starting at `start`.
+fn node_end(events: &[(Event<'_>, Span)], start: usize) -> usize {
+    let mut depth = 0usize;
+    for (i, (event, _)) in events.iter().enumerate().skip(start) {
+        match event {
+            Event::SequenceStart(..) | Event::MappingStart(..) => depth += 1,
+            Event::SequenceEnd | Event::MappingEnd => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        if depth == 0 {
+            return i + 1;
+        }
+    }
+    events.len()
+}
+
+/// Return the text of a scalar event, or `None` for any other event.
+fn scalar_text<'e>(event: &'e Event<'_>) -> Option<&'e str> {
+    match event {
+        Event::Scalar(value, ..) => Some(value.as_ref()),
+        _ => None,
+    }
+}
+
 /// Check if input is suitable for streaming formatter.
 ///
-/// Returns `true` for inputs that benefit from streaming:
-/// - Large files (>1KB)
-/// - Files without heavy anchor/alias usage
+/// Returns `true` for:
+/// - Inputs of at least `threshold` bytes
+/// - Inputs of any size that contain `&`, `*`, or a line starting with `---`
+///   or `...`: the DOM path expands aliases and drops anchors and document
+///   markers, which the streaming formatter keeps
+///
+/// Returns `false` for other inputs smaller than `threshold`, where the
+/// streaming overhead is not worth it.
 ///
-/// Returns `false` for:
-/// - Small files (streaming overhead not worth it)
-/// - Files with heavy anchor/alias usage (DOM better for resolution)
+/// `threshold` is usually [`EmitterConfig::streaming_threshold`](crate::EmitterConfig::streaming_threshold);
+/// `0` accepts inputs of any size.
 ///
 /// # Examples
 ///
@@ -189,40 +413,32 @@ pub(super) fn extract_anchor_names(input: &str) -> Vec<String> {
 /// # {
 /// use fast_yaml_core::streaming::is_streaming_suitable;
 ///
-/// // Regular files - use streaming (preserves float types)
-/// assert!(is_streaming_suitable("version: 1.0"));
-/// assert!(is_streaming_suitable("small: yaml"));
+/// // Small files - below a 1KB threshold
+/// assert!(!is_streaming_suitable("small: yaml", 1024));
+/// assert!(is_streaming_suitable("small: yaml", 0));
 ///
-/// // Large files - also use streaming
+/// // Large files - use streaming
 /// let large = "key: value\n".repeat(1000);
-/// assert!(is_streaming_suitable(&large));
+/// assert!(is_streaming_suitable(&large, 1024));
+///
+/// // Anchors and aliases only survive the streaming formatter
+/// assert!(is_streaming_suitable("a: &x 1\nb: *x", 1024));
 /// # }
 /// ```
-pub fn is_streaming_suitable(input: &str) -> bool {
-    // Streaming preserves the original scalar text (e.g. "1.0" stays "1.0"),
-    // while DOM-based formatting loses type information (float 1.0 → integer 1).
-    // Always prefer streaming to maintain YAML 1.2.2 Core Schema type fidelity.
-
-    // Heavy anchor/alias usage: avoid streaming only when anchor density is very
-    // high, because the DOM path resolves aliases during parse.
-    let len = input.len();
-    if len > 0 {
-        let anchor_count = input.bytes().filter(|&b| b == b'&').count();
-        let alias_count = input.bytes().filter(|&b| b == b'*').count();
-
-        // More than 10 anchors/aliases per 1000 bytes → fall back to DOM
-        if (anchor_count + alias_count) * 100 > len {
-            return false;
-        }
-    }
-
-    true
+pub fn is_streaming_suitable(input: &str, threshold: usize) -> bool {
+    // `&` and `*` may also sit inside a string; streaming those inputs too
+    // costs a little speed but never changes the output.
+    input.len() >= threshold
+        || input.contains(['&', '*'])
+        || input
+            .lines()
+            .any(|line| line.starts_with("---") || line.starts_with("..."))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::EmitterConfig;
+    use crate::{EmitterConfig, NullStyle, StringNormalization};
 
     #[test]
     fn test_format_streaming_simple_scalar() {
@@ -279,6 +495,95 @@ mod tests {
         assert!(result.starts_with("---"));
     }
 
+    #[test]
+    fn test_format_streaming_with_explicit_end() {
+        let config = EmitterConfig::new()
+            .with_explicit_start(true)
+            .with_explicit_end(true);
+        let result = format_streaming("key: value", &config).unwrap();
+        assert_eq!(result, "---\nkey: value\n...\n");
+
+        let docs = crate::Parser::parse_all(&result).unwrap();
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0]["key"].as_str(), Some("value"));
+    }
+
+    #[test]
+    fn test_format_streaming_sort_keys() {
+        let yaml = "b: 1\na:\n  z:\n    - y: 1\n      x: 2\n  c: 'text'\n---\nd: 3\nc: 4\n";
+        let config = EmitterConfig::new().with_sort_keys(true);
+        assert_eq!(
+            format_streaming(yaml, &config).unwrap(),
+            "a:\n  c: 'text'\n  z:\n    - x: 2\n      y: 1\nb: 1\n---\nc: 4\nd: 3\n"
+        );
+
+        #[cfg(feature = "arena")]
+        assert_eq!(
+            format_streaming_arena(yaml, &config).unwrap(),
+            format_streaming(yaml, &config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_sort_keys_collection_keys_last() {
+        let yaml = "? [x]\n: 1\nb: 2\na: 3\n";
+        let config = EmitterConfig::new().with_sort_keys(true);
+        let result = format_streaming(yaml, &config).unwrap();
+        assert!(result.starts_with("a: 3\nb: 2\n"), "{result}");
+    }
+
+    #[test]
+    fn test_format_streaming_sort_keys_moves_anchor_before_alias() {
+        let yaml = "b: &x 1\na: *x\n";
+        let config = EmitterConfig::new().with_sort_keys(true);
+        assert_eq!(format_streaming(yaml, &config).unwrap(), "a: &x 1\nb: *x\n");
+    }
+
+    #[test]
+    fn test_format_streaming_sort_keys_with_nested_aliases() {
+        let yaml = "z: &outer\n  k: &inner [1, 2]\n  j: 3\ny: *inner\nx: *outer\nw: *inner\n";
+        let config = EmitterConfig::new().with_sort_keys(true);
+        let result = format_streaming(yaml, &config).unwrap();
+        assert_eq!(
+            result,
+            "w: &inner\n  - 1\n  - 2\nx: &outer\n  j: 3\n  k: *inner\ny: *inner\nz: *outer\n"
+        );
+
+        #[cfg(feature = "arena")]
+        assert_eq!(format_streaming_arena(yaml, &config).unwrap(), result);
+    }
+
+    #[test]
+    fn test_format_streaming_sequence_as_key() {
+        let yaml = "? [a, b]\n: 1\nc:\n  ? - p\n    - q\n  : [x, y]\n";
+        let config = EmitterConfig::default();
+        let result = format_streaming(yaml, &config).unwrap();
+        assert_eq!(
+            result,
+            "? - a\n  - b\n: 1\nc:\n  ? - p\n    - q\n  :\n    - x\n    - y\n"
+        );
+        assert_eq!(
+            crate::Parser::parse_str(&result).unwrap(),
+            crate::Parser::parse_str(yaml).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_mapping_as_key() {
+        let yaml = "? {x: 1, y: 2}\n: v\nz: w\n";
+        let config = EmitterConfig::default();
+        let result = format_streaming(yaml, &config).unwrap();
+        assert_eq!(result, "? x: 1\n  y: 2\n: v\nz: w\n");
+        assert_eq!(
+            crate::Parser::parse_str(&result).unwrap(),
+            crate::Parser::parse_str(yaml).unwrap()
+        );
+
+        // An anchored key inside a sequence item
+        let result = format_streaming("- ? &k {m: n}\n  : {o: p}\n", &config).unwrap();
+        assert_eq!(result, "- ? &k\n    m: n\n  :\n    o: p\n");
+    }
+
     #[test]
     fn test_format_streaming_quoted_strings() {
         let yaml = r#"single: 'quoted'
@@ -318,30 +623,37 @@ double: "quoted""#;
 
     #[test]
     fn test_is_streaming_suitable_small() {
-        // Small files now use streaming to preserve float types (issue #66)
-        assert!(is_streaming_suitable("small: yaml"));
-        assert!(is_streaming_suitable("key: value\nlist:\n  - a\n  - b"));
-        assert!(is_streaming_suitable("version: 1.0"));
-        assert!(is_streaming_suitable("count: 1.23e10"));
+        // Small files use streaming when the threshold allows it (issue #66)
+        assert!(is_streaming_suitable("small: yaml", 0));
+        assert!(is_streaming_suitable("key: value\nlist:\n  - a\n  - b", 0));
+        assert!(is_streaming_suitable("version: 1.0", 0));
+        assert!(is_streaming_suitable("count: 1.23e10", 0));
+
+        assert!(!is_streaming_suitable("small: yaml", 1024));
+        assert!(is_streaming_suitable("small: yaml", 11));
     }
 
     #[test]
     fn test_is_streaming_suitable_large() {
         let large = "key: value\n".repeat(200); // ~2.2KB
-        assert!(is_streaming_suitable(&large));
+        assert!(is_streaming_suitable(&large, 1024));
+        assert!(!is_streaming_suitable(&large, large.len() + 1));
     }
 
     #[test]
-    fn test_is_streaming_suitable_heavy_anchors() {
+    fn test_is_streaming_suitable_anchors_and_markers() {
         use std::fmt::Write;
         let mut heavy_anchors = String::new();
         for i in 0..100 {
             writeln!(heavy_anchors, "key{i}: &anchor{i} value{i}").unwrap();
         }
-        assert!(
-            !is_streaming_suitable(&heavy_anchors),
-            "Heavy anchor usage should not be suitable for streaming"
-        );
+        assert!(is_streaming_suitable(&heavy_anchors, 0));
+
+        // Small inputs the DOM path would not reproduce stream regardless of size
+        assert!(is_streaming_suitable("a: &x 1\nb: *x\n", 1024));
+        assert!(is_streaming_suitable("---\na: 1\n", 1024));
+        assert!(is_streaming_suitable("a: 1\n...\n", 1024));
+        assert!(!is_streaming_suitable("a: 1\nb: --- x\n", 1024));
     }
 
     #[test]
@@ -447,6 +759,17 @@ double: "quoted""#;
         );
     }
 
+    #[test]
+    fn test_format_streaming_block_scalar_no_extra_blank_line() {
+        let config = EmitterConfig::default();
+        for yaml in [
+            "desc: |\n  line one\nnext: 1\n",
+            "- >-\n  folded\n- |+\n  kept\n\n- x\n",
+        ] {
+            assert_eq!(format_streaming(yaml, &config).unwrap(), yaml);
+        }
+    }
+
     #[test]
     fn test_format_streaming_block_scalar_empty_line_no_indent() {
         // Empty lines in block scalars must not get trailing whitespace
@@ -500,6 +823,33 @@ double: "quoted""#;
         );
     }
 
+    #[test]
+    fn test_format_streaming_indent_aligns_mappings_in_sequences() {
+        // Keys after the first stay aligned with the one after "- ", so the
+        // output stays valid for any indent; nested blocks use the indent
+        let yaml = "list:\n  - a: 1\n    b:\n      - c\n  - - x\n    - y: 2\n      z: 3\n";
+        let expected = [
+            (
+                3,
+                "list:\n   - a: 1\n     b:\n        - c\n   - - x\n     - y: 2\n       z: 3\n",
+            ),
+            (
+                4,
+                "list:\n    - a: 1\n      b:\n          - c\n    - - x\n      - y: 2\n        z: 3\n",
+            ),
+        ];
+        for (indent, formatted) in expected {
+            let config = EmitterConfig::new().with_indent(indent);
+            let result = format_streaming(yaml, &config).unwrap();
+            assert_eq!(result, formatted, "indent {indent}");
+            assert_eq!(
+                crate::Parser::parse_str(&result).unwrap(),
+                crate::Parser::parse_str(yaml).unwrap(),
+                "indent {indent}"
+            );
+        }
+    }
+
     #[test]
     fn test_format_streaming_sequence_first_item_mapping() {
         // Mapping as first item of a nested sequence
@@ -672,6 +1022,133 @@ double: "quoted""#;
         assert!(result.contains("null") || result.contains('~'));
     }
 
+    const NULLS_YAML: &str = "a: ~\nb: null\nc: NULL\nd:\nlist:\n  - ~\n  - null\ns: 'null'\n";
+
+    #[test]
+    fn test_format_streaming_null_style_tilde() {
+        let config = EmitterConfig::default().with_null_style(NullStyle::Tilde);
+        let result = format_streaming(NULLS_YAML, &config).unwrap();
+        assert_eq!(
+            result,
+            "a: ~\nb: ~\nc: ~\nd: ~\nlist:\n  - ~\n  - ~\ns: 'null'\n"
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_null_style_null() {
+        let config = EmitterConfig::default().with_null_style(NullStyle::Null);
+        let result = format_streaming(NULLS_YAML, &config).unwrap();
+        assert_eq!(
+            result,
+            "a: null\nb: null\nc: null\nd: null\nlist:\n  - null\n  - null\ns: 'null'\n"
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_null_style_empty() {
+        let config = EmitterConfig::default().with_null_style(NullStyle::Empty);
+        let result = format_streaming(NULLS_YAML, &config).unwrap();
+        assert_eq!(result, "a:\nb:\nc:\nd:\nlist:\n  -\n  -\ns: 'null'\n");
+        // Keys, anchored values and the document root keep their representation
+        let result = format_streaming("~: &n null\n", &config).unwrap();
+        assert_eq!(result, "~: &n null\n");
+        let result = format_streaming("null\n", &config).unwrap();
+        assert_eq!(result, "null\n");
+    }
+
+    #[test]
+    fn test_format_streaming_null_style_preserve() {
+        let config = EmitterConfig::default();
+        assert_eq!(config.null_style, NullStyle::Preserve);
+        let result = format_streaming(NULLS_YAML, &config).unwrap();
+        // Empty values are reported by the parser as `~`
+        assert_eq!(
+            result,
+            "a: ~\nb: null\nc: NULL\nd: ~\nlist:\n  - ~\n  - null\ns: 'null'\n"
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_normalize_strings_yaml11() {
+        let config = EmitterConfig::default()
+            .with_string_normalization(Some(StringNormalization::QuoteYaml11Scalars));
+        let input = "on:\n  - y\n  - 'n'\nenabled: true\nwhen: Off # note\nname: yesterday\n";
+        let result = format_streaming(input, &config).unwrap();
+        assert_eq!(
+            result,
+            "\"on\":\n  - \"y\"\n  - 'n'\nenabled: true\nwhen: \"Off\"\nname: yesterday\n"
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_normalize_strings_integers() {
+        let config = EmitterConfig::default()
+            .with_string_normalization(Some(StringNormalization::QuoteAmbiguousIntegers));
+        let result =
+            format_streaming("a: 1_000\nb: 0b11\nc: 1:30\nd: 0x1F\ne: 1.0.0\n", &config).unwrap();
+        assert_eq!(
+            result,
+            "a: \"1_000\"\nb: \"0b11\"\nc: \"1:30\"\nd: 0x1F\ne: 1.0.0\n"
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_normalize_strings_all() {
+        let config =
+            EmitterConfig::default().with_string_normalization(Some(StringNormalization::QuoteAll));
+        let result = format_streaming("a: x\\y\nb: 2\nc: ~\nd: 'kept'\n", &config).unwrap();
+        assert_eq!(
+            result,
+            "\"a\": \"x\\\\y\"\n\"b\": 2\n\"c\": ~\n\"d\": 'kept'\n"
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_keep_original_styles() {
+        let input = "a: \"true\"\nb: 'x'\nc: on\n";
+        let config = EmitterConfig::default()
+            .with_string_normalization(Some(StringNormalization::QuoteAll))
+            .with_keep_original_styles(true);
+        assert_eq!(format_streaming(input, &config).unwrap(), input);
+    }
+
+    #[test]
+    fn test_format_streaming_keep_original_styles_flow() {
+        let config = EmitterConfig::default().with_keep_original_styles(true);
+        for input in [
+            "a: [1, 'x', \"y\"]\nb: {k: v, n: [1, {p: q}]}\nc:\n  - [a, b]\n  - {}\n",
+            "- &s [ü, 1]\n- *s\n- {[a]: b, c: ~}\n",
+            "{a: 1}: x\ny: [z]\n",
+            "[1, 2]\n",
+        ] {
+            assert_eq!(format_streaming(input, &config).unwrap(), input);
+
+            #[cfg(feature = "arena")]
+            assert_eq!(format_streaming_arena(input, &config).unwrap(), input);
+        }
+
+        assert_eq!(
+            format_streaming("a: [ 1,2 ]\nb: { x:  y }\n", &config).unwrap(),
+            "a: [1, 2]\nb: {x: y}\n"
+        );
+
+        let config = config.with_sort_keys(true);
+        assert_eq!(
+            format_streaming("b: {z: 1, y: 2}\na: [3]\n", &config).unwrap(),
+            "a: [3]\nb: {y: 2, z: 1}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_streaming_trailing_newline() {
+        let config = EmitterConfig::default();
+        assert_eq!(format_streaming("a: 1", &config).unwrap(), "a: 1\n");
+
+        let config = EmitterConfig::new().with_trailing_newline(false);
+        assert_eq!(format_streaming("a: 1\n", &config).unwrap(), "a: 1");
+        assert_eq!(format_streaming("- x\n- y\n", &config).unwrap(), "- x\n- y");
+    }
+
     #[test]
     fn test_format_streaming_boolean_values() {
         let yaml = "yes: true\nno: false";
@@ -1047,6 +1524,7 @@ mod arena_tests {
             ("literal_block", "text: |\n  line1\n  line2"),
             ("folded_block", "text: >-\n  folded\n  block"),
             ("explicit_start", "---\nkey: value"),
+            ("explicit_end", "a: 1\n...\n---\nb: 2\n..."),
             ("null_value", "key: null"),
             ("boolean_values", "yes: true\nno: false"),
             ("integer_values", "decimal: 123\nhex: 0x1A"),
@@ -10,6 +10,7 @@ use saphyr_parser::Parser;
 use super::Context;
 use super::extract_anchor_names;
 use super::formatter::StreamingFormatter;
+use super::sorted_events;
 use super::traits::{AnchorStoreOps, ContextStackOps, FormatterBackend};
 use crate::emitter::EmitterConfig;
 use crate::error::{EmitError, EmitResult};
@@ -145,8 +146,6 @@ impl FormatterBackend for StdBackend {
 /// # }
 /// ```
 pub fn format_streaming(input: &str, config: &EmitterConfig) -> EmitResult<String> {
-    let parser = Parser::new_from_str(input);
-
     // Output is typically 10-20% larger than input due to formatting
     let output_capacity = input.len() + (input.len() / 5);
 
@@ -159,11 +158,17 @@ pub fn format_streaming(input: &str, config: &EmitterConfig) -> EmitResult<Strin
     let anchor_names = extract_anchor_names(input);
     let mut backend = StdBackend::new(context_capacity, anchor_capacity.max(1));
     *backend.anchor_store_mut() = anchor_names;
-    let mut formatter = StreamingFormatter::new(config, output_capacity, backend);
+    let mut formatter = StreamingFormatter::new(config, input, output_capacity, backend);
 
-    for result in parser {
-        let (event, span) = result.map_err(|e| EmitError::Emit(e.to_string()))?;
-        formatter.format_event(event, span);
+    if config.sort_keys {
+        for (event, span) in sorted_events(input)? {
+            formatter.format_event(event, span);
+        }
+    } else {
+        for result in Parser::new_from_str(input) {
+            let (event, span) = result.map_err(|e| EmitError::Emit(e.to_string()))?;
+            formatter.format_event(event, span);
+        }
     }
 
     Ok(formatter.finish())
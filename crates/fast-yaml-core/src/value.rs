@@ -1,3 +1,9 @@
+use std::borrow::Cow;
+
+use saphyr_parser::ScalarStyle;
+
+use crate::emitter::NullStyle;
+
 pub use saphyr::MappingOwned as Map;
 pub use saphyr::ScalarOwned;
 /// Wrapper around saphyr's `YamlOwned` type for consistent API.
@@ -15,6 +21,529 @@ pub use ordered_float::OrderedFloat;
 /// Type alias for YAML arrays.
 pub type Array = Vec<Value>;
 
+/// Which plain string scalars [`ValueExt::normalize_strings`] quotes.
+///
+/// Intended for migrating documents from YAML 1.1 to 1.2: scalars such as
+/// `yes` or `1_000` are strings under YAML 1.2 but booleans and integers under
+/// YAML 1.1, so quoting them keeps their meaning clear to readers and to
+/// YAML 1.1 consumers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringNormalization {
+    /// Quote the YAML 1.1 booleans `y`, `yes`, `n`, `no`, `on` and `off`
+    /// (also capitalized and uppercase).
+    QuoteYaml11Scalars,
+    /// Quote strings that YAML 1.1 reads as integers: digits with `_`
+    /// separators, `0b` binary and base 60 (`1:30`).
+    QuoteAmbiguousIntegers,
+    /// Quote every plain string.
+    QuoteAll,
+}
+
+impl StringNormalization {
+    /// Returns true if the plain scalar `value` should be quoted.
+    ///
+    /// Only scalars that resolve to strings under the YAML 1.2 Core Schema
+    /// are quoted; quoting a number, boolean or null would change its type.
+    #[must_use]
+    pub fn quotes(self, value: &str) -> bool {
+        !NullStyle::is_null(value)
+            && matches!(
+                ScalarOwned::parse_from_cow(Cow::Borrowed(value)),
+                ScalarOwned::String(_)
+            )
+            && self.selects(value)
+    }
+
+    /// Returns true if the string `value` is selected by this mode.
+    fn selects(self, value: &str) -> bool {
+        match self {
+            Self::QuoteYaml11Scalars => is_yaml11_bool(value),
+            Self::QuoteAmbiguousIntegers => is_yaml11_int(value),
+            Self::QuoteAll => true,
+        }
+    }
+}
+
+/// Returns true if `value` is a boolean under the YAML 1.1 type repository.
+fn is_yaml11_bool(value: &str) -> bool {
+    matches!(
+        value,
+        "y" | "Y"
+            | "yes"
+            | "Yes"
+            | "YES"
+            | "n"
+            | "N"
+            | "no"
+            | "No"
+            | "NO"
+            | "true"
+            | "True"
+            | "TRUE"
+            | "false"
+            | "False"
+            | "FALSE"
+            | "on"
+            | "On"
+            | "ON"
+            | "off"
+            | "Off"
+            | "OFF"
+    )
+}
+
+/// Returns true if `value` is an integer under the YAML 1.1 type repository.
+fn is_yaml11_int(value: &str) -> bool {
+    fn digits(s: &str, radix: u32) -> bool {
+        !s.is_empty() && s.chars().all(|c| c == '_' || c.is_digit(radix))
+    }
+
+    let value = value.strip_prefix(['-', '+']).unwrap_or(value);
+    if let Some(bin) = value.strip_prefix("0b") {
+        return digits(bin, 2);
+    }
+    if let Some(hex) = value.strip_prefix("0x") {
+        return digits(hex, 16);
+    }
+    if let Some(octal) = value.strip_prefix('0') {
+        return octal.is_empty() || digits(octal, 8);
+    }
+
+    // Decimal, or base 60 such as `1:30:00`
+    let mut parts = value.split(':');
+    let head = parts.next().unwrap_or_default();
+    head.starts_with(|c: char| c.is_ascii_digit())
+        && digits(head, 10)
+        && parts
+            .all(|part| (1..=2).contains(&part.len()) && part.parse::<u8>().is_ok_and(|n| n < 60))
+}
+
+/// In-place filtering helpers and accessors for [`Value`].
+///
+/// `Value` is a re-export of saphyr's `YamlOwned`, so these methods are
+/// provided through an extension trait. saphyr itself provides `is_null`,
+/// `as_bool`, `as_str`, `as_sequence` and `as_mapping`.
+pub trait ValueExt {
+    /// Removes mapping entries whose key does not satisfy `predicate`.
+    ///
+    /// Applies recursively to every mapping in the tree, including mappings
+    /// nested in sequences and tagged nodes. Entries are filtered before their
+    /// values are visited, so removed subtrees are never inspected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{Parser, Value, ValueExt};
+    ///
+    /// let mut value = Parser::parse_str("user: {name: a, password: b}\ntoken: c")?.unwrap();
+    /// let secret = |key: &Value| matches!(key.as_str(), Some("password" | "token"));
+    /// value.retain_keys(|key| !secret(key));
+    ///
+    /// assert_eq!(value, Parser::parse_str("user: {name: a}")?.unwrap());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn retain_keys(&mut self, predicate: impl Fn(&Value) -> bool);
+
+    /// Removes sequence items that do not satisfy `predicate`.
+    ///
+    /// Applies recursively to every sequence in the tree, including sequences
+    /// nested in mappings and tagged nodes. Items are filtered before they are
+    /// visited, so the predicate sees each kept item with its original content.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{Parser, ValueExt};
+    ///
+    /// let mut value = Parser::parse_str("ports: [80, ~, 443]")?.unwrap();
+    /// value.retain_values(|item| !item.is_null());
+    ///
+    /// assert_eq!(value, Parser::parse_str("ports: [80, 443]")?.unwrap());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn retain_values(&mut self, predicate: impl Fn(&Value) -> bool);
+
+    /// Double-quotes the string scalars selected by `mode`.
+    ///
+    /// Applies recursively to mapping keys and values and to sequence items.
+    /// Selected strings become `Value::Representation` nodes in double-quoted
+    /// style. Scalars with an explicit tag are left unchanged, as are strings
+    /// containing `"`, `\` or control characters, which the emitter quotes
+    /// and escapes itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{Emitter, Parser, StringNormalization, ValueExt};
+    ///
+    /// let mut value = Parser::parse_str("enabled: y\nport: 8080")?.unwrap();
+    /// value.normalize_strings(StringNormalization::QuoteYaml11Scalars);
+    ///
+    /// assert_eq!(Emitter::emit_str(&value)?, "enabled: \"y\"\nport: 8080\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn normalize_strings(&mut self, mode: StringNormalization);
+
+    /// Sorts mapping entries by key.
+    ///
+    /// Applies recursively to every mapping in the tree, including mappings
+    /// nested in sequences and tagged nodes. Scalar keys are compared by their
+    /// text, so `10` sorts before `9`; collection keys keep their relative
+    /// order after all scalar keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{Emitter, Parser, ValueExt};
+    ///
+    /// let mut value = Parser::parse_str("b: 1\na: {d: 2, c: 3}")?.unwrap();
+    /// value.sort_keys();
+    ///
+    /// assert_eq!(Emitter::emit_str(&value)?, "a:\n  c: 3\n  d: 2\nb: 1\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn sort_keys(&mut self);
+
+    /// Rewrites strings containing non-ASCII characters as double-quoted
+    /// scalars with `\u` escapes, so the emitted YAML is pure ASCII.
+    ///
+    /// Applies recursively to mapping keys and values, sequence items and
+    /// tagged nodes. The emitter writes the escaped text verbatim, and it
+    /// parses back to the original strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{Emitter, Parser, ValueExt};
+    ///
+    /// let mut value = Parser::parse_str("city: Zürich")?.unwrap();
+    /// value.escape_non_ascii();
+    ///
+    /// assert_eq!(Emitter::emit_str(&value)?, "city: \"Z\\u00FCrich\"\n");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn escape_non_ascii(&mut self);
+
+    /// Returns the value as an `i64` if it is an integer scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{Parser, ValueExt};
+    ///
+    /// let value = Parser::parse_str("port: 8080\nratio: 0.5")?.unwrap();
+    /// assert_eq!(value["port"].as_i64(), Some(8080));
+    /// assert_eq!(value["ratio"].as_i64(), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn as_i64(&self) -> Option<i64>;
+
+    /// Returns the value as an `f64` if it is a float or integer scalar.
+    ///
+    /// Integers are converted like `serde_json::Value::as_f64`, which loses
+    /// precision beyond 2^53.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{Parser, ValueExt};
+    ///
+    /// let value = Parser::parse_str("port: 8080\nratio: 0.5\nname: x")?.unwrap();
+    /// assert_eq!(value["ratio"].as_f64(), Some(0.5));
+    /// assert_eq!(value["port"].as_f64(), Some(8080.0));
+    /// assert_eq!(value["name"].as_f64(), None);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn as_f64(&self) -> Option<f64>;
+
+    /// Resolves YAML merge keys (`<<`) throughout the tree.
+    ///
+    /// A `<<` entry whose value is a mapping, or a sequence of mappings, is
+    /// replaced by the entries of those mappings. Explicit keys win over
+    /// merged keys, and earlier mappings in a sequence win over later ones.
+    /// Nested mappings are resolved first, so inheritance chains are
+    /// flattened. Other `<<` values are dropped.
+    ///
+    /// [`Parser::parse_str`](crate::Parser::parse_str) already does this
+    /// unless disabled through [`ParseConfig`](crate::ParseConfig).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_core::{ParseConfig, Parser, ValueExt};
+    ///
+    /// let yaml = "base: &base {host: localhost}\ndev:\n  <<: *base\n  db: dev\n";
+    /// let config = ParseConfig::new().with_resolve_merge_keys(false);
+    /// let mut value = Parser::parse_str_with_config(yaml, &config)?.unwrap();
+    /// value.apply_merge_keys();
+    ///
+    /// assert_eq!(value["dev"], Parser::parse_str("{db: dev, host: localhost}")?.unwrap());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn apply_merge_keys(&mut self);
+}
+
+impl ValueExt for Value {
+    fn retain_keys(&mut self, predicate: impl Fn(&Self) -> bool) {
+        retain_keys_with(self, &predicate);
+    }
+
+    fn retain_values(&mut self, predicate: impl Fn(&Self) -> bool) {
+        retain_values_with(self, &predicate);
+    }
+
+    fn normalize_strings(&mut self, mode: StringNormalization) {
+        normalize_strings_with(self, mode);
+    }
+
+    fn sort_keys(&mut self) {
+        match self {
+            Self::Mapping(map) => {
+                let mut entries: Vec<_> = std::mem::take(map).into_iter().collect();
+                entries.sort_by(|(a, _), (b, _)| {
+                    let (a, b) = (key_text(a), key_text(b));
+                    a.is_none().cmp(&b.is_none()).then_with(|| a.cmp(&b))
+                });
+                *map = entries
+                    .into_iter()
+                    .map(|(key, mut value)| {
+                        value.sort_keys();
+                        (key, value)
+                    })
+                    .collect();
+            }
+            Self::Sequence(seq) => {
+                for item in seq {
+                    item.sort_keys();
+                }
+            }
+            Self::Tagged(_, inner) => inner.sort_keys(),
+            Self::Representation(..) | Self::Value(_) | Self::Alias(_) | Self::BadValue => {}
+        }
+    }
+
+    fn escape_non_ascii(&mut self) {
+        match self {
+            Self::Value(ScalarOwned::String(s)) if !s.is_ascii() => {
+                *self = Self::Representation(
+                    crate::emitter::escape_double_quoted(s, true),
+                    ScalarStyle::DoubleQuoted,
+                    None,
+                );
+            }
+            Self::Mapping(map) => {
+                // Keys are immutable in place, so rebuild the mapping in order
+                *map = std::mem::take(map)
+                    .into_iter()
+                    .map(|(mut key, mut value)| {
+                        key.escape_non_ascii();
+                        value.escape_non_ascii();
+                        (key, value)
+                    })
+                    .collect();
+            }
+            Self::Sequence(seq) => {
+                for item in seq {
+                    item.escape_non_ascii();
+                }
+            }
+            Self::Tagged(_, inner) => inner.escape_non_ascii(),
+            Self::Representation(..) | Self::Value(_) | Self::Alias(_) | Self::BadValue => {}
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        self.as_integer()
+    }
+
+    #[allow(clippy::cast_precision_loss)] // Matches serde_json's `as_f64`
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Value(ScalarOwned::FloatingPoint(f)) => Some(f.into_inner()),
+            Self::Value(ScalarOwned::Integer(i)) => Some(*i as f64),
+            _ => None,
+        }
+    }
+
+    fn apply_merge_keys(&mut self) {
+        match self {
+            Self::Mapping(map) => {
+                let needs_rebuild = map
+                    .keys()
+                    .any(|key| matches!(key, Self::Mapping(_) | Self::Sequence(_)));
+                if needs_rebuild {
+                    *map = std::mem::take(map)
+                        .into_iter()
+                        .map(|(mut key, value)| {
+                            key.apply_merge_keys();
+                            (key, value)
+                        })
+                        .collect();
+                }
+                for value in map.values_mut() {
+                    value.apply_merge_keys();
+                }
+                resolve_merge_keys(map);
+            }
+            Self::Sequence(seq) => {
+                for item in seq {
+                    item.apply_merge_keys();
+                }
+            }
+            Self::Tagged(_, inner) => inner.apply_merge_keys(),
+            Self::Representation(..) | Self::Value(_) | Self::Alias(_) | Self::BadValue => {}
+        }
+    }
+}
+
+/// Returns true if `key` is the YAML merge key `<<`.
+fn is_merge_key(key: &Value) -> bool {
+    matches!(
+        key,
+        Value::Value(ScalarOwned::String(s)) | Value::Representation(s, _, _) if s == "<<"
+    )
+}
+
+/// Replaces the `<<` entries of `map` with the entries they merge in.
+///
+/// Explicit keys always win over merged keys. Nested mappings are not visited.
+pub(crate) fn resolve_merge_keys(map: &mut Map) {
+    if !map.keys().any(is_merge_key) {
+        return;
+    }
+
+    let mut merges = Vec::new();
+    let mut result = Map::new();
+    for (key, value) in std::mem::take(map) {
+        if is_merge_key(&key) {
+            merges.push(value);
+        } else {
+            result.insert(key, value);
+        }
+    }
+
+    for source in merges {
+        let sources = match source {
+            Value::Mapping(source) => vec![source],
+            Value::Sequence(seq) => seq
+                .into_iter()
+                .filter_map(|item| match item {
+                    Value::Mapping(source) => Some(source),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        for (key, value) in sources.into_iter().flatten() {
+            result.entry(key).or_insert(value);
+        }
+    }
+
+    *map = result;
+}
+
+/// Returns the text a scalar mapping key sorts by; `None` for other keys,
+/// which sorts them after all scalar keys.
+fn key_text(key: &Value) -> Option<Cow<'_, str>> {
+    let text = match key {
+        Value::Value(ScalarOwned::String(s)) | Value::Representation(s, _, _) => {
+            Cow::Borrowed(s.as_str())
+        }
+        Value::Value(ScalarOwned::Null) => Cow::Borrowed("null"),
+        Value::Value(ScalarOwned::Boolean(b)) => Cow::Owned(b.to_string()),
+        Value::Value(ScalarOwned::Integer(i)) => Cow::Owned(i.to_string()),
+        Value::Value(ScalarOwned::FloatingPoint(f)) => Cow::Owned(f.to_string()),
+        Value::Tagged(_, inner) => return key_text(inner),
+        Value::Sequence(_) | Value::Mapping(_) | Value::Alias(_) | Value::BadValue => {
+            return None;
+        }
+    };
+    Some(text)
+}
+
+fn retain_keys_with<F: Fn(&Value) -> bool>(value: &mut Value, predicate: &F) {
+    match value {
+        Value::Mapping(map) => map.retain(|key, value| {
+            let keep = predicate(key);
+            if keep {
+                retain_keys_with(value, predicate);
+            }
+            keep
+        }),
+        Value::Sequence(seq) => {
+            for item in seq {
+                retain_keys_with(item, predicate);
+            }
+        }
+        Value::Tagged(_, inner) => retain_keys_with(inner, predicate),
+        Value::Representation(..) | Value::Value(_) | Value::Alias(_) | Value::BadValue => {}
+    }
+}
+
+fn retain_values_with<F: Fn(&Value) -> bool>(value: &mut Value, predicate: &F) {
+    match value {
+        Value::Sequence(seq) => {
+            seq.retain(|item| predicate(item));
+            for item in seq {
+                retain_values_with(item, predicate);
+            }
+        }
+        Value::Mapping(map) => {
+            for (_, value) in map.iter_mut() {
+                retain_values_with(value, predicate);
+            }
+        }
+        Value::Tagged(_, inner) => retain_values_with(inner, predicate),
+        Value::Representation(..) | Value::Value(_) | Value::Alias(_) | Value::BadValue => {}
+    }
+}
+
+fn normalize_strings_with(value: &mut Value, mode: StringNormalization) {
+    match value {
+        // Parsed strings: quoting them never changes their type
+        Value::Value(ScalarOwned::String(s)) => {
+            if mode.selects(s) && can_double_quote(s) {
+                *value = Value::Representation(std::mem::take(s), ScalarStyle::DoubleQuoted, None);
+            }
+        }
+        // Unresolved plain scalars, as kept by `Parser::parse_all_preserving_styles`
+        Value::Representation(s, style @ ScalarStyle::Plain, None) => {
+            if mode.quotes(s) && can_double_quote(s) {
+                *style = ScalarStyle::DoubleQuoted;
+            }
+        }
+        Value::Sequence(seq) => {
+            for item in seq {
+                normalize_strings_with(item, mode);
+            }
+        }
+        Value::Mapping(map) => {
+            // Keys are immutable in place, so rebuild the mapping in order
+            *map = std::mem::take(map)
+                .into_iter()
+                .map(|(mut key, mut value)| {
+                    normalize_strings_with(&mut key, mode);
+                    normalize_strings_with(&mut value, mode);
+                    (key, value)
+                })
+                .collect();
+        }
+        Value::Tagged(_, inner) => {
+            if matches!(**inner, Value::Sequence(_) | Value::Mapping(_)) {
+                normalize_strings_with(inner, mode);
+            }
+        }
+        Value::Value(_) | Value::Representation(..) | Value::Alias(_) | Value::BadValue => {}
+    }
+}
+
+/// Returns true if `s` can be written between double quotes without escapes.
+///
+/// The saphyr emitter writes `Representation` content verbatim.
+fn can_double_quote(s: &str) -> bool {
+    !s.contains(|c: char| matches!(c, '"' | '\\') || c.is_control())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,4 +572,308 @@ mod tests {
         let val = Value::Value(ScalarOwned::String("test".to_string()));
         assert!(matches!(val, Value::Value(ScalarOwned::String(_))));
     }
+
+    fn parse(yaml: &str) -> Value {
+        crate::Parser::parse_str(yaml).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_scalar_accessors() {
+        let value = parse("n: ~\nb: true\ni: -3\nf: 2.5\ns: text\n");
+
+        assert!(value["n"].is_null());
+        assert!(!value["s"].is_null());
+        assert_eq!(value["b"].as_bool(), Some(true));
+        assert_eq!(value["s"].as_bool(), None);
+        assert_eq!(value["s"].as_str(), Some("text"));
+        assert_eq!(value["i"].as_str(), None);
+
+        assert_eq!(value["i"].as_i64(), Some(-3));
+        assert_eq!(value["f"].as_i64(), None);
+        assert_eq!(value["s"].as_i64(), None);
+        assert_eq!(value["f"].as_f64(), Some(2.5));
+        assert_eq!(value["i"].as_f64(), Some(-3.0));
+        assert_eq!(value["b"].as_f64(), None);
+        assert!(parse(".nan").as_f64().is_some_and(f64::is_nan));
+        assert_eq!(parse("-.inf").as_f64(), Some(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_collection_accessors() {
+        let value = parse("l: [1, 2]\nm: {k: v}\n");
+
+        assert_eq!(value["l"].as_sequence().map(Vec::len), Some(2));
+        assert_eq!(value["m"].as_sequence(), None);
+        assert_eq!(value["m"].as_mapping().map(Map::len), Some(1));
+        assert_eq!(value["l"].as_mapping(), None);
+        assert_eq!(value.as_mapping().map(Map::len), Some(2));
+    }
+
+    #[test]
+    fn test_apply_merge_keys() {
+        let yaml = "a: &a {x: 1, y: 1}\nb: &b {y: 2, z: 2}\nc:\n  - <<: [*a, *b]\n    x: 3\n";
+        let mut value = crate::Parser::parse_all_preserving_styles(yaml)
+            .unwrap()
+            .remove(0);
+        value.apply_merge_keys();
+        assert_eq!(
+            crate::canonicalize(value)["c"][0],
+            parse("{x: 3, y: 1, z: 2}")
+        );
+    }
+
+    #[test]
+    fn test_apply_merge_keys_ignores_scalar_source() {
+        let mut value = parse("k: v");
+        let Value::Mapping(map) = &mut value else {
+            panic!("expected mapping")
+        };
+        map.insert(
+            Value::Value(ScalarOwned::String("<<".into())),
+            Value::Value(ScalarOwned::Integer(1)),
+        );
+        value.apply_merge_keys();
+        assert_eq!(value, parse("k: v"));
+    }
+
+    #[test]
+    fn test_retain_keys_top_level() {
+        let mut value = parse("a: 1\nb: 2\nc: 3");
+        value.retain_keys(|key| key.as_str() != Some("b"));
+        assert_eq!(value, parse("a: 1\nc: 3"));
+    }
+
+    #[test]
+    fn test_retain_keys_nested() {
+        let mut value =
+            parse("db:\n  host: x\n  password: secret\nusers:\n  - name: a\n    password: p\n");
+        value.retain_keys(|key| key.as_str() != Some("password"));
+        assert_eq!(value, parse("db:\n  host: x\nusers:\n  - name: a\n"));
+    }
+
+    #[test]
+    fn test_retain_keys_none() {
+        let mut value = parse("a: {b: 1}\nc: 2");
+        value.retain_keys(|_| false);
+        assert_eq!(value, Value::Mapping(Map::new()));
+    }
+
+    #[test]
+    fn test_retain_keys_non_string_keys() {
+        let mut value = parse("1: one\n2: two\n3: three");
+        value.retain_keys(|key| key.as_integer().is_some_and(|n| n % 2 == 1));
+        assert_eq!(value, parse("1: one\n3: three"));
+    }
+
+    #[test]
+    fn test_retain_keys_scalar_unchanged() {
+        let mut value = parse("hello");
+        value.retain_keys(|_| false);
+        assert_eq!(value, parse("hello"));
+    }
+
+    #[test]
+    fn test_retain_values_top_level() {
+        let mut value = parse("[1, 2, 3, 4]");
+        value.retain_values(|item| item.as_integer().is_some_and(|n| n > 2));
+        assert_eq!(value, parse("[3, 4]"));
+    }
+
+    #[test]
+    fn test_retain_values_nested() {
+        let mut value = parse("a: [x, ~, y]\nb:\n  - [~, z]\n  - ~\n");
+        value.retain_values(|item| !item.is_null());
+        assert_eq!(value, parse("a: [x, y]\nb:\n  - [z]\n"));
+    }
+
+    #[test]
+    fn test_retain_values_none() {
+        let mut value = parse("items: [1, 2]");
+        value.retain_values(|_| false);
+        assert_eq!(value, parse("items: []"));
+    }
+
+    #[test]
+    fn test_string_normalization_yaml11_bools() {
+        let mode = StringNormalization::QuoteYaml11Scalars;
+        for s in ["y", "Y", "yes", "No", "on", "OFF"] {
+            assert!(mode.quotes(s), "{s}");
+        }
+        // Booleans and plain strings under YAML 1.2
+        for s in ["true", "False", "yesterday", "nope"] {
+            assert!(!mode.quotes(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_string_normalization_ambiguous_integers() {
+        let mode = StringNormalization::QuoteAmbiguousIntegers;
+        for s in [
+            "1_000",
+            "-1_000",
+            "0b1010",
+            "0x_FF",
+            "0_17",
+            "1:30",
+            "190:20:30",
+        ] {
+            assert!(mode.quotes(s), "{s}");
+        }
+        // Integers under YAML 1.2, or not integers under YAML 1.1
+        for s in ["1000", "0x1F", "017", "1:60", "08_9", "1.0.0", "_1", "12ab"] {
+            assert!(!mode.quotes(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn test_string_normalization_all_skips_non_strings() {
+        let mode = StringNormalization::QuoteAll;
+        assert!(mode.quotes("hello world"));
+        for s in ["42", "1.5", ".inf", "true", "null", "~", ""] {
+            assert!(!mode.quotes(s), "{s}");
+        }
+    }
+
+    fn emit_normalized(yaml: &str, mode: StringNormalization) -> String {
+        let mut value = parse(yaml);
+        value.normalize_strings(mode);
+        crate::Emitter::emit_str(&value).unwrap()
+    }
+
+    #[test]
+    fn test_normalize_strings_yaml11_migration() {
+        let yaml = "answer: y\nconfirm: n\nswitch: on\nenabled: true\nname: yesterday\n";
+        assert_eq!(
+            emit_normalized(yaml, StringNormalization::QuoteYaml11Scalars),
+            "answer: \"y\"\nconfirm: \"n\"\nswitch: \"on\"\nenabled: true\nname: yesterday\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_strings_keys_and_nested() {
+        let yaml = "on:\n  push: [y, main]\nitems:\n  - {flag: off}\n";
+        let mut value = parse(yaml);
+        value.normalize_strings(StringNormalization::QuoteYaml11Scalars);
+        let output = crate::Emitter::emit_str(&value).unwrap();
+        assert!(output.starts_with("\"on\":\n"), "{output}");
+        assert!(output.contains("- \"y\"\n"), "{output}");
+        assert!(output.contains("flag: \"off\""), "{output}");
+        // Quoting changes the representation, not the value
+        assert_eq!(
+            crate::Parser::parse_str(&output).unwrap().unwrap(),
+            parse(yaml)
+        );
+    }
+
+    #[test]
+    fn test_normalize_strings_ambiguous_integers() {
+        let yaml = "size: 1_000\nmode: 0b101\ntime: 1:30\ncount: 1000\n";
+        assert_eq!(
+            emit_normalized(yaml, StringNormalization::QuoteAmbiguousIntegers),
+            "size: \"1_000\"\nmode: \"0b101\"\ntime: \"1:30\"\ncount: 1000\n"
+        );
+    }
+
+    #[test]
+    fn test_sort_keys_nested() {
+        let mut value = parse("b: 1\na:\n  - {z: 1, y: 2}\nc: {q: 1, p: 2}\n");
+        value.sort_keys();
+        assert_eq!(
+            crate::Emitter::emit_str(&value).unwrap(),
+            "a:\n  - y: 2\n    z: 1\nb: 1\nc:\n  p: 2\n  q: 1\n"
+        );
+    }
+
+    #[test]
+    fn test_sort_keys_scalar_keys_by_text() {
+        let mut value = parse("9: a\n10: b\n[x]: c\nnull: d\ntrue: e\n");
+        value.sort_keys();
+        let Value::Mapping(map) = value else {
+            panic!("expected a mapping");
+        };
+        let keys: Vec<_> = map.keys().cloned().collect();
+        assert_eq!(
+            keys,
+            [
+                parse("10"),
+                parse("9"),
+                Value::Value(ScalarOwned::Null),
+                parse("true"),
+                parse("[x]"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_escape_non_ascii() {
+        let yaml = "café: [\"\\\"ü\\\"\", \"😀\\x01\"]\nplain: ascii\n";
+        let mut value = parse(yaml);
+        value.escape_non_ascii();
+        let emitted = crate::Emitter::emit_str(&value).unwrap();
+        assert_eq!(
+            emitted,
+            concat!(
+                r#""caf\u00E9":"#,
+                "\n",
+                r#"  - "\"\u00FC\"""#,
+                "\n",
+                r#"  - "\U0001F600\u0001""#,
+                "\nplain: ascii\n"
+            )
+        );
+        assert_eq!(parse(&emitted), parse(yaml));
+    }
+
+    #[test]
+    fn test_normalize_strings_all() {
+        let yaml = "name: app\nport: 8080\ndebug: false\nnothing: ~\n";
+        assert_eq!(
+            emit_normalized(yaml, StringNormalization::QuoteAll),
+            "\"name\": \"app\"\n\"port\": 8080\n\"debug\": false\n\"nothing\": ~\n"
+        );
+    }
+
+    #[test]
+    fn test_normalize_strings_preserving_styles() {
+        let mut docs =
+            crate::Parser::parse_all_preserving_styles("a: yes\nb: 'no'\nc: 1\n").unwrap();
+        docs[0].normalize_strings(StringNormalization::QuoteAll);
+        let Value::Mapping(map) = &docs[0] else {
+            panic!("expected mapping");
+        };
+        let styles: Vec<_> = map
+            .values()
+            .map(|v| match v {
+                Value::Representation(_, style, _) => *style,
+                _ => panic!("expected representation"),
+            })
+            .collect();
+        assert_eq!(
+            styles,
+            [
+                ScalarStyle::DoubleQuoted,
+                ScalarStyle::SingleQuoted,
+                ScalarStyle::Plain
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_strings_skips_tagged_and_escaped() {
+        let tag = saphyr_parser::Tag {
+            handle: "!".to_string(),
+            suffix: "flag".to_string(),
+        };
+        let tagged = Value::Tagged(
+            tag,
+            Box::new(Value::Value(ScalarOwned::String("yes".into()))),
+        );
+        let mut value = parse("- 'say \"on\"'\n- 'C:\\dir'\n");
+        if let Value::Sequence(items) = &mut value {
+            items.push(tagged);
+        }
+        let expected = value.clone();
+        value.normalize_strings(StringNormalization::QuoteAll);
+        assert_eq!(value, expected);
+    }
 }
@@ -0,0 +1,74 @@
+//! Emitter throughput check for CI.
+//!
+//! Timing-sensitive, so ignored by default; CI runs it in release mode:
+//!
+//! ```bash
+//! cargo test --release -p fast-yaml-core --test emitter_throughput -- --ignored
+//! ```
+//!
+//! The minimum is 100 MB/s, overridable with `FAST_YAML_MIN_EMIT_MBPS`.
+
+use fast_yaml_core::{Emitter, Map, ScalarOwned, Value};
+use std::time::{Duration, Instant};
+
+const DEFAULT_MIN_MBPS: f64 = 100.0;
+const MEASUREMENT_TIME: Duration = Duration::from_millis(500);
+
+fn string(s: impl Into<String>) -> Value {
+    Value::Value(ScalarOwned::String(s.into()))
+}
+
+/// A mix of the document shapes from `benches/emitter_bench.rs`.
+fn document() -> Value {
+    let scalars = Value::Sequence(
+        (0..1_000_i64)
+            .map(|i| Value::Value(ScalarOwned::Integer(i)))
+            .collect(),
+    );
+    let sequence = Value::Sequence((0..10_000).map(|i| string(format!("item-{i}"))).collect());
+    let mut mapping = Map::new();
+    for i in 0..1_000 {
+        mapping.insert(string(format!("key_{i:04}")), string(format!("value {i}")));
+    }
+    let nested = (0..50).fold(string("leaf"), |inner, level| {
+        let mut map = Map::new();
+        map.insert(string(format!("child{level}")), inner);
+        Value::Mapping(map)
+    });
+
+    let mut root = Map::new();
+    root.insert(string("scalars"), scalars);
+    root.insert(string("sequence"), sequence);
+    root.insert(string("mapping"), Value::Mapping(mapping));
+    root.insert(string("nested"), nested);
+    Value::Mapping(root)
+}
+
+#[test]
+#[ignore = "timing-sensitive; run with --release -- --ignored"]
+fn emit_throughput_above_minimum() {
+    let min_mbps = std::env::var("FAST_YAML_MIN_EMIT_MBPS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MIN_MBPS);
+    let value = document();
+
+    // Warm up caches and the allocator before measuring
+    let output_len = Emitter::emit_str(&value).unwrap().len();
+
+    let mut bytes = 0usize;
+    let start = Instant::now();
+    while start.elapsed() < MEASUREMENT_TIME {
+        bytes += Emitter::emit_str(std::hint::black_box(&value))
+            .unwrap()
+            .len();
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let mbps = bytes as f64 / start.elapsed().as_secs_f64() / 1_000_000.0;
+
+    assert!(bytes >= output_len);
+    assert!(
+        mbps > min_mbps,
+        "emit throughput {mbps:.1} MB/s is below the {min_mbps} MB/s minimum"
+    );
+}
@@ -0,0 +1,421 @@
+//! Baselines of known violations, for adopting the linter on existing files.
+//!
+//! A baseline records the diagnostics a project already has so that only new
+//! ones are reported. Entries are keyed by file, rule code and message rather
+//! than by line, and line references such as `(line 3)` are dropped from the
+//! message, so edits elsewhere in a file do not invalidate them.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Diagnostic;
+
+/// Known violations of one rule with one message in one file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// File path, as passed to the linter.
+    pub path: String,
+    /// Diagnostic code (e.g., "line-length").
+    pub code: String,
+    /// Diagnostic message, without line references.
+    pub message: String,
+    /// Number of matching diagnostics that are suppressed.
+    pub count: usize,
+}
+
+/// A set of known violations that are not reported.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use fast_yaml_linter::{Baseline, Linter};
+///
+/// let yaml = "key: value   \n";
+/// let diagnostics = Linter::with_all_rules().lint(yaml)?;
+///
+/// let baseline = Baseline::new().update(Path::new("config.yaml"), &diagnostics).0;
+/// assert!(baseline.filter(Path::new("config.yaml"), diagnostics).is_empty());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    /// Suppressed counts by `(path, code, message)`.
+    entries: BTreeMap<(String, String, String), usize>,
+}
+
+/// On-disk representation of a baseline file.
+#[derive(Serialize, Deserialize)]
+struct BaselineFile {
+    #[serde(default)]
+    entries: Vec<BaselineEntry>,
+}
+
+/// Errors from baseline loading and saving.
+#[derive(Debug, thiserror::Error)]
+pub enum BaselineError {
+    /// I/O error reading or writing the baseline file.
+    #[error("failed to access baseline file '{path}': {source}")]
+    Io {
+        /// Path that failed.
+        path: PathBuf,
+        /// Underlying I/O error.
+        source: std::io::Error,
+    },
+
+    /// The baseline file is not valid.
+    #[error("failed to parse baseline file '{path}': {source}")]
+    Parse {
+        /// Path that failed.
+        path: PathBuf,
+        /// Underlying parse error.
+        source: serde_norway::Error,
+    },
+
+    /// The baseline could not be serialized.
+    #[error("failed to serialize baseline '{path}': {source}")]
+    Serialize {
+        /// Destination path.
+        path: PathBuf,
+        /// Underlying serialization error.
+        source: serde_norway::Error,
+    },
+}
+
+impl Baseline {
+    /// Creates an empty baseline.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load a baseline file from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BaselineError` on I/O or parse failure.
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let content = std::fs::read_to_string(path).map_err(|source| BaselineError::Io {
+            path: path.to_owned(),
+            source,
+        })?;
+        let file: Option<BaselineFile> =
+            serde_norway::from_str(&content).map_err(|source| BaselineError::Parse {
+                path: path.to_owned(),
+                source,
+            })?;
+        Ok(file.map_or_else(Self::new, |file| file.entries.into_iter().collect()))
+    }
+
+    /// Write the baseline to disk as YAML, sorted by path, code and message.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BaselineError` if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let file = BaselineFile {
+            entries: self.entries().collect(),
+        };
+        let yaml = serde_norway::to_string(&file).map_err(|source| BaselineError::Serialize {
+            path: path.to_owned(),
+            source,
+        })?;
+        std::fs::write(path, yaml).map_err(|source| BaselineError::Io {
+            path: path.to_owned(),
+            source,
+        })
+    }
+
+    /// Returns the entries, sorted by path, code and message.
+    pub fn entries(&self) -> impl Iterator<Item = BaselineEntry> + '_ {
+        self.entries
+            .iter()
+            .map(|((path, code, message), &count)| BaselineEntry {
+                path: path.clone(),
+                code: code.clone(),
+                message: message.clone(),
+                count,
+            })
+    }
+
+    /// Returns the total number of suppressed diagnostics.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.values().sum()
+    }
+
+    /// Returns true if nothing is suppressed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes the diagnostics of `path` that are covered by the baseline.
+    ///
+    /// Each entry suppresses at most `count` matching diagnostics, in order;
+    /// any beyond that are new and kept.
+    #[must_use]
+    pub fn filter(&self, path: &Path, diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+        let path = path_key(path);
+        let mut remaining: BTreeMap<&str, BTreeMap<&str, usize>> = BTreeMap::new();
+        for ((_, code, message), &count) in self
+            .entries
+            .range((path.clone(), String::new(), String::new())..)
+            .take_while(|((p, _, _), _)| *p == path)
+        {
+            remaining
+                .entry(code.as_str())
+                .or_default()
+                .insert(message.as_str(), count);
+        }
+
+        diagnostics
+            .into_iter()
+            .filter(|d| {
+                let count = remaining
+                    .get_mut(d.code.as_str())
+                    .and_then(|messages| messages.get_mut(message_key(&d.message).as_ref()));
+                match count {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                }
+            })
+            .collect()
+    }
+
+    /// Replaces the entries of `path` with its `current` diagnostics.
+    ///
+    /// Entries for other files are kept, so linting a subset of a project
+    /// does not drop the rest of its baseline. Returns the updated baseline
+    /// with the number of diagnostics removed (no longer reported) and added
+    /// (newly reported).
+    #[must_use]
+    pub fn update(mut self, path: &Path, current: &[Diagnostic]) -> (Self, usize, usize) {
+        let path = path_key(path);
+
+        let mut new: BTreeMap<(String, String), usize> = BTreeMap::new();
+        for d in current {
+            *new.entry((
+                d.code.as_str().to_owned(),
+                message_key(&d.message).into_owned(),
+            ))
+            .or_default() += 1;
+        }
+
+        let mut old: BTreeMap<(String, String), usize> = BTreeMap::new();
+        self.entries.retain(|(p, code, message), count| {
+            if *p == path {
+                old.insert((code.clone(), message.clone()), *count);
+                false
+            } else {
+                true
+            }
+        });
+
+        let removed = old
+            .iter()
+            .map(|(key, &count)| count.saturating_sub(new.get(key).copied().unwrap_or(0)))
+            .sum();
+        let added = new
+            .iter()
+            .map(|(key, &count)| count.saturating_sub(old.get(key).copied().unwrap_or(0)))
+            .sum();
+
+        for ((code, message), count) in new {
+            self.entries.insert((path.clone(), code, message), count);
+        }
+
+        (self, removed, added)
+    }
+}
+
+impl FromIterator<BaselineEntry> for Baseline {
+    fn from_iter<I: IntoIterator<Item = BaselineEntry>>(iter: I) -> Self {
+        let mut entries = BTreeMap::new();
+        for entry in iter {
+            if entry.count > 0 {
+                let message = message_key(&entry.message).into_owned();
+                *entries
+                    .entry((entry.path, entry.code, message))
+                    .or_default() += entry.count;
+            }
+        }
+        Self { entries }
+    }
+}
+
+/// Drops line references from a message, so that moving a violation (or the
+/// line it refers to) keeps its key.
+///
+/// Rules mention related lines as a trailing parenthetical, e.g.
+/// `duplicate key 'a' (first defined at line 1)` or
+/// `key 'a' should be ordered before 'b' (line 3)`; these are removed.
+fn message_key(message: &str) -> Cow<'_, str> {
+    let Some((start, inner)) = message
+        .strip_suffix(')')
+        .and_then(|rest| rest.rfind(" (").map(|start| (start, &rest[start + 2..])))
+    else {
+        return Cow::Borrowed(message);
+    };
+    let is_line_reference = inner
+        .rsplit_once("line ")
+        .is_some_and(|(_, n)| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()));
+    if is_line_reference {
+        Cow::Owned(message[..start].to_owned())
+    } else {
+        Cow::Borrowed(message)
+    }
+}
+
+/// Normalizes a path into a baseline key, using `/` on every platform.
+fn path_key(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticBuilder, Location, Severity, Span};
+
+    fn diag(code: &str, message: &str, line: usize) -> Diagnostic {
+        let loc = Location::new(line, 1, 0);
+        DiagnosticBuilder::new(code, Severity::Warning, message, Span::new(loc, loc))
+            .build_without_context()
+    }
+
+    #[test]
+    fn test_update_from_empty_adds_all() {
+        let current = [
+            diag("line-length", "too long", 1),
+            diag("line-length", "too long", 3),
+        ];
+        let (baseline, removed, added) = Baseline::new().update(Path::new("a.yaml"), &current);
+        assert_eq!((removed, added), (0, 2));
+        assert_eq!(baseline.len(), 2);
+        assert_eq!(baseline.entries().count(), 1);
+    }
+
+    #[test]
+    fn test_update_removes_fixed_violations() {
+        let before = [
+            diag("line-length", "too long", 1),
+            diag("key-ordering", "unsorted", 2),
+        ];
+        let (baseline, _, _) = Baseline::new().update(Path::new("a.yaml"), &before);
+
+        let after = [diag("line-length", "too long", 5)];
+        let (baseline, removed, added) = baseline.update(Path::new("a.yaml"), &after);
+        assert_eq!((removed, added), (1, 0));
+        assert_eq!(baseline.len(), 1);
+        assert_eq!(baseline.entries().next().unwrap().code, "line-length");
+    }
+
+    #[test]
+    fn test_update_keeps_other_files() {
+        let (baseline, _, _) = Baseline::new().update(Path::new("a.yaml"), &[diag("x", "m", 1)]);
+        let (baseline, removed, added) = baseline.update(Path::new("b.yaml"), &[]);
+        assert_eq!((removed, added), (0, 0));
+        assert_eq!(baseline.len(), 1);
+    }
+
+    #[test]
+    fn test_filter_suppresses_up_to_count() {
+        let (baseline, _, _) = Baseline::new().update(Path::new("a.yaml"), &[diag("x", "m", 1)]);
+        let current = vec![diag("x", "m", 7), diag("x", "m", 9), diag("y", "m", 1)];
+        let remaining = baseline.filter(Path::new("a.yaml"), current);
+        assert_eq!(remaining.len(), 2);
+        assert_eq!(remaining[0].span.start.line, 9);
+        assert_eq!(remaining[1].code.as_str(), "y");
+    }
+
+    #[test]
+    fn test_message_key_drops_line_references() {
+        assert_eq!(
+            message_key("duplicate key 'a' (first defined at line 12)"),
+            "duplicate key 'a'"
+        );
+        assert_eq!(
+            message_key("key 'a' should be ordered before 'b' (line 3)"),
+            "key 'a' should be ordered before 'b'"
+        );
+        assert_eq!(
+            message_key("line exceeds maximum length of 80 characters (current: 95)"),
+            "line exceeds maximum length of 80 characters (current: 95)"
+        );
+    }
+
+    #[test]
+    fn test_filter_survives_line_inserted_above() {
+        let linter = crate::Linter::with_all_rules();
+        let before = linter.lint("b: 1\nb: 2\na: 3\n").unwrap();
+        assert!(before.iter().any(|d| d.message.contains("line 1")));
+        let (baseline, _, _) = Baseline::new().update(Path::new("a.yaml"), &before);
+
+        let after = linter.lint("# settings\nb: 1\nb: 2\na: 3\n").unwrap();
+        assert!(after.iter().any(|d| d.message.contains("line 2")));
+        assert!(baseline.filter(Path::new("a.yaml"), after).is_empty());
+    }
+
+    #[test]
+    fn test_load_drops_line_references() {
+        let baseline = Baseline::from_iter([BaselineEntry {
+            path: "a.yaml".to_string(),
+            code: "duplicate-key".to_string(),
+            message: "duplicate key 'a' (first defined at line 1)".to_string(),
+            count: 1,
+        }]);
+        let current = vec![diag(
+            "duplicate-key",
+            "duplicate key 'a' (first defined at line 4)",
+            5,
+        )];
+        assert!(baseline.filter(Path::new("a.yaml"), current).is_empty());
+    }
+
+    #[test]
+    fn test_filter_ignores_other_files() {
+        let (baseline, _, _) = Baseline::new().update(Path::new("a.yaml"), &[diag("x", "m", 1)]);
+        let remaining = baseline.filter(Path::new("ab.yaml"), vec![diag("x", "m", 1)]);
+        assert_eq!(remaining.len(), 1);
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.yaml");
+        let (baseline, _, _) = Baseline::new().update(
+            Path::new("a.yaml"),
+            &[diag("x", "m", 1), diag("x", "m", 2), diag("y", "n", 3)],
+        );
+        baseline.save(&path).unwrap();
+        assert_eq!(Baseline::load(&path).unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_load_empty_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.yaml");
+        std::fs::write(&path, "").unwrap();
+        assert!(Baseline::load(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_load_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = Baseline::load(&dir.path().join("missing.yaml"));
+        assert!(matches!(missing, Err(BaselineError::Io { .. })));
+
+        let path = dir.path().join("bad.yaml");
+        std::fs::write(&path, "entries: 42\n").unwrap();
+        assert!(matches!(
+            Baseline::load(&path),
+            Err(BaselineError::Parse { .. })
+        ));
+    }
+}
@@ -134,20 +134,27 @@ impl<'a> CommentParser<'a> {
                     continue;
                 }
 
-                // Track string boundaries
-                if (ch == '"' || ch == '\'') && !in_string {
+                // A quote only opens a scalar at the start of a token; the
+                // apostrophe in a plain scalar such as `it's` is literal.
+                if (ch == '"' || ch == '\'') && !in_string && starts_token(line, col_idx) {
                     in_string = true;
                     string_delimiter = ch as u8;
                     continue;
                 }
 
                 if in_string && ch as u8 == string_delimiter {
+                    // `''` is an escaped quote inside a single-quoted scalar
+                    if ch == '\'' && line[col_idx + 1..].starts_with('\'') {
+                        escape_next = true;
+                        continue;
+                    }
                     in_string = false;
                     continue;
                 }
 
-                // Found comment outside string
-                if ch == '#' && !in_string {
+                // Found comment outside string; a `#` inside a plain scalar
+                // such as `a#b` is not preceded by whitespace.
+                if ch == '#' && !in_string && follows_whitespace(line, col_idx) {
                     let comment_start = offset;
                     let comment_content = &line[col_idx + 1..];
 
@@ -248,6 +255,24 @@ impl<'a> CommentParser<'a> {
     }
 }
 
+/// Returns `true` if the byte at `col` begins a new token: it is the first
+/// character of the line or follows whitespace or a flow indicator.
+fn starts_token(line: &str, col: usize) -> bool {
+    line[..col]
+        .chars()
+        .next_back()
+        .is_none_or(|prev| matches!(prev, ' ' | '\t' | '[' | '{' | ','))
+}
+
+/// Returns `true` if the byte at `col` is first on the line or follows
+/// whitespace, as a `#` must to start a comment.
+fn follows_whitespace(line: &str, col: usize) -> bool {
+    line[..col]
+        .chars()
+        .next_back()
+        .is_none_or(|prev| matches!(prev, ' ' | '\t'))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -450,4 +475,35 @@ mod tests {
         assert_eq!(comments.len(), 1);
         assert_eq!(comments[0].content, " real comment");
     }
+
+    #[test]
+    fn test_hash_inside_plain_scalar() {
+        let yaml = "url: http://example.com/#anchor\ncolor: a#b  # real";
+        let context = SourceContext::new(yaml);
+        let parser = CommentParser::new(yaml, &context);
+        let comments = parser.find_all();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].span.start.line, 2);
+        assert_eq!(comments[0].content, " real");
+    }
+
+    #[test]
+    fn test_apostrophe_in_plain_scalar() {
+        let yaml = "text: it's here  # comment";
+        let context = SourceContext::new(yaml);
+        let parser = CommentParser::new(yaml, &context);
+        let comments = parser.find_all();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].content, " comment");
+    }
+
+    #[test]
+    fn test_quoted_in_flow_sequence() {
+        let yaml = "list: ['#a', \"#b\"]  # comment";
+        let context = SourceContext::new(yaml);
+        let parser = CommentParser::new(yaml, &context);
+        let comments = parser.find_all();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].content, " comment");
+    }
 }
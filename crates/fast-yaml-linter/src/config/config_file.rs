@@ -49,17 +49,56 @@ pub struct ConfigFile {
 }
 
 /// Per-rule configuration entry from the config file.
+///
+/// Options may be written next to `enabled` and `severity` or nested under an
+/// `options:` mapping; nested options win when a key appears in both places.
+/// `level` is accepted as an alias of `severity` for yamllint compatibility.
+///
+/// ```yaml
+/// rules:
+///   line-length:
+///     severity: error
+///     max: 120
+///   truthy:
+///     level: warning
+///     options:
+///       allowed-values: ["true", "false"]
+/// ```
 #[derive(Debug, Clone, Deserialize)]
 pub struct ConfigFileRule {
     /// Whether this rule is enabled.
     pub enabled: Option<bool>,
     /// Override the rule's default severity.
+    #[serde(alias = "level")]
     pub severity: Option<ConfigFileSeverity>,
+    /// Options from a nested `options:` mapping.
+    #[serde(rename = "options", default)]
+    pub nested_options: HashMap<String, ConfigFileValue>,
     /// Rule-specific options.
     #[serde(flatten)]
     pub options: HashMap<String, ConfigFileValue>,
 }
 
+impl From<ConfigFileRule> for RuleConfig {
+    fn from(rule: ConfigFileRule) -> Self {
+        let mut config = if rule.enabled.unwrap_or(true) {
+            Self::new()
+        } else {
+            Self::disabled()
+        };
+
+        if let Some(severity) = rule.severity {
+            config = config.with_severity(severity.into());
+        }
+
+        for (key, value) in rule.options.into_iter().chain(rule.nested_options) {
+            config = config.with_option(key, RuleOption::from(value));
+        }
+
+        config
+    }
+}
+
 /// Severity value as parsed from the config file.
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -103,6 +142,17 @@ pub enum ConfigFileValue {
     StringList(Vec<String>),
 }
 
+impl From<ConfigFileValue> for RuleOption {
+    fn from(value: ConfigFileValue) -> Self {
+        match value {
+            ConfigFileValue::Bool(b) => Self::Bool(b),
+            ConfigFileValue::Int(i) => Self::Int(i),
+            ConfigFileValue::String(s) => Self::String(s),
+            ConfigFileValue::StringList(v) => Self::StringList(v),
+        }
+    }
+}
+
 /// Errors from config file loading.
 #[derive(Debug, thiserror::Error)]
 pub enum ConfigFileError {
@@ -174,6 +224,8 @@ impl ConfigFile {
     }
 
     /// Convert into a `LintConfig`, applying all `rules:` entries.
+    ///
+    /// Entries for unknown rule codes are skipped.
     #[must_use]
     pub fn into_lint_config(self) -> LintConfig {
         let mut config = LintConfig::default();
@@ -183,43 +235,21 @@ impl ConfigFile {
                 continue; // already warned above
             }
 
-            let enabled = rule_cfg.enabled.unwrap_or(true);
-            let mut rc = if enabled {
-                RuleConfig::new()
-            } else {
-                RuleConfig::disabled()
-            };
+            let rc = RuleConfig::from(rule_cfg);
 
-            if let Some(sev) = rule_cfg.severity {
-                rc = rc.with_severity(sev.into());
+            // Special case: line-length.max maps to the top-level LintConfig field because
+            // LineLengthRule reads config.max_line_length directly, not rule_configs.
+            if rule_name == "line-length"
+                && let Some(max) = rc.options.get_usize("max")
+            {
+                config.max_line_length = Some(max);
             }
-
-            for (key, val) in rule_cfg.options {
-                // Special case: line-length.max maps to the top-level LintConfig field because
-                // LineLengthRule reads config.max_line_length directly, not rule_configs.
-                if rule_name == "line-length"
-                    && key == "max"
-                    && let ConfigFileValue::Int(max) = &val
-                    && let Ok(max_usize) = usize::try_from(*max)
-                {
-                    config.max_line_length = Some(max_usize);
-                }
-                // Special case: indentation.indent-size maps to the top-level LintConfig field
-                // because IndentationRule reads config.indent_size directly, not rule_configs.
-                if rule_name == "indentation"
-                    && key == "indent-size"
-                    && let ConfigFileValue::Int(size) = &val
-                    && let Ok(size_usize) = usize::try_from(*size)
-                {
-                    config.indent_size = size_usize;
-                }
-                let opt = match val {
-                    ConfigFileValue::Bool(b) => RuleOption::Bool(b),
-                    ConfigFileValue::Int(i) => RuleOption::Int(i),
-                    ConfigFileValue::String(s) => RuleOption::String(s),
-                    ConfigFileValue::StringList(v) => RuleOption::StringList(v),
-                };
-                rc = rc.with_option(key, opt);
+            // Special case: indentation.indent-size maps to the top-level LintConfig field
+            // because IndentationRule reads config.indent_size directly, not rule_configs.
+            if rule_name == "indentation"
+                && let Some(size) = rc.options.get_usize("indent-size")
+            {
+                config.indent_size = size;
             }
 
             config = config.with_rule_config(rule_name, rc);
@@ -252,6 +282,12 @@ impl ConfigFile {
     }
 }
 
+impl From<ConfigFile> for LintConfig {
+    fn from(file: ConfigFile) -> Self {
+        file.into_lint_config()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -472,4 +508,26 @@ mod tests {
         let rule = &cfg.rules["line-length"];
         assert!(matches!(rule.severity, Some(ConfigFileSeverity::Warning)));
     }
+
+    #[test]
+    fn test_nested_options_and_level_alias() {
+        let f = write_temp(
+            "rules:\n  truthy:\n    level: error\n    check-keys: true\n    options:\n      check-keys: false\n      allowed-values: [\"yes\"]\n",
+        );
+        let lint_config = ConfigFile::load(f.path()).unwrap().into_lint_config();
+        let rc = lint_config.get_rule_config("truthy").unwrap();
+        assert_eq!(rc.severity, Some(Severity::Error));
+        assert_eq!(rc.options.get_bool("check-keys"), Some(false));
+        assert_eq!(
+            rc.options.get_string_list("allowed-values"),
+            Some(&["yes".to_string()][..])
+        );
+    }
+
+    #[test]
+    fn test_nested_line_length_max_sets_top_level_field() {
+        let f = write_temp("rules:\n  line-length:\n    options:\n      max: 90\n");
+        let lint_config = ConfigFile::load(f.path()).unwrap().into_lint_config();
+        assert_eq!(lint_config.max_line_length, Some(90));
+    }
 }
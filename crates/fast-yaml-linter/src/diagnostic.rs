@@ -50,6 +50,95 @@ pub struct Diagnostic {
     pub suggestions: Vec<Suggestion>,
 }
 
+impl Diagnostic {
+    /// Converts the diagnostic to a Language Server Protocol `Diagnostic` object.
+    ///
+    /// LSP positions are 0-indexed, so line and column numbers are shifted
+    /// down by one. Severity maps to the LSP `DiagnosticSeverity` values
+    /// (1 = error, 2 = warning, 3 = information, 4 = hint).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_linter::{DiagnosticBuilder, DiagnosticCode, Severity, Location, Span};
+    ///
+    /// let span = Span::new(Location::new(3, 1, 20), Location::new(3, 5, 24));
+    /// let diagnostic = DiagnosticBuilder::new(
+    ///     DiagnosticCode::DUPLICATE_KEY,
+    ///     Severity::Error,
+    ///     "duplicate key 'name' found",
+    ///     span
+    /// ).build_without_context();
+    ///
+    /// let lsp = diagnostic.to_lsp_json();
+    /// assert_eq!(lsp["range"]["start"]["line"], 2);
+    /// assert_eq!(lsp["severity"], 1);
+    /// assert_eq!(lsp["source"], "fast-yaml");
+    /// ```
+    #[cfg(feature = "json-output")]
+    #[must_use]
+    pub fn to_lsp_json(&self) -> serde_json::Value {
+        let severity = match self.severity {
+            Severity::Error => 1,
+            Severity::Warning => 2,
+            Severity::Info => 3,
+            Severity::Hint => 4,
+        };
+
+        serde_json::json!({
+            "range": {
+                "start": {
+                    "line": self.span.start.line.saturating_sub(1),
+                    "character": self.span.start.column.saturating_sub(1),
+                },
+                "end": {
+                    "line": self.span.end.line.saturating_sub(1),
+                    "character": self.span.end.column.saturating_sub(1),
+                },
+            },
+            "severity": severity,
+            "code": self.code.as_str(),
+            "source": "fast-yaml",
+            "message": self.message,
+        })
+    }
+
+    /// Formats the diagnostic as a single line for VS Code task problem matchers.
+    ///
+    /// The output has the form `line:column: severity: message [code]` and can be
+    /// matched with the pattern `^(\d+):(\d+): (error|warning|info|hint): (.*) \[(.*)\]$`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_linter::{DiagnosticBuilder, DiagnosticCode, Severity, Location, Span};
+    ///
+    /// let span = Span::new(Location::new(1, 81, 80), Location::new(1, 95, 94));
+    /// let diagnostic = DiagnosticBuilder::new(
+    ///     DiagnosticCode::LINE_LENGTH,
+    ///     Severity::Warning,
+    ///     "line too long (94 > 80 characters)",
+    ///     span
+    /// ).build_without_context();
+    ///
+    /// assert_eq!(
+    ///     diagnostic.to_vscode_problem_matcher_str(),
+    ///     "1:81: warning: line too long (94 > 80 characters) [line-length]"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_vscode_problem_matcher_str(&self) -> String {
+        format!(
+            "{}:{}: {}: {} [{}]",
+            self.span.start.line,
+            self.span.start.column,
+            self.severity,
+            self.message,
+            self.code.as_str()
+        )
+    }
+}
+
 /// Unique identifier for a diagnostic.
 ///
 /// Represents the type of diagnostic issue being reported.
@@ -78,6 +167,8 @@ impl DiagnosticCode {
     pub const UNDEFINED_ALIAS: &'static str = "undefined-alias";
     /// Predefined code for indentation issues.
     pub const INDENTATION: &'static str = "indentation";
+    /// Predefined code for indentation mixing tabs and spaces.
+    pub const MIXED_INDENTATION: &'static str = "mixed-indentation";
     /// Predefined code for line length violations.
     pub const LINE_LENGTH: &'static str = "line-length";
     /// Predefined code for trailing whitespace.
@@ -442,6 +533,59 @@ mod tests {
     use super::*;
     use crate::Location;
 
+    fn make_diagnostic(severity: Severity) -> Diagnostic {
+        let span = Span::new(Location::new(2, 3, 12), Location::new(2, 8, 17));
+        DiagnosticBuilder::new(
+            DiagnosticCode::TRUTHY,
+            severity,
+            "truthy value should be one of [false, true]",
+            span,
+        )
+        .build_without_context()
+    }
+
+    #[cfg(feature = "json-output")]
+    #[test]
+    fn test_to_lsp_json() {
+        let lsp = make_diagnostic(Severity::Warning).to_lsp_json();
+        assert_eq!(
+            lsp,
+            serde_json::json!({
+                "range": {
+                    "start": { "line": 1, "character": 2 },
+                    "end": { "line": 1, "character": 7 },
+                },
+                "severity": 2,
+                "code": "truthy",
+                "source": "fast-yaml",
+                "message": "truthy value should be one of [false, true]",
+            })
+        );
+    }
+
+    #[cfg(feature = "json-output")]
+    #[test]
+    fn test_to_lsp_json_severity_mapping() {
+        assert_eq!(
+            make_diagnostic(Severity::Error).to_lsp_json()["severity"],
+            1
+        );
+        assert_eq!(
+            make_diagnostic(Severity::Warning).to_lsp_json()["severity"],
+            2
+        );
+        assert_eq!(make_diagnostic(Severity::Info).to_lsp_json()["severity"], 3);
+        assert_eq!(make_diagnostic(Severity::Hint).to_lsp_json()["severity"], 4);
+    }
+
+    #[test]
+    fn test_to_vscode_problem_matcher_str() {
+        assert_eq!(
+            make_diagnostic(Severity::Info).to_vscode_problem_matcher_str(),
+            "2:3: info: truthy value should be one of [false, true] [truthy]"
+        );
+    }
+
     #[test]
     fn test_diagnostic_code_new() {
         let code = DiagnosticCode::new("test-code");
@@ -459,6 +603,7 @@ mod tests {
         assert_eq!(DiagnosticCode::DUPLICATE_KEY, "duplicate-key");
         assert_eq!(DiagnosticCode::INVALID_ANCHOR, "invalid-anchor");
         assert_eq!(DiagnosticCode::INDENTATION, "indentation");
+        assert_eq!(DiagnosticCode::MIXED_INDENTATION, "mixed-indentation");
     }
 
     #[test]
@@ -0,0 +1,159 @@
+//! GitHub Actions workflow command formatter.
+
+use crate::{Diagnostic, Formatter, Severity};
+use std::fmt::Write;
+
+/// GitHub Actions workflow command formatter.
+///
+/// Emits one `::error`, `::warning` or `::notice` command per diagnostic, so
+/// that a workflow step running the linter annotates the offending lines.
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_linter::{DiagnosticBuilder, DiagnosticCode, Formatter, GithubFormatter};
+/// use fast_yaml_linter::{Location, Severity, Span};
+///
+/// let span = Span::new(Location::new(3, 5, 20), Location::new(3, 8, 23));
+/// let diagnostic = DiagnosticBuilder::new(
+///     DiagnosticCode::DUPLICATE_KEY,
+///     Severity::Error,
+///     "duplicate key 'name'",
+///     span,
+/// )
+/// .build_without_context();
+///
+/// let formatter = GithubFormatter::new().with_file("config.yaml");
+/// assert_eq!(
+///     formatter.format(&[diagnostic], ""),
+///     "::error file=config.yaml,line=3,col=5,endLine=3,endColumn=8,title=duplicate-key::duplicate key 'name'\n"
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GithubFormatter {
+    /// Path reported as the annotation's `file`, if known.
+    pub file: Option<String>,
+}
+
+impl GithubFormatter {
+    /// Creates a new GitHub formatter without a file path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_linter::GithubFormatter;
+    ///
+    /// let formatter = GithubFormatter::new();
+    /// assert!(formatter.file.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { file: None }
+    }
+
+    /// Sets the path reported as the annotation's `file`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_linter::GithubFormatter;
+    ///
+    /// let formatter = GithubFormatter::new().with_file("config.yaml");
+    /// assert_eq!(formatter.file.as_deref(), Some("config.yaml"));
+    /// ```
+    #[must_use]
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl Formatter for GithubFormatter {
+    fn format(&self, diagnostics: &[Diagnostic], _source: &str) -> String {
+        let mut output = String::new();
+
+        for diag in diagnostics {
+            let command = match diag.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info | Severity::Hint => "notice",
+            };
+
+            let _ = write!(output, "::{command} ");
+            if let Some(file) = &self.file {
+                let _ = write!(output, "file={},", escape_property(file));
+            }
+            let _ = writeln!(
+                output,
+                "line={},col={},endLine={},endColumn={},title={}::{}",
+                diag.span.start.line,
+                diag.span.start.column,
+                diag.span.end.line,
+                diag.span.end.column,
+                escape_property(diag.code.as_str()),
+                escape_data(&diag.message)
+            );
+        }
+
+        output
+    }
+}
+
+/// Escapes a workflow command message.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a workflow command property value.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DiagnosticBuilder, DiagnosticCode, Location, Span};
+
+    fn diagnostic(severity: Severity, message: &str) -> Diagnostic {
+        let span = Span::new(Location::new(2, 1, 10), Location::new(2, 4, 13));
+        DiagnosticBuilder::new(DiagnosticCode::LINE_LENGTH, severity, message, span)
+            .build_without_context()
+    }
+
+    #[test]
+    fn test_github_formatter_empty() {
+        assert!(GithubFormatter::new().format(&[], "").is_empty());
+    }
+
+    #[test]
+    fn test_github_formatter_severity_commands() {
+        let diagnostics = [
+            diagnostic(Severity::Error, "a"),
+            diagnostic(Severity::Warning, "b"),
+            diagnostic(Severity::Info, "c"),
+            diagnostic(Severity::Hint, "d"),
+        ];
+        let output = GithubFormatter::new().format(&diagnostics, "");
+        let commands: Vec<_> = output
+            .lines()
+            .map(|line| line.split(' ').next().unwrap())
+            .collect();
+        assert_eq!(commands, ["::error", "::warning", "::notice", "::notice"]);
+        assert!(
+            output.starts_with("::error line=2,col=1,endLine=2,endColumn=4,title=line-length::a\n")
+        );
+    }
+
+    #[test]
+    fn test_github_formatter_escaping() {
+        let formatter = GithubFormatter::new().with_file("dir,a/b:c.yaml");
+        let output = formatter.format(&[diagnostic(Severity::Error, "100% bad\nvalue: x")], "");
+        assert_eq!(
+            output,
+            "::error file=dir%2Ca/b%3Ac.yaml,line=2,col=1,endLine=2,endColumn=4,title=line-length::100%25 bad%0Avalue: x\n"
+        );
+    }
+}
@@ -4,9 +4,15 @@ use crate::{Diagnostic, Formatter};
 
 /// JSON formatter for machine-readable output.
 ///
-/// Serializes diagnostics to JSON format for consumption by tools
+/// Serializes diagnostics to a JSON array for consumption by tools
 /// and IDEs.
 ///
+/// Each object holds the serialized [`Diagnostic`] plus flattened fields for
+/// scripting: `file` (when set), the 1-based `line` and `column` of the span
+/// start, `severity_level` (0 = hint, 1 = info, 2 = warning, 3 = error) and
+/// `suggestion`, the first suggested fix as `{description, replacement,
+/// span}` or `null`.
+///
 /// # Examples
 ///
 /// ```
@@ -20,6 +26,8 @@ use crate::{Diagnostic, Formatter};
 pub struct JsonFormatter {
     /// Pretty-print JSON.
     pub pretty: bool,
+    /// Path reported as each diagnostic's `file`, if known.
+    pub file: Option<String>,
 }
 
 #[cfg(feature = "json-output")]
@@ -40,7 +48,72 @@ impl JsonFormatter {
     /// ```
     #[must_use]
     pub const fn new(pretty: bool) -> Self {
-        Self { pretty }
+        Self { pretty, file: None }
+    }
+
+    /// Sets the path reported as each diagnostic's `file`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_linter::JsonFormatter;
+    ///
+    /// let formatter = JsonFormatter::new(false).with_file("<stdin>");
+    /// assert_eq!(formatter.file.as_deref(), Some("<stdin>"));
+    /// ```
+    #[must_use]
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Converts one diagnostic to the JSON object written by [`Formatter::format`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_linter::{DiagnosticBuilder, DiagnosticCode, JsonFormatter};
+    /// use fast_yaml_linter::{Location, Severity, Span};
+    ///
+    /// let span = Span::new(Location::new(2, 5, 12), Location::new(2, 9, 16));
+    /// let diagnostic =
+    ///     DiagnosticBuilder::new(DiagnosticCode::LINE_LENGTH, Severity::Warning, "too long", span)
+    ///         .build_without_context();
+    ///
+    /// let value = JsonFormatter::new(false).with_file("a.yaml").to_value(&diagnostic);
+    /// assert_eq!(value["file"], "a.yaml");
+    /// assert_eq!(value["line"], 2);
+    /// assert_eq!(value["column"], 5);
+    /// assert_eq!(value["severity_level"], 2);
+    /// assert!(value["suggestion"].is_null());
+    /// ```
+    #[must_use]
+    pub fn to_value(&self, diagnostic: &Diagnostic) -> serde_json::Value {
+        let mut value = serde_json::to_value(diagnostic).unwrap_or(serde_json::Value::Null);
+        if let serde_json::Value::Object(map) = &mut value {
+            if let Some(file) = &self.file {
+                map.insert("file".to_string(), file.as_str().into());
+            }
+            map.insert("line".to_string(), diagnostic.span.start.line.into());
+            map.insert("column".to_string(), diagnostic.span.start.column.into());
+            map.insert(
+                "severity_level".to_string(),
+                diagnostic.severity.level().into(),
+            );
+            let suggestion =
+                diagnostic
+                    .suggestions
+                    .first()
+                    .map_or(serde_json::Value::Null, |suggestion| {
+                        serde_json::json!({
+                            "description": suggestion.message,
+                            "replacement": suggestion.replacement,
+                            "span": suggestion.span,
+                        })
+                    });
+            map.insert("suggestion".to_string(), suggestion);
+        }
+        value
     }
 }
 
@@ -54,10 +127,11 @@ impl Default for JsonFormatter {
 #[cfg(feature = "json-output")]
 impl Formatter for JsonFormatter {
     fn format(&self, diagnostics: &[Diagnostic], _source: &str) -> String {
+        let values: Vec<_> = diagnostics.iter().map(|d| self.to_value(d)).collect();
         if self.pretty {
-            serde_json::to_string_pretty(diagnostics).unwrap_or_else(|_| "[]".to_string())
+            serde_json::to_string_pretty(&values).unwrap_or_else(|_| "[]".to_string())
         } else {
-            serde_json::to_string(diagnostics).unwrap_or_else(|_| "[]".to_string())
+            serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string())
         }
     }
 }
@@ -96,4 +170,55 @@ mod tests {
         assert!(output.contains("\"code\":\"line-length\""));
         assert!(output.contains("\"severity\":\"info\""));
     }
+
+    #[test]
+    fn test_json_formatter_schema() {
+        let span = Span::new(Location::new(3, 7, 30), Location::new(3, 9, 32));
+        let diagnostics = [
+            DiagnosticBuilder::new(
+                DiagnosticCode::TRAILING_WHITESPACE,
+                Severity::Hint,
+                "a",
+                span,
+            )
+            .with_suggestion("remove trailing whitespace", span, None)
+            .build_without_context(),
+            DiagnosticBuilder::new(DiagnosticCode::LINE_LENGTH, Severity::Error, "b", span)
+                .with_suggestion("replace", span, Some("x".to_string()))
+                .build_without_context(),
+        ];
+
+        let formatter = JsonFormatter::new(false).with_file("config.yaml");
+        let output: serde_json::Value =
+            serde_json::from_str(&formatter.format(&diagnostics, "")).unwrap();
+
+        let first = &output[0];
+        assert_eq!(first["file"], "config.yaml");
+        assert_eq!(first["line"], 3);
+        assert_eq!(first["column"], 7);
+        assert_eq!(first["severity"], "hint");
+        assert_eq!(first["severity_level"], 0);
+        assert_eq!(
+            first["suggestion"]["description"],
+            "remove trailing whitespace"
+        );
+        assert!(first["suggestion"]["replacement"].is_null());
+        assert_eq!(first["suggestion"]["span"]["start"]["offset"], 30);
+
+        assert_eq!(output[1]["severity_level"], 3);
+        assert_eq!(output[1]["suggestion"]["replacement"], "x");
+    }
+
+    #[test]
+    fn test_json_formatter_without_file() {
+        let span = Span::new(Location::new(1, 1, 0), Location::new(1, 2, 1));
+        let diagnostic =
+            DiagnosticBuilder::new(DiagnosticCode::LINE_LENGTH, Severity::Info, "test", span)
+                .build_without_context();
+
+        let value = JsonFormatter::new(false).to_value(&diagnostic);
+        assert!(value.get("file").is_none());
+        assert_eq!(value["severity_level"], 1);
+        assert!(value["suggestion"].is_null());
+    }
 }
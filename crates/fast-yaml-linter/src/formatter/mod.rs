@@ -1,5 +1,6 @@
 //! Diagnostic output formatters.
 
+mod github;
 mod text;
 
 #[cfg(feature = "json-output")]
@@ -8,6 +9,7 @@ mod json;
 #[cfg(feature = "sarif-output")]
 mod sarif;
 
+pub use github::GithubFormatter;
 pub use text::TextFormatter;
 
 #[cfg(feature = "json-output")]
@@ -26,6 +26,10 @@
 //! println!("{}", output);
 //! ```
 
+// Lets `#[register_lint_rule]` expand to `::fast_yaml_linter::...` paths inside this crate.
+extern crate self as fast_yaml_linter;
+
+mod baseline;
 mod context;
 mod diagnostic;
 mod linter;
@@ -39,12 +43,13 @@ pub mod rules;
 pub mod source;
 pub mod tokenizer;
 
+pub use baseline::{Baseline, BaselineEntry, BaselineError};
 pub use config::{ConfigFile, ConfigFileError};
 pub use context::{LineMetadata, LintContext, SourceContext};
 pub use diagnostic::{
     ContextLine, Diagnostic, DiagnosticBuilder, DiagnosticCode, DiagnosticContext, Suggestion,
 };
-pub use formatter::{Formatter, TextFormatter};
+pub use formatter::{Formatter, GithubFormatter, TextFormatter};
 pub use linter::{LintConfig, LintError, Linter};
 pub use location::{Location, Span};
 pub use severity::Severity;
@@ -54,3 +59,9 @@ pub use formatter::JsonFormatter;
 
 #[cfg(feature = "sarif-output")]
 pub use formatter::SarifFormatter;
+
+/// Items used by code generated from `#[register_lint_rule]`. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use inventory;
+}
@@ -1,8 +1,14 @@
 //! Main linter engine and configuration.
 
-use crate::{Diagnostic, LintContext, Severity, config::RuleConfig, rules::RuleRegistry};
+use crate::{
+    Diagnostic, LintContext, Severity,
+    config::{ConfigFile, ConfigFileError, RuleConfig},
+    rules::RuleRegistry,
+};
 use fast_yaml_core::{Parser, ScalarOwned, Value};
+use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 /// Configuration for the linter.
 ///
@@ -18,7 +24,11 @@ use std::collections::{HashMap, HashSet};
 /// assert_eq!(config.max_line_length, Some(80));
 /// assert_eq!(config.indent_size, 2);
 /// ```
-#[derive(Debug, Clone)]
+///
+/// Deserializing a `LintConfig` reads the config file format described by
+/// [`ConfigFile`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "ConfigFile")]
 pub struct LintConfig {
     /// Maximum line length (None = unlimited).
     pub max_line_length: Option<usize>,
@@ -66,6 +76,30 @@ impl LintConfig {
         Self::default()
     }
 
+    /// Loads a configuration from a YAML config file.
+    ///
+    /// The file holds a `rules:` mapping from rule code to `enabled`,
+    /// `severity` (or `level`) and rule options, written inline or under
+    /// `options:`. Rules the file does not mention keep their defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigFileError` if the file cannot be read or is not a
+    /// valid config file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fast_yaml_linter::LintConfig;
+    /// use std::path::Path;
+    ///
+    /// let config = LintConfig::from_yaml_file(Path::new(".fast-yaml.yaml")).unwrap();
+    /// assert!(config.is_rule_enabled("line-length"));
+    /// ```
+    pub fn from_yaml_file(path: &Path) -> Result<Self, ConfigFileError> {
+        ConfigFile::load(path).map(ConfigFile::into_lint_config)
+    }
+
     /// Sets the maximum line length.
     ///
     /// # Examples
@@ -202,7 +236,7 @@ impl LintConfig {
     /// Sets whether the document start marker (`---`) is required.
     ///
     /// When `true`, the linter will warn if a document is missing `---`.
-    /// This is equivalent to setting `present: "required"` via `rule_configs` for
+    /// This is equivalent to setting `present: "always"` via `rule_configs` for
     /// the `document-start` rule, but `rule_configs` takes priority if both are set.
     ///
     /// # Examples
@@ -821,4 +855,66 @@ mod tests {
             "rule disabled via RuleConfig::disabled() should not produce diagnostics"
         );
     }
+
+    #[test]
+    fn test_trailing_whitespace_severity_override() {
+        let yaml = "key: value   \nother: 1\n";
+        let config = LintConfig::new().with_rule_config(
+            crate::DiagnosticCode::TRAILING_WHITESPACE,
+            RuleConfig::new().with_severity(Severity::Error),
+        );
+        let diagnostics = Linter::with_all_rules_and_config(config)
+            .lint(yaml)
+            .unwrap();
+
+        let trailing: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.code.as_str() == crate::DiagnosticCode::TRAILING_WHITESPACE)
+            .collect();
+        assert_eq!(trailing.len(), 1);
+        assert_eq!(trailing[0].severity, Severity::Error);
+    }
+
+    /// Every built-in rule must report its help example with the severity set
+    /// in its rule config rather than a hardcoded one.
+    #[test]
+    fn test_severity_override_respected_by_all_rules() {
+        let mut checked = 0;
+        for rule in RuleRegistry::with_default_rules().rules() {
+            let help = rule.help();
+            let Some(example) = help.violation_example else {
+                continue;
+            };
+            let severity = if rule.default_severity() == Severity::Error {
+                Severity::Hint
+            } else {
+                Severity::Error
+            };
+            let config = LintConfig::new()
+                .with_rule_config(rule.code(), RuleConfig::new().with_severity(severity));
+            let Ok(diagnostics) = Linter::with_all_rules_and_config(config).lint(example) else {
+                continue;
+            };
+
+            let own: Vec<_> = diagnostics
+                .iter()
+                .filter(|d| d.code.as_str() == rule.code())
+                .collect();
+            if !own.is_empty() {
+                checked += 1;
+            }
+            for diagnostic in own {
+                assert_eq!(
+                    diagnostic.severity,
+                    severity,
+                    "rule '{}' ignored its severity override",
+                    rule.code()
+                );
+            }
+        }
+        assert!(
+            checked >= 15,
+            "only {checked} rule examples produced diagnostics"
+        );
+    }
 }
@@ -1,9 +1,10 @@
 //! Rule to check flow mapping braces `{}` formatting.
 
 use crate::{
-    Diagnostic, DiagnosticBuilder, DiagnosticCode, LintConfig, LintContext, Severity,
+    Diagnostic, DiagnosticCode, LintConfig, LintContext, Severity,
     rules::flow_common::{
-        check_spaces_after_opening, check_spaces_before_closing, is_empty_collection,
+        FlowKind, check_forbidden, check_spaces_after_opening, check_spaces_before_closing,
+        is_empty_collection,
     },
     tokenizer::{FlowTokenizer, TokenType},
 };
@@ -15,6 +16,7 @@ use fast_yaml_core::Value;
 ///
 /// Configuration options:
 /// - `forbid`: "no" | "non-empty" | "all" (default: "no")
+/// - `forbid-flow-mappings`: boolean, shorthand for `forbid: all` (default: false)
 /// - `min-spaces-inside`: integer (default: 0)
 /// - `max-spaces-inside`: integer (default: 0)
 /// - `min-spaces-inside-empty`: integer (default: -1, disabled)
@@ -36,6 +38,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct BracesRule;
 
 impl super::LintRule for BracesRule {
@@ -55,6 +58,17 @@ impl super::LintRule for BracesRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(self.code(), self.name(), self.description(), self.default_severity())
+            .with_option(super::RuleOptionHelp::new("forbid", "string", "no", "`no` allows flow mappings, `non-empty` forbids non-empty ones, `all` forbids them entirely"))
+            .with_option(super::RuleOptionHelp::new("forbid-flow-mappings", "boolean", "false", "Forbid all flow mappings; shorthand for `forbid: all`"))
+            .with_option(super::RuleOptionHelp::new("min-spaces-inside", "integer", "0", "Minimum spaces after the opening and before the closing delimiter"))
+            .with_option(super::RuleOptionHelp::new("max-spaces-inside", "integer", "0", "Maximum spaces after the opening and before the closing delimiter"))
+            .with_option(super::RuleOptionHelp::new("min-spaces-inside-empty", "integer", "-1", "Minimum spaces inside an empty flow mapping (-1 disables the check)"))
+            .with_option(super::RuleOptionHelp::new("max-spaces-inside-empty", "integer", "-1", "Maximum spaces inside an empty flow mapping (-1 disables the check)"))
+            .with_examples("object: { key: value }\n", "object: {key: value}\n")
+    }
+
     #[allow(clippy::too_many_lines)]
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
@@ -62,9 +76,16 @@ impl super::LintRule for BracesRule {
         let tokenizer = FlowTokenizer::new(source, source_context);
 
         let rule_config = config.get_rule_config(self.code());
-        let forbid = rule_config
-            .and_then(|rc| rc.options.get_string("forbid"))
-            .unwrap_or("no");
+        let forbid = if rule_config
+            .and_then(|rc| rc.options.get_bool("forbid-flow-mappings"))
+            .unwrap_or(false)
+        {
+            "all"
+        } else {
+            rule_config
+                .and_then(|rc| rc.options.get_string("forbid"))
+                .unwrap_or("no")
+        };
 
         let min_spaces_inside = rule_config
             .and_then(|rc| rc.options.get_int("min-spaces-inside"))
@@ -82,57 +103,23 @@ impl super::LintRule for BracesRule {
             .and_then(|rc| rc.options.get_int("max-spaces-inside-empty"))
             .unwrap_or(-1);
 
+        if matches!(forbid, "all" | "non-empty") {
+            let severity = config.get_effective_severity(self.code(), self.default_severity());
+            return check_forbidden(
+                source,
+                source_context,
+                FlowKind::Mapping,
+                forbid,
+                self.code(),
+                severity,
+            );
+        }
+
         let mut diagnostics = Vec::new();
 
         let open_braces = tokenizer.find_all(TokenType::BraceOpen);
         let close_braces = tokenizer.find_all(TokenType::BraceClose);
 
-        // Check forbid option
-        match forbid {
-            "all" => {
-                for token in &open_braces {
-                    let severity =
-                        config.get_effective_severity(self.code(), self.default_severity());
-                    diagnostics.push(
-                        DiagnosticBuilder::new(
-                            self.code(),
-                            severity,
-                            "flow mapping forbidden (forbid: all)",
-                            token.span,
-                        )
-                        .build_with_context(source_context),
-                    );
-                }
-                return diagnostics;
-            }
-            "non-empty" => {
-                // Check if mapping is non-empty
-                for (i, open) in open_braces.iter().enumerate() {
-                    if let Some(close) = close_braces.get(i)
-                        && !is_empty_collection(
-                            source,
-                            open.span.end.offset,
-                            close.span.start.offset,
-                        )
-                    {
-                        let severity =
-                            config.get_effective_severity(self.code(), self.default_severity());
-                        diagnostics.push(
-                            DiagnosticBuilder::new(
-                                self.code(),
-                                severity,
-                                "non-empty flow mapping forbidden (forbid: non-empty)",
-                                open.span,
-                            )
-                            .build_with_context(source_context),
-                        );
-                    }
-                }
-                return diagnostics;
-            }
-            _ => {} // "no" - continue with spacing checks
-        }
-
         // Check spacing
         for (i, open) in open_braces.iter().enumerate() {
             if let Some(close) = close_braces.get(i) {
@@ -460,4 +447,43 @@ mod tests {
             "no false positives in block scalar: {diagnostics:?}"
         );
     }
+
+    #[test]
+    fn test_braces_forbid_flow_mappings() {
+        let yaml = "obj: {a: 1}\nempty: {}\nblock:\n  b: 2\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = BracesRule;
+        let config = LintConfig::new().with_rule_config(
+            "braces",
+            RuleConfig::new().with_option("forbid-flow-mappings", true),
+        );
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].span.start.line, 1);
+        assert_eq!(diagnostics[0].span.start.column, 6);
+        assert_eq!(diagnostics[1].span.start.line, 2);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(
+            diagnostics[0].suggestions[0]
+                .message
+                .contains("block mapping")
+        );
+    }
+
+    #[test]
+    fn test_braces_forbid_ignores_strings_and_block_scalars() {
+        let yaml = "a: \"{b: c}\"\nb: |\n  {x}\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = BracesRule;
+        let config = LintConfig::new()
+            .with_rule_config("braces", RuleConfig::new().with_option("forbid", "all"));
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
 }
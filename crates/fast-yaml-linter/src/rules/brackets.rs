@@ -1,9 +1,10 @@
 //! Rule to check flow sequence brackets `[]` formatting.
 
 use crate::{
-    Diagnostic, DiagnosticBuilder, DiagnosticCode, LintConfig, LintContext, Severity,
+    Diagnostic, DiagnosticCode, LintConfig, LintContext, Severity,
     rules::flow_common::{
-        check_spaces_after_opening, check_spaces_before_closing, is_empty_collection,
+        FlowKind, check_forbidden, check_spaces_after_opening, check_spaces_before_closing,
+        is_empty_collection,
     },
     tokenizer::{FlowTokenizer, TokenType},
 };
@@ -15,6 +16,7 @@ use fast_yaml_core::Value;
 ///
 /// Configuration options:
 /// - `forbid`: "no" | "non-empty" | "all" (default: "no")
+/// - `forbid-flow-sequences`: boolean, shorthand for `forbid: all` (default: false)
 /// - `min-spaces-inside`: integer (default: 0)
 /// - `max-spaces-inside`: integer (default: 0)
 /// - `min-spaces-inside-empty`: integer (default: -1, disabled)
@@ -36,6 +38,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct BracketsRule;
 
 impl super::LintRule for BracketsRule {
@@ -55,6 +58,17 @@ impl super::LintRule for BracketsRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(self.code(), self.name(), self.description(), self.default_severity())
+            .with_option(super::RuleOptionHelp::new("forbid", "string", "no", "`no` allows flow sequences, `non-empty` forbids non-empty ones, `all` forbids them entirely"))
+            .with_option(super::RuleOptionHelp::new("forbid-flow-sequences", "boolean", "false", "Forbid all flow sequences; shorthand for `forbid: all`"))
+            .with_option(super::RuleOptionHelp::new("min-spaces-inside", "integer", "0", "Minimum spaces after the opening and before the closing delimiter"))
+            .with_option(super::RuleOptionHelp::new("max-spaces-inside", "integer", "0", "Maximum spaces after the opening and before the closing delimiter"))
+            .with_option(super::RuleOptionHelp::new("min-spaces-inside-empty", "integer", "-1", "Minimum spaces inside an empty flow sequence (-1 disables the check)"))
+            .with_option(super::RuleOptionHelp::new("max-spaces-inside-empty", "integer", "-1", "Maximum spaces inside an empty flow sequence (-1 disables the check)"))
+            .with_examples("list: [ 1, 2 ]\n", "list: [1, 2]\n")
+    }
+
     #[allow(clippy::too_many_lines)]
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
@@ -62,9 +76,16 @@ impl super::LintRule for BracketsRule {
         let tokenizer = FlowTokenizer::new(source, source_context);
 
         let rule_config = config.get_rule_config(self.code());
-        let forbid = rule_config
-            .and_then(|rc| rc.options.get_string("forbid"))
-            .unwrap_or("no");
+        let forbid = if rule_config
+            .and_then(|rc| rc.options.get_bool("forbid-flow-sequences"))
+            .unwrap_or(false)
+        {
+            "all"
+        } else {
+            rule_config
+                .and_then(|rc| rc.options.get_string("forbid"))
+                .unwrap_or("no")
+        };
 
         let min_spaces_inside = rule_config
             .and_then(|rc| rc.options.get_int("min-spaces-inside"))
@@ -82,57 +103,23 @@ impl super::LintRule for BracketsRule {
             .and_then(|rc| rc.options.get_int("max-spaces-inside-empty"))
             .unwrap_or(-1);
 
+        if matches!(forbid, "all" | "non-empty") {
+            let severity = config.get_effective_severity(self.code(), self.default_severity());
+            return check_forbidden(
+                source,
+                source_context,
+                FlowKind::Sequence,
+                forbid,
+                self.code(),
+                severity,
+            );
+        }
+
         let mut diagnostics = Vec::new();
 
         let open_brackets = tokenizer.find_all(TokenType::BracketOpen);
         let close_brackets = tokenizer.find_all(TokenType::BracketClose);
 
-        // Check forbid option
-        match forbid {
-            "all" => {
-                for token in &open_brackets {
-                    let severity =
-                        config.get_effective_severity(self.code(), self.default_severity());
-                    diagnostics.push(
-                        DiagnosticBuilder::new(
-                            self.code(),
-                            severity,
-                            "flow sequence forbidden (forbid: all)",
-                            token.span,
-                        )
-                        .build_with_context(source_context),
-                    );
-                }
-                return diagnostics;
-            }
-            "non-empty" => {
-                // Check if sequence is non-empty
-                for (i, open) in open_brackets.iter().enumerate() {
-                    if let Some(close) = close_brackets.get(i)
-                        && !is_empty_collection(
-                            source,
-                            open.span.end.offset,
-                            close.span.start.offset,
-                        )
-                    {
-                        let severity =
-                            config.get_effective_severity(self.code(), self.default_severity());
-                        diagnostics.push(
-                            DiagnosticBuilder::new(
-                                self.code(),
-                                severity,
-                                "non-empty flow sequence forbidden (forbid: non-empty)",
-                                open.span,
-                            )
-                            .build_with_context(source_context),
-                        );
-                    }
-                }
-                return diagnostics;
-            }
-            _ => {} // "no" - continue with spacing checks
-        }
-
         // Check spacing
         for (i, open) in open_brackets.iter().enumerate() {
             if let Some(close) = close_brackets.get(i) {
@@ -367,4 +354,43 @@ mod tests {
             "no false positives in block scalar: {diagnostics:?}"
         );
     }
+
+    #[test]
+    fn test_brackets_forbid_flow_sequences() {
+        let yaml = "list: [1, 2]\nempty: []\nblock:\n  - a\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = BracketsRule;
+        let config = LintConfig::new().with_rule_config(
+            "brackets",
+            RuleConfig::new().with_option("forbid-flow-sequences", true),
+        );
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].span.start.line, 1);
+        assert_eq!(diagnostics[0].span.start.column, 7);
+        assert_eq!(diagnostics[1].span.start.line, 2);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert!(
+            diagnostics[0].suggestions[0]
+                .message
+                .contains("block sequence")
+        );
+    }
+
+    #[test]
+    fn test_brackets_forbid_ignores_strings_and_block_scalars() {
+        let yaml = "a: \"[1, 2]\"\nb: |\n  [x]\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = BracketsRule;
+        let config = LintConfig::new()
+            .with_rule_config("brackets", RuleConfig::new().with_option("forbid", "all"));
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
 }
@@ -35,6 +35,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct ColonsRule;
 
 impl super::LintRule for ColonsRule {
@@ -54,6 +55,28 @@ impl super::LintRule for ColonsRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "max-spaces-before",
+            "integer",
+            "0",
+            "Maximum spaces before a colon",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "max-spaces-after",
+            "integer",
+            "1",
+            "Maximum spaces after a colon",
+        ))
+        .with_examples("key : value\n", "key: value\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let source_context = context.source_context();
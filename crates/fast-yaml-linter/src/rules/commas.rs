@@ -32,6 +32,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct CommasRule;
 
 impl super::LintRule for CommasRule {
@@ -51,6 +52,34 @@ impl super::LintRule for CommasRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "max-spaces-before",
+            "integer",
+            "0",
+            "Maximum spaces before a comma",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "min-spaces-after",
+            "integer",
+            "1",
+            "Minimum spaces after a comma",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "max-spaces-after",
+            "integer",
+            "1",
+            "Maximum spaces after a comma",
+        ))
+        .with_examples("list: [1 ,2]\n", "list: [1, 2]\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let source_context = context.source_context();
@@ -29,6 +29,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct CommentsRule;
 
 impl super::LintRule for CommentsRule {
@@ -48,6 +49,37 @@ impl super::LintRule for CommentsRule {
         Severity::Info
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "require-starting-space",
+            "boolean",
+            "true",
+            "Require a space after `#`",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "ignore-shebangs",
+            "boolean",
+            "true",
+            "Do not report a `#!` shebang on the first line",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "min-spaces-from-content",
+            "integer",
+            "2",
+            "Minimum spaces between content and an inline comment",
+        ))
+        .with_examples(
+            "#comment\nkey: value # inline\n",
+            "# comment\nkey: value  # inline\n",
+        )
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let comments = context.comments();
@@ -326,4 +358,74 @@ mod tests {
         let diagnostics = rule.check(&context, &value, &config);
         assert!(diagnostics.is_empty());
     }
+
+    fn check_with(yaml: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+        let context = LintContext::new(yaml);
+        CommentsRule.check(&context, &value, config)
+    }
+
+    #[test]
+    fn test_comments_inline_without_space_and_spacing() {
+        let diagnostics = check_with("key: value #comment", &LintConfig::default());
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].message.contains("should start with a space"));
+        assert!(
+            diagnostics[1]
+                .message
+                .contains("expected at least 2, found 1")
+        );
+        assert_eq!(diagnostics[0].span.start.column, 12);
+    }
+
+    #[test]
+    fn test_comments_hash_in_single_quoted_string() {
+        let diagnostics = check_with("key: 'a #b'  # note", &LintConfig::default());
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_comments_hash_in_double_quoted_string() {
+        let diagnostics = check_with("key: \"a #b\"\nother: \"x\\\"#y\"", &LintConfig::default());
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_comments_single_quote_escape() {
+        let diagnostics = check_with("key: 'it''s #1'  #bad", &LintConfig::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("should start with a space"));
+        assert_eq!(diagnostics[0].span.start.column, 18);
+    }
+
+    #[test]
+    fn test_comments_hash_in_plain_scalar_ignored() {
+        let diagnostics = check_with(
+            "url: http://example.com/#section\ncolor: a#b\n",
+            &LintConfig::default(),
+        );
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_comments_apostrophe_in_plain_scalar() {
+        let diagnostics = check_with("text: it's fine #bad", &LintConfig::default());
+        assert_eq!(diagnostics.len(), 2);
+    }
+
+    #[test]
+    fn test_comments_min_spaces_disabled() {
+        let config = LintConfig::new().with_rule_config(
+            "comments",
+            RuleConfig::new().with_option("min-spaces-from-content", 0_i64),
+        );
+        let diagnostics = check_with("key: value # ok", &config);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_comments_in_flow_sequence() {
+        let diagnostics = check_with("list: ['#a', \"#b\"]  # ok", &LintConfig::default());
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
 }
@@ -33,6 +33,7 @@ struct LineInfo {
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct CommentsIndentationRule;
 
 impl super::LintRule for CommentsIndentationRule {
@@ -52,6 +53,19 @@ impl super::LintRule for CommentsIndentationRule {
         Severity::Info
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_examples(
+            "key:\n# comment\n  nested: value\n",
+            "key:\n  # comment\n  nested: value\n",
+        )
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let comments = context.comments();
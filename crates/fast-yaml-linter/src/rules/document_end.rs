@@ -28,6 +28,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct DocumentEndRule;
 
 impl super::LintRule for DocumentEndRule {
@@ -47,6 +48,22 @@ impl super::LintRule for DocumentEndRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "present",
+            "boolean",
+            "false",
+            "Require the `...` document end marker",
+        ))
+        .with_examples("key: value\n", "key: value\n...\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let required = config
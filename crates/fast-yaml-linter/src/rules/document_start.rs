@@ -8,26 +8,36 @@ use fast_yaml_core::Value;
 
 /// Linting rule for document start marker.
 ///
-/// Requires, forbids, or allows the YAML document start marker `---`.
+/// Requires or forbids the YAML document start marker `---`. The raw source
+/// is scanned rather than the parsed value, since the marker does not survive
+/// parsing.
 ///
 /// Configuration options:
-/// - `present`: "required" | "forbidden" | "allowed" (default: "allowed")
+/// - `present`: "always" | "never" | "only-if-multiple-docs" | "allowed"
+///   (default: "only-if-multiple-docs")
+///
+/// With "always" every document must start with `---`, with "never" no
+/// document may, and with "only-if-multiple-docs" the marker is required only
+/// when the stream contains more than one document. "required" and
+/// "forbidden" are accepted as aliases for "always" and "never".
 ///
 /// # Examples
 ///
 /// ```
-/// use fast_yaml_linter::{rules::DocumentStartRule, rules::LintRule, LintConfig, config::RuleConfig};
+/// use fast_yaml_linter::{rules::DocumentStartRule, rules::LintRule, LintConfig, LintContext, config::RuleConfig};
+/// use fast_yaml_core::Parser;
 ///
 /// let rule = DocumentStartRule;
 /// let yaml = "---\nname: John";
 /// let value = Parser::parse_str(yaml).unwrap().unwrap();
 ///
 /// let config = LintConfig::new()
-///     .with_rule_config("document-start", RuleConfig::new().with_option("present", "required"));
+///     .with_rule_config("document-start", RuleConfig::new().with_option("present", "always"));
 ///
-/// let diagnostics = rule.check(yaml, &value, &config);
+/// let diagnostics = rule.check(&LintContext::new(yaml), &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct DocumentStartRule;
 
 impl super::LintRule for DocumentStartRule {
@@ -47,62 +57,99 @@ impl super::LintRule for DocumentStartRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "present",
+            "string",
+            "only-if-multiple-docs",
+            "`always` requires the `---` marker on every document, `never` forbids it, \
+             `only-if-multiple-docs` requires it in multi-document streams, `allowed` accepts both",
+        ))
+        .with_examples("key: value\n", "---\nkey: value\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
-        let source = context.source();
         let presence = config
             .get_rule_config(self.code())
             .and_then(|rc| rc.options.get_string("present"))
             .unwrap_or(if config.require_document_start {
-                "required"
+                "always"
             } else {
-                "allowed"
+                "only-if-multiple-docs"
             });
 
+        let documents = scan_documents(context.source());
         let source_context = context.source_context();
         match presence {
-            "required" => check_required(source, source_context, config, self.code()),
-            "forbidden" => check_forbidden(source, source_context, config, self.code()),
-            _ => Vec::new(), // "allowed" = no checks
+            "always" | "required" => {
+                check_required(&documents, source_context, config, self.code())
+            }
+            "never" | "forbidden" => {
+                check_forbidden(&documents, source_context, config, self.code())
+            }
+            "only-if-multiple-docs" if documents.len() > 1 => {
+                check_required(&documents, source_context, config, self.code())
+            }
+            _ => Vec::new(), // "allowed" or a single document
         }
     }
 }
 
+/// A document found in the raw source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DocumentStart {
+    /// The document opens with a `---` marker covering `span`.
+    Marker(Span),
+    /// The document has no marker; its first content line starts at `location`.
+    Bare(Location),
+}
+
 fn check_required(
-    source: &str,
+    documents: &[DocumentStart],
     source_context: &SourceContext<'_>,
     config: &LintConfig,
     code: &str,
 ) -> Vec<Diagnostic> {
-    if has_document_start_marker(source) {
-        Vec::new()
-    } else {
-        let severity = config.get_effective_severity(code, Severity::Warning);
-        vec![
-            DiagnosticBuilder::new(
-                code,
-                severity,
-                "missing document start marker '---'",
-                Span::new(Location::new(1, 1, 0), Location::new(1, 1, 0)),
-            )
-            .with_suggestion(
-                "Add '---' at the beginning",
-                Span::new(Location::new(1, 1, 0), Location::new(1, 1, 0)),
-                Some("---\n".to_string()),
-            )
-            .build_with_context(source_context),
-        ]
-    }
+    let severity = config.get_effective_severity(code, Severity::Warning);
+    documents
+        .iter()
+        .filter_map(|doc| match doc {
+            DocumentStart::Bare(location) => Some(*location),
+            DocumentStart::Marker(_) => None,
+        })
+        .map(|location| {
+            let span = Span::new(location, location);
+            DiagnosticBuilder::new(code, severity, "missing document start marker '---'", span)
+                .with_suggestion(
+                    "Add '---' at the beginning",
+                    span,
+                    Some("---\n".to_string()),
+                )
+                .build_with_context(source_context)
+        })
+        .collect()
 }
 
 fn check_forbidden(
-    source: &str,
+    documents: &[DocumentStart],
     source_context: &SourceContext<'_>,
     config: &LintConfig,
     code: &str,
 ) -> Vec<Diagnostic> {
-    if let Some((_line_num, span)) = find_document_start_marker(source) {
-        let severity = config.get_effective_severity(code, Severity::Warning);
-        vec![
+    let severity = config.get_effective_severity(code, Severity::Warning);
+    documents
+        .iter()
+        .filter_map(|doc| match doc {
+            DocumentStart::Marker(span) => Some(*span),
+            DocumentStart::Bare(_) => None,
+        })
+        .map(|span| {
             DiagnosticBuilder::new(
                 code,
                 severity,
@@ -110,48 +157,57 @@ fn check_forbidden(
                 span,
             )
             .with_suggestion("Remove '---'", span, None)
-            .build_with_context(source_context),
-        ]
-    } else {
-        Vec::new()
-    }
+            .build_with_context(source_context)
+        })
+        .collect()
 }
 
-fn has_document_start_marker(source: &str) -> bool {
-    find_document_start_marker(source).is_some()
-}
-
-fn find_document_start_marker(source: &str) -> Option<(usize, Span)> {
-    for (line_num, line) in source.lines().enumerate() {
-        let trimmed = line.trim_start();
-
-        // Skip empty lines and comments
-        if trimmed.is_empty() || trimmed.starts_with('#') {
-            continue;
-        }
-
-        if trimmed.starts_with("---") {
-            let col = line.len() - trimmed.len() + 1;
-            let offset = source
-                .lines()
-                .take(line_num)
-                .map(|l| l.len() + 1)
-                .sum::<usize>();
-
-            return Some((
-                line_num + 1,
-                Span::new(
-                    Location::new(line_num + 1, col, offset + col - 1),
-                    Location::new(line_num + 1, col + 3, offset + col + 2),
-                ),
-            ));
+/// Splits the source into documents and records how each one starts.
+///
+/// A `---` or `...` at column 1 followed by whitespace or the end of the line
+/// is always a document marker, even inside a block scalar, so a line scan is
+/// sufficient. Comments, blank lines and directives before a document do not
+/// count as its content.
+fn scan_documents(source: &str) -> Vec<DocumentStart> {
+    let mut documents = Vec::new();
+    let mut in_document = false;
+    let mut offset = 0;
+
+    for (idx, line) in source.split_inclusive('\n').enumerate() {
+        let line_num = idx + 1;
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if is_marker(content, "---") {
+            documents.push(DocumentStart::Marker(Span::new(
+                Location::new(line_num, 1, offset),
+                Location::new(line_num, 4, offset + 3),
+            )));
+            in_document = true;
+        } else if is_marker(content, "...") {
+            in_document = false;
+        } else if !in_document {
+            let trimmed = content.trim_start();
+            if !(trimmed.is_empty() || trimmed.starts_with('#') || content.starts_with('%')) {
+                let col = content.len() - trimmed.len() + 1;
+                documents.push(DocumentStart::Bare(Location::new(
+                    line_num,
+                    col,
+                    offset + col - 1,
+                )));
+                in_document = true;
+            }
         }
 
-        // Found content before marker
-        break;
+        offset += line.len();
     }
 
-    None
+    documents
+}
+
+/// Returns `true` if `line` is the document marker `marker` at column 1.
+fn is_marker(line: &str, marker: &str) -> bool {
+    line.strip_prefix(marker)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
 }
 
 #[cfg(test)]
@@ -252,12 +308,85 @@ mod tests {
     }
 
     #[test]
-    fn test_find_document_start_marker() {
-        assert!(find_document_start_marker("---\ntest: value").is_some());
-        assert!(find_document_start_marker("# comment\n---\ntest: value").is_some());
-        assert!(find_document_start_marker("  ---\ntest: value").is_some());
-        assert!(find_document_start_marker("test: value").is_none());
-        assert!(find_document_start_marker("").is_none());
+    fn test_scan_documents() {
+        assert!(matches!(
+            scan_documents("---\ntest: value")[..],
+            [DocumentStart::Marker(_)]
+        ));
+        assert!(matches!(
+            scan_documents("# comment\n%YAML 1.2\n---\ntest: value")[..],
+            [DocumentStart::Marker(_)]
+        ));
+        assert!(matches!(
+            scan_documents("test: value")[..],
+            [DocumentStart::Bare(_)]
+        ));
+        assert!(matches!(
+            scan_documents("a: 1\n---\nb: 2\n...\nc: 3\n")[..],
+            [
+                DocumentStart::Bare(_),
+                DocumentStart::Marker(_),
+                DocumentStart::Bare(_)
+            ]
+        ));
+        assert!(matches!(
+            scan_documents("--- |\n  text\n---text: 1\n")[..],
+            [DocumentStart::Marker(_)]
+        ));
+        assert!(scan_documents("").is_empty());
+        assert!(scan_documents("# only a comment\n").is_empty());
+    }
+
+    #[test]
+    fn test_document_start_only_if_multiple_docs_default() {
+        let rule = DocumentStartRule;
+        let config = LintConfig::new();
+
+        let single = "name: John\n";
+        let value = Parser::parse_str(single).unwrap().unwrap();
+        let diagnostics = rule.check(&LintContext::new(single), &value, &config);
+        assert!(diagnostics.is_empty());
+
+        let multi = "a: 1\n---\nb: 2\n";
+        let diagnostics = rule.check(&LintContext::new(multi), &value, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.start.line, 1);
+
+        let marked = "---\na: 1\n---\nb: 2\n";
+        let diagnostics = rule.check(&LintContext::new(marked), &value, &config);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_document_start_always_each_document() {
+        let yaml = "---\na: 1\n...\n# second\nb: 2\n";
+        let value = Parser::parse_str("a: 1").unwrap().unwrap();
+
+        let rule = DocumentStartRule;
+        let config = LintConfig::new().with_rule_config(
+            "document-start",
+            RuleConfig::new().with_option("present", "always"),
+        );
+
+        let diagnostics = rule.check(&LintContext::new(yaml), &value, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.start.line, 5);
+    }
+
+    #[test]
+    fn test_document_start_never_multiple_markers() {
+        let yaml = "---\na: 1\n---\nb: 2\n";
+        let value = Parser::parse_str("a: 1").unwrap().unwrap();
+
+        let rule = DocumentStartRule;
+        let config = LintConfig::new().with_rule_config(
+            "document-start",
+            RuleConfig::new().with_option("present", "never"),
+        );
+
+        let diagnostics = rule.check(&LintContext::new(yaml), &value, &config);
+        let lines: Vec<_> = diagnostics.iter().map(|d| d.span.start.line).collect();
+        assert_eq!(lines, [1, 3]);
     }
 
     #[test]
@@ -15,6 +15,7 @@ use std::collections::HashMap;
 ///
 /// Duplicate keys cause silent data loss — most parsers keep the last value, silently
 /// discarding earlier ones. This rule detects them per-mapping-scope at all depths.
+#[super::register_lint_rule]
 pub struct DuplicateKeysRule;
 
 impl super::LintRule for DuplicateKeysRule {
@@ -34,6 +35,19 @@ impl super::LintRule for DuplicateKeysRule {
         Severity::Error
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_examples(
+            "name: first\nname: second\n",
+            "name: first\nalias: second\n",
+        )
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         if config.allow_duplicate_keys {
             return Vec::new();
@@ -32,6 +32,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct EmptyLinesRule;
 
 impl super::LintRule for EmptyLinesRule {
@@ -51,6 +52,37 @@ impl super::LintRule for EmptyLinesRule {
         Severity::Info
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "max",
+            "integer",
+            "2",
+            "Maximum consecutive empty lines",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "max-start",
+            "integer",
+            "0",
+            "Maximum empty lines at the start of the document",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "max-end",
+            "integer",
+            "0",
+            "Maximum empty lines at the end of the document",
+        ))
+        .with_examples(
+            "key: value\n\n\n\nother: value\n",
+            "key: value\n\nother: value\n",
+        )
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let rule_config = config.get_rule_config(self.code());
@@ -76,7 +108,6 @@ impl super::LintRule for EmptyLinesRule {
         // Track consecutive empty lines
         let mut empty_count = 0;
         let mut empty_start_line = 0;
-        let mut offset = 0;
 
         for (idx, line) in lines.iter().enumerate() {
             let line_num = idx + 1;
@@ -86,88 +117,104 @@ impl super::LintRule for EmptyLinesRule {
                     empty_start_line = line_num;
                 }
                 empty_count += 1;
-            } else {
-                // Check if we exceeded limits
-                if empty_count > 0 {
-                    let limit = if empty_start_line == 1 {
-                        max_start
-                    } else {
-                        max
-                    };
-
-                    #[allow(
-                        clippy::cast_possible_truncation,
-                        clippy::cast_possible_wrap,
-                        clippy::cast_lossless
-                    )]
-                    let empty_count_i64 = empty_count as i64;
-
-                    if limit >= 0 && empty_count_i64 > limit {
-                        let severity =
-                            config.get_effective_severity(self.code(), self.default_severity());
-
-                        let location = Location::new(empty_start_line, 1, offset - empty_count);
-                        let span = Span::new(location, location);
-
-                        let position = if empty_start_line == 1 {
-                            "at document start"
-                        } else {
-                            "in document"
-                        };
-
-                        diagnostics.push(
-                            DiagnosticBuilder::new(
-                                self.code(),
-                                severity,
-                                format!(
-                                    "too many consecutive empty lines {position} (expected at most {limit}, found {empty_count})"
-                                ),
-                                span,
-                            )
-                            .build_with_context(context.source_context()),
-                        );
-                    }
-
-                    empty_count = 0;
+            } else if empty_count > 0 {
+                let (limit, position) = if empty_start_line == 1 {
+                    (max_start, "at document start")
+                } else {
+                    (max, "in document")
+                };
+
+                if let Some(diag) = self.check_run(
+                    context,
+                    config,
+                    empty_start_line,
+                    empty_count,
+                    limit,
+                    position,
+                ) {
+                    diagnostics.push(diag);
                 }
-            }
 
-            offset += line.len() + 1; // +1 for newline
+                empty_count = 0;
+            }
         }
 
         // Check trailing empty lines at end
-        if empty_count > 0 {
-            #[allow(
-                clippy::cast_possible_truncation,
-                clippy::cast_possible_wrap,
-                clippy::cast_lossless
-            )]
-            let empty_count_i64 = empty_count as i64;
-
-            if max_end >= 0 && empty_count_i64 > max_end {
-                let severity = config.get_effective_severity(self.code(), self.default_severity());
-
-                let location = Location::new(empty_start_line, 1, offset - empty_count);
-                let span = Span::new(location, location);
-
-                diagnostics.push(
-                    DiagnosticBuilder::new(
-                        self.code(),
-                        severity,
-                        format!(
-                            "too many consecutive empty lines at document end (expected at most {max_end}, found {empty_count})"
-                        ),
-                        span,
-                    )
-                    .build_with_context(context.source_context()),
-                );
-            }
+        if empty_count > 0
+            && let Some(diag) = self.check_run(
+                context,
+                config,
+                empty_start_line,
+                empty_count,
+                max_end,
+                "at document end",
+            )
+        {
+            diagnostics.push(diag);
         }
 
         diagnostics
     }
 }
 
+impl EmptyLinesRule {
+    /// Reports a run of `count` empty lines starting at `start_line` if it
+    /// exceeds `limit` (negative disables the check).
+    ///
+    /// The diagnostic points at the first excess line and suggests removing
+    /// the excess lines.
+    fn check_run(
+        &self,
+        context: &LintContext,
+        config: &LintConfig,
+        start_line: usize,
+        count: usize,
+        limit: i64,
+        position: &str,
+    ) -> Option<Diagnostic> {
+        use super::LintRule;
+
+        let allowed = usize::try_from(limit).ok()?;
+        if count <= allowed {
+            return None;
+        }
+
+        let source_context = context.source_context();
+        let excess_line = start_line + allowed;
+        let excess_offset = source_context.get_line_offset(excess_line);
+        let location = Location::new(excess_line, 1, excess_offset);
+
+        // The run ends where the next line begins, or at the end of the source.
+        let next_line = start_line + count;
+        let end = if next_line <= source_context.line_count() {
+            Location::new(next_line, 1, source_context.get_line_offset(next_line))
+        } else {
+            source_context.offset_to_location(context.source().len())
+        };
+
+        let severity = config.get_effective_severity(self.code(), self.default_severity());
+        let excess = count - allowed;
+        let lines = if excess == 1 { "line" } else { "lines" };
+
+        Some(
+            DiagnosticBuilder::new(
+                self.code(),
+                severity,
+                format!(
+                    "too many consecutive empty lines {position} (expected at most {limit}, found {count})"
+                ),
+                Span::new(location, location),
+            )
+            .with_suggestion(
+                format!("remove {excess} empty {lines}"),
+                Span::new(location, end),
+                None,
+            )
+            .build_with_context(source_context),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -332,4 +379,60 @@ mod tests {
         // Should report 2 violations (two blocks with 3 empty lines each)
         assert_eq!(diagnostics.len(), 2);
     }
+
+    #[test]
+    fn test_empty_lines_points_at_first_excess_line() {
+        let yaml = "key: value\n\n  \n\t\n\nanother: value\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = EmptyLinesRule;
+        let config = LintConfig::new()
+            .with_rule_config("empty-lines", RuleConfig::new().with_option("max", 1_i64));
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert_eq!(diagnostics.len(), 1);
+
+        let diag = &diagnostics[0];
+        assert_eq!(diag.span.start.line, 3);
+        assert_eq!(diag.span.start.offset, yaml.find("  \n").unwrap());
+        assert_eq!(diag.suggestions[0].message, "remove 3 empty lines");
+        assert_eq!(diag.suggestions[0].span.end.line, 6);
+        assert_eq!(
+            diag.suggestions[0].span.end.offset,
+            yaml.find("another").unwrap()
+        );
+        assert!(diag.suggestions[0].replacement.is_none());
+    }
+
+    #[test]
+    fn test_empty_lines_crlf_offsets() {
+        let yaml = "a: 1\r\n\r\n\r\n\r\nb: 2\r\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = EmptyLinesRule;
+        let config = LintConfig::default();
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.start.line, 4);
+        assert_eq!(diagnostics[0].span.start.offset, 10);
+        assert_eq!(diagnostics[0].suggestions[0].message, "remove 1 empty line");
+    }
+
+    #[test]
+    fn test_empty_lines_at_end_suggestion_reaches_eof() {
+        let yaml = "key: value\n\n\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = EmptyLinesRule;
+        let config = LintConfig::default();
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.start.line, 2);
+        assert_eq!(diagnostics[0].suggestions[0].span.end.offset, yaml.len());
+    }
 }
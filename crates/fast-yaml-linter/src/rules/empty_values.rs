@@ -27,6 +27,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &LintConfig::new());
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct EmptyValuesRule;
 
 impl super::LintRule for EmptyValuesRule {
@@ -50,6 +51,34 @@ impl super::LintRule for EmptyValuesRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "forbid_in_block_mappings",
+            "boolean",
+            "true",
+            "Report implicit null values in block mappings",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "forbid_in_flow_mappings",
+            "boolean",
+            "true",
+            "Report implicit null values in flow mappings",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "forbid_in_block_sequences",
+            "boolean",
+            "true",
+            "Report implicit null items in block sequences",
+        ))
+        .with_examples("key:\n", "key: null\n")
+    }
+
     fn check(&self, context: &LintContext, value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let forbid_block = config
             .get_rule_config(self.code())
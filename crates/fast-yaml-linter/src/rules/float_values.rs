@@ -5,6 +5,9 @@ use crate::{
     Span,
 };
 use fast_yaml_core::Value;
+use saphyr_parser::ScalarStyle;
+
+use super::scalar_events::for_each_scalar;
 
 /// Linting rule for float values.
 ///
@@ -16,6 +19,10 @@ use fast_yaml_core::Value;
 /// - `forbid-scientific-notation`: boolean (default: false)
 /// - `forbid-nan`: boolean (default: false)
 /// - `forbid-inf`: boolean (default: false)
+/// - `canonical-special-floats`: boolean (default: true) - require `.nan` and
+///   `.inf` rather than `.NaN`, `.INF` or the dotless `nan` and `inf`
+///
+/// Only plain scalars are checked; quoted values are strings.
 ///
 /// # Examples
 ///
@@ -32,6 +39,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(&context, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct FloatValuesRule;
 
 impl super::LintRule for FloatValuesRule {
@@ -51,205 +59,172 @@ impl super::LintRule for FloatValuesRule {
         Severity::Warning
     }
 
-    #[allow(clippy::too_many_lines)]
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "require-numeral-before-decimal",
+            "boolean",
+            "true",
+            "Require a digit before the decimal point (`0.5`, not `.5`)",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "forbid-scientific-notation",
+            "boolean",
+            "false",
+            "Report floats written in scientific notation",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "forbid-nan",
+            "boolean",
+            "false",
+            "Report `.nan` values",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "forbid-inf",
+            "boolean",
+            "false",
+            "Report `.inf` values",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "canonical-special-floats",
+            "boolean",
+            "true",
+            "Require `.nan` and `.inf` rather than `.NaN`, `.Inf`, `nan` or `inf`",
+        ))
+        .with_examples("value: .5\n", "value: 0.5\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let rule_config = config.get_rule_config(self.code());
-
-        let require_numeral_before_decimal = rule_config
-            .and_then(|rc| rc.options.get_bool("require-numeral-before-decimal"))
-            .unwrap_or(true);
-
-        let forbid_scientific_notation = rule_config
-            .and_then(|rc| rc.options.get_bool("forbid-scientific-notation"))
-            .unwrap_or(false);
-
-        let forbid_nan = rule_config
-            .and_then(|rc| rc.options.get_bool("forbid-nan"))
-            .unwrap_or(false);
-
-        let forbid_inf = rule_config
-            .and_then(|rc| rc.options.get_bool("forbid-inf"))
-            .unwrap_or(false);
+        let option = |name: &str, default: bool| {
+            rule_config
+                .and_then(|rc| rc.options.get_bool(name))
+                .unwrap_or(default)
+        };
+
+        let require_numeral_before_decimal = option("require-numeral-before-decimal", true);
+        let forbid_scientific_notation = option("forbid-scientific-notation", false);
+        let forbid_nan = option("forbid-nan", false);
+        let forbid_inf = option("forbid-inf", false);
+        let canonical_special_floats = option("canonical-special-floats", true);
 
         let mut diagnostics = Vec::new();
 
-        // Use cached lines and metadata from context
-        let lines = context.lines();
-        let line_metadata = context.line_metadata();
-
-        for (line_idx, (line, metadata)) in lines.iter().zip(line_metadata).enumerate() {
-            let line_num = line_idx + 1;
-            let line_offset = context.source_context().get_line_offset(line_num);
-
-            // Skip comment lines using cached metadata
-            if metadata.is_comment {
-                continue;
+        for_each_scalar(context.source(), |scalar| {
+            if scalar.style != ScalarStyle::Plain || scalar.is_key {
+                return;
             }
 
-            // Find value parts (after : or -)
-            let parts: Vec<(&str, usize)> = line.find(':').map_or_else(
-                || {
-                    if line.trim_start().find('-').is_some() {
-                        let actual_hyphen_pos = line.find('-').unwrap_or(0);
-                        vec![(&line[actual_hyphen_pos + 1..], actual_hyphen_pos + 1)]
-                    } else {
-                        vec![]
-                    }
-                },
-                |colon_pos| vec![(&line[colon_pos + 1..], colon_pos + 1)],
+            let value_token = scalar.value;
+            let offset = context.source_context().get_line_offset(scalar.line) + scalar.col;
+            let span = Span::new(
+                Location::new(scalar.line, scalar.col + 1, offset),
+                Location::new(
+                    scalar.line,
+                    scalar.col + 1 + value_token.len(),
+                    offset + value_token.len(),
+                ),
             );
-
-            for (part, part_offset) in parts {
-                let trimmed = part.trim();
-
-                // Skip if empty, quoted, or starts with flow collection markers
-                if trimmed.is_empty()
-                    || trimmed.starts_with('"')
-                    || trimmed.starts_with('\'')
-                    || trimmed.starts_with('[')
-                    || trimmed.starts_with('{')
-                {
-                    continue;
-                }
-
-                // Extract the value token (before any comment or space)
-                let value_token = trimmed
-                    .split_whitespace()
-                    .next()
-                    .and_then(|s| s.split('#').next())
-                    .unwrap_or(trimmed);
-
-                // Pre-compute lowercase once for all checks
-                let value_lower = value_token.to_lowercase();
-
-                // Check for missing numeral before decimal point
-                let bare = value_token.trim_start_matches(['-', '+']);
-                if require_numeral_before_decimal && bare.starts_with('.') {
-                    // Check if it's a valid float starting with '.' (e.g. .5, -.5, +.5)
-                    if bare.len() > 1
-                        && bare[1..].chars().next().is_some_and(|c| c.is_ascii_digit())
-                    {
-                        let value_start =
-                            line[part_offset..].find(value_token).unwrap_or(0) + part_offset;
-                        let offset = line_offset + value_start;
-                        let severity =
-                            config.get_effective_severity(self.code(), self.default_severity());
-
-                        let location = Location::new(line_num, 1, offset);
-                        let span = Span::new(
-                            location,
-                            Location::new(line_num, 1, offset + value_token.len()),
-                        );
-
-                        let suggestion = if value_token.starts_with(['-', '+']) {
-                            format!("{}0{}", &value_token[..1], &value_token[1..])
-                        } else {
-                            format!("0{value_token}")
-                        };
-                        diagnostics.push(
-                            DiagnosticBuilder::new(
-                                self.code(),
-                                severity,
-                                format!(
-                                    "float value '{value_token}' should have a numeral before the decimal point (e.g., '{suggestion}')"
-                                ),
-                                span,
-                            )
-                            .build_with_context(context.source_context()),
-                        );
-                    }
+            let mut report = |message: String, fix: Option<String>| {
+                let severity = config.get_effective_severity(self.code(), self.default_severity());
+                let mut builder = DiagnosticBuilder::new(self.code(), severity, message, span);
+                if let Some(fix) = fix {
+                    builder =
+                        builder.with_suggestion(format!("replace with '{fix}'"), span, Some(fix));
                 }
+                diagnostics.push(builder.build_with_context(context.source_context()));
+            };
+
+            // Pre-compute lowercase once for all checks
+            let value_lower = value_token.to_lowercase();
+
+            // Check for missing numeral before decimal point (e.g. .5, -.5, +.5)
+            let bare = value_token.trim_start_matches(['-', '+']);
+            if require_numeral_before_decimal
+                && bare.starts_with('.')
+                && bare[1..].chars().next().is_some_and(|c| c.is_ascii_digit())
+            {
+                let suggestion = if value_token.starts_with(['-', '+']) {
+                    format!("{}0{}", &value_token[..1], &value_token[1..])
+                } else {
+                    format!("0{value_token}")
+                };
+                report(
+                    format!(
+                        "float value '{value_token}' should have a numeral before the decimal point (e.g., '{suggestion}')"
+                    ),
+                    Some(suggestion),
+                );
+            }
 
-                // Check for scientific notation
-                if forbid_scientific_notation
-                    && ((value_lower.contains('e') && value_token.parse::<f64>().is_ok())
-                        || value_lower.ends_with("e+")
-                        || value_lower.ends_with("e-"))
-                {
-                    let value_start =
-                        line[part_offset..].find(value_token).unwrap_or(0) + part_offset;
-                    let offset = line_offset + value_start;
-                    let severity =
-                        config.get_effective_severity(self.code(), self.default_severity());
-
-                    let location = Location::new(line_num, 1, offset);
-                    let span = Span::new(
-                        location,
-                        Location::new(line_num, 1, offset + value_token.len()),
-                    );
-
-                    diagnostics.push(
-                        DiagnosticBuilder::new(
-                            self.code(),
-                            severity,
-                            format!("scientific notation '{value_token}' is forbidden"),
-                            span,
-                        )
-                        .build_with_context(context.source_context()),
-                    );
-                }
+            // Check for scientific notation
+            if forbid_scientific_notation
+                && ((value_lower.contains('e') && value_token.parse::<f64>().is_ok())
+                    || value_lower.ends_with("e+")
+                    || value_lower.ends_with("e-"))
+            {
+                report(
+                    format!("scientific notation '{value_token}' is forbidden"),
+                    None,
+                );
+            }
 
-                // Check for NaN
-                if forbid_nan && matches!(value_lower.as_str(), ".nan" | "nan") {
-                    let value_start =
-                        line[part_offset..].find(value_token).unwrap_or(0) + part_offset;
-                    let offset = line_offset + value_start;
-                    let severity =
-                        config.get_effective_severity(self.code(), self.default_severity());
-
-                    let location = Location::new(line_num, 1, offset);
-                    let span = Span::new(
-                        location,
-                        Location::new(line_num, 1, offset + value_token.len()),
-                    );
-
-                    diagnostics.push(
-                        DiagnosticBuilder::new(
-                            self.code(),
-                            severity,
-                            "NaN (not a number) is forbidden",
-                            span,
-                        )
-                        .build_with_context(context.source_context()),
-                    );
-                }
+            let is_nan = matches!(value_lower.as_str(), ".nan" | "nan");
+            let is_inf = matches!(
+                value_lower.as_str(),
+                ".inf" | "-.inf" | "+.inf" | "inf" | "-inf" | "+inf"
+            );
 
-                // Check for Infinity
-                if forbid_inf
-                    && matches!(
-                        value_lower.as_str(),
-                        ".inf" | "-.inf" | "+.inf" | "inf" | "-inf" | "+inf"
-                    )
-                {
-                    let value_start =
-                        line[part_offset..].find(value_token).unwrap_or(0) + part_offset;
-                    let offset = line_offset + value_start;
-                    let severity =
-                        config.get_effective_severity(self.code(), self.default_severity());
-
-                    let location = Location::new(line_num, 1, offset);
-                    let span = Span::new(
-                        location,
-                        Location::new(line_num, 1, offset + value_token.len()),
-                    );
-
-                    diagnostics.push(
-                        DiagnosticBuilder::new(
-                            self.code(),
-                            severity,
-                            "Infinity is forbidden",
-                            span,
-                        )
-                        .build_with_context(context.source_context()),
-                    );
-                }
+            if forbid_nan && is_nan {
+                report("NaN (not a number) is forbidden".to_string(), None);
+            } else if forbid_inf && is_inf {
+                report("Infinity is forbidden".to_string(), None);
+            } else if canonical_special_floats
+                && let Some(canonical) = canonical_special_float(value_token)
+            {
+                report(
+                    format!("float value '{value_token}' should be written as '{canonical}'"),
+                    Some(canonical),
+                );
             }
-        }
+        });
 
         diagnostics
     }
 }
 
+/// Returns the canonical spelling of a non-canonical NaN or infinity.
+///
+/// YAML 1.2 writes these as `.nan`, `.inf`, `+.inf` and `-.inf`. The core
+/// schema also accepts `.NaN`, `.NAN`, `.Inf` and `.INF`, while a bare `nan`
+/// or `inf` without the dot is a plain string rather than a float. Returns
+/// `None` for canonical forms and for anything that is not NaN or infinity.
+fn canonical_special_float(token: &str) -> Option<String> {
+    const CANONICAL: [&str; 4] = [".nan", ".inf", "+.inf", "-.inf"];
+
+    if CANONICAL.contains(&token) {
+        return None;
+    }
+
+    let (sign, unsigned) = token
+        .strip_prefix(['+', '-'])
+        .map_or(("", token), |rest| (&token[..1], rest));
+    let name = unsigned.strip_prefix('.').unwrap_or(unsigned);
+
+    if name.eq_ignore_ascii_case("inf") {
+        Some(format!("{sign}.inf"))
+    } else if name.eq_ignore_ascii_case("nan") && sign.is_empty() {
+        Some(".nan".to_string())
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -482,4 +457,122 @@ mod tests {
         let diagnostics = rule.check(&context, &value, &config);
         assert!(diagnostics.is_empty());
     }
+
+    fn fixes(yaml: &str) -> Vec<(String, String)> {
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+        let diagnostics =
+            FloatValuesRule.check(&LintContext::new(yaml), &value, &LintConfig::default());
+        diagnostics
+            .iter()
+            .map(|d| {
+                let suggestion = &d.suggestions[0];
+                (
+                    yaml[d.span.start.offset..d.span.end.offset].to_string(),
+                    suggestion.replacement.clone().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_canonical_special_floats_accepted() {
+        assert!(fixes("a: .nan\nb: .inf\nc: +.inf\nd: -.inf\n").is_empty());
+    }
+
+    #[test]
+    fn test_non_canonical_nan_variants() {
+        let yaml = "a: .NaN\nb: .NAN\nc: nan\nd: NaN\ne: NAN\n";
+        let expected: Vec<_> = [".NaN", ".NAN", "nan", "NaN", "NAN"]
+            .iter()
+            .map(|v| ((*v).to_string(), ".nan".to_string()))
+            .collect();
+        assert_eq!(fixes(yaml), expected);
+    }
+
+    #[test]
+    fn test_non_canonical_inf_variants() {
+        let yaml = "a: .Inf\nb: .INF\nc: inf\nd: Inf\ne: INF\n";
+        let expected: Vec<_> = [".Inf", ".INF", "inf", "Inf", "INF"]
+            .iter()
+            .map(|v| ((*v).to_string(), ".inf".to_string()))
+            .collect();
+        assert_eq!(fixes(yaml), expected);
+    }
+
+    #[test]
+    fn test_non_canonical_signed_inf_variants() {
+        let yaml = "- -.Inf\n- +.INF\n- -inf\n- +Inf\n";
+        assert_eq!(
+            fixes(yaml),
+            [
+                ("-.Inf".to_string(), "-.inf".to_string()),
+                ("+.INF".to_string(), "+.inf".to_string()),
+                ("-inf".to_string(), "-.inf".to_string()),
+                ("+Inf".to_string(), "+.inf".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_canonical_special_float_message_and_severity() {
+        let yaml = "ratio: .NaN\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+        let diagnostics =
+            FloatValuesRule.check(&LintContext::new(yaml), &value, &LintConfig::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "float value '.NaN' should be written as '.nan'"
+        );
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].span.start.column, 8);
+    }
+
+    #[test]
+    fn test_non_canonical_special_floats_ignored_when_quoted_or_keys() {
+        assert!(fixes("a: '.NaN'\nb: \"inf\"\n.Inf: 1\nnan: 2\n").is_empty());
+    }
+
+    #[test]
+    fn test_non_canonical_special_floats_in_flow_sequence() {
+        assert_eq!(
+            fixes("limits: [.Inf, 1.5, NaN]\n"),
+            [
+                (".Inf".to_string(), ".inf".to_string()),
+                ("NaN".to_string(), ".nan".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_canonical_special_floats_disabled() {
+        let yaml = "a: .NaN\nb: inf\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+        let config = LintConfig::new().with_rule_config(
+            "float-values",
+            RuleConfig::new().with_option("canonical-special-floats", false),
+        );
+        let diagnostics = FloatValuesRule.check(&LintContext::new(yaml), &value, &config);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_canonical_special_float() {
+        assert_eq!(canonical_special_float(".inf"), None);
+        assert_eq!(canonical_special_float("infinity"), None);
+        assert_eq!(canonical_special_float("-nan"), None);
+        assert_eq!(canonical_special_float("1.5"), None);
+        assert_eq!(canonical_special_float(".iNf").as_deref(), Some(".inf"));
+    }
+
+    #[test]
+    fn test_missing_numeral_suggestion() {
+        assert_eq!(
+            fixes("a: .5\nb: -.25\n"),
+            [
+                (".5".to_string(), "0.5".to_string()),
+                ("-.25".to_string(), "-0.25".to_string()),
+            ]
+        );
+    }
 }
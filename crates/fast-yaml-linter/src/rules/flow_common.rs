@@ -1,9 +1,91 @@
 //! Common utilities for flow collection rules (braces, brackets).
 
 use crate::{
-    LintConfig, Severity, SourceContext, Span,
+    LintConfig, Location, Severity, SourceContext, Span,
     diagnostic::{Diagnostic, DiagnosticBuilder},
 };
+use saphyr_parser::{BufferedInput, Event, Parser as SaphyrParser};
+
+/// Kind of flow collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FlowKind {
+    /// A flow sequence `[...]`.
+    Sequence,
+    /// A flow mapping `{...}`.
+    Mapping,
+}
+
+/// Start of a flow collection found in the parser event stream.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FlowCollection {
+    /// Sequence or mapping.
+    pub kind: FlowKind,
+    /// Span of the opening delimiter.
+    pub span: Span,
+    /// Whether the collection has no entries.
+    pub empty: bool,
+}
+
+/// Finds every flow collection in `source` using the parser event stream.
+///
+/// The parser reports no collection style, so a `SequenceStart` or
+/// `MappingStart` is taken to be flow style when the source character at its
+/// start mark is the opening delimiter. Brackets in comments, quoted strings
+/// and block scalars never produce such events. Parsing stops silently at the
+/// first syntax error.
+pub(crate) fn flow_collections(
+    source: &str,
+    source_ctx: &SourceContext<'_>,
+) -> Vec<FlowCollection> {
+    let mut collections: Vec<FlowCollection> = Vec::new();
+    // Index of a collection whose start was the previous event.
+    let mut just_opened: Option<usize> = None;
+    let mut parser = SaphyrParser::new(BufferedInput::new(source.chars()));
+
+    while let Some(Ok((event, marker))) = parser.next_event() {
+        let kind = match event {
+            Event::SequenceStart(..) => Some((FlowKind::Sequence, '[')),
+            Event::MappingStart(..) => Some((FlowKind::Mapping, '{')),
+            Event::SequenceEnd | Event::MappingEnd => {
+                if let Some(index) = just_opened.take() {
+                    collections[index].empty = true;
+                }
+                continue;
+            }
+            _ => None,
+        };
+        just_opened = None;
+
+        let Some((kind, delimiter)) = kind else {
+            continue;
+        };
+        // saphyr line() is 1-indexed; col() is a 0-indexed char column.
+        let line = marker.start.line();
+        let Some(text) = source_ctx.get_line(line) else {
+            continue;
+        };
+        let Some((byte_col, ch)) = text.char_indices().nth(marker.start.col()) else {
+            continue;
+        };
+        if ch != delimiter {
+            continue;
+        }
+
+        let offset = source_ctx.get_line_offset(line) + byte_col;
+        let column = byte_col + 1;
+        just_opened = Some(collections.len());
+        collections.push(FlowCollection {
+            kind,
+            span: Span::new(
+                Location::new(line, column, offset),
+                Location::new(line, column + 1, offset + 1),
+            ),
+            empty: false,
+        });
+    }
+
+    collections
+}
 
 /// Checks if a flow collection is empty (contains only whitespace between delimiters).
 ///
@@ -31,6 +113,50 @@ pub fn is_empty_collection(source: &str, start_offset: usize, end_offset: usize)
     source[start_offset..end_offset].trim().is_empty()
 }
 
+/// Reports flow collections of `kind` forbidden by a rule's `forbid` mode.
+///
+/// `forbid` is `"all"` or `"non-empty"`; any other value reports nothing.
+/// Each diagnostic suggests the block-style equivalent.
+pub(crate) fn check_forbidden(
+    source: &str,
+    source_ctx: &SourceContext<'_>,
+    kind: FlowKind,
+    forbid: &str,
+    code: &str,
+    severity: Severity,
+) -> Vec<Diagnostic> {
+    let non_empty_only = match forbid {
+        "all" => false,
+        "non-empty" => true,
+        _ => return Vec::new(),
+    };
+    let (name, block_form) = match kind {
+        FlowKind::Sequence => (
+            "flow sequence",
+            "a block sequence with one `- item` per line",
+        ),
+        FlowKind::Mapping => (
+            "flow mapping",
+            "a block mapping with one `key: value` per line",
+        ),
+    };
+    let message = if non_empty_only {
+        format!("non-empty {name} forbidden (forbid: non-empty)")
+    } else {
+        format!("{name} forbidden (forbid: all)")
+    };
+
+    flow_collections(source, source_ctx)
+        .into_iter()
+        .filter(|c| c.kind == kind && !(non_empty_only && c.empty))
+        .map(|c| {
+            DiagnosticBuilder::new(code, severity, message.clone(), c.span)
+                .with_suggestion(format!("use {block_form}"), c.span, None)
+                .build_with_context(source_ctx)
+        })
+        .collect()
+}
+
 /// Checks spacing after an opening delimiter (brace or bracket).
 ///
 /// # Arguments
@@ -185,6 +311,47 @@ pub fn check_spaces_before_closing(
 mod tests {
     use super::*;
 
+    fn flow_kinds(source: &str) -> Vec<(FlowKind, usize, usize, bool)> {
+        let source_ctx = SourceContext::new(source);
+        flow_collections(source, &source_ctx)
+            .into_iter()
+            .map(|c| (c.kind, c.span.start.line, c.span.start.column, c.empty))
+            .collect()
+    }
+
+    #[test]
+    fn test_flow_collections() {
+        assert_eq!(
+            flow_kinds("a: [1, {b: c}]\nd: {}\ne:\n  - f\n"),
+            [
+                (FlowKind::Sequence, 1, 4, false),
+                (FlowKind::Mapping, 1, 8, false),
+                (FlowKind::Mapping, 2, 4, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flow_collections_nested_empty() {
+        assert_eq!(
+            flow_kinds("a: [[]]\n"),
+            [
+                (FlowKind::Sequence, 1, 4, false),
+                (FlowKind::Sequence, 1, 5, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flow_collections_ignores_implicit_mapping_and_scalars() {
+        // `[a: b]` holds a single-pair mapping without braces.
+        assert_eq!(
+            flow_kinds("x: [a: b]\n"),
+            [(FlowKind::Sequence, 1, 4, false)]
+        );
+        assert!(flow_kinds("a: '[1]'\nb: |\n  {c}\n# [d]\n").is_empty());
+    }
+
     #[test]
     fn test_is_empty_collection() {
         assert!(is_empty_collection("{}", 1, 1));
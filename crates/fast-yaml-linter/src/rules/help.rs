@@ -0,0 +1,196 @@
+//! Structured documentation for lint rules.
+
+use std::fmt;
+
+use crate::Severity;
+
+/// Documentation for a single rule configuration option.
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_linter::rules::RuleOptionHelp;
+///
+/// let option = RuleOptionHelp::new("max", "integer", "80", "Maximum line length");
+/// assert_eq!(option.name, "max");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleOptionHelp {
+    /// Option name as written in the config file.
+    pub name: &'static str,
+    /// Value type (e.g. "integer", "boolean", "string", "list of strings").
+    pub value_type: &'static str,
+    /// Default value, rendered as YAML.
+    pub default: &'static str,
+    /// What the option controls.
+    pub description: &'static str,
+}
+
+impl RuleOptionHelp {
+    /// Creates option documentation.
+    #[must_use]
+    pub const fn new(
+        name: &'static str,
+        value_type: &'static str,
+        default: &'static str,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            value_type,
+            default,
+            description,
+        }
+    }
+}
+
+/// Detailed documentation for a lint rule.
+///
+/// Returned by [`LintRule::help`](super::LintRule::help) and rendered by
+/// `fy lint --rule-help <code>` through its `Display` implementation.
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_linter::rules::{RuleHelp, RuleOptionHelp};
+/// use fast_yaml_linter::Severity;
+///
+/// let help = RuleHelp::new("example", "Example", "Checks things", Severity::Warning)
+///     .with_option(RuleOptionHelp::new("max", "integer", "1", "Upper bound"))
+///     .with_examples("key:  value\n", "key: value\n");
+/// assert_eq!(help.options.len(), 1);
+/// assert!(help.to_string().contains("max (integer, default: 1)"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleHelp {
+    /// Rule code (e.g. "line-length").
+    pub code: String,
+    /// Human-readable name.
+    pub name: String,
+    /// What the rule checks.
+    pub description: String,
+    /// Severity used when the config does not override it.
+    pub default_severity: Severity,
+    /// Configuration options accepted by the rule.
+    pub options: Vec<RuleOptionHelp>,
+    /// YAML that violates the rule (with the default configuration, unless the
+    /// rule is opt-in and only checks once an option is set).
+    pub violation_example: Option<&'static str>,
+    /// YAML that satisfies the rule.
+    pub compliant_example: Option<&'static str>,
+    /// Description of the automatic fix, if the rule has one.
+    pub auto_fix: Option<&'static str>,
+}
+
+impl RuleHelp {
+    /// Creates help with only the basic rule metadata.
+    #[must_use]
+    pub fn new(code: &str, name: &str, description: &str, default_severity: Severity) -> Self {
+        Self {
+            code: code.to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            default_severity,
+            options: Vec::new(),
+            violation_example: None,
+            compliant_example: None,
+            auto_fix: None,
+        }
+    }
+
+    /// Adds a configuration option.
+    #[must_use]
+    pub fn with_option(mut self, option: RuleOptionHelp) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Sets the violating and compliant YAML examples.
+    #[must_use]
+    pub const fn with_examples(mut self, violation: &'static str, compliant: &'static str) -> Self {
+        self.violation_example = Some(violation);
+        self.compliant_example = Some(compliant);
+        self
+    }
+
+    /// Describes the automatic fix offered by the rule.
+    #[must_use]
+    pub const fn with_auto_fix(mut self, auto_fix: &'static str) -> Self {
+        self.auto_fix = Some(auto_fix);
+        self
+    }
+}
+
+/// Writes `text` with every line indented by four spaces.
+fn write_indented(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    for line in text.lines() {
+        writeln!(f, "    {line}")?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for RuleHelp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} ({})", self.code, self.name)?;
+        writeln!(f)?;
+        writeln!(f, "  {}", self.description)?;
+        writeln!(f)?;
+        writeln!(f, "Default severity: {}", self.default_severity)?;
+
+        writeln!(f)?;
+        if self.options.is_empty() {
+            writeln!(f, "Options: none")?;
+        } else {
+            writeln!(f, "Options:")?;
+            for option in &self.options {
+                writeln!(
+                    f,
+                    "  {} ({}, default: {})",
+                    option.name, option.value_type, option.default
+                )?;
+                writeln!(f, "      {}", option.description)?;
+            }
+        }
+
+        if let Some(example) = self.violation_example {
+            writeln!(f)?;
+            writeln!(f, "Violation:")?;
+            write_indented(f, example)?;
+        }
+
+        if let Some(example) = self.compliant_example {
+            writeln!(f)?;
+            writeln!(f, "Compliant:")?;
+            write_indented(f, example)?;
+        }
+
+        writeln!(f)?;
+        write!(f, "Auto-fix: {}", self.auto_fix.unwrap_or("not available"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_without_options() {
+        let help = RuleHelp::new("example", "Example", "Checks things", Severity::Error);
+        let text = help.to_string();
+        assert!(text.starts_with("example (Example)"));
+        assert!(text.contains("Default severity: error"));
+        assert!(text.contains("Options: none"));
+        assert!(text.ends_with("Auto-fix: not available"));
+    }
+
+    #[test]
+    fn test_display_with_examples() {
+        let help = RuleHelp::new("example", "Example", "Checks things", Severity::Warning)
+            .with_examples("a:  1\nb:  2\n", "a: 1\n")
+            .with_auto_fix("removes extra spaces");
+        let text = help.to_string();
+        assert!(text.contains("Violation:\n    a:  1\n    b:  2\n"));
+        assert!(text.contains("Compliant:\n    a: 1\n"));
+        assert!(text.ends_with("Auto-fix: removes extra spaces"));
+    }
+}
@@ -30,6 +30,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct HyphensRule;
 
 impl super::LintRule for HyphensRule {
@@ -49,6 +50,22 @@ impl super::LintRule for HyphensRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "max-spaces-after",
+            "integer",
+            "1",
+            "Maximum spaces after a list item hyphen",
+        ))
+        .with_examples("-  item\n", "- item\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let source_context = context.source_context();
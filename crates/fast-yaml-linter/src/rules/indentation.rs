@@ -7,6 +7,14 @@ use crate::{
 use fast_yaml_core::Value;
 
 /// Rule to check indentation consistency.
+///
+/// Space indentation must be a multiple of `indent_size`. Lines whose
+/// indentation mixes tabs and spaces are reported separately under the
+/// `mixed-indentation` code, with a suggestion to use spaces only.
+///
+/// Configuration options:
+/// - `forbid-mixed`: boolean (default: true)
+#[super::register_lint_rule]
 pub struct IndentationRule;
 
 impl super::LintRule for IndentationRule {
@@ -26,9 +34,35 @@ impl super::LintRule for IndentationRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "indent-size",
+            "integer",
+            "2",
+            "Number of spaces per indentation level",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "forbid-mixed",
+            "boolean",
+            "true",
+            "Report indentation that mixes tabs and spaces (code `mixed-indentation`)",
+        ))
+        .with_examples("key:\n   nested: value\n", "key:\n  nested: value\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let ctx = context.source_context();
         let indent_size = config.indent_size;
+        let forbid_mixed = config
+            .get_rule_config(self.code())
+            .and_then(|rc| rc.options.get_bool("forbid-mixed"))
+            .unwrap_or(true);
         let mut diagnostics = Vec::new();
 
         for line_num in 1..=ctx.line_count() {
@@ -64,19 +98,27 @@ impl super::LintRule for IndentationRule {
             };
 
             if has_mixed {
-                let indent_width = line.chars().take_while(char::is_ascii_whitespace).count();
-                let span = Span::new(
-                    Location::new(line_num, 1, line_offset),
-                    Location::new(line_num, indent_width + 1, line_offset + indent_width),
-                );
-                let diagnostic = DiagnosticBuilder::new(
-                    DiagnosticCode::INDENTATION,
-                    config.get_effective_severity(self.code(), self.default_severity()),
-                    "mixed tabs and spaces in indentation".to_string(),
-                    span,
-                )
-                .build_with_context(context.source_context());
-                diagnostics.push(diagnostic);
+                if forbid_mixed {
+                    let indent: &str =
+                        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+                    let span = Span::new(
+                        Location::new(line_num, 1, line_offset),
+                        Location::new(line_num, indent.len() + 1, line_offset + indent.len()),
+                    );
+                    let diagnostic = DiagnosticBuilder::new(
+                        DiagnosticCode::MIXED_INDENTATION,
+                        config.get_effective_severity(self.code(), self.default_severity()),
+                        "mixed tabs and spaces in indentation".to_string(),
+                        span,
+                    )
+                    .with_suggestion(
+                        "indent with spaces only",
+                        span,
+                        Some(expand_tabs(indent, indent_size)),
+                    )
+                    .build_with_context(context.source_context());
+                    diagnostics.push(diagnostic);
+                }
                 continue;
             }
 
@@ -108,6 +150,20 @@ impl super::LintRule for IndentationRule {
     }
 }
 
+/// Converts `indent` to spaces, advancing each tab to the next multiple of
+/// `tab_width`.
+fn expand_tabs(indent: &str, tab_width: usize) -> String {
+    let tab_width = tab_width.max(1);
+    let width = indent.chars().fold(0, |width, c| {
+        if c == '\t' {
+            width + tab_width - width % tab_width
+        } else {
+            width + 1
+        }
+    });
+    " ".repeat(width)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,4 +284,122 @@ mod tests {
         let diagnostics = rule.check(&ctx, &value, &config);
         assert_eq!(diagnostics.len(), 2);
     }
+
+    /// Runs the rule on `source`, which may contain tabs the parser rejects.
+    fn check_source(source: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let value = parse("key: value\n");
+        IndentationRule.check(&LintContext::new(source), &value, config)
+    }
+
+    fn forbid_mixed(enabled: bool) -> LintConfig {
+        LintConfig::new().with_rule_config(
+            "indentation",
+            RuleConfig::new().with_option("forbid-mixed", enabled),
+        )
+    }
+
+    #[test]
+    fn test_mixed_uses_separate_code() {
+        let diagnostics = check_source("a:\n\t  key: value\n", &LintConfig::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code.as_str(),
+            DiagnosticCode::MIXED_INDENTATION
+        );
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_mixed_tab_then_spaces_suggestion() {
+        let diagnostics = check_source("a:\n\t  key: value\n", &LintConfig::default());
+        let suggestion = &diagnostics[0].suggestions[0];
+        assert_eq!(suggestion.message, "indent with spaces only");
+        assert_eq!(suggestion.replacement.as_deref(), Some("    "));
+    }
+
+    #[test]
+    fn test_mixed_spaces_then_tab_suggestion() {
+        // One space, then a tab advancing to column 2, then a space
+        let diagnostics = check_source("a:\n \t key: value\n", &LintConfig::default());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].suggestions[0].replacement.as_deref(),
+            Some("   ")
+        );
+    }
+
+    #[test]
+    fn test_mixed_suggestion_uses_indent_size() {
+        let config = LintConfig::new().with_indent_size(4);
+        let diagnostics = check_source("a:\n\t\t key: value\n", &config);
+        assert_eq!(
+            diagnostics[0].suggestions[0].replacement.as_deref(),
+            Some(" ".repeat(9).as_str())
+        );
+    }
+
+    #[test]
+    fn test_mixed_span_covers_indentation() {
+        let diagnostics = check_source("a: 1\nb:\n\t key: value\n", &LintConfig::default());
+        let span = diagnostics[0].span;
+        assert_eq!(span.start.line, 3);
+        assert_eq!((span.start.column, span.end.column), (1, 3));
+        assert_eq!((span.start.offset, span.end.offset), (8, 10));
+        assert_eq!(diagnostics[0].suggestions[0].span, span);
+    }
+
+    #[test]
+    fn test_mixed_forbid_disabled() {
+        assert!(check_source("a:\n\t  key: value\n", &forbid_mixed(false)).is_empty());
+    }
+
+    #[test]
+    fn test_mixed_forbid_disabled_keeps_space_checks() {
+        let diagnostics = check_source("a:\n\t key: 1\nb:\n   c: 2\n", &forbid_mixed(false));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code.as_str(), DiagnosticCode::INDENTATION);
+        assert_eq!(diagnostics[0].span.start.line, 4);
+    }
+
+    #[test]
+    fn test_mixed_forbid_enabled_explicitly() {
+        assert_eq!(
+            check_source("a:\n  \tkey: value\n", &forbid_mixed(true)).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_mixed_tab_inside_value_ignored() {
+        assert!(check_source("a:\n  key: x\ty\n", &LintConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_mixed_reported_once_per_line() {
+        let diagnostics = check_source("a:\n\t key: 1\n \tother: 2\n", &LintConfig::default());
+        let lines: Vec<_> = diagnostics.iter().map(|d| d.span.start.line).collect();
+        assert_eq!(lines, [2, 3]);
+        assert!(
+            diagnostics
+                .iter()
+                .all(|d| d.code.as_str() == DiagnosticCode::MIXED_INDENTATION)
+        );
+    }
+
+    #[test]
+    fn test_mixed_severity_override() {
+        let config = LintConfig::new().with_rule_config(
+            "indentation",
+            RuleConfig::new().with_severity(Severity::Error),
+        );
+        let diagnostics = check_source("a:\n\t key: value\n", &config);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        assert_eq!(expand_tabs("\t ", 2), "   ");
+        assert_eq!(expand_tabs(" \t", 4), "    ");
+        assert_eq!(expand_tabs("\t\t", 0), "  ");
+    }
 }
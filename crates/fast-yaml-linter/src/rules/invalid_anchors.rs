@@ -20,6 +20,7 @@ use std::collections::HashMap;
 /// - Content inside block scalars (`|` / `>`) is skipped using indentation-based
 ///   termination detection.
 /// - Document boundaries (`---` at column 0) reset the anchor map.
+#[super::register_lint_rule]
 pub struct InvalidAnchorsRule;
 
 impl super::LintRule for InvalidAnchorsRule {
@@ -39,6 +40,16 @@ impl super::LintRule for InvalidAnchorsRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_examples("a: &ref 1\nb: &ref 2\n", "a: &ref 1\nb: *ref\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let severity = config.get_effective_severity(self.code(), self.default_severity());
         scan_duplicate_anchors(context.source(), context.source_context(), severity)
@@ -5,6 +5,7 @@ use crate::{
     Span,
 };
 use fast_yaml_core::Value;
+use std::cmp::Ordering;
 
 /// Linting rule for key ordering.
 ///
@@ -13,6 +14,11 @@ use fast_yaml_core::Value;
 ///
 /// Configuration options:
 /// - `case-sensitive`: boolean (default: true)
+/// - `natural-sort`: boolean (default: false) - compare runs of digits by
+///   numeric value, so `item2` sorts before `item10`
+///
+/// Each violation carries a suggestion listing the mapping's keys in the
+/// expected order.
 ///
 /// # Examples
 ///
@@ -29,6 +35,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(&context, &value, &config);
 /// assert!(!diagnostics.is_empty());  // Keys are not in alphabetical order
 /// ```
+#[super::register_lint_rule]
 pub struct KeyOrderingRule;
 
 impl super::LintRule for KeyOrderingRule {
@@ -52,6 +59,28 @@ impl super::LintRule for KeyOrderingRule {
         Severity::Info
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "case-sensitive",
+            "boolean",
+            "true",
+            "Compare keys case-sensitively",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "natural-sort",
+            "boolean",
+            "false",
+            "Compare runs of digits numerically (item2 before item10)",
+        ))
+        .with_examples("name: app\nage: 1\n", "age: 1\nname: app\n")
+    }
+
     fn check(&self, context: &LintContext, value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let rule_config = config.get_rule_config(DiagnosticCode::KEY_ORDERING);
@@ -59,6 +88,13 @@ impl super::LintRule for KeyOrderingRule {
         let case_sensitive = rule_config
             .and_then(|rc| rc.options.get_bool("case-sensitive"))
             .unwrap_or(true);
+        let natural_sort = rule_config
+            .and_then(|rc| rc.options.get_bool("natural-sort"))
+            .unwrap_or(false);
+        let order = KeyOrder {
+            case_sensitive,
+            natural_sort,
+        };
 
         let mut diagnostics = Vec::new();
         let mut cursor = context.doc_start_line();
@@ -67,7 +103,7 @@ impl super::LintRule for KeyOrderingRule {
             value,
             context,
             source,
-            case_sensitive,
+            order,
             config,
             &mut diagnostics,
             &mut cursor,
@@ -76,6 +112,64 @@ impl super::LintRule for KeyOrderingRule {
     }
 }
 
+/// Comparison settings for mapping keys.
+#[derive(Debug, Clone, Copy)]
+struct KeyOrder {
+    case_sensitive: bool,
+    natural_sort: bool,
+}
+
+impl KeyOrder {
+    /// Compares two keys according to the configured options.
+    fn compare(self, a: &str, b: &str) -> Ordering {
+        match (self.case_sensitive, self.natural_sort) {
+            (true, false) => a.cmp(b),
+            (false, false) => a.to_lowercase().cmp(&b.to_lowercase()),
+            (true, true) => natural_cmp(a, b),
+            (false, true) => natural_cmp(&a.to_lowercase(), &b.to_lowercase()),
+        }
+    }
+}
+
+/// Compares strings so that runs of ASCII digits are ordered by numeric value.
+///
+/// Digit runs with equal value but different leading zeros fall back to
+/// comparing their length, keeping the order total.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        match (a.chars().next(), b.chars().next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(ca), Some(cb)) if ca.is_ascii_digit() && cb.is_ascii_digit() => {
+                let a_len = a.find(|c: char| !c.is_ascii_digit()).unwrap_or(a.len());
+                let b_len = b.find(|c: char| !c.is_ascii_digit()).unwrap_or(b.len());
+                let (a_num, b_num) = (&a[..a_len], &b[..b_len]);
+                let a_trimmed = a_num.trim_start_matches('0');
+                let b_trimmed = b_num.trim_start_matches('0');
+                let ordering = a_trimmed
+                    .len()
+                    .cmp(&b_trimmed.len())
+                    .then_with(|| a_trimmed.cmp(b_trimmed))
+                    .then_with(|| a_num.len().cmp(&b_num.len()));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a = &a[a_len..];
+                b = &b[b_len..];
+            }
+            (Some(ca), Some(cb)) => {
+                if ca != cb {
+                    return ca.cmp(&cb);
+                }
+                a = &a[ca.len_utf8()..];
+                b = &b[cb.len_utf8()..];
+            }
+        }
+    }
+}
+
 /// Recursively walks `value` and emits ordering diagnostics.
 ///
 /// `cursor` is a 1-based source line index that advances after each key is
@@ -92,7 +186,7 @@ fn check_value(
     value: &Value,
     context: &LintContext<'_>,
     source: &str,
-    case_sensitive: bool,
+    order: KeyOrder,
     config: &LintConfig,
     diagnostics: &mut Vec<Diagnostic>,
     cursor: &mut usize,
@@ -115,33 +209,18 @@ fn check_value(
                     nested_value,
                     context,
                     source,
-                    case_sensitive,
+                    order,
                     config,
                     diagnostics,
                     cursor,
                 );
             }
 
-            emit_ordering_diagnostics(
-                &key_positions,
-                context,
-                source,
-                case_sensitive,
-                config,
-                diagnostics,
-            );
+            emit_ordering_diagnostics(&key_positions, context, source, order, config, diagnostics);
         }
         Value::Sequence(arr) => {
             for item in arr {
-                check_value(
-                    item,
-                    context,
-                    source,
-                    case_sensitive,
-                    config,
-                    diagnostics,
-                    cursor,
-                );
+                check_value(item, context, source, order, config, diagnostics, cursor);
             }
         }
         _ => {}
@@ -191,54 +270,50 @@ fn locate_key(key: &str, context: &LintContext<'_>, cursor: &mut usize) -> Optio
 }
 
 /// Compares consecutive key pairs and pushes a diagnostic for each violation.
+///
+/// Every diagnostic suggests the full expected key order of the mapping.
 fn emit_ordering_diagnostics(
     key_positions: &[(String, usize)],
     context: &LintContext<'_>,
     _source: &str,
-    case_sensitive: bool,
+    order: KeyOrder,
     config: &LintConfig,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
-    let mut prev_key: Option<&str> = None;
-    let mut prev_line: Option<usize> = None;
-
-    for (key, line_num) in key_positions {
-        if let Some(prev) = prev_key {
-            let out_of_order = if case_sensitive {
-                key.as_str() < prev
-            } else {
-                key.to_lowercase() < prev.to_lowercase()
-            };
-
-            if out_of_order {
-                let severity =
-                    config.get_effective_severity(DiagnosticCode::KEY_ORDERING, Severity::Info);
-                let line_offset = context.source_context().get_line_offset(*line_num);
-                let location = Location::new(*line_num, 1, line_offset);
-                let span = Span::new(
-                    location,
-                    Location::new(*line_num, 1, line_offset + key.len()),
-                );
+    let mut expected: Option<String> = None;
 
-                diagnostics.push(
-                    DiagnosticBuilder::new(
-                        DiagnosticCode::KEY_ORDERING,
-                        severity,
-                        format!(
-                            "key '{}' should be ordered before '{}' (line {})",
-                            key,
-                            prev,
-                            prev_line.unwrap_or(0)
-                        ),
-                        span,
-                    )
-                    .build_with_context(context.source_context()),
-                );
-            }
+    for pair in key_positions.windows(2) {
+        let [(prev, prev_line), (key, line_num)] = pair else {
+            continue;
+        };
+        if order.compare(key, prev) != Ordering::Less {
+            continue;
         }
 
-        prev_key = Some(key);
-        prev_line = Some(*line_num);
+        let expected = expected.get_or_insert_with(|| {
+            let mut keys: Vec<&str> = key_positions.iter().map(|(k, _)| k.as_str()).collect();
+            keys.sort_by(|a, b| order.compare(a, b));
+            keys.join(", ")
+        });
+
+        let severity = config.get_effective_severity(DiagnosticCode::KEY_ORDERING, Severity::Info);
+        let line_offset = context.source_context().get_line_offset(*line_num);
+        let location = Location::new(*line_num, 1, line_offset);
+        let span = Span::new(
+            location,
+            Location::new(*line_num, 1, line_offset + key.len()),
+        );
+
+        diagnostics.push(
+            DiagnosticBuilder::new(
+                DiagnosticCode::KEY_ORDERING,
+                severity,
+                format!("key '{key}' should be ordered before '{prev}' (line {prev_line})"),
+                span,
+            )
+            .with_suggestion(format!("order keys as: {expected}"), span, None)
+            .build_with_context(context.source_context()),
+        );
     }
 }
 
@@ -305,6 +380,90 @@ mod tests {
         assert!(diagnostics.is_empty());
     }
 
+    #[test]
+    fn test_key_ordering_case_insensitive_sorted() {
+        let yaml = "age: 30\nName: John";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = KeyOrderingRule;
+        let config = LintConfig::new().with_rule_config(
+            "key-ordering",
+            RuleConfig::new().with_option("case-sensitive", false),
+        );
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_key_ordering_natural_sort() {
+        let yaml = "item1: a\nitem2: b\nitem10: c";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = KeyOrderingRule;
+        let context = LintContext::new(yaml);
+
+        // Lexicographically "item10" < "item2"
+        let diagnostics = rule.check(&context, &value, &LintConfig::default());
+        assert_eq!(diagnostics.len(), 1);
+
+        let config = LintConfig::new().with_rule_config(
+            "key-ordering",
+            RuleConfig::new().with_option("natural-sort", true),
+        );
+        let diagnostics = rule.check(&context, &value, &config);
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_key_ordering_natural_sort_unsorted() {
+        let yaml = "item10: a\nitem2: b";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = KeyOrderingRule;
+        let config = LintConfig::new().with_rule_config(
+            "key-ordering",
+            RuleConfig::new().with_option("natural-sort", true),
+        );
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].suggestions[0].message,
+            "order keys as: item2, item10"
+        );
+    }
+
+    #[test]
+    fn test_key_ordering_suggests_expected_order() {
+        let yaml = "name: John\nage: 30\ncity: Paris";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = KeyOrderingRule;
+        let config = LintConfig::default();
+
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].suggestions.len(), 1);
+        assert_eq!(
+            diagnostics[0].suggestions[0].message,
+            "order keys as: age, city, name"
+        );
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp("item2", "item10"), Ordering::Less);
+        assert_eq!(natural_cmp("item10", "item10"), Ordering::Equal);
+        assert_eq!(natural_cmp("a10b2", "a10b10"), Ordering::Less);
+        assert_eq!(natural_cmp("item02", "item2"), Ordering::Greater);
+        assert_eq!(natural_cmp("item", "item1"), Ordering::Less);
+        assert_eq!(natural_cmp("b1", "a2"), Ordering::Greater);
+    }
+
     #[test]
     fn test_key_ordering_nested() {
         let yaml = "person:\n  name: John\n  age: 30";
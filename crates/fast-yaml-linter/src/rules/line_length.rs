@@ -7,6 +7,11 @@ use crate::{
 use fast_yaml_core::Value;
 
 /// Rule to check line length limits.
+///
+/// Length is counted in characters. Configuration options:
+/// - `tab-width`: integer (default: 1) - a tab advances to the next multiple
+///   of this width, so two leading tabs count as 8 with a width of 4
+#[super::register_lint_rule]
 pub struct LineLengthRule;
 
 impl super::LintRule for LineLengthRule {
@@ -26,18 +31,31 @@ impl super::LintRule for LineLengthRule {
         Severity::Info
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(self.code(), self.name(), self.description(), self.default_severity())
+            .with_option(super::RuleOptionHelp::new("max", "integer", "80", "Maximum line length in characters"))
+            .with_option(super::RuleOptionHelp::new("tab-width", "integer", "1", "Column width of a tab stop"))
+            .with_examples("description: this line is deliberately written to be longer than the default eighty characters\n", "description: short enough\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let Some(max_length) = config.max_line_length else {
             return Vec::new();
         };
 
+        let tab_width = config
+            .get_rule_config(self.code())
+            .and_then(|rc| rc.options.get_usize("tab-width"))
+            .unwrap_or(1)
+            .max(1);
+
         let mut diagnostics = Vec::new();
         let ctx = context.source_context();
 
         for line_num in 1..=ctx.line_count() {
             if let Some(line_content) = ctx.get_line(line_num) {
-                let line_len = line_content.chars().count();
+                let line_len = display_width(line_content, tab_width);
                 if line_len > max_length {
                     let line_start = ctx.offset_to_location(
                         ctx.get_snippet(Span::new(
@@ -76,6 +94,18 @@ impl super::LintRule for LineLengthRule {
     }
 }
 
+/// Returns the width of `line` in columns, expanding each tab to the next
+/// multiple of `tab_width`.
+fn display_width(line: &str, tab_width: usize) -> usize {
+    line.chars().fold(0, |width, c| {
+        if c == '\t' {
+            width + tab_width - width % tab_width
+        } else {
+            width + 1
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,4 +259,52 @@ mod tests {
         assert_eq!(diagnostics.len(), 1);
         assert_eq!(diagnostics[0].span.start.line, 2); // Second line
     }
+
+    #[test]
+    fn test_display_width_tabs() {
+        assert_eq!(display_width("\t\tkey", 4), 11);
+        assert_eq!(display_width("ab\tc", 4), 5);
+        assert_eq!(display_width("abcd\tc", 4), 9);
+        assert_eq!(display_width("\t\tkey", 1), 5);
+    }
+
+    #[test]
+    fn test_tab_width_expands_indentation() {
+        // Two tabs at tab-width 4 count as 8 columns, plus 6 for "# note"
+        let yaml = "key: value\n\t\t# note\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = LineLengthRule;
+        let lint_context = LintContext::new(yaml);
+
+        let config = LintConfig::new()
+            .with_max_line_length(Some(13))
+            .with_rule_config(
+                "line-length",
+                RuleConfig::new().with_option("tab-width", 4_i64),
+            );
+        let diagnostics = rule.check(&lint_context, &value, &config);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("current: 14"));
+
+        let config = LintConfig::new()
+            .with_max_line_length(Some(14))
+            .with_rule_config(
+                "line-length",
+                RuleConfig::new().with_option("tab-width", 4_i64),
+            );
+        assert!(rule.check(&lint_context, &value, &config).is_empty());
+    }
+
+    #[test]
+    fn test_tab_width_default_counts_tab_once() {
+        let yaml = "key: value\n\t\t# note\n";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = LineLengthRule;
+        // "\t\t# note" is 8 characters without expansion
+        let config = LintConfig::new().with_max_line_length(Some(10));
+        let lint_context = LintContext::new(yaml);
+        assert!(rule.check(&lint_context, &value, &config).is_empty());
+    }
 }
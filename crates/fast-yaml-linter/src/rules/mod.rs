@@ -1,8 +1,12 @@
 //! Lint rules and rule registry.
 
+use std::sync::LazyLock;
+
 use crate::{Diagnostic, LintConfig, LintContext, Severity};
 use fast_yaml_core::Value;
 
+pub use fast_yaml_macros::register_lint_rule;
+
 mod braces;
 mod brackets;
 mod colons;
@@ -16,6 +20,7 @@ mod empty_lines;
 mod empty_values;
 mod float_values;
 pub mod flow_common;
+mod help;
 mod hyphens;
 mod indentation;
 mod invalid_anchors;
@@ -25,6 +30,7 @@ mod new_line_at_end_of_file;
 mod new_lines;
 mod octal_values;
 mod quoted_strings;
+mod scalar_events;
 mod trailing_whitespace;
 mod truthy;
 
@@ -40,6 +46,7 @@ pub use duplicate_keys::DuplicateKeysRule;
 pub use empty_lines::EmptyLinesRule;
 pub use empty_values::EmptyValuesRule;
 pub use float_values::FloatValuesRule;
+pub use help::{RuleHelp, RuleOptionHelp};
 pub use hyphens::HyphensRule;
 pub use indentation::IndentationRule;
 pub use invalid_anchors::InvalidAnchorsRule;
@@ -114,6 +121,19 @@ pub trait LintRule: Send + Sync {
         false
     }
 
+    /// Detailed documentation: options, examples, and available fixes.
+    ///
+    /// The default implementation only includes the code, name, description,
+    /// and default severity.
+    fn help(&self) -> RuleHelp {
+        RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+    }
+
     /// Checks the source and returns diagnostics.
     ///
     /// # Parameters
@@ -160,31 +180,10 @@ impl RuleRegistry {
         Self { rules: Vec::new() }
     }
 
-    /// Registers all default rules.
+    /// Creates a registry with every rule registered via [`register_lint_rule`].
     ///
-    /// Includes:
-    /// - Duplicate Keys (ERROR)
-    /// - Line Too Long (INFO)
-    /// - Trailing Whitespace (HINT)
-    /// - Document Start (WARNING)
-    /// - Document End (WARNING)
-    /// - Empty Values (WARNING)
-    /// - New Line at End of File (INFO)
-    /// - Braces (WARNING)
-    /// - Brackets (WARNING)
-    /// - Colons (WARNING)
-    /// - Commas (WARNING)
-    /// - Hyphens (WARNING)
-    /// - Comments (INFO)
-    /// - Comments Indentation (INFO)
-    /// - Empty Lines (INFO)
-    /// - New Lines (WARNING)
-    /// - Octal Values (WARNING)
-    /// - Truthy (WARNING)
-    /// - Quoted Strings (WARNING)
-    /// - Key Ordering (INFO)
-    /// - Float Values (WARNING)
-    /// - Indentation (WARNING)
+    /// Rules are ordered by code. This includes all built-in rules as well as
+    /// rules registered by other crates linked into the binary.
     ///
     /// # Examples
     ///
@@ -196,42 +195,9 @@ impl RuleRegistry {
     /// ```
     #[must_use]
     pub fn with_default_rules() -> Self {
-        let mut registry = Self::new();
-
-        // Phase 1 rules (7)
-        registry.add(Box::new(DuplicateKeysRule));
-        registry.add(Box::new(LineLengthRule));
-        registry.add(Box::new(TrailingWhitespaceRule));
-        registry.add(Box::new(DocumentStartRule));
-        registry.add(Box::new(DocumentEndRule));
-        registry.add(Box::new(EmptyValuesRule));
-        registry.add(Box::new(NewLineAtEndOfFileRule));
-
-        // Phase 2 rules (5)
-        registry.add(Box::new(BracesRule));
-        registry.add(Box::new(BracketsRule));
-        registry.add(Box::new(ColonsRule));
-        registry.add(Box::new(CommasRule));
-        registry.add(Box::new(HyphensRule));
-
-        // Phase 3 rules (5)
-        registry.add(Box::new(CommentsRule));
-        registry.add(Box::new(CommentsIndentationRule));
-        registry.add(Box::new(EmptyLinesRule));
-        registry.add(Box::new(NewLinesRule));
-        registry.add(Box::new(OctalValuesRule));
-
-        // Phase 4 rules (4)
-        registry.add(Box::new(TruthyRule));
-        registry.add(Box::new(QuotedStringsRule));
-        registry.add(Box::new(KeyOrderingRule));
-        registry.add(Box::new(FloatValuesRule));
-
-        registry.add(Box::new(InvalidAnchorsRule));
-
-        registry.add(Box::new(IndentationRule));
-
-        registry
+        Self {
+            rules: construct_registered_rules(),
+        }
     }
 
     /// Adds a rule to the registry.
@@ -289,6 +255,51 @@ impl Default for RuleRegistry {
     }
 }
 
+/// Constructor for a lint rule, collected at link time.
+///
+/// Created by [`register_lint_rule`]; there is no need to build one by hand.
+pub struct RuleRegistration {
+    constructor: fn() -> Box<dyn LintRule>,
+}
+
+impl RuleRegistration {
+    /// Wraps a rule constructor for registration.
+    #[must_use]
+    pub const fn new(constructor: fn() -> Box<dyn LintRule>) -> Self {
+        Self { constructor }
+    }
+}
+
+inventory::collect!(RuleRegistration);
+
+static DEFAULT_RULES: LazyLock<Vec<Box<dyn LintRule>>> = LazyLock::new(construct_registered_rules);
+
+/// Returns a shared instance of every registered rule, ordered by code.
+///
+/// Use this for read-only lookups (listing rules, rendering help) to avoid
+/// constructing a fresh [`RuleRegistry`].
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_linter::rules::default_rules;
+///
+/// assert!(default_rules().iter().any(|rule| rule.code() == "line-length"));
+/// ```
+#[must_use]
+pub fn default_rules() -> &'static [Box<dyn LintRule>] {
+    &DEFAULT_RULES
+}
+
+fn construct_registered_rules() -> Vec<Box<dyn LintRule>> {
+    let mut rules: Vec<Box<dyn LintRule>> = inventory::iter::<RuleRegistration>
+        .into_iter()
+        .map(|registration| (registration.constructor)())
+        .collect();
+    rules.sort_by(|a, b| a.code().cmp(b.code()));
+    rules
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,6 +316,48 @@ mod tests {
         assert_eq!(registry.rules().len(), 23);
     }
 
+    #[test]
+    fn test_registry_contains_all_rule_codes() {
+        let registry = RuleRegistry::with_default_rules();
+        let codes: Vec<&str> = registry.rules().iter().map(|rule| rule.code()).collect();
+        assert_eq!(
+            codes,
+            [
+                "braces",
+                "brackets",
+                "colons",
+                "commas",
+                "comments",
+                "comments-indentation",
+                "document-end",
+                "document-start",
+                "duplicate-key",
+                "empty-lines",
+                "empty-values",
+                "float-values",
+                "hyphens",
+                "indentation",
+                "invalid-anchor",
+                "key-ordering",
+                "line-length",
+                "new-line-at-end-of-file",
+                "new-lines",
+                "octal-values",
+                "quoted-strings",
+                "trailing-whitespace",
+                "truthy",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_default_rules_match_registry() {
+        let registry = RuleRegistry::with_default_rules();
+        let shared: Vec<&str> = default_rules().iter().map(|rule| rule.code()).collect();
+        let owned: Vec<&str> = registry.rules().iter().map(|rule| rule.code()).collect();
+        assert_eq!(shared, owned);
+    }
+
     #[test]
     fn test_registry_add() {
         let mut registry = RuleRegistry::new();
@@ -326,6 +379,60 @@ mod tests {
         assert!(registry.get("nonexistent").is_none());
     }
 
+    #[test]
+    fn test_every_rule_has_examples() {
+        let registry = RuleRegistry::with_default_rules();
+        for rule in registry.rules() {
+            let help = rule.help();
+            assert_eq!(help.code, rule.code());
+            assert!(
+                help.violation_example.is_some() && help.compliant_example.is_some(),
+                "rule '{}' is missing help examples",
+                rule.code()
+            );
+        }
+    }
+
+    #[test]
+    fn test_help_examples_match_rule_behavior() {
+        let registry = RuleRegistry::with_default_rules();
+        let config = LintConfig::default();
+        let check = |rule: &dyn LintRule, yaml: &str| {
+            let value = fast_yaml_core::Parser::parse_str(yaml)
+                .unwrap()
+                .unwrap_or(Value::Value(fast_yaml_core::ScalarOwned::Null));
+            rule.check(&LintContext::new(yaml), &value, &config)
+        };
+
+        for rule in registry.rules() {
+            let help = rule.help();
+            let compliant = help.compliant_example.unwrap();
+            assert!(
+                check(rule.as_ref(), compliant).is_empty(),
+                "compliant example of '{}' is reported",
+                rule.code()
+            );
+            // document-start and document-end only check once `present` is set
+            if !matches!(rule.code(), "document-start" | "document-end") {
+                let violation = help.violation_example.unwrap();
+                assert!(
+                    !check(rule.as_ref(), violation).is_empty(),
+                    "violation example of '{}' is not reported",
+                    rule.code()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_line_length_help_documents_max() {
+        let registry = RuleRegistry::with_default_rules();
+        let help = registry.get("line-length").unwrap().help();
+        let max = help.options.iter().find(|o| o.name == "max").unwrap();
+        assert_eq!(max.default, "80");
+        assert!(help.to_string().contains("max (integer, default: 80)"));
+    }
+
     #[test]
     fn test_registry_default() {
         let registry = RuleRegistry::default();
@@ -11,19 +11,25 @@ use fast_yaml_core::Value;
 /// Requires that files end with a newline character.
 ///
 /// This is a common convention in Unix-like systems and many coding standards.
+/// The diagnostic points at the last character of the file, and its
+/// suggested fix appends `\r\n` if the file uses CRLF line endings and `\n`
+/// otherwise.
 ///
 /// # Examples
 ///
 /// ```
-/// use fast_yaml_linter::{rules::NewLineAtEndOfFileRule, rules::LintRule, LintConfig};
+/// use fast_yaml_linter::{rules::NewLineAtEndOfFileRule, rules::LintRule, LintConfig, LintContext};
+/// use fast_yaml_core::Parser;
 ///
 /// let rule = NewLineAtEndOfFileRule;
 /// let yaml = "name: John\n";  // Ends with newline - OK
 /// let value = Parser::parse_str(yaml).unwrap().unwrap();
 ///
-/// let diagnostics = rule.check(yaml, &value, &LintConfig::new());
+/// let context = LintContext::new(yaml);
+/// let diagnostics = rule.check(&context, &value, &LintConfig::new());
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct NewLineAtEndOfFileRule;
 
 impl super::LintRule for NewLineAtEndOfFileRule {
@@ -40,43 +46,60 @@ impl super::LintRule for NewLineAtEndOfFileRule {
     }
 
     fn default_severity(&self) -> Severity {
-        Severity::Info
+        Severity::Warning
+    }
+
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_examples("key: value", "key: value\n")
     }
 
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
-        if source.is_empty() {
+        let Some(last_char) = source.chars().next_back() else {
+            return Vec::new();
+        };
+        if last_char == '\n' {
             return Vec::new();
         }
 
-        if source.ends_with('\n') {
-            Vec::new()
+        let severity = config.get_effective_severity(self.code(), self.default_severity());
+        let last_line = source.lines().count().max(1);
+        let last_line_text = source.rsplit('\n').next().unwrap_or(source);
+        let column = last_line_text.chars().count();
+        let end_offset = source.len();
+        let start_offset = end_offset - last_char.len_utf8();
+        let newline = if source.contains("\r\n") {
+            "\r\n"
         } else {
-            let severity = config.get_effective_severity(self.code(), Severity::Info);
-            let last_line = source.lines().count().max(1);
-            let last_offset = source.len();
-
-            vec![
-                DiagnosticBuilder::new(
-                    self.code(),
-                    severity,
-                    "no newline at end of file",
-                    Span::new(
-                        Location::new(last_line, 1, last_offset),
-                        Location::new(last_line, 1, last_offset),
-                    ),
-                )
-                .with_suggestion(
-                    "Add newline",
-                    Span::new(
-                        Location::new(last_line, 1, last_offset),
-                        Location::new(last_line, 1, last_offset),
-                    ),
-                    Some("\n".to_string()),
-                )
-                .build_with_context(context.source_context()),
-            ]
-        }
+            "\n"
+        };
+
+        vec![
+            DiagnosticBuilder::new(
+                self.code(),
+                severity,
+                "no newline at end of file",
+                Span::new(
+                    Location::new(last_line, column, start_offset),
+                    Location::new(last_line, column + 1, end_offset),
+                ),
+            )
+            .with_suggestion(
+                "Add newline",
+                Span::new(
+                    Location::new(last_line, column + 1, end_offset),
+                    Location::new(last_line, column + 1, end_offset),
+                ),
+                Some(newline.to_string()),
+            )
+            .build_with_context(context.source_context()),
+        ]
     }
 }
 
@@ -166,4 +189,55 @@ mod tests {
         assert_eq!(diagnostics.len(), 1);
         assert_eq!(diagnostics[0].severity, Severity::Error);
     }
+
+    #[test]
+    fn test_newline_missing_span_and_suggestion() {
+        let yaml = "a: 1\nname: John";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = NewLineAtEndOfFileRule;
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &LintConfig::new());
+
+        assert_eq!(diagnostics.len(), 1);
+        let diag = &diagnostics[0];
+        assert_eq!(diag.severity, Severity::Warning);
+        assert_eq!(diag.span.start.line, 2);
+        assert_eq!(diag.span.start.column, 10);
+        assert_eq!(diag.span.start.offset, yaml.len() - 1);
+        assert_eq!(diag.span.end.offset, yaml.len());
+        assert_eq!(diag.suggestions[0].span.start.offset, yaml.len());
+        assert_eq!(diag.suggestions[0].replacement.as_deref(), Some("\n"));
+    }
+
+    #[test]
+    fn test_windows_newline_missing() {
+        let yaml = "a: 1\r\nname: John";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = NewLineAtEndOfFileRule;
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &LintConfig::new());
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.start.line, 2);
+        assert_eq!(
+            diagnostics[0].suggestions[0].replacement.as_deref(),
+            Some("\r\n")
+        );
+    }
+
+    #[test]
+    fn test_newline_missing_multibyte_last_char() {
+        let yaml = "name: Jos\u{e9}";
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+
+        let rule = NewLineAtEndOfFileRule;
+        let context = LintContext::new(yaml);
+        let diagnostics = rule.check(&context, &value, &LintConfig::new());
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.start.column, 10);
+        assert_eq!(diagnostics[0].span.start.offset, yaml.len() - 2);
+    }
 }
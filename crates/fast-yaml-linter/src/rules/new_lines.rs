@@ -30,6 +30,7 @@ use fast_yaml_core::Value;
 /// let diagnostics = rule.check(yaml, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct NewLinesRule;
 
 impl super::LintRule for NewLinesRule {
@@ -49,6 +50,22 @@ impl super::LintRule for NewLinesRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "type",
+            "string",
+            "unix",
+            "Required line ending: `unix` (LF), `dos` (CRLF), or `platform`",
+        ))
+        .with_examples("key: value\r\n", "key: value\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let rule_config = config.get_rule_config(self.code());
@@ -28,9 +28,14 @@ fn strip_inline_comment(line: &str) -> &str {
 /// Linting rule for octal values.
 ///
 /// Forbids unquoted octal numbers to prevent ambiguity:
-/// - Implicit octal: `010` (YAML 1.1 style, leading zero)
+/// - Implicit octal: `010` (YAML 1.1 style, leading zero). YAML 1.1 reads
+///   `0755` as 493 while YAML 1.2 reads it as the decimal 755.
 /// - Explicit octal: `0o10` (YAML 1.2 style, 0o prefix)
 ///
+/// Implicit octals come with a suggested fix: the `0o` form when explicit
+/// octals are allowed, a quoted string otherwise. The raw source is scanned,
+/// since the parser has already turned `0755` into an integer.
+///
 /// Configuration options:
 /// - `forbid-implicit-octal`: bool (default: true)
 /// - `forbid-explicit-octal`: bool (default: true)
@@ -38,7 +43,7 @@ fn strip_inline_comment(line: &str) -> &str {
 /// # Examples
 ///
 /// ```
-/// use fast_yaml_linter::{rules::OctalValuesRule, rules::LintRule, LintConfig, config::RuleConfig};
+/// use fast_yaml_linter::{rules::OctalValuesRule, rules::LintRule, LintConfig, LintContext};
 /// use fast_yaml_core::Parser;
 ///
 /// let rule = OctalValuesRule;
@@ -46,9 +51,10 @@ fn strip_inline_comment(line: &str) -> &str {
 /// let value = Parser::parse_str(yaml).unwrap().unwrap();
 ///
 /// let config = LintConfig::default();
-/// let diagnostics = rule.check(yaml, &value, &config);
+/// let diagnostics = rule.check(&LintContext::new(yaml), &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct OctalValuesRule;
 
 impl super::LintRule for OctalValuesRule {
@@ -68,6 +74,28 @@ impl super::LintRule for OctalValuesRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "forbid-implicit-octal",
+            "boolean",
+            "true",
+            "Report unquoted values like `010`",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "forbid-explicit-octal",
+            "boolean",
+            "true",
+            "Report unquoted values like `0o10`",
+        ))
+        .with_examples("mode: 0755\n", "mode: '0755'\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let rule_config = config.get_rule_config(self.code());
@@ -196,6 +224,7 @@ impl OctalValuesRule {
             {
                 let rest = &value_token[1..];
                 if !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit() && c < '8') {
+                    let (hint, replacement) = implicit_octal_fix(value_token, forbid_explicit);
                     let value_offset = line_offset + trim_offset_in_line;
                     let col = trim_offset_in_line + 1;
                     let severity =
@@ -217,6 +246,7 @@ impl OctalValuesRule {
                             ),
                             span,
                         )
+                        .with_suggestion(hint, span, Some(replacement))
                         .build_with_context(context.source_context()),
                     );
                 }
@@ -225,6 +255,19 @@ impl OctalValuesRule {
     }
 }
 
+/// Returns the suggestion message and replacement for an implicit octal.
+///
+/// Suggests the `0o` form unless explicit octals are forbidden too, in which
+/// case the value is quoted instead.
+fn implicit_octal_fix(token: &str, forbid_explicit: bool) -> (String, String) {
+    if forbid_explicit {
+        return (format!("quote it as '{token}'"), format!("'{token}'"));
+    }
+    let digits = token.trim_start_matches('0');
+    let digits = if digits.is_empty() { "0" } else { digits };
+    (format!("write it as 0o{digits}"), format!("0o{digits}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,4 +528,50 @@ mod tests {
             span.start.offset
         );
     }
+
+    fn allow_explicit() -> LintConfig {
+        LintConfig::new().with_rule_config(
+            "octal-values",
+            RuleConfig::new().with_option("forbid-explicit-octal", false),
+        )
+    }
+
+    fn check(yaml: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+        OctalValuesRule.check(&LintContext::new(yaml), &value, config)
+    }
+
+    #[test]
+    fn test_octal_values_suggests_explicit_form() {
+        let diagnostics = check("mode: 0755\n", &allow_explicit());
+        assert_eq!(diagnostics.len(), 1);
+        let suggestion = &diagnostics[0].suggestions[0];
+        assert_eq!(suggestion.message, "write it as 0o755");
+        assert_eq!(suggestion.replacement.as_deref(), Some("0o755"));
+        assert_eq!(suggestion.span, diagnostics[0].span);
+    }
+
+    #[test]
+    fn test_octal_values_suggests_explicit_form_0644() {
+        let diagnostics = check("- 0644\n- 00\n", &allow_explicit());
+        let replacements: Vec<_> = diagnostics
+            .iter()
+            .map(|d| d.suggestions[0].replacement.as_deref().unwrap())
+            .collect();
+        assert_eq!(replacements, ["0o644", "0o0"]);
+    }
+
+    #[test]
+    fn test_octal_values_explicit_form_accepted() {
+        assert!(check("mode: 0o755\n", &allow_explicit()).is_empty());
+    }
+
+    #[test]
+    fn test_octal_values_suggests_quotes_when_explicit_forbidden() {
+        let diagnostics = check("mode: 0644\n", &LintConfig::default());
+        assert_eq!(
+            diagnostics[0].suggestions[0].replacement.as_deref(),
+            Some("'0644'")
+        );
+    }
 }
@@ -19,6 +19,12 @@ use super::LintRule;
 /// - `required`: "always", "only-when-needed", "never" (default: "only-when-needed")
 /// - `extra-required`: list of patterns that always need quotes (default: [])
 /// - `extra-allowed`: list of patterns where quotes are optional (default: [])
+/// - `forbid-unnecessary-quotes`: bool - report quoted strings that do not need
+///   quotes (default: true when `required` is "only-when-needed", false otherwise)
+/// - `required-for-special-values`: bool (default: true) - strings that would
+///   resolve to a boolean, null or number when unquoted, such as `'true'`,
+///   `'null'`, `'1.0'` or `'0o17'`, must keep their quotes. They are never
+///   reported as unnecessarily quoted and are exempt from `required: never`
 ///
 /// # Examples
 ///
@@ -35,8 +41,19 @@ use super::LintRule;
 /// let diagnostics = rule.check(&context, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct QuotedStringsRule;
 
+/// Resolved configuration for a single run of the rule.
+struct QuoteOptions<'a> {
+    quote_type: &'a str,
+    required: &'a str,
+    extra_required: Vec<String>,
+    extra_allowed: Vec<String>,
+    forbid_unnecessary: bool,
+    special_values: bool,
+}
+
 /// Tracks whether the next scalar in a mapping scope is a key or a value.
 enum ScopeKind {
     /// Inside a mapping; `expecting_key` alternates after each key/value.
@@ -62,6 +79,52 @@ impl super::LintRule for QuotedStringsRule {
         Severity::Warning
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "quote-type",
+            "string",
+            "any",
+            "Allowed quote style: `single`, `double`, or `any`",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "required",
+            "string",
+            "only-when-needed",
+            "When quotes are required: `always`, `only-when-needed`, or `never`",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "extra-required",
+            "list of strings",
+            "[]",
+            "Substrings that always require quotes",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "extra-allowed",
+            "list of strings",
+            "[]",
+            "Substrings for which quotes are optional",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "forbid-unnecessary-quotes",
+            "boolean",
+            "true with `only-when-needed`",
+            "Report quoted strings that do not need quotes",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "required-for-special-values",
+            "boolean",
+            "true",
+            "Require quotes on strings that would otherwise read as booleans, nulls or numbers",
+        ))
+        .with_examples("name: 'app'\n", "name: app\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let source = context.source();
         let rule_config = config.get_rule_config(self.code());
@@ -84,6 +147,23 @@ impl super::LintRule for QuotedStringsRule {
             .map(std::borrow::ToOwned::to_owned)
             .unwrap_or_default();
 
+        let forbid_unnecessary = rule_config
+            .and_then(|rc| rc.options.get_bool("forbid-unnecessary-quotes"))
+            .unwrap_or(required == "only-when-needed");
+
+        let special_values = rule_config
+            .and_then(|rc| rc.options.get_bool("required-for-special-values"))
+            .unwrap_or(true);
+
+        let options = QuoteOptions {
+            quote_type,
+            required,
+            extra_required,
+            extra_allowed,
+            forbid_unnecessary,
+            special_values,
+        };
+
         let mut diagnostics = Vec::new();
         let mut scopes: Vec<ScopeKind> = Vec::new();
 
@@ -136,10 +216,7 @@ impl super::LintRule for QuotedStringsRule {
                         line,
                         col,
                         scalar_offset,
-                        quote_type,
-                        required,
-                        &extra_required,
-                        &extra_allowed,
+                        &options,
                     );
                 }
 
@@ -173,10 +250,7 @@ impl QuotedStringsRule {
         line: usize,
         col: usize,
         scalar_offset: usize,
-        quote_type: &str,
-        required: &str,
-        extra_required: &[String],
-        extra_allowed: &[String],
+        options: &QuoteOptions<'_>,
     ) {
         match style {
             ScalarStyle::SingleQuoted | ScalarStyle::DoubleQuoted => {
@@ -188,7 +262,7 @@ impl QuotedStringsRule {
                 // value.len() + 2 for the surrounding quote characters.
                 let scalar_span = Self::make_span(line, col, scalar_offset, value.len() + 2);
 
-                if quote_type == "single" && quote_char == '"' {
+                if options.quote_type == "single" && quote_char == '"' {
                     let severity =
                         config.get_effective_severity(self.code(), self.default_severity());
                     diagnostics.push(
@@ -200,7 +274,7 @@ impl QuotedStringsRule {
                         )
                         .build_with_context(source_ctx),
                     );
-                } else if quote_type == "double" && quote_char == '\'' {
+                } else if options.quote_type == "double" && quote_char == '\'' {
                     let severity =
                         config.get_effective_severity(self.code(), self.default_severity());
                     diagnostics.push(
@@ -214,13 +288,19 @@ impl QuotedStringsRule {
                     );
                 }
 
-                if required == "only-when-needed" {
+                let is_special = options.special_values && Self::is_special_value(value);
+
+                if options.required == "only-when-needed" && options.forbid_unnecessary {
                     let has_escape = style == ScalarStyle::DoubleQuoted
                         && (Self::has_yaml_escape(value)
                             || Self::has_source_unicode_hex_escape(source, scalar_offset));
                     let needs = has_escape
+                        || is_special
                         || Self::needs_quotes(value)
-                        || extra_required.iter().any(|p| value.contains(p.as_str()));
+                        || options
+                            .extra_required
+                            .iter()
+                            .any(|p| value.contains(p.as_str()));
                     if !needs {
                         let severity =
                             config.get_effective_severity(self.code(), self.default_severity());
@@ -234,7 +314,7 @@ impl QuotedStringsRule {
                             .build_with_context(source_ctx),
                         );
                     }
-                } else if required == "never" {
+                } else if options.required == "never" && !is_special {
                     let severity =
                         config.get_effective_severity(self.code(), self.default_severity());
                     diagnostics.push(
@@ -251,10 +331,13 @@ impl QuotedStringsRule {
 
             // Plain scalars: only check when required == "always" and not a key.
             ScalarStyle::Plain
-                if required == "always"
+                if options.required == "always"
                     && !is_key
                     && !Self::is_scalar_literal(value)
-                    && !extra_allowed.iter().any(|p| value.contains(p.as_str())) =>
+                    && !options
+                        .extra_allowed
+                        .iter()
+                        .any(|p| value.contains(p.as_str())) =>
             {
                 let scalar_span = Self::make_span(line, col, scalar_offset, value.len());
                 let severity = config.get_effective_severity(self.code(), self.default_severity());
@@ -332,25 +415,41 @@ impl QuotedStringsRule {
         false
     }
 
-    /// Checks if a string needs quotes based on YAML syntax rules.
-    fn needs_quotes(s: &str) -> bool {
-        // Empty strings need quotes
-        if s.is_empty() {
-            return true;
-        }
-
-        // Strings that could be interpreted as special values need quotes
-        let special_values = [
+    /// Returns `true` if `s` would resolve to a boolean, null or number when
+    /// written as a plain scalar, under either YAML 1.2 or YAML 1.1 rules.
+    fn is_special_value(s: &str) -> bool {
+        const SPECIAL_WORDS: &[&str] = &[
             "true", "false", "True", "False", "TRUE", "FALSE", "yes", "no", "Yes", "No", "YES",
-            "NO", "on", "off", "On", "Off", "ON", "OFF", "null", "Null", "NULL", "~",
+            "NO", "on", "off", "On", "Off", "ON", "OFF", "y", "n", "Y", "N", "null", "Null",
+            "NULL", "~", ".inf", ".Inf", ".INF", "+.inf", "+.Inf", "+.INF", "-.inf", "-.Inf",
+            "-.INF", ".nan", ".NaN", ".NAN",
         ];
 
-        if special_values.contains(&s) {
+        if SPECIAL_WORDS.contains(&s) {
             return true;
         }
 
-        // Numbers need quotes to be treated as strings
-        if s.parse::<f64>().is_ok() {
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let radix_digits = |prefix: &str, is_digit: fn(&u8) -> bool| {
+            unsigned
+                .strip_prefix(prefix)
+                .is_some_and(|digits| !digits.is_empty() && digits.bytes().all(|b| is_digit(&b)))
+        };
+        if radix_digits("0o", |b| matches!(b, b'0'..=b'7'))
+            || radix_digits("0x", u8::is_ascii_hexdigit)
+            || radix_digits("0b", |b| matches!(b, b'0' | b'1'))
+        {
+            return true;
+        }
+
+        // Decimal integers and floats; YAML 1.1 also allows `_` digit separators
+        s.contains(|c: char| c.is_ascii_digit()) && s.replace('_', "").parse::<f64>().is_ok()
+    }
+
+    /// Checks if a string needs quotes based on YAML syntax rules.
+    fn needs_quotes(s: &str) -> bool {
+        // Empty strings need quotes
+        if s.is_empty() {
             return true;
         }
 
@@ -568,8 +667,8 @@ mod tests {
     #[test]
     fn test_quoted_strings_needs_quotes() {
         assert!(QuotedStringsRule::needs_quotes(""));
-        assert!(QuotedStringsRule::needs_quotes("true"));
-        assert!(QuotedStringsRule::needs_quotes("123"));
+        assert!(QuotedStringsRule::is_special_value("true"));
+        assert!(QuotedStringsRule::is_special_value("123"));
         assert!(QuotedStringsRule::needs_quotes("http://example.com"));
         assert!(QuotedStringsRule::needs_quotes("#comment"));
 
@@ -769,4 +868,160 @@ mod tests {
             "expected no diagnostics for \\x escape, got: {diagnostics:?}"
         );
     }
+
+    fn check_with(yaml: &str, rule_config: RuleConfig) -> Vec<Diagnostic> {
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+        let config = LintConfig::new().with_rule_config("quoted-strings", rule_config);
+        QuotedStringsRule.check(&LintContext::new(yaml), &value, &config)
+    }
+
+    #[test]
+    fn test_is_special_value() {
+        for special in [
+            "true", "False", "yes", "OFF", "y", "N", "null", "~", "1", "-42", "1.0", "1e3", ".inf",
+            "-.Inf", ".NaN", "0o17", "0x1F", "0b101", "1_000",
+        ] {
+            assert!(
+                QuotedStringsRule::is_special_value(special),
+                "{special} should be special"
+            );
+        }
+        for plain in [
+            "John",
+            "0o",
+            "0o8",
+            "0xZZ",
+            "inf",
+            "nan",
+            "_",
+            "1.0.0",
+            "v1",
+            "yes please",
+        ] {
+            assert!(
+                !QuotedStringsRule::is_special_value(plain),
+                "{plain} should not be special"
+            );
+        }
+    }
+
+    #[test]
+    fn test_special_values_keep_quotes() {
+        let yaml = "a: 'true'\nb: \"null\"\nc: '1.0'\nd: '0o17'\ne: 'yes'\nf: '.inf'\n";
+        assert!(check_with(yaml, RuleConfig::new()).is_empty());
+    }
+
+    #[test]
+    fn test_special_values_exempt_from_never() {
+        let yaml = "a: 'true'\nb: '0o17'\nname: 'John'\n";
+        let diagnostics = check_with(yaml, RuleConfig::new().with_option("required", "never"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.start.line, 3);
+    }
+
+    #[test]
+    fn test_special_values_disabled_never() {
+        let yaml = "a: 'true'\nb: '0o17'\n";
+        let diagnostics = check_with(
+            yaml,
+            RuleConfig::new()
+                .with_option("required", "never")
+                .with_option("required-for-special-values", false),
+        );
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].message.contains("should not be quoted"));
+    }
+
+    #[test]
+    fn test_special_values_disabled_only_when_needed() {
+        let yaml = "a: 'null'\n";
+        let diagnostics = check_with(
+            yaml,
+            RuleConfig::new().with_option("required-for-special-values", false),
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("does not need quotes"));
+    }
+
+    #[test]
+    fn test_special_values_hex_and_binary_keep_quotes() {
+        let yaml = "mask: '0xFF'\nbits: '0b1010'\nbig: '1_000_000'\n";
+        assert!(check_with(yaml, RuleConfig::new()).is_empty());
+    }
+
+    #[test]
+    fn test_non_numeric_words_flagged() {
+        // `inf` and `nan` without a leading dot are plain strings in YAML
+        let yaml = "a: 'inf'\nb: 'nan'\n";
+        assert_eq!(check_with(yaml, RuleConfig::new()).len(), 2);
+    }
+
+    #[test]
+    fn test_forbid_unnecessary_quotes_disabled() {
+        let yaml = "name: 'John'\ncity: \"NYC\"\n";
+        let diagnostics = check_with(
+            yaml,
+            RuleConfig::new().with_option("forbid-unnecessary-quotes", false),
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_forbid_unnecessary_quotes_disabled_keeps_quote_type() {
+        let yaml = "name: \"John\"\n";
+        let diagnostics = check_with(
+            yaml,
+            RuleConfig::new()
+                .with_option("forbid-unnecessary-quotes", false)
+                .with_option("quote-type", "single"),
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("single quotes"));
+    }
+
+    #[test]
+    fn test_forbid_unnecessary_quotes_enabled() {
+        let yaml = "name: 'John'\n";
+        let diagnostics = check_with(
+            yaml,
+            RuleConfig::new().with_option("forbid-unnecessary-quotes", true),
+        );
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("does not need quotes"));
+    }
+
+    #[test]
+    fn test_plain_special_values_not_flagged() {
+        // Plain scalars are left to the `truthy` and `octal-values` rules
+        let yaml = "a: true\nb: null\nc: 1.0\nd: 0o17\n";
+        assert!(check_with(yaml, RuleConfig::new()).is_empty());
+    }
+
+    #[test]
+    fn test_quoted_special_keys_keep_quotes() {
+        let yaml = "'true': a\n'1': b\n";
+        assert!(check_with(yaml, RuleConfig::new()).is_empty());
+    }
+
+    #[test]
+    fn test_special_values_in_sequence() {
+        let yaml = "- 'yes'\n- 'no'\n- 'maybe'\n";
+        let diagnostics = check_with(yaml, RuleConfig::new());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].span.start.line, 3);
+    }
+
+    #[test]
+    fn test_special_values_in_flow_mapping() {
+        let yaml = "opts: {debug: 'off', level: '3', name: 'x'}\n";
+        let diagnostics = check_with(yaml, RuleConfig::new());
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("does not need quotes"));
+    }
+
+    #[test]
+    fn test_signed_special_values_keep_quotes() {
+        let yaml = "a: '-1'\nb: '+.inf'\nc: '-0x1A'\n";
+        assert!(check_with(yaml, RuleConfig::new()).is_empty());
+    }
 }
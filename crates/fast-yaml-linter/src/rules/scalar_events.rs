@@ -0,0 +1,133 @@
+//! Scalar walk over the parser event stream, shared by value-style rules.
+//!
+//! Rules that care about how a scalar is written (plain or quoted) cannot use
+//! the parsed `Value`, which has already resolved `yes` or `.Inf` to a type.
+//! The event stream keeps the source text and style of every scalar.
+
+use saphyr_parser::{BufferedInput, Event, Parser as SaphyrParser, ScalarStyle};
+
+/// A scalar as written in the source.
+pub(super) struct ScalarEvent<'a> {
+    /// Scalar text after unescaping.
+    pub value: &'a str,
+    /// Plain, quoted or block style.
+    pub style: ScalarStyle,
+    /// Whether the scalar is a mapping key.
+    pub is_key: bool,
+    /// 1-based line number.
+    pub line: usize,
+    /// 0-based column.
+    pub col: usize,
+}
+
+/// Tracks whether the next scalar in a mapping scope is a key or a value.
+enum ScopeKind {
+    /// Inside a mapping; `expecting_key` alternates after each key/value.
+    Mapping { expecting_key: bool },
+    /// Inside a sequence; no key/value distinction.
+    Sequence,
+}
+
+/// Calls `f` for every scalar in `source`, in document order.
+///
+/// Parsing stops silently at the first syntax error; such input is reported
+/// by the linter before rules run.
+pub(super) fn for_each_scalar(source: &str, mut f: impl FnMut(&ScalarEvent<'_>)) {
+    let mut scopes: Vec<ScopeKind> = Vec::new();
+    let mut parser = SaphyrParser::new(BufferedInput::new(source.chars()));
+
+    while let Some(Ok((event, span))) = parser.next_event() {
+        match event {
+            Event::MappingStart(..) => scopes.push(ScopeKind::Mapping {
+                expecting_key: true,
+            }),
+            Event::SequenceStart(..) => scopes.push(ScopeKind::Sequence),
+            Event::MappingEnd | Event::SequenceEnd => {
+                scopes.pop();
+                advance_scope(&mut scopes);
+            }
+            Event::Scalar(ref value, style, ..) => {
+                let is_key = matches!(
+                    scopes.last(),
+                    Some(ScopeKind::Mapping {
+                        expecting_key: true
+                    })
+                );
+                advance_scope(&mut scopes);
+
+                // saphyr line() is 1-indexed; col() is 0-indexed.
+                f(&ScalarEvent {
+                    value,
+                    style,
+                    is_key,
+                    line: span.start.line(),
+                    col: span.start.col(),
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Flips the innermost mapping scope between key and value after a node ends.
+const fn advance_scope(scopes: &mut [ScopeKind]) {
+    if let Some(ScopeKind::Mapping { expecting_key }) = scopes.last_mut() {
+        *expecting_key = !*expecting_key;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(source: &str) -> Vec<(String, bool, usize, usize)> {
+        let mut scalars = Vec::new();
+        for_each_scalar(source, |scalar| {
+            scalars.push((
+                scalar.value.to_string(),
+                scalar.is_key,
+                scalar.line,
+                scalar.col,
+            ));
+        });
+        scalars
+    }
+
+    #[test]
+    fn test_for_each_scalar_keys_and_values() {
+        let scalars = collect("a: 1\nb:\n  - x\n  - {c: d}\ne: f\n");
+        let expected = [
+            ("a", true, 1, 0),
+            ("1", false, 1, 3),
+            ("b", true, 2, 0),
+            ("x", false, 3, 4),
+            ("c", true, 4, 5),
+            ("d", false, 4, 8),
+            ("e", true, 5, 0),
+            ("f", false, 5, 3),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|&(v, k, l, c)| (v.to_string(), k, l, c))
+            .collect();
+        assert_eq!(scalars, expected);
+    }
+
+    #[test]
+    fn test_for_each_scalar_style() {
+        let mut styles = Vec::new();
+        for_each_scalar("a: 'x'\nb: \"y\"\nc: z\n", |scalar| {
+            if !scalar.is_key {
+                styles.push(scalar.style);
+            }
+        });
+        assert_eq!(
+            styles,
+            [
+                ScalarStyle::SingleQuoted,
+                ScalarStyle::DoubleQuoted,
+                ScalarStyle::Plain
+            ]
+        );
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
 use fast_yaml_core::Value;
 
 /// Rule to detect trailing whitespace.
+#[super::register_lint_rule]
 pub struct TrailingWhitespaceRule;
 
 impl super::LintRule for TrailingWhitespaceRule {
@@ -26,6 +27,16 @@ impl super::LintRule for TrailingWhitespaceRule {
         Severity::Hint
     }
 
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_examples("key: value   \n", "key: value\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
         let ctx = context.source_context();
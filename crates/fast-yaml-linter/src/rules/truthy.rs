@@ -5,13 +5,15 @@ use crate::{
     Span,
 };
 use fast_yaml_core::Value;
+use saphyr_parser::ScalarStyle;
 use std::collections::HashSet;
 
+use super::scalar_events::for_each_scalar;
+
 /// YAML 1.1-only boolean representations — not valid in YAML 1.2.2 Core Schema.
-const NON_STANDARD_BOOLS: &[&str] = &[
-    "yes", "no", "Yes", "No", "YES", "NO", "on", "off", "On", "Off", "ON", "OFF", "y", "n", "Y",
-    "N",
-];
+///
+/// Matched case-insensitively, so `Yes`, `OFF` and `yEs` are flagged as well.
+const NON_STANDARD_BOOLS: &[&str] = &["yes", "no", "on", "off", "y", "n"];
 
 /// Valid YAML 1.2.2 booleans that are not in canonical form (`true`/`false`).
 const NON_CANONICAL_BOOLS: &[&str] = &["True", "False", "TRUE", "FALSE"];
@@ -22,6 +24,10 @@ const NON_CANONICAL_BOOLS: &[&str] = &["True", "False", "TRUE", "FALSE"];
 /// YAML 1.2 standardizes on `true` and `false`, but YAML 1.1 allowed
 /// many alternatives (yes/no, on/off, y/n, etc.) which can cause confusion.
 ///
+/// Only plain scalars are checked, using the parser's scalar style: quoted
+/// and block scalars are strings in every YAML version. Values inside flow
+/// collections and multi-document streams are covered as well.
+///
 /// Configuration options:
 /// - `allowed-values`: list of allowed truthy representations (default: `["true", "false"]`)
 /// - `check-keys`: whether to check keys too (default: false)
@@ -41,6 +47,7 @@ const NON_CANONICAL_BOOLS: &[&str] = &["True", "False", "TRUE", "FALSE"];
 /// let diagnostics = rule.check(&context, &value, &config);
 /// assert!(diagnostics.is_empty());
 /// ```
+#[super::register_lint_rule]
 pub struct TruthyRule;
 
 impl super::LintRule for TruthyRule {
@@ -60,7 +67,28 @@ impl super::LintRule for TruthyRule {
         Severity::Warning
     }
 
-    #[allow(clippy::too_many_lines)]
+    fn help(&self) -> super::RuleHelp {
+        super::RuleHelp::new(
+            self.code(),
+            self.name(),
+            self.description(),
+            self.default_severity(),
+        )
+        .with_option(super::RuleOptionHelp::new(
+            "allowed-values",
+            "list of strings",
+            "[true, false]",
+            "Boolean representations that are accepted",
+        ))
+        .with_option(super::RuleOptionHelp::new(
+            "check-keys",
+            "boolean",
+            "false",
+            "Also check mapping keys",
+        ))
+        .with_examples("enabled: yes\n", "enabled: true\n")
+    }
+
     fn check(&self, context: &LintContext, _value: &Value, config: &LintConfig) -> Vec<Diagnostic> {
         let rule_config = config.get_rule_config(self.code());
 
@@ -75,184 +103,63 @@ impl super::LintRule for TruthyRule {
             .and_then(|rc| rc.options.get_bool("check-keys"))
             .unwrap_or(false);
 
-        // Pre-build HashSets for O(1) lookup
-        let non_standard_set: HashSet<&str> = NON_STANDARD_BOOLS.iter().copied().collect();
-        let non_canonical_set: HashSet<&str> = NON_CANONICAL_BOOLS.iter().copied().collect();
         let allowed_set: HashSet<&str> = allowed_values.iter().map(String::as_str).collect();
 
         let mut diagnostics = Vec::new();
 
-        // Use cached lines and metadata from context
-        let lines = context.lines();
-        let line_metadata = context.line_metadata();
-
-        for (line_idx, (line, metadata)) in lines.iter().zip(line_metadata).enumerate() {
-            let line_num = line_idx + 1;
-            let line_offset = context.source_context().get_line_offset(line_num);
-
-            // Skip comment lines using cached metadata
-            if metadata.is_comment {
-                continue;
+        for_each_scalar(context.source(), |scalar| {
+            let value = scalar.value;
+            // Quoted and block scalars are always strings.
+            if scalar.style != ScalarStyle::Plain
+                || (scalar.is_key && !check_keys)
+                || allowed_set.contains(value)
+            {
+                return;
             }
 
-            // Find key-value pairs (after ':')
-            if let Some(colon_pos) = line.find(':') {
-                let key_part = &line[..colon_pos];
-                let value_part = &line[colon_pos + 1..];
-
-                // Check key if configured
-                if check_keys {
-                    let key_trimmed = key_part.trim();
-                    let key_msg = if non_standard_set.contains(key_trimmed)
-                        && !allowed_set.contains(key_trimmed)
-                    {
-                        Some(format!(
-                            "found non-standard truthy key '{key_trimmed}' (use {})",
-                            allowed_values.join(" or ")
-                        ))
-                    } else if non_canonical_set.contains(key_trimmed)
-                        && !allowed_set.contains(key_trimmed)
-                    {
-                        Some(format!(
-                            "found non-canonical boolean key '{key_trimmed}', use 'true' or 'false'"
-                        ))
-                    } else {
-                        None
-                    };
-                    if let Some(msg) = key_msg {
-                        let key_start = line.find(key_trimmed).unwrap_or(0);
-                        let offset = line_offset + key_start;
-                        let severity =
-                            config.get_effective_severity(self.code(), self.default_severity());
-                        let location = Location::new(line_num, 1, offset);
-                        let span = Span::new(
-                            location,
-                            Location::new(line_num, 1, offset + key_trimmed.len()),
-                        );
-                        diagnostics.push(
-                            DiagnosticBuilder::new(self.code(), severity, msg, span)
-                                .build_with_context(context.source_context()),
-                        );
-                    }
-                }
-
-                // Check value
-                let value_trimmed = value_part.trim();
-
-                // Skip if empty, quoted, or starts with flow collection markers
-                if value_trimmed.is_empty()
-                    || value_trimmed.starts_with('"')
-                    || value_trimmed.starts_with('\'')
-                    || value_trimmed.starts_with('[')
-                    || value_trimmed.starts_with('{')
-                {
-                    continue;
-                }
-
-                // Extract the value token (before any comment or space)
-                let value_token = value_trimmed
-                    .split_whitespace()
-                    .next()
-                    .and_then(|s| s.split('#').next())
-                    .unwrap_or(value_trimmed);
-
-                let val_msg = if non_standard_set.contains(value_token)
-                    && !allowed_set.contains(value_token)
-                {
-                    Some(format!(
-                        "found non-standard truthy value '{value_token}' (use {})",
-                        allowed_values.join(" or ")
-                    ))
-                } else if non_canonical_set.contains(value_token)
-                    && !allowed_set.contains(value_token)
-                {
-                    Some(format!(
-                        "found non-canonical boolean '{value_token}', use 'true' or 'false'"
-                    ))
+            let message = if is_non_standard_bool(value) {
+                let kind = if scalar.is_key { "key" } else { "value" };
+                format!(
+                    "found non-standard truthy {kind} '{value}' (use {})",
+                    allowed_values.join(" or ")
+                )
+            } else if NON_CANONICAL_BOOLS.contains(&value) {
+                if scalar.is_key {
+                    format!("found non-canonical boolean key '{value}', use 'true' or 'false'")
                 } else {
-                    None
-                };
-                if let Some(msg) = val_msg {
-                    let value_start = line.find(value_token).unwrap_or(colon_pos + 1);
-                    let offset = line_offset + value_start;
-                    let severity =
-                        config.get_effective_severity(self.code(), self.default_severity());
-                    let location = Location::new(line_num, 1, offset);
-                    let span = Span::new(
-                        location,
-                        Location::new(line_num, 1, offset + value_token.len()),
-                    );
-                    diagnostics.push(
-                        DiagnosticBuilder::new(self.code(), severity, msg, span)
-                            .build_with_context(context.source_context()),
-                    );
+                    format!("found non-canonical boolean '{value}', use 'true' or 'false'")
                 }
-            }
-
-            // Check list items (after '- ')
-            if let Some(hyphen_pos) = line.find('-') {
-                let after_hyphen = &line[hyphen_pos + 1..];
-                let value_trimmed = after_hyphen.trim();
-
-                // Skip if this is a mapping key (contains ':')
-                if value_trimmed.contains(':') {
-                    continue;
-                }
-
-                // Skip if empty, quoted, or starts with flow collection markers
-                if value_trimmed.is_empty()
-                    || value_trimmed.starts_with('"')
-                    || value_trimmed.starts_with('\'')
-                    || value_trimmed.starts_with('[')
-                    || value_trimmed.starts_with('{')
-                {
-                    continue;
-                }
-
-                let value_token = value_trimmed
-                    .split_whitespace()
-                    .next()
-                    .and_then(|s| s.split('#').next())
-                    .unwrap_or(value_trimmed);
-
-                let list_msg = if non_standard_set.contains(value_token)
-                    && !allowed_set.contains(value_token)
-                {
-                    Some(format!(
-                        "found non-standard truthy value '{value_token}' (use {})",
-                        allowed_values.join(" or ")
-                    ))
-                } else if non_canonical_set.contains(value_token)
-                    && !allowed_set.contains(value_token)
-                {
-                    Some(format!(
-                        "found non-canonical boolean '{value_token}', use 'true' or 'false'"
-                    ))
-                } else {
-                    None
-                };
-                if let Some(msg) = list_msg {
-                    let value_start = line.find(value_token).unwrap_or(hyphen_pos + 1);
-                    let offset = line_offset + value_start;
-                    let severity =
-                        config.get_effective_severity(self.code(), self.default_severity());
-                    let location = Location::new(line_num, 1, offset);
-                    let span = Span::new(
-                        location,
-                        Location::new(line_num, 1, offset + value_token.len()),
-                    );
-                    diagnostics.push(
-                        DiagnosticBuilder::new(self.code(), severity, msg, span)
-                            .build_with_context(context.source_context()),
-                    );
-                }
-            }
-        }
+            } else {
+                return;
+            };
+
+            let offset = context.source_context().get_line_offset(scalar.line) + scalar.col;
+            let severity = config.get_effective_severity(self.code(), self.default_severity());
+            let span = Span::new(
+                Location::new(scalar.line, scalar.col + 1, offset),
+                Location::new(
+                    scalar.line,
+                    scalar.col + 1 + value.len(),
+                    offset + value.len(),
+                ),
+            );
+            diagnostics.push(
+                DiagnosticBuilder::new(self.code(), severity, message, span)
+                    .build_with_context(context.source_context()),
+            );
+        });
 
         diagnostics
     }
 }
 
+/// Returns `true` for YAML 1.1 booleans, compared case-insensitively.
+fn is_non_standard_bool(value: &str) -> bool {
+    NON_STANDARD_BOOLS
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(value))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -446,4 +353,61 @@ mod tests {
         let diagnostics = rule.check(&context, &value, &config);
         assert_eq!(diagnostics.len(), 2);
     }
+
+    fn check(yaml: &str) -> Vec<Diagnostic> {
+        let value = Parser::parse_str(yaml).unwrap().unwrap();
+        TruthyRule.check(&LintContext::new(yaml), &value, &LintConfig::default())
+    }
+
+    #[test]
+    fn test_truthy_case_insensitive() {
+        let diagnostics = check("a: YES\nb: Off\nc: yEs\n");
+        assert_eq!(diagnostics.len(), 3);
+        assert!(diagnostics[2].message.contains("'yEs'"));
+    }
+
+    #[test]
+    fn test_truthy_flow_collections() {
+        let diagnostics = check("flags: [yes, 'no', true]\nopts: {debug: on}\n");
+        let values: Vec<_> = diagnostics
+            .iter()
+            .map(|d| (d.span.start.line, d.span.start.column))
+            .collect();
+        assert_eq!(values, [(1, 9), (2, 15)]);
+    }
+
+    #[test]
+    fn test_truthy_block_scalar_ignored() {
+        assert!(check("text: |\n  yes\nfolded: >\n  no\n").is_empty());
+    }
+
+    #[test]
+    fn test_truthy_inside_plain_text_ignored() {
+        assert!(check("msg: yes we can\nrun: echo on\n").is_empty());
+    }
+
+    #[test]
+    fn test_truthy_multi_document() {
+        let yaml = "a: yes\n---\nb: no\n";
+        let value = Parser::parse_all(yaml).unwrap().remove(0);
+        let diagnostics = TruthyRule.check(&LintContext::new(yaml), &value, &LintConfig::default());
+        let lines: Vec<_> = diagnostics.iter().map(|d| d.span.start.line).collect();
+        assert_eq!(lines, [1, 3]);
+    }
+
+    #[test]
+    fn test_truthy_span_covers_value() {
+        let diagnostics = check("enabled: yes\n");
+        let span = diagnostics[0].span;
+        assert_eq!((span.start.column, span.end.column), (10, 13));
+        assert_eq!((span.start.offset, span.end.offset), (9, 12));
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_truthy_nested_mapping_values() {
+        let diagnostics = check("server:\n  tls:\n    enabled: on\n  port: 80\nlog: n\n");
+        let lines: Vec<_> = diagnostics.iter().map(|d| d.span.start.line).collect();
+        assert_eq!(lines, [3, 5]);
+    }
 }
@@ -54,6 +54,28 @@ impl Severity {
         }
     }
 
+    /// Returns the severity as an integer that sorts by importance.
+    ///
+    /// Hint is 0, info 1, warning 2 and error 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_linter::Severity;
+    ///
+    /// assert_eq!(Severity::Hint.level(), 0);
+    /// assert_eq!(Severity::Error.level(), 3);
+    /// ```
+    #[must_use]
+    pub const fn level(self) -> u8 {
+        match self {
+            Self::Hint => 0,
+            Self::Info => 1,
+            Self::Warning => 2,
+            Self::Error => 3,
+        }
+    }
+
     /// Returns ANSI color code for terminal display.
     ///
     /// Returns the appropriate ANSI escape sequence for coloring
@@ -0,0 +1,74 @@
+//! Integration tests for loading `LintConfig` from config files.
+
+use std::io::Write;
+
+use fast_yaml_linter::{ConfigFileError, LintConfig, Linter, Severity};
+use tempfile::NamedTempFile;
+
+fn write_config(content: &str) -> NamedTempFile {
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn test_from_yaml_file_rule_settings() {
+    let file = write_config(
+        "rules:\n  line-length:\n    severity: error\n    max: 100\n  trailing-whitespace:\n    enabled: false\n  truthy:\n    level: hint\n    options:\n      allowed-values: [\"yes\", \"no\"]\n      check-keys: false\n",
+    );
+
+    let config = LintConfig::from_yaml_file(file.path()).unwrap();
+
+    assert_eq!(config.max_line_length, Some(100));
+    let line_length = config.get_rule_config("line-length").unwrap();
+    assert!(line_length.enabled);
+    assert_eq!(line_length.severity, Some(Severity::Error));
+
+    assert!(!config.is_rule_enabled("trailing-whitespace"));
+
+    let truthy = config.get_rule_config("truthy").unwrap();
+    assert_eq!(truthy.severity, Some(Severity::Hint));
+    assert_eq!(truthy.options.get_bool("check-keys"), Some(false));
+    assert_eq!(
+        truthy.options.get_string_list("allowed-values"),
+        Some(&["yes".to_string(), "no".to_string()][..])
+    );
+
+    // Rules not mentioned keep their defaults
+    assert!(config.is_rule_enabled("duplicate-key"));
+    assert!(config.get_rule_config("duplicate-key").is_none());
+}
+
+#[test]
+fn test_from_yaml_file_applies_when_linting() {
+    let file = write_config("rules:\n  line-length:\n    severity: error\n    max: 10\n");
+    let config = LintConfig::from_yaml_file(file.path()).unwrap();
+
+    let diagnostics = Linter::with_config(config)
+        .lint("key: a very long value\n")
+        .unwrap();
+    let line_length: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.code.as_str() == "line-length")
+        .collect();
+    assert_eq!(line_length.len(), 1);
+    assert_eq!(line_length[0].severity, Severity::Error);
+}
+
+#[test]
+fn test_from_yaml_file_errors() {
+    let missing = LintConfig::from_yaml_file(std::path::Path::new("/nonexistent/.fast-yaml.yaml"));
+    assert!(matches!(missing, Err(ConfigFileError::Io { .. })));
+
+    let file = write_config("rules:\n  line-length:\n    severity: loud\n");
+    let invalid = LintConfig::from_yaml_file(file.path());
+    assert!(matches!(invalid, Err(ConfigFileError::Parse { .. })));
+}
+
+#[test]
+fn test_lint_config_deserialize() {
+    let config: LintConfig =
+        serde_norway::from_str("rules:\n  indentation:\n    indent-size: 4\n").unwrap();
+    assert_eq!(config.indent_size, 4);
+    assert!(config.get_rule_config("indentation").is_some());
+}
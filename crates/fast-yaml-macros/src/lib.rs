@@ -0,0 +1,72 @@
+//! Procedural macros for fast-yaml.
+//!
+//! Use these through `fast-yaml-linter`, which re-exports them together with
+//! the runtime items the generated code refers to.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, parse_macro_input};
+
+/// Registers a lint rule in the global `fast_yaml_linter` rule registry.
+///
+/// Apply it to the struct that implements `LintRule`. Unit structs are
+/// constructed directly; any other struct must implement `Default`.
+///
+/// ```ignore
+/// use fast_yaml_linter::rules::{LintRule, register_lint_rule};
+///
+/// #[register_lint_rule]
+/// pub struct NoTodoRule;
+/// ```
+#[proc_macro_attribute]
+pub fn register_lint_rule(args: TokenStream, item: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        return syn::Error::new(
+            TokenStream2::from(args)
+                .into_iter()
+                .next()
+                .map_or_else(proc_macro2::Span::call_site, |token| token.span()),
+            "#[register_lint_rule] takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let input = parse_macro_input!(item as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[register_lint_rule] can only be applied to structs",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "#[register_lint_rule] does not support generic rules",
+        ));
+    }
+
+    let ident = &input.ident;
+    let construct = if matches!(data.fields, Fields::Unit) {
+        quote!(#ident)
+    } else {
+        quote!(<#ident as ::core::default::Default>::default())
+    };
+
+    Ok(quote! {
+        #input
+
+        ::fast_yaml_linter::__private::inventory::submit! {
+            ::fast_yaml_linter::rules::RuleRegistration::new(|| {
+                ::std::boxed::Box::new(#construct)
+            })
+        }
+    })
+}
@@ -71,6 +71,28 @@ fn bench_parallel_overhead(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark: Single-document input.
+///
+/// Compares `parse_parallel` on one document, which takes the sequential
+/// fast path, against parsing the document directly.
+fn bench_single_document(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_document");
+
+    for bytes in [200, 5000, 100_000] {
+        let yaml = generate_yaml_docs(1, bytes);
+
+        group.bench_with_input(BenchmarkId::new("parallel", bytes), &yaml, |b, yaml| {
+            b.iter(|| parse_parallel(black_box(yaml)));
+        });
+
+        group.bench_with_input(BenchmarkId::new("sequential", bytes), &yaml, |b, yaml| {
+            b.iter(|| Parser::parse_str(black_box(yaml)));
+        });
+    }
+
+    group.finish();
+}
+
 /// Benchmark: Scalability across document counts.
 ///
 /// Tests how performance scales with increasing document count.
@@ -395,6 +417,7 @@ fn bench_multifile_thread_scaling(c: &mut Criterion) {
 criterion_group!(
     benches,
     bench_parallel_overhead,
+    bench_single_document,
     bench_scalability,
     bench_thread_pool_strategies,
     bench_document_sizes,
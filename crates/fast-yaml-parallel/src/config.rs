@@ -1,8 +1,20 @@
 //! Configuration for parallel processing behavior.
 
+use std::fmt;
+use std::sync::Arc;
+
+use crate::io::EncodingFallback;
+
 /// Maximum number of threads allowed (security limit).
 const MAX_THREADS: usize = 128;
 
+/// Callback invoked after each file of a batch completes, with the number of
+/// completed files and the total number of files.
+///
+/// Files are processed in parallel, so the callback may be called from
+/// several threads at once.
+pub type ProgressCallback = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
 /// Configuration for parallel processing behavior.
 ///
 /// Simplified configuration with essential fields for both document-level
@@ -23,7 +35,7 @@ const MAX_THREADS: usize = 128;
 ///     .with_workers(Some(8))
 ///     .with_sequential_threshold(2048);
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Config {
     /// Worker count: None = auto (CPU count), Some(0) = sequential, Some(n) = n threads
     pub(crate) workers: Option<usize>,
@@ -36,6 +48,41 @@ pub struct Config {
 
     /// Sequential threshold: use sequential for small inputs (default: 4KB)
     pub(crate) sequential_threshold: usize,
+
+    /// Inputs with fewer documents are parsed sequentially (default: 2)
+    pub(crate) sequential_threshold_docs: usize,
+
+    /// Handling of files that are not valid UTF-8 (default: strict)
+    pub(crate) encoding_fallback: EncodingFallback,
+
+    /// Time parsing separately when formatting in place (default: false)
+    pub(crate) detailed_timing: bool,
+
+    /// Called after each file completes (default: none)
+    pub(crate) progress_callback: Option<ProgressCallback>,
+
+    /// Stop processing after this many failed files (default: none)
+    pub(crate) max_errors: Option<usize>,
+
+    /// Report changes without writing files back (default: false)
+    pub(crate) dry_run: bool,
+}
+
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Config")
+            .field("workers", &self.workers)
+            .field("mmap_threshold", &self.mmap_threshold)
+            .field("max_input_size", &self.max_input_size)
+            .field("sequential_threshold", &self.sequential_threshold)
+            .field("sequential_threshold_docs", &self.sequential_threshold_docs)
+            .field("encoding_fallback", &self.encoding_fallback)
+            .field("detailed_timing", &self.detailed_timing)
+            .field("progress_callback", &self.progress_callback.is_some())
+            .field("max_errors", &self.max_errors)
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
 }
 
 impl Config {
@@ -153,6 +200,133 @@ impl Config {
         self
     }
 
+    /// Sets the minimum number of documents for parallel parsing.
+    ///
+    /// Inputs with fewer documents are parsed on the calling thread. The
+    /// default of 2 only skips the thread pool for single-document input.
+    /// Default: 2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_parallel::Config;
+    ///
+    /// // Parse up to 7 documents sequentially
+    /// let config = Config::new().with_sequential_threshold_docs(8);
+    /// ```
+    #[must_use]
+    pub const fn with_sequential_threshold_docs(mut self, docs: usize) -> Self {
+        self.sequential_threshold_docs = docs;
+        self
+    }
+
+    /// Sets how files that are not valid UTF-8 are decoded.
+    ///
+    /// With a fallback other than [`EncodingFallback::Strict`], such files are
+    /// processed and reported with a non-fatal
+    /// [`Error::EncodingWarning`](crate::Error::EncodingWarning) instead of failing.
+    /// Files formatted in place are written back as UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_parallel::{Config, EncodingFallback};
+    ///
+    /// let config = Config::new()
+    ///     .with_encoding_fallback(EncodingFallback::ReplaceInvalid);
+    /// ```
+    #[must_use]
+    pub const fn with_encoding_fallback(mut self, fallback: EncodingFallback) -> Self {
+        self.encoding_fallback = fallback;
+        self
+    }
+
+    /// Enables a separate parse pass when formatting files in place.
+    ///
+    /// The formatter parses and emits in a single pass, so
+    /// [`FormattingTimes::parse`](crate::FormattingTimes::parse) is only
+    /// measured when this is enabled, at the cost of parsing each file twice.
+    /// Default: false
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_parallel::Config;
+    ///
+    /// let config = Config::new().with_detailed_timing(true);
+    /// ```
+    #[must_use]
+    pub const fn with_detailed_timing(mut self, enabled: bool) -> Self {
+        self.detailed_timing = enabled;
+        self
+    }
+
+    /// Sets a callback invoked after each file of a batch completes.
+    ///
+    /// The callback receives the number of completed files and the total
+    /// number of files. Default: none
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use fast_yaml_parallel::Config;
+    ///
+    /// let config = Config::new().with_progress_callback(Some(Arc::new(|done, total| {
+    ///     eprintln!("{done}/{total}");
+    /// })));
+    /// ```
+    #[must_use]
+    pub fn with_progress_callback(mut self, callback: Option<ProgressCallback>) -> Self {
+        self.progress_callback = callback;
+        self
+    }
+
+    /// Sets the number of failed files after which a batch stops early.
+    ///
+    /// Once `max_errors` files have failed, files that have not started yet
+    /// are reported in [`BatchResult::skipped`](crate::BatchResult::skipped)
+    /// instead of being processed. Files already in progress on other
+    /// workers still finish, so slightly more errors may be reported.
+    /// Applies to [`FileProcessor::process`](crate::FileProcessor::process),
+    /// [`FileProcessor::parse_files`](crate::FileProcessor::parse_files) and
+    /// [`FileProcessor::format_in_place`](crate::FileProcessor::format_in_place).
+    /// Default: none (process every file)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_parallel::Config;
+    ///
+    /// let config = Config::new().with_max_errors(Some(50));
+    /// ```
+    #[must_use]
+    pub const fn with_max_errors(mut self, max_errors: Option<usize>) -> Self {
+        self.max_errors = max_errors;
+        self
+    }
+
+    /// Makes [`FileProcessor::format_in_place`](crate::FileProcessor::format_in_place)
+    /// report which files would change without writing them.
+    ///
+    /// Files that would change are still counted in
+    /// [`BatchResult::changed`](crate::BatchResult::changed). Default: false
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_parallel::Config;
+    ///
+    /// let config = Config::new().with_dry_run(true);
+    /// assert!(config.dry_run());
+    /// ```
+    #[must_use]
+    pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
     /// Returns worker count setting.
     #[must_use]
     pub const fn workers(&self) -> Option<usize> {
@@ -176,6 +350,36 @@ impl Config {
     pub const fn sequential_threshold(&self) -> usize {
         self.sequential_threshold
     }
+
+    /// Returns the minimum document count for parallel parsing.
+    #[must_use]
+    pub const fn sequential_threshold_docs(&self) -> usize {
+        self.sequential_threshold_docs
+    }
+
+    /// Returns the encoding fallback.
+    #[must_use]
+    pub const fn encoding_fallback(&self) -> EncodingFallback {
+        self.encoding_fallback
+    }
+
+    /// Returns true if parsing is timed separately.
+    #[must_use]
+    pub const fn detailed_timing(&self) -> bool {
+        self.detailed_timing
+    }
+
+    /// Returns the error limit.
+    #[must_use]
+    pub const fn max_errors(&self) -> Option<usize> {
+        self.max_errors
+    }
+
+    /// Returns true if in-place formatting leaves files unchanged.
+    #[must_use]
+    pub const fn dry_run(&self) -> bool {
+        self.dry_run
+    }
 }
 
 impl Default for Config {
@@ -185,6 +389,12 @@ impl Default for Config {
             mmap_threshold: 512 * 1024,        // 512KB
             max_input_size: 100 * 1024 * 1024, // 100MB
             sequential_threshold: 4096,        // 4KB
+            sequential_threshold_docs: 2,
+            encoding_fallback: EncodingFallback::Strict,
+            detailed_timing: false,
+            progress_callback: None,
+            max_errors: None,
+            dry_run: false,
         }
     }
 }
@@ -212,6 +422,10 @@ mod tests {
         assert_eq!(config.mmap_threshold, 512 * 1024);
         assert_eq!(config.max_input_size, 100 * 1024 * 1024);
         assert_eq!(config.sequential_threshold, 4096);
+        assert_eq!(config.sequential_threshold_docs, 2);
+        assert_eq!(config.encoding_fallback, EncodingFallback::Strict);
+        assert!(!config.detailed_timing);
+        assert!(!config.dry_run);
     }
 
     #[test]
@@ -259,12 +473,16 @@ mod tests {
             .with_workers(Some(8))
             .with_mmap_threshold(2048)
             .with_max_input_size(50_000_000)
-            .with_sequential_threshold(8192);
+            .with_sequential_threshold(8192)
+            .with_encoding_fallback(EncodingFallback::Latin1Fallback)
+            .with_detailed_timing(true);
 
         assert_eq!(config.workers(), Some(8));
         assert_eq!(config.mmap_threshold(), 2048);
         assert_eq!(config.max_input_size(), 50_000_000);
         assert_eq!(config.sequential_threshold(), 8192);
+        assert_eq!(config.encoding_fallback(), EncodingFallback::Latin1Fallback);
+        assert!(config.detailed_timing());
     }
 
     #[test]
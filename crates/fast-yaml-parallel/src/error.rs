@@ -38,6 +38,19 @@ pub enum Error {
         source: std::str::Utf8Error,
     },
 
+    /// File was not valid UTF-8 and was decoded with a fallback encoding.
+    ///
+    /// Non-fatal: reported alongside a successful result (see
+    /// [`EncodingFallback`](crate::EncodingFallback)) rather than failing the file.
+    #[error("'{path}' is not valid UTF-8, decoded as {from_encoding}")]
+    EncodingWarning {
+        /// Path to the file that was transcoded.
+        path: PathBuf,
+
+        /// Encoding the content was decoded from.
+        from_encoding: &'static str,
+    },
+
     /// Failed to format YAML.
     #[error("format error: {message}")]
     Format {
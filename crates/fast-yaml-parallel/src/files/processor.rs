@@ -2,6 +2,7 @@
 
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::Instant;
 
 use fast_yaml_core::emitter::{Emitter, EmitterConfig};
@@ -9,8 +10,8 @@ use rayon::prelude::*;
 
 use crate::config::Config;
 use crate::error::{Error, Result};
-use crate::io::SmartReader;
-use crate::result::{BatchResult, FileOutcome, FileResult};
+use crate::io::{FileContent, ReadStrategy, SmartReader};
+use crate::result::{BatchResult, FileOutcome, FileResult, FormattingTimes};
 
 /// Parallel file processor for batch YAML operations.
 ///
@@ -78,10 +79,11 @@ impl FileProcessor {
             return BatchResult::new();
         }
 
+        let batch = BatchState::new(self.config.max_errors());
         let results = if Self::should_use_sequential(paths) {
-            self.process_files_sequential(paths, &f)
+            self.process_files_sequential(paths, &f, &batch)
         } else {
-            self.process_files_parallel(paths, &f)
+            self.process_files_parallel(paths, &f, &batch)
         };
 
         let mut batch = BatchResult::from_results(results);
@@ -102,39 +104,84 @@ impl FileProcessor {
     }
 
     /// Format files and return `(path, formatted_content)` pairs.
+    ///
+    /// Files are decoded according to the configured encoding fallback;
+    /// encoding warnings are not reported by this method.
     pub fn format_files(
         &self,
         paths: &[PathBuf],
         emitter_config: &EmitterConfig,
     ) -> Vec<(PathBuf, Result<String>)> {
-        let process_file = |path: &Path| -> Result<String> {
-            let file_content = self.reader.read(path)?;
-            let original = file_content.as_str()?;
+        self.format_files_with_original(paths, emitter_config)
+            .into_iter()
+            .map(|(path, result)| (path, result.map(|(_, formatted)| formatted)))
+            .collect()
+    }
 
-            Emitter::format_with_config(original, emitter_config).map_err(|e| Error::Format {
-                message: format!("{}: {}", path.display(), e),
-            })
+    /// Format files and return `(path, (original_content, formatted_content))` pairs.
+    ///
+    /// Like [`format_files`](Self::format_files), but also keeps the decoded
+    /// original so callers comparing the two do not have to read each file again.
+    pub fn format_files_with_original(
+        &self,
+        paths: &[PathBuf],
+        emitter_config: &EmitterConfig,
+    ) -> Vec<(PathBuf, Result<(String, String)>)> {
+        let batch = BatchState::new(None);
+        let process_file = |path: &PathBuf| {
+            let format = || -> Result<(String, String)> {
+                let (file_content, _warning) = self
+                    .reader
+                    .read_with_encoding_fallback(path, self.config.encoding_fallback())?;
+                let original = file_content.as_str()?;
+
+                let formatted =
+                    Emitter::format_with_config(original, emitter_config).map_err(|e| {
+                        Error::Format {
+                            message: format!("{}: {}", path.display(), e),
+                        }
+                    })?;
+                Ok((original.to_string(), formatted))
+            };
+            let result = format();
+            self.report_progress(&batch, paths.len());
+            (path.clone(), result)
         };
 
         if Self::should_use_sequential(paths) {
-            paths
-                .iter()
-                .map(|path| (path.clone(), process_file(path)))
-                .collect()
+            paths.iter().map(process_file).collect()
         } else {
-            paths
-                .par_iter()
-                .map(|path| (path.clone(), process_file(path)))
-                .collect()
+            paths.par_iter().map(process_file).collect()
         }
     }
 
     /// Format files in place (write back if changed).
+    ///
+    /// With [`Config::with_dry_run`](crate::Config::with_dry_run), changed
+    /// files are counted but not written.
     pub fn format_in_place(
         &self,
         paths: &[PathBuf],
         emitter_config: &EmitterConfig,
     ) -> BatchResult {
+        self.format_in_place_with_progress(paths, emitter_config, |_| {})
+    }
+
+    /// Format files in place, calling `on_file` with each file's result as
+    /// soon as that file is done.
+    ///
+    /// The configured progress callback, if any, is called after `on_file`.
+    /// Files are processed in parallel, so `on_file` may be called from
+    /// several threads at once and in any order.
+    pub fn format_in_place_with_progress<F>(
+        &self,
+        paths: &[PathBuf],
+        emitter_config: &EmitterConfig,
+        on_file: F,
+    ) -> BatchResult
+    where
+        F: Fn(&FileResult) + Sync,
+    {
         let batch_start = std::time::Instant::now();
         let total = paths.len();
 
@@ -142,16 +189,17 @@ impl FileProcessor {
             return BatchResult::new();
         }
 
+        let batch = BatchState::new(self.config.max_errors());
+        let format_file = |path: &PathBuf| {
+            let result = batch.run(path, || self.format_single_file(path, emitter_config));
+            on_file(&result);
+            self.report_progress(&batch, total);
+            result
+        };
         let results = if Self::should_use_sequential(paths) {
-            paths
-                .iter()
-                .map(|path| self.format_single_file(path, emitter_config))
-                .collect()
+            paths.iter().map(format_file).collect()
         } else {
-            paths
-                .par_iter()
-                .map(|path| self.format_single_file(path, emitter_config))
-                .collect()
+            paths.par_iter().map(format_file).collect()
         };
 
         let mut batch = BatchResult::from_results(results);
@@ -161,94 +209,61 @@ impl FileProcessor {
 
     /// Format a single file in place
     fn format_single_file(&self, path: &Path, emitter_config: &EmitterConfig) -> FileResult {
-        let start = std::time::Instant::now();
-
-        let metadata = match std::fs::metadata(path) {
-            Ok(m) => m,
-            Err(source) => {
-                return FileResult::new(
-                    path.to_path_buf(),
-                    FileOutcome::Error {
-                        error: Error::Io {
-                            path: path.to_path_buf(),
-                            source,
-                        },
-                        duration: start.elapsed(),
-                    },
-                );
-            }
-        };
-
-        let file_size = metadata.len();
-        let max_size = self.config.max_input_size();
-
-        #[allow(clippy::cast_possible_truncation)]
-        let size = file_size as usize;
-
-        if file_size > max_size as u64 {
-            return FileResult::new(
+        let start = Instant::now();
+        let error_result = |error| {
+            FileResult::new(
                 path.to_path_buf(),
                 FileOutcome::Error {
-                    error: Error::InputTooLarge {
-                        size,
-                        max: max_size,
-                    },
+                    error,
                     duration: start.elapsed(),
                 },
-            );
-        }
+            )
+        };
 
-        let file_content = match self.reader.read(path) {
+        let (file_content, warning) = match self.read_checked(path) {
             Ok(c) => c,
-            Err(error) => {
-                return FileResult::new(
-                    path.to_path_buf(),
-                    FileOutcome::Error {
-                        error,
-                        duration: start.elapsed(),
-                    },
-                );
-            }
+            Err(error) => return error_result(error),
         };
 
+        let read_strategy = file_content.strategy();
         let content = match file_content.as_str() {
             Ok(s) => s,
-            Err(error) => {
-                return FileResult::new(
-                    path.to_path_buf(),
-                    FileOutcome::Error {
-                        error,
-                        duration: start.elapsed(),
-                    },
-                );
-            }
+            Err(error) => return error_result(error),
         };
+        let mut times = FormattingTimes {
+            read: start.elapsed(),
+            ..FormattingTimes::default()
+        };
+
+        // The formatter parses and emits in one pass; timing the parse on its
+        // own takes an extra pass, whose errors the formatter reports anyway.
+        if self.config.detailed_timing() {
+            let parse_start = Instant::now();
+            let _ = fast_yaml_core::Parser::parse_all(content);
+            times.parse = parse_start.elapsed();
+        }
 
+        let format_start = Instant::now();
         let formatted = match Emitter::format_with_config(content, emitter_config) {
             Ok(f) => f,
             Err(e) => {
-                return FileResult::new(
-                    path.to_path_buf(),
-                    FileOutcome::Error {
-                        error: Error::Format {
-                            message: format!("{}: {}", path.display(), e),
-                        },
-                        duration: start.elapsed(),
-                    },
-                );
+                return error_result(Error::Format {
+                    message: format!("{}: {}", path.display(), e),
+                });
             }
         };
+        times.format = format_start.elapsed();
 
-        let changed = content != formatted;
+        // A decoded file is rewritten even if formatting left the text as-is,
+        // since its bytes on disk are not UTF-8.
+        let changed = content != formatted || warning.is_some();
 
-        if changed && let Err(error) = Self::write_file_atomic(path, &formatted) {
-            return FileResult::new(
-                path.to_path_buf(),
-                FileOutcome::Error {
-                    error,
-                    duration: start.elapsed(),
-                },
-            );
+        if changed && !self.config.dry_run() {
+            let write_start = Instant::now();
+            if let Err(error) = Self::write_file_atomic(path, &formatted) {
+                return error_result(error);
+            }
+            times.write = write_start.elapsed();
         }
 
         let duration = start.elapsed();
@@ -259,31 +274,59 @@ impl FileProcessor {
         };
 
         FileResult::new(path.to_path_buf(), outcome)
+            .with_warning(warning)
+            .with_times(times)
+            .with_read_strategy(read_strategy)
     }
 
     /// Processes files in parallel using Rayon's `par_iter`
-    fn process_files_parallel<F, R>(&self, paths: &[PathBuf], f: &F) -> Vec<FileResult>
+    fn process_files_parallel<F, R>(
+        &self,
+        paths: &[PathBuf],
+        f: &F,
+        batch: &BatchState,
+    ) -> Vec<FileResult>
     where
         F: Fn(&Path, &str) -> Result<R> + Sync,
         R: Send,
     {
         paths
             .par_iter()
-            .map(|path| self.process_single_file(path, f))
+            .map(|path| {
+                let result = batch.run(path, || self.process_single_file(path, f));
+                self.report_progress(batch, paths.len());
+                result
+            })
             .collect()
     }
 
     /// Processes files sequentially without parallel overhead.
-    fn process_files_sequential<F, R>(&self, paths: &[PathBuf], f: &F) -> Vec<FileResult>
+    fn process_files_sequential<F, R>(
+        &self,
+        paths: &[PathBuf],
+        f: &F,
+        batch: &BatchState,
+    ) -> Vec<FileResult>
     where
         F: Fn(&Path, &str) -> Result<R>,
     {
         paths
             .iter()
-            .map(|path| self.process_single_file(path, f))
+            .map(|path| {
+                let result = batch.run(path, || self.process_single_file(path, f));
+                self.report_progress(batch, paths.len());
+                result
+            })
             .collect()
     }
 
+    /// Counts a completed file and passes the count to the progress callback
+    fn report_progress(&self, batch: &BatchState, total: usize) {
+        if let Some(callback) = &self.config.progress_callback {
+            callback(batch.done.fetch_add(1, Ordering::Relaxed) + 1, total);
+        }
+    }
+
     /// Processes a single file and returns the result
     fn process_single_file<F, R>(&self, path: &Path, f: &F) -> FileResult
     where
@@ -292,9 +335,11 @@ impl FileProcessor {
         let start = Instant::now();
 
         match self.process_file_content(path, f) {
-            Ok(()) => {
+            Ok((read_strategy, warning)) => {
                 let duration = start.elapsed();
                 FileResult::new(path.to_path_buf(), FileOutcome::Success { duration })
+                    .with_warning(warning)
+                    .with_read_strategy(read_strategy)
             }
             Err(error) => FileResult::new(
                 path.to_path_buf(),
@@ -306,11 +351,25 @@ impl FileProcessor {
         }
     }
 
-    /// Process file content with given function
-    fn process_file_content<F, R>(&self, path: &Path, f: &F) -> Result<()>
+    /// Process file content with given function, returning the read strategy
+    /// and any encoding warning
+    fn process_file_content<F, R>(
+        &self,
+        path: &Path,
+        f: &F,
+    ) -> Result<(ReadStrategy, Option<Error>)>
     where
         F: Fn(&Path, &str) -> Result<R>,
     {
+        let (file_content, warning) = self.read_checked(path)?;
+        let content = file_content.as_str()?;
+
+        f(path, content)?;
+        Ok((file_content.strategy(), warning))
+    }
+
+    /// Reads a file after checking it against the input size limit
+    fn read_checked(&self, path: &Path) -> Result<(FileContent, Option<Error>)> {
         let metadata = std::fs::metadata(path).map_err(|source| Error::Io {
             path: path.to_path_buf(),
             source,
@@ -328,11 +387,8 @@ impl FileProcessor {
             });
         }
 
-        let file_content = self.reader.read(path)?;
-        let content = file_content.as_str()?;
-
-        f(path, content)?;
-        Ok(())
+        self.reader
+            .read_with_encoding_fallback(path, self.config.encoding_fallback())
     }
 
     /// Writes content to file atomically using secure temp file + rename.
@@ -397,10 +453,49 @@ impl Default for FileProcessor {
     }
 }
 
+/// Shared state of one batch: completed files and the error limit.
+struct BatchState {
+    /// Files completed so far, including skipped files
+    done: AtomicUsize,
+    /// Failed files so far
+    errors: AtomicUsize,
+    max_errors: Option<usize>,
+    /// Set once `max_errors` is reached; checked before each file starts
+    cancelled: AtomicBool,
+}
+
+impl BatchState {
+    const fn new(max_errors: Option<usize>) -> Self {
+        Self {
+            done: AtomicUsize::new(0),
+            errors: AtomicUsize::new(0),
+            max_errors,
+            cancelled: AtomicBool::new(false),
+        }
+    }
+
+    /// Runs `process` for `path` unless the batch was cancelled, recording a failure
+    fn run(&self, path: &Path, process: impl FnOnce() -> FileResult) -> FileResult {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return FileResult::new(path.to_path_buf(), FileOutcome::Skipped);
+        }
+        let result = process();
+        if let Some(max) = self.max_errors
+            && !result.is_success()
+            && self.errors.fetch_add(1, Ordering::Relaxed) + 1 >= max
+        {
+            self.cancelled.store(true, Ordering::Relaxed);
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::io::EncodingFallback;
     use std::fs;
+    use std::time::Duration;
     use tempfile::TempDir;
 
     fn create_test_file(dir: &TempDir, name: &str, content: &str) -> PathBuf {
@@ -509,6 +604,225 @@ mod tests {
         assert_eq!(result.total, 1);
     }
 
+    #[test]
+    fn test_progress_callback() {
+        use std::sync::{Arc, Mutex};
+
+        let dir = TempDir::new().unwrap();
+        let paths: Vec<_> = (0..6)
+            .map(|i| create_test_file(&dir, &format!("f{i}.yaml"), "key:  value\n"))
+            .collect();
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&calls);
+        let config = Config::new().with_progress_callback(Some(Arc::new(move |done, total| {
+            recorded.lock().unwrap().push((done, total));
+        })));
+        let processor = FileProcessor::with_config(config);
+
+        let formatted = processor.format_files(&paths, &EmitterConfig::new());
+        assert_eq!(formatted.len(), 6);
+        let result = processor.format_in_place(&paths, &EmitterConfig::new());
+        assert_eq!(result.changed, 6);
+        let result = processor.parse_files(&paths);
+        assert!(result.is_success());
+
+        let mut calls = calls.lock().unwrap().clone();
+        calls.sort_unstable();
+        let expected: Vec<_> = (1..=6).flat_map(|done| [(done, 6); 3]).collect();
+        assert_eq!(calls, expected);
+    }
+
+    #[test]
+    fn test_max_errors_sequential() {
+        let dir = TempDir::new().unwrap();
+        let paths: Vec<_> = (0..3)
+            .map(|i| create_test_file(&dir, &format!("f{i}.yaml"), "key: [\n"))
+            .collect();
+
+        let processor = FileProcessor::with_config(Config::new().with_max_errors(Some(1)));
+        let result = processor.format_in_place(&paths, &EmitterConfig::new());
+
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.total, 1);
+        assert_eq!(result.skipped, paths[1..]);
+    }
+
+    #[test]
+    fn test_max_errors_stops_parallel_batch() {
+        let dir = TempDir::new().unwrap();
+        let paths: Vec<_> = (0..500)
+            .map(|i| create_test_file(&dir, &format!("f{i}.yaml"), "key: [\n"))
+            .collect();
+
+        let processor = FileProcessor::with_config(Config::new().with_max_errors(Some(10)));
+        let result = processor.parse_files(&paths);
+
+        // Files already running on other workers finish after the limit is hit
+        let workers = rayon::current_num_threads();
+        assert!(result.failed >= 10, "failed {}", result.failed);
+        assert!(
+            result.failed <= 10 + 2 * workers,
+            "failed {}",
+            result.failed
+        );
+        assert_eq!(result.total, result.failed);
+        assert_eq!(result.total + result.skipped.len(), 500);
+    }
+
+    #[test]
+    fn test_format_in_place_with_progress() {
+        use std::sync::Mutex;
+
+        let dir = TempDir::new().unwrap();
+        let changed = create_test_file(&dir, "changed.yaml", "key:  value\n");
+        let unchanged = create_test_file(&dir, "unchanged.yaml", "key: value\n");
+        let invalid = create_test_file(&dir, "invalid.yaml", "key: [\n");
+
+        let seen = Mutex::new(Vec::new());
+        let result = FileProcessor::new().format_in_place_with_progress(
+            &[changed.clone(), unchanged.clone(), invalid.clone()],
+            &EmitterConfig::new(),
+            |file| {
+                seen.lock().unwrap().push((
+                    file.path.clone(),
+                    file.outcome.was_changed(),
+                    file.is_success(),
+                ));
+            },
+        );
+
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort();
+        assert_eq!(
+            seen,
+            [
+                (changed, true, true),
+                (invalid, false, false),
+                (unchanged, false, true),
+            ]
+        );
+        assert_eq!(result.total, 3);
+        assert_eq!(result.changed, 1);
+        assert_eq!(result.failed, 1);
+    }
+
+    #[test]
+    fn test_format_in_place_dry_run() {
+        let dir = TempDir::new().unwrap();
+        let changed = create_test_file(&dir, "changed.yaml", "key:  value\n");
+        let unchanged = create_test_file(&dir, "unchanged.yaml", "key: value\n");
+
+        let result = FileProcessor::with_config(Config::new().with_dry_run(true))
+            .format_in_place(&[changed.clone(), unchanged], &EmitterConfig::new());
+
+        assert_eq!(result.total, 2);
+        assert_eq!(result.changed, 1);
+        assert_eq!(fs::read_to_string(&changed).unwrap(), "key:  value\n");
+    }
+
+    #[test]
+    fn test_format_in_place_records_times() {
+        let dir = TempDir::new().unwrap();
+        let changed = create_test_file(&dir, "changed.yaml", "key:  value\n");
+        let unchanged = create_test_file(&dir, "unchanged.yaml", "key: value\n");
+
+        let result = FileProcessor::new()
+            .format_in_place(&[changed.clone(), unchanged.clone()], &EmitterConfig::new());
+
+        assert_eq!(result.timings.len(), 2);
+        let times_of = |path: &PathBuf| {
+            result
+                .timings
+                .iter()
+                .find(|(p, _)| p == path)
+                .map(|(_, times)| *times)
+                .unwrap()
+        };
+        assert!(times_of(&changed).write > Duration::ZERO);
+        assert_eq!(times_of(&unchanged).write, Duration::ZERO);
+        // Parsing is only timed separately on request
+        assert_eq!(times_of(&changed).parse, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_format_in_place_detailed_timing_measures_parse() {
+        let dir = TempDir::new().unwrap();
+        let path = create_test_file(&dir, "test.yaml", "key:  value\n");
+
+        let config = Config::new().with_detailed_timing(true);
+        let result = FileProcessor::with_config(config)
+            .format_in_place(std::slice::from_ref(&path), &EmitterConfig::new());
+
+        let (_, times) = &result.timings[0];
+        assert!(times.parse > Duration::ZERO);
+        assert_eq!(
+            times.total(),
+            times.read + times.parse + times.format + times.write
+        );
+    }
+
+    #[test]
+    fn test_format_in_place_error_has_no_times() {
+        let dir = TempDir::new().unwrap();
+        let path = create_test_file(&dir, "bad.yaml", "key: [unclosed\n");
+
+        let result = FileProcessor::new()
+            .format_in_place(std::slice::from_ref(&path), &EmitterConfig::new());
+
+        assert_eq!(result.failed, 1);
+        assert!(result.timings.is_empty());
+    }
+
+    #[test]
+    fn test_parse_files_latin1_strict_fails() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("latin1.yaml");
+        fs::write(&path, b"name: Jos\xe9\n").unwrap();
+
+        let result = FileProcessor::new().parse_files(&[path]);
+
+        assert_eq!(result.failed, 1);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_parse_files_latin1_fallback_warns() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("latin1.yaml");
+        fs::write(&path, b"name: Jos\xe9\n").unwrap();
+
+        let config = Config::new().with_encoding_fallback(EncodingFallback::Latin1Fallback);
+        let result = FileProcessor::with_config(config).parse_files(std::slice::from_ref(&path));
+
+        assert!(result.is_success());
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].0, path);
+        assert!(matches!(
+            result.warnings[0].1,
+            Error::EncodingWarning {
+                from_encoding: "ISO-8859-1",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_format_in_place_latin1_writes_utf8() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("latin1.yaml");
+        fs::write(&path, b"name:   Jos\xe9\n").unwrap();
+
+        let config = Config::new().with_encoding_fallback(EncodingFallback::Latin1Fallback);
+        let result = FileProcessor::with_config(config)
+            .format_in_place(std::slice::from_ref(&path), &EmitterConfig::new());
+
+        assert!(result.is_success());
+        assert_eq!(result.changed, 1);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "name: Jos\u{e9}\n");
+    }
+
     #[test]
     fn test_atomic_write() {
         let dir = TempDir::new().unwrap();
@@ -670,6 +984,39 @@ mod tests {
         assert!(result.is_success());
     }
 
+    #[test]
+    fn test_format_in_place_records_read_strategy() {
+        use std::sync::Mutex;
+
+        let dir = TempDir::new().unwrap();
+        let small = create_test_file(&dir, "small.yaml", "key: value\n");
+        let large = create_test_file(&dir, "large.yaml", &"key: value\n".repeat(200));
+        let invalid = create_test_file(&dir, "invalid.yaml", "key: [\n");
+
+        let processor = FileProcessor::with_config(Config::new().with_mmap_threshold(1024));
+        let seen = Mutex::new(Vec::new());
+        processor.format_in_place_with_progress(
+            &[small.clone(), large.clone(), invalid.clone()],
+            &EmitterConfig::new(),
+            |file| {
+                seen.lock()
+                    .unwrap()
+                    .push((file.path.clone(), file.read_strategy));
+            },
+        );
+
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            seen,
+            [
+                (invalid, None),
+                (large, Some(ReadStrategy::MemoryMapped)),
+                (small, Some(ReadStrategy::InMemory)),
+            ]
+        );
+    }
+
     #[test]
     fn test_partial_batch_failure() {
         let dir = TempDir::new().unwrap();
@@ -760,6 +1107,21 @@ mod tests {
         assert!(results[1].1.is_ok());
     }
 
+    #[test]
+    fn test_format_files_with_original() {
+        let dir = TempDir::new().unwrap();
+        let paths = vec![create_test_file(&dir, "test.yaml", "key:   value\n")];
+
+        let processor = FileProcessor::new();
+        let emitter_config = EmitterConfig::new();
+        let results = processor.format_files_with_original(&paths, &emitter_config);
+
+        assert_eq!(results.len(), 1);
+        let (original, formatted) = results[0].1.as_ref().unwrap();
+        assert_eq!(original, "key:   value\n");
+        assert_eq!(formatted, "key: value\n");
+    }
+
     #[test]
     fn test_default_equals_new() {
         let processor1 = FileProcessor::new();
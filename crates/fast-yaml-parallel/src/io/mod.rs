@@ -13,6 +13,8 @@
 //!
 //! - [`SmartReader`] - Adaptive file reader with configurable threshold
 //! - [`FileContent`] - Content container (String or Mmap)
+//! - [`ReadStrategy`] - Which of the two strategies was used for a file
+//! - [`EncodingFallback`] - Handling of files that are not valid UTF-8
 //!
 //! # Examples
 //!
@@ -29,4 +31,4 @@
 
 pub mod reader;
 
-pub use reader::{FileContent, SmartReader};
+pub use reader::{EncodingFallback, FileContent, ReadStrategy, SmartReader};
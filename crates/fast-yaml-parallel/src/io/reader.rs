@@ -10,6 +10,79 @@ use crate::error::{Error, Result};
 /// Memory-map threshold constant: 512KB
 const MMAP_THRESHOLD: u64 = 512 * 1024;
 
+/// How to handle files that are not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_parallel::{Config, EncodingFallback};
+///
+/// let config = Config::new().with_encoding_fallback(EncodingFallback::Latin1Fallback);
+/// assert_eq!(config.encoding_fallback(), EncodingFallback::Latin1Fallback);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EncodingFallback {
+    /// Reject files that are not valid UTF-8 (default).
+    #[default]
+    Strict,
+    /// Decode invalid UTF-8 files as ISO-8859-1 (Latin-1).
+    ///
+    /// Uses the WHATWG decoder for the `iso-8859-1` label, which maps bytes
+    /// 0x80-0x9F as windows-1252.
+    Latin1Fallback,
+    /// Replace invalid UTF-8 sequences with U+FFFD.
+    ReplaceInvalid,
+}
+
+impl EncodingFallback {
+    /// Decodes non-UTF-8 `bytes`, returning the text and the name of the
+    /// encoding it was decoded from, or `None` in strict mode.
+    fn decode(self, bytes: &[u8]) -> Option<(String, &'static str)> {
+        match self {
+            Self::Strict => None,
+            Self::Latin1Fallback => {
+                let (text, _) = encoding_rs::WINDOWS_1252.decode_without_bom_handling(bytes);
+                Some((text.into_owned(), "ISO-8859-1"))
+            }
+            Self::ReplaceInvalid => Some((
+                String::from_utf8_lossy(bytes).into_owned(),
+                "UTF-8 with invalid bytes replaced",
+            )),
+        }
+    }
+}
+
+/// Strategy [`SmartReader`] used to read a file.
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_parallel::{ReadStrategy, SmartReader};
+///
+/// # let temp_file = tempfile::NamedTempFile::new().unwrap();
+/// # std::fs::write(temp_file.path(), "key: value\n").unwrap();
+/// let content = SmartReader::new().read(temp_file.path())?;
+/// assert_eq!(content.strategy(), ReadStrategy::InMemory);
+/// assert_eq!(ReadStrategy::MemoryMapped.to_string(), "mmap");
+/// # Ok::<(), fast_yaml_parallel::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadStrategy {
+    /// Content read into a heap-allocated `String`
+    InMemory,
+    /// Content accessed through a memory map
+    MemoryMapped,
+}
+
+impl std::fmt::Display for ReadStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::InMemory => "in-memory",
+            Self::MemoryMapped => "mmap",
+        })
+    }
+}
+
 /// File content holder that abstracts over in-memory strings and memory-mapped files.
 #[derive(Debug)]
 pub enum FileContent {
@@ -36,6 +109,17 @@ impl FileContent {
         matches!(self, Self::Mmap(_))
     }
 
+    /// Returns the strategy the content was read with.
+    ///
+    /// Content decoded by an encoding fallback is always
+    /// [`ReadStrategy::InMemory`], even if the file was memory-mapped first.
+    pub const fn strategy(&self) -> ReadStrategy {
+        match self {
+            Self::String(_) => ReadStrategy::InMemory,
+            Self::Mmap(_) => ReadStrategy::MemoryMapped,
+        }
+    }
+
     /// Returns the size of the content in bytes
     pub fn len(&self) -> usize {
         match self {
@@ -96,6 +180,20 @@ impl SmartReader {
         }
     }
 
+    /// Returns the size in bytes from which files are memory-mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_parallel::SmartReader;
+    ///
+    /// assert_eq!(SmartReader::new().threshold(), 512 * 1024);
+    /// assert_eq!(SmartReader::with_threshold(4096).threshold(), 4096);
+    /// ```
+    pub const fn threshold(&self) -> u64 {
+        self.mmap_threshold
+    }
+
     /// Reads file content using the optimal strategy based on file size.
     ///
     /// Returns `FileContent` and automatically chooses between:
@@ -138,6 +236,70 @@ impl SmartReader {
         }
     }
 
+    /// Reads a file like [`read`](Self::read), decoding content that is not
+    /// valid UTF-8 according to `fallback`.
+    ///
+    /// The returned content is always valid UTF-8. When a fallback decoding was
+    /// used, the second element is an [`Error::EncodingWarning`] naming the
+    /// source encoding; it is informational and does not fail the read.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`read`](Self::read). With
+    /// [`EncodingFallback::Strict`], invalid UTF-8 is an error as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_parallel::{EncodingFallback, SmartReader};
+    ///
+    /// # let temp_file = tempfile::NamedTempFile::new().unwrap();
+    /// std::fs::write(temp_file.path(), b"name: Jos\xe9\n").unwrap();
+    /// let reader = SmartReader::new();
+    /// let (content, warning) =
+    ///     reader.read_with_encoding_fallback(temp_file.path(), EncodingFallback::Latin1Fallback)?;
+    /// assert_eq!(content.as_str()?, "name: Jos\u{e9}\n");
+    /// assert!(warning.is_some());
+    /// # Ok::<(), fast_yaml_parallel::Error>(())
+    /// ```
+    pub fn read_with_encoding_fallback(
+        &self,
+        path: &Path,
+        fallback: EncodingFallback,
+    ) -> Result<(FileContent, Option<Error>)> {
+        let (text, from_encoding) = match self.read(path) {
+            // Small files are read with `read_to_string`, which rejects invalid UTF-8
+            // as `InvalidData`; re-read the raw bytes to decode them.
+            Err(Error::Io {
+                path: io_path,
+                source,
+            }) if source.kind() == std::io::ErrorKind::InvalidData => {
+                let bytes = std::fs::read(path).map_err(|source| Error::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+                fallback.decode(&bytes).ok_or(Error::Io {
+                    path: io_path,
+                    source,
+                })?
+            }
+            Err(error) => return Err(error),
+            Ok(FileContent::Mmap(mmap)) => match std::str::from_utf8(&mmap) {
+                Ok(_) => return Ok((FileContent::Mmap(mmap), None)),
+                Err(source) => fallback.decode(&mmap).ok_or(Error::Utf8 { source })?,
+            },
+            Ok(content) => return Ok((content, None)),
+        };
+
+        Ok((
+            FileContent::String(text),
+            Some(Error::EncodingWarning {
+                path: path.to_path_buf(),
+                from_encoding,
+            }),
+        ))
+    }
+
     /// Reads file into memory as a String
     fn read_string(path: &Path) -> Result<FileContent> {
         let content = std::fs::read_to_string(path).map_err(|source| Error::Io {
@@ -332,6 +494,7 @@ mod tests {
 
         // At threshold, should use mmap
         assert!(file_content.is_mmap());
+        assert_eq!(file_content.strategy(), ReadStrategy::MemoryMapped);
         assert_eq!(file_content.len(), 512 * 1024);
     }
 
@@ -348,6 +511,7 @@ mod tests {
 
         // Below threshold, should use String
         assert!(!file_content.is_mmap());
+        assert_eq!(file_content.strategy(), ReadStrategy::InMemory);
         assert_eq!(file_content.len(), 512 * 1024 - 1);
     }
 
@@ -486,4 +650,103 @@ mod tests {
             _ => panic!("expected Io error"),
         }
     }
+
+    const LATIN1_YAML: &[u8] = b"name: Jos\xe9\ncity: K\xf6ln\n";
+
+    #[test]
+    fn test_encoding_fallback_strict_rejects_latin1() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(LATIN1_YAML).unwrap();
+
+        let reader = SmartReader::new();
+        let result = reader.read_with_encoding_fallback(file.path(), EncodingFallback::Strict);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_encoding_fallback_latin1() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(LATIN1_YAML).unwrap();
+
+        let reader = SmartReader::new();
+        let (content, warning) = reader
+            .read_with_encoding_fallback(file.path(), EncodingFallback::Latin1Fallback)
+            .unwrap();
+
+        assert_eq!(
+            content.as_str().unwrap(),
+            "name: Jos\u{e9}\ncity: K\u{f6}ln\n"
+        );
+        match warning {
+            Some(Error::EncodingWarning {
+                path,
+                from_encoding,
+            }) => {
+                assert_eq!(path, file.path());
+                assert_eq!(from_encoding, "ISO-8859-1");
+            }
+            other => panic!("expected encoding warning, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_encoding_fallback_latin1_mmap() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(LATIN1_YAML).unwrap();
+
+        let reader = SmartReader::with_threshold(1);
+        let (content, warning) = reader
+            .read_with_encoding_fallback(file.path(), EncodingFallback::Latin1Fallback)
+            .unwrap();
+
+        assert!(!content.is_mmap());
+        assert_eq!(content.strategy(), ReadStrategy::InMemory);
+        assert_eq!(
+            content.as_str().unwrap(),
+            "name: Jos\u{e9}\ncity: K\u{f6}ln\n"
+        );
+        assert!(warning.is_some());
+    }
+
+    #[test]
+    fn test_encoding_fallback_replace_invalid() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(LATIN1_YAML).unwrap();
+
+        let reader = SmartReader::new();
+        let (content, warning) = reader
+            .read_with_encoding_fallback(file.path(), EncodingFallback::ReplaceInvalid)
+            .unwrap();
+
+        assert_eq!(
+            content.as_str().unwrap(),
+            "name: Jos\u{fffd}\ncity: K\u{fffd}ln\n"
+        );
+        assert!(matches!(warning, Some(Error::EncodingWarning { .. })));
+    }
+
+    #[test]
+    fn test_encoding_fallback_valid_utf8_has_no_warning() {
+        let mut file = NamedTempFile::new().unwrap();
+        writeln!(file, "name: Jos\u{e9}").unwrap();
+
+        for threshold in [1, MMAP_THRESHOLD] {
+            let reader = SmartReader::with_threshold(threshold);
+            let (content, warning) = reader
+                .read_with_encoding_fallback(file.path(), EncodingFallback::Latin1Fallback)
+                .unwrap();
+            assert_eq!(content.as_str().unwrap(), "name: Jos\u{e9}\n");
+            assert!(warning.is_none());
+        }
+    }
+
+    #[test]
+    fn test_encoding_fallback_missing_file() {
+        let reader = SmartReader::new();
+        let result = reader.read_with_encoding_fallback(
+            Path::new("/nonexistent/file.yaml"),
+            EncodingFallback::Latin1Fallback,
+        );
+        assert!(matches!(result, Err(Error::Io { .. })));
+    }
 }
@@ -79,14 +79,14 @@ mod io;
 mod result;
 
 // Core public API
-pub use config::Config;
+pub use config::{Config, ProgressCallback};
 pub use error::{Error, Result};
 pub use fast_yaml_core::Value;
 
 // File-level parallelism
 pub use files::FileProcessor;
-pub use io::{FileContent, SmartReader};
-pub use result::{BatchResult, FileOutcome, FileResult};
+pub use io::{EncodingFallback, FileContent, ReadStrategy, SmartReader};
+pub use result::{BatchResult, FileOutcome, FileResult, FormattingTimes, escape_xml};
 
 /// Parse multi-document YAML stream in parallel.
 ///
@@ -56,7 +56,8 @@ pub(crate) fn process_parallel(input: &str, config: &Config) -> Result<Vec<Value
 /// Determines if sequential processing is more efficient.
 ///
 /// Returns true when:
-/// - Single document (no parallelism benefit)
+/// - Fewer documents than [`Config::sequential_threshold_docs`] (single
+///   document by default, where parallelism has no benefit)
 /// - Total size is very small AND few documents (overhead exceeds benefit)
 /// - Workers explicitly set to 0
 fn should_use_sequential(chunks: &[Chunk<'_>], config: &Config) -> bool {
@@ -64,8 +65,8 @@ fn should_use_sequential(chunks: &[Chunk<'_>], config: &Config) -> bool {
         return true; // User requested sequential
     }
 
-    if chunks.len() <= 1 {
-        return true; // Single document
+    if chunks.len() < config.sequential_threshold_docs().max(2) {
+        return true; // Too few documents
     }
 
     // With global thread pool (no creation overhead), parallelism is beneficial
@@ -245,6 +246,24 @@ mod tests {
         assert!(!should_use_sequential(&chunks, &config));
     }
 
+    #[test]
+    fn test_should_use_sequential_below_doc_threshold() {
+        let large_content = "x".repeat(2048);
+        let chunks: Vec<_> = (0..3)
+            .map(|index| Chunk {
+                index,
+                content: &large_content,
+                offset: index * 2048,
+            })
+            .collect();
+
+        let config = Config::new().with_sequential_threshold_docs(4);
+        assert!(should_use_sequential(&chunks, &config));
+
+        let config = Config::new().with_sequential_threshold_docs(3);
+        assert!(!should_use_sequential(&chunks, &config));
+    }
+
     #[test]
     fn test_parse_sequential_error() {
         let chunks = vec![
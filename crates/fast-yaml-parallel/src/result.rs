@@ -1,9 +1,12 @@
 //! Result types for batch file processing.
 
+use std::collections::HashMap;
+use std::fmt::Write;
 use std::path::PathBuf;
 use std::time::Duration;
 
 use crate::error::Error;
+use crate::io::ReadStrategy;
 
 /// Outcome of processing a single file.
 #[derive(Debug)]
@@ -30,6 +33,9 @@ pub enum FileOutcome {
         /// Processing duration
         duration: Duration,
     },
+    /// File not processed because the batch reached
+    /// [`Config::with_max_errors`](crate::Config::with_max_errors)
+    Skipped,
 }
 
 impl FileOutcome {
@@ -45,6 +51,7 @@ impl FileOutcome {
             | Self::Changed { duration }
             | Self::Unchanged { duration }
             | Self::Error { duration, .. } => *duration,
+            Self::Skipped => Duration::ZERO,
         }
     }
 
@@ -54,6 +61,27 @@ impl FileOutcome {
     }
 }
 
+/// Time spent in each stage of formatting a single file in place.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FormattingTimes {
+    /// Checking the size and reading the file
+    pub read: Duration,
+    /// Parsing, only measured with
+    /// [`Config::with_detailed_timing`](crate::Config::with_detailed_timing)
+    pub parse: Duration,
+    /// Formatting the content
+    pub format: Duration,
+    /// Writing the file back (zero if the content was unchanged)
+    pub write: Duration,
+}
+
+impl FormattingTimes {
+    /// Returns the sum of all stages
+    pub fn total(&self) -> Duration {
+        self.read + self.parse + self.format + self.write
+    }
+}
+
 /// Result for a single file with path context.
 #[derive(Debug)]
 pub struct FileResult {
@@ -61,12 +89,45 @@ pub struct FileResult {
     pub path: PathBuf,
     /// Processing outcome
     pub outcome: FileOutcome,
+    /// Non-fatal problems, such as [`Error::EncodingWarning`]
+    pub warnings: Vec<Error>,
+    /// Per-stage timings, recorded when formatting in place
+    pub times: Option<FormattingTimes>,
+    /// How the file was read, if it was read successfully
+    pub read_strategy: Option<ReadStrategy>,
 }
 
 impl FileResult {
     /// Creates a new `FileResult`
     pub const fn new(path: PathBuf, outcome: FileOutcome) -> Self {
-        Self { path, outcome }
+        Self {
+            path,
+            outcome,
+            warnings: Vec::new(),
+            times: None,
+            read_strategy: None,
+        }
+    }
+
+    /// Attaches per-stage timings
+    #[must_use]
+    pub const fn with_times(mut self, times: FormattingTimes) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    /// Records how the file was read
+    #[must_use]
+    pub const fn with_read_strategy(mut self, strategy: ReadStrategy) -> Self {
+        self.read_strategy = Some(strategy);
+        self
+    }
+
+    /// Attaches a non-fatal warning, if any
+    #[must_use]
+    pub fn with_warning(mut self, warning: Option<Error>) -> Self {
+        self.warnings.extend(warning);
+        self
     }
 
     /// Returns true if processing was successful
@@ -90,6 +151,17 @@ pub struct BatchResult {
     pub duration: Duration,
     /// List of errors with file paths
     pub errors: Vec<(PathBuf, Error)>,
+    /// Paths of all processed files, in processing order
+    pub files: Vec<PathBuf>,
+    /// Non-fatal warnings with file paths (the files were still processed)
+    pub warnings: Vec<(PathBuf, Error)>,
+    /// Paths left out of processing, e.g. files above a size limit or files
+    /// not reached before the error limit.
+    ///
+    /// Skipped files are not counted in `total`.
+    pub skipped: Vec<PathBuf>,
+    /// Per-stage timings of files that recorded them, in processing order
+    pub timings: Vec<(PathBuf, FormattingTimes)>,
 }
 
 impl BatchResult {
@@ -101,13 +173,30 @@ impl BatchResult {
     /// Creates a `BatchResult` from a list of `FileResult`s
     pub fn from_results(results: Vec<FileResult>) -> Self {
         let start = std::time::Instant::now();
-        let total = results.len();
         let mut success = 0;
         let mut changed = 0;
         let mut failed = 0;
-        let mut errors = Vec::with_capacity(total);
+        let mut errors = Vec::new();
+        let mut files = Vec::with_capacity(results.len());
+        let mut warnings = Vec::new();
+        let mut timings = Vec::new();
+        let mut skipped = Vec::new();
 
         for result in results {
+            if matches!(result.outcome, FileOutcome::Skipped) {
+                skipped.push(result.path);
+                continue;
+            }
+            files.push(result.path.clone());
+            if let Some(times) = result.times {
+                timings.push((result.path.clone(), times));
+            }
+            warnings.extend(
+                result
+                    .warnings
+                    .into_iter()
+                    .map(|warning| (result.path.clone(), warning)),
+            );
             match result.outcome {
                 FileOutcome::Success { .. } | FileOutcome::Unchanged { .. } => {
                     success += 1;
@@ -120,18 +209,23 @@ impl BatchResult {
                     failed += 1;
                     errors.push((result.path, error));
                 }
+                FileOutcome::Skipped => {}
             }
         }
 
         let duration = start.elapsed();
 
         Self {
-            total,
+            total: files.len(),
             success,
             changed,
             failed,
             duration,
             errors,
+            files,
+            warnings,
+            skipped,
+            timings,
         }
     }
 
@@ -140,6 +234,18 @@ impl BatchResult {
         self.failed == 0
     }
 
+    /// Returns up to `n` files with the highest total timing, slowest first
+    pub fn slowest(&self, n: usize) -> Vec<(&PathBuf, &FormattingTimes)> {
+        let mut slowest: Vec<_> = self
+            .timings
+            .iter()
+            .map(|(path, times)| (path, times))
+            .collect();
+        slowest.sort_by(|a, b| b.1.total().cmp(&a.1.total()).then_with(|| a.0.cmp(b.0)));
+        slowest.truncate(n);
+        slowest
+    }
+
     /// Calculates files processed per second
     #[allow(clippy::cast_precision_loss)]
     pub fn files_per_second(&self) -> f64 {
@@ -150,6 +256,111 @@ impl BatchResult {
             0.0
         }
     }
+
+    /// Renders the result as a `JUnit` XML report.
+    ///
+    /// Each processed file becomes a `<testcase>`; failed files carry a
+    /// `<failure>` element with the error message, and skipped files a
+    /// `<skipped/>` element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fast_yaml_parallel::BatchResult;
+    ///
+    /// let xml = BatchResult::new().to_junit_xml("fy format");
+    /// assert!(xml.contains(r#"<testsuite name="fy format" tests="0""#));
+    /// ```
+    pub fn to_junit_xml(&self, suite_name: &str) -> String {
+        let failures: HashMap<&PathBuf, &Error> = self
+            .errors
+            .iter()
+            .map(|(path, error)| (path, error))
+            .collect();
+        let suite_name = escape_xml(suite_name);
+        let tests = self.total + self.skipped.len();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            r#"<testsuites name="{suite_name}" tests="{tests}" failures="{}" errors="0" time="{:.3}">"#,
+            self.failed,
+            self.duration.as_secs_f64()
+        );
+        let _ = writeln!(
+            xml,
+            r#"  <testsuite name="{suite_name}" tests="{tests}" failures="{}" errors="0" skipped="{}" time="{:.3}">"#,
+            self.failed,
+            self.skipped.len(),
+            self.duration.as_secs_f64()
+        );
+
+        for path in &self.files {
+            let name = escape_xml(&path.display().to_string());
+            match failures.get(path) {
+                Some(error) => {
+                    let message = escape_xml(&error.to_string());
+                    let _ = writeln!(
+                        xml,
+                        r#"    <testcase name="{name}" classname="{suite_name}">"#
+                    );
+                    let _ = writeln!(
+                        xml,
+                        r#"      <failure message="{message}">{message}</failure>"#
+                    );
+                    xml.push_str("    </testcase>\n");
+                }
+                None => {
+                    let _ = writeln!(
+                        xml,
+                        r#"    <testcase name="{name}" classname="{suite_name}"/>"#
+                    );
+                }
+            }
+        }
+
+        for path in &self.skipped {
+            let name = escape_xml(&path.display().to_string());
+            let _ = writeln!(
+                xml,
+                r#"    <testcase name="{name}" classname="{suite_name}">"#
+            );
+            xml.push_str("      <skipped/>\n    </testcase>\n");
+        }
+
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+}
+
+/// Escapes text for use in XML content or a quoted attribute value.
+///
+/// The five predefined entities are escaped, and control characters that
+/// XML 1.0 does not allow (anything but tab, line feed and carriage return)
+/// are replaced with U+FFFD.
+///
+/// # Examples
+///
+/// ```
+/// use fast_yaml_parallel::escape_xml;
+///
+/// assert_eq!(escape_xml("a < b && c"), "a &lt; b &amp;&amp; c");
+/// ```
+pub fn escape_xml(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\t' | '\n' | '\r' => out.push(ch),
+            c if c.is_control() && c < '\u{7f}' => out.push(char::REPLACEMENT_CHARACTER),
+            _ => out.push(ch),
+        }
+    }
+    out
 }
 
 #[cfg(test)]
@@ -257,9 +468,73 @@ mod tests {
         assert_eq!(batch.changed, 1);
         assert_eq!(batch.failed, 1);
         assert_eq!(batch.errors.len(), 1);
+        assert_eq!(batch.files.len(), 4);
         assert!(!batch.is_success());
     }
 
+    #[test]
+    fn test_batch_result_to_junit_xml() {
+        let results = vec![
+            FileResult::new(
+                PathBuf::from("/test/ok.yaml"),
+                FileOutcome::Unchanged {
+                    duration: Duration::from_millis(1),
+                },
+            ),
+            FileResult::new(
+                PathBuf::from("/test/bad.yaml"),
+                FileOutcome::Error {
+                    error: Error::Format {
+                        message: "unexpected <eof> & \"more\"".to_string(),
+                    },
+                    duration: Duration::from_millis(1),
+                },
+            ),
+        ];
+
+        let xml = BatchResult::from_results(results).to_junit_xml("fy format");
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains(
+            r#"<testsuite name="fy format" tests="2" failures="1" errors="0" skipped="0""#
+        ));
+        assert!(xml.contains(r#"<testcase name="/test/ok.yaml" classname="fy format"/>"#));
+        assert!(xml.contains(r#"<testcase name="/test/bad.yaml" classname="fy format">"#));
+        assert!(xml.contains(
+            r#"<failure message="format error: unexpected &lt;eof&gt; &amp; &quot;more&quot;">"#
+        ));
+        assert!(xml.ends_with("  </testsuite>\n</testsuites>\n"));
+    }
+
+    #[test]
+    fn test_to_junit_xml_skipped() {
+        let results = vec![FileResult::new(
+            PathBuf::from("/test/ok.yaml"),
+            FileOutcome::Unchanged {
+                duration: Duration::from_millis(1),
+            },
+        )];
+        let mut batch = BatchResult::from_results(results);
+        batch.skipped.push(PathBuf::from("/test/huge.yaml"));
+
+        let xml = batch.to_junit_xml("fy format");
+        assert!(xml.contains(
+            r#"<testsuite name="fy format" tests="2" failures="0" errors="0" skipped="1""#
+        ));
+        assert!(xml.contains(
+            "    <testcase name=\"/test/huge.yaml\" classname=\"fy format\">\n      <skipped/>\n    </testcase>\n"
+        ));
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("a<b>&'\""), "a&lt;b&gt;&amp;&apos;&quot;");
+        assert_eq!(escape_xml("plain"), "plain");
+        assert_eq!(
+            escape_xml("a\tb\nc\u{0}d\u{1b}"),
+            "a\tb\nc\u{fffd}d\u{fffd}"
+        );
+    }
+
     #[test]
     fn test_batch_result_is_success() {
         let mut batch = BatchResult::new();
@@ -269,6 +544,55 @@ mod tests {
         assert!(!batch.is_success());
     }
 
+    #[test]
+    fn test_formatting_times_total() {
+        let times = FormattingTimes {
+            read: Duration::from_millis(1),
+            parse: Duration::from_millis(2),
+            format: Duration::from_millis(3),
+            write: Duration::from_millis(4),
+        };
+        assert_eq!(times.total(), Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_batch_result_slowest() {
+        let timed = |path: &str, format_ms| {
+            FileResult::new(
+                PathBuf::from(path),
+                FileOutcome::Success {
+                    duration: Duration::from_millis(format_ms),
+                },
+            )
+            .with_times(FormattingTimes {
+                format: Duration::from_millis(format_ms),
+                ..FormattingTimes::default()
+            })
+        };
+        let results = vec![
+            timed("/test/fast.yaml", 1),
+            timed("/test/slow.yaml", 30),
+            FileResult::new(
+                PathBuf::from("/test/untimed.yaml"),
+                FileOutcome::Success {
+                    duration: Duration::from_millis(100),
+                },
+            ),
+            timed("/test/medium.yaml", 10),
+        ];
+
+        let batch = BatchResult::from_results(results);
+        assert_eq!(batch.timings.len(), 3);
+
+        let slowest: Vec<_> = batch
+            .slowest(2)
+            .into_iter()
+            .map(|(path, _)| path.to_str().unwrap())
+            .collect();
+        assert_eq!(slowest, ["/test/slow.yaml", "/test/medium.yaml"]);
+        assert_eq!(batch.slowest(10).len(), 3);
+    }
+
     #[test]
     fn test_batch_result_files_per_second() {
         let batch = BatchResult {
@@ -278,6 +602,10 @@ mod tests {
             failed: 0,
             duration: Duration::from_secs(2),
             errors: vec![],
+            files: vec![],
+            warnings: vec![],
+            skipped: vec![],
+            timings: vec![],
         };
         assert!((batch.files_per_second() - 50.0).abs() < f64::EPSILON);
     }
@@ -291,6 +619,10 @@ mod tests {
             failed: 0,
             duration: Duration::from_secs(0),
             errors: vec![],
+            files: vec![],
+            warnings: vec![],
+            skipped: vec![],
+            timings: vec![],
         };
         assert!((batch.files_per_second() - 0.0).abs() < f64::EPSILON);
     }
@@ -405,6 +737,10 @@ mod tests {
             failed: 0,
             duration: Duration::from_nanos(1),
             errors: vec![],
+            files: vec![],
+            warnings: vec![],
+            skipped: vec![],
+            timings: vec![],
         };
 
         let fps = batch.files_per_second();
@@ -430,6 +766,10 @@ mod tests {
                 failed,
                 duration: Duration::from_secs(1),
                 errors: vec![],
+                files: vec![],
+                warnings: vec![],
+                skipped: vec![],
+                timings: vec![],
             };
 
             prop_assert_eq!(batch.total, batch.success + batch.failed);
@@ -449,6 +789,10 @@ mod tests {
                 failed,
                 duration: Duration::from_secs(1),
                 errors: vec![],
+                files: vec![],
+                warnings: vec![],
+                skipped: vec![],
+                timings: vec![],
             };
 
             prop_assert!(batch.success <= batch.total);
@@ -467,6 +811,10 @@ mod tests {
                 failed: 0,
                 duration: Duration::from_millis(duration_ms),
                 errors: vec![],
+                files: vec![],
+                warnings: vec![],
+                skipped: vec![],
+                timings: vec![],
             };
 
             let fps = batch.files_per_second();
@@ -490,6 +838,10 @@ mod tests {
                 failed: total.saturating_sub(success),
                 duration: Duration::from_secs(1),
                 errors: vec![],
+                files: vec![],
+                warnings: vec![],
+                skipped: vec![],
+                timings: vec![],
             };
 
             prop_assert!(batch.changed <= batch.success);
@@ -517,6 +869,10 @@ mod tests {
                 failed,
                 duration: Duration::from_secs(1),
                 errors,
+                files: vec![],
+                warnings: vec![],
+                skipped: vec![],
+                timings: vec![],
             };
 
             prop_assert_eq!(batch.errors.len(), batch.failed);
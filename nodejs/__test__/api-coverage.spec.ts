@@ -11,11 +11,13 @@ import {
   loadAll,
   Mark,
   Schema,
+  SPEC_URL,
   safeDump,
   safeDumpAll,
   safeLoad,
   safeLoadAll,
   version,
+  YAML_VERSION,
 } from '../index';
 
 describe('API Coverage - All Functions', () => {
@@ -33,6 +35,13 @@ describe('API Coverage - All Functions', () => {
     });
   });
 
+  describe('YAML spec constants', () => {
+    it('should export the supported YAML spec version', () => {
+      expect(YAML_VERSION).toBe('1.2.2');
+      expect(SPEC_URL).toBe('https://yaml.org/spec/1.2.2/');
+    });
+  });
+
   describe('safeLoad() - all variations', () => {
     it('should parse primitive values', () => {
       expect(safeLoad('string_value')).toBe('string_value');
@@ -282,39 +291,41 @@ describe('API Coverage - All Functions', () => {
       ];
       options.forEach((opt) => {
         const yaml = safeDump({ a: { b: 1 } }, opt);
-        expect(yaml).toBeTruthy();
+        expect(yaml).toBe(`a:\n${' '.repeat(opt.indent ?? 2)}b: 1\n`);
       });
     });
 
+    it('should clamp out-of-range indent', () => {
+      expect(safeDump({ a: { b: 1 } }, { indent: 20 })).toBe(`a:\n${' '.repeat(9)}b: 1\n`);
+    });
+
     it('should work with width option', () => {
-      const options: DumpOptions[] = [
-        { width: 20 },
-        { width: 40 },
-        { width: 80 },
-        { width: 120 },
-        { width: 200 },
-      ];
-      options.forEach((opt) => {
-        const yaml = safeDump({ key: 'value' }, opt);
-        expect(yaml).toBeTruthy();
-      });
+      const data = { text: 'lorem ipsum dolor sit amet consectetur adipiscing elit sed do' };
+      expect(safeDump(data, { width: 200 })).toBe(`text: ${data.text}\n`);
+
+      const folded = safeDump(data, { width: 30 });
+      expect(folded).toContain('>-');
+      expect(safeLoad(folded)).toEqual(data);
     });
 
-    it('should work with default_flow_style option', () => {
+    it('should work with defaultFlowStyle option', () => {
       const data = { items: [1, 2, 3] };
-      expect(safeDump(data, { default_flow_style: true })).toBeTruthy();
-      expect(safeDump(data, { default_flow_style: false })).toBeTruthy();
+      expect(safeDump(data, { defaultFlowStyle: true })).toContain('[1, 2, 3]');
+      expect(safeDump(data, { defaultFlowStyle: false })).toContain('- 1');
     });
 
-    it('should work with explicit_start option', () => {
-      expect(safeDump({ key: 'value' }, { explicit_start: true })).toBeTruthy();
-      expect(safeDump({ key: 'value' }, { explicit_start: false })).toBeTruthy();
+    it('should work with explicitStart option', () => {
+      expect(safeDump({ key: 'value' }, { explicitStart: true })).toBe('---\nkey: value\n');
+      expect(safeDump({ key: 'value' }, { explicitStart: false })).toBe('key: value\n');
     });
 
-    it('should work with allow_unicode option', () => {
+    it('should work with allowUnicode option', () => {
       const data = { text: 'Hello 世界' };
-      expect(safeDump(data, { allow_unicode: true })).toContain('世界');
-      expect(safeDump(data, { allow_unicode: false })).toBeTruthy();
+      expect(safeDump(data, { allowUnicode: true })).toContain('世界');
+
+      const escaped = safeDump(data, { allowUnicode: false });
+      expect(escaped).toBe('text: "Hello \\u4E16\\u754C"\n');
+      expect(safeLoad(escaped)).toEqual(data);
     });
 
     it('should work with all options combined', () => {
@@ -322,12 +333,12 @@ describe('API Coverage - All Functions', () => {
         sortKeys: true,
         indent: 4,
         width: 100,
-        default_flow_style: false,
-        explicit_start: true,
-        allow_unicode: true,
+        defaultFlowStyle: false,
+        explicitStart: true,
+        allowUnicode: false,
       };
-      const yaml = safeDump({ z: 1, a: 2 }, options);
-      expect(yaml).toBeTruthy();
+      const yaml = safeDump({ z: { y: 'ü' }, a: 2 }, options);
+      expect(yaml).toBe('---\na: 2\nz:\n    y: "\\u00FC"\n');
     });
   });
 
@@ -373,7 +384,7 @@ describe('API Coverage - All Functions', () => {
         sortKeys: true,
         indent: 2,
         width: 80,
-        explicit_start: true,
+        explicitStart: true,
       };
       const yaml = safeDumpAll(
         [
@@ -58,6 +58,12 @@ describe('Batch Processing', () => {
       expect(result.failed).toBe(0);
     });
 
+    it('should expand glob patterns', () => {
+      const result = processFiles([path.join(tmpDir, 'file*.yaml')]);
+      expect(result.total).toBe(5);
+      expect(result.success).toBe(5);
+    });
+
     it('should accept config', () => {
       const config: BatchConfig = { workers: 2 };
       const result = processFiles(testFiles, config);
@@ -107,6 +113,16 @@ describe('Batch Processing', () => {
       expect(results).toHaveLength(5);
     });
 
+    it('should return a diff for changed files', () => {
+      const unformattedPath = path.join(tmpDir, 'unformatted.yaml');
+      fs.writeFileSync(unformattedPath, 'key:     value\n');
+
+      const [changed, unchanged] = formatFiles([unformattedPath, testFiles[0]]);
+      expect(changed.diff?.startsWith('@@ ')).toBe(true);
+      expect(changed.diff).toContain('-key:     value\n+key: value\n');
+      expect(unchanged.diff).toBeUndefined();
+    });
+
     it('should not modify files', () => {
       const unformattedPath = path.join(tmpDir, 'unformatted.yaml');
       fs.writeFileSync(unformattedPath, 'key:     value\n');
@@ -152,6 +168,38 @@ describe('Batch Processing', () => {
       expect(result.total).toBe(1);
       expect(result.failed).toBe(1);
     });
+
+    it('should not write files in dry-run mode', () => {
+      const unformattedPath = path.join(tmpDir, 'unformatted.yaml');
+      fs.writeFileSync(unformattedPath, 'key:     value\n');
+
+      const result = formatFilesInPlace([...testFiles, unformattedPath], { dryRun: true });
+      expect(result.total).toBe(6);
+      expect(result.changed).toBe(1);
+      expect(fs.readFileSync(unformattedPath, 'utf-8')).toBe('key:     value\n');
+    });
+
+    it('should format files matched by a glob pattern', () => {
+      const nestedDir = path.join(tmpDir, 'config', 'nested');
+      fs.mkdirSync(nestedDir, { recursive: true });
+      const nestedPath = path.join(nestedDir, 'app.yaml');
+      fs.writeFileSync(nestedPath, 'key:     value\n');
+      fs.writeFileSync(path.join(nestedDir, 'notes.txt'), 'not yaml\n');
+
+      const result = formatFilesInPlace([path.join(tmpDir, 'config', '**', '*.yaml')]);
+      expect(result.total).toBe(1);
+      expect(result.changed).toBe(1);
+      expect(fs.readFileSync(nestedPath, 'utf-8')).toBe('key: value\n');
+    });
+
+    it('should reject an invalid glob pattern', () => {
+      expect(() => formatFilesInPlace([path.join(tmpDir, '[')])).toThrow(/invalid glob pattern/);
+    });
+
+    it('should throw on an invalid config', () => {
+      expect(() => processFiles([], { workers: 1000 })).toThrow(/exceeds maximum/);
+      expect(() => formatFiles([path.join(tmpDir, '[')])).toThrow(/invalid glob pattern/);
+    });
   });
 
   describe('BatchConfig', () => {
@@ -3,7 +3,7 @@
  */
 
 import { describe, expect, it } from 'vitest';
-import { Linter, lint } from '../index';
+import { Linter, diagnosticToLspJson, diagnosticToProblemMatcher, lint } from '../index';
 
 const VALID_YAML = 'name: John\nage: 30\n';
 const DUPLICATE_KEYS_YAML = 'key: value\nkey: duplicate\n';
@@ -234,3 +234,32 @@ describe('Per-rule severity overrides', () => {
     }
   });
 });
+
+describe('editor integration output', () => {
+  it('diagnosticToLspJson produces an LSP diagnostic', () => {
+    const dupKey = lint(DUPLICATE_KEYS_YAML).find((d) => d.code === 'duplicate-key');
+    expect(dupKey).toBeDefined();
+    if (!dupKey) return;
+
+    expect(diagnosticToLspJson(dupKey)).toEqual({
+      range: {
+        start: { line: 1, character: 0 },
+        end: { line: 1, character: 3 },
+      },
+      severity: 1,
+      code: 'duplicate-key',
+      source: 'fast-yaml',
+      message: dupKey.message,
+    });
+  });
+
+  it('diagnosticToProblemMatcher produces a single matchable line', () => {
+    const dupKey = lint(DUPLICATE_KEYS_YAML).find((d) => d.code === 'duplicate-key');
+    expect(dupKey).toBeDefined();
+    if (!dupKey) return;
+
+    expect(diagnosticToProblemMatcher(dupKey)).toBe(
+      `2:1: error: ${dupKey.message} [duplicate-key]`
+    );
+  });
+});
@@ -33,6 +33,12 @@ describe('parseParallel', () => {
     expect(docs).toHaveLength(2);
   });
 
+  it('parses below sequentialThresholdDocs without changing results', () => {
+    const yaml = '---\nfoo: 1\n---\nbar: 2\n---\nbaz: 3';
+    const docs = parseParallel(yaml, { sequentialThresholdDocs: 10 });
+    expect(docs).toEqual([{ foo: 1 }, { bar: 2 }, { baz: 3 }]);
+  });
+
   it('throws on invalid YAML', () => {
     const yaml = '---\nfoo: bar\n---\n{ invalid: yaml: structure ]';
     expect(() => parseParallel(yaml)).toThrow(/parse|invalid|error/i);
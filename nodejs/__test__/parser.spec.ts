@@ -3,7 +3,17 @@
  */
 
 import { describe, expect, it } from 'vitest';
-import { safeDump, safeDumpAll, safeLoad, safeLoadAll, version } from '../index';
+import {
+  load,
+  Mark,
+  safeDump,
+  safeDumpAll,
+  safeLoad,
+  safeLoadAll,
+  validateYaml,
+  version,
+  YamlError,
+} from '../index';
 
 describe('Core API - Parser', () => {
   describe('version', () => {
@@ -127,6 +137,8 @@ person:
     it('should handle empty input', () => {
       expect(safeLoadAll('')).toEqual([]);
       expect(safeLoadAll('   ')).toEqual([]);
+      expect(safeLoadAll('# only a comment\n...\n')).toEqual([]);
+      expect(safeLoadAll('---\n')).toEqual([null]);
     });
 
     it('should throw on invalid YAML', () => {
@@ -138,6 +150,143 @@ person:
       expect(() => safeLoadAll(large)).toThrow(/exceeds maximum/);
     });
   });
+
+  describe('reviver', () => {
+    it('should transform values like JSON.parse', () => {
+      const yaml = 'a: 1\nb: [1, 2, {c: 3}]\nd: {e: drop}\n';
+      const reviver = (_key: string, value: unknown) => {
+        if (value === 'drop') return undefined;
+        return typeof value === 'number' ? value * 10 : value;
+      };
+      const expected = JSON.parse(
+        JSON.stringify({ a: 1, b: [1, 2, { c: 3 }], d: { e: 'drop' } }),
+        reviver
+      );
+      expect(safeLoad(yaml, reviver)).toEqual(expected);
+      expect(safeLoad(yaml, reviver)).toEqual({ a: 10, b: [10, 20, { c: 30 }], d: {} });
+    });
+
+    it('should visit children before parents, ending with the root', () => {
+      const keys: string[] = [];
+      safeLoad('a: 1\nb: [x, {c: 2}]\n', (key, value) => {
+        keys.push(key);
+        return value;
+      });
+      expect(keys).toEqual(['a', '0', 'c', '1', 'b', '']);
+    });
+
+    it('should call the reviver with the holder as this', () => {
+      const holders: unknown[] = [];
+      const result = safeLoad('list: [1]\n', function (this: unknown, _key, value) {
+        holders.push(this);
+        return value;
+      }) as { list: number[] };
+      expect(holders[0]).toBe(result.list);
+      expect(holders[1]).toBe(result);
+    });
+
+    it('should replace the root document', () => {
+      const wrapped = safeLoad('a: 1', (key, value) => (key === '' ? [value] : value));
+      expect(wrapped).toEqual([{ a: 1 }]);
+      const dated = load('created: 2024-01-15', undefined, (key, value) =>
+        key === 'created' ? new Date(value as string) : value
+      ) as { created: Date };
+      expect(dated.created).toBeInstanceOf(Date);
+    });
+
+    it('should propagate exceptions thrown by the reviver', () => {
+      expect(() =>
+        safeLoad('a: 1', () => {
+          throw new RangeError('rejected');
+        })
+      ).toThrow(RangeError);
+    });
+
+    it('should reject a reviver that is not a function', () => {
+      expect(() => safeLoad('a: 1', 5 as unknown as () => unknown)).toThrow();
+    });
+  });
+
+  describe('YamlError', () => {
+    const catchError = (fn: () => unknown): unknown => {
+      try {
+        fn();
+      } catch (err) {
+        return err;
+      }
+      throw new Error('expected an error');
+    };
+
+    it('should be thrown by safeLoad for invalid YAML', () => {
+      const err = catchError(() => safeLoad('key: [unclosed\n'));
+      expect(err).toBeInstanceOf(YamlError);
+      expect(err).toBeInstanceOf(Error);
+      const yamlErr = err as YamlError;
+      expect(yamlErr.name).toBe('YamlError');
+      expect(yamlErr.kind).toBe('ParseError');
+      expect(yamlErr.message).toMatch(/YAML parse error/);
+      expect(String(yamlErr)).toMatch(/^YamlError: YAML parse error/);
+      expect(yamlErr.stack).toMatch(/^YamlError: /);
+    });
+
+    it('should report the 0-indexed position in mark', () => {
+      const err = catchError(() => safeLoad('key: value\n  bad: indent\n')) as YamlError;
+      expect(err.mark).toBeInstanceOf(Mark);
+      expect(err.mark?.name).toBe('<input>');
+      expect(err.mark?.line).toBe(1);
+      expect(err.mark?.column).toBe(5);
+    });
+
+    it('should be thrown by safeLoadAll and load', () => {
+      const err = catchError(() => safeLoadAll('a: 1\n---\nb: [\n')) as YamlError;
+      expect(err).toBeInstanceOf(YamlError);
+      expect(err.mark?.line).toBe(3);
+      expect(catchError(() => load('a: ['))).toBeInstanceOf(YamlError);
+    });
+
+    it('should not be used for non-YAML errors', () => {
+      const large = 'x: '.repeat(35_000_000);
+      const err = catchError(() => safeLoad(large));
+      expect(err).toBeInstanceOf(Error);
+      expect(err).not.toBeInstanceOf(YamlError);
+    });
+
+    it('should be constructible', () => {
+      const err = new YamlError('boom');
+      expect(err).toBeInstanceOf(Error);
+      expect(err.kind).toBe('ParseError');
+      expect(err.mark).toBeNull();
+      expect(new YamlError('boom', 'EmitError').kind).toBe('EmitError');
+    });
+  });
+
+  describe('validateYaml', () => {
+    it('should report valid YAML', () => {
+      expect(validateYaml('name: test\nvalue: 123')).toEqual({ valid: true });
+    });
+
+    it('should accept multiple documents', () => {
+      expect(validateYaml('---\nfoo: 1\n---\nbar: 2').valid).toBe(true);
+    });
+
+    it('should accept empty input', () => {
+      expect(validateYaml('').valid).toBe(true);
+    });
+
+    it('should report the 0-indexed error position without throwing', () => {
+      const result = validateYaml('key: value\n  bad: indent\n');
+      expect(result.valid).toBe(false);
+      expect(result.error).toMatch(/^YAML parse error/);
+      expect(result.line).toBe(1);
+      expect(result.column).toBe(5);
+    });
+
+    it('should report errors in later documents', () => {
+      const result = validateYaml('a: 1\n---\nb: [\n');
+      expect(result.valid).toBe(false);
+      expect(result.line).toBe(3);
+    });
+  });
 });
 
 describe('Core API - Serializer', () => {
@@ -212,6 +361,63 @@ describe('Core API - Serializer', () => {
     });
   });
 
+  describe('replacer', () => {
+    it('should transform values like JSON.stringify', () => {
+      const data = { user: 'admin', password: 'secret', list: [1, { n: 2 }] };
+      const replacer = (key: string, value: unknown) => {
+        if (key === 'password') return undefined;
+        return typeof value === 'number' ? value * 10 : value;
+      };
+      const expected = JSON.parse(JSON.stringify(data, replacer));
+      expect(safeLoad(safeDump(data, null, replacer))).toEqual(expected);
+      expect(expected).toEqual({ user: 'admin', list: [10, { n: 20 }] });
+    });
+
+    it('should visit parents before children, starting with the root', () => {
+      const keys: string[] = [];
+      safeDump({ a: 1, b: ['x', { c: 2 }] }, null, (key, value) => {
+        keys.push(key);
+        return value;
+      });
+      expect(keys).toEqual(['', 'a', 'b', '0', '1', 'c']);
+    });
+
+    it('should call the replacer with the holder as this', () => {
+      const data = { list: [1] };
+      const holders: unknown[] = [];
+      safeDump(data, null, function (this: unknown, _key, value) {
+        holders.push(this);
+        return value;
+      });
+      expect(holders[1]).toBe(data);
+      expect(holders[2]).toBe(data.list);
+    });
+
+    it('should turn undefined sequence items into null', () => {
+      const yaml = safeDump([1, 2, 3], null, (key, value) => (key === '1' ? undefined : value));
+      expect(safeLoad(yaml)).toEqual([1, null, 3]);
+    });
+
+    it('should combine with options', () => {
+      const yaml = safeDump({ b: 1, a: 2, c: 3 }, { sortKeys: true }, (key, value) =>
+        key === 'c' ? undefined : value
+      );
+      expect(yaml).toBe('a: 2\nb: 1\n');
+    });
+
+    it('should propagate exceptions thrown by the replacer', () => {
+      expect(() =>
+        safeDump({ a: 1 }, null, () => {
+          throw new RangeError('rejected');
+        })
+      ).toThrow(RangeError);
+    });
+
+    it('should reject a replacer that is not a function', () => {
+      expect(() => safeDump({ a: 1 }, null, 5 as unknown as () => unknown)).toThrow();
+    });
+  });
+
   describe('safeDumpAll', () => {
     it('should serialize single document', () => {
       const yaml = safeDumpAll([{ name: 'test' }]);
@@ -0,0 +1,69 @@
+import { PassThrough, Readable } from 'node:stream';
+import { describe, expect, it } from 'vitest';
+import { parseStream, safeLoadAll, YamlStream } from '../index.js';
+
+async function collect(stream: NodeJS.ReadableStream): Promise<unknown[]> {
+  const docs: unknown[] = [];
+  for await (const doc of parseStream(stream)) {
+    docs.push(doc);
+  }
+  return docs;
+}
+
+describe('parseStream', () => {
+  it('returns an async iterable', () => {
+    const stream = parseStream(Readable.from([]));
+    expect(stream).toBeInstanceOf(YamlStream);
+    expect(typeof stream[Symbol.asyncIterator]).toBe('function');
+  });
+
+  it('yields each document in order', async () => {
+    const yaml = '---\nfoo: 1\n---\nbar: [1, 2]\n---\nbaz: x\n';
+    expect(await collect(Readable.from([yaml]))).toEqual(safeLoadAll(yaml));
+  });
+
+  it('joins documents split across chunks', async () => {
+    const chunks = ['a: 1\n--', '-\nb: 2', '\n---\n', 'c: 3'];
+    expect(await collect(Readable.from(chunks))).toEqual([{ a: 1 }, { b: 2 }, { c: 3 }]);
+  });
+
+  it('accepts Buffer chunks split inside a character', async () => {
+    const bytes = Buffer.from('key: é\n');
+    const stream = Readable.from([bytes.subarray(0, 6), bytes.subarray(6)]);
+    expect(await collect(stream)).toEqual([{ key: 'é' }]);
+  });
+
+  it('handles document end markers and directives', async () => {
+    const yaml = '%YAML 1.2\n---\na: 1\n...\n%YAML 1.2\n---\nb: 2\n...\n';
+    expect(await collect(Readable.from([yaml]))).toEqual([{ a: 1 }, { b: 2 }]);
+  });
+
+  it('yields nothing for an empty stream', async () => {
+    expect(await collect(Readable.from([]))).toEqual([]);
+  });
+
+  it('parses large streams', async () => {
+    const yaml = Array.from({ length: 5000 }, (_, i) => `---\nid: ${i}\n`).join('');
+    const docs = (await collect(Readable.from([yaml]))) as { id: number }[];
+    expect(docs).toHaveLength(5000);
+    expect(docs[4999]).toEqual({ id: 4999 });
+  });
+
+  it('rejects on invalid YAML after yielding earlier documents', async () => {
+    const docs: unknown[] = [];
+    const iterate = async () => {
+      for await (const doc of parseStream(Readable.from(['a: 1\n---\nb: [1\n']))) {
+        docs.push(doc);
+      }
+    };
+    await expect(iterate()).rejects.toThrow(/document starting at line 2/);
+    expect(docs).toEqual([{ a: 1 }]);
+  });
+
+  it('rejects when the stream errors', async () => {
+    const stream = new PassThrough();
+    stream.write('a: 1\n');
+    setTimeout(() => stream.destroy(new Error('boom')), 10);
+    await expect(collect(stream)).rejects.toThrow('boom');
+  });
+});
@@ -75,6 +75,63 @@ export declare class Mark {
   toString(): string
 }
 
+/**
+ * Error thrown when a YAML document cannot be parsed.
+ *
+ * `YamlError` inherits from `Error`, so existing `catch` blocks keep working,
+ * while `err instanceof YamlError` tells YAML errors apart from other failures.
+ *
+ * # Example
+ *
+ * ```javascript
+ * const { safeLoad, YamlError } = require('@fast-yaml/core');
+ *
+ * try {
+ *   safeLoad('key: [unclosed');
+ * } catch (err) {
+ *   if (err instanceof YamlError) {
+ *     console.log(err.kind);      // 'ParseError'
+ *     console.log(err.mark.line); // 0
+ *   }
+ * }
+ * ```
+ */
+export declare class YamlError extends Error {
+  /** The error name, always `"YamlError"`. */
+  readonly name: string
+  /** Description of the failure. */
+  readonly message: string
+  /** Category of the failure (e.g., `"ParseError"`). */
+  readonly kind: string
+  /** Stack trace captured where the error was thrown. */
+  readonly stack?: string
+  /**
+   * Create a new `YamlError` instance.
+   *
+   * # Arguments
+   *
+   * * `message` - Description of the failure
+   * * `kind` - Category of the failure (default: `"ParseError"`)
+   */
+  constructor(message: string, kind?: string | undefined | null)
+  /** Position of the failure in the input, or `null` if unknown. */
+  get mark(): Mark | null
+}
+
+/**
+ * Async iterable over the documents of a readable stream.
+ *
+ * Returned by `parseStream`; consume it with `for await...of`.
+ *
+ * This type implements JavaScript's async iterable protocol.
+ * It can be used with `for await...of` loops.
+ *
+ * @see https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols#the_async_iterator_and_async_iterable_protocols
+ */
+export declare class YamlStream {
+  [Symbol.asyncIterator](): AsyncGenerator<unknown, void, undefined>
+}
+
 /** Configuration for batch file processing. */
 export interface BatchConfig {
   /** Worker count (null = auto, 0 = sequential) */
@@ -91,6 +148,8 @@ export interface BatchConfig {
   width?: number
   /** Sort dictionary keys alphabetically (default: false) */
   sortKeys?: boolean
+  /** Count files `formatFilesInPlace` would change without writing them (default: false) */
+  dryRun?: boolean
 }
 
 /** Error entry for batch result. */
@@ -149,13 +208,46 @@ export interface DiagnosticContext {
   lines: Array<ContextLine>
 }
 
+/**
+ * Converts a diagnostic to a Language Server Protocol `Diagnostic` object.
+ *
+ * Positions are 0-indexed and severity uses LSP values
+ * (1 = error, 2 = warning, 3 = information, 4 = hint).
+ *
+ * # Example
+ *
+ * ```javascript
+ * const { lint, diagnosticToLspJson } = require('@fast-yaml/core');
+ * const [diagnostic] = lint('key: value\nkey: duplicate');
+ * const lsp = diagnosticToLspJson(diagnostic);
+ * // { range: { start: { line: 1, character: 0 }, ... }, severity: 1, ... }
+ * ```
+ */
+export declare function diagnosticToLspJson(diagnostic: Diagnostic): Record<string, unknown>
+
+/**
+ * Formats a diagnostic as `line:column: severity: message [code]` for VS Code
+ * task problem matchers.
+ *
+ * # Example
+ *
+ * ```javascript
+ * const { lint, diagnosticToProblemMatcher } = require('@fast-yaml/core');
+ * const [diagnostic] = lint('key: value\nkey: duplicate');
+ * diagnosticToProblemMatcher(diagnostic);
+ * // "2:1: error: duplicate key 'key' (first defined at line 1) [duplicate-key]"
+ * ```
+ */
+export declare function diagnosticToProblemMatcher(diagnostic: Diagnostic): string
+
 /** Options for YAML serialization. */
 export interface DumpOptions {
   /** If true, sort object keys alphabetically (default: false) */
   sortKeys?: boolean
   /**
    * Allow unicode characters (default: true).
-   * Note: yaml-rust2 always outputs unicode; this is accepted for API compatibility.
+   * If false, strings containing non-ASCII characters are emitted as
+   * double-quoted scalars with `\uXXXX` escapes.
    */
   allowUnicode?: boolean
   /**
@@ -188,7 +280,9 @@ export declare const enum FileOutcome {
   /** File unchanged (already formatted) */
   Unchanged = 'Unchanged',
   /** Processing failed */
-  Error = 'Error'
+  Error = 'Error',
+  /** Not processed because the batch stopped early */
+  Skipped = 'Skipped'
 }
 
 /** Result for a single file with path context. */
@@ -210,7 +304,7 @@ export interface FileResult {
  *
  * # Arguments
  *
- * * `paths` - Array of file paths to format
+ * * `paths` - Array of file paths or glob patterns to format
  * * `config` - Optional batch processing configuration
  *
  * # Returns
@@ -237,7 +331,7 @@ export declare function formatFiles(paths: Array<string>, config?: BatchConfig |
  *
  * # Arguments
  *
- * * `paths` - Array of file paths to format
+ * * `paths` - Array of file paths or glob patterns to format
  * * `config` - Optional batch processing configuration
  *
  * # Returns
@@ -260,6 +354,11 @@ export interface FormatResult {
   path: string
   /** Formatted content (null if error) */
   content?: string
+  /**
+   * Unified diff from the original to the formatted content, starting at
+   * the first `@@` hunk (null if unchanged or error)
+   */
+  diff?: string
   /** Error message (null if success) */
   error?: string
 }
@@ -333,6 +432,7 @@ export interface LintConfig {
  *
  * * `yaml_str` - A YAML document as a string
  * * `options` - Optional parsing options (schema, filename, etc.)
+ * * `reviver` - Optional `(key, value) => value` callback, as for `safeLoad`
  *
  * # Returns
  *
@@ -353,7 +453,7 @@ export interface LintConfig {
  * console.log(data); // { name: 'test' }
  * ```
  */
-export declare function load(yamlStr: string, options?: LoadOptions | undefined | null): NapiResult<unknown>
+export declare function load(yamlStr: string, options?: LoadOptions | undefined | null, reviver?: ((key: string, value: unknown) => unknown) | undefined | null): NapiResult<unknown>
 
 /**
  * Parse a YAML string containing multiple documents with options (js-yaml compatible).
@@ -444,6 +544,8 @@ export interface ParallelConfig {
   maxInputSize?: number
   /** Maximum number of documents allowed (default: 100k, max: 10M). */
   maxDocuments?: number
+  /** Inputs with fewer documents are parsed without the thread pool (default: 2). */
+  sequentialThresholdDocs?: number
 }
 
 /**
@@ -467,7 +569,8 @@ export interface ParallelConfig {
  *
  * # Performance
  *
- * - Single document: Falls back to sequential parsing
+ * - Single document: Falls back to sequential parsing (see
+ *   `sequentialThresholdDocs`)
  * - Multi-document: 2-3x faster on 4-8 core systems
  * - Use for files > 1MB with multiple documents
  *
@@ -518,6 +621,42 @@ bar: 2';
  */
 export declare function parseParallelAsync(yamlStr: string, config?: ParallelConfig | undefined | null): Promise<unknown>
 
+/**
+ * Parse the YAML documents of a readable stream incrementally.
+ *
+ * Chunks are buffered until a document boundary (`---` or `...`) is seen, and
+ * each complete document is parsed off the main thread and yielded in order.
+ * The stream is paused while more than 1MB of input waits to be parsed.
+ *
+ * # Arguments
+ *
+ * * `readable` - A readable stream of `Buffer` or string chunks
+ * * `options` - Optional parsing options (schema, filename, etc.)
+ *
+ * # Returns
+ *
+ * An async iterable of parsed JavaScript values, one per document
+ *
+ * # Errors
+ *
+ * Iteration rejects if:
+ * - A document is invalid YAML or invalid UTF-8
+ * - A single document exceeds the size limit (100MB)
+ * - The stream emits an `error` event
+ *
+ * # Example
+ *
+ * ```javascript
+ * const { createReadStream } = require('node:fs');
+ * const { parseStream } = require('@fast-yaml/core');
+ *
+ * for await (const doc of parseStream(createReadStream('events.yaml'))) {
+ *   console.log(doc);
+ * }
+ * ```
+ */
+export declare function parseStream(readable: NodeJS.ReadableStream, options?: LoadOptions | undefined | null): YamlStream
+
 /**
  * Process files and return batch result.
  *
@@ -525,7 +664,7 @@ export declare function parseParallelAsync(yamlStr: string, config?: ParallelCon
  *
  * # Arguments
  *
- * * `paths` - Array of file paths to process
+ * * `paths` - Array of file paths or glob patterns to process
  * * `config` - Optional batch processing configuration
  *
  * # Returns
@@ -551,6 +690,8 @@ export declare function processFiles(paths: Array<string>, config?: BatchConfig
  *
  * * `data` - A JavaScript object to serialize (Object, Array, string, number, boolean, null)
  * * `options` - Optional serialization options
+ * * `replacer` - Optional `(key, value) => value` callback, applied to each value
+ *   before it is serialized as `JSON.stringify` applies its replacer
  *
  * # Returns
  *
@@ -558,7 +699,8 @@ export declare function processFiles(paths: Array<string>, config?: BatchConfig
  *
  * # Errors
  *
- * Throws an error if the object contains non-serializable types.
+ * Throws an error if the object contains non-serializable types or the
+ * replacer throws.
  *
  * # Example
  *
@@ -568,10 +710,16 @@ export declare function processFiles(paths: Array<string>, config?: BatchConfig
  * const yaml = safeDump({ name: 'test', value: 123 });
  * console.log(yaml); // 'name: test
 value: 123
+'
+ *
+ * const redacted = safeDump({ user: 'admin', password: 'secret' }, null, (key, value) =>
+ *   key === 'password' ? undefined : value,
+ * );
+ * console.log(redacted); // 'user: admin
 '
  * ```
  */
-export declare function safeDump(data: unknown, options?: DumpOptions | undefined | null): NapiResult<string>
+export declare function safeDump(data: unknown, options?: DumpOptions | undefined | null, replacer?: ((key: string, value: unknown) => unknown) | undefined | null): NapiResult<string>
 
 /**
  * Serialize multiple JavaScript objects to a YAML string with document separators.
@@ -620,6 +768,8 @@ export declare function safeDumpAll(documents: Array<unknown>, options?: DumpOpt
  * # Arguments
  *
  * * `yaml_str` - A YAML document as a string
+ * * `reviver` - Optional `(key, value) => value` callback, applied to the result as
+ *   `JSON.parse` applies its reviver
  *
  * # Returns
  *
@@ -628,7 +778,8 @@ export declare function safeDumpAll(documents: Array<unknown>, options?: DumpOpt
  * # Errors
  *
  * Throws an error if:
- * - The YAML is invalid
+ * - The YAML is invalid (a `YamlError` with `kind` `"ParseError"` and the
+ *   failure position in `mark`)
  * - Input exceeds size limit (100MB)
  *
  * # Security
@@ -643,9 +794,13 @@ export declare function safeDumpAll(documents: Array<unknown>, options?: DumpOpt
  * const data = safeLoad('name: test
 value: 123');
  * console.log(data); // { name: 'test', value: 123 }
+ *
+ * const dated = safeLoad('created: 2024-01-15', (key, value) =>
+ *   key === 'created' ? new Date(value) : value,
+ * );
  * ```
  */
-export declare function safeLoad(yamlStr: string): NapiResult<unknown>
+export declare function safeLoad(yamlStr: string, reviver?: ((key: string, value: unknown) => unknown) | undefined | null): NapiResult<unknown>
 
 /**
  * Parse a YAML string containing multiple documents.
@@ -663,7 +818,7 @@ export declare function safeLoad(yamlStr: string): NapiResult<unknown>
  * # Errors
  *
  * Throws an error if:
- * - The YAML is invalid
+ * - The YAML is invalid (a `YamlError`, as for `safeLoad`)
  * - Input exceeds size limit (100MB)
  *
  * # Security
@@ -734,6 +889,47 @@ export interface Suggestion {
   replacement?: string
 }
 
+/** URL of the YAML specification the parser implements. */
+export declare const SPEC_URL: string
+
+/** Outcome of `validateYaml`. */
+export interface ValidateResult {
+  /** True if the input parsed successfully. */
+  valid: boolean
+  /** Error message if the input is invalid. */
+  error?: string
+  /** Line of the parse error (0-indexed, as in `YamlError.mark`). */
+  line?: number
+  /** Column of the parse error (0-indexed, as in `YamlError.mark`). */
+  column?: number
+}
+
+/**
+ * Check whether a string is valid YAML without converting it to JavaScript.
+ *
+ * Every document in the input is parsed. Invalid input is reported in the
+ * result instead of being thrown.
+ *
+ * # Arguments
+ *
+ * * `yaml_str` - A YAML string, possibly containing multiple documents
+ *
+ * # Returns
+ *
+ * `{ valid: true }` on success, or `{ valid: false, error, line, column }`
+ * with the 0-indexed position of the parse error
+ *
+ * # Example
+ *
+ * ```javascript
+ * const { validateYaml } = require('@fast-yaml/core');
+ *
+ * validateYaml('name: test'); // { valid: true }
+ * validateYaml('key: [unclosed'); // { valid: false, error: '...', line: 1, column: 0 }
+ * ```
+ */
+export declare function validateYaml(yamlStr: string): NapiResult<ValidateResult>
+
 /**
  * Get the library version.
  *
@@ -747,3 +943,17 @@ export interface Suggestion {
  * ```
  */
 export declare function version(): string
+
+/**
+ * Version of the YAML specification the parser implements.
+ *
+ * This is distinct from the package version returned by `version()`.
+ *
+ * # Examples
+ *
+ * ```javascript
+ * const { YAML_VERSION } = require('@fast-yaml/core');
+ * console.log(YAML_VERSION); // "1.2.2"
+ * ```
+ */
+export declare const YAML_VERSION: string
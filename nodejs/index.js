@@ -578,6 +578,10 @@ if (!nativeBinding) {
 module.exports = nativeBinding
 module.exports.Linter = nativeBinding.Linter
 module.exports.Mark = nativeBinding.Mark
+module.exports.YamlError = nativeBinding.YamlError
+module.exports.YamlStream = nativeBinding.YamlStream
+module.exports.diagnosticToLspJson = nativeBinding.diagnosticToLspJson
+module.exports.diagnosticToProblemMatcher = nativeBinding.diagnosticToProblemMatcher
 module.exports.FileOutcome = nativeBinding.FileOutcome
 module.exports.formatFiles = nativeBinding.formatFiles
 module.exports.formatFilesInPlace = nativeBinding.formatFilesInPlace
@@ -586,6 +590,7 @@ module.exports.load = nativeBinding.load
 module.exports.loadAll = nativeBinding.loadAll
 module.exports.parseParallel = nativeBinding.parseParallel
 module.exports.parseParallelAsync = nativeBinding.parseParallelAsync
+module.exports.parseStream = nativeBinding.parseStream
 module.exports.processFiles = nativeBinding.processFiles
 module.exports.safeDump = nativeBinding.safeDump
 module.exports.safeDumpAll = nativeBinding.safeDumpAll
@@ -593,4 +598,7 @@ module.exports.safeLoad = nativeBinding.safeLoad
 module.exports.safeLoadAll = nativeBinding.safeLoadAll
 module.exports.Schema = nativeBinding.Schema
 module.exports.Severity = nativeBinding.Severity
+module.exports.SPEC_URL = nativeBinding.SPEC_URL
+module.exports.validateYaml = nativeBinding.validateYaml
 module.exports.version = nativeBinding.version
+module.exports.YAML_VERSION = nativeBinding.YAML_VERSION
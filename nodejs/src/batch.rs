@@ -5,10 +5,16 @@ use std::path::PathBuf;
 use fast_yaml_core::emitter::EmitterConfig;
 use fast_yaml_parallel::{
     BatchResult as RustBatchResult, Config as RustConfig, FileOutcome as RustFileOutcome,
-    FileProcessor, FileResult as RustFileResult,
+    FileProcessor, FileResult as RustFileResult, SmartReader,
 };
+// Exported functions spell out `napi::Result`: `#[napi]` only throws for a
+// return type named `Result` and hands an aliased error back as a value
 use napi::Result as NapiResult;
 use napi_derive::napi;
+use similar::TextDiff;
+
+/// Maximum number of files a single glob pattern may expand to.
+const MAX_GLOB_MATCHES: usize = 100_000;
 
 /// Outcome of processing a single file.
 #[napi(string_enum)]
@@ -22,6 +28,8 @@ pub enum FileOutcome {
     Unchanged,
     /// Processing failed
     Error,
+    /// Not processed because the batch stopped early
+    Skipped,
 }
 
 impl From<&RustFileOutcome> for FileOutcome {
@@ -31,6 +39,7 @@ impl From<&RustFileOutcome> for FileOutcome {
             RustFileOutcome::Changed { .. } => Self::Changed,
             RustFileOutcome::Unchanged { .. } => Self::Unchanged,
             RustFileOutcome::Error { .. } => Self::Error,
+            RustFileOutcome::Skipped => Self::Skipped,
         }
     }
 }
@@ -134,6 +143,8 @@ pub struct BatchConfig {
     pub width: Option<u32>,
     /// Sort dictionary keys alphabetically (default: false)
     pub sort_keys: Option<bool>,
+    /// Count files `formatFilesInPlace` would change without writing them (default: false)
+    pub dry_run: Option<bool>,
 }
 
 impl BatchConfig {
@@ -167,6 +178,9 @@ impl BatchConfig {
         if let Some(t) = self.sequential_threshold {
             config = config.with_sequential_threshold(t as usize);
         }
+        if let Some(dry_run) = self.dry_run {
+            config = config.with_dry_run(dry_run);
+        }
         config
     }
 
@@ -185,17 +199,42 @@ pub struct FormatResult {
     pub path: String,
     /// Formatted content (null if error)
     pub content: Option<String>,
+    /// Unified diff from the original to the formatted content, starting at
+    /// the first `@@` hunk (null if unchanged or error)
+    pub diff: Option<String>,
     /// Error message (null if success)
     pub error: Option<String>,
 }
 
+/// Expand entries containing `*`, `?` or `[` as glob patterns, in sorted
+/// order and keeping only regular files. Other entries are passed through
+/// unchanged, so missing files are still reported as errors.
+fn expand_paths(paths: &[String]) -> NapiResult<Vec<PathBuf>> {
+    let mut expanded = Vec::with_capacity(paths.len());
+    for path in paths {
+        if !path.contains(['*', '?', '[']) {
+            expanded.push(PathBuf::from(path));
+            continue;
+        }
+
+        let matches = glob::glob(path)
+            .map_err(|e| napi::Error::from_reason(format!("invalid glob pattern '{path}': {e}")))?;
+        let files = matches
+            .filter_map(Result::ok)
+            .filter(|p| p.is_file())
+            .take(MAX_GLOB_MATCHES);
+        expanded.extend(files);
+    }
+    Ok(expanded)
+}
+
 /// Process files and return batch result.
 ///
 /// Parses and validates YAML files in parallel.
 ///
 /// # Arguments
 ///
-/// * `paths` - Array of file paths to process
+/// * `paths` - Array of file paths or glob patterns to process
 /// * `config` - Optional batch processing configuration
 ///
 /// # Returns
@@ -211,12 +250,12 @@ pub struct FormatResult {
 /// ```
 #[napi]
 #[allow(clippy::needless_pass_by_value)]
-pub fn process_files(paths: Vec<String>, config: Option<BatchConfig>) -> NapiResult<BatchResult> {
+pub fn process_files(paths: Vec<String>, config: Option<BatchConfig>) -> napi::Result<BatchResult> {
     let config = config.unwrap_or_default();
     config.validate()?;
 
     let rust_config = config.to_rust_config();
-    let path_bufs: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+    let path_bufs = expand_paths(&paths)?;
 
     let processor = FileProcessor::with_config(rust_config);
     let result = processor.parse_files(&path_bufs);
@@ -230,7 +269,7 @@ pub fn process_files(paths: Vec<String>, config: Option<BatchConfig>) -> NapiRes
 ///
 /// # Arguments
 ///
-/// * `paths` - Array of file paths to format
+/// * `paths` - Array of file paths or glob patterns to format
 /// * `config` - Optional batch processing configuration
 ///
 /// # Returns
@@ -243,7 +282,7 @@ pub fn process_files(paths: Vec<String>, config: Option<BatchConfig>) -> NapiRes
 /// const { formatFiles } = require('fastyaml-rs');
 /// const results = formatFiles(['file1.yaml']);
 /// results.forEach(r => {
-///   if (r.content) console.log(r.content);
+///   if (r.diff) console.log(r.diff);
 /// });
 /// ```
 #[napi]
@@ -251,14 +290,18 @@ pub fn process_files(paths: Vec<String>, config: Option<BatchConfig>) -> NapiRes
 pub fn format_files(
     paths: Vec<String>,
     config: Option<BatchConfig>,
-) -> NapiResult<Vec<FormatResult>> {
+) -> napi::Result<Vec<FormatResult>> {
     let config = config.unwrap_or_default();
     config.validate()?;
 
     let rust_config = config.to_rust_config();
     let emitter_config = config.to_emitter_config();
-    let path_bufs: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+    let path_bufs = expand_paths(&paths)?;
 
+    // Re-read originals the same way the processor decoded them
+    #[allow(clippy::cast_possible_truncation)]
+    let reader = SmartReader::with_threshold(rust_config.mmap_threshold() as u64);
+    let encoding_fallback = rust_config.encoding_fallback();
     let processor = FileProcessor::with_config(rust_config);
     let results = processor.format_files(&path_bufs, &emitter_config);
 
@@ -267,14 +310,22 @@ pub fn format_files(
         .map(|(path, result)| {
             let path_str = path.to_string_lossy().to_string();
             match result {
-                Ok(content) => FormatResult {
-                    path: path_str,
-                    content: Some(content),
-                    error: None,
-                },
+                Ok(content) => {
+                    let diff = reader
+                        .read_with_encoding_fallback(&path, encoding_fallback)
+                        .ok()
+                        .and_then(|(original, _)| hunk_diff(original.as_str().ok()?, &content));
+                    FormatResult {
+                        path: path_str,
+                        content: Some(content),
+                        diff,
+                        error: None,
+                    }
+                }
                 Err(e) => FormatResult {
                     path: path_str,
                     content: None,
+                    diff: None,
                     error: Some(e.to_string()),
                 },
             }
@@ -282,14 +333,26 @@ pub fn format_files(
         .collect())
 }
 
+/// Builds a unified diff of `original` and `formatted` without file headers,
+/// or `None` if they are identical.
+fn hunk_diff(original: &str, formatted: &str) -> Option<String> {
+    (original != formatted).then(|| {
+        TextDiff::from_lines(original, formatted)
+            .unified_diff()
+            .context_radius(3)
+            .to_string()
+    })
+}
+
 /// Format files in place (write changes back).
 ///
 /// Formats YAML files and writes changes atomically.
-/// Only modified files are written.
+/// Only modified files are written. With `dryRun: true` no file is written
+/// and `changed` counts the files that would change.
 ///
 /// # Arguments
 ///
-/// * `paths` - Array of file paths to format
+/// * `paths` - Array of file paths or glob patterns to format
 /// * `config` - Optional batch processing configuration
 ///
 /// # Returns
@@ -308,13 +371,13 @@ pub fn format_files(
 pub fn format_files_in_place(
     paths: Vec<String>,
     config: Option<BatchConfig>,
-) -> NapiResult<BatchResult> {
+) -> napi::Result<BatchResult> {
     let config = config.unwrap_or_default();
     config.validate()?;
 
     let rust_config = config.to_rust_config();
     let emitter_config = config.to_emitter_config();
-    let path_bufs: Vec<PathBuf> = paths.iter().map(PathBuf::from).collect();
+    let path_bufs = expand_paths(&paths)?;
 
     let processor = FileProcessor::with_config(rust_config);
     let result = processor.format_in_place(&path_bufs, &emitter_config);
@@ -75,6 +75,132 @@ pub fn yaml_to_js<'env>(env: &'env Env, yaml: &YamlOwned) -> NapiResult<Unknown<
     }
 }
 
+/// A `JSON.parse`-style reviver: `(key, value) => value`.
+pub type Reviver<'env> = Function<'env, FnArgs<(String, Unknown<'env>)>, Unknown<'env>>;
+
+/// Apply a `JSON.parse`-style reviver to a converted document.
+///
+/// As with `JSON.parse`, entries are revived before the object or array that
+/// holds them, each call receives that holder as `this`, and returning
+/// `undefined` deletes the entry. The document itself is passed last, under
+/// the key `""`.
+///
+/// # Errors
+///
+/// Returns an error if the reviver throws.
+pub fn apply_reviver<'env>(
+    env: &'env Env,
+    value: Unknown<'env>,
+    reviver: &Reviver<'env>,
+) -> NapiResult<Unknown<'env>> {
+    let mut root = Object::new(env)?;
+    root.set("", value)?;
+    revive(env, root, "", reviver)
+}
+
+/// Revive `holder[key]`, then its children first if it is an object or array.
+fn revive<'env>(
+    env: &'env Env,
+    holder: Object<'env>,
+    key: &str,
+    reviver: &Reviver<'env>,
+) -> NapiResult<Unknown<'env>> {
+    let value: Unknown = holder.get_named_property(key)?;
+
+    if value.get_type()? == ValueType::Object {
+        let mut js_obj: Object = unsafe { FromNapiValue::from_napi_value(env.raw(), value.raw())? };
+
+        let keys: Vec<String> = if js_obj.is_array()? {
+            (0..js_obj.get_array_length()?)
+                .map(|i| i.to_string())
+                .collect()
+        } else {
+            let property_names = js_obj.get_property_names()?;
+            (0..property_names.get_array_length()?)
+                .map(|i| property_names.get_element::<String>(i))
+                .collect::<NapiResult<_>>()?
+        };
+
+        for child_key in keys {
+            let child = revive(env, js_obj, &child_key, reviver)?;
+            if child.get_type()? == ValueType::Undefined {
+                js_obj.delete_named_property(&child_key)?;
+            } else {
+                js_obj.set_named_property(&child_key, child)?;
+            }
+        }
+    }
+
+    reviver.apply(holder, (key.to_string(), value).into())
+}
+
+/// A `JSON.stringify`-style replacer: `(key, value) => value`.
+pub type Replacer<'env> = Function<'env, FnArgs<(String, Unknown<'env>)>, Unknown<'env>>;
+
+/// Convert a JavaScript value to a YAML value, passing it through a
+/// `JSON.stringify`-style replacer.
+///
+/// As with `JSON.stringify`, the replacer sees each value before its entries,
+/// starting with the value itself under the key `""`, and each call receives
+/// the holding object or array as `this`. Returning `undefined` omits a mapping
+/// entry and turns a sequence item or the document into `null`.
+///
+/// # Errors
+///
+/// Returns an error if the replacer throws or returns a non-serializable value.
+pub fn js_to_yaml_with_replacer<'env>(
+    env: Env,
+    js_value: Unknown<'env>,
+    replacer: &Replacer<'env>,
+) -> NapiResult<YamlOwned> {
+    let mut root = Object::new(&env)?;
+    root.set("", js_value)?;
+    Ok(replace(&env, root, "", replacer)?.unwrap_or(YamlOwned::Value(ScalarOwned::Null)))
+}
+
+/// Replace `holder[key]`, then convert it, or return `None` if the replacer
+/// returned `undefined`.
+fn replace<'env>(
+    env: &Env,
+    holder: Object<'_>,
+    key: &str,
+    replacer: &Replacer<'env>,
+) -> NapiResult<Option<YamlOwned>> {
+    let original: Unknown<'env> = holder.get_named_property(key)?;
+    let value = replacer.apply(holder, (key.to_string(), original).into())?;
+
+    match value.get_type()? {
+        ValueType::Undefined => Ok(None),
+
+        ValueType::Object => {
+            let js_obj: Object = unsafe { FromNapiValue::from_napi_value(env.raw(), value.raw())? };
+
+            if js_obj.is_array()? {
+                let len = js_obj.get_array_length()?;
+                let mut arr = Vec::with_capacity(len as usize);
+                for i in 0..len {
+                    let item = replace(env, js_obj, &i.to_string(), replacer)?;
+                    arr.push(item.unwrap_or(YamlOwned::Value(ScalarOwned::Null)));
+                }
+                return Ok(Some(YamlOwned::Sequence(arr)));
+            }
+
+            let property_names = js_obj.get_property_names()?;
+            let len = property_names.get_array_length()?;
+            let mut map = MappingOwned::with_capacity(len as usize);
+            for i in 0..len {
+                let key_str: String = property_names.get_element(i)?;
+                if let Some(item) = replace(env, js_obj, &key_str, replacer)? {
+                    map.insert(YamlOwned::Value(ScalarOwned::String(key_str)), item);
+                }
+            }
+            Ok(Some(YamlOwned::Mapping(map)))
+        }
+
+        _ => js_to_yaml(env, value).map(Some),
+    }
+}
+
 /// Convert a YAML key to a string for use as JavaScript object property.
 ///
 /// YAML keys can be any type, but JavaScript object keys must be strings.
@@ -3,10 +3,10 @@
 //! This module provides safe YAML serialization functions that convert
 //! JavaScript objects to YAML strings.
 
-use crate::conversion::js_to_yaml;
+use crate::conversion::{Replacer, js_to_yaml, js_to_yaml_with_replacer};
+use fast_yaml_core::ValueExt;
 use napi::{Env, Result as NapiResult, bindgen_prelude::*};
 use napi_derive::napi;
-use saphyr::{MappingOwned, ScalarOwned, YamlOwned};
 
 /// Maximum output size in bytes for `safe_dump`/`safe_dump_all` (100MB).
 ///
@@ -21,7 +21,8 @@ pub struct DumpOptions {
     pub sort_keys: Option<bool>,
 
     /// Allow unicode characters (default: true).
-    /// Note: yaml-rust2 always outputs unicode; this is accepted for API compatibility.
+    /// If false, strings containing non-ASCII characters are emitted as
+    /// double-quoted scalars with `\uXXXX` escapes.
     pub allow_unicode: Option<bool>,
 
     /// Indentation width in spaces (default: 2).
@@ -63,6 +64,8 @@ impl Default for DumpOptions {
 ///
 /// * `data` - A JavaScript object to serialize (Object, Array, string, number, boolean, null)
 /// * `options` - Optional serialization options
+/// * `replacer` - Optional `(key, value) => value` callback, applied to each value
+///   before it is serialized as `JSON.stringify` applies its replacer
 ///
 /// # Returns
 ///
@@ -70,7 +73,8 @@ impl Default for DumpOptions {
 ///
 /// # Errors
 ///
-/// Throws an error if the object contains non-serializable types.
+/// Throws an error if the object contains non-serializable types or the
+/// replacer throws.
 ///
 /// # Example
 ///
@@ -79,21 +83,35 @@ impl Default for DumpOptions {
 ///
 /// const yaml = safeDump({ name: 'test', value: 123 });
 /// console.log(yaml); // 'name: test\nvalue: 123\n'
+///
+/// const redacted = safeDump({ user: 'admin', password: 'secret' }, null, (key, value) =>
+///   key === 'password' ? undefined : value,
+/// );
+/// console.log(redacted); // 'user: admin\n'
 /// ```
-#[napi]
+#[napi(strict)]
 pub fn safe_dump(
     env: Env,
     data: Unknown<'static>,
     options: Option<DumpOptions>,
+    replacer: Option<Replacer<'_>>,
 ) -> NapiResult<String> {
     let opts = options.unwrap_or_default();
 
     // Convert JavaScript to YAML
-    let mut yaml = js_to_yaml(&env, data)?;
+    let mut yaml = match replacer {
+        Some(replacer) => js_to_yaml_with_replacer(env, data, &replacer)?,
+        None => js_to_yaml(&env, data)?,
+    };
 
     // Sort keys if requested
     if opts.sort_keys.unwrap_or(false) {
-        yaml = sort_yaml_keys(&yaml);
+        yaml.sort_keys();
+    }
+
+    // Escape non-ASCII characters if unicode output is disabled
+    if !opts.allow_unicode.unwrap_or(true) {
+        yaml.escape_non_ascii();
     }
 
     // Create emitter configuration from options
@@ -159,7 +177,12 @@ pub fn safe_dump_all(
 
         // Sort keys if requested
         if opts.sort_keys.unwrap_or(false) {
-            yaml = sort_yaml_keys(&yaml);
+            yaml.sort_keys();
+        }
+
+        // Escape non-ASCII characters if unicode output is disabled
+        if !opts.allow_unicode.unwrap_or(true) {
+            yaml.escape_non_ascii();
         }
 
         yamls.push(yaml);
@@ -188,41 +211,6 @@ pub fn safe_dump_all(
     Ok(output)
 }
 
-/// Helper function to recursively sort dictionary keys in YAML
-fn sort_yaml_keys(yaml: &YamlOwned) -> YamlOwned {
-    match yaml {
-        YamlOwned::Mapping(map) => {
-            let mut sorted: Vec<_> = map.iter().collect();
-            sorted.sort_by(|(k1, _), (k2, _)| {
-                let s1 = yaml_to_sort_key(k1);
-                let s2 = yaml_to_sort_key(k2);
-                s1.cmp(&s2)
-            });
-            let mut new_map = MappingOwned::new();
-            for (k, v) in sorted {
-                new_map.insert(k.clone(), sort_yaml_keys(v));
-            }
-            YamlOwned::Mapping(new_map)
-        }
-        YamlOwned::Sequence(arr) => YamlOwned::Sequence(arr.iter().map(sort_yaml_keys).collect()),
-        other => other.clone(),
-    }
-}
-
-/// Convert YAML value to a sortable string key
-fn yaml_to_sort_key(yaml: &YamlOwned) -> String {
-    match yaml {
-        YamlOwned::Value(scalar) => match scalar {
-            ScalarOwned::String(s) => s.clone(),
-            ScalarOwned::Integer(i) => i.to_string(),
-            ScalarOwned::FloatingPoint(f) => f.to_string(),
-            ScalarOwned::Boolean(b) => b.to_string(),
-            ScalarOwned::Null => String::new(),
-        },
-        _ => String::new(),
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -242,57 +230,4 @@ mod tests {
         assert_eq!(opts.default_flow_style, None);
         assert_eq!(opts.explicit_start, Some(false));
     }
-
-    #[test]
-    fn test_yaml_to_sort_key() {
-        assert_eq!(
-            yaml_to_sort_key(&YamlOwned::Value(ScalarOwned::String("test".to_string()))),
-            "test"
-        );
-        assert_eq!(
-            yaml_to_sort_key(&YamlOwned::Value(ScalarOwned::Integer(42))),
-            "42"
-        );
-        assert_eq!(
-            yaml_to_sort_key(&YamlOwned::Value(ScalarOwned::Boolean(true))),
-            "true"
-        );
-    }
-
-    #[test]
-    fn test_sort_yaml_keys() {
-        let mut map = MappingOwned::new();
-        map.insert(
-            YamlOwned::Value(ScalarOwned::String("z".to_string())),
-            YamlOwned::Value(ScalarOwned::Integer(1)),
-        );
-        map.insert(
-            YamlOwned::Value(ScalarOwned::String("a".to_string())),
-            YamlOwned::Value(ScalarOwned::Integer(2)),
-        );
-        map.insert(
-            YamlOwned::Value(ScalarOwned::String("m".to_string())),
-            YamlOwned::Value(ScalarOwned::Integer(3)),
-        );
-
-        let yaml = YamlOwned::Mapping(map);
-        let sorted = sort_yaml_keys(&yaml);
-
-        if let YamlOwned::Mapping(sorted_map) = sorted {
-            let keys: Vec<String> = sorted_map
-                .keys()
-                .map(|k| {
-                    if let YamlOwned::Value(ScalarOwned::String(s)) = k {
-                        s.clone()
-                    } else {
-                        String::new()
-                    }
-                })
-                .collect();
-
-            assert_eq!(keys, vec!["a", "m", "z"]);
-        } else {
-            panic!("Expected Mapping");
-        }
-    }
 }
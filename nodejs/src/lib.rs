@@ -21,7 +21,9 @@
 // Note: NAPI-RS uses unsafe code internally, so we can't forbid it here
 #![warn(missing_docs)]
 
+use napi::{Env, bindgen_prelude::*};
 use napi_derive::napi;
+use saphyr::ScanError;
 
 mod batch;
 mod conversion;
@@ -29,6 +31,7 @@ mod emitter;
 mod lint;
 mod parallel;
 mod parser;
+mod stream;
 
 // Re-export public API
 pub use batch::{
@@ -38,10 +41,13 @@ pub use batch::{
 pub use emitter::{DumpOptions, safe_dump, safe_dump_all};
 pub use lint::{
     ContextLine, Diagnostic, DiagnosticContext, LintConfig, Linter, Location, Severity, Span,
-    Suggestion, lint,
+    Suggestion, diagnostic_to_lsp_json, diagnostic_to_problem_matcher, lint,
 };
 pub use parallel::{ParallelConfig, parse_parallel, parse_parallel_async};
-pub use parser::{LoadOptions, load, load_all, safe_load, safe_load_all};
+pub use parser::{
+    LoadOptions, ValidateResult, load, load_all, safe_load, safe_load_all, validate_yaml,
+};
+pub use stream::{YamlStream, parse_stream};
 
 // ============================================================================
 // Schema Types (js-yaml compatibility)
@@ -123,6 +129,135 @@ impl Mark {
     }
 }
 
+// ============================================================================
+// YamlError Class (typed errors)
+// ============================================================================
+
+/// Error thrown when a YAML document cannot be parsed.
+///
+/// `YamlError` inherits from `Error`, so existing `catch` blocks keep working,
+/// while `err instanceof YamlError` tells YAML errors apart from other failures.
+///
+/// # Example
+///
+/// ```javascript
+/// const { safeLoad, YamlError } = require('@fast-yaml/core');
+///
+/// try {
+///   safeLoad('key: [unclosed');
+/// } catch (err) {
+///   if (err instanceof YamlError) {
+///     console.log(err.kind);      // 'ParseError'
+///     console.log(err.mark.line); // 0
+///   }
+/// }
+/// ```
+#[napi]
+#[derive(Clone, Debug)]
+pub struct YamlError {
+    /// The error name, always `"YamlError"`.
+    #[napi(readonly)]
+    pub name: String,
+    /// Description of the failure.
+    #[napi(readonly)]
+    pub message: String,
+    /// Category of the failure (e.g., `"ParseError"`).
+    #[napi(readonly)]
+    pub kind: String,
+    /// Position of the failure in the input, if known.
+    #[napi(skip)]
+    pub mark: Option<Mark>,
+    /// Stack trace captured where the error was thrown.
+    #[napi(readonly)]
+    pub stack: Option<String>,
+}
+
+#[napi]
+impl YamlError {
+    /// Create a new `YamlError` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Description of the failure
+    /// * `kind` - Category of the failure (default: `"ParseError"`)
+    #[napi(constructor)]
+    pub fn new(message: String, kind: Option<String>) -> Self {
+        Self {
+            name: "YamlError".to_string(),
+            message,
+            kind: kind.unwrap_or_else(|| "ParseError".to_string()),
+            mark: None,
+            stack: None,
+        }
+    }
+
+    /// Position of the failure in the input, or `null` if unknown.
+    #[napi(getter)]
+    pub fn mark(&self) -> Option<Mark> {
+        self.mark.clone()
+    }
+
+    /// Create a parse error from a scanner error, with its position as `mark`.
+    pub(crate) fn from_scan_error(error: &ScanError) -> Self {
+        let marker = error.marker();
+        Self {
+            mark: Some(Mark::new(
+                "<input>".to_string(),
+                u32::try_from(marker.line().saturating_sub(1)).unwrap_or(u32::MAX),
+                u32::try_from(marker.col()).unwrap_or(u32::MAX),
+            )),
+            ..Self::new(format!("YAML parse error: {error}"), None)
+        }
+    }
+
+    /// Throw this error as a pending JavaScript exception.
+    pub(crate) fn throw(mut self, env: Env) -> napi::Result<()> {
+        // Borrow the stack trace of a regular Error created at the throw site
+        let error = env.create_error(napi::Error::from_reason(self.message.clone()))?;
+        self.stack = error
+            .get_named_property::<Option<String>>("stack")?
+            .map(|stack| match stack.strip_prefix("Error") {
+                Some(rest) => format!("{}{rest}", self.name),
+                None => stack,
+            });
+        env.throw(self)
+    }
+}
+
+/// Make `YamlError.prototype` inherit from `Error.prototype`.
+#[napi(module_exports)]
+pub fn init_yaml_error(exports: Object<'_>, env: Env) -> napi::Result<()> {
+    let global = env.get_global()?;
+    let error_prototype: Object<'_> = global
+        .get_named_property::<Function<'_>>("Error")?
+        .get_named_property("prototype")?;
+    let yaml_error_prototype: Object<'_> = exports
+        .get_named_property::<Function<'_>>("YamlError")?
+        .get_named_property("prototype")?;
+    let set_prototype_of: Function<'_, FnArgs<(Object<'_>, Object<'_>)>, Object<'_>> = global
+        .get_named_property::<Function<'_>>("Object")?
+        .get_named_property("setPrototypeOf")?;
+    set_prototype_of.call((yaml_error_prototype, error_prototype).into())?;
+    Ok(())
+}
+
+/// Version of the YAML specification the parser implements.
+///
+/// This is distinct from the package version returned by `version()`.
+///
+/// # Examples
+///
+/// ```javascript
+/// const { YAML_VERSION } = require('@fast-yaml/core');
+/// console.log(YAML_VERSION); // "1.2.2"
+/// ```
+#[napi]
+pub const YAML_VERSION: &str = "1.2.2";
+
+/// URL of the YAML specification the parser implements.
+#[napi]
+pub const SPEC_URL: &str = "https://yaml.org/spec/1.2.2/";
+
 /// Get the library version.
 ///
 /// Returns the version string of the fast-yaml-nodejs crate.
@@ -183,4 +318,27 @@ mod tests {
         assert_eq!(mark.column, 0);
         assert_eq!(mark.to_string(), "test.yaml:0:0");
     }
+
+    #[test]
+    fn test_yaml_error_new() {
+        let error = YamlError::new("boom".to_string(), None);
+        assert_eq!(error.name, "YamlError");
+        assert_eq!(error.kind, "ParseError");
+        assert!(error.mark.is_none());
+        assert_eq!(
+            YamlError::new("boom".to_string(), Some("EmitError".to_string())).kind,
+            "EmitError"
+        );
+    }
+
+    #[test]
+    fn test_yaml_error_from_scan_error() {
+        use saphyr::{LoadableYamlNode, YamlOwned};
+
+        let scan_error = YamlOwned::load_from_str("key: value\n  bad: indent\n").unwrap_err();
+        let error = YamlError::from_scan_error(&scan_error);
+        assert!(error.message.starts_with("YAML parse error: "));
+        let mark = error.mark.unwrap();
+        assert_eq!((mark.line, mark.column), (1, 5));
+    }
 }
@@ -5,9 +5,10 @@
 
 use fast_yaml_linter::{
     ContextLine as RustContextLine, Diagnostic as RustDiagnostic,
-    DiagnosticContext as RustDiagnosticContext, LintConfig as RustLintConfig, Linter as RustLinter,
-    Location as RustLocation, Severity as RustSeverity, Span as RustSpan,
-    Suggestion as RustSuggestion, config::RuleConfig as RustRuleConfig,
+    DiagnosticBuilder as RustDiagnosticBuilder, DiagnosticContext as RustDiagnosticContext,
+    LintConfig as RustLintConfig, Linter as RustLinter, Location as RustLocation,
+    Severity as RustSeverity, Span as RustSpan, Suggestion as RustSuggestion,
+    config::RuleConfig as RustRuleConfig,
 };
 use napi_derive::napi;
 use serde_json::Value as JsonValue;
@@ -38,6 +39,17 @@ impl From<RustSeverity> for Severity {
     }
 }
 
+impl From<Severity> for RustSeverity {
+    fn from(s: Severity) -> Self {
+        match s {
+            Severity::Error => Self::Error,
+            Severity::Warning => Self::Warning,
+            Severity::Info => Self::Info,
+            Severity::Hint => Self::Hint,
+        }
+    }
+}
+
 /// A position in the source file.
 #[napi(object)]
 #[derive(Debug, Clone)]
@@ -60,6 +72,12 @@ impl From<RustLocation> for Location {
     }
 }
 
+impl From<&Location> for RustLocation {
+    fn from(loc: &Location) -> Self {
+        Self::new(loc.line as usize, loc.column as usize, loc.offset as usize)
+    }
+}
+
 /// A span of text in the source file.
 #[napi(object)]
 #[derive(Debug, Clone)]
@@ -79,6 +97,12 @@ impl From<RustSpan> for Span {
     }
 }
 
+impl From<&Span> for RustSpan {
+    fn from(span: &Span) -> Self {
+        Self::new((&span.start).into(), (&span.end).into())
+    }
+}
+
 /// A single line of source context.
 #[napi(object)]
 #[derive(Debug, Clone)]
@@ -179,6 +203,18 @@ impl From<RustDiagnostic> for Diagnostic {
     }
 }
 
+impl From<&Diagnostic> for RustDiagnostic {
+    fn from(d: &Diagnostic) -> Self {
+        RustDiagnosticBuilder::new(
+            d.code.as_str(),
+            d.severity.into(),
+            d.message.as_str(),
+            (&d.span).into(),
+        )
+        .build_without_context()
+    }
+}
+
 /// Parses a severity string into a `RustSeverity`.
 ///
 /// # Errors
@@ -377,3 +413,39 @@ pub fn lint(source: String, config: Option<LintConfig>) -> napi::Result<Vec<Diag
         .map(convert_diagnostics)
         .map_err(|e| napi::Error::from_reason(format!("Linting failed: {e}")))
 }
+
+/// Converts a diagnostic to a Language Server Protocol `Diagnostic` object.
+///
+/// Positions are 0-indexed and severity uses LSP values
+/// (1 = error, 2 = warning, 3 = information, 4 = hint).
+///
+/// # Example
+///
+/// ```javascript
+/// const { lint, diagnosticToLspJson } = require('@fast-yaml/core');
+/// const [diagnostic] = lint('key: value\nkey: duplicate');
+/// const lsp = diagnosticToLspJson(diagnostic);
+/// // { range: { start: { line: 1, character: 0 }, ... }, severity: 1, ... }
+/// ```
+#[napi(ts_return_type = "Record<string, unknown>")]
+#[allow(clippy::needless_pass_by_value)]
+pub fn diagnostic_to_lsp_json(diagnostic: Diagnostic) -> JsonValue {
+    RustDiagnostic::from(&diagnostic).to_lsp_json()
+}
+
+/// Formats a diagnostic as `line:column: severity: message [code]` for VS Code
+/// task problem matchers.
+///
+/// # Example
+///
+/// ```javascript
+/// const { lint, diagnosticToProblemMatcher } = require('@fast-yaml/core');
+/// const [diagnostic] = lint('key: value\nkey: duplicate');
+/// diagnosticToProblemMatcher(diagnostic);
+/// // "2:1: error: duplicate key 'key' (first defined at line 1) [duplicate-key]"
+/// ```
+#[napi]
+#[allow(clippy::needless_pass_by_value)]
+pub fn diagnostic_to_problem_matcher(diagnostic: Diagnostic) -> String {
+    RustDiagnostic::from(&diagnostic).to_vscode_problem_matcher_str()
+}
@@ -51,6 +51,9 @@ pub struct ParallelConfig {
 
     /// Maximum number of documents allowed (default: 100k, max: 10M).
     pub max_documents: Option<u32>,
+
+    /// Inputs with fewer documents are parsed without the thread pool (default: 2).
+    pub sequential_threshold_docs: Option<u32>,
 }
 
 impl ParallelConfig {
@@ -112,6 +115,9 @@ impl ParallelConfig {
         if let Some(size) = self.min_chunk_size {
             config = config.with_sequential_threshold(size as usize);
         }
+        if let Some(docs) = self.sequential_threshold_docs {
+            config = config.with_sequential_threshold_docs(docs as usize);
+        }
 
         Ok(config)
     }
@@ -153,7 +159,8 @@ fn to_static(v: Unknown<'_>) -> Unknown<'static> {
 ///
 /// # Performance
 ///
-/// - Single document: Falls back to sequential parsing
+/// - Single document: Falls back to sequential parsing (see
+///   `sequentialThresholdDocs`)
 /// - Multi-document: 2-3x faster on 4-8 core systems
 /// - Use for files > 1MB with multiple documents
 ///
@@ -292,8 +299,10 @@ mod tests {
             max_chunk_size: Some(5 * 1024 * 1024),
             max_input_size: Some(50 * 1024 * 1024),
             max_documents: Some(50_000),
+            sequential_threshold_docs: Some(4),
         };
-        assert!(config.to_rust_config().is_ok());
+        let rust_config = config.to_rust_config().unwrap();
+        assert_eq!(rust_config.sequential_threshold_docs(), 4);
 
         // Invalid thread count
         let config = ParallelConfig {
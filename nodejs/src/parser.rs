@@ -3,12 +3,12 @@
 //! This module provides safe YAML parsing functions that convert YAML strings
 //! to JavaScript objects.
 
-use crate::Schema;
-use crate::conversion::yaml_to_js;
-use fast_yaml_core::canonicalize;
+use crate::conversion::{Reviver, apply_reviver, yaml_to_js};
+use crate::{Schema, YamlError};
+use fast_yaml_core::{ParseError, Parser, canonicalize};
 use napi::{Env, Result as NapiResult, bindgen_prelude::*};
 use napi_derive::napi;
-use saphyr::{LoadableYamlNode, ScalarOwned, YamlOwned};
+use saphyr::{LoadableYamlNode, ScalarOwned, ScanError, YamlOwned};
 
 /// Maximum input size in bytes for `safe_load`/`safe_load_all` (100MB).
 ///
@@ -31,6 +31,59 @@ pub struct LoadOptions {
     pub allow_duplicate_keys: Option<bool>,
 }
 
+/// Outcome of `validateYaml`.
+#[napi(object)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidateResult {
+    /// True if the input parsed successfully.
+    pub valid: bool,
+
+    /// Error message if the input is invalid.
+    pub error: Option<String>,
+
+    /// Line of the parse error (0-indexed, as in `YamlError.mark`).
+    pub line: Option<u32>,
+
+    /// Column of the parse error (0-indexed, as in `YamlError.mark`).
+    pub column: Option<u32>,
+}
+
+impl ValidateResult {
+    /// Check `yaml_str` without converting it to JavaScript values.
+    fn check(yaml_str: &str) -> Self {
+        if yaml_str.len() > MAX_INPUT_SIZE {
+            return Self {
+                valid: false,
+                error: Some(format!(
+                    "input size {} exceeds maximum allowed {} (100MB)",
+                    yaml_str.len(),
+                    MAX_INPUT_SIZE
+                )),
+                line: None,
+                column: None,
+            };
+        }
+
+        match YamlOwned::load_from_str(yaml_str) {
+            Ok(_) => Self {
+                valid: true,
+                error: None,
+                line: None,
+                column: None,
+            },
+            Err(e) => {
+                let marker = e.marker();
+                Self {
+                    valid: false,
+                    error: Some(format!("YAML parse error: {e}")),
+                    line: Some(u32::try_from(marker.line().saturating_sub(1)).unwrap_or(u32::MAX)),
+                    column: Some(u32::try_from(marker.col()).unwrap_or(u32::MAX)),
+                }
+            }
+        }
+    }
+}
+
 /// Coerce `Unknown<'env>` to `Unknown<'static>` for returning from `#[napi]` functions.
 ///
 /// # Safety
@@ -58,6 +111,15 @@ fn throw_and_undefined(env: Env, msg: &str) -> NapiResult<Unknown<'static>> {
     Ok(to_static(undef))
 }
 
+/// Throw a `YamlError` for `error` and return a JS `undefined` sentinel, as
+/// `throw_and_undefined` does for plain errors.
+#[inline]
+fn throw_parse_error(env: Env, error: &ScanError) -> NapiResult<Unknown<'static>> {
+    YamlError::from_scan_error(error).throw(env)?;
+    let undef = ().into_unknown(&env)?;
+    Ok(to_static(undef))
+}
+
 /// Parse a YAML string and return a JavaScript object.
 ///
 /// This is equivalent to js-yaml's `safeLoad()` and `PyYAML`'s `safe_load()`.
@@ -65,6 +127,8 @@ fn throw_and_undefined(env: Env, msg: &str) -> NapiResult<Unknown<'static>> {
 /// # Arguments
 ///
 /// * `yaml_str` - A YAML document as a string
+/// * `reviver` - Optional `(key, value) => value` callback, applied to the result as
+///   `JSON.parse` applies its reviver
 ///
 /// # Returns
 ///
@@ -73,7 +137,8 @@ fn throw_and_undefined(env: Env, msg: &str) -> NapiResult<Unknown<'static>> {
 /// # Errors
 ///
 /// Throws an error if:
-/// - The YAML is invalid
+/// - The YAML is invalid (a `YamlError` with `kind` `"ParseError"` and the
+///   failure position in `mark`)
 /// - Input exceeds size limit (100MB)
 ///
 /// # Security
@@ -87,11 +152,19 @@ fn throw_and_undefined(env: Env, msg: &str) -> NapiResult<Unknown<'static>> {
 ///
 /// const data = safeLoad('name: test\nvalue: 123');
 /// console.log(data); // { name: 'test', value: 123 }
+///
+/// const dated = safeLoad('created: 2024-01-15', (key, value) =>
+///   key === 'created' ? new Date(value) : value,
+/// );
 /// ```
 // NAPI-RS requires String by value for proper FFI handling
 #[allow(clippy::needless_pass_by_value)]
-#[napi]
-pub fn safe_load(env: Env, yaml_str: String) -> NapiResult<Unknown<'static>> {
+#[napi(strict)]
+pub fn safe_load(
+    env: Env,
+    yaml_str: String,
+    reviver: Option<Reviver<'_>>,
+) -> NapiResult<Unknown<'static>> {
     // Validate input size to prevent DoS attacks
     if yaml_str.len() > MAX_INPUT_SIZE {
         return throw_and_undefined(
@@ -107,7 +180,7 @@ pub fn safe_load(env: Env, yaml_str: String) -> NapiResult<Unknown<'static>> {
     // Parse YAML string
     let docs = match YamlOwned::load_from_str(&yaml_str) {
         Ok(d) => d,
-        Err(e) => return throw_and_undefined(env, &format!("YAML parse error: {e}")),
+        Err(e) => return throw_parse_error(env, &e),
     };
 
     // Convert first document to JavaScript (or null if empty)
@@ -119,9 +192,15 @@ pub fn safe_load(env: Env, yaml_str: String) -> NapiResult<Unknown<'static>> {
             .unwrap_or(YamlOwned::Value(ScalarOwned::Null))
     };
 
-    match yaml_to_js(&env, &canonicalize(doc)) {
-        Ok(v) => Ok(to_static(v)),
-        Err(e) => throw_and_undefined(env, &e.to_string()),
+    let value = match yaml_to_js(&env, &canonicalize(doc)) {
+        Ok(v) => v,
+        Err(e) => return throw_and_undefined(env, &e.to_string()),
+    };
+
+    // An exception thrown by the reviver propagates unchanged
+    match reviver {
+        Some(reviver) => apply_reviver(&env, value, &reviver).map(to_static),
+        None => Ok(to_static(value)),
     }
 }
 
@@ -140,7 +219,7 @@ pub fn safe_load(env: Env, yaml_str: String) -> NapiResult<Unknown<'static>> {
 /// # Errors
 ///
 /// Throws an error if:
-/// - The YAML is invalid
+/// - The YAML is invalid (a `YamlError`, as for `safeLoad`)
 /// - Input exceeds size limit (100MB)
 ///
 /// # Security
@@ -173,18 +252,28 @@ pub fn safe_load_all(env: Env, yaml_str: String) -> NapiResult<Vec<Unknown<'stat
     }
 
     // Parse YAML string
-    let docs = match YamlOwned::load_from_str(&yaml_str) {
+    let docs = match Parser::parse_all(&yaml_str) {
         Ok(d) => d,
+        Err(ParseError::Scanner(e)) => {
+            YamlError::from_scan_error(&e).throw(env)?;
+            return Ok(Vec::new());
+        }
         Err(e) => {
-            env.throw_error(&format!("YAML parse error: {e}"), None)?;
+            env.throw_error(&e.to_string(), None)?;
             return Ok(Vec::new());
         }
     };
 
+    // `parse_all` injects a null document for a stream without any; like
+    // js-yaml's `loadAll`, an empty stream yields an empty array here
+    if is_empty_stream(&yaml_str) {
+        return Ok(Vec::new());
+    }
+
     // Convert all documents to JavaScript
     let mut js_docs = Vec::with_capacity(docs.len());
     for doc in docs {
-        match yaml_to_js(&env, &canonicalize(doc)) {
+        match yaml_to_js(&env, &doc) {
             Ok(v) => js_docs.push(to_static(v)),
             Err(e) => {
                 env.throw_error(&e.to_string(), None)?;
@@ -196,6 +285,16 @@ pub fn safe_load_all(env: Env, yaml_str: String) -> NapiResult<Vec<Unknown<'stat
     Ok(js_docs)
 }
 
+/// Returns `true` if `input` holds no documents: only blank lines, comments
+/// and `...` document end markers.
+fn is_empty_stream(input: &str) -> bool {
+    input.lines().all(|line| {
+        let line = line.trim();
+        let line = line.strip_prefix("...").unwrap_or(line).trim_start();
+        line.is_empty() || line.starts_with('#')
+    })
+}
+
 /// Parse a YAML string with options (js-yaml compatible).
 ///
 /// This is the js-yaml compatible `load()` function that accepts an options object.
@@ -205,6 +304,7 @@ pub fn safe_load_all(env: Env, yaml_str: String) -> NapiResult<Vec<Unknown<'stat
 ///
 /// * `yaml_str` - A YAML document as a string
 /// * `options` - Optional parsing options (schema, filename, etc.)
+/// * `reviver` - Optional `(key, value) => value` callback, as for `safeLoad`
 ///
 /// # Returns
 ///
@@ -226,17 +326,18 @@ pub fn safe_load_all(env: Env, yaml_str: String) -> NapiResult<Vec<Unknown<'stat
 /// ```
 // NAPI-RS requires String by value for proper FFI handling
 #[allow(clippy::needless_pass_by_value)]
-#[napi]
+#[napi(strict)]
 pub fn load(
     env: Env,
     yaml_str: String,
     options: Option<LoadOptions>,
+    reviver: Option<Reviver<'_>>,
 ) -> NapiResult<Unknown<'static>> {
     // Options are accepted for API compatibility but schema is ignored (safe by default)
     let _opts = options.unwrap_or_default();
 
     // Delegate to safe_load
-    safe_load(env, yaml_str)
+    safe_load(env, yaml_str, reviver)
 }
 
 /// Parse a YAML string containing multiple documents with options (js-yaml compatible).
@@ -282,6 +383,35 @@ pub fn load_all(
     safe_load_all(env, yaml_str)
 }
 
+/// Check whether a string is valid YAML without converting it to JavaScript.
+///
+/// Every document in the input is parsed. Invalid input is reported in the
+/// result instead of being thrown.
+///
+/// # Arguments
+///
+/// * `yaml_str` - A YAML string, possibly containing multiple documents
+///
+/// # Returns
+///
+/// `{ valid: true }` on success, or `{ valid: false, error, line, column }`
+/// with the 0-indexed position of the parse error
+///
+/// # Example
+///
+/// ```javascript
+/// const { validateYaml } = require('@fast-yaml/core');
+///
+/// validateYaml('name: test'); // { valid: true }
+/// validateYaml('key: [unclosed'); // { valid: false, error: '...', line: 1, column: 0 }
+/// ```
+// NAPI-RS requires String by value for proper FFI handling
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn validate_yaml(yaml_str: String) -> NapiResult<ValidateResult> {
+    Ok(ValidateResult::check(&yaml_str))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,6 +435,23 @@ mod tests {
         assert_eq!(docs.len(), 2);
     }
 
+    #[test]
+    fn test_is_empty_stream() {
+        for input in [
+            "",
+            "   ",
+            "\n\n",
+            "# comment\n",
+            "# a\n...\n# b\n",
+            "... # end\n",
+        ] {
+            assert!(is_empty_stream(input), "{input:?}");
+        }
+        for input in ["---\n", "--- # x\n", "null\n", "....\n", "# a\nkey: 1\n"] {
+            assert!(!is_empty_stream(input), "{input:?}");
+        }
+    }
+
     #[test]
     fn test_parse_invalid() {
         let yaml = "invalid: [\n";
@@ -331,4 +478,22 @@ mod tests {
         assert_eq!(opts.filename, Some("test.yaml".to_string()));
         assert_eq!(opts.allow_duplicate_keys, Some(true));
     }
+
+    #[test]
+    fn test_validate_valid() {
+        let result = ValidateResult::check("---\nfoo: 1\n---\nbar: 2");
+        assert!(result.valid);
+        assert_eq!(result.error, None);
+        assert_eq!(result.line, None);
+        assert_eq!(result.column, None);
+    }
+
+    #[test]
+    fn test_validate_invalid() {
+        let result = ValidateResult::check("key: value\n  bad: indent\n");
+        assert!(!result.valid);
+        assert!(result.error.unwrap().starts_with("YAML parse error"));
+        assert_eq!(result.line, Some(1));
+        assert_eq!(result.column, Some(5));
+    }
 }
@@ -0,0 +1,451 @@
+//! Incremental YAML parsing of Node.js readable streams.
+//!
+//! `parseStream` listens to a readable stream, buffers incoming chunks until a
+//! document boundary is seen, and parses each complete document on the async
+//! runtime, so large multi-document inputs (e.g. YAML logs) never have to be
+//! held in memory at once.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::conversion::yaml_to_js;
+use crate::parser::LoadOptions;
+use fast_yaml_core::canonicalize;
+use memchr::{memchr, memchr_iter};
+use napi::bindgen_prelude::*;
+use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi::tokio::sync::{Mutex, mpsc};
+use napi::{Env, Result as NapiResult};
+use napi_derive::napi;
+use saphyr::{LoadableYamlNode, YamlOwned};
+
+/// Maximum size in bytes of a single streamed document (100MB).
+///
+/// This limit prevents denial-of-service attacks via a stream that never
+/// reaches a document boundary.
+const MAX_DOCUMENT_SIZE: usize = 100 * 1024 * 1024;
+
+/// Number of received but unparsed bytes above which the stream is paused.
+const HIGH_WATER_MARK: usize = 1024 * 1024;
+
+/// Number of received but unparsed bytes below which a paused stream is resumed.
+const LOW_WATER_MARK: usize = HIGH_WATER_MARK / 4;
+
+/// Events forwarded from the readable stream's listeners.
+enum StreamEvent {
+    Data(Vec<u8>),
+    End,
+    Error(String),
+}
+
+/// An event listener created from a Rust closure.
+type Listener<'env> = Function<'env, Unknown<'env>, ()>;
+
+/// Flow-control state shared by the stream listeners and the iterator.
+#[derive(Default)]
+struct FlowControl {
+    queued_bytes: AtomicUsize,
+    paused: AtomicBool,
+}
+
+/// Splits a byte stream into complete YAML documents at line granularity.
+///
+/// A document ends before a `---` marker line that follows document content,
+/// or after a `...` marker line. Directives, comments and blank lines before a
+/// `---` marker belong to the document the marker starts.
+#[derive(Debug, Default)]
+struct DocumentSplitter {
+    /// Bytes of the current document, starting at its first line.
+    buffer: Vec<u8>,
+    /// Length of the prefix of `buffer` already scanned for markers.
+    scanned: usize,
+    /// Whether the current document has content beyond directives and comments.
+    has_content: bool,
+    /// 0-indexed line of the stream at which the current document starts.
+    start_line: usize,
+}
+
+/// A complete document cut from the stream, with its first line (0-indexed).
+#[derive(Debug, PartialEq, Eq)]
+struct DocumentText {
+    start_line: usize,
+    text: Vec<u8>,
+}
+
+impl DocumentSplitter {
+    /// Append a chunk and return the documents it completes.
+    fn push(&mut self, chunk: &[u8]) -> Vec<DocumentText> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut documents = Vec::new();
+        while let Some(offset) = memchr(b'\n', &self.buffer[self.scanned..]) {
+            let line_start = self.scanned;
+            let line = &self.buffer[line_start..=line_start + offset];
+
+            if is_marker(line, b"---") {
+                if self.has_content {
+                    documents.push(self.cut(line_start));
+                }
+                self.has_content = true;
+                self.scanned += offset + 1;
+            } else if is_marker(line, b"...") {
+                self.scanned += offset + 1;
+                documents.push(self.cut(self.scanned));
+            } else {
+                if !matches!(line.first(), None | Some(b'\r' | b'\n' | b'#' | b'%')) {
+                    self.has_content = true;
+                }
+                self.scanned += offset + 1;
+            }
+        }
+        documents
+    }
+
+    /// Return the remaining input as the last document, if there is any.
+    fn finish(&mut self) -> Option<DocumentText> {
+        if self.buffer.is_empty() {
+            return None;
+        }
+        self.scanned = self.buffer.len();
+        Some(self.cut(self.scanned))
+    }
+
+    /// Size in bytes of the document being accumulated.
+    fn pending_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Split off the first `len` bytes of the buffer as a document.
+    fn cut(&mut self, len: usize) -> DocumentText {
+        let rest = self.buffer.split_off(len);
+        let text = std::mem::replace(&mut self.buffer, rest);
+        let document = DocumentText {
+            start_line: self.start_line,
+            text,
+        };
+        self.start_line += memchr_iter(b'\n', &document.text).count();
+        self.scanned -= len;
+        self.has_content = false;
+        document
+    }
+}
+
+/// Check whether `line` is the document marker `marker`, alone or followed by
+/// whitespace.
+fn is_marker(line: &[u8], marker: &[u8]) -> bool {
+    line.strip_prefix(marker)
+        .is_some_and(|rest| matches!(rest.first(), None | Some(b' ' | b'\t' | b'\r' | b'\n')))
+}
+
+/// Parse a complete document cut from the stream.
+fn parse_document(document: &DocumentText) -> NapiResult<Vec<YamlOwned>> {
+    let text = std::str::from_utf8(&document.text).map_err(|e| {
+        napi::Error::from_reason(format!(
+            "invalid UTF-8 in document starting at line {}: {e}",
+            document.start_line + 1
+        ))
+    })?;
+    let docs = YamlOwned::load_from_str(text).map_err(|e| {
+        napi::Error::from_reason(format!(
+            "YAML parse error in document starting at line {}: {e}",
+            document.start_line + 1
+        ))
+    })?;
+    Ok(docs.into_iter().map(canonicalize).collect())
+}
+
+/// A parsed document yielded by a `YamlStream`.
+pub struct StreamDocument(YamlOwned);
+
+impl ToNapiValue for StreamDocument {
+    unsafe fn to_napi_value(
+        env: napi::sys::napi_env,
+        val: Self,
+    ) -> NapiResult<napi::sys::napi_value> {
+        let env = Env::from_raw(env);
+        Ok(yaml_to_js(&env, &val.0)?.raw())
+    }
+}
+
+impl TypeName for StreamDocument {
+    fn type_name() -> &'static str {
+        "unknown"
+    }
+
+    fn value_type() -> ValueType {
+        ValueType::Unknown
+    }
+}
+
+/// Iterator state, shared with the futures returned by `next()`.
+struct StreamState {
+    events: mpsc::UnboundedReceiver<StreamEvent>,
+    splitter: DocumentSplitter,
+    parsed: VecDeque<YamlOwned>,
+    flow: Arc<FlowControl>,
+    resume: ThreadsafeFunction<(), Unknown<'static>, (), Status, false, true>,
+    done: bool,
+}
+
+impl StreamState {
+    /// Return the next parsed document, waiting for more input as needed.
+    async fn next_document(&mut self) -> NapiResult<Option<YamlOwned>> {
+        loop {
+            if let Some(doc) = self.parsed.pop_front() {
+                return Ok(Some(doc));
+            }
+            if self.done {
+                return Ok(None);
+            }
+
+            let documents = match self.events.recv().await {
+                Some(StreamEvent::Data(chunk)) => {
+                    self.release(chunk.len());
+                    let documents = self.splitter.push(&chunk);
+                    if self.splitter.pending_len() > MAX_DOCUMENT_SIZE {
+                        self.done = true;
+                        return Err(napi::Error::from_reason(format!(
+                            "document size exceeds maximum allowed {MAX_DOCUMENT_SIZE} (100MB)"
+                        )));
+                    }
+                    documents
+                }
+                Some(StreamEvent::End) | None => {
+                    self.done = true;
+                    self.splitter.finish().into_iter().collect()
+                }
+                Some(StreamEvent::Error(message)) => {
+                    self.done = true;
+                    return Err(napi::Error::from_reason(message));
+                }
+            };
+
+            for document in &documents {
+                match parse_document(document) {
+                    Ok(docs) => self.parsed.extend(docs),
+                    Err(e) => {
+                        self.done = true;
+                        return Err(e);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Account for `len` consumed bytes and resume the stream once drained.
+    fn release(&self, len: usize) {
+        let queued = self.flow.queued_bytes.fetch_sub(len, Ordering::AcqRel) - len;
+        if queued <= LOW_WATER_MARK && self.flow.paused.swap(false, Ordering::AcqRel) {
+            self.resume
+                .call((), ThreadsafeFunctionCallMode::NonBlocking);
+        }
+    }
+}
+
+/// Async iterable over the documents of a readable stream.
+///
+/// Returned by `parseStream`; consume it with `for await...of`.
+#[napi(async_iterator)]
+pub struct YamlStream {
+    state: Arc<Mutex<StreamState>>,
+}
+
+#[napi]
+impl AsyncGenerator for YamlStream {
+    type Yield = StreamDocument;
+    type Next = ();
+    type Return = ();
+
+    fn next(
+        &mut self,
+        _value: Option<Self::Next>,
+    ) -> impl Future<Output = NapiResult<Option<Self::Yield>>> + Send + 'static {
+        let state = Arc::clone(&self.state);
+        async move {
+            let mut state = state.lock().await;
+            Ok(state.next_document().await?.map(StreamDocument))
+        }
+    }
+}
+
+/// Parse the YAML documents of a readable stream incrementally.
+///
+/// Chunks are buffered until a document boundary (`---` or `...`) is seen, and
+/// each complete document is parsed off the main thread and yielded in order.
+/// The stream is paused while more than 1MB of input waits to be parsed.
+///
+/// # Arguments
+///
+/// * `readable` - A readable stream of `Buffer` or string chunks
+/// * `options` - Optional parsing options (schema, filename, etc.)
+///
+/// # Returns
+///
+/// An async iterable of parsed JavaScript values, one per document
+///
+/// # Errors
+///
+/// Iteration rejects if:
+/// - A document is invalid YAML or invalid UTF-8
+/// - A single document exceeds the size limit (100MB)
+/// - The stream emits an `error` event
+///
+/// # Example
+///
+/// ```javascript
+/// const { createReadStream } = require('node:fs');
+/// const { parseStream } = require('@fast-yaml/core');
+///
+/// for await (const doc of parseStream(createReadStream('events.yaml'))) {
+///   console.log(doc);
+/// }
+/// ```
+#[napi(ts_args_type = "readable: NodeJS.ReadableStream, options?: LoadOptions | undefined | null")]
+#[allow(clippy::needless_pass_by_value)]
+pub fn parse_stream(
+    env: Env,
+    readable: Object<'_>,
+    options: Option<LoadOptions>,
+) -> NapiResult<YamlStream> {
+    // Options are accepted for API compatibility but schema is ignored (safe by default)
+    let _opts = options.unwrap_or_default();
+
+    let (sender, events) = mpsc::unbounded_channel();
+    let flow = Arc::new(FlowControl::default());
+    let on: Function<'_, FnArgs<(&str, Listener<'_>)>, Unknown<'_>> =
+        readable.get_named_property("on")?;
+
+    let data_sender = sender.clone();
+    let data_flow = Arc::clone(&flow);
+    let on_data = env.create_function_from_closure("onData", move |ctx| {
+        let chunk = match ctx.first_arg::<Either<String, BufferSlice>>()? {
+            Either::A(text) => text.into_bytes(),
+            Either::B(buffer) => buffer.to_vec(),
+        };
+        let queued = data_flow
+            .queued_bytes
+            .fetch_add(chunk.len(), Ordering::AcqRel)
+            + chunk.len();
+        // The iterator has been dropped; nothing will read this chunk
+        if data_sender.send(StreamEvent::Data(chunk)).is_err() {
+            return Ok(());
+        }
+        if queued > HIGH_WATER_MARK && !data_flow.paused.swap(true, Ordering::AcqRel) {
+            let this: Object<'_> = ctx.this()?;
+            let pause: Function<'_, (), Unknown<'_>> = this.get_named_property("pause")?;
+            pause.apply(this, ())?;
+        }
+        Ok(())
+    })?;
+    on.apply(readable, ("data", on_data).into())?;
+
+    let end_sender = sender.clone();
+    let on_end = env.create_function_from_closure("onEnd", move |_ctx| {
+        let _ = end_sender.send(StreamEvent::End);
+        Ok(())
+    })?;
+    on.apply(readable, ("end", on_end).into())?;
+
+    let on_error = env.create_function_from_closure("onError", move |ctx| {
+        let message = ctx
+            .first_arg::<Object<'_>>()
+            .and_then(|error| error.get_named_property::<String>("message"))
+            .unwrap_or_else(|_| "stream error".to_string());
+        let _ = sender.send(StreamEvent::Error(message));
+        Ok(())
+    })?;
+    on.apply(readable, ("error", on_error).into())?;
+
+    // Bind `resume` to the stream so the iterator can call it from the async runtime
+    let resume: Function<'_, (), Unknown<'_>> = readable.get_named_property("resume")?;
+    let bind: Function<'_, Object<'_>, Function<'_, (), Unknown<'static>>> =
+        resume.get_named_property("bind")?;
+    let resume = bind
+        .apply(resume, readable)?
+        .build_threadsafe_function()
+        .callee_handled::<false>()
+        .weak::<true>()
+        .build()?;
+
+    Ok(YamlStream {
+        state: Arc::new(Mutex::new(StreamState {
+            events,
+            splitter: DocumentSplitter::default(),
+            parsed: VecDeque::new(),
+            flow,
+            resume,
+            done: false,
+        })),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(documents: &[DocumentText]) -> Vec<(usize, &str)> {
+        documents
+            .iter()
+            .map(|d| (d.start_line, std::str::from_utf8(&d.text).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_split_at_document_markers() {
+        let mut splitter = DocumentSplitter::default();
+        let documents = splitter.push(b"a: 1\n---\nb: 2\n--- c\n");
+        assert_eq!(texts(&documents), vec![(0, "a: 1\n"), (1, "---\nb: 2\n")]);
+        let last = splitter.finish().unwrap();
+        assert_eq!(
+            (last.start_line, last.text.as_slice()),
+            (3, &b"--- c\n"[..])
+        );
+    }
+
+    #[test]
+    fn test_split_across_chunks() {
+        let mut splitter = DocumentSplitter::default();
+        assert!(splitter.push(b"---\nkey: va").is_empty());
+        assert!(splitter.push(b"lue\n-").is_empty());
+        let documents = splitter.push(b"--\nnext: 1");
+        assert_eq!(texts(&documents), vec![(0, "---\nkey: value\n")]);
+        assert_eq!(splitter.finish().unwrap().text, b"---\nnext: 1");
+        assert!(splitter.finish().is_none());
+    }
+
+    #[test]
+    fn test_split_keeps_directives_with_their_document() {
+        let mut splitter = DocumentSplitter::default();
+        let documents =
+            splitter.push(b"# header\n%YAML 1.2\n---\na: 1\n...\n%YAML 1.2\n---\nb: 2\n");
+        assert_eq!(
+            texts(&documents),
+            vec![(0, "# header\n%YAML 1.2\n---\na: 1\n...\n")]
+        );
+        assert_eq!(splitter.finish().unwrap().text, b"%YAML 1.2\n---\nb: 2\n");
+    }
+
+    #[test]
+    fn test_split_ignores_marker_prefixes() {
+        let mut splitter = DocumentSplitter::default();
+        assert!(splitter.push(b"a: 1\n----\n---x\n  ---\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_document() {
+        let document = DocumentText {
+            start_line: 4,
+            text: b"---\na: 1\n".to_vec(),
+        };
+        assert_eq!(parse_document(&document).unwrap().len(), 1);
+
+        let invalid = DocumentText {
+            start_line: 4,
+            text: b"a: [1\n".to_vec(),
+        };
+        let message = parse_document(&invalid).unwrap_err().reason;
+        assert!(message.contains("starting at line 5"), "{message}");
+    }
+}
@@ -20,6 +20,7 @@ For drop-in replacement of PyYAML:
 
 from __future__ import annotations
 
+import os
 from collections.abc import Iterator
 from typing import IO, Any
 
@@ -41,8 +42,10 @@ from ._core import (
     ParserError,
     SafeDumper,
     SafeLoader,
+    SafeLoadIter,
     ScannerError,
     YAMLError,
+    YamlScanError,
 )
 from ._core import dump as _dump
 from ._core import dump_all as _dump_all
@@ -53,13 +56,19 @@ from ._core import safe_dump_all as _safe_dump_all
 from ._core import safe_dump_to as _safe_dump_to
 from ._core import safe_load as _safe_load
 from ._core import safe_load_all as _safe_load_all
+from ._core import safe_load_bytes as _safe_load_bytes
+from ._core import safe_load_path as _safe_load_path
+from ._core import safe_load_iter as _safe_load_iter
 from ._core import version as _version
 
 __version__ = _version()
 __all__ = [
     # Core functions
     "safe_load",
+    "safe_load_bytes",
+    "safe_load_path",
     "safe_load_all",
+    "safe_load_iter",
     "safe_dump",
     "safe_dump_all",
     "safe_dump_to",
@@ -75,6 +84,7 @@ __all__ = [
     "SafeLoader",
     "FullLoader",
     "Loader",
+    "SafeLoadIter",
     # Dumper classes
     "SafeDumper",
     "Dumper",
@@ -86,6 +96,7 @@ __all__ = [
     "ComposerError",
     "ConstructorError",
     "EmitterError",
+    "YamlScanError",
     # Mark
     "Mark",
 ]
@@ -104,7 +115,9 @@ def safe_load(stream: str | bytes | IO[str] | IO[bytes]) -> Any:
         The parsed YAML document as Python objects (dict, list, str, int, float, bool, None).
 
     Raises:
-        ValueError: If the YAML is invalid.
+        YamlScanError: If the YAML is invalid. This is a subclass of ValueError
+            with 0-indexed ``line`` and ``column`` attributes.
+        UnicodeDecodeError: If bytes input is not valid UTF-8.
 
     Example:
         >>> import fast_yaml
@@ -113,19 +126,65 @@ def safe_load(stream: str | bytes | IO[str] | IO[bytes]) -> Any:
         >>> fast_yaml.safe_load("items:\\n  - one\\n  - two")
         {'items': ['one', 'two']}
     """
-    if hasattr(stream, "read"):
-        # File-like object
-        content = stream.read()
-        if isinstance(content, bytes):
-            content = content.decode("utf-8")
-    elif isinstance(stream, bytes):
-        content = stream.decode("utf-8")
-    else:
-        content = stream
+    content = stream.read() if hasattr(stream, "read") else stream
+    if isinstance(content, bytes):
+        return _safe_load_bytes(content)
 
     return _safe_load(content)
 
 
+def safe_load_bytes(data: bytes) -> Any:
+    """
+    Parse a UTF-8 encoded YAML document and return a Python object.
+
+    A leading UTF-8 byte order mark is skipped.
+
+    Args:
+        data: A YAML document as UTF-8 bytes.
+
+    Returns:
+        The parsed YAML document as Python objects.
+
+    Raises:
+        UnicodeDecodeError: If data is not valid UTF-8. This is a subclass of
+            ValueError; its ``start`` is the byte offset of the first invalid sequence.
+        YamlScanError: If the YAML is invalid.
+
+    Example:
+        >>> import fast_yaml
+        >>> fast_yaml.safe_load_bytes(b"\\xef\\xbb\\xbfname: test")
+        {'name': 'test'}
+    """
+    return _safe_load_bytes(data)
+
+
+def safe_load_path(path: str | os.PathLike[str]) -> Any:
+    """
+    Read a YAML file and return a Python object.
+
+    The file is read in Rust with the GIL released, so there is no
+    Python-side ``open()``/``read()`` step.
+
+    Args:
+        path: Path to a UTF-8 encoded YAML file.
+
+    Returns:
+        The parsed YAML document as Python objects.
+
+    Raises:
+        FileNotFoundError: If the file does not exist.
+        PermissionError: If the file cannot be read.
+        UnicodeDecodeError: If the file is not valid UTF-8.
+        YamlScanError: If the YAML is invalid.
+
+    Example:
+        >>> import fast_yaml
+        >>> fast_yaml.safe_load_path("config.yaml")
+        {'name': 'test'}
+    """
+    return _safe_load_path(path)
+
+
 def safe_load_all(stream: str | bytes | IO[str] | IO[bytes]) -> Iterator[Any]:
     """
     Parse all YAML documents in a stream and return an iterator.
@@ -156,16 +215,63 @@ def safe_load_all(stream: str | bytes | IO[str] | IO[bytes]) -> Iterator[Any]:
     return iter(_safe_load_all(content))
 
 
+def safe_load_iter(stream: str | bytes | IO[str] | IO[bytes]) -> SafeLoadIter:
+    """
+    Parse the YAML documents in a stream lazily, one per iteration.
+
+    Unlike `safe_load_all`, each document is only converted to Python objects
+    when the iterator reaches it, so large multi-document streams can be
+    processed without holding every document in memory.
+
+    Args:
+        stream: A YAML string potentially containing multiple documents.
+
+    Yields:
+        Parsed YAML documents.
+
+    Raises:
+        YamlScanError: When iteration reaches invalid YAML.
+
+    Example:
+        >>> import fast_yaml
+        >>> for doc in fast_yaml.safe_load_iter("---\\nfoo: 1\\n---\\nbar: 2"):
+        ...     print(doc)
+        {'foo': 1}
+        {'bar': 2}
+    """
+    if hasattr(stream, "read"):
+        content = stream.read()
+        if isinstance(content, bytes):
+            content = content.decode("utf-8")
+    elif isinstance(stream, bytes):
+        content = stream.decode("utf-8")
+    else:
+        content = stream
+
+    return _safe_load_iter(content)
+
+
+def _check_safe_dumper(dumper: Any) -> None:
+    """Raise TypeError unless ``dumper`` is None or a SafeDumper/Dumper class or instance."""
+    if dumper is None:
+        return
+    dumper_type = dumper if isinstance(dumper, type) else type(dumper)
+    if not issubclass(dumper_type, (SafeDumper, Dumper)):
+        raise TypeError(f"safe_dump only supports SafeDumper, got {dumper_type.__name__}")
+
+
 def safe_dump(
     data: Any,
     stream: IO[str] | None = None,
     *,
+    Dumper: type | None = None,  # noqa: N803 - PyYAML API compatibility
     allow_unicode: bool = True,
     sort_keys: bool = False,
     indent: int | None = None,
     width: int | None = None,
     explicit_start: bool = False,
     default_flow_style: bool | None = None,
+    default_style: str | None = None,
 ) -> str | None:
     """
     Serialize a Python object to a YAML string.
@@ -175,18 +281,28 @@ def safe_dump(
     Args:
         data: A Python object to serialize.
         stream: If provided, write to this file-like object and return None.
-        allow_unicode: If True, allow unicode characters in output. Default: True.
+        Dumper: Accepted for PyYAML compatibility; only None, SafeDumper and
+            Dumper (classes or instances) are allowed.
+        allow_unicode: If False, escape non-ASCII characters. Default: True.
         sort_keys: If True, sort dictionary keys. Default: False.
-        indent: Number of spaces for indentation. Default: 2.
-        width: Maximum line width. Default: 80.
+        indent: Number of spaces for indentation, clamped to 1-9. Default: 2.
+        width: Maximum line width; longer string values are wrapped in
+            folded style (``>-``). Default: 80.
         explicit_start: If True, add explicit document start marker (---). Default: False.
-        default_flow_style: Force flow style for collections. Default: None.
+        default_flow_style: If True, render all collections in flow style
+            (``{key: value}``, ``[a, b]``). Default: None (block style).
+        default_style: Style for every string scalar: ``'"'``, ``"'"``,
+            ``'|'`` or ``'>'``. Strings a style cannot hold, mapping keys
+            with ``'|'``/``'>'`` and flow collections fall back to double
+            quotes; numbers, booleans and None stay plain. Default: None.
 
     Returns:
         A YAML string if stream is None, otherwise None.
 
     Raises:
-        TypeError: If the object contains types that cannot be serialized.
+        TypeError: If the object contains types that cannot be serialized,
+            or Dumper is not a safe dumper.
+        ValueError: If default_style is not a supported style.
 
     Example:
         >>> import fast_yaml
@@ -194,7 +310,10 @@ def safe_dump(
         'name: test\\nvalue: 123\\n'
         >>> fast_yaml.safe_dump({'k': 'v'}, explicit_start=True)
         '---\\nk: v\\n'
+        >>> fast_yaml.safe_dump({'k': 'v'}, default_style='"')
+        '"k": "v"\\n'
     """
+    _check_safe_dumper(Dumper)
     result = _safe_dump(
         data,
         allow_unicode=allow_unicode,
@@ -203,6 +322,7 @@ def safe_dump(
         width=width if width is not None else 80,
         explicit_start=explicit_start,
         default_flow_style=default_flow_style,
+        default_style=default_style,
     )
 
     if stream is not None:
@@ -216,12 +336,14 @@ def safe_dump_all(
     documents: Iterator[Any],
     stream: IO[str] | None = None,
     *,
+    Dumper: type | None = None,  # noqa: N803 - PyYAML API compatibility
     allow_unicode: bool = True,
     sort_keys: bool = False,
     indent: int | None = None,
     width: int | None = None,
     explicit_start: bool = False,
     default_flow_style: bool | None = None,
+    default_style: str | None = None,
 ) -> str | None:
     """
     Serialize multiple Python objects to a YAML string with document separators.
@@ -231,12 +353,15 @@ def safe_dump_all(
     Args:
         documents: An iterable of Python objects to serialize.
         stream: If provided, write to this file-like object and return None.
-        allow_unicode: If True, allow unicode characters in output. Default: True.
+        allow_unicode: If False, escape non-ASCII characters. Default: True.
         sort_keys: If True, sort dictionary keys. Default: False.
-        indent: Number of spaces for indentation. Default: 2.
+        indent: Number of spaces for indentation, clamped to 1-9. Default: 2.
         width: Maximum line width. Default: 80.
+        Dumper: Accepted for PyYAML compatibility, as for ``safe_dump``.
         explicit_start: If True, add ``---`` document start marker. Default: False.
         default_flow_style: If True, use flow style. Default: None (block style).
+        default_style: Style for every string scalar, as for ``safe_dump``.
+            Default: None.
 
     Returns:
         A YAML string if stream is None, otherwise None.
@@ -248,6 +373,7 @@ def safe_dump_all(
         >>> fast_yaml.safe_dump_all([{'a': 1}], indent=4, explicit_start=True)
         '---\\na: 1\\n'
     """
+    _check_safe_dumper(Dumper)
     result = _safe_dump_all(
         list(documents),
         allow_unicode=allow_unicode,
@@ -256,6 +382,7 @@ def safe_dump_all(
         width=width if width is not None else 80,
         explicit_start=explicit_start,
         default_flow_style=default_flow_style,
+        default_style=default_style,
     )
 
     if stream is not None:
@@ -286,9 +413,9 @@ def safe_dump_to(
     Args:
         data: Python object to serialize
         stream: File-like object with write() method
-        allow_unicode: Allow unicode characters (default: True)
+        allow_unicode: If False, escape non-ASCII characters (default: True)
         sort_keys: Sort dictionary keys (default: False)
-        indent: Number of spaces for indentation (default: 2)
+        indent: Number of spaces for indentation, clamped to 1-9 (default: 2)
         width: Maximum line width (default: 80)
         default_flow_style: Force flow style for collections (default: None)
         explicit_start: Add explicit document start marker (default: False)
@@ -343,7 +470,7 @@ def load(
         The parsed YAML document as Python objects.
 
     Raises:
-        ValueError: If the YAML is invalid.
+        YamlScanError: If the YAML is invalid.
 
     Example:
         >>> import fast_yaml
@@ -440,9 +567,9 @@ def dump(
         data: A Python object to serialize.
         stream: If provided, write to this file-like object and return None.
         Dumper: Optional dumper class (SafeDumper, Dumper).
-        allow_unicode: If True, allow unicode characters in output. Default: True.
+        allow_unicode: If False, escape non-ASCII characters. Default: True.
         sort_keys: If True, sort dictionary keys. Default: False.
-        indent: Number of spaces for indentation. Default: 2.
+        indent: Number of spaces for indentation, clamped to 1-9. Default: 2.
         width: Maximum line width. Default: 80.
         explicit_start: If True, add explicit document start marker (---).
 
@@ -507,9 +634,9 @@ def dump_all(
         documents: An iterable of Python objects to serialize.
         stream: If provided, write to this file-like object and return None.
         Dumper: Optional dumper class (SafeDumper, Dumper).
-        allow_unicode: If True, allow unicode characters in output. Default: True.
+        allow_unicode: If False, escape non-ASCII characters. Default: True.
         sort_keys: If True, sort dictionary keys. Default: False.
-        indent: Number of spaces for indentation. Default: 2.
+        indent: Number of spaces for indentation, clamped to 1-9. Default: 2.
         width: Maximum line width. Default: 80.
         explicit_start: If True, add explicit document start markers (---).
 
@@ -2,6 +2,8 @@
 
 from __future__ import annotations
 
+import os
+from collections.abc import Iterator
 from typing import Any, TypedDict
 
 # =============================================================================
@@ -33,6 +35,16 @@ class Loader:
 
     ...
 
+class SafeLoadIter(Iterator[Any]):
+    """Iterator over YAML documents, returned by safe_load_iter().
+
+    Each document is parsed when the iterator reaches it. Invalid YAML raises
+    ValueError, after which the iterator is exhausted.
+    """
+
+    def __iter__(self) -> SafeLoadIter: ...
+    def __next__(self) -> Any: ...
+
 # =============================================================================
 # Dumper Classes (PyYAML compatibility)
 # =============================================================================
@@ -106,6 +118,19 @@ class EmitterError(YAMLError):
 
     ...
 
+class YamlScanError(ValueError):
+    """YAML parse error raised by the loaders, with its position.
+
+    ``line`` and ``column`` are 0-indexed, as in PyYAML's marks; the message
+    shows them 1-indexed.
+    """
+
+    line: int
+    column: int
+    problem: str
+    context: str | None
+    problem_mark: Mark
+
 # =============================================================================
 # Mark Class (error location tracking)
 # =============================================================================
@@ -139,6 +164,39 @@ def safe_load(yaml_str: str) -> Any:
     """
     ...
 
+def safe_load_bytes(data: bytes) -> Any:
+    """Parse a UTF-8 encoded YAML document, skipping a leading BOM.
+
+    Args:
+        data: A YAML document as UTF-8 bytes
+
+    Returns:
+        The parsed YAML document as Python objects
+
+    Raises:
+        UnicodeDecodeError: If data is not valid UTF-8 (start is the byte offset
+            of the first invalid sequence)
+        ValueError: If the YAML is invalid or input exceeds 100MB limit
+    """
+    ...
+
+def safe_load_path(path: str | os.PathLike[str]) -> Any:
+    """Read a YAML file in Rust, with the GIL released, and parse it.
+
+    Args:
+        path: Path to a UTF-8 encoded YAML file
+
+    Returns:
+        The parsed YAML document as Python objects
+
+    Raises:
+        FileNotFoundError: If the file does not exist
+        PermissionError: If the file cannot be read
+        UnicodeDecodeError: If the file is not valid UTF-8
+        ValueError: If the YAML is invalid or the file exceeds 100MB limit
+    """
+    ...
+
 def safe_load_all(yaml_str: str) -> list[Any]:
     """Parse a YAML string containing multiple documents.
 
@@ -153,6 +211,20 @@ def safe_load_all(yaml_str: str) -> list[Any]:
     """
     ...
 
+def safe_load_iter(yaml_str: str) -> SafeLoadIter:
+    """Parse the documents of a YAML string lazily.
+
+    Args:
+        yaml_str: A YAML string potentially containing multiple documents
+
+    Returns:
+        An iterator yielding one parsed document at a time
+
+    Raises:
+        ValueError: From iteration, if a document is invalid YAML
+    """
+    ...
+
 def safe_dump(
     data: Any,
     allow_unicode: bool = True,
@@ -161,27 +233,31 @@ def safe_dump(
     width: int = 80,
     default_flow_style: bool | None = None,
     explicit_start: bool = False,
+    default_style: str | None = None,
 ) -> str:
     """Serialize a Python object to a YAML string.
 
     Args:
         data: A Python object to serialize
-        allow_unicode: If True, allow unicode in output (currently always enabled)
+        allow_unicode: If False, escape non-ASCII characters (default: True)
         sort_keys: If True, sort dictionary keys
-        indent: Number of spaces for indentation (default: 2)
-        width: Maximum line width (default: 80)
-        default_flow_style: Force flow style for collections (default: None)
+        indent: Number of spaces for indentation, clamped to 1-9 (default: 2)
+        width: Maximum line width; longer strings are wrapped in folded style (default: 80)
+        default_flow_style: If True, render all collections in flow style (default: None, block style)
         explicit_start: Add explicit document start marker (default: False)
+        default_style: Style for every string scalar: '"', "'", '|' or '>'
+            (default: None, plain where possible)
 
     Returns:
         A YAML string representation of the object
 
     Raises:
         TypeError: If the object cannot be serialized
+        ValueError: If default_style is not a supported style
 
     Note:
-        The allow_unicode parameter is accepted for PyYAML compatibility,
-        but yaml-rust2 always outputs unicode characters.
+        With allow_unicode=False, strings containing non-ASCII characters are
+        written as double-quoted scalars using \\uXXXX or \\UXXXXXXXX escapes.
     """
     ...
 
@@ -193,17 +269,19 @@ def safe_dump_all(
     width: int = 80,
     default_flow_style: bool | None = None,
     explicit_start: bool = False,
+    default_style: str | None = None,
 ) -> str:
     """Serialize multiple Python objects to a YAML string.
 
     Args:
         documents: An iterable of Python objects to serialize
-        allow_unicode: If True, allow unicode in output (currently always enabled)
+        allow_unicode: If False, escape non-ASCII characters (default: True)
         sort_keys: If True, sort dictionary keys
-        indent: Number of spaces for indentation (default: 2)
+        indent: Number of spaces for indentation, clamped to 1-9 (default: 2)
         width: Maximum line width (default: 80)
         default_flow_style: Force flow style for collections (default: None)
         explicit_start: Add explicit document start marker (default: False)
+        default_style: Style for every string scalar, as for safe_dump (default: None)
 
     Returns:
         A YAML string with multiple documents separated by '---'
@@ -232,9 +310,9 @@ def safe_dump_to(
     Args:
         data: Python object to serialize
         stream: File-like object with write() method
-        allow_unicode: Allow unicode characters (default: True)
+        allow_unicode: If False, escape non-ASCII characters (default: True)
         sort_keys: Sort dictionary keys (default: False)
-        indent: Number of spaces for indentation (default: 2)
+        indent: Number of spaces for indentation, clamped to 1-9 (default: 2)
         width: Maximum line width (default: 80)
         default_flow_style: Force flow style for collections (default: None)
         explicit_start: Add explicit document start marker (default: False)
@@ -421,6 +499,22 @@ class lint:  # noqa: N801
         span: "lint.Span"
         context: "lint.DiagnosticContext | None"
         suggestions: list["lint.Suggestion"]
+        line: int  # span.start.line
+        column: int  # span.start.column
+        rule_code: str  # same as code
+        suggestion: str | None  # message of the first suggestion
+
+        def to_lsp_json(self) -> str:
+            """Convert to a Language Server Protocol diagnostic as a JSON string."""
+            ...
+
+        def to_vscode_problem_matcher_str(self) -> str:
+            """Format as `line:column: severity: message [code]` for VS Code problem matchers."""
+            ...
+
+        def __str__(self) -> str:
+            """Format as `[severity] code: line L col C: message`."""
+            ...
 
         def __repr__(self) -> str: ...
 
@@ -549,9 +643,9 @@ class parallel:  # noqa: N801
         Args:
             documents: List or iterable of Python objects to serialize
             config: Optional parallel processing configuration
-            allow_unicode: Allow unicode characters (default: True)
+            allow_unicode: If False, escape non-ASCII characters (default: True)
             sort_keys: If True, sort dictionary keys (default: False)
-            indent: Number of spaces for indentation (default: 2)
+            indent: Number of spaces for indentation, clamped to 1-9 (default: 2)
             width: Maximum line width (default: 80)
             default_flow_style: Force flow style for collections (default: None)
             explicit_start: Add explicit document start marker (default: False)
@@ -581,6 +675,7 @@ class batch:  # noqa: N801
         Changed: "batch.FileOutcome"
         Unchanged: "batch.FileOutcome"
         Error: "batch.FileOutcome"
+        Skipped: "batch.FileOutcome"
 
         def __repr__(self) -> str: ...
         def __eq__(self, other: object) -> bool: ...
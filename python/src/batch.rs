@@ -26,6 +26,8 @@ pub enum PyFileOutcome {
     Unchanged,
     /// Processing failed
     Error,
+    /// Not processed because the batch stopped early
+    Skipped,
 }
 
 #[pymethods]
@@ -36,6 +38,7 @@ impl PyFileOutcome {
             Self::Changed => "FileOutcome.Changed",
             Self::Unchanged => "FileOutcome.Unchanged",
             Self::Error => "FileOutcome.Error",
+            Self::Skipped => "FileOutcome.Skipped",
         }
     }
 
@@ -49,6 +52,7 @@ impl PyFileOutcome {
             Self::Changed => 1,
             Self::Unchanged => 2,
             Self::Error => 3,
+            Self::Skipped => 4,
         }
     }
 }
@@ -93,6 +97,7 @@ impl From<RustFileResult> for PyFileResult {
             RustFileOutcome::Changed { .. } => (PyFileOutcome::Changed, None),
             RustFileOutcome::Unchanged { .. } => (PyFileOutcome::Unchanged, None),
             RustFileOutcome::Error { error, .. } => (PyFileOutcome::Error, Some(error.to_string())),
+            RustFileOutcome::Skipped => (PyFileOutcome::Skipped, None),
         };
         Self {
             path: result.path.to_string_lossy().to_string(),
@@ -3,30 +3,34 @@
 //! Uses `saphyr_parser` events directly instead of `saphyr`'s `YamlLoader`,
 //! which silently drops core-schema collection tags (`!!set`, `!!omap`, …).
 //! This loader preserves the `!!set` tag and converts the mapping to a Python `set`.
+//!
+//! Documents can also be loaded lazily through [`SafeLoadIter`].
 
 use std::collections::HashMap;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PySet, PyString};
-use saphyr_parser::{Event, Parser, ScanError, StrInput};
+use saphyr_parser::{BufferedInput, Event, Input, Parser};
 
 use crate::repr_to_python;
 
+/// Byte order mark, skipped at the start of the input.
+const BOM: char = '\u{feff}';
+
 /// Parse all YAML documents from `input` into Python objects.
 ///
 /// Injects one implicit null document when the stream is non-empty but contains
 /// no explicit documents (comment-only, whitespace-only, bare `---`/`...`),
-/// matching YAML 1.2 §9.2 and `PyYAML` parity.
+/// matching YAML 1.2 §9.2 and `PyYAML` parity. A leading byte order mark is
+/// skipped, so an input holding only a BOM is empty.
 ///
 /// # Errors
 ///
-/// Returns `PyValueError` on invalid YAML syntax.
+/// Returns a `YamlScanError` (a `ValueError`) on invalid YAML syntax.
 pub fn load_all(py: Python<'_>, input: &str) -> PyResult<Vec<Py<PyAny>>> {
-    let mut loader = EventLoader {
-        parser: Parser::new_from_str(input),
-        anchors: HashMap::new(),
-    };
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let mut loader = EventLoader::new(Parser::new_from_str(input));
     let docs = loader.load_stream(py)?;
     // Replicate fast-yaml-core: inject implicit null for non-empty, zero-doc streams
     if docs.is_empty() && !input.is_empty() {
@@ -36,13 +40,97 @@ pub fn load_all(py: Python<'_>, input: &str) -> PyResult<Vec<Py<PyAny>>> {
     }
 }
 
-struct EventLoader<'input> {
-    parser: Parser<'input, StrInput<'input>>,
+/// Iterator over the YAML documents of a string, built one at a time.
+///
+/// Returned by `safe_load_iter`. Only the current document is held as Python
+/// objects, and anchors are dropped when their document ends, so memory use
+/// does not grow with the number of documents. Invalid YAML raises
+/// `ValueError` from `__next__`, after which the iterator is exhausted.
+#[pyclass(module = "fast_yaml._core", name = "SafeLoadIter")]
+pub struct SafeLoadIter {
+    loader: EventLoader<'static, BufferedInput<OwnedChars>>,
+    /// Input had content, so an empty stream yields one implicit null document.
+    implicit_null: bool,
+    /// End of stream reached or an error was raised.
+    done: bool,
+}
+
+impl SafeLoadIter {
+    /// Create an iterator over the documents of `input`.
+    pub fn new(input: String) -> Self {
+        let pos = if input.starts_with(BOM) {
+            BOM.len_utf8()
+        } else {
+            0
+        };
+        let implicit_null = input.len() > pos;
+        Self {
+            loader: EventLoader::new(Parser::new(BufferedInput::new(OwnedChars { input, pos }))),
+            implicit_null,
+            done: false,
+        }
+    }
+}
+
+#[pymethods]
+impl SafeLoadIter {
+    const fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
+        if self.done {
+            return Ok(None);
+        }
+        match self.loader.load_document(py) {
+            Ok(Some(doc)) => {
+                self.implicit_null = false;
+                Ok(Some(doc))
+            }
+            Ok(None) => {
+                self.done = true;
+                // Same implicit null document as `load_all`
+                Ok(self.implicit_null.then(|| py.None()))
+            }
+            Err(e) => {
+                self.done = true;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Character iterator that owns its input, so a parser over it has no
+/// borrowed lifetime and can be stored in a Python object.
+struct OwnedChars {
+    input: String,
+    pos: usize,
+}
+
+impl Iterator for OwnedChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.input[self.pos..].chars().next()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+}
+
+struct EventLoader<'input, T: Input> {
+    parser: Parser<'input, T>,
     /// Anchor id → Python object, used to resolve YAML aliases.
     anchors: HashMap<usize, Py<PyAny>>,
 }
 
-impl<'input> EventLoader<'input> {
+impl<'input, T: Input> EventLoader<'input, T> {
+    fn new(parser: Parser<'input, T>) -> Self {
+        Self {
+            parser,
+            anchors: HashMap::new(),
+        }
+    }
+
     /// Advance the parser and return the next meaningful event.
     fn next(&mut self) -> PyResult<Event<'input>> {
         loop {
@@ -50,7 +138,7 @@ impl<'input> EventLoader<'input> {
                 Some(Ok((Event::Nothing, _))) => {}
                 Some(Ok((ev, _))) => return Ok(ev),
                 Some(Err(ref e)) => {
-                    return Err(scan_err(e));
+                    return Err(crate::scan_error(e));
                 }
                 None => return Ok(Event::StreamEnd),
             }
@@ -58,23 +146,29 @@ impl<'input> EventLoader<'input> {
     }
 
     fn load_stream(&mut self, py: Python<'_>) -> PyResult<Vec<Py<PyAny>>> {
-        // Consume StreamStart
-        self.next()?;
-
         let mut docs = Vec::new();
+        while let Some(doc) = self.load_document(py)? {
+            docs.push(doc);
+        }
+        Ok(docs)
+    }
+
+    /// Build the next document, or return `None` at the end of the stream.
+    fn load_document(&mut self, py: Python<'_>) -> PyResult<Option<Py<PyAny>>> {
         loop {
             match self.next()? {
-                Event::StreamEnd => break,
+                Event::StreamEnd => return Ok(None),
                 Event::DocumentStart(_) => {
+                    // Aliases cannot refer to anchors of earlier documents
+                    self.anchors.clear();
+                    // The DocumentEnd event, if not consumed here, is skipped by the
+                    // next call as a stray event.
                     let value = self.parse_node(py)?.unwrap_or_else(|| py.None());
-                    // Consume DocumentEnd (best-effort; parse_node may have consumed it
-                    // already as a None return).
-                    docs.push(value);
+                    return Ok(Some(value));
                 }
-                _ => {} // ignore stray events
+                _ => {} // StreamStart and stray events
             }
         }
-        Ok(docs)
     }
 
     /// Consume the next event and build a Python value.
@@ -230,7 +324,3 @@ impl<'input> EventLoader<'input> {
         }
     }
 }
-
-fn scan_err(e: &ScanError) -> PyErr {
-    PyValueError::new_err(format!("YAML parse error: {e}"))
-}
@@ -16,19 +16,26 @@
 //!
 //! ## Error Handling Strategy
 //!
-//! - `ValueError`: Input validation errors (invalid YAML, limits exceeded, invalid config)
+//! - `ValueError`: Input validation errors (limits exceeded, invalid config)
+//! - `YamlScanError`: Invalid YAML syntax; a `ValueError` subclass carrying the position
 //! - `TypeError`: Type conversion errors (unsupported Python types)
 //! - `IOError`: I/O failures (stream write errors)
 
 #![allow(clippy::doc_markdown)] // Python docstrings use different conventions
 
+use std::path::{Path, PathBuf};
+
+use fast_yaml_core::ValueExt;
 use ordered_float::OrderedFloat;
 use pyo3::create_exception;
-use pyo3::exceptions::{PyException, PyTypeError, PyValueError};
+use pyo3::exceptions::{
+    PyException, PyFileNotFoundError, PyOSError, PyPermissionError, PyTypeError,
+    PyUnicodeDecodeError, PyValueError,
+};
 use pyo3::prelude::*;
-use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString};
+use pyo3::types::{PyBool, PyDate, PyDict, PyFloat, PyInt, PyList, PyString};
 use saphyr::{MappingOwned, ScalarOwned, YamlOwned};
-use saphyr_parser::{ScalarStyle, Tag};
+use saphyr_parser::{ScalarStyle, ScanError, Tag};
 
 mod batch;
 mod conversion;
@@ -280,6 +287,55 @@ create_exception!(
     "Error during YAML emission."
 );
 
+// Parse error raised by the loaders, with its position.
+create_exception!(
+    _core,
+    YamlScanError,
+    PyValueError,
+    "YAML parse error with `line`, `column`, `problem`, `context` and `problem_mark` attributes."
+);
+
+/// Split a saphyr message such as "while parsing a flow sequence, expected
+/// ',' or ']'" into `PyYAML`'s context and problem.
+fn split_scan_info(info: &str) -> (Option<&str>, &str) {
+    match info.split_once(", ") {
+        Some((context, problem)) if context.starts_with("while ") => (Some(context), problem),
+        _ => (None, info),
+    }
+}
+
+/// Convert a saphyr scan error into a `YamlScanError`.
+///
+/// `line` and `column` are 0-indexed, as in `PyYAML`'s marks; the message
+/// shows them 1-indexed.
+pub(crate) fn scan_error(error: &ScanError) -> PyErr {
+    let marker = error.marker();
+    let line = marker.line().saturating_sub(1);
+    let column = marker.col();
+    let (context, problem) = split_scan_info(error.info());
+    let err = YamlScanError::new_err(format!(
+        "YAML parse error: {} at line {}, column {}",
+        error.info(),
+        line + 1,
+        column + 1
+    ));
+    Python::attach(|py| {
+        let value = err.value(py);
+        let mark = Mark {
+            name: "<unicode string>".to_string(),
+            line,
+            column,
+        };
+        // Setting attributes on a fresh exception instance does not fail
+        let _ = value.setattr("line", line);
+        let _ = value.setattr("column", column);
+        let _ = value.setattr("problem", problem);
+        let _ = value.setattr("context", context);
+        let _ = value.setattr("problem_mark", mark);
+    });
+    err
+}
+
 /// Mark class for tracking source location in YAML errors.
 ///
 /// Stores the name of the input source (e.g., filename or `"<string>"`),
@@ -523,6 +579,59 @@ pub(crate) fn repr_to_python(
     }
 }
 
+/// Returns true if `s` matches the YAML 1.1 timestamp type
+/// (<https://yaml.org/type/timestamp.html>): `2001-12-14`, or a date and time
+/// such as `2001-12-14T21:59:43.10-05:00` or `2001-12-14 21:59:43.10 Z`.
+fn is_yaml_timestamp(s: &str) -> bool {
+    /// Consumes between `min` and `max` ASCII digits.
+    fn digits(s: &str, min: usize, max: usize) -> Option<&str> {
+        let n = s.bytes().take(max).take_while(u8::is_ascii_digit).count();
+        (n >= min).then(|| &s[n..])
+    }
+
+    let date = || -> Option<&str> {
+        let rest = digits(s, 4, 4)?.strip_prefix('-')?;
+        let rest = digits(rest, 1, 2)?.strip_prefix('-')?;
+        digits(rest, 1, 2)
+    };
+    let Some(rest) = date() else {
+        return false;
+    };
+    if rest.is_empty() {
+        // The date-only form needs two-digit month and day
+        return s.len() == 10;
+    }
+
+    let time = || -> Option<&str> {
+        let rest = rest.strip_prefix(['T', 't']).or_else(|| {
+            rest.strip_prefix([' ', '\t'])
+                .map(|r| r.trim_start_matches([' ', '\t']))
+        })?;
+        let rest = digits(rest, 1, 2)?.strip_prefix(':')?;
+        let rest = digits(rest, 2, 2)?.strip_prefix(':')?;
+        let rest = digits(rest, 2, 2)?;
+        Some(
+            rest.strip_prefix('.')
+                .map_or(rest, |r| r.trim_start_matches(|c: char| c.is_ascii_digit())),
+        )
+    };
+    let Some(zone) = time() else {
+        return false;
+    };
+    if zone.is_empty() {
+        return true;
+    }
+    let zone = zone.trim_start_matches([' ', '\t']);
+    if zone == "Z" {
+        return true;
+    }
+    zone.strip_prefix(['+', '-'])
+        .and_then(|r| digits(r, 1, 2))
+        .is_some_and(|r| {
+            r.is_empty() || r.strip_prefix(':').and_then(|m| digits(m, 2, 2)) == Some("")
+        })
+}
+
 /// Convert a Python object to a `YamlOwned` value.
 ///
 /// Handles Python types including special float values (inf, -inf, nan)
@@ -541,8 +650,17 @@ pub(crate) fn python_to_yaml(obj: &Bound<'_, PyAny>) -> PyResult<YamlOwned> {
 
     // Check int
     if obj.is_instance_of::<PyInt>() {
-        let i: i64 = obj.extract()?;
-        return Ok(YamlOwned::Value(ScalarOwned::Integer(i)));
+        if let Ok(i) = obj.extract::<i64>() {
+            return Ok(YamlOwned::Value(ScalarOwned::Integer(i)));
+        }
+        // Python ints are arbitrary-precision; keep values outside i64 exact as
+        // plain decimal scalars, which load back as int
+        let digits: String = obj
+            .py()
+            .get_type::<PyInt>()
+            .call_method1("__repr__", (obj,))?
+            .extract()?;
+        return Ok(YamlOwned::Representation(digits, ScalarStyle::Plain, None));
     }
 
     // Check float - handle special values per YAML 1.2.2 spec
@@ -559,6 +677,18 @@ pub(crate) fn python_to_yaml(obj: &Bound<'_, PyAny>) -> PyResult<YamlOwned> {
         return Ok(YamlOwned::Value(ScalarOwned::String(s)));
     }
 
+    // Check datetime.date / datetime.datetime (and subclasses such as pandas
+    // Timestamp) - emitted as plain ISO 8601 timestamps like PyYAML's
+    // safe_dump. An isoformat() that is not a YAML timestamp is dumped as a
+    // string, quoted where needed.
+    if obj.is_instance_of::<PyDate>() {
+        let iso: String = obj.call_method0("isoformat")?.extract()?;
+        if is_yaml_timestamp(&iso) {
+            return Ok(YamlOwned::Representation(iso, ScalarStyle::Plain, None));
+        }
+        return Ok(YamlOwned::Value(ScalarOwned::String(iso)));
+    }
+
     // Check list
     if let Ok(list) = obj.cast::<PyList>() {
         let mut arr = Vec::with_capacity(list.len());
@@ -619,7 +749,9 @@ pub(crate) fn python_to_yaml(obj: &Bound<'_, PyAny>) -> PyResult<YamlOwned> {
 ///     The parsed YAML document as Python objects (dict, list, str, int, float, bool, None)
 ///
 /// Raises:
-///     `ValueError`: If the YAML is invalid or input exceeds size limit (100MB)
+///     `YamlScanError`: If the YAML is invalid; a `ValueError` subclass with
+///         0-indexed `line` and `column` attributes
+///     `ValueError`: If input exceeds size limit (100MB)
 ///
 /// Security:
 ///     Maximum input size is limited to 100MB to prevent denial-of-service attacks.
@@ -645,6 +777,87 @@ fn safe_load(py: Python<'_>, yaml_str: &str) -> PyResult<Py<PyAny>> {
     Ok(docs.into_iter().next().unwrap_or_else(|| py.None()))
 }
 
+/// Parse a UTF-8 encoded YAML document and return a Python object.
+///
+/// Like `safe_load`, but takes `bytes`, so callers need not decode first.
+/// A leading UTF-8 byte order mark is skipped.
+///
+/// Args:
+///     data: A YAML document as UTF-8 bytes
+///
+/// Returns:
+///     The parsed YAML document as Python objects (dict, list, str, int, float, bool, None)
+///
+/// Raises:
+///     `UnicodeDecodeError`: If `data` is not valid UTF-8; the error's `start`
+///         is the byte offset of the first invalid sequence
+///     `YamlScanError`: If the YAML is invalid
+///     `ValueError`: If input exceeds size limit (100MB)
+///
+/// Example:
+///     >>> import `fast_yaml`
+///     >>> `fast_yaml.safe_load_bytes`(b"\\xef\\xbb\\xbfname: test")
+///     {'name': 'test'}
+#[pyfunction]
+#[pyo3(signature = (data))]
+fn safe_load_bytes(py: Python<'_>, data: &[u8]) -> PyResult<Py<PyAny>> {
+    let text = std::str::from_utf8(data)
+        .map_err(|e| PyUnicodeDecodeError::new_err_from_utf8(py, data, e))?;
+    safe_load(py, text)
+}
+
+/// Map an I/O error on `path` to the matching Python `OSError` subclass.
+fn path_io_error(path: &Path, error: &std::io::Error) -> PyErr {
+    let msg = format!("{}: {error}", path.display());
+    match error.kind() {
+        std::io::ErrorKind::NotFound => PyFileNotFoundError::new_err(msg),
+        std::io::ErrorKind::PermissionDenied => PyPermissionError::new_err(msg),
+        _ => PyOSError::new_err(msg),
+    }
+}
+
+/// Read a YAML file and return its first document as a Python object.
+///
+/// The file is read in Rust with the GIL released, then parsed like
+/// `safe_load_bytes`.
+///
+/// Args:
+///     path: Path to a UTF-8 encoded YAML file (`str` or `os.PathLike`)
+///
+/// Returns:
+///     The parsed YAML document as Python objects (dict, list, str, int, float, bool, None)
+///
+/// Raises:
+///     `FileNotFoundError`: If `path` does not exist
+///     `PermissionError`: If `path` cannot be read
+///     `OSError`: For any other I/O failure
+///     `UnicodeDecodeError`: If the file is not valid UTF-8
+///     `YamlScanError`: If the YAML is invalid
+///     `ValueError`: If the file exceeds size limit (100MB)
+///
+/// Example:
+///     >>> import `fast_yaml`
+///     >>> `fast_yaml.safe_load_path`("config.yaml")
+///     {'name': 'test'}
+#[pyfunction]
+#[pyo3(signature = (path))]
+#[allow(clippy::needless_pass_by_value)] // PyO3 requires by-value for Python objects
+fn safe_load_path(py: Python<'_>, path: PathBuf) -> PyResult<Py<PyAny>> {
+    let size = std::fs::metadata(&path)
+        .map_err(|e| path_io_error(&path, &e))?
+        .len();
+    if size > MAX_INPUT_SIZE as u64 {
+        return Err(PyValueError::new_err(format!(
+            "input size {size} exceeds maximum allowed {MAX_INPUT_SIZE} (100MB)"
+        )));
+    }
+
+    let data = py
+        .detach(|| std::fs::read(&path))
+        .map_err(|e| path_io_error(&path, &e))?;
+    safe_load_bytes(py, &data)
+}
+
 /// Parse a YAML string containing multiple documents.
 ///
 /// This is equivalent to `PyYAML`'s `yaml.safe_load_all()`.
@@ -656,7 +869,8 @@ fn safe_load(py: Python<'_>, yaml_str: &str) -> PyResult<Py<PyAny>> {
 ///     A list of parsed YAML documents
 ///
 /// Raises:
-///     `ValueError`: If the YAML is invalid or input exceeds size limit (100MB)
+///     `YamlScanError`: If the YAML is invalid
+///     `ValueError`: If input exceeds size limit (100MB)
 ///
 /// Security:
 ///     Maximum input size is limited to 100MB to prevent denial-of-service attacks.
@@ -683,24 +897,58 @@ fn safe_load_all(py: Python<'_>, yaml_str: &str) -> PyResult<Py<PyAny>> {
     Ok(list.into_any().unbind())
 }
 
+/// Parse the documents of a YAML string lazily.
+///
+/// Unlike `safe_load_all`, documents are built one at a time as the returned
+/// iterator is advanced, so large multi-document streams (e.g. audit logs)
+/// can be processed without holding every document in memory.
+///
+/// Args:
+///     `yaml_str`: A YAML string potentially containing multiple documents
+///
+/// Returns:
+///     A `SafeLoadIter` yielding one parsed document per iteration
+///
+/// Raises:
+///     `YamlScanError`: From `__next__`, if the next document is invalid YAML
+///
+/// Security:
+///     The 100MB input limit of `safe_load_all` does not apply, since only
+///     one document is converted to Python objects at a time.
+///
+/// Example:
+///     >>> import `fast_yaml`
+///     >>> for doc in `fast_yaml.safe_load_iter`("---\\nfoo: 1\\n---\\nbar: 2"):
+///     ...     print(doc)
+///     {'foo': 1}
+///     {'bar': 2}
+#[pyfunction]
+#[pyo3(signature = (yaml_str))]
+fn safe_load_iter(yaml_str: &str) -> event_loader::SafeLoadIter {
+    event_loader::SafeLoadIter::new(yaml_str.to_owned())
+}
+
 /// Serialize a Python object to a YAML string.
 ///
 /// This is equivalent to `PyYAML`'s `yaml.safe_dump()`.
 ///
 /// Args:
 ///     data: A Python object to serialize (dict, list, str, int, float, bool, None)
-///     `allow_unicode`: Accepted for `PyYAML` API compatibility (default: `True`)
+///     `allow_unicode`: If `False`, escape non-ASCII characters (default: `True`)
 ///     `sort_keys`: If `True`, sort dictionary keys (default: `False`)
-///     `indent`: Indentation width in spaces (default: 2)
-///     `width`: Line width for wrapping (default: 80)
-///     `default_flow_style`: Force flow/block style (default: `None`)
+///     `indent`: Indentation width in spaces, clamped to 1-9 (default: 2)
+///     `width`: Line width; longer string values are wrapped in folded style (default: 80)
+///     `default_flow_style`: If `True`, render all collections in flow style (default: `None`, block style)
 ///     `explicit_start`: Add document start marker `---` (default: `False`)
+///     `default_style`: Style for every string scalar: `'"'`, `"'"`, `'|'` or `'>'`
+///         (default: `None`, plain where possible)
 ///
 /// Returns:
 ///     A YAML string representation of the object
 ///
 /// Raises:
 ///     TypeError: If the object contains types that cannot be serialized
+///     ValueError: If `default_style` is not a supported style
 ///
 /// Example:
 ///     >>> import fast_yaml
@@ -716,9 +964,9 @@ fn safe_load_all(py: Python<'_>, yaml_str: &str) -> PyResult<Py<PyAny>> {
     indent=2,
     width=80,
     default_flow_style=None,
-    explicit_start=false
+    explicit_start=false,
+    default_style=None
 ))]
-#[allow(unused_variables)] // allow_unicode is accepted for PyYAML API compatibility
 #[allow(clippy::too_many_arguments)] // PyYAML API compatibility requires these parameters
 fn safe_dump(
     py: Python<'_>,
@@ -729,23 +977,28 @@ fn safe_dump(
     width: usize,
     default_flow_style: Option<bool>,
     explicit_start: bool,
+    default_style: Option<&str>,
 ) -> PyResult<String> {
+    let default_style = parse_default_style(default_style)?;
+
     // Convert Python object to YAML
-    let yaml = python_to_yaml(data)?;
+    let mut yaml = python_to_yaml(data)?;
 
     // Sort keys if requested
-    let yaml = if sort_keys {
-        sort_yaml_keys(&yaml)
-    } else {
-        yaml
-    };
+    if sort_keys {
+        yaml.sort_keys();
+    }
+    if !allow_unicode {
+        yaml.escape_non_ascii();
+    }
 
     // Create emitter configuration
     let config = fast_yaml_core::EmitterConfig::new()
         .with_indent(indent)
         .with_width(width)
         .with_default_flow_style(default_flow_style)
-        .with_explicit_start(explicit_start);
+        .with_explicit_start(explicit_start)
+        .with_default_style(default_style);
 
     // Release GIL during CPU-intensive serialization
     let output = py
@@ -755,6 +1008,20 @@ fn safe_dump(
     Ok(output)
 }
 
+/// Parse `PyYAML`'s `default_style` indicator into a scalar style.
+fn parse_default_style(default_style: Option<&str>) -> PyResult<Option<ScalarStyle>> {
+    match default_style {
+        None => Ok(None),
+        Some("\"") => Ok(Some(ScalarStyle::DoubleQuoted)),
+        Some("'") => Ok(Some(ScalarStyle::SingleQuoted)),
+        Some("|") => Ok(Some(ScalarStyle::Literal)),
+        Some(">") => Ok(Some(ScalarStyle::Folded)),
+        Some(other) => Err(PyValueError::new_err(format!(
+            "invalid default_style {other:?}: expected None, '\"', \"'\", '|' or '>'"
+        ))),
+    }
+}
+
 /// Wrapper to call Python stream.write() from Rust.
 struct PyWriteable<'py> {
     stream: Bound<'py, PyAny>,
@@ -859,8 +1126,6 @@ fn safe_dump_to(
     explicit_start: bool,
     chunk_size: usize,
 ) -> PyResult<usize> {
-    let _ = allow_unicode; // Accepted for API compatibility, always true in saphyr
-
     // Validate chunk size (1KB - 1MB)
     let chunk_size = chunk_size.clamp(1024, 1024 * 1024);
 
@@ -868,12 +1133,13 @@ fn safe_dump_to(
     let mut writer = PyWriteable::new(stream.clone())?;
 
     // Convert Python to YAML value
-    let yaml = python_to_yaml(data)?;
-    let yaml = if sort_keys {
-        sort_yaml_keys(&yaml)
-    } else {
-        yaml
-    };
+    let mut yaml = python_to_yaml(data)?;
+    if sort_keys {
+        yaml.sort_keys();
+    }
+    if !allow_unicode {
+        yaml.escape_non_ascii();
+    }
 
     // Create emitter config
     let config = fast_yaml_core::EmitterConfig::new()
@@ -908,60 +1174,26 @@ fn safe_dump_to(
     Ok(writer.bytes_written())
 }
 
-/// Helper function to recursively sort dictionary keys in YAML
-pub(crate) fn sort_yaml_keys(yaml: &YamlOwned) -> YamlOwned {
-    match yaml {
-        YamlOwned::Mapping(map) => {
-            let mut sorted: Vec<_> = map.iter().collect();
-            sorted.sort_by(|(k1, _), (k2, _)| {
-                let s1 = yaml_to_sort_key(k1);
-                let s2 = yaml_to_sort_key(k2);
-                s1.cmp(&s2)
-            });
-            let mut new_map = MappingOwned::new();
-            for (k, v) in sorted {
-                new_map.insert(k.clone(), sort_yaml_keys(v));
-            }
-            YamlOwned::Mapping(new_map)
-        }
-        YamlOwned::Sequence(arr) => YamlOwned::Sequence(arr.iter().map(sort_yaml_keys).collect()),
-        other => other.clone(),
-    }
-}
-
-/// Convert YAML value to a sortable string key
-fn yaml_to_sort_key(yaml: &YamlOwned) -> String {
-    match yaml {
-        YamlOwned::Value(scalar) => match scalar {
-            ScalarOwned::String(s) => s.clone(),
-            ScalarOwned::Integer(i) => i.to_string(),
-            ScalarOwned::FloatingPoint(f) => f.to_string(),
-            ScalarOwned::Boolean(b) => b.to_string(),
-            ScalarOwned::Null => String::new(),
-        },
-        _ => String::new(),
-    }
-}
-
 /// Serialize multiple Python objects to a YAML string with document separators.
 ///
 /// This is equivalent to `PyYAML`'s `yaml.safe_dump_all()`.
 ///
 /// Args:
 ///     documents: An iterable of Python objects to serialize
-///     `allow_unicode`: Accepted for `PyYAML` API compatibility (default: `True`)
+///     `allow_unicode`: If `False`, escape non-ASCII characters (default: `True`)
 ///     `sort_keys`: If `True`, sort dictionary keys (default: `False`)
-///     `indent`: Indentation width in spaces (default: 2)
+///     `indent`: Indentation width in spaces, clamped to 1-9 (default: 2)
 ///     `width`: Line width for wrapping (default: 80)
 ///     `default_flow_style`: Force flow/block style (default: `None`)
 ///     `explicit_start`: Add document start marker `---` (default: `False`)
+///     `default_style`: Style for every string scalar, as for `safe_dump` (default: `None`)
 ///
 /// Returns:
 ///     A YAML string with multiple documents separated by "---"
 ///
 /// Raises:
 ///     TypeError: If any object cannot be serialized
-///     ValueError: If total output size exceeds 100MB limit
+///     ValueError: If total output size exceeds 100MB limit or `default_style` is invalid
 ///
 /// Security:
 ///     Maximum output size is limited to 100MB to prevent memory exhaustion.
@@ -978,9 +1210,9 @@ fn yaml_to_sort_key(yaml: &YamlOwned) -> String {
     indent=2,
     width=80,
     default_flow_style=None,
-    explicit_start=false
+    explicit_start=false,
+    default_style=None
 ))]
-#[allow(unused_variables)] // allow_unicode is accepted for PyYAML API compatibility
 #[allow(clippy::too_many_arguments)] // PyYAML API compatibility requires these parameters
 fn safe_dump_all(
     py: Python<'_>,
@@ -991,19 +1223,22 @@ fn safe_dump_all(
     width: usize,
     default_flow_style: Option<bool>,
     explicit_start: bool,
+    default_style: Option<&str>,
 ) -> PyResult<String> {
+    let default_style = parse_default_style(default_style)?;
     let iter = documents.try_iter()?;
 
     // Convert all Python objects to YAML first
     let mut yamls = Vec::new();
     for item in iter {
         let item = item?;
-        let yaml = python_to_yaml(&item)?;
-        let yaml = if sort_keys {
-            sort_yaml_keys(&yaml)
-        } else {
-            yaml
-        };
+        let mut yaml = python_to_yaml(&item)?;
+        if sort_keys {
+            yaml.sort_keys();
+        }
+        if !allow_unicode {
+            yaml.escape_non_ascii();
+        }
         yamls.push(yaml);
     }
 
@@ -1012,7 +1247,8 @@ fn safe_dump_all(
         .with_indent(indent)
         .with_width(width)
         .with_default_flow_style(default_flow_style)
-        .with_explicit_start(explicit_start);
+        .with_explicit_start(explicit_start)
+        .with_default_style(default_style);
 
     // Release GIL during CPU-intensive serialization
     let output = py
@@ -1053,7 +1289,8 @@ fn safe_dump_all(
 ///     The parsed YAML document as Python objects
 ///
 /// Raises:
-///     ValueError: If the YAML is invalid or input exceeds size limit (100MB)
+///     YamlScanError: If the YAML is invalid
+///     ValueError: If input exceeds size limit (100MB)
 ///
 /// Example:
 ///     >>> import fast_yaml
@@ -1085,7 +1322,8 @@ fn load(py: Python<'_>, stream: &str, loader: Option<Py<PyAny>>) -> PyResult<Py<
 ///     A list of parsed YAML documents
 ///
 /// Raises:
-///     ValueError: If the YAML is invalid or input exceeds size limit (100MB)
+///     YamlScanError: If the YAML is invalid
+///     ValueError: If input exceeds size limit (100MB)
 ///
 /// Example:
 ///     >>> import fast_yaml
@@ -1112,9 +1350,9 @@ fn load_all(py: Python<'_>, stream: &str, loader: Option<Py<PyAny>>) -> PyResult
 ///     data: A Python object to serialize (dict, list, str, int, float, bool, None)
 ///     stream: Reserved for PyYAML compatibility (not currently used)
 ///     dumper: Optional dumper instance (SafeDumper, Dumper)
-///     allow_unicode: Allow unicode characters (default: True)
+///     allow_unicode: If False, escape non-ASCII characters (default: True)
 ///     sort_keys: If True, sort dictionary keys (default: False)
-///     indent: Indentation width in spaces (default: 2)
+///     indent: Indentation width in spaces, clamped to 1-9 (default: 2)
 ///     width: Line width for wrapping (default: 80)
 ///     default_flow_style: Force flow/block style (default: None)
 ///     explicit_start: Add document start marker `---` (default: False)
@@ -1143,7 +1381,7 @@ fn load_all(py: Python<'_>, stream: &str, loader: Option<Py<PyAny>>) -> PyResult
     default_flow_style=None,
     explicit_start=false
 ))]
-#[allow(unused_variables)] // stream, dumper, allow_unicode accepted for PyYAML API compatibility
+#[allow(unused_variables)] // stream, dumper accepted for PyYAML API compatibility
 #[allow(clippy::too_many_arguments)] // PyYAML API compatibility requires these parameters
 #[allow(clippy::needless_pass_by_value)] // PyO3 requires by-value for Python objects
 fn dump(
@@ -1169,6 +1407,7 @@ fn dump(
         width,
         default_flow_style,
         explicit_start,
+        None,
     )
 }
 
@@ -1182,9 +1421,9 @@ fn dump(
 ///     documents: An iterable of Python objects to serialize
 ///     stream: Reserved for PyYAML compatibility (not currently used)
 ///     dumper: Optional dumper instance (SafeDumper, Dumper)
-///     allow_unicode: Allow unicode characters (default: True)
+///     allow_unicode: If False, escape non-ASCII characters (default: True)
 ///     sort_keys: If True, sort dictionary keys (default: False)
-///     indent: Indentation width in spaces (default: 2)
+///     indent: Indentation width in spaces, clamped to 1-9 (default: 2)
 ///     width: Line width for wrapping (default: 80)
 ///     default_flow_style: Force flow/block style (default: None)
 ///     explicit_start: Add document start marker `---` (default: False)
@@ -1214,7 +1453,7 @@ fn dump(
     default_flow_style=None,
     explicit_start=false
 ))]
-#[allow(unused_variables)] // stream, dumper, allow_unicode accepted for PyYAML API compatibility
+#[allow(unused_variables)] // stream, dumper accepted for PyYAML API compatibility
 #[allow(clippy::too_many_arguments)] // PyYAML API compatibility requires these parameters
 #[allow(clippy::needless_pass_by_value)] // PyO3 requires by-value for Python objects
 fn dump_all(
@@ -1240,6 +1479,7 @@ fn dump_all(
         width,
         default_flow_style,
         explicit_start,
+        None,
     )
 }
 
@@ -1263,7 +1503,10 @@ const fn version() -> &'static str {
 fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Core parsing functions
     m.add_function(wrap_pyfunction!(safe_load, m)?)?;
+    m.add_function(wrap_pyfunction!(safe_load_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(safe_load_path, m)?)?;
     m.add_function(wrap_pyfunction!(safe_load_all, m)?)?;
+    m.add_function(wrap_pyfunction!(safe_load_iter, m)?)?;
     m.add_function(wrap_pyfunction!(safe_dump, m)?)?;
     m.add_function(wrap_pyfunction!(safe_dump_all, m)?)?;
     m.add_function(wrap_pyfunction!(safe_dump_to, m)?)?;
@@ -1276,6 +1519,7 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Loader classes
     m.add_class::<SafeLoader>()?;
+    m.add_class::<event_loader::SafeLoadIter>()?;
     m.add_class::<FullLoader>()?;
     m.add_class::<Loader>()?;
 
@@ -1291,6 +1535,7 @@ fn _core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("ComposerError", m.py().get_type::<ComposerError>())?;
     m.add("ConstructorError", m.py().get_type::<ConstructorError>())?;
     m.add("EmitterError", m.py().get_type::<EmitterError>())?;
+    m.add("YamlScanError", m.py().get_type::<YamlScanError>())?;
 
     // Mark class for error location
     m.add_class::<Mark>()?;
@@ -1550,4 +1795,67 @@ development:
             }
         }
     }
+
+    #[test]
+    fn test_is_yaml_timestamp() {
+        for valid in [
+            "2023-01-15",
+            "2023-01-15T10:30:00",
+            "2023-01-15T10:30:00.123456",
+            "2023-01-15T10:30:00+00:00",
+            "2001-12-14t21:59:43.10-05:00",
+            "2001-12-14 21:59:43.10 -5",
+            "2001-12-14 21:59:43.10 Z",
+            "2001-12-15 2:59:43.10",
+            "2024-01-01T00:00:00.000000001",
+        ] {
+            assert!(is_yaml_timestamp(valid), "{valid}");
+        }
+        for invalid in [
+            "",
+            "2023-1-15",
+            "23-01-15",
+            "2023-01-15T",
+            "2023-01-15T10:30",
+            "2023-01-15T10:30:00+",
+            "2023-01-15T10:30:00+05:3",
+            "2023-01-15T10:30:00 ",
+            "soon: maybe",
+        ] {
+            assert!(!is_yaml_timestamp(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_split_scan_info() {
+        assert_eq!(
+            split_scan_info("while parsing a flow sequence, expected ',' or ']'"),
+            (Some("while parsing a flow sequence"), "expected ',' or ']'")
+        );
+        assert_eq!(
+            split_scan_info("mapping values are not allowed in this context"),
+            (None, "mapping values are not allowed in this context")
+        );
+    }
+
+    #[test]
+    fn test_parse_default_style() {
+        assert_eq!(parse_default_style(None).unwrap(), None);
+        assert_eq!(
+            parse_default_style(Some("\"")).unwrap(),
+            Some(ScalarStyle::DoubleQuoted)
+        );
+        assert_eq!(
+            parse_default_style(Some("'")).unwrap(),
+            Some(ScalarStyle::SingleQuoted)
+        );
+        assert_eq!(
+            parse_default_style(Some("|")).unwrap(),
+            Some(ScalarStyle::Literal)
+        );
+        assert_eq!(
+            parse_default_style(Some(">")).unwrap(),
+            Some(ScalarStyle::Folded)
+        );
+    }
 }
@@ -381,6 +381,56 @@ pub struct PyDiagnostic {
 
 #[pymethods]
 impl PyDiagnostic {
+    /// Line where the diagnostic starts (1-indexed), same as `span.start.line`.
+    #[getter]
+    const fn line(&self) -> usize {
+        self.span.start.line
+    }
+
+    /// Column where the diagnostic starts (1-indexed), same as `span.start.column`.
+    #[getter]
+    const fn column(&self) -> usize {
+        self.span.start.column
+    }
+
+    /// Code of the rule that reported the diagnostic, same as `code`.
+    #[getter]
+    fn rule_code(&self) -> String {
+        self.code.clone()
+    }
+
+    /// Message of the first suggested fix, or `None` if there is none.
+    #[getter]
+    fn suggestion(&self) -> Option<String> {
+        self.suggestions.first().map(|s| s.message.clone())
+    }
+
+    /// Format as `[severity] code: line L col C: message`.
+    fn __str__(&self) -> String {
+        format!(
+            "[{}] {}: line {} col {}: {}",
+            self.severity.as_str(),
+            self.code,
+            self.line(),
+            self.column(),
+            self.message
+        )
+    }
+
+    /// Convert to a Language Server Protocol diagnostic as a JSON string.
+    ///
+    /// Positions are 0-indexed and severity uses LSP values
+    /// (1 = error, 2 = warning, 3 = information, 4 = hint).
+    #[cfg(feature = "json-output")]
+    fn to_lsp_json(&self) -> String {
+        RustDiagnostic::from(self.clone()).to_lsp_json().to_string()
+    }
+
+    /// Format as `line:column: severity: message [code]` for VS Code problem matchers.
+    fn to_vscode_problem_matcher_str(&self) -> String {
+        RustDiagnostic::from(self.clone()).to_vscode_problem_matcher_str()
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "Diagnostic(code={:?}, severity={}, message={:?})",
@@ -415,6 +465,65 @@ impl From<RustDiagnostic> for PyDiagnostic {
     }
 }
 
+impl From<PyDiagnostic> for RustDiagnostic {
+    fn from(diagnostic: PyDiagnostic) -> Self {
+        // Convert context back to Rust type
+        let context = diagnostic.context.map(|py_ctx| RustDiagnosticContext {
+            lines: py_ctx
+                .lines
+                .into_iter()
+                .map(|py_line| RustContextLine {
+                    line_number: py_line.line_number,
+                    content: py_line.content,
+                    highlights: py_line.highlights,
+                })
+                .collect(),
+        });
+
+        // Convert suggestions back to Rust type
+        let suggestions = diagnostic
+            .suggestions
+            .into_iter()
+            .map(|py_suggestion| RustSuggestion {
+                message: py_suggestion.message,
+                span: RustSpan::new(
+                    RustLocation::new(
+                        py_suggestion.span.start.line,
+                        py_suggestion.span.start.column,
+                        py_suggestion.span.start.offset,
+                    ),
+                    RustLocation::new(
+                        py_suggestion.span.end.line,
+                        py_suggestion.span.end.column,
+                        py_suggestion.span.end.offset,
+                    ),
+                ),
+                replacement: py_suggestion.replacement,
+            })
+            .collect();
+
+        Self {
+            code: RustDiagnosticCode::new(diagnostic.code),
+            severity: diagnostic.severity.inner,
+            message: diagnostic.message,
+            span: RustSpan::new(
+                RustLocation::new(
+                    diagnostic.span.start.line,
+                    diagnostic.span.start.column,
+                    diagnostic.span.start.offset,
+                ),
+                RustLocation::new(
+                    diagnostic.span.end.line,
+                    diagnostic.span.end.column,
+                    diagnostic.span.end.offset,
+                ),
+            ),
+            context,
+            suggestions,
+        }
+    }
+}
+
 /// Parses a severity string into `RustSeverity`.
 ///
 /// Valid values (case-insensitive): "error", "warning", "info", "hint".
@@ -719,64 +828,7 @@ impl PyTextFormatter {
     fn format(&self, diagnostics: &Bound<'_, PyList>, source: &str) -> PyResult<String> {
         let rust_diagnostics: Vec<RustDiagnostic> = diagnostics
             .iter()
-            .map(|item| {
-                let py_diag: PyDiagnostic = item.extract()?;
-
-                // Convert context back to Rust type
-                let context = py_diag.context.map(|py_ctx| RustDiagnosticContext {
-                    lines: py_ctx
-                        .lines
-                        .into_iter()
-                        .map(|py_line| RustContextLine {
-                            line_number: py_line.line_number,
-                            content: py_line.content,
-                            highlights: py_line.highlights,
-                        })
-                        .collect(),
-                });
-
-                // Convert suggestions back to Rust type
-                let suggestions = py_diag
-                    .suggestions
-                    .into_iter()
-                    .map(|py_suggestion| RustSuggestion {
-                        message: py_suggestion.message,
-                        span: RustSpan::new(
-                            RustLocation::new(
-                                py_suggestion.span.start.line,
-                                py_suggestion.span.start.column,
-                                py_suggestion.span.start.offset,
-                            ),
-                            RustLocation::new(
-                                py_suggestion.span.end.line,
-                                py_suggestion.span.end.column,
-                                py_suggestion.span.end.offset,
-                            ),
-                        ),
-                        replacement: py_suggestion.replacement,
-                    })
-                    .collect();
-
-                Ok(RustDiagnostic {
-                    code: RustDiagnosticCode::new(py_diag.code),
-                    severity: py_diag.severity.inner,
-                    message: py_diag.message,
-                    span: RustSpan::new(
-                        RustLocation::new(
-                            py_diag.span.start.line,
-                            py_diag.span.start.column,
-                            py_diag.span.start.offset,
-                        ),
-                        RustLocation::new(
-                            py_diag.span.end.line,
-                            py_diag.span.end.column,
-                            py_diag.span.end.offset,
-                        ),
-                    ),
-                    context,
-                    suggestions,
-                })
-            })
+            .map(|item| Ok(item.extract::<PyDiagnostic>()?.into()))
             .collect::<PyResult<Vec<_>>>()?;
 
         Ok(self.inner.format(&rust_diagnostics, source))
@@ -804,64 +856,7 @@ impl PyJsonFormatter {
     fn format(&self, diagnostics: &Bound<'_, PyList>, source: &str) -> PyResult<String> {
         let rust_diagnostics: Vec<RustDiagnostic> = diagnostics
             .iter()
-            .map(|item| {
-                let py_diag: PyDiagnostic = item.extract()?;
-
-                // Convert context back to Rust type
-                let context = py_diag.context.map(|py_ctx| RustDiagnosticContext {
-                    lines: py_ctx
-                        .lines
-                        .into_iter()
-                        .map(|py_line| RustContextLine {
-                            line_number: py_line.line_number,
-                            content: py_line.content,
-                            highlights: py_line.highlights,
-                        })
-                        .collect(),
-                });
-
-                // Convert suggestions back to Rust type
-                let suggestions = py_diag
-                    .suggestions
-                    .into_iter()
-                    .map(|py_suggestion| RustSuggestion {
-                        message: py_suggestion.message,
-                        span: RustSpan::new(
-                            RustLocation::new(
-                                py_suggestion.span.start.line,
-                                py_suggestion.span.start.column,
-                                py_suggestion.span.start.offset,
-                            ),
-                            RustLocation::new(
-                                py_suggestion.span.end.line,
-                                py_suggestion.span.end.column,
-                                py_suggestion.span.end.offset,
-                            ),
-                        ),
-                        replacement: py_suggestion.replacement,
-                    })
-                    .collect();
-
-                Ok(RustDiagnostic {
-                    code: RustDiagnosticCode::new(py_diag.code),
-                    severity: py_diag.severity.inner,
-                    message: py_diag.message,
-                    span: RustSpan::new(
-                        RustLocation::new(
-                            py_diag.span.start.line,
-                            py_diag.span.start.column,
-                            py_diag.span.start.offset,
-                        ),
-                        RustLocation::new(
-                            py_diag.span.end.line,
-                            py_diag.span.end.column,
-                            py_diag.span.end.offset,
-                        ),
-                    ),
-                    context,
-                    suggestions,
-                })
-            })
+            .map(|item| Ok(item.extract::<PyDiagnostic>()?.into()))
             .collect::<PyResult<Vec<_>>>()?;
 
         Ok(self.inner.format(&rust_diagnostics, source))
@@ -8,8 +8,8 @@
 //! - `TypeError`: Used for type conversion errors (handled in conversion module)
 
 use crate::conversion::value_to_python;
-use crate::{python_to_yaml, sort_yaml_keys};
-use fast_yaml_core::{Emitter, EmitterConfig};
+use crate::python_to_yaml;
+use fast_yaml_core::{Emitter, EmitterConfig, ValueExt};
 use fast_yaml_parallel::{
     Config as RustParallelConfig, Error as ParallelError, parse_parallel as rust_parse_parallel,
     parse_parallel_with_config,
@@ -331,7 +331,7 @@ fn parse_parallel(
 /// Args:
 ///     documents: Iterable of Python objects to serialize
 ///     config: Optional parallel processing configuration
-///     allow_unicode: Allow unicode characters (default: true)
+///     allow_unicode: If false, escape non-ASCII characters (default: true)
 ///     sort_keys: Sort dictionary keys alphabetically (default: false)
 ///     indent: Indentation width in spaces (default: 2)
 ///     width: Maximum line width (default: 80)
@@ -372,7 +372,6 @@ fn dump_parallel(
     default_flow_style: Option<bool>,
     explicit_start: bool,
 ) -> PyResult<String> {
-    let _ = allow_unicode; // Accepted for API compatibility, always true in saphyr
     // Collect documents from Python iterator (requires GIL)
     let iter = documents.try_iter()?;
     let mut yaml_values = Vec::new();
@@ -395,14 +394,14 @@ fn dump_parallel(
     }
 
     // Sort keys if requested (serial, before parallel phase)
-    let yaml_values: Vec<_> = if sort_keys {
-        yaml_values
-            .into_iter()
-            .map(|y| sort_yaml_keys(&y))
-            .collect()
-    } else {
-        yaml_values
-    };
+    for yaml in &mut yaml_values {
+        if sort_keys {
+            yaml.sort_keys();
+        }
+        if !allow_unicode {
+            yaml.escape_non_ascii();
+        }
+    }
 
     // Create emitter config
     let emitter_config = EmitterConfig::new()
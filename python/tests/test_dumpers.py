@@ -1,5 +1,7 @@
 """Tests for PyYAML-compatible dumper classes and dump functions."""
 
+import datetime
+
 import pytest
 
 import fast_yaml
@@ -227,6 +229,36 @@ class TestSafeDumpOptions:
             f"Expected 8-space indent, got: {repr(nested_line)}"
         )
 
+    @pytest.mark.parametrize("indent", [1, 2, 3, 4, 8])
+    def test_indent_each_nesting_level(self, indent):
+        """safe_dump() indents every nesting level by `indent` spaces."""
+        data = {"l1": {"l2": {"l3": {"l4": "leaf"}}}}
+        lines = fast_yaml.safe_dump(data, indent=indent).splitlines()
+        assert lines == [
+            "l1:",
+            " " * indent + "l2:",
+            " " * (2 * indent) + "l3:",
+            " " * (3 * indent) + "l4: leaf",
+        ]
+
+    @pytest.mark.parametrize("indent", [1, 3, 4, 9])
+    def test_indent_sequence_of_mappings(self, indent):
+        """Keys of a mapping in a sequence stay aligned at every indent."""
+        data = {"items": [{"name": "a", "meta": {"tags": ["x", "y"]}}, {"name": "b"}]}
+        result = fast_yaml.safe_dump(data, indent=indent)
+        lines = result.splitlines()
+        assert lines[1] == " " * indent + "- name: a"
+        assert lines[2] == " " * (indent + 2) + "meta:"
+        assert lines[3] == " " * (2 * indent + 2) + "tags:"
+        assert fast_yaml.safe_load(result) == data
+
+    @pytest.mark.parametrize(("indent", "expected"), [(0, 1), (10, 9), (100, 9)])
+    def test_indent_clamped(self, indent, expected):
+        """safe_dump() clamps indent to the range 1-9."""
+        data = {"parent": {"child": "value"}}
+        lines = fast_yaml.safe_dump(data, indent=indent).splitlines()
+        assert lines[1] == " " * expected + "child: value"
+
     def test_default_flow_style_false(self):
         """safe_dump() with default_flow_style=False produces block style."""
         data = {"key": [1, 2, 3]}
@@ -246,6 +278,28 @@ class TestSafeDumpOptions:
         result = fast_yaml.safe_dump(data, default_flow_style=True)
         assert "{" in result and "}" in result
 
+    @pytest.mark.parametrize(
+        ("data", "expected"),
+        [
+            ({"key": "value"}, "{key: value}\n"),
+            ({"a": [1, 2], "b": {"c": "d"}}, "{a: [1, 2], b: {c: d}}\n"),
+            ([1, "two", True], "[1, two, true]\n"),
+            ({}, "{}\n"),
+            ([], "[]\n"),
+        ],
+    )
+    def test_default_flow_style_true_exact(self, data, expected):
+        """safe_dump() with default_flow_style=True renders every collection in flow style."""
+        result = fast_yaml.safe_dump(data, default_flow_style=True)
+        assert result == expected
+        assert fast_yaml.safe_load(result) == data
+
+    def test_default_flow_style_true_quotes_flow_indicators(self):
+        """Strings containing flow indicators are quoted in flow style."""
+        data = {"k": "a, b", "j": "{x}", "l": ["[y]"]}
+        result = fast_yaml.safe_dump(data, default_flow_style=True)
+        assert fast_yaml.safe_load(result) == data
+
     def test_indent_4_with_flow_style_false(self):
         """safe_dump() with indent=4 and default_flow_style=False works correctly."""
         data = {"parent": {"child": "value"}}
@@ -266,6 +320,42 @@ class TestSafeDumpOptions:
         result = fast_yaml.safe_dump(data, width=40)
         assert "key: value" in result
 
+    LONG_TEXT = (
+        "The quick brown fox jumps over the lazy dog and keeps running "
+        "through the forest until night falls on the valley."
+    )
+
+    def test_width_wraps_long_string_folded(self):
+        """safe_dump() wraps strings longer than width=80 in folded style."""
+        data = {"description": self.LONG_TEXT}
+        result = fast_yaml.safe_dump(data, width=80)
+        lines = result.splitlines()
+        assert lines[0] == "description: >-"
+        assert len(lines) == 3
+        assert all(len(line) <= 80 for line in lines)
+        assert fast_yaml.safe_load(result) == data
+
+    def test_width_200_keeps_long_string_on_one_line(self):
+        """safe_dump() keeps the string on one line when it fits within width."""
+        data = {"description": self.LONG_TEXT}
+        result = fast_yaml.safe_dump(data, width=200)
+        assert result == f"description: {self.LONG_TEXT}\n"
+
+    def test_width_wraps_nested_strings(self):
+        """Folded strings in nested collections re-parse to the same value."""
+        data = {"items": [self.LONG_TEXT, {"note": self.LONG_TEXT}]}
+        for width in (40, 80):
+            result = fast_yaml.safe_dump(data, width=width)
+            assert ">-" in result
+            assert fast_yaml.safe_load(result) == data
+
+    def test_width_does_not_wrap_unbreakable_string(self):
+        """Strings without spaces stay on one line even when too long."""
+        data = {"url": "https://example.com/" + "a" * 100}
+        result = fast_yaml.safe_dump(data, width=80)
+        assert ">-" not in result
+        assert fast_yaml.safe_load(result) == data
+
     def test_sort_keys(self):
         """safe_dump() sorts keys when sort_keys=True."""
         data = {"z": 1, "a": 2}
@@ -302,6 +392,70 @@ class TestSafeDumpOptions:
         assert result.index("a:") < result.index("z:")
 
 
+class TestAllowUnicode:
+    """Tests for allow_unicode=False escaping of non-ASCII characters."""
+
+    DATA = {
+        "name": "café",
+        "emoji": "😀 \"quoted\" \\ slash",
+        "ключ": ["日本語", "tab\tand\nnewline"],
+        "plain": "ascii",
+        "count": 3,
+    }
+
+    def test_allow_unicode_default_keeps_characters(self):
+        """safe_dump() writes non-ASCII characters as-is by default."""
+        result = fast_yaml.safe_dump({"name": "café"})
+        assert "café" in result
+
+    def test_output_is_ascii(self):
+        """safe_dump(allow_unicode=False) produces ASCII-only output."""
+        result = fast_yaml.safe_dump(self.DATA, allow_unicode=False)
+        assert result.isascii()
+        assert '"caf\\u00E9"' in result
+        assert "\\U0001F600" in result
+
+    def test_roundtrip(self):
+        """Escaped output re-parses to the original value."""
+        result = fast_yaml.safe_dump(self.DATA, allow_unicode=False)
+        assert fast_yaml.safe_load(result) == self.DATA
+
+    def test_roundtrip_flow_style_and_sorted(self):
+        """Escaping works with flow style, custom indent and sorted keys."""
+        result = fast_yaml.safe_dump(
+            self.DATA, allow_unicode=False, default_flow_style=True, indent=4, sort_keys=True
+        )
+        assert result.isascii()
+        assert fast_yaml.safe_load(result) == self.DATA
+
+    def test_ascii_strings_unchanged(self):
+        """Strings without non-ASCII characters keep their usual style."""
+        result = fast_yaml.safe_dump({"plain": "ascii"}, allow_unicode=False)
+        assert result == "plain: ascii\n"
+
+    def test_safe_dump_all_roundtrip(self):
+        """safe_dump_all(allow_unicode=False) escapes every document."""
+        docs = [self.DATA, {"é": "ü"}]
+        result = fast_yaml.safe_dump_all(docs, allow_unicode=False)
+        assert result.isascii()
+        assert list(fast_yaml.safe_load_all(result)) == docs
+
+    def test_dump_roundtrip(self):
+        """dump(allow_unicode=False) escapes like safe_dump()."""
+        result = fast_yaml.dump(self.DATA, allow_unicode=False)
+        assert result.isascii()
+        assert fast_yaml.safe_load(result) == self.DATA
+
+    def test_safe_dump_to_roundtrip(self):
+        """safe_dump_to(allow_unicode=False) writes escaped output to the stream."""
+        import io
+
+        buf = io.StringIO()
+        fast_yaml.safe_dump_to(self.DATA, buf, allow_unicode=False)
+        assert buf.getvalue().isascii()
+        assert fast_yaml.safe_load(buf.getvalue()) == self.DATA
+
+
 class TestSafeDumpAllOptions:
     """Tests for safe_dump_all() formatting parameters (issue #151)."""
 
@@ -389,3 +543,149 @@ class TestDumpOptions:
         large_docs = [{"key": "x" * 10000000} for _ in range(20)]
         with pytest.raises(ValueError, match="exceeds maximum"):
             fast_yaml.dump_all(large_docs)
+
+
+class TestSafeDumpDumper:
+    """Tests for the PyYAML-compatible Dumper argument of safe_dump()."""
+
+    @pytest.mark.parametrize(
+        "dumper",
+        [None, fast_yaml.SafeDumper, fast_yaml.SafeDumper(), fast_yaml.Dumper],
+    )
+    def test_safe_dumpers_accepted(self, dumper):
+        """safe_dump() and safe_dump_all() accept safe dumpers and ignore them."""
+        assert fast_yaml.safe_dump({"a": 1}, Dumper=dumper) == "a: 1\n"
+        assert fast_yaml.safe_dump_all([{"a": 1}], Dumper=dumper) == "a: 1\n"
+
+    def test_other_dumper_rejected(self):
+        """safe_dump() rejects a Dumper that is not a safe dumper."""
+        with pytest.raises(TypeError, match="SafeDumper"):
+            fast_yaml.safe_dump({"a": 1}, Dumper=dict)
+
+
+class TestDefaultStyle:
+    """Tests for safe_dump(default_style=...)."""
+
+    DATA = {
+        "name": "it's",
+        "text": "line one\nline two\n",
+        "count": 3,
+        "flag": True,
+        "none": None,
+        "items": ["a", "b c"],
+    }
+
+    def test_none_is_plain(self):
+        """default_style=None keeps the usual plain output."""
+        assert fast_yaml.safe_dump({"k": "v"}, default_style=None) == "k: v\n"
+
+    def test_double_quoted(self):
+        """default_style='"' double-quotes keys and string values."""
+        assert fast_yaml.safe_dump({"k": "v", "n": 1}, default_style='"') == '"k": "v"\n"n": 1\n'
+
+    def test_single_quoted(self):
+        """default_style="'" single-quotes strings and doubles embedded quotes."""
+        assert fast_yaml.safe_dump({"k": "it's"}, default_style="'") == "'k': 'it''s'\n"
+
+    def test_literal(self):
+        """default_style='|' writes string values as literal block scalars."""
+        result = fast_yaml.safe_dump({"k": "a\nb\n"}, default_style="|")
+        assert result == '"k": |\n  a\n  b\n'
+
+    def test_folded(self):
+        """default_style='>' writes string values as folded block scalars."""
+        result = fast_yaml.safe_dump({"k": "a b"}, default_style=">")
+        assert result == '"k": >-\n  a b\n'
+
+    @pytest.mark.parametrize("style", ['"', "'", "|", ">"])
+    def test_roundtrip(self, style):
+        """Every style re-parses to the original value."""
+        result = fast_yaml.safe_dump(self.DATA, default_style=style)
+        assert fast_yaml.safe_load(result) == self.DATA
+
+    @pytest.mark.parametrize("style", ['"', "'", "|", ">"])
+    def test_roundtrip_flow_style(self, style):
+        """Styles combine with flow style output."""
+        result = fast_yaml.safe_dump(self.DATA, default_style=style, default_flow_style=True)
+        assert fast_yaml.safe_load(result) == self.DATA
+
+    def test_safe_dump_all(self):
+        """safe_dump_all() applies default_style to every document."""
+        result = fast_yaml.safe_dump_all([{"a": "x"}, {"b": "y"}], default_style="'")
+        assert result == "'a': 'x'\n---\n'b': 'y'\n"
+
+    def test_invalid_style(self):
+        """An unsupported default_style raises ValueError."""
+        with pytest.raises(ValueError, match="default_style"):
+            fast_yaml.safe_dump({"k": "v"}, default_style="x")
+
+
+class TestDatetime:
+    """Tests for dumping datetime.date and datetime.datetime values."""
+
+    def test_date(self):
+        """A date is dumped as its ISO 8601 form."""
+        assert fast_yaml.safe_dump({"d": datetime.date(2023, 1, 15)}) == "d: 2023-01-15\n"
+
+    @pytest.mark.parametrize(
+        ("value", "expected"),
+        [
+            (datetime.datetime(2024, 1, 2, 3, 4, 5), "t: 2024-01-02T03:04:05\n"),
+            (
+                datetime.datetime(2023, 1, 15, 10, 30, 0, 123456),
+                "t: 2023-01-15T10:30:00.123456\n",
+            ),
+            (
+                datetime.datetime(2023, 1, 15, 10, 30, tzinfo=datetime.timezone.utc),
+                "t: 2023-01-15T10:30:00+00:00\n",
+            ),
+        ],
+    )
+    def test_datetime(self, value, expected):
+        """A datetime is dumped as its plain, unquoted isoformat() string."""
+        assert fast_yaml.safe_dump({"t": value}) == expected
+
+    @pytest.mark.parametrize(
+        "value",
+        [
+            datetime.date(2023, 1, 15),
+            datetime.datetime(2023, 1, 15, 10, 30),
+            datetime.datetime(2023, 1, 15, 10, 30, 0, 123456),
+            datetime.datetime(2023, 1, 15, 10, 30, tzinfo=datetime.timezone.utc),
+        ],
+    )
+    def test_roundtrip(self, value):
+        """Dumped dates load back as their ISO 8601 strings."""
+        result = fast_yaml.safe_load(fast_yaml.safe_dump({"v": value}))
+        assert result == {"v": value.isoformat()}
+
+    def test_subclass(self):
+        """Subclasses such as pandas.Timestamp are dumped like datetime."""
+
+        class Stamp(datetime.datetime):
+            pass
+
+        class Day(datetime.date):
+            pass
+
+        value = {"t": Stamp(2024, 1, 2, 3, 4, 5), "d": Day(2024, 2, 29)}
+        assert fast_yaml.safe_dump(value) == "t: 2024-01-02T03:04:05\nd: 2024-02-29\n"
+
+    def test_invalid_isoformat_is_a_string(self):
+        """An isoformat() that is not a YAML timestamp is dumped as a string."""
+
+        class Vague(datetime.date):
+            def isoformat(self):
+                return "soon: maybe"
+
+        dumped = fast_yaml.safe_dump({"d": Vague(2024, 1, 1)})
+        assert dumped == 'd: "soon: maybe"\n'
+        assert fast_yaml.safe_load(dumped) == {"d": "soon: maybe"}
+
+    def test_in_sequence_and_dump_all(self):
+        """Dates are handled inside sequences and by safe_dump_all()."""
+        dates = [datetime.date(2024, 2, 29), datetime.datetime(1999, 12, 31, 23, 59)]
+        assert fast_yaml.safe_dump(dates) == "- 2024-02-29\n- 1999-12-31T23:59:00\n"
+        assert fast_yaml.safe_dump_all([{"d": d} for d in dates]) == (
+            "d: 2024-02-29\n---\nd: 1999-12-31T23:59:00\n"
+        )
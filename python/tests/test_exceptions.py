@@ -258,6 +258,45 @@ class TestExceptionHierarchy:
                 pytest.fail("EmitterError should not be MarkedYAMLError")
 
 
+class TestYamlScanError:
+    """Tests for YamlScanError raised by the loaders."""
+
+    def test_is_value_error(self):
+        """YamlScanError is a ValueError, so existing handlers still catch it."""
+        assert issubclass(fast_yaml.YamlScanError, ValueError)
+        with pytest.raises(ValueError):
+            fast_yaml.safe_load("key: [unclosed\n")
+
+    def test_position(self):
+        """line and column are 0-indexed; the message shows them 1-indexed."""
+        with pytest.raises(fast_yaml.YamlScanError) as exc_info:
+            fast_yaml.safe_load("key: value\n  bad: indent\n")
+        err = exc_info.value
+        assert err.line == 1
+        assert err.column == 5
+        assert "line 2, column 6" in str(err)
+        assert err.problem_mark.line == 1
+        assert err.problem_mark.column == 5
+
+    def test_problem_and_context(self):
+        """A 'while ...' prefix is split into context, as in PyYAML."""
+        with pytest.raises(fast_yaml.YamlScanError) as exc_info:
+            fast_yaml.safe_load("key: [unclosed")
+        err = exc_info.value
+        assert err.context == "while parsing a flow sequence"
+        assert err.problem.startswith("expected ")
+
+    def test_raised_by_all_loaders(self):
+        """safe_load_all(), safe_load_bytes() and load() raise it too."""
+        with pytest.raises(fast_yaml.YamlScanError) as exc_info:
+            list(fast_yaml.safe_load_all("a: 1\n---\nb: [\n"))
+        assert exc_info.value.line >= 2
+        with pytest.raises(fast_yaml.YamlScanError):
+            fast_yaml.safe_load_bytes(b"a: [")
+        with pytest.raises(fast_yaml.YamlScanError):
+            fast_yaml.load("a: [")
+
+
 class TestPyYAMLCompatibility:
     """Tests for PyYAML API compatibility."""
 
@@ -271,6 +310,7 @@ class TestPyYAMLCompatibility:
             "ComposerError",
             "ConstructorError",
             "EmitterError",
+            "YamlScanError",
         ]
 
         for exc_name in exceptions:
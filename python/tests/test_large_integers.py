@@ -1,3 +1,5 @@
+import pytest
+
 import fast_yaml
 
 
@@ -29,3 +31,29 @@ def test_negative_large_integer():
 def test_float_unaffected():
     result = fast_yaml.safe_load("x: 1.5e10")
     assert isinstance(result["x"], float)
+
+
+@pytest.mark.parametrize("value", [2**63, 2**64, -(2**63) - 1, -(2**64), 10**40])
+def test_dump_large_integer_roundtrip(value):
+    output = fast_yaml.safe_dump({"x": value})
+    assert output == f"x: {value}\n"
+    result = fast_yaml.safe_load(output)
+    assert result == {"x": value}
+    assert isinstance(result["x"], int)
+
+
+@pytest.mark.parametrize("value", [2**63 - 1, -(2**63)])
+def test_dump_i64_bounds_unaffected(value):
+    assert fast_yaml.safe_dump(value) == f"{value}\n"
+
+
+def test_dump_large_integer_key_and_flow_style():
+    data = {2**64: "key", "items": [2**65, -(2**64)]}
+    for flow in (None, True):
+        output = fast_yaml.safe_dump(data, default_flow_style=flow)
+        assert fast_yaml.safe_load(output) == data
+
+
+def test_dump_all_large_integers():
+    docs = [2**64, {"x": -(2**63) - 1}]
+    assert list(fast_yaml.safe_load_all(fast_yaml.safe_dump_all(docs))) == docs
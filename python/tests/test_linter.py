@@ -221,6 +221,48 @@ class TestDiagnostic:
         if diagnostics:
             assert repr(diagnostics[0]) is not None
 
+    def test_diagnostic_position_and_rule_code(self):
+        """line, column and rule_code mirror span.start and code."""
+        diagnostics = lint.Linter(config=None).lint("key: 1\nkey: 2\n")
+        diag = next(d for d in diagnostics if d.code == "duplicate-key")
+        assert diag.rule_code == "duplicate-key"
+        assert (diag.line, diag.column) == (2, 1)
+        assert (diag.line, diag.column) == (diag.span.start.line, diag.span.start.column)
+        assert "duplicate key 'key'" in diag.message
+        assert diag.severity == lint.Severity.ERROR
+
+    def test_diagnostic_fields_for_each_result(self):
+        """Every diagnostic exposes its position and rule code."""
+        config = lint.LintConfig(max_line_length=20)
+        diagnostics = lint.Linter(config).lint("a: 1\nkey: this value is longer than twenty\n")
+        long_line = [d for d in diagnostics if d.rule_code == "line-length"]
+        assert len(long_line) == 1
+        assert long_line[0].line == 2
+        assert long_line[0].column >= 1
+
+    def test_diagnostic_str(self):
+        """str() formats severity, code, position and message."""
+        diagnostics = lint.Linter.with_all_rules().lint("key: 1\nkey: 2\n")
+        diag = next(d for d in diagnostics if d.code == "duplicate-key")
+        assert str(diag) == (
+            "[error] duplicate-key: line 2 col 1: duplicate key 'key' (first defined at line 1)"
+        )
+
+    def test_diagnostic_suggestion(self):
+        """suggestion is the first suggested fix's message."""
+        diagnostics = lint.Linter.with_all_rules().lint("key: value   \n")
+        diag = next(d for d in diagnostics if d.code == "trailing-whitespace")
+        assert diag.suggestion == diag.suggestions[0].message
+        assert str(diag.severity) in ("error", "warning", "info", "hint")
+
+    def test_diagnostic_without_suggestion(self):
+        """suggestion is None when a diagnostic has no suggested fix."""
+        config = lint.LintConfig(max_line_length=20)
+        diagnostics = lint.Linter(config).lint("key: this value is longer than twenty\n")
+        diag = next(d for d in diagnostics if d.code == "line-length")
+        assert diag.suggestions == []
+        assert diag.suggestion is None
+
 
 class TestLintFunction:
     """Tests for the lint() convenience function."""
@@ -534,3 +576,34 @@ class TestPerRuleSeverity:
         )
         diagnostics = lint.lint(self.DUP_YAML, config)
         assert not any(d.code == "duplicate-key" for d in diagnostics)
+
+
+class TestDiagnosticEditorOutput:
+    """Tests for LSP and VS Code problem matcher output."""
+
+    DUP_YAML = "key: value\nkey: duplicate\n"
+
+    def _dup_diagnostic(self):
+        diagnostics = lint.lint(self.DUP_YAML)
+        return next(d for d in diagnostics if d.code == "duplicate-key")
+
+    def test_to_lsp_json(self):
+        """LSP output uses 0-indexed positions and numeric severity."""
+        diag = self._dup_diagnostic()
+        assert json.loads(diag.to_lsp_json()) == {
+            "range": {
+                "start": {"line": 1, "character": 0},
+                "end": {"line": 1, "character": 3},
+            },
+            "severity": 1,
+            "code": "duplicate-key",
+            "source": "fast-yaml",
+            "message": diag.message,
+        }
+
+    def test_to_vscode_problem_matcher_str(self):
+        """Problem matcher output is a single line with code suffix."""
+        diag = self._dup_diagnostic()
+        assert diag.to_vscode_problem_matcher_str() == (
+            f"2:1: error: {diag.message} [duplicate-key]"
+        )
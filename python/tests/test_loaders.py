@@ -1,5 +1,11 @@
 """Tests for PyYAML-compatible loader classes."""
 
+import io
+import os
+import sys
+import tempfile
+from pathlib import Path
+
 import pytest
 
 import fast_yaml
@@ -223,6 +229,158 @@ bar: 2
             fast_yaml.load_all("{ invalid: yaml: }")
 
 
+class TestSafeLoadIter:
+    """Tests for safe_load_iter() function."""
+
+    def test_yields_documents_in_order(self):
+        """safe_load_iter() yields each document in turn."""
+        result = fast_yaml.safe_load_iter("---\nfoo: 1\n---\nbar: 2\n---\n- 3\n")
+        assert list(result) == [{"foo": 1}, {"bar": 2}, [3]]
+
+    def test_is_lazy_iterator(self):
+        """safe_load_iter() returns an iterator that is its own __iter__."""
+        result = fast_yaml.safe_load_iter("a: 1\n---\nb: 2\n")
+        assert isinstance(result, fast_yaml.SafeLoadIter)
+        assert iter(result) is result
+        assert next(result) == {"a": 1}
+        assert next(result) == {"b": 2}
+        with pytest.raises(StopIteration):
+            next(result)
+
+    def test_matches_safe_load_all(self):
+        """safe_load_iter() yields the same documents as safe_load_all()."""
+        yaml_str = "base: &b {x: 1}\nuse: *b\n---\n!!set {a, b}\n---\n<<: {k: v}\nn: ~\n"
+        assert list(fast_yaml.safe_load_iter(yaml_str)) == list(fast_yaml.safe_load_all(yaml_str))
+
+    def test_empty_and_comment_only(self):
+        """Empty input yields nothing; comment-only input yields one None document."""
+        assert list(fast_yaml.safe_load_iter("")) == []
+        assert list(fast_yaml.safe_load_iter("# comment\n")) == [None]
+
+    def test_accepts_bytes_and_streams(self):
+        """safe_load_iter() accepts bytes and file-like objects."""
+        assert list(fast_yaml.safe_load_iter(b"a: 1\n---\nb: 2")) == [{"a": 1}, {"b": 2}]
+        assert list(fast_yaml.safe_load_iter(io.StringIO("- x\n"))) == [["x"]]
+
+    def test_error_mid_stream_raises_value_error(self):
+        """Invalid YAML raises ValueError after the preceding documents are yielded."""
+        result = fast_yaml.safe_load_iter("a: 1\n---\nb: [\n---\nc: 3\n")
+        assert next(result) == {"a": 1}
+        with pytest.raises(ValueError):
+            next(result)
+        # The iterator is exhausted after an error
+        with pytest.raises(StopIteration):
+            next(result)
+
+    def test_many_documents(self):
+        """safe_load_iter() handles long multi-document streams."""
+        yaml_str = "".join(f"---\nid: {i}\n" for i in range(1000))
+        ids = [doc["id"] for doc in fast_yaml.safe_load_iter(yaml_str)]
+        assert ids == list(range(1000))
+
+
+class TestSafeLoadBytes:
+    """Tests for safe_load_bytes() and bytes input to safe_load()."""
+
+    def test_utf8_bytes(self):
+        """safe_load_bytes() parses UTF-8 bytes."""
+        data = "name: café\nitems: [1, 2]\n".encode()
+        assert fast_yaml.safe_load_bytes(data) == {"name": "café", "items": [1, 2]}
+
+    def test_bom_stripped(self):
+        """A leading UTF-8 BOM does not become part of the first key."""
+        assert fast_yaml.safe_load_bytes(b"\xef\xbb\xbfkey: value\n") == {"key": "value"}
+
+    def test_only_leading_bom_stripped(self):
+        """A BOM inside the document is kept as content."""
+        result = fast_yaml.safe_load_bytes('key: "\ufeffx"\n'.encode())
+        assert result == {"key": "\ufeffx"}
+
+    def test_invalid_utf8_reports_offset(self):
+        """Invalid UTF-8 raises UnicodeDecodeError at the first invalid byte."""
+        with pytest.raises(UnicodeDecodeError) as exc_info:
+            fast_yaml.safe_load_bytes(b"key: val\xffue\n")
+        assert exc_info.value.start == 8
+        assert "position 8" in str(exc_info.value)
+
+    def test_invalid_utf8_offset_counts_bom(self):
+        """The reported offset is relative to the input including the BOM."""
+        with pytest.raises(UnicodeDecodeError) as exc_info:
+            fast_yaml.safe_load_bytes(b"\xef\xbb\xbfa: \xc3\n")
+        assert exc_info.value.start == 6
+
+    def test_decode_error_is_value_error(self):
+        """UnicodeDecodeError can be caught as ValueError."""
+        with pytest.raises(ValueError):
+            fast_yaml.safe_load_bytes(b"\x80")
+
+    def test_invalid_yaml(self):
+        """Invalid YAML in valid UTF-8 raises ValueError."""
+        with pytest.raises(ValueError):
+            fast_yaml.safe_load_bytes(b"key: [unclosed\n")
+
+    def test_safe_load_bytes_and_binary_stream(self):
+        """safe_load() strips the BOM from bytes and binary file objects."""
+        data = b"\xef\xbb\xbfkey: value\n"
+        assert fast_yaml.safe_load(data) == {"key": "value"}
+        assert fast_yaml.safe_load(io.BytesIO(data)) == {"key": "value"}
+
+
+EMPTY_INPUTS = [
+    "",
+    " ",
+    "\n",
+    "\r\n",
+    "   \n\t\n",
+    "\t",
+    "# just a comment\n",
+    "# no trailing newline",
+    "  # indented\n\n# second comment\n",
+    "\ufeff",
+    "\ufeff# comment after BOM\n",
+    "---\n",
+    "--- # comment\n",
+    "---\n...\n",
+]
+
+
+class TestEmptyInput:
+    """Inputs without content load as None, as in PyYAML."""
+
+    @pytest.mark.parametrize("yaml_str", EMPTY_INPUTS)
+    def test_safe_load_returns_none(self, yaml_str):
+        """safe_load() returns None for empty, whitespace and comment-only input."""
+        assert fast_yaml.safe_load(yaml_str) is None
+
+    @pytest.mark.parametrize("yaml_str", EMPTY_INPUTS)
+    def test_safe_load_bytes_returns_none(self, yaml_str):
+        """Bytes input behaves like the decoded string."""
+        assert fast_yaml.safe_load_bytes(yaml_str.encode()) is None
+        assert fast_yaml.safe_load(io.BytesIO(yaml_str.encode())) is None
+
+    @pytest.mark.parametrize("yaml_str", EMPTY_INPUTS)
+    def test_load_returns_none(self, yaml_str):
+        """load() agrees with safe_load()."""
+        assert fast_yaml.load(yaml_str) is None
+
+    @pytest.mark.parametrize("yaml_str", ["", "\ufeff"])
+    def test_no_documents(self, yaml_str):
+        """An empty stream, or one holding only a BOM, has no documents."""
+        assert list(fast_yaml.safe_load_all(yaml_str)) == []
+        assert list(fast_yaml.safe_load_iter(yaml_str)) == []
+
+    @pytest.mark.parametrize("yaml_str", ["   \n\t\n", "# just a comment\n", "\ufeff# comment\n"])
+    def test_whitespace_and_comments_are_one_null_document(self, yaml_str):
+        """Non-empty input without content is one implicit null document."""
+        assert list(fast_yaml.safe_load_all(yaml_str)) == [None]
+        assert list(fast_yaml.safe_load_iter(yaml_str)) == [None]
+
+    def test_bom_skipped_before_content(self):
+        """A leading BOM in a str is not part of the first scalar."""
+        assert fast_yaml.safe_load("\ufeffkey: value\n") == {"key": "value"}
+        assert list(fast_yaml.safe_load_iter("\ufeff- a\n")) == [["a"]]
+
+
 class TestLoaderCompatibility:
     """Tests for PyYAML API compatibility."""
 
@@ -295,3 +453,68 @@ class TestInputValidation:
         yaml_str = "key: value\nnumber: 42\nbool: true"
         result = fast_yaml.safe_load(yaml_str)
         assert result == {"key": "value", "number": 42, "bool": True}
+
+
+class TestSafeLoadPath:
+    """Tests for safe_load_path()."""
+
+    def test_load_str_path(self):
+        """A file named by a str path is read and parsed."""
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = os.path.join(tmpdir, "config.yaml")
+            with open(path, "w", encoding="utf-8") as f:
+                f.write("name: test\nitems:\n  - 1\n  - 2\n")
+            assert fast_yaml.safe_load_path(path) == {"name": "test", "items": [1, 2]}
+
+    def test_load_pathlike(self):
+        """os.PathLike paths are accepted, and a leading BOM is skipped."""
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = Path(tmpdir) / "bom.yaml"
+            path.write_bytes(b"\xef\xbb\xbfkey: value\n")
+            assert fast_yaml.safe_load_path(path) == {"key": "value"}
+
+    def test_empty_file(self):
+        """An empty file loads as None, like safe_load('')."""
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = Path(tmpdir) / "empty.yaml"
+            path.write_bytes(b"")
+            assert fast_yaml.safe_load_path(path) is None
+
+    def test_missing_file(self):
+        """A missing file raises FileNotFoundError naming the path."""
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = os.path.join(tmpdir, "missing.yaml")
+            with pytest.raises(FileNotFoundError, match="missing.yaml"):
+                fast_yaml.safe_load_path(path)
+
+    @pytest.mark.skipif(
+        sys.platform == "win32" or (hasattr(os, "geteuid") and os.geteuid() == 0),
+        reason="file permissions are not enforced",
+    )
+    def test_unreadable_file(self):
+        """A file without read permission raises PermissionError."""
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = Path(tmpdir) / "secret.yaml"
+            path.write_text("a: 1\n")
+            path.chmod(0)
+            try:
+                with pytest.raises(PermissionError):
+                    fast_yaml.safe_load_path(path)
+            finally:
+                path.chmod(0o600)
+
+    def test_invalid_utf8(self):
+        """A file that is not UTF-8 raises UnicodeDecodeError."""
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = Path(tmpdir) / "latin1.yaml"
+            path.write_bytes(b"key: caf\xe9\n")
+            with pytest.raises(UnicodeDecodeError):
+                fast_yaml.safe_load_path(path)
+
+    def test_invalid_yaml(self):
+        """Invalid YAML raises YamlScanError."""
+        with tempfile.TemporaryDirectory() as tmpdir:
+            path = Path(tmpdir) / "bad.yaml"
+            path.write_text("key: [unclosed\n")
+            with pytest.raises(fast_yaml.YamlScanError):
+                fast_yaml.safe_load_path(path)